@@ -147,7 +147,7 @@ impl Trainer {
         context: &AiContext<'_>,
         request: &TurnRequest,
     ) -> Result<Vec<Choice>> {
-        let TurnRequest { active, allies } = request;
+        let TurnRequest { active, allies, .. } = request;
         let mut state = ChoiceState::default();
         let mut choices = Vec::default();
         for (i, active) in active.into_iter().enumerate() {
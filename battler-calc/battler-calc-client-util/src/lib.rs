@@ -1,5 +1,7 @@
 mod mon;
 
+use std::str::FromStr;
+
 use anyhow::{
     Error,
     Result,
@@ -9,6 +11,9 @@ use battler::{
     Fraction,
     Nature,
     StatTable,
+    Type,
+    TypeChartOverlay,
+    TypeEffectiveness,
 };
 use battler_calc::{
     simulate::{
@@ -116,9 +121,28 @@ fn create_field(state: &BattleState, attacker_side: usize, defender_side: usize)
         conditions: field_conditions(state).map(|s| s.to_owned()).collect(),
         attacker_side: create_side(state, attacker_side)?,
         defender_side: create_side(state, defender_side)?,
+        type_chart_overlay: create_type_chart_overlay(state)?,
     })
 }
 
+fn create_type_chart_overlay(state: &BattleState) -> Result<TypeChartOverlay> {
+    let mut overlay = TypeChartOverlay {
+        inverse: state.field.inverse_type_chart,
+        ..Default::default()
+    };
+    for type_chart_override in &state.field.type_chart_overrides {
+        let offense = Type::from_str(&type_chart_override.offense).map_err(Error::msg)?;
+        let defense = Type::from_str(&type_chart_override.defense).map_err(Error::msg)?;
+        let multiplier = type_chart_override.multiplier.parse::<f32>()?;
+        overlay
+            .overrides
+            .entry(offense)
+            .or_default()
+            .insert(defense, TypeEffectiveness::from(multiplier));
+    }
+    Ok(overlay)
+}
+
 fn create_side(state: &BattleState, side: usize) -> Result<Side> {
     Ok(Side {
         conditions: side_conditions(state, side)?
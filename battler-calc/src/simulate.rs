@@ -222,7 +222,8 @@ impl<'d> MoveContext<'d> {
     }
 
     pub fn type_effectiveness(&self, offense: Type, defense: Type) -> TypeEffectiveness {
-        self.data
+        let effectiveness = self
+            .data
             .get_type_chart()
             .map(|type_chart| {
                 type_chart
@@ -233,7 +234,10 @@ impl<'d> MoveContext<'d> {
             })
             .ok()
             .flatten()
-            .unwrap_or_default()
+            .unwrap_or_default();
+        self.field
+            .type_chart_overlay
+            .effectiveness(offense, defense, effectiveness)
     }
 
     pub fn mon_is_grounded(&self, mon_type: MonType) -> bool {
@@ -1817,6 +1821,7 @@ mod damage_test {
         Nature,
         Stat,
         StatTable,
+        TypeChartOverlay,
     };
     use battler_test_utils::static_local_data_store;
 
@@ -2480,6 +2485,118 @@ mod damage_test {
         });
     }
 
+    #[test]
+    fn type_chart_overlay() {
+        assert_matches::assert_matches!(simulate_move(MoveSimulatorInput {
+            data: static_local_data_store(),
+            field: Field {
+                type_chart_overlay: TypeChartOverlay {
+                    inverse: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            attacker: Mon {
+                name: "Pikachu".to_owned(),
+                level: 100,
+                nature: Some(Nature::Hardy),
+                ivs: Some(max_ivs()),
+                evs: Some(empty_evs()),
+                ..Default::default()
+            },
+            defender: Mon {
+                name: "Gyarados".to_owned(),
+                level: 100,
+                nature: Some(Nature::Hardy),
+                ivs: Some(max_ivs()),
+                evs: Some(empty_evs()),
+                ..Default::default()
+            },
+            mov: Move {
+                name: "Thunderbolt".to_owned(),
+                ..Default::default()
+            },
+            flags: MoveSimulatorInputFlags::default(),
+        }), Ok(output) => {
+            let type_effectiveness = output.hits[0].damage.type_effectiveness.as_ref().unwrap();
+            pretty_assertions::assert_eq!(type_effectiveness, &Output::new(Fraction::new(1u64, 4u64), [
+                "x1/2 - not very effective against Water",
+                "x1/2 - not very effective against Flying",
+            ]));
+        });
+        assert_matches::assert_matches!(simulate_move(MoveSimulatorInput {
+            data: static_local_data_store(),
+            field: Field {
+                type_chart_overlay: TypeChartOverlay {
+                    inverse: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            attacker: Mon {
+                name: "Pikachu".to_owned(),
+                level: 100,
+                nature: Some(Nature::Hardy),
+                ivs: Some(max_ivs()),
+                evs: Some(empty_evs()),
+                ..Default::default()
+            },
+            defender: Mon {
+                name: "Sandslash".to_owned(),
+                level: 100,
+                nature: Some(Nature::Hardy),
+                ivs: Some(max_ivs()),
+                evs: Some(empty_evs()),
+                ..Default::default()
+            },
+            mov: Move {
+                name: "Thunderbolt".to_owned(),
+                ..Default::default()
+            },
+            flags: MoveSimulatorInputFlags::default(),
+        }), Ok(output) => {
+            let type_effectiveness = output.hits[0].damage.type_effectiveness.as_ref().unwrap();
+            pretty_assertions::assert_eq!(type_effectiveness, &Output::new(Fraction::from(2u64), [
+                "x2 - super effective against Ground",
+            ]));
+        });
+        assert_matches::assert_matches!(simulate_move(MoveSimulatorInput {
+            data: static_local_data_store(),
+            field: Field {
+                type_chart_overlay: TypeChartOverlay {
+                    overrides: TypeChartOverlay::parse_overrides("Electric > Flying: 0").unwrap(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            attacker: Mon {
+                name: "Pikachu".to_owned(),
+                level: 100,
+                nature: Some(Nature::Hardy),
+                ivs: Some(max_ivs()),
+                evs: Some(empty_evs()),
+                ..Default::default()
+            },
+            defender: Mon {
+                name: "Pidgeot".to_owned(),
+                level: 100,
+                nature: Some(Nature::Hardy),
+                ivs: Some(max_ivs()),
+                evs: Some(empty_evs()),
+                ..Default::default()
+            },
+            mov: Move {
+                name: "Thunderbolt".to_owned(),
+                ..Default::default()
+            },
+            flags: MoveSimulatorInputFlags::default(),
+        }), Ok(output) => {
+            assert!(output.hits[0].failed);
+            let damage = &output.hits[0].damage.damage;
+            assert_eq!(damage.value().min_max_range(), Some(Range::new(0, 0)));
+        });
+    }
+
     #[test]
     fn levitate_immunity() {
         assert_matches::assert_matches!(simulate_move(MoveSimulatorInput {
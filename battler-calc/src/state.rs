@@ -6,6 +6,7 @@ use battler_data::{
     Nature,
     StatTable,
     Type,
+    TypeChartOverlay,
 };
 
 #[derive(Debug, Default, Clone)]
@@ -17,6 +18,7 @@ pub struct Field {
     pub conditions: HashSet<String>,
     pub attacker_side: Side,
     pub defender_side: Side,
+    pub type_chart_overlay: TypeChartOverlay,
 }
 
 impl Field {
@@ -318,6 +318,62 @@ impl FromStr for SelectChoice {
     }
 }
 
+/// The direction of a rotation.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub enum RotateDirection {
+    /// The Mon to the right of the front Mon rotates to the front.
+    #[default]
+    Left,
+    /// The Mon to the left of the front Mon rotates to the front.
+    Right,
+}
+
+impl Display for RotateDirection {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Left => write!(f, "left"),
+            Self::Right => write!(f, "right"),
+        }
+    }
+}
+
+impl FromStr for RotateDirection {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "left" => Ok(Self::Left),
+            "right" => Ok(Self::Right),
+            _ => Err(Error::msg(format!("invalid rotate direction: {s}"))),
+        }
+    }
+}
+
+/// A choice to rotate Mons before using a move.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct RotateChoice {
+    /// The direction to rotate.
+    pub direction: RotateDirection,
+}
+
+impl Display for RotateChoice {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.direction)
+    }
+}
+
+impl FromStr for RotateChoice {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let direction = RotateDirection::from_str(s)?;
+        Ok(Self { direction })
+    }
+}
+
 /// A choice, which controls how a player responds to a request in a battle.
 #[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -338,6 +394,8 @@ pub enum Choice {
     Forfeit,
     /// Shift to the center (Triples battle only).
     Shift,
+    /// Rotate Mons before the following move (Rotation battle only).
+    Rotate(RotateChoice),
     /// Select team during team preview.
     Team(TeamSelectionChoice),
     /// Switch a Mon in.
@@ -362,6 +420,7 @@ impl Display for Choice {
             Self::Escape => write!(f, "escape"),
             Self::Forfeit => write!(f, "forfeit"),
             Self::Shift => write!(f, "shift"),
+            Self::Rotate(choice) => write!(f, "rotate {choice}"),
             Self::Team(choice) => {
                 write!(f, "team {choice}")
             }
@@ -400,6 +459,7 @@ impl FromStr for Choice {
             "escape" => Ok(Self::Escape),
             "forfeit" => Ok(Self::Forfeit),
             "shift" => Ok(Self::Shift),
+            "rotate" => Ok(Self::Rotate(RotateChoice::from_str(data)?)),
             "team" => Ok(Self::Team(TeamSelectionChoice::from_str(data)?)),
             "switch" => Ok(Self::Switch(SwitchChoice::from_str(data)?)),
             "move" => Ok(Self::Move(MoveChoice::from_str(data)?)),
@@ -464,6 +524,8 @@ mod battler_choice_test {
         ItemChoice,
         LearnMoveChoice,
         MoveChoice,
+        RotateChoice,
+        RotateDirection,
         SwitchChoice,
         TeamSelectionChoice,
         choice_results_from_string,
@@ -479,6 +541,20 @@ mod battler_choice_test {
         assert_eq!(Choice::Escape.to_string(), "escape");
         assert_eq!(Choice::Forfeit.to_string(), "forfeit");
         assert_eq!(Choice::Shift.to_string(), "shift");
+        assert_eq!(
+            Choice::Rotate(RotateChoice {
+                direction: RotateDirection::Left,
+            })
+            .to_string(),
+            "rotate left"
+        );
+        assert_eq!(
+            Choice::Rotate(RotateChoice {
+                direction: RotateDirection::Right,
+            })
+            .to_string(),
+            "rotate right"
+        );
         assert_eq!(
             Choice::Team(TeamSelectionChoice {
                 mons: Vec::from_iter([0, 2, 4]),
@@ -585,6 +661,24 @@ mod battler_choice_test {
         assert_matches::assert_matches!(Choice::from_str("escape"), Ok(Choice::Escape));
         assert_matches::assert_matches!(Choice::from_str("forfeit"), Ok(Choice::Forfeit));
         assert_matches::assert_matches!(Choice::from_str("shift"), Ok(Choice::Shift));
+        assert_matches::assert_matches!(
+            Choice::from_str("rotate left"),
+            Ok(Choice::Rotate(choice)) => {
+                assert_eq!(choice, RotateChoice {
+                    direction: RotateDirection::Left,
+                });
+            }
+        );
+        assert_matches::assert_matches!(
+            Choice::from_str("rotate right"),
+            Ok(Choice::Rotate(choice)) => {
+                assert_eq!(choice, RotateChoice {
+                    direction: RotateDirection::Right,
+                });
+            }
+        );
+        assert_matches::assert_matches!(Choice::from_str("rotate"), Err(_));
+        assert_matches::assert_matches!(Choice::from_str("rotate up"), Err(_));
         assert_matches::assert_matches!(
             Choice::from_str("team 0 2 4"),
             Ok(Choice::Team(choice)) => {
@@ -732,6 +826,17 @@ mod battler_choice_test {
                 ..Default::default()
             })]));
         });
+        assert_matches::assert_matches!(choices_from_string("rotate right;move 0"), Ok(choices) => {
+            pretty_assertions::assert_eq!(choices, Vec::from_iter([
+                Choice::Rotate(RotateChoice {
+                    direction: RotateDirection::Right,
+                }),
+                Choice::Move(MoveChoice {
+                    slot: 0,
+                    ..Default::default()
+                }),
+            ]));
+        });
         assert_matches::assert_matches!(choices_from_string("move 1,2;switch 3;forfeit"), Ok(choices) => {
             pretty_assertions::assert_eq!(choices, Vec::from_iter([
                 Choice::Move(MoveChoice {
@@ -769,6 +874,8 @@ mod battler_choice_test {
         super::SwitchChoice::export().unwrap();
         super::LearnMoveChoice::export().unwrap();
         super::SelectChoice::export().unwrap();
+        super::RotateDirection::export().unwrap();
+        super::RotateChoice::export().unwrap();
         super::Choice::export().unwrap();
     }
 }
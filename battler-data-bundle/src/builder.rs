@@ -0,0 +1,153 @@
+use anyhow::{
+    Context,
+    Result,
+};
+use battler::effect::fxlang::{
+    CompiledProgram,
+    Effect,
+    ParsedSubroutine,
+};
+use battler_data::Id;
+use battler_local_data::LocalDataStore;
+use serde::Serialize;
+
+use crate::format::{
+    FORMAT_VERSION,
+    Index,
+    MAGIC,
+    Span,
+    Table,
+};
+
+/// Writes the header of a bundle.
+fn write_header(bundle: &mut Vec<u8>, index_len: usize) -> Result<()> {
+    bundle.extend_from_slice(MAGIC);
+    bundle.extend_from_slice(&FORMAT_VERSION.to_le_bytes());
+    bundle.extend_from_slice(&CompiledProgram::FORMAT_VERSION.to_le_bytes());
+    bundle.extend_from_slice(
+        &u32::try_from(index_len)
+            .context("bundle index is too large")?
+            .to_le_bytes(),
+    );
+    Ok(())
+}
+
+/// Compiles an fxlang effect ahead of time.
+fn precompile_effect(effect: &mut serde_json::Value) -> Result<()> {
+    if effect.is_null() {
+        return Ok(());
+    }
+    let mut parsed = Effect::try_from(effect.clone())?;
+    parsed.precompile()?;
+    *effect = serde_json::to_value(parsed).context("failed to serialize effect")?;
+    Ok(())
+}
+
+/// Resources sorted by ID, so that bundles are built deterministically.
+fn sorted<'a, T>(resources: impl IntoIterator<Item = (&'a Id, &'a T)>) -> Vec<(&'a Id, &'a T)>
+where
+    T: 'a,
+{
+    let mut resources = resources.into_iter().collect::<Vec<_>>();
+    resources.sort_by_key(|(id, _)| *id);
+    resources
+}
+
+/// The resource section of a bundle under construction.
+#[derive(Default)]
+struct Resources {
+    bytes: Vec<u8>,
+}
+
+impl Resources {
+    fn add<T>(&mut self, table: &mut Table, id: &Id, name: Option<&str>, resource: &T) -> Result<()>
+    where
+        T: Serialize,
+    {
+        let bytes = serde_json::to_vec(resource).context(format!("failed to encode {id}"))?;
+        let span = Span {
+            offset: u32::try_from(self.bytes.len()).context("bundle is too large")?,
+            len: u32::try_from(bytes.len()).context(format!("{id} is too large"))?,
+        };
+        self.bytes.extend(bytes);
+        table.entries.insert(id.clone(), span);
+        if let Some(name) = name {
+            table.names.insert(name.to_owned(), id.clone());
+        }
+        Ok(())
+    }
+}
+
+/// Builds a bundle from all data in a [`LocalDataStore`].
+///
+/// Every fxlang program and subroutine is compiled ahead of time, so building fails if any program
+/// cannot be parsed.
+pub fn build_bundle(data: &LocalDataStore) -> Result<Vec<u8>> {
+    let mut resources = Resources::default();
+    let mut index = Index {
+        type_chart: data.type_chart.clone(),
+        aliases: data
+            .aliases
+            .iter()
+            .map(|(a, b)| (a.clone(), b.clone()))
+            .collect(),
+        abilities: Table::default(),
+        clauses: Table::default(),
+        conditions: Table::default(),
+        items: Table::default(),
+        moves: Table::default(),
+        species: Table::default(),
+        subroutines: Table::default(),
+    };
+
+    for (id, ability) in sorted(&data.abilities) {
+        let mut ability = ability.clone();
+        precompile_effect(&mut ability.effect).context(format!("invalid ability {id}"))?;
+        precompile_effect(&mut ability.condition).context(format!("invalid ability {id}"))?;
+        resources.add(&mut index.abilities, id, Some(&ability.name), &ability)?;
+    }
+    for (id, clause) in sorted(&data.clauses) {
+        let mut clause = clause.clone();
+        precompile_effect(&mut clause.effect).context(format!("invalid clause {id}"))?;
+        resources.add(&mut index.clauses, id, Some(&clause.name), &clause)?;
+    }
+    for (id, condition) in sorted(&data.conditions) {
+        let mut condition = condition.clone();
+        precompile_effect(&mut condition.condition).context(format!("invalid condition {id}"))?;
+        resources.add(&mut index.conditions, id, Some(&condition.name), &condition)?;
+    }
+    for (id, item) in sorted(&data.items) {
+        let mut item = item.clone();
+        precompile_effect(&mut item.effect).context(format!("invalid item {id}"))?;
+        precompile_effect(&mut item.condition).context(format!("invalid item {id}"))?;
+        resources.add(&mut index.items, id, Some(&item.name), &item)?;
+    }
+    for (id, mov) in sorted(&data.moves) {
+        let mut mov = mov.clone();
+        precompile_effect(&mut mov.effect).context(format!("invalid move {id}"))?;
+        precompile_effect(&mut mov.condition).context(format!("invalid move {id}"))?;
+        for secondary_effect in &mut mov.secondary_effects {
+            precompile_effect(&mut secondary_effect.effect)
+                .context(format!("invalid move {id}"))?;
+        }
+        resources.add(&mut index.moves, id, Some(&mov.name), &mov)?;
+    }
+    for (id, species) in sorted(&data.species) {
+        let mut species = species.clone();
+        precompile_effect(&mut species.effect).context(format!("invalid species {id}"))?;
+        resources.add(&mut index.species, id, Some(&species.name), &species)?;
+    }
+    for (id, subroutine) in sorted(&data.subroutines) {
+        let mut subroutine = subroutine.clone();
+        ParsedSubroutine::precompile(&mut subroutine)
+            .context(format!("invalid subroutine {id}"))?;
+        resources.add(&mut index.subroutines, id, None, &subroutine)?;
+    }
+
+    let index = serde_json::to_vec(&index).context("failed to encode bundle index")?;
+    let mut bundle = Vec::new();
+    write_header(&mut bundle, index.len())?;
+    bundle.extend(index);
+    bundle.extend(resources.bytes);
+    Ok(bundle)
+}
@@ -0,0 +1,99 @@
+use alloc::{
+    collections::BTreeMap,
+    format,
+    string::String,
+};
+
+use anyhow::{
+    Error,
+    Result,
+};
+use battler::effect::fxlang::CompiledProgram;
+use battler_data::{
+    Id,
+    TypeChart,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+/// Bytes at the start of every bundle.
+pub(crate) const MAGIC: &[u8; 8] = b"BTLRDATA";
+
+/// The version of the bundle format.
+///
+/// Must be incremented whenever the layout of bundles changes, so that bundles built by an older
+/// version are not loaded.
+pub const FORMAT_VERSION: u32 = 2;
+
+/// The length of the bundle header.
+///
+/// The header consists of:
+/// 1. Magic bytes.
+/// 1. The bundle format version.
+/// 1. The compiled program format version.
+/// 1. The length of the index.
+pub(crate) const HEADER_LEN: usize = MAGIC.len() + 3 * size_of::<u32>();
+
+/// The location of a single resource in the resource section of a bundle.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub(crate) struct Span {
+    pub offset: u32,
+    pub len: u32,
+}
+
+/// A table of resources of a single type.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub(crate) struct Table {
+    /// Resources by ID.
+    pub entries: BTreeMap<Id, Span>,
+    /// Resource IDs by name.
+    pub names: BTreeMap<String, Id>,
+}
+
+/// The index of a bundle, which is read eagerly when a bundle is loaded.
+///
+/// Resources themselves are decoded lazily, when they are looked up.
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct Index {
+    pub type_chart: TypeChart,
+    pub aliases: BTreeMap<Id, Id>,
+    pub abilities: Table,
+    pub clauses: Table,
+    pub conditions: Table,
+    pub items: Table,
+    pub moves: Table,
+    pub species: Table,
+    pub subroutines: Table,
+}
+
+/// Reads the header of a bundle, returning the length of the index.
+pub(crate) fn read_header(bundle: &[u8]) -> Result<usize> {
+    if bundle.len() < HEADER_LEN || !bundle.starts_with(MAGIC) {
+        return Err(Error::msg("not a battle data bundle"));
+    }
+    let read_u32 = |i: usize| {
+        let offset = MAGIC.len() + i * size_of::<u32>();
+        u32::from_le_bytes([
+            bundle[offset],
+            bundle[offset + 1],
+            bundle[offset + 2],
+            bundle[offset + 3],
+        ])
+    };
+    let format_version = read_u32(0);
+    if format_version != FORMAT_VERSION {
+        return Err(Error::msg(format!(
+            "unsupported bundle format version {format_version} (expected {FORMAT_VERSION})"
+        )));
+    }
+    let program_format_version = read_u32(1);
+    if program_format_version != CompiledProgram::FORMAT_VERSION {
+        return Err(Error::msg(format!(
+            "unsupported compiled program format version {program_format_version} (expected {})",
+            CompiledProgram::FORMAT_VERSION
+        )));
+    }
+    Ok(read_u32(2) as usize)
+}
@@ -0,0 +1,266 @@
+//! Precompiled binary bundles of battle data.
+//!
+//! A bundle contains all data from a data directory in a single file, with every fxlang program
+//! compiled ahead of time. Resources are only decoded when they are looked up, so loading a bundle
+//! is much faster than reading a data directory with `battler_local_data::LocalDataStore`.
+//!
+//! Bundles are versioned. A bundle can only be loaded by the same bundle format version and
+//! compiled program format version that built it, so bundles should be rebuilt whenever `battler`
+//! is upgraded.
+//!
+//! Loading bundles only requires `alloc`, so bundles can be embedded in `no_std` builds. Building
+//! bundles and reading them from disk require the `std` feature. The command-line tool for building
+//! bundles requires the `cli` feature.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "std")]
+mod builder;
+mod format;
+mod store;
+
+#[cfg(feature = "std")]
+pub use builder::build_bundle;
+pub use format::FORMAT_VERSION;
+pub use store::BundleDataStore;
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::BTreeSet,
+        env,
+        fs,
+    };
+
+    use battler::{
+        BattleType,
+        DataStore,
+        DataStoreByName,
+        Effect,
+        Id,
+        PublicCoreBattle,
+        TeamData,
+        effect::fxlang::ParsedSubroutine,
+    };
+    use battler_local_data::LocalDataStore;
+    use battler_test_utils::TestBattleBuilder;
+    use serde::Serialize;
+
+    use crate::{
+        BundleDataStore,
+        build_bundle,
+    };
+
+    fn bundle() -> Vec<u8> {
+        build_bundle(&LocalDataStore::new_from_env("DATA_DIR").unwrap()).unwrap()
+    }
+
+    /// Serializes a value to JSON with all arrays sorted, since sets are not serialized in a stable
+    /// order.
+    fn sorted_json<T>(value: T) -> serde_json::Value
+    where
+        T: Serialize,
+    {
+        fn sort(value: &mut serde_json::Value) {
+            match value {
+                serde_json::Value::Array(values) => {
+                    values.iter_mut().for_each(sort);
+                    values.sort_by_key(|value| value.to_string());
+                }
+                serde_json::Value::Object(values) => values.values_mut().for_each(sort),
+                _ => (),
+            }
+        }
+        let mut value = serde_json::to_value(value).unwrap();
+        sort(&mut value);
+        value
+    }
+
+    fn team() -> TeamData {
+        serde_json::from_str(
+            r#"{
+                "members": [
+                    {
+                        "name": "Bulbasaur",
+                        "species": "Bulbasaur",
+                        "ability": "Overgrow",
+                        "moves": [
+                            "Protect",
+                            "Tackle"
+                        ],
+                        "nature": "Hardy",
+                        "level": 50
+                    }
+                ]
+            }"#,
+        )
+        .unwrap()
+    }
+
+    fn make_battle(data: &dyn DataStore) -> PublicCoreBattle<'_> {
+        TestBattleBuilder::new()
+            .with_battle_type(BattleType::Singles)
+            .with_seed(0)
+            .with_team_validation(false)
+            .add_player_to_side_1("player-1", "Player 1")
+            .add_player_to_side_2("player-2", "Player 2")
+            .with_team("player-1", team())
+            .with_team("player-2", team())
+            .build(data)
+            .unwrap()
+    }
+
+    #[test]
+    fn reads_all_data() {
+        let local = LocalDataStore::new_from_env("DATA_DIR").unwrap();
+        let bundle = bundle();
+        let data = BundleDataStore::new(bundle.as_slice()).unwrap();
+
+        pretty_assertions::assert_eq!(data.get_type_chart().unwrap(), local.type_chart);
+        for (alias, id) in &local.aliases {
+            pretty_assertions::assert_eq!(data.translate_alias(alias).unwrap(), Some(id.clone()));
+        }
+        for (id, species) in &local.species {
+            // Effects are compared separately, since programs are compiled.
+            let mut bundled = data.get_species(id).unwrap().unwrap();
+            let mut species = species.clone();
+            assert_eq!(bundled.effect.is_null(), species.effect.is_null(), "{id}");
+            bundled.effect = serde_json::Value::Null;
+            species.effect = serde_json::Value::Null;
+            pretty_assertions::assert_eq!(sorted_json(bundled), sorted_json(species));
+        }
+        for id in local.abilities.keys() {
+            assert!(data.get_ability(id).unwrap().is_some(), "{id}");
+        }
+        for id in local.clauses.keys() {
+            assert!(data.get_clause(id).unwrap().is_some(), "{id}");
+        }
+        for id in local.conditions.keys() {
+            assert!(data.get_condition(id).unwrap().is_some(), "{id}");
+        }
+        for id in local.items.keys() {
+            assert!(data.get_item(id).unwrap().is_some(), "{id}");
+        }
+        for id in local.subroutines.keys() {
+            assert!(data.get_subroutine(id).unwrap().is_some(), "{id}");
+        }
+
+        let mut move_ids = data.all_move_ids(&|_| true).unwrap();
+        move_ids.sort();
+        let mut local_move_ids = local.moves.keys().cloned().collect::<Vec<_>>();
+        local_move_ids.sort();
+        pretty_assertions::assert_eq!(move_ids, local_move_ids);
+
+        assert_matches::assert_matches!(data.get_move_by_name("Tackle"), Ok(Some(move_data)) => {
+            assert_eq!(move_data.base_power, 40);
+        });
+        assert_matches::assert_matches!(data.get_item(&Id::from("nonexistent")), Ok(None));
+    }
+
+    #[test]
+    fn precompiles_programs() {
+        let local = LocalDataStore::new_from_env("DATA_DIR").unwrap();
+        let bundle = bundle();
+        let data = BundleDataStore::new(bundle.as_slice()).unwrap();
+
+        let mut effects = Vec::new();
+        for (id, condition) in &local.conditions {
+            let bundled = data.get_condition(id).unwrap().unwrap();
+            effects.push((id, condition.condition.clone(), bundled.condition));
+        }
+        for (id, mov) in &local.moves {
+            let bundled = data.get_move(id).unwrap().unwrap();
+            effects.push((id, mov.effect.clone(), bundled.effect));
+            effects.push((id, mov.condition.clone(), bundled.condition));
+        }
+        for (id, effect, bundled) in effects {
+            if effect.is_null() {
+                assert!(bundled.is_null(), "{id}");
+                continue;
+            }
+            let effect = Effect::try_from(effect).unwrap();
+            let bundled = Effect::try_from(bundled).unwrap();
+            pretty_assertions::assert_eq!(
+                bundled.callbacks.keys().collect::<BTreeSet<_>>(),
+                effect.callbacks.keys().collect::<BTreeSet<_>>(),
+                "{id}"
+            );
+            for (name, callback) in &effect.callbacks {
+                let bundled = &bundled.callbacks[name];
+                assert!(bundled.program().is_none(), "{id} {name}");
+                assert_eq!(bundled.has_program(), callback.has_program(), "{id} {name}");
+                assert_eq!(bundled.metadata(), callback.metadata(), "{id} {name}");
+                pretty_assertions::assert_eq!(
+                    bundled.compile().unwrap(),
+                    callback.compile().unwrap(),
+                    "{id} {name}"
+                );
+            }
+        }
+
+        let subroutine = data
+            .get_subroutine(&Id::from("effective_item_special_data"))
+            .unwrap();
+        assert_matches::assert_matches!(subroutine, Some(subroutine) => {
+            assert!(subroutine.program.get("compiled").is_some());
+            assert_matches::assert_matches!(ParsedSubroutine::new(&subroutine), Ok(_));
+        });
+    }
+
+    #[test]
+    fn rejects_invalid_bundles() {
+        let error = |bundle: &[u8]| {
+            BundleDataStore::new(bundle)
+                .err()
+                .map(|err| err.to_string())
+        };
+        assert_eq!(
+            error(b"not a bundle"),
+            Some("not a battle data bundle".to_owned())
+        );
+
+        let mut bundle = bundle();
+        bundle[8] += 1;
+        assert_matches::assert_matches!(error(&bundle), Some(err) => {
+            assert!(err.starts_with("unsupported bundle format version"), "{err}");
+        });
+        bundle[8] -= 1;
+        bundle[12] += 1;
+        assert_matches::assert_matches!(error(&bundle), Some(err) => {
+            assert!(err.starts_with("unsupported compiled program format version"), "{err}");
+        });
+        bundle[12] -= 1;
+        bundle.truncate(100);
+        assert_eq!(error(&bundle), Some("bundle index is truncated".to_owned()));
+    }
+
+    #[test]
+    fn opens_bundle_from_disk() {
+        let path = env::temp_dir().join(format!("battler-bundle-{}", std::process::id()));
+        fs::write(&path, bundle()).unwrap();
+        let data = BundleDataStore::open(&path);
+        fs::remove_file(&path).unwrap();
+        assert_matches::assert_matches!(
+            data.unwrap().get_species_by_name("Bulbasaur"),
+            Ok(Some(species)) => assert_eq!(species.name, "Bulbasaur")
+        );
+    }
+
+    #[test]
+    fn battles_use_bundled_data() {
+        let bundle = bundle();
+        let data = BundleDataStore::new(bundle.as_slice()).unwrap();
+        let mut battle = make_battle(&data);
+        assert_matches::assert_matches!(battle.start(), Ok(()));
+        assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 0"), Ok(()));
+        assert_matches::assert_matches!(battle.set_player_choice("player-2", "move 1"), Ok(()));
+        assert!(
+            battle
+                .full_log()
+                .any(|log| log == "activate|mon:Bulbasaur,player-1,1|move:Protect"),
+            "{:?}",
+            battle.full_log().collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,40 @@
+use std::fs;
+
+use anyhow::{
+    Context,
+    Result,
+};
+use battler_data_bundle::build_bundle;
+use battler_local_data::LocalDataStore;
+use clap::Parser;
+
+#[derive(Parser, Debug)]
+#[command(
+    name = "battler-data-bundle",
+    about = "Builds a precompiled binary bundle from battle data"
+)]
+struct Args {
+    /// Path to Pokemon data directory
+    #[arg(short, long, default_value = "battle-data/data")]
+    data_dir: String,
+
+    /// Path to write the bundle to
+    #[arg(short, long, default_value = "battle-data.bundle")]
+    output: String,
+}
+
+fn main() {
+    if let Err(err) = run() {
+        eprintln!("{err:#}");
+        std::process::exit(1);
+    }
+}
+
+fn run() -> Result<()> {
+    let args = Args::parse();
+    let data = LocalDataStore::new(args.data_dir)?;
+    let bundle = build_bundle(&data)?;
+    fs::write(&args.output, &bundle).context(format!("failed to write {}", args.output))?;
+    println!("wrote {} bytes to {}", bundle.len(), args.output);
+    Ok(())
+}
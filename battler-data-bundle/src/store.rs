@@ -0,0 +1,236 @@
+use alloc::{
+    format,
+    vec::Vec,
+};
+#[cfg(feature = "std")]
+use std::{
+    fs,
+    path::Path,
+};
+
+use anyhow::{
+    Context,
+    Result,
+};
+use battler_data::{
+    AbilityData,
+    Aliases,
+    ClauseData,
+    ConditionData,
+    DataStore,
+    DataStoreByName,
+    Id,
+    ItemData,
+    MoveData,
+    SpeciesData,
+    SubroutineData,
+    TypeChart,
+};
+use serde::de::DeserializeOwned;
+
+use crate::format::{
+    HEADER_LEN,
+    Index,
+    Table,
+    read_header,
+};
+
+/// An implementation of [`DataStore`] that reads data from a bundle built by
+/// [`build_bundle`][`crate::build_bundle`].
+///
+/// Only the index of the bundle is decoded when the store is created. Resources are decoded from
+/// the bundle when they are looked up, so startup time does not depend on the size of the bundle.
+///
+/// The bundle can be any buffer of bytes:
+/// - An embedded bundle, from [`include_bytes!`], for environments without a filesystem.
+/// - A bundle read from disk with `BundleDataStore::open`, which requires the `std` feature.
+pub struct BundleDataStore<B = &'static [u8]> {
+    bundle: B,
+    index: Index,
+    /// The offset of the resource section in the bundle.
+    resources: usize,
+}
+
+impl<B> BundleDataStore<B>
+where
+    B: AsRef<[u8]>,
+{
+    /// Creates a new instance of [`BundleDataStore`] over the given bundle.
+    ///
+    /// Fails if the bundle is invalid or was built by an incompatible version.
+    pub fn new(bundle: B) -> Result<Self> {
+        let bytes = bundle.as_ref();
+        let index_len = read_header(bytes)?;
+        let resources = HEADER_LEN + index_len;
+        let index = serde_json::from_slice(
+            bytes
+                .get(HEADER_LEN..resources)
+                .context("bundle index is truncated")?,
+        )
+        .context("failed to decode bundle index")?;
+        Ok(Self {
+            bundle,
+            index,
+            resources,
+        })
+    }
+
+    fn get<T>(&self, table: &Table, id: &Id) -> Result<Option<T>>
+    where
+        T: DeserializeOwned,
+    {
+        let span = match table.entries.get(id) {
+            Some(span) => span,
+            None => return Ok(None),
+        };
+        let start = self.resources + span.offset as usize;
+        let bytes = self
+            .bundle
+            .as_ref()
+            .get(start..start + span.len as usize)
+            .context(format!("{id} is truncated"))?;
+        serde_json::from_slice(bytes)
+            .map(Some)
+            .context(format!("failed to decode {id}"))
+    }
+
+    fn all_ids<T>(&self, table: &Table, filter: &dyn Fn(&T) -> bool) -> Result<Vec<Id>>
+    where
+        T: DeserializeOwned,
+    {
+        let mut ids = Vec::new();
+        for id in table.entries.keys() {
+            if let Some(resource) = self.get(table, id)?
+                && filter(&resource)
+            {
+                ids.push(id.clone());
+            }
+        }
+        Ok(ids)
+    }
+
+    fn get_by_name<T>(&self, table: &Table, name: &str) -> Result<Option<T>>
+    where
+        T: DeserializeOwned,
+    {
+        match table.names.get(name) {
+            Some(id) => self.get(table, id),
+            None => Ok(None),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl BundleDataStore<Vec<u8>> {
+    /// Reads a bundle from disk.
+    pub fn open<P>(path: P) -> Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        Self::new(fs::read(path).context(format!("{} could not be opened", path.display()))?)
+    }
+}
+
+impl<B> DataStore for BundleDataStore<B>
+where
+    B: AsRef<[u8]> + Send + Sync,
+{
+    fn all_ability_ids(&self, filter: &dyn Fn(&AbilityData) -> bool) -> Result<Vec<Id>> {
+        self.all_ids(&self.index.abilities, filter)
+    }
+
+    fn all_clause_ids(&self, filter: &dyn Fn(&ClauseData) -> bool) -> Result<Vec<Id>> {
+        self.all_ids(&self.index.clauses, filter)
+    }
+
+    fn all_condition_ids(&self, filter: &dyn Fn(&ConditionData) -> bool) -> Result<Vec<Id>> {
+        self.all_ids(&self.index.conditions, filter)
+    }
+
+    fn all_item_ids(&self, filter: &dyn Fn(&ItemData) -> bool) -> Result<Vec<Id>> {
+        self.all_ids(&self.index.items, filter)
+    }
+
+    fn all_move_ids(&self, filter: &dyn Fn(&MoveData) -> bool) -> Result<Vec<Id>> {
+        self.all_ids(&self.index.moves, filter)
+    }
+
+    fn all_species_ids(&self, filter: &dyn Fn(&SpeciesData) -> bool) -> Result<Vec<Id>> {
+        self.all_ids(&self.index.species, filter)
+    }
+
+    fn get_type_chart(&self) -> Result<TypeChart> {
+        Ok(self.index.type_chart.clone())
+    }
+
+    fn all_aliases(&self) -> Result<Aliases> {
+        Ok(self
+            .index
+            .aliases
+            .iter()
+            .map(|(a, b)| (a.clone(), b.clone()))
+            .collect())
+    }
+
+    fn translate_alias(&self, id: &Id) -> Result<Option<Id>> {
+        Ok(self.index.aliases.get(id).cloned())
+    }
+
+    fn get_ability(&self, id: &Id) -> Result<Option<AbilityData>> {
+        self.get(&self.index.abilities, id)
+    }
+
+    fn get_clause(&self, id: &Id) -> Result<Option<ClauseData>> {
+        self.get(&self.index.clauses, id)
+    }
+
+    fn get_condition(&self, id: &Id) -> Result<Option<ConditionData>> {
+        self.get(&self.index.conditions, id)
+    }
+
+    fn get_item(&self, id: &Id) -> Result<Option<ItemData>> {
+        self.get(&self.index.items, id)
+    }
+
+    fn get_move(&self, id: &Id) -> Result<Option<MoveData>> {
+        self.get(&self.index.moves, id)
+    }
+
+    fn get_species(&self, id: &Id) -> Result<Option<SpeciesData>> {
+        self.get(&self.index.species, id)
+    }
+
+    fn get_subroutine(&self, id: &Id) -> Result<Option<SubroutineData>> {
+        self.get(&self.index.subroutines, id)
+    }
+}
+
+impl<B> DataStoreByName for BundleDataStore<B>
+where
+    B: AsRef<[u8]> + Send + Sync,
+{
+    fn get_ability_by_name(&self, name: &str) -> Result<Option<AbilityData>> {
+        self.get_by_name(&self.index.abilities, name)
+    }
+
+    fn get_clause_by_name(&self, name: &str) -> Result<Option<ClauseData>> {
+        self.get_by_name(&self.index.clauses, name)
+    }
+
+    fn get_condition_by_name(&self, name: &str) -> Result<Option<ConditionData>> {
+        self.get_by_name(&self.index.conditions, name)
+    }
+
+    fn get_item_by_name(&self, name: &str) -> Result<Option<ItemData>> {
+        self.get_by_name(&self.index.items, name)
+    }
+
+    fn get_move_by_name(&self, name: &str) -> Result<Option<MoveData>> {
+        self.get_by_name(&self.index.moves, name)
+    }
+
+    fn get_species_by_name(&self, name: &str) -> Result<Option<SpeciesData>> {
+        self.get_by_name(&self.index.species, name)
+    }
+}
@@ -20,6 +20,12 @@ pub enum ClauseValueType {
     PositiveInteger,
     #[string = "NonNegativeInteger"]
     NonNegativeInteger,
+    #[string = "TypeChartOverride"]
+    TypeChartOverride,
+    #[string = "SpeciesPredicate"]
+    SpeciesPredicate,
+    #[string = "MovePredicate"]
+    MovePredicate,
 }
 
 /// Data for an individual clause.
@@ -1,10 +1,19 @@
 mod clause_data;
+mod predicate;
 mod rules;
 
 pub use clause_data::{
     ClauseData,
     ClauseValueType,
 };
+pub use predicate::{
+    MovePredicate,
+    MovePredicateKey,
+    Predicate,
+    PredicateCondition,
+    SpeciesPredicate,
+    SpeciesPredicateKey,
+};
 pub use rules::{
     Rule,
     SerializedRuleSet,
@@ -0,0 +1,232 @@
+use alloc::{
+    format,
+    string::ToString,
+    vec::Vec,
+};
+use core::str::FromStr;
+
+use anyhow::{
+    Error,
+    Result,
+};
+use serde_string_enum::{
+    DeserializeLabeledStringEnum,
+    SerializeLabeledStringEnum,
+};
+
+use crate::{
+    Id,
+    MoveData,
+    SpeciesData,
+};
+
+/// The property of a species checked by a [`SpeciesPredicate`] condition.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, SerializeLabeledStringEnum, DeserializeLabeledStringEnum,
+)]
+pub enum SpeciesPredicateKey {
+    /// The species name or its base species name.
+    #[string = "Species"]
+    Species,
+    /// One of the species' types.
+    #[string = "Type"]
+    Type,
+    /// The ability of the Mon.
+    #[string = "Ability"]
+    Ability,
+    /// One of the species' flags.
+    #[string = "Flag"]
+    Flag,
+}
+
+/// The property of a move checked by a [`MovePredicate`] condition.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, SerializeLabeledStringEnum, DeserializeLabeledStringEnum,
+)]
+pub enum MovePredicateKey {
+    /// The move name.
+    #[string = "Move"]
+    Move,
+    /// The type of the move.
+    #[string = "Type"]
+    Type,
+    /// The category of the move.
+    #[string = "Category"]
+    Category,
+    /// One of the move's flags.
+    #[string = "Flag"]
+    Flag,
+}
+
+/// A single condition of a [`Predicate`], which checks that some property is equal to a value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PredicateCondition<K> {
+    pub key: K,
+    pub value: Id,
+}
+
+/// A predicate over some resource, made up of conditions where at least one must match.
+///
+/// Serialized as `key=value|key=value`. For example, `Type=Flying|Ability=Levitate` matches Mons
+/// that are Flying type or have the Levitate ability.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Predicate<K> {
+    pub conditions: Vec<PredicateCondition<K>>,
+}
+
+impl<K> Predicate<K> {
+    fn matches_any<F>(&self, f: F) -> bool
+    where
+        F: Fn(&K, &Id) -> bool,
+    {
+        self.conditions
+            .iter()
+            .any(|condition| f(&condition.key, &condition.value))
+    }
+}
+
+impl<K> FromStr for Predicate<K>
+where
+    K: FromStr,
+{
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let conditions = s
+            .split('|')
+            .map(|condition| {
+                let (key, value) = condition.split_once('=').ok_or_else(|| {
+                    Error::msg(format!("invalid predicate condition \"{condition}\""))
+                })?;
+                let key = key.trim();
+                let key = K::from_str(key)
+                    .map_err(|_| Error::msg(format!("invalid predicate key \"{key}\"")))?;
+                let value = Id::from(value.trim());
+                if value.as_ref().is_empty() {
+                    return Err(Error::msg(format!(
+                        "invalid predicate condition \"{condition}\""
+                    )));
+                }
+                Ok(PredicateCondition { key, value })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self { conditions })
+    }
+}
+
+/// A predicate over Mons, based on their species and ability.
+pub type SpeciesPredicate = Predicate<SpeciesPredicateKey>;
+
+impl SpeciesPredicate {
+    /// Checks if a Mon of the given species and ability matches the predicate.
+    pub fn matches(&self, species: &SpeciesData, ability: &Id) -> bool {
+        self.matches_any(|key, value| match key {
+            SpeciesPredicateKey::Species => {
+                Id::from(species.name.as_str()).eq(value)
+                    || Id::from(species.base_species.as_str()).eq(value)
+            }
+            SpeciesPredicateKey::Type => species
+                .types_iter()
+                .any(|typ| Id::from(typ.to_string()) == *value),
+            SpeciesPredicateKey::Ability => ability == value,
+            SpeciesPredicateKey::Flag => species
+                .flags
+                .iter()
+                .any(|flag| Id::from(flag.to_string()) == *value),
+        })
+    }
+}
+
+/// A predicate over moves.
+pub type MovePredicate = Predicate<MovePredicateKey>;
+
+impl MovePredicate {
+    /// Checks if the given move matches the predicate.
+    pub fn matches(&self, mov: &MoveData) -> bool {
+        self.matches_any(|key, value| match key {
+            MovePredicateKey::Move => Id::from(mov.name.as_str()).eq(value),
+            MovePredicateKey::Type => Id::from(mov.primary_type.to_string()) == *value,
+            MovePredicateKey::Category => Id::from(mov.category.to_string()) == *value,
+            MovePredicateKey::Flag => mov
+                .flags
+                .iter()
+                .any(|flag| Id::from(flag.to_string()) == *value),
+        })
+    }
+}
+
+#[cfg(test)]
+mod predicate_test {
+    use alloc::{
+        borrow::ToOwned,
+        format,
+    };
+    use core::str::FromStr;
+
+    use crate::{
+        Id,
+        MoveCategory,
+        MoveData,
+        MoveFlag,
+        MovePredicate,
+        SpeciesPredicate,
+        Type,
+    };
+
+    #[test]
+    fn parses_conditions() {
+        let predicate = SpeciesPredicate::from_str("Type=Flying | ability = Levitate").unwrap();
+        assert_eq!(predicate.conditions.len(), 2);
+        assert_eq!(predicate.conditions[1].value, Id::from_known("levitate"));
+    }
+
+    #[test]
+    fn fails_to_parse_invalid_conditions() {
+        assert_matches::assert_matches!(SpeciesPredicate::from_str("Type"), Err(err) => {
+            assert_eq!(format!("{err}"), "invalid predicate condition \"Type\"");
+        });
+        assert_matches::assert_matches!(SpeciesPredicate::from_str("Type="), Err(err) => {
+            assert_eq!(format!("{err}"), "invalid predicate condition \"Type=\"");
+        });
+        assert_matches::assert_matches!(SpeciesPredicate::from_str("Category=Status"), Err(err) => {
+            assert_eq!(format!("{err}"), "invalid predicate key \"Category\"");
+        });
+    }
+
+    #[test]
+    fn matches_moves() {
+        let mov = MoveData {
+            name: "Earthquake".to_owned(),
+            category: MoveCategory::Physical,
+            primary_type: Type::Ground,
+            flags: [MoveFlag::Protect].into_iter().collect(),
+            ..Default::default()
+        };
+
+        assert!(
+            MovePredicate::from_str("Move=Dig|Move=Earthquake")
+                .unwrap()
+                .matches(&mov)
+        );
+        assert!(!MovePredicate::from_str("Move=Dig").unwrap().matches(&mov));
+        assert!(
+            MovePredicate::from_str("Type=Ground")
+                .unwrap()
+                .matches(&mov)
+        );
+        assert!(
+            MovePredicate::from_str("Category=Physical")
+                .unwrap()
+                .matches(&mov)
+        );
+        assert!(
+            !MovePredicate::from_str("Category=Status")
+                .unwrap()
+                .matches(&mov)
+        );
+        assert!(
+            MovePredicate::from_str("Flag=Protect")
+                .unwrap()
+                .matches(&mov)
+        );
+    }
+}
@@ -491,14 +491,14 @@ where
 
 impl<I> Serialize for Fraction<I>
 where
-    I: FractionInteger + Into<i64> + Display,
+    I: FractionInteger + Serialize + Display,
 {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
         if self.is_whole() {
-            serializer.serialize_i64(self.floor().into())
+            self.floor().serialize(serializer)
         } else {
             serializer.serialize_str(&format!("{self}"))
         }
@@ -527,11 +527,13 @@ where
         + TryFrom<u16>
         + TryFrom<u32>
         + TryFrom<u64>
+        + TryFrom<i64>
         + Deserialize<'de>,
     <I as TryFrom<u8>>::Error: alloc::fmt::Display,
     <I as TryFrom<u16>>::Error: alloc::fmt::Display,
     <I as TryFrom<u32>>::Error: alloc::fmt::Display,
     <I as TryFrom<u64>>::Error: alloc::fmt::Display,
+    <I as TryFrom<i64>>::Error: alloc::fmt::Display,
     Fraction<I>: FromStr,
 {
     type Value = Fraction<I>;
@@ -579,6 +581,15 @@ where
         ))
     }
 
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(Self::Value::from(
+            TryInto::<I>::try_into(v).map_err(serde::de::Error::custom)?,
+        ))
+    }
+
     fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
     where
         E: serde::de::Error,
@@ -619,11 +630,13 @@ where
         + TryFrom<u16>
         + TryFrom<u32>
         + TryFrom<u64>
+        + TryFrom<i64>
         + Deserialize<'de>,
     <I as TryFrom<u8>>::Error: alloc::fmt::Display,
     <I as TryFrom<u16>>::Error: alloc::fmt::Display,
     <I as TryFrom<u32>>::Error: alloc::fmt::Display,
     <I as TryFrom<u64>>::Error: alloc::fmt::Display,
+    <I as TryFrom<i64>>::Error: alloc::fmt::Display,
     Fraction<I>: FromStr,
 {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
@@ -662,6 +675,12 @@ mod fraction_test {
         test_deserialization("100", Fraction::new(100, 1));
     }
 
+    #[test]
+    fn deserializes_negative_integers() {
+        test_deserialization("-1", Fraction::new(-1, 1));
+        test_deserialization("-25", Fraction::new(-25, 1));
+    }
+
     #[test]
     fn deserializes_floats() {
         test_deserialization("2.5", Fraction::new(5, 2));
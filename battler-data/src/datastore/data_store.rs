@@ -1,15 +1,20 @@
 use alloc::vec::Vec;
 
-use anyhow::Result;
+use anyhow::{
+    Error,
+    Result,
+};
 
 use crate::{
     AbilityData,
+    Aliases,
     ClauseData,
     ConditionData,
     Id,
     ItemData,
     MoveData,
     SpeciesData,
+    SubroutineData,
     TypeChart,
 };
 
@@ -20,12 +25,50 @@ use crate::{
 /// This collection is used for "raw lookup" of resources by ID. Individual dexes may implement
 /// specialized lookup rules over this table, such as resolving aliases or special names.
 pub trait DataStore: Send + Sync {
+    /// Gets all ability IDs, applying the given filter on the underlying data.
+    ///
+    /// By default, enumeration is not supported and this method fails.
+    fn all_ability_ids(&self, _filter: &dyn Fn(&AbilityData) -> bool) -> Result<Vec<Id>> {
+        Err(Error::msg("ability enumeration not supported"))
+    }
+    /// Gets all clause IDs, applying the given filter on the underlying data.
+    ///
+    /// By default, enumeration is not supported and this method fails.
+    fn all_clause_ids(&self, _filter: &dyn Fn(&ClauseData) -> bool) -> Result<Vec<Id>> {
+        Err(Error::msg("clause enumeration not supported"))
+    }
+    /// Gets all condition IDs, applying the given filter on the underlying data.
+    ///
+    /// By default, enumeration is not supported and this method fails.
+    fn all_condition_ids(&self, _filter: &dyn Fn(&ConditionData) -> bool) -> Result<Vec<Id>> {
+        Err(Error::msg("condition enumeration not supported"))
+    }
+    /// Gets all item IDs, applying the given filter on the underlying data.
+    ///
+    /// By default, enumeration is not supported and this method fails.
+    fn all_item_ids(&self, _filter: &dyn Fn(&ItemData) -> bool) -> Result<Vec<Id>> {
+        Err(Error::msg("item enumeration not supported"))
+    }
     /// Gets all move IDs, applying the given filter on the underlying data.
     fn all_move_ids(&self, filter: &dyn Fn(&MoveData) -> bool) -> Result<Vec<Id>>;
+    /// Gets all species IDs, applying the given filter on the underlying data.
+    ///
+    /// By default, enumeration is not supported and this method fails.
+    fn all_species_ids(&self, _filter: &dyn Fn(&SpeciesData) -> bool) -> Result<Vec<Id>> {
+        Err(Error::msg("species enumeration not supported"))
+    }
 
     /// Gets the type chart.
     fn get_type_chart(&self) -> Result<TypeChart>;
 
+    /// Gets all aliases.
+    ///
+    /// By default, enumeration is not supported and this method fails, even if
+    /// [`DataStore::translate_alias`] translates some IDs.
+    fn all_aliases(&self) -> Result<Aliases> {
+        Err(Error::msg("alias enumeration not supported"))
+    }
+
     /// Translates the given alias to another ID, if the alias mapping exists.
     fn translate_alias(&self, id: &Id) -> Result<Option<Id>>;
 
@@ -41,6 +84,12 @@ pub trait DataStore: Send + Sync {
     fn get_move(&self, id: &Id) -> Result<Option<MoveData>>;
     /// Gets a species by ID.
     fn get_species(&self, id: &Id) -> Result<Option<SpeciesData>>;
+    /// Gets a subroutine by ID.
+    ///
+    /// By default, no subroutines are defined.
+    fn get_subroutine(&self, _id: &Id) -> Result<Option<SubroutineData>> {
+        Ok(None)
+    }
 }
 
 /// An extension of [`DataStore`] for looking up resources by name.
@@ -58,3 +107,83 @@ pub trait DataStoreByName: DataStore {
     /// Gets a species by name.
     fn get_species_by_name(&self, name: &str) -> Result<Option<SpeciesData>>;
 }
+
+#[cfg(test)]
+mod data_store_test {
+    use alloc::vec::Vec;
+
+    use anyhow::Result;
+
+    use crate::{
+        AbilityData,
+        ClauseData,
+        ConditionData,
+        DataStore,
+        Id,
+        ItemData,
+        MoveData,
+        SpeciesData,
+        TypeChart,
+    };
+
+    /// A data store that only implements the required methods.
+    struct EmptyDataStore;
+
+    impl DataStore for EmptyDataStore {
+        fn all_move_ids(&self, _: &dyn Fn(&MoveData) -> bool) -> Result<Vec<Id>> {
+            Ok(Vec::new())
+        }
+
+        fn get_type_chart(&self) -> Result<TypeChart> {
+            Ok(TypeChart::new())
+        }
+
+        fn translate_alias(&self, _: &Id) -> Result<Option<Id>> {
+            Ok(None)
+        }
+
+        fn get_ability(&self, _: &Id) -> Result<Option<AbilityData>> {
+            Ok(None)
+        }
+
+        fn get_clause(&self, _: &Id) -> Result<Option<ClauseData>> {
+            Ok(None)
+        }
+
+        fn get_condition(&self, _: &Id) -> Result<Option<ConditionData>> {
+            Ok(None)
+        }
+
+        fn get_item(&self, _: &Id) -> Result<Option<ItemData>> {
+            Ok(None)
+        }
+
+        fn get_move(&self, _: &Id) -> Result<Option<MoveData>> {
+            Ok(None)
+        }
+
+        fn get_species(&self, _: &Id) -> Result<Option<SpeciesData>> {
+            Ok(None)
+        }
+    }
+
+    #[test]
+    fn does_not_support_enumeration_by_default() {
+        let data = EmptyDataStore;
+        assert_matches::assert_matches!(data.all_ability_ids(&|_| true), Err(err) => {
+            assert_eq!(format!("{err}"), "ability enumeration not supported");
+        });
+        assert!(data.all_clause_ids(&|_| true).is_err());
+        assert!(data.all_condition_ids(&|_| true).is_err());
+        assert!(data.all_item_ids(&|_| true).is_err());
+        assert!(data.all_species_ids(&|_| true).is_err());
+        assert_matches::assert_matches!(data.all_aliases(), Err(err) => {
+            assert_eq!(format!("{err}"), "alias enumeration not supported");
+        });
+        assert!(
+            data.get_subroutine(&Id::from("subroutine"))
+                .unwrap()
+                .is_none()
+        );
+    }
+}
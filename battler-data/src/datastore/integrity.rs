@@ -0,0 +1,519 @@
+use alloc::{
+    borrow::ToOwned,
+    format,
+    string::String,
+    vec::Vec,
+};
+use core::{
+    fmt,
+    fmt::Display,
+};
+
+use anyhow::Result;
+use hashbrown::{
+    HashMap,
+    HashSet,
+};
+
+use crate::{
+    Aliases,
+    DataStore,
+    HitEffect,
+    Id,
+    ResourceType,
+    Rule,
+    ZCrystalSource,
+};
+
+/// A single problem found by [`check_integrity`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IntegrityIssue {
+    /// A resource refers to another resource that does not exist.
+    DanglingReference {
+        /// The type of the resource containing the reference.
+        resource_type: ResourceType,
+        /// The ID of the resource containing the reference.
+        id: Id,
+        /// The field containing the reference.
+        field: String,
+        /// The type of resource being referred to.
+        ///
+        /// References to conditions may refer to any effect, such as a move or an item.
+        target_type: ResourceType,
+        /// The reference, as written in the data.
+        target: String,
+    },
+    /// An alias maps to an ID that does not exist.
+    DanglingAlias { alias: Id, target: Id },
+    /// An alias chain loops back on itself.
+    AliasCycle { alias: Id },
+    /// Multiple resources of the same type have names with the same ID.
+    DuplicateName {
+        resource_type: ResourceType,
+        name: String,
+        ids: Vec<Id>,
+    },
+}
+
+impl Display for IntegrityIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DanglingReference {
+                resource_type,
+                id,
+                field,
+                target_type,
+                target,
+            } => write!(
+                f,
+                "{resource_type} {id} has {field} referring to {target_type} {target}, which does not exist"
+            ),
+            Self::DanglingAlias { alias, target } => {
+                write!(f, "alias {alias} refers to {target}, which does not exist")
+            }
+            Self::AliasCycle { alias } => write!(f, "alias {alias} is part of a cycle"),
+            Self::DuplicateName {
+                resource_type,
+                name,
+                ids,
+            } => {
+                write!(f, "{resource_type} name {name} is shared by ")?;
+                for (i, id) in ids.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{id}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// The result of [`check_integrity`].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct IntegrityReport {
+    /// All problems found, in a deterministic order.
+    pub issues: Vec<IntegrityIssue>,
+}
+
+impl IntegrityReport {
+    /// Checks if no problems were found.
+    pub fn is_ok(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+impl Display for IntegrityReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for issue in &self.issues {
+            writeln!(f, "{issue}")?;
+        }
+        Ok(())
+    }
+}
+
+/// All resource IDs in a [`DataStore`], used to resolve references.
+struct ResourceIds {
+    aliases: Aliases,
+    ids: HashMap<ResourceType, HashSet<Id>>,
+}
+
+impl ResourceIds {
+    /// Resolves an ID through the alias chain, the same way a dex does.
+    ///
+    /// Returns [`None`] if the chain has a cycle.
+    fn resolve_alias(&self, id: &Id) -> Option<Id> {
+        let mut id = id.clone();
+        let mut seen = HashSet::new();
+        while let Some(target) = self.aliases.get(&id) {
+            if !seen.insert(id) {
+                return None;
+            }
+            id = target.clone();
+        }
+        Some(id)
+    }
+
+    fn contains(&self, resource_type: ResourceType, id: &Id) -> bool {
+        self.ids
+            .get(&resource_type)
+            .is_some_and(|ids| ids.contains(id))
+    }
+
+    fn exists(&self, target_type: ResourceType, target: &str) -> bool {
+        let Some(id) = self.resolve_alias(&Id::from(target)) else {
+            // Cycles are reported separately.
+            return true;
+        };
+        match target_type {
+            // Conditions are looked up as any effect.
+            ResourceType::Condition => [
+                ResourceType::Condition,
+                ResourceType::Ability,
+                ResourceType::Item,
+                ResourceType::Move,
+                ResourceType::Clause,
+                ResourceType::Species,
+            ]
+            .into_iter()
+            .any(|resource_type| self.contains(resource_type, &id)),
+            _ => self.contains(target_type, &id),
+        }
+    }
+}
+
+/// Checks references from a single resource.
+struct ReferenceChecker<'r> {
+    resources: &'r ResourceIds,
+    resource_type: ResourceType,
+    id: &'r Id,
+    issues: &'r mut Vec<IntegrityIssue>,
+}
+
+impl ReferenceChecker<'_> {
+    fn check(&mut self, field: &str, target_type: ResourceType, target: &str) {
+        if !self.resources.exists(target_type, target) {
+            self.issues.push(IntegrityIssue::DanglingReference {
+                resource_type: self.resource_type,
+                id: self.id.clone(),
+                field: field.to_owned(),
+                target_type,
+                target: target.to_owned(),
+            });
+        }
+    }
+
+    fn check_optional(&mut self, field: &str, target_type: ResourceType, target: &Option<String>) {
+        if let Some(target) = target {
+            self.check(field, target_type, target);
+        }
+    }
+
+    fn check_all<'s, I>(&mut self, field: &str, target_type: ResourceType, targets: I)
+    where
+        I: IntoIterator<Item = &'s String>,
+    {
+        // Sorted, so that the report is deterministic.
+        let mut targets = targets.into_iter().collect::<Vec<_>>();
+        targets.sort();
+        for target in targets {
+            self.check(field, target_type, target);
+        }
+    }
+
+    fn check_hit_effect(&mut self, field: &str, hit_effect: &Option<HitEffect>) {
+        let Some(hit_effect) = hit_effect else {
+            return;
+        };
+        for (name, target) in [
+            ("status", &hit_effect.status),
+            ("volatile_status", &hit_effect.volatile_status),
+            ("side_condition", &hit_effect.side_condition),
+            ("slot_condition", &hit_effect.slot_condition),
+            ("weather", &hit_effect.weather),
+            ("pseudo_weather", &hit_effect.pseudo_weather),
+            ("terrain", &hit_effect.terrain),
+        ] {
+            self.check_optional(&format!("{field}.{name}"), ResourceType::Condition, target);
+        }
+    }
+}
+
+fn sorted(mut ids: Vec<Id>) -> Vec<Id> {
+    ids.sort();
+    ids
+}
+
+/// Reads all resources of a single type, sorted by ID.
+fn read_all<T>(
+    all_ids: impl Fn(&dyn Fn(&T) -> bool) -> Result<Vec<Id>>,
+    get: impl Fn(&Id) -> Result<Option<T>>,
+) -> Result<Vec<(Id, T)>> {
+    let mut resources = Vec::new();
+    for id in sorted(all_ids(&|_| true)?) {
+        if let Some(resource) = get(&id)? {
+            resources.push((id, resource));
+        }
+    }
+    Ok(resources)
+}
+
+/// Finds resources of a single type whose names have the same ID.
+fn check_duplicate_names<'a, I>(
+    resource_type: ResourceType,
+    names: I,
+    issues: &mut Vec<IntegrityIssue>,
+) where
+    I: IntoIterator<Item = (&'a Id, &'a String)>,
+{
+    let mut by_name = HashMap::<Id, (String, Vec<Id>)>::new();
+    for (id, name) in names {
+        by_name
+            .entry(Id::from(name.as_str()))
+            .or_insert_with(|| (name.clone(), Vec::new()))
+            .1
+            .push(id.clone());
+    }
+    let mut duplicates = by_name
+        .into_values()
+        .filter(|(_, ids)| ids.len() > 1)
+        .collect::<Vec<_>>();
+    duplicates.sort_by(|(_, a), (_, b)| a.cmp(b));
+    issues.extend(
+        duplicates
+            .into_iter()
+            .map(|(name, ids)| IntegrityIssue::DuplicateName {
+                resource_type,
+                name,
+                ids,
+            }),
+    );
+}
+
+/// Checks the integrity of all data in a [`DataStore`].
+///
+/// Every reference from one resource to another is resolved the same way a dex would resolve it,
+/// including following aliases. The report lists every reference that does not resolve, every
+/// broken alias, and every set of resources of the same type with colliding names.
+///
+/// References that cannot be resolved statically, such as bans in clause rules (which may refer to
+/// tags) and references inside fxlang programs, are not checked.
+pub fn check_integrity(data: &dyn DataStore) -> Result<IntegrityReport> {
+    let abilities = read_all(|f| data.all_ability_ids(f), |id| data.get_ability(id))?;
+    let clauses = read_all(|f| data.all_clause_ids(f), |id| data.get_clause(id))?;
+    let conditions = read_all(|f| data.all_condition_ids(f), |id| data.get_condition(id))?;
+    let items = read_all(|f| data.all_item_ids(f), |id| data.get_item(id))?;
+    let moves = read_all(|f| data.all_move_ids(f), |id| data.get_move(id))?;
+    let species = read_all(|f| data.all_species_ids(f), |id| data.get_species(id))?;
+
+    let resources = ResourceIds {
+        aliases: data.all_aliases()?,
+        ids: HashMap::from_iter([
+            (
+                ResourceType::Ability,
+                abilities.iter().map(|(id, _)| id.clone()).collect(),
+            ),
+            (
+                ResourceType::Clause,
+                clauses.iter().map(|(id, _)| id.clone()).collect(),
+            ),
+            (
+                ResourceType::Condition,
+                conditions.iter().map(|(id, _)| id.clone()).collect(),
+            ),
+            (
+                ResourceType::Item,
+                items.iter().map(|(id, _)| id.clone()).collect(),
+            ),
+            (
+                ResourceType::Move,
+                moves.iter().map(|(id, _)| id.clone()).collect(),
+            ),
+            (
+                ResourceType::Species,
+                species.iter().map(|(id, _)| id.clone()).collect(),
+            ),
+        ]),
+    };
+
+    let mut issues = Vec::new();
+
+    let mut aliases = resources.aliases.iter().collect::<Vec<_>>();
+    aliases.sort();
+    for (alias, target) in aliases {
+        match resources.resolve_alias(alias) {
+            None => issues.push(IntegrityIssue::AliasCycle {
+                alias: alias.clone(),
+            }),
+            Some(id) => {
+                if !resources.ids.values().any(|ids| ids.contains(&id)) {
+                    issues.push(IntegrityIssue::DanglingAlias {
+                        alias: alias.clone(),
+                        target: target.clone(),
+                    });
+                }
+            }
+        }
+    }
+
+    for (id, clause) in &clauses {
+        let mut checker = ReferenceChecker {
+            resources: &resources,
+            resource_type: ResourceType::Clause,
+            id,
+            issues: &mut issues,
+        };
+        let mut rules = clause.rules.iter().collect::<Vec<_>>();
+        rules.sort_by_cached_key(|rule| format!("{rule}"));
+        for rule in rules {
+            match rule {
+                Rule::Value { name, .. } | Rule::Repeal(name) => {
+                    checker.check("rules", ResourceType::Clause, name.as_ref())
+                }
+                // Bans may refer to tags, which are not resources.
+                Rule::Ban(_) | Rule::Unban(_) => (),
+            }
+        }
+    }
+
+    for (id, item) in &items {
+        let mut checker = ReferenceChecker {
+            resources: &resources,
+            resource_type: ResourceType::Item,
+            id,
+            issues: &mut issues,
+        };
+        checker.check_optional("force_forme", ResourceType::Species, &item.force_forme);
+        let special_data = &item.special_data;
+        if let Some(mega_evolution) = &special_data.mega_evolution {
+            checker.check(
+                "mega_evolution.from",
+                ResourceType::Species,
+                &mega_evolution.from,
+            );
+            checker.check(
+                "mega_evolution.into",
+                ResourceType::Species,
+                &mega_evolution.into,
+            );
+        }
+        if let Some(z_crystal) = &special_data.z_crystal {
+            if let Some(ZCrystalSource::Move(from)) = &z_crystal.source {
+                checker.check("z_crystal.from", ResourceType::Move, from);
+            }
+            checker.check("z_crystal.into", ResourceType::Move, &z_crystal.into);
+            checker.check_all("z_crystal.users", ResourceType::Species, &z_crystal.users);
+        }
+        if let Some(ultra_burst) = &special_data.ultra_burst {
+            checker.check_all("ultra_burst.from", ResourceType::Species, &ultra_burst.from);
+            checker.check("ultra_burst.into", ResourceType::Species, &ultra_burst.into);
+        }
+        if let Some(fling) = &special_data.fling {
+            checker.check_hit_effect("fling.hit_effect", &fling.hit_effect);
+        }
+    }
+
+    for (id, mov) in &moves {
+        let mut checker = ReferenceChecker {
+            resources: &resources,
+            resource_type: ResourceType::Move,
+            id,
+            issues: &mut issues,
+        };
+        checker.check_hit_effect("hit_effect", &mov.hit_effect);
+        checker.check_hit_effect("user_effect", &mov.user_effect);
+        for secondary_effect in &mov.secondary_effects {
+            checker.check_hit_effect("secondary_effects.target", &secondary_effect.target);
+            checker.check_hit_effect("secondary_effects.user", &secondary_effect.user);
+            checker.check_optional(
+                "secondary_effects.source_effect",
+                ResourceType::Condition,
+                &secondary_effect.source_effect,
+            );
+        }
+    }
+
+    for (id, species) in &species {
+        let mut checker = ReferenceChecker {
+            resources: &resources,
+            resource_type: ResourceType::Species,
+            id,
+            issues: &mut issues,
+        };
+        checker.check("base_species", ResourceType::Species, &species.base_species);
+        checker.check_all("abilities", ResourceType::Ability, &species.abilities);
+        checker.check_optional(
+            "hidden_ability",
+            ResourceType::Ability,
+            &species.hidden_ability,
+        );
+        checker.check_optional("prevo", ResourceType::Species, &species.prevo);
+        checker.check_all("evos", ResourceType::Species, &species.evos);
+        if let Some(evolution_data) = &species.evolution_data {
+            checker.check_optional(
+                "evolution_data.knows_move",
+                ResourceType::Move,
+                &evolution_data.knows_move,
+            );
+            checker.check_optional(
+                "evolution_data.holding_item",
+                ResourceType::Item,
+                &evolution_data.holding_item,
+            );
+            checker.check_optional(
+                "evolution_data.use_item",
+                ResourceType::Item,
+                &evolution_data.use_item,
+            );
+            checker.check_optional(
+                "evolution_data.trade_for",
+                ResourceType::Species,
+                &evolution_data.trade_for,
+            );
+        }
+        checker.check_all("formes", ResourceType::Species, &species.formes);
+        checker.check_all(
+            "cosmetic_formes",
+            ResourceType::Species,
+            &species.cosmetic_formes,
+        );
+        checker.check_all(
+            "required_moves",
+            ResourceType::Move,
+            &species.required_moves,
+        );
+        checker.check_all(
+            "required_items",
+            ResourceType::Item,
+            &species.required_items,
+        );
+        checker.check_optional("changes_from", ResourceType::Species, &species.changes_from);
+        checker.check_optional(
+            "gigantamax_move",
+            ResourceType::Move,
+            &species.gigantamax_move,
+        );
+        let mut events = species.events.iter().collect::<Vec<_>>();
+        events.sort_by_key(|(name, _)| *name);
+        for (_, event) in events {
+            checker.check_all("events.moves", ResourceType::Move, &event.moves);
+        }
+        checker.check_all("learnset", ResourceType::Move, species.learnset.keys());
+    }
+
+    check_duplicate_names(
+        ResourceType::Ability,
+        abilities.iter().map(|(id, ability)| (id, &ability.name)),
+        &mut issues,
+    );
+    check_duplicate_names(
+        ResourceType::Clause,
+        clauses.iter().map(|(id, clause)| (id, &clause.name)),
+        &mut issues,
+    );
+    check_duplicate_names(
+        ResourceType::Condition,
+        conditions
+            .iter()
+            .map(|(id, condition)| (id, &condition.name)),
+        &mut issues,
+    );
+    check_duplicate_names(
+        ResourceType::Item,
+        items.iter().map(|(id, item)| (id, &item.name)),
+        &mut issues,
+    );
+    check_duplicate_names(
+        ResourceType::Move,
+        moves.iter().map(|(id, mov)| (id, &mov.name)),
+        &mut issues,
+    );
+    check_duplicate_names(
+        ResourceType::Species,
+        species.iter().map(|(id, species)| (id, &species.name)),
+        &mut issues,
+    );
+
+    Ok(IntegrityReport { issues })
+}
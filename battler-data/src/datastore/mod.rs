@@ -1,5 +1,7 @@
 mod aliases;
 mod data_store;
+mod integrity;
+mod resource_type;
 
 pub use aliases::{
     Aliases,
@@ -10,3 +12,9 @@ pub use data_store::{
     DataStore,
     DataStoreByName,
 };
+pub use integrity::{
+    IntegrityIssue,
+    IntegrityReport,
+    check_integrity,
+};
+pub use resource_type::ResourceType;
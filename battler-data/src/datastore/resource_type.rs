@@ -0,0 +1,30 @@
+use core::{
+    fmt,
+    fmt::Display,
+};
+
+/// A type of resource in a [`DataStore`][`crate::DataStore`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ResourceType {
+    Ability,
+    Clause,
+    Condition,
+    Item,
+    Move,
+    Species,
+    Subroutine,
+}
+
+impl Display for ResourceType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Ability => write!(f, "ability"),
+            Self::Clause => write!(f, "clause"),
+            Self::Condition => write!(f, "condition"),
+            Self::Item => write!(f, "item"),
+            Self::Move => write!(f, "move"),
+            Self::Species => write!(f, "species"),
+            Self::Subroutine => write!(f, "subroutine"),
+        }
+    }
+}
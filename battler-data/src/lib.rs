@@ -13,6 +13,7 @@ mod datastore;
 mod items;
 mod mons;
 mod moves;
+mod subroutines;
 
 #[cfg(test)]
 pub mod test_util;
@@ -25,3 +26,4 @@ pub use datastore::*;
 pub use items::*;
 pub use mons::*;
 pub use moves::*;
+pub use subroutines::*;
@@ -39,6 +39,7 @@ pub use stat::{
 pub use r#type::{
     Type,
     TypeChart,
+    TypeChartOverlay,
     TypeEffectiveness,
     TypeTable,
 };
@@ -1,5 +1,13 @@
-use core::fmt;
+use alloc::format;
+use core::{
+    fmt,
+    str::FromStr,
+};
 
+use anyhow::{
+    Error,
+    Result,
+};
 use hashbrown::HashMap;
 use serde::{
     Deserialize,
@@ -99,6 +107,20 @@ impl From<f32> for TypeEffectiveness {
     }
 }
 
+impl TypeEffectiveness {
+    /// Inverts the effectiveness, as in an Inverse Battle.
+    ///
+    /// Immunities become weaknesses.
+    pub fn inverse(self) -> Self {
+        match self {
+            Self::None => Self::Strong,
+            Self::Weak => Self::Strong,
+            Self::Normal => Self::Normal,
+            Self::Strong => Self::Weak,
+        }
+    }
+}
+
 impl Into<f32> for TypeEffectiveness {
     fn into(self) -> f32 {
         match self {
@@ -197,6 +219,92 @@ impl TypeChart {
     }
 }
 
+/// An overlay applied on top of a [`TypeChart`], which allows a format to change type
+/// effectiveness.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct TypeChartOverlay {
+    /// Whether all type effectiveness is inverted, as in an Inverse Battle.
+    pub inverse: bool,
+    /// Type effectiveness that overrides the underlying type chart.
+    ///
+    /// Overrides are applied after inversion.
+    pub overrides: TypeTable<Type>,
+}
+
+impl TypeChartOverlay {
+    /// Checks if the overlay leaves the underlying type chart unchanged.
+    pub fn is_empty(&self) -> bool {
+        !self.inverse && self.overrides.is_empty()
+    }
+
+    /// Returns the effectiveness of an offensive type against a defensive type, given the
+    /// effectiveness from the underlying type chart.
+    pub fn effectiveness(
+        &self,
+        offense: Type,
+        defense: Type,
+        base: TypeEffectiveness,
+    ) -> TypeEffectiveness {
+        if let Some(effectiveness) = self
+            .overrides
+            .get(&offense)
+            .and_then(|row| row.get(&defense))
+        {
+            return *effectiveness;
+        }
+        if self.inverse { base.inverse() } else { base }
+    }
+
+    /// Applies the overlay to a type chart, creating a new type chart.
+    pub fn apply(&self, type_chart: &TypeChart) -> TypeChart {
+        let mut types = type_chart.types.clone();
+        if self.inverse {
+            for effectiveness in types.values_mut().flat_map(|row| row.values_mut()) {
+                *effectiveness = effectiveness.inverse();
+            }
+        }
+        for (offense, row) in &self.overrides {
+            types.entry(*offense).or_default().extend(row);
+        }
+        TypeChart::from_filled(types)
+    }
+
+    /// Parses type effectiveness overrides from a string.
+    ///
+    /// Overrides are separated by commas and are written in the form `Offense > Defense:
+    /// Multiplier`, where the multiplier is one of `0`, `0.5`, `1`, or `2`. For example, `Dragon >
+    /// Fairy: 0, Fire > Water: 2`.
+    pub fn parse_overrides(s: &str) -> Result<TypeTable<Type>> {
+        let mut overrides = TypeTable::new();
+        for entry in s.split(',') {
+            let (types, effectiveness) = entry
+                .split_once(':')
+                .ok_or_else(|| Error::msg(format!("invalid type chart override \"{entry}\"")))?;
+            let (offense, defense) = types
+                .split_once('>')
+                .ok_or_else(|| Error::msg(format!("invalid type chart override \"{entry}\"")))?;
+            let offense = Type::from_str(offense.trim()).map_err(Error::msg)?;
+            let defense = Type::from_str(defense.trim()).map_err(Error::msg)?;
+            let effectiveness = match effectiveness.trim() {
+                "0" => TypeEffectiveness::None,
+                "0.5" => TypeEffectiveness::Weak,
+                "1" => TypeEffectiveness::Normal,
+                "2" => TypeEffectiveness::Strong,
+                effectiveness => {
+                    return Err(Error::msg(format!(
+                        "invalid type effectiveness \"{effectiveness}\""
+                    )));
+                }
+            };
+            overrides
+                .entry(offense)
+                .or_insert_with(HashMap::new)
+                .insert(defense, effectiveness);
+        }
+        Ok(overrides)
+    }
+}
+
 #[cfg(test)]
 mod type_test {
     use crate::{
@@ -306,3 +414,124 @@ mod type_effectiveness_test {
         assert_eq!(tc, expected)
     }
 }
+
+#[cfg(test)]
+mod type_chart_overlay_test {
+    use hashbrown::HashMap;
+
+    use crate::{
+        Type,
+        TypeChart,
+        TypeChartOverlay,
+        TypeEffectiveness,
+        TypeTable,
+    };
+
+    fn type_chart() -> TypeChart {
+        TypeChart::from_filled(TypeTable::from_iter([
+            (
+                Type::Normal,
+                HashMap::from_iter([
+                    (Type::Rock, TypeEffectiveness::Weak),
+                    (Type::Ghost, TypeEffectiveness::None),
+                ]),
+            ),
+            (
+                Type::Fire,
+                HashMap::from_iter([(Type::Grass, TypeEffectiveness::Strong)]),
+            ),
+        ]))
+    }
+
+    #[test]
+    fn empty_overlay_does_not_modify_type_chart() {
+        let overlay = TypeChartOverlay::default();
+        assert!(overlay.is_empty());
+        assert_eq!(overlay.apply(&type_chart()), type_chart());
+    }
+
+    #[test]
+    fn inverts_type_chart() {
+        let overlay = TypeChartOverlay {
+            inverse: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            overlay.apply(&type_chart()),
+            TypeChart::from_filled(TypeTable::from_iter([
+                (
+                    Type::Normal,
+                    HashMap::from_iter([
+                        (Type::Rock, TypeEffectiveness::Strong),
+                        (Type::Ghost, TypeEffectiveness::Strong),
+                    ]),
+                ),
+                (
+                    Type::Fire,
+                    HashMap::from_iter([(Type::Grass, TypeEffectiveness::Weak)]),
+                ),
+            ]))
+        );
+        assert_eq!(
+            overlay.effectiveness(Type::Normal, Type::Ghost, TypeEffectiveness::None),
+            TypeEffectiveness::Strong
+        );
+        assert_eq!(
+            overlay.effectiveness(Type::Normal, Type::Fire, TypeEffectiveness::Normal),
+            TypeEffectiveness::Normal
+        );
+    }
+
+    #[test]
+    fn applies_overrides_after_inversion() {
+        let overlay = TypeChartOverlay {
+            inverse: true,
+            overrides: TypeChartOverlay::parse_overrides("Normal > Ghost: 0, Fire > Water: 2")
+                .unwrap(),
+        };
+        let type_chart = overlay.apply(&type_chart());
+        assert_eq!(
+            type_chart
+                .types
+                .get(&Type::Normal)
+                .unwrap()
+                .get(&Type::Ghost),
+            Some(&TypeEffectiveness::None)
+        );
+        assert_eq!(
+            type_chart
+                .types
+                .get(&Type::Normal)
+                .unwrap()
+                .get(&Type::Rock),
+            Some(&TypeEffectiveness::Strong)
+        );
+        assert_eq!(
+            type_chart.types.get(&Type::Fire).unwrap().get(&Type::Water),
+            Some(&TypeEffectiveness::Strong)
+        );
+        assert_eq!(
+            overlay.effectiveness(Type::Fire, Type::Water, TypeEffectiveness::Weak),
+            TypeEffectiveness::Strong
+        );
+    }
+
+    #[test]
+    fn fails_to_parse_invalid_overrides() {
+        assert_eq!(
+            format!(
+                "{:#}",
+                TypeChartOverlay::parse_overrides("Dragon Fairy: 0").unwrap_err()
+            ),
+            "invalid type chart override \"Dragon Fairy: 0\""
+        );
+        assert_eq!(
+            format!(
+                "{:#}",
+                TypeChartOverlay::parse_overrides("Dragon > Fairy: 3").unwrap_err()
+            ),
+            "invalid type effectiveness \"3\""
+        );
+        assert!(TypeChartOverlay::parse_overrides("Dragon > Light: 0").is_err());
+    }
+}
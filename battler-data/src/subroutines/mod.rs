@@ -0,0 +1,3 @@
+mod subroutine_data;
+
+pub use subroutine_data::SubroutineData;
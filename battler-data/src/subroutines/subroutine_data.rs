@@ -0,0 +1,23 @@
+use alloc::{
+    string::String,
+    vec::Vec,
+};
+
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+/// Data about a shared fxlang subroutine.
+///
+/// Subroutines are named programs that can be called from any fxlang program, exactly like a
+/// function.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubroutineData {
+    /// Named parameters, which are assigned to arguments in order.
+    #[serde(default)]
+    pub parameters: Vec<String>,
+
+    /// The program to run.
+    pub program: serde_json::Value,
+}
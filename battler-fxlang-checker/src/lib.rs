@@ -0,0 +1,120 @@
+use battler::{
+    CheckError,
+    Coverage,
+    CoverageReport,
+    ExternalFunctions,
+    check_effect_data,
+    check_subroutine_data,
+    effect::fxlang::NativeFunctionRegistry,
+};
+use battler_data::{
+    Id,
+    SubroutineData,
+};
+use battler_local_data::LocalDataStore;
+
+/// Statically checks every fxlang effect in the data store.
+///
+/// Effects on abilities, clauses, conditions, items, moves, and species are checked, along with all
+/// subroutines. Errors are returned in effect ID order.
+///
+/// Native functions registered for battles should be passed in, so that programs calling them are
+/// checked correctly.
+pub fn check_local_data_store(
+    data: &LocalDataStore,
+    native_functions: &NativeFunctionRegistry,
+) -> Vec<CheckError> {
+    let mut functions = ExternalFunctions::default();
+    functions.add_native_functions(native_functions);
+    for (id, subroutine) in &data.subroutines {
+        functions.add_subroutine(id.clone(), subroutine);
+    }
+
+    effects(data)
+        .into_iter()
+        .flat_map(|(id, effect)| check_effect_data(&id, effect, &functions))
+        .chain(
+            subroutines(data)
+                .into_iter()
+                .flat_map(|(id, subroutine)| check_subroutine_data(&id, subroutine, &functions)),
+        )
+        .collect()
+}
+
+/// Reports the coverage of every fxlang effect and subroutine in the data store.
+///
+/// Programs are identified by the same effect IDs used for errors from [`check_local_data_store`].
+pub fn coverage_report(data: &LocalDataStore, coverage: &Coverage) -> CoverageReport {
+    CoverageReport::new(coverage, effects(data), subroutines(data))
+}
+
+/// All fxlang effects in the data store, sorted by effect ID.
+fn effects(data: &LocalDataStore) -> Vec<(String, &serde_json::Value)> {
+    let mut effects = Vec::new();
+    for (id, ability) in &data.abilities {
+        effects.push((effect_id("ability", id), &ability.effect));
+        effects.push((condition_id("ability", id), &ability.condition));
+    }
+    for (id, clause) in &data.clauses {
+        effects.push((effect_id("clause", id), &clause.effect));
+    }
+    for (id, condition) in &data.conditions {
+        effects.push((effect_id("condition", id), &condition.condition));
+    }
+    for (id, item) in &data.items {
+        effects.push((effect_id("item", id), &item.effect));
+        effects.push((condition_id("item", id), &item.condition));
+    }
+    for (id, mov) in &data.moves {
+        effects.push((effect_id("move", id), &mov.effect));
+        effects.push((condition_id("move", id), &mov.condition));
+        for (i, secondary_effect) in mov.secondary_effects.iter().enumerate() {
+            effects.push((
+                format!("{}/secondary_effects/{i}", effect_id("move", id)),
+                &secondary_effect.effect,
+            ));
+        }
+    }
+    for (id, species) in &data.species {
+        effects.push((effect_id("species", id), &species.effect));
+    }
+    effects.sort_by(|(a, _), (b, _)| a.cmp(b));
+    effects
+}
+
+/// All subroutines in the data store, sorted by effect ID.
+fn subroutines(data: &LocalDataStore) -> Vec<(String, &SubroutineData)> {
+    let mut subroutines = data
+        .subroutines
+        .iter()
+        .map(|(id, subroutine)| (effect_id("subroutine", id), subroutine))
+        .collect::<Vec<_>>();
+    subroutines.sort_by(|(a, _), (b, _)| a.cmp(b));
+    subroutines
+}
+
+fn effect_id(effect_type: &str, id: &Id) -> String {
+    format!("{effect_type}:{id}")
+}
+
+fn condition_id(effect_type: &str, id: &Id) -> String {
+    format!("{}/condition", effect_id(effect_type, id))
+}
+
+#[cfg(test)]
+mod tests {
+    use battler::effect::fxlang::NativeFunctionRegistry;
+    use battler_local_data::LocalDataStore;
+
+    use crate::check_local_data_store;
+
+    #[test]
+    fn battle_data_has_no_errors() {
+        let store = LocalDataStore::new_from_env("DATA_DIR").unwrap();
+        let errors = check_local_data_store(&store, &NativeFunctionRegistry::default())
+            .into_iter()
+            .map(|err| err.to_string())
+            .collect::<Vec<_>>();
+        pretty_assertions::assert_eq!(errors, Vec::<String>::new());
+    }
+}
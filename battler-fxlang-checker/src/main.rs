@@ -0,0 +1,90 @@
+use std::fs;
+
+use anyhow::{
+    Context,
+    Error,
+    Result,
+};
+use battler::{
+    Coverage,
+    effect::fxlang::NativeFunctionRegistry,
+};
+use battler_fxlang_checker::{
+    check_local_data_store,
+    coverage_report,
+};
+use battler_local_data::LocalDataStore;
+use clap::Parser;
+
+#[derive(Parser, Debug)]
+#[command(
+    name = "battler-fxlang-checker",
+    about = "Static checker for fxlang programs in battle data"
+)]
+struct Args {
+    /// Path to Pokemon data directory
+    #[arg(short, long, default_value = "battle-data/data")]
+    data_dir: String,
+
+    /// Directory of coverage files to report on, instead of checking programs
+    #[arg(long)]
+    coverage_dir: Option<String>,
+
+    /// Path to write the coverage report to, as JSON
+    #[arg(long, requires = "coverage_dir")]
+    coverage_report: Option<String>,
+}
+
+fn main() {
+    if let Err(err) = run() {
+        eprintln!("{err:#}");
+        std::process::exit(1);
+    }
+}
+
+fn run() -> Result<()> {
+    let args = Args::parse();
+    let data = LocalDataStore::new(args.data_dir)?;
+    if let Some(coverage_dir) = args.coverage_dir {
+        return report_coverage(&data, &coverage_dir, args.coverage_report.as_deref());
+    }
+
+    let errors = check_local_data_store(&data, &NativeFunctionRegistry::default());
+    for err in &errors {
+        println!("{err}");
+    }
+    if !errors.is_empty() {
+        return Err(Error::msg(format!("found {} errors", errors.len())));
+    }
+    Ok(())
+}
+
+fn report_coverage(
+    data: &LocalDataStore,
+    coverage_dir: &str,
+    coverage_report_path: Option<&str>,
+) -> Result<()> {
+    let mut coverage = Coverage::default();
+    for entry in fs::read_dir(coverage_dir).context(format!("failed to read {coverage_dir}"))? {
+        let path = entry?.path();
+        if path
+            .extension()
+            .is_some_and(|extension| extension == "json")
+        {
+            let file =
+                fs::read_to_string(&path).context(format!("failed to read {}", path.display()))?;
+            coverage.merge(
+                serde_json::from_str(&file)
+                    .context(format!("failed to parse {}", path.display()))?,
+            );
+        }
+    }
+
+    let report = coverage_report(data, &coverage);
+    if let Some(path) = coverage_report_path {
+        fs::write(path, serde_json::to_string_pretty(&report)?)
+            .context(format!("failed to write {path}"))?;
+    }
+    print!("{report}");
+    Ok(())
+}
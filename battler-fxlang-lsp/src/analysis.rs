@@ -0,0 +1,630 @@
+use std::{
+    collections::{
+        BTreeMap,
+        BTreeSet,
+    },
+    ops::Range,
+};
+
+use battler::{
+    CheckError,
+    Effect,
+    ExternalFunctions,
+    Program,
+    StatementToken,
+    StatementTokenKind,
+    check_effect,
+    check_subroutine_data,
+    common::split_once_optional,
+    effect::fxlang::ParsedProgram,
+    input_vars,
+    tokenize_statement,
+};
+use battler_data::{
+    Id,
+    SubroutineData,
+};
+use lsp_types::{
+    CompletionItem,
+    CompletionItemKind,
+    CompletionTextEdit,
+    Diagnostic,
+    DiagnosticSeverity,
+    Documentation,
+    Hover,
+    HoverContents,
+    Location,
+    MarkupContent,
+    MarkupKind,
+    Position,
+    TextEdit,
+};
+
+use crate::{
+    docs::function_docs,
+    document::{
+        DataDocument,
+        DataKind,
+        ProgramNode,
+    },
+    index::DataIndex,
+    json::{
+        JsonError,
+        JsonString,
+    },
+    line_index::LineIndex,
+};
+
+const DIAGNOSTIC_SOURCE: &str = "fxlang";
+
+/// A statement of a program, along with its tokens.
+struct Statement<'d> {
+    string: &'d JsonString,
+    tokens: Vec<StatementToken>,
+}
+
+impl<'d> Statement<'d> {
+    fn new(string: &'d JsonString) -> Self {
+        Self {
+            string,
+            tokens: tokenize_statement(&string.value),
+        }
+    }
+
+    /// Checks if the statement is empty, which is how the checker counts statements.
+    fn is_empty(&self) -> bool {
+        self.tokens
+            .iter()
+            .all(|token| token.kind == StatementTokenKind::Comment)
+    }
+
+    fn text(&self, token: &StatementToken) -> &'d str {
+        &self.string.value[token.start..token.end]
+    }
+
+    /// The token containing an offset in the statement.
+    fn token_at(&self, offset: usize) -> Option<&StatementToken> {
+        self.tokens
+            .iter()
+            .find(|token| token.start <= offset && offset <= token.end)
+    }
+}
+
+/// The analysis of a single battle data document, which answers language server requests.
+#[derive(Debug)]
+pub struct Analysis {
+    line_index: LineIndex,
+    document: Result<DataDocument, JsonError>,
+}
+
+impl Analysis {
+    pub fn new(kind: DataKind, text: &str) -> Self {
+        Self {
+            line_index: LineIndex::new(text),
+            document: DataDocument::parse(kind, text),
+        }
+    }
+
+    fn diagnostic(&self, range: Range<usize>, message: String) -> Diagnostic {
+        Diagnostic {
+            range: self.line_index.range(range),
+            severity: Some(DiagnosticSeverity::ERROR),
+            source: Some(DIAGNOSTIC_SOURCE.to_owned()),
+            message,
+            ..Default::default()
+        }
+    }
+
+    /// Diagnostics for the document.
+    ///
+    /// Every statement is parsed on its own, so that parse errors are reported on the statement
+    /// itself. Programs that parse are then statically checked. Native functions are unknown to
+    /// the language server, so calls to them are reported as undefined.
+    pub fn diagnostics(&self, index: &DataIndex) -> Vec<Diagnostic> {
+        let document = match &self.document {
+            Ok(document) => document,
+            Err(err) => {
+                return Vec::from_iter([
+                    self.diagnostic(err.offset..err.offset, err.message.clone())
+                ]);
+            }
+        };
+
+        let mut functions = ExternalFunctions::default();
+        for (id, subroutine) in index.subroutines() {
+            functions.add_subroutine(id, &subroutine);
+        }
+
+        let mut diagnostics = Vec::new();
+        let mut check_errors = BTreeMap::<(String, Option<String>), Vec<CheckError>>::new();
+        for effect in document.effects() {
+            if effect.node.to_value().is_null() {
+                continue;
+            }
+            match Effect::try_from(effect.node.to_value()) {
+                Ok(parsed) => {
+                    for err in check_effect(&effect.id, &parsed, &functions) {
+                        check_errors
+                            .entry((err.effect.clone(), err.callback.clone()))
+                            .or_default()
+                            .push(err);
+                    }
+                }
+                Err(err) => {
+                    diagnostics.push(self.diagnostic(effect.key.range.clone(), format!("{err:#}")))
+                }
+            }
+        }
+        if document.kind == DataKind::Subroutine {
+            for (key, entry) in document.entries() {
+                let id = format!("subroutine:{}", Id::from(key.name.as_str()));
+                match serde_json::from_value::<SubroutineData>(entry.to_value()) {
+                    Ok(subroutine) => {
+                        for err in check_subroutine_data(&id, &subroutine, &functions) {
+                            check_errors
+                                .entry((err.effect.clone(), None))
+                                .or_default()
+                                .push(err);
+                        }
+                    }
+                    Err(err) => diagnostics.push(
+                        self.diagnostic(key.range.clone(), format!("invalid subroutine: {err}")),
+                    ),
+                }
+            }
+        }
+
+        for program in document.programs() {
+            let statements = program
+                .statements()
+                .into_iter()
+                .map(Statement::new)
+                .filter(|statement| !statement.is_empty())
+                .collect::<Vec<_>>();
+
+            let parse_errors = statements
+                .iter()
+                .filter_map(|statement| {
+                    let err = ParsedProgram::from(&Program::Leaf(statement.string.value.clone()))
+                        .err()?;
+                    Some(
+                        self.diagnostic(
+                            statement
+                                .string
+                                .source_range(0..statement.string.value.len()),
+                            format!("{:#}", err.root_cause()),
+                        ),
+                    )
+                })
+                .collect::<Vec<_>>();
+            if !parse_errors.is_empty() {
+                // The checker only reports the first parse error, without a location.
+                diagnostics.extend(parse_errors);
+                continue;
+            }
+
+            let errors = check_errors
+                .remove(&(program.effect.clone(), program.callback.clone()))
+                .unwrap_or_default();
+            for err in errors {
+                let range = match err.statement.and_then(|i| statements.get(i)) {
+                    Some(statement) => statement
+                        .string
+                        .source_range(0..statement.string.value.len()),
+                    None => program.key.range.clone(),
+                };
+                diagnostics.push(self.diagnostic(range, err.message));
+            }
+        }
+        diagnostics
+    }
+
+    /// The statement at a position, along with the program containing it.
+    fn statement_at(&self, position: Position) -> Option<(ProgramNode<'_>, Statement<'_>, usize)> {
+        let offset = self.line_index.offset(position);
+        let program = self.document.as_ref().ok()?.program_at(offset)?;
+        let (string, value_offset) = program
+            .statements()
+            .into_iter()
+            .find_map(|string| Some((string, string.value_offset(offset)?)))?;
+        Some((program, Statement::new(string), value_offset))
+    }
+
+    /// Hover information for a function or variable.
+    pub fn hover(&self, position: Position, index: &DataIndex) -> Option<Hover> {
+        let (program, statement, offset) = self.statement_at(position)?;
+        let token = statement.token_at(offset)?;
+        let text = statement.text(token);
+        let markdown = match token.kind {
+            StatementTokenKind::Function => match function_docs().get(text) {
+                Some(doc) => doc.markdown(),
+                None => {
+                    let subroutine = index.subroutine(&Id::from(text))?;
+                    subroutine_markdown(text, subroutine)
+                }
+            },
+            StatementTokenKind::Variable => {
+                let name = text.strip_prefix('$').unwrap_or(text);
+                let description = match input_vars(program.event, &program.parameters)
+                    .into_iter()
+                    .find(|var| var.name == name)
+                {
+                    Some(var) => match var.value_type {
+                        Some(value_type) => format!("Input variable of type `{value_type}`."),
+                        None => "Input variable.".to_owned(),
+                    },
+                    None => "Local variable.".to_owned(),
+                };
+                format!("```fxlang\n{text}\n```\n\n{description}\n")
+            }
+            _ => return None,
+        };
+        Some(Hover {
+            contents: HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value: markdown,
+            }),
+            range: Some(
+                self.line_index
+                    .range(statement.string.source_range(token.start..token.end)),
+            ),
+        })
+    }
+
+    /// Completions for variables and functions in a statement.
+    pub fn completions(&self, position: Position, index: &DataIndex) -> Vec<CompletionItem> {
+        let Some((program, statement, offset)) = self.statement_at(position) else {
+            return Vec::new();
+        };
+        let prefix = &statement.string.value[..offset];
+        let tokens = tokenize_statement(prefix);
+        let kinds = tokens
+            .iter()
+            .map(|token| (token.kind, &prefix[token.start..token.end]))
+            .collect::<Vec<_>>();
+        let word_start = |kind| match tokens.last() {
+            Some(token) if token.kind == kind && token.end == offset => token.start,
+            _ => offset,
+        };
+
+        if prefix.ends_with('$')
+            || tokens.last().is_some_and(|token| {
+                token.kind == StatementTokenKind::Variable && token.end == offset
+            })
+        {
+            let start = match prefix.ends_with('$') {
+                true => offset,
+                false => word_start(StatementTokenKind::Variable) + 1,
+            };
+            let range = self
+                .line_index
+                .range(statement.string.source_range(start..offset));
+            return variables(&program)
+                .into_iter()
+                .map(|(name, detail)| CompletionItem {
+                    label: format!("${name}"),
+                    kind: Some(CompletionItemKind::VARIABLE),
+                    detail,
+                    filter_text: Some(name.clone()),
+                    text_edit: Some(CompletionTextEdit::Edit(TextEdit::new(range, name))),
+                    ..Default::default()
+                })
+                .collect();
+        }
+
+        // Functions are completed at the start of a statement or as the first token of a function
+        // call expression, while the function name is still being typed.
+        let function_position = match kinds.as_slice() {
+            [] => true,
+            [.., (StatementTokenKind::Keyword, "func_call"), (_, "(")] => true,
+            [.., (StatementTokenKind::Function, _)] => {
+                tokens.last().is_some_and(|token| token.end == offset)
+            }
+            _ => false,
+        };
+        if !function_position {
+            return Vec::new();
+        }
+        let range = self.line_index.range(
+            statement
+                .string
+                .source_range(word_start(StatementTokenKind::Function)..offset),
+        );
+        let mut completions = function_docs()
+            .values()
+            .map(|doc| CompletionItem {
+                label: doc.name.clone(),
+                kind: Some(CompletionItemKind::FUNCTION),
+                detail: Some(doc.usage()),
+                documentation: Some(Documentation::MarkupContent(MarkupContent {
+                    kind: MarkupKind::Markdown,
+                    value: doc.markdown(),
+                })),
+                text_edit: Some(CompletionTextEdit::Edit(TextEdit::new(
+                    range,
+                    doc.name.clone(),
+                ))),
+                ..Default::default()
+            })
+            .collect::<Vec<_>>();
+        completions.extend(index.subroutines().into_iter().map(|(id, subroutine)| {
+            CompletionItem {
+                label: id.to_string(),
+                kind: Some(CompletionItemKind::FUNCTION),
+                detail: Some(subroutine_usage(id.as_ref(), &subroutine)),
+                text_edit: Some(CompletionTextEdit::Edit(TextEdit::new(
+                    range,
+                    id.to_string(),
+                ))),
+                ..Default::default()
+            }
+        }));
+        completions
+    }
+
+    /// The definition of a subroutine, delegate effect, or effect referenced by name.
+    pub fn definition(&self, position: Position, index: &DataIndex) -> Option<Location> {
+        let offset = self.line_index.offset(position);
+        let document = self.document.as_ref().ok()?;
+        for effect in document.effects() {
+            if let Some(delegate) = effect
+                .node
+                .get("delegates")
+                .and_then(|delegates| delegates.elements())
+                .unwrap_or_default()
+                .iter()
+                .find(|delegate| delegate.contains(offset))
+            {
+                // Delegates may map events after the effect ID.
+                let (fxlang_id, _) = split_once_optional(&delegate.string()?.value, ';');
+                return index.effect_location(fxlang_id);
+            }
+        }
+
+        let (_, statement, offset) = self.statement_at(position)?;
+        let token = statement.token_at(offset)?;
+        let text = statement.text(token);
+        match token.kind {
+            StatementTokenKind::Function => index.subroutine_location(&Id::from(text)),
+            StatementTokenKind::Identifier => index.effect_location_by_id(&Id::from(text)),
+            _ => None,
+        }
+    }
+}
+
+/// Every variable available in a program, along with a description.
+///
+/// Input variables are listed first, followed by variables assigned in the program.
+fn variables(program: &ProgramNode) -> Vec<(String, Option<String>)> {
+    let mut names = BTreeSet::new();
+    let mut variables = Vec::new();
+    for var in input_vars(program.event, &program.parameters) {
+        names.insert(var.name.clone());
+        variables.push((
+            var.name,
+            var.value_type.map(|value_type| value_type.to_string()),
+        ));
+    }
+    for string in program.statements() {
+        let statement = Statement::new(string);
+        for (i, token) in statement.tokens.iter().enumerate() {
+            if token.kind != StatementTokenKind::Variable {
+                continue;
+            }
+            let assigned = statement
+                .tokens
+                .get(i + 1)
+                .is_some_and(|next| statement.text(next) == "=")
+                || i.checked_sub(1)
+                    .and_then(|i| statement.tokens.get(i))
+                    .is_some_and(|previous| statement.text(previous) == "foreach");
+            let name = &statement.text(token)[1..];
+            if assigned && names.insert(name.to_owned()) {
+                variables.push((name.to_owned(), None));
+            }
+        }
+    }
+    variables
+}
+
+fn subroutine_usage(name: &str, subroutine: &SubroutineData) -> String {
+    match subroutine.parameters.is_empty() {
+        true => name.to_owned(),
+        false => format!("{name}: {}", subroutine.parameters.join(" ")),
+    }
+}
+
+fn subroutine_markdown(name: &str, subroutine: &SubroutineData) -> String {
+    format!(
+        "```fxlang\n{}\n```\n\nSubroutine defined in battle data.\n",
+        subroutine_usage(name, subroutine)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        fs,
+        path::Path,
+    };
+
+    use lsp_types::{
+        HoverContents,
+        Position,
+    };
+
+    use crate::{
+        analysis::Analysis,
+        document::DataKind,
+        index::DataIndex,
+    };
+
+    const MOVES: &str = r#"{
+  "protect": {
+    "condition": {
+      "delegates": ["condition:stall", "condition:stall;on_hit=>on_try_hit"],
+      "callbacks": {
+        "on_try_hit": [
+          "if $target.hp > 0:",
+          ["add_volatile: $target stall"],
+          "log_nothing: $targett",
+          "add_volatile: $target"
+        ],
+        "on_invalid": "return",
+        "on_hit": "$x = func_call(("
+      }
+    }
+  }
+}"#;
+
+    fn index() -> DataIndex {
+        let mut index = DataIndex::default();
+        index.update(Path::new("/data/moves/moves.json"), MOVES);
+        index.update(
+            Path::new("/data/conditions.json"),
+            r#"{ "stall": { "condition": {} } }"#,
+        );
+        index.update(
+            Path::new("/data/subroutines.json"),
+            r#"{ "heal": { "parameters": ["mon"], "program": ["return"] } }"#,
+        );
+        index
+    }
+
+    #[test]
+    fn reports_diagnostics_on_statements() {
+        let analysis = Analysis::new(DataKind::Move, MOVES);
+        pretty_assertions::assert_eq!(
+            analysis
+                .diagnostics(&index())
+                .into_iter()
+                .map(|diagnostic| (diagnostic.range.start.line, diagnostic.message))
+                .collect::<Vec<_>>(),
+            [
+                (
+                    8,
+                    "error on statement 2: undefined function: log_nothing".to_owned()
+                ),
+                (
+                    8,
+                    "error on statement 2: undefined variable $targett".to_owned()
+                ),
+                (11, "invalid callback on_invalid".to_owned()),
+                (
+                    12,
+                    "unexpected token at index 15: ( (expected identifier)".to_owned()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn hovers_functions_and_variables() {
+        let analysis = Analysis::new(DataKind::Move, MOVES);
+        let hover = |line, character| match analysis
+            .hover(Position::new(line, character), &index())
+            .map(|hover| hover.contents)
+        {
+            Some(HoverContents::Markup(markup)) => Some(markup.value),
+            _ => None,
+        };
+        assert!(
+            hover(7, 12)
+                .unwrap()
+                .starts_with("```fxlang\nadd_volatile: [mon] [effect]\n```")
+        );
+        pretty_assertions::assert_eq!(
+            hover(6, 16).unwrap(),
+            "```fxlang\n$target\n```\n\nInput variable of type `Mon`.\n"
+        );
+        pretty_assertions::assert_eq!(hover(6, 13), None);
+    }
+
+    #[test]
+    fn completes_variables_and_functions() {
+        let text = r#"{
+  "protect": {
+    "condition": {
+      "callbacks": {
+        "on_hit": ["foreach $mon in $target.side.active:", ["$target.hp = func_call(he"]]
+      }
+    }
+  }
+}"#;
+        let analysis = Analysis::new(DataKind::Move, text);
+        let labels = |line, character| {
+            analysis
+                .completions(Position::new(line, character), &index())
+                .into_iter()
+                .map(|completion| completion.label)
+                .collect::<Vec<_>>()
+        };
+        let variables = labels(4, 64);
+        assert!(variables.contains(&"$target".to_owned()), "{variables:?}");
+        assert!(variables.contains(&"$mon".to_owned()), "{variables:?}");
+        let functions = labels(4, 86);
+        assert!(functions.contains(&"heal".to_owned()), "{functions:?}");
+        assert!(
+            functions.contains(&"add_volatile".to_owned()),
+            "{functions:?}"
+        );
+        pretty_assertions::assert_eq!(labels(4, 70), Vec::<String>::new());
+    }
+
+    #[test]
+    fn finds_definitions() {
+        let analysis = Analysis::new(DataKind::Move, MOVES);
+        let index = index();
+        let definition = |line, character| {
+            analysis
+                .definition(Position::new(line, character), &index)
+                .map(|location| (location.uri.path().to_owned(), location.range.start.line))
+        };
+        pretty_assertions::assert_eq!(
+            definition(3, 22),
+            Some(("/data/conditions.json".to_owned(), 0))
+        );
+        pretty_assertions::assert_eq!(
+            definition(3, 45),
+            Some(("/data/conditions.json".to_owned(), 0))
+        );
+        pretty_assertions::assert_eq!(
+            definition(7, 35),
+            Some(("/data/conditions.json".to_owned(), 0))
+        );
+        pretty_assertions::assert_eq!(definition(7, 12), None);
+    }
+
+    #[test]
+    fn battle_data_has_no_diagnostics() {
+        let root = Path::new(&std::env::var("DATA_DIR").unwrap()).to_owned();
+        let index = DataIndex::load(&root);
+        let mut paths = fs::read_dir(&root)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect::<Vec<_>>();
+        for dir in ["abilities", "items", "moves", "mons"] {
+            paths.extend(
+                fs::read_dir(root.join(dir))
+                    .unwrap()
+                    .map(|entry| entry.unwrap().path()),
+            );
+        }
+        let mut diagnostics = Vec::new();
+        for path in paths {
+            let Some(kind) = DataKind::from_path(&path) else {
+                continue;
+            };
+            let text = fs::read_to_string(&path).unwrap();
+            for diagnostic in Analysis::new(kind, &text).diagnostics(&index) {
+                diagnostics.push(format!(
+                    "{}:{}: {}",
+                    path.display(),
+                    diagnostic.range.start.line + 1,
+                    diagnostic.message
+                ));
+            }
+        }
+        pretty_assertions::assert_eq!(diagnostics, Vec::<String>::new());
+    }
+}
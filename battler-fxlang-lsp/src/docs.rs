@@ -0,0 +1,242 @@
+//! Documentation of built-in fxlang functions.
+//!
+//! Function documentation only exists in the battle engine's table of built-in functions, which
+//! exposes it for every built-in function. Functions are only documented if the engine actually
+//! defines them, so documentation never drifts from the engine.
+
+use std::{
+    collections::BTreeMap,
+    sync::LazyLock,
+};
+
+use battler::{
+    FunctionSignature,
+    effect::fxlang::BuiltinFunction,
+};
+
+/// A parameter of a built-in fxlang function.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FunctionParam {
+    pub name: String,
+    pub value_type: String,
+    pub optional: bool,
+    pub description: String,
+}
+
+/// Documentation for a built-in fxlang function.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FunctionDoc {
+    pub name: String,
+    pub signature: FunctionSignature,
+    pub description: String,
+    pub params: Vec<FunctionParam>,
+    pub flags: Vec<(String, String)>,
+    pub returns: Option<(String, String)>,
+}
+
+impl FunctionDoc {
+    /// A one-line summary of how the function is called.
+    pub fn usage(&self) -> String {
+        let mut usage = self.name.clone();
+        for (i, param) in self.params.iter().enumerate() {
+            usage.push_str(if i == 0 { ": " } else { " " });
+            if param.name == "..." {
+                usage.push_str("...");
+            } else if param.optional {
+                usage.push_str(&format!("[{}]", param.name));
+            } else {
+                usage.push_str(&param.name);
+            }
+        }
+        usage
+    }
+
+    /// The documentation of the function, as Markdown.
+    pub fn markdown(&self) -> String {
+        let mut markdown = format!("```fxlang\n{}\n```\n", self.usage());
+        if !self.description.is_empty() {
+            markdown.push_str(&format!("\n{}\n", self.description));
+        }
+        if !self.params.is_empty() {
+            markdown.push_str("\n**Parameters**\n\n");
+            for param in &self.params {
+                let optional = if param.optional { ", optional" } else { "" };
+                markdown.push_str(&format!(
+                    "- `{}` (`{}`{optional}): {}\n",
+                    param.name, param.value_type, param.description
+                ));
+            }
+        }
+        if !self.flags.is_empty() {
+            markdown.push_str("\n**Flags**\n\n");
+            for (name, description) in &self.flags {
+                markdown.push_str(&format!("- `{name}`: {description}\n"));
+            }
+        }
+        if let Some((value_type, description)) = &self.returns {
+            markdown.push_str(&format!("\n**Returns** `{value_type}`: {description}\n"));
+        }
+        markdown
+    }
+}
+
+/// Documentation for every built-in fxlang function, by name.
+pub fn function_docs() -> &'static BTreeMap<String, FunctionDoc> {
+    static DOCS: LazyLock<BTreeMap<String, FunctionDoc>> = LazyLock::new(|| {
+        BuiltinFunction::all()
+            .map(|function| {
+                let mut doc = parse_doc_comment(&function.doc());
+                doc.name = function.name().to_owned();
+                doc.signature = function.signature();
+                (doc.name.clone(), doc)
+            })
+            .collect()
+    });
+    &DOCS
+}
+
+fn value_type(value_type: &str) -> String {
+    value_type.replace("[`ValueType::", "").replace("`]", "")
+}
+
+fn parse_doc_comment(doc_comment: &str) -> FunctionDoc {
+    enum Section {
+        Description,
+        Param,
+        Flag,
+        Returns,
+    }
+
+    let mut doc = FunctionDoc {
+        name: String::new(),
+        signature: FunctionSignature {
+            required_args: 0,
+            returns_value: false,
+        },
+        description: String::new(),
+        params: Vec::new(),
+        flags: Vec::new(),
+        returns: None,
+    };
+    let mut section = Section::Description;
+    for line in doc_comment.lines() {
+        if let Some(param) = line.strip_prefix("@param ") {
+            section = Section::Param;
+            let (value_type_string, rest) = split_value_type(param);
+            let (name, description) = rest.split_once(' ').unwrap_or((rest, ""));
+            let (name, optional) = match name.strip_prefix('[').and_then(|n| n.strip_suffix(']')) {
+                Some(name) => (name, true),
+                None => (name, false),
+            };
+            doc.params.push(FunctionParam {
+                name: name.to_owned(),
+                value_type: value_type(value_type_string),
+                optional,
+                description: description.to_owned(),
+            });
+        } else if let Some(flag) = line.strip_prefix("@flag ") {
+            section = Section::Flag;
+            let (name, description) = flag.split_once(' ').unwrap_or((flag, ""));
+            doc.flags.push((name.to_owned(), description.to_owned()));
+        } else if let Some(returns) = line.strip_prefix("@returns ") {
+            section = Section::Returns;
+            let (value_type_string, description) = split_value_type(returns);
+            doc.returns = Some((value_type(value_type_string), description.to_owned()));
+        } else if line == "@returns_item_from_list" {
+            section = Section::Returns;
+            doc.returns = Some(("Any".to_owned(), "An item from the list.".to_owned()));
+        } else {
+            let text = match section {
+                Section::Description => &mut doc.description,
+                Section::Param => match doc.params.last_mut() {
+                    Some(param) => &mut param.description,
+                    None => continue,
+                },
+                Section::Flag => match doc.flags.last_mut() {
+                    Some((_, description)) => description,
+                    None => continue,
+                },
+                Section::Returns => match &mut doc.returns {
+                    Some((_, description)) => description,
+                    None => continue,
+                },
+            };
+            if line.is_empty() {
+                if matches!(section, Section::Description) && !text.is_empty() {
+                    text.push_str("\n\n");
+                }
+            } else {
+                if !text.is_empty() && !text.ends_with('\n') {
+                    text.push(' ');
+                }
+                text.push_str(line);
+            }
+        }
+    }
+    doc.description = doc.description.trim().to_owned();
+    doc
+}
+
+fn split_value_type(input: &str) -> (&str, &str) {
+    match input
+        .strip_prefix('{')
+        .and_then(|rest| rest.split_once("} "))
+    {
+        Some((value_type, rest)) => (value_type, rest),
+        None => ("Any", input),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::docs::{
+        FunctionParam,
+        function_docs,
+        parse_doc_comment,
+    };
+
+    #[test]
+    fn parses_doc_comments() {
+        let mut doc = parse_doc_comment(
+            r#"Performs a random chance check.
+
+@param {[`ValueType::UFraction`]} numerator The numerator of the probability.
+@param {[`ValueType::UFraction`]} [denominator] The denominator of the
+probability.
+@returns {[`ValueType::Boolean`]} Whether the check succeeded."#,
+        );
+        doc.name = "chance".to_owned();
+        pretty_assertions::assert_eq!(doc.description, "Performs a random chance check.");
+        pretty_assertions::assert_eq!(
+            doc.params[1],
+            FunctionParam {
+                name: "denominator".to_owned(),
+                value_type: "UFraction".to_owned(),
+                optional: true,
+                description: "The denominator of the probability.".to_owned(),
+            }
+        );
+        pretty_assertions::assert_eq!(doc.usage(), "chance: numerator [denominator]");
+        pretty_assertions::assert_eq!(
+            doc.returns,
+            Some((
+                "Boolean".to_owned(),
+                "Whether the check succeeded.".to_owned()
+            ))
+        );
+    }
+
+    #[test]
+    fn documents_engine_functions() {
+        let doc = &function_docs()["add_volatile"];
+        pretty_assertions::assert_eq!(doc.usage(), "add_volatile: [mon] [effect]");
+        pretty_assertions::assert_eq!(
+            doc.flags,
+            [(
+                "link".to_owned(),
+                "If set, the volatile effect is linked to the current effect.".to_owned()
+            )]
+        );
+        assert!(function_docs().contains_key("log_activate"));
+    }
+}
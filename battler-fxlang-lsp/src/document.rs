@@ -0,0 +1,339 @@
+use std::path::Path;
+
+use battler::{
+    BattleEvent,
+    effect::fxlang::ParsedEffect,
+};
+use battler_data::Id;
+use battler_local_data::LocalDataStore;
+
+use crate::json::{
+    self,
+    JsonError,
+    JsonKey,
+    JsonNode,
+    JsonString,
+};
+
+/// The kind of battle data stored in a data file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DataKind {
+    Ability,
+    Clause,
+    Condition,
+    Item,
+    Move,
+    Species,
+    Subroutine,
+}
+
+impl DataKind {
+    /// The kind of data stored in a file, based on the layout of [`LocalDataStore`].
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let file_name = path.file_name()?.to_str()?;
+        match file_name {
+            LocalDataStore::CLAUSES_FILE => return Some(Self::Clause),
+            LocalDataStore::CONDITIONS_FILE
+            | LocalDataStore::MOVE_BASES_FILE
+            | LocalDataStore::ABILITY_BASES_FILE
+            | LocalDataStore::ITEM_BASES_FILE => return Some(Self::Condition),
+            LocalDataStore::SUBROUTINES_FILE => return Some(Self::Subroutine),
+            _ => (),
+        }
+        if !file_name.ends_with(".json") {
+            return None;
+        }
+        match path.parent()?.file_name()?.to_str()? {
+            LocalDataStore::ABILITIES_DIR => Some(Self::Ability),
+            LocalDataStore::ITEMS_DIR => Some(Self::Item),
+            LocalDataStore::MOVES_DIR => Some(Self::Move),
+            LocalDataStore::SPECIES_DIR => Some(Self::Species),
+            _ => None,
+        }
+    }
+
+    /// The effect type used in effect IDs for this kind of data.
+    pub fn effect_type(&self) -> &'static str {
+        match self {
+            Self::Ability => "ability",
+            Self::Clause => "clause",
+            Self::Condition => "condition",
+            Self::Item => "item",
+            Self::Move => "move",
+            Self::Species => "species",
+            Self::Subroutine => "subroutine",
+        }
+    }
+
+    /// The fields of an entry that hold an effect, along with the suffix of their effect ID.
+    fn effect_fields(&self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Self::Ability | Self::Item | Self::Move => {
+                &[("effect", ""), ("condition", "/condition")]
+            }
+            Self::Clause | Self::Species => &[("effect", "")],
+            Self::Condition => &[("condition", "")],
+            Self::Subroutine => &[],
+        }
+    }
+}
+
+/// An fxlang effect in a data file.
+#[derive(Debug)]
+pub struct EffectNode<'d> {
+    /// The ID of the effect, which matches the IDs reported by the fxlang checker (e.g.,
+    /// `move:tackle/condition`).
+    pub id: String,
+    /// The key of the effect in its entry.
+    pub key: &'d JsonKey,
+    pub node: &'d JsonNode,
+}
+
+/// An fxlang program in a data file, which is either a callback of an effect or a subroutine.
+#[derive(Debug)]
+pub struct ProgramNode<'d> {
+    /// The ID of the effect or subroutine.
+    pub effect: String,
+    /// The name of the callback, which is [`None`] for subroutines.
+    pub callback: Option<String>,
+    /// The key of the callback or subroutine.
+    pub key: &'d JsonKey,
+    /// The event the callback runs for, if it is a valid callback.
+    pub event: Option<BattleEvent>,
+    /// Custom parameters of the program.
+    pub parameters: Vec<String>,
+    /// The program itself, which is a string or a list of programs.
+    pub program: &'d JsonNode,
+}
+
+impl<'d> ProgramNode<'d> {
+    /// Every statement of the program, in program order.
+    pub fn statements(&self) -> Vec<&'d JsonString> {
+        let mut statements = Vec::new();
+        collect_statements(self.program, &mut statements);
+        statements
+    }
+}
+
+fn collect_statements<'d>(node: &'d JsonNode, statements: &mut Vec<&'d JsonString>) {
+    match (node.string(), node.elements()) {
+        (Some(statement), _) => statements.push(statement),
+        (_, Some(elements)) => {
+            for element in elements {
+                collect_statements(element, statements);
+            }
+        }
+        _ => (),
+    }
+}
+
+/// A parsed battle data file.
+#[derive(Debug)]
+pub struct DataDocument {
+    pub kind: DataKind,
+    pub root: JsonNode,
+}
+
+impl DataDocument {
+    pub fn parse(kind: DataKind, text: &str) -> Result<Self, JsonError> {
+        Ok(Self {
+            kind,
+            root: json::parse(text)?,
+        })
+    }
+
+    /// The entries of the data file.
+    pub fn entries(&self) -> &[(JsonKey, JsonNode)] {
+        self.root.entries().unwrap_or_default()
+    }
+
+    /// Every effect in the data file.
+    pub fn effects(&self) -> Vec<EffectNode<'_>> {
+        let mut effects = Vec::new();
+        for (key, entry) in self.entries() {
+            let id = format!(
+                "{}:{}",
+                self.kind.effect_type(),
+                Id::from(key.name.as_str())
+            );
+            let Some(fields) = entry.entries() else {
+                continue;
+            };
+            for (field, suffix) in self.kind.effect_fields() {
+                if let Some((key, node)) = fields.iter().find(|(key, _)| key.name == *field) {
+                    effects.push(EffectNode {
+                        id: format!("{id}{suffix}"),
+                        key,
+                        node,
+                    });
+                }
+            }
+            if self.kind == DataKind::Move
+                && let Some(secondary_effects) = entry
+                    .get("secondary_effects")
+                    .and_then(|node| node.elements())
+            {
+                for (i, secondary_effect) in secondary_effects.iter().enumerate() {
+                    if let Some((key, node)) = secondary_effect
+                        .entries()
+                        .and_then(|fields| fields.iter().find(|(key, _)| key.name == "effect"))
+                    {
+                        effects.push(EffectNode {
+                            id: format!("{id}/secondary_effects/{i}"),
+                            key,
+                            node,
+                        });
+                    }
+                }
+            }
+        }
+        effects
+    }
+
+    /// Every program in the data file.
+    pub fn programs(&self) -> Vec<ProgramNode<'_>> {
+        if self.kind == DataKind::Subroutine {
+            return self
+                .entries()
+                .iter()
+                .filter_map(|(key, entry)| {
+                    Some(ProgramNode {
+                        effect: format!("subroutine:{}", Id::from(key.name.as_str())),
+                        callback: None,
+                        key,
+                        event: None,
+                        parameters: string_list(entry.get("parameters")),
+                        program: entry.get("program")?,
+                    })
+                })
+                .collect();
+        }
+
+        let mut programs = Vec::new();
+        for effect in self.effects() {
+            let Some(callbacks) = effect.node.get("callbacks").and_then(|node| node.entries())
+            else {
+                continue;
+            };
+            for (key, callback) in callbacks {
+                let (program, parameters) = match callback.get("program") {
+                    Some(program) => (
+                        program,
+                        string_list(
+                            callback
+                                .get("metadata")
+                                .and_then(|metadata| metadata.get("parameters")),
+                        ),
+                    ),
+                    None => (callback, Vec::new()),
+                };
+                programs.push(ProgramNode {
+                    effect: effect.id.clone(),
+                    callback: Some(key.name.clone()),
+                    key,
+                    event: ParsedEffect::callback_name_to_event_key(&key.name)
+                        .ok()
+                        .map(|(event, _)| event),
+                    parameters,
+                    program,
+                });
+            }
+        }
+        programs
+    }
+
+    /// The program containing the source offset.
+    pub fn program_at(&self, offset: usize) -> Option<ProgramNode<'_>> {
+        self.programs()
+            .into_iter()
+            .find(|program| program.program.contains(offset))
+    }
+}
+
+fn string_list(node: Option<&JsonNode>) -> Vec<String> {
+    node.and_then(|node| node.elements())
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|node| Some(node.string()?.value.clone()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use battler::BattleEvent;
+
+    use crate::document::{
+        DataDocument,
+        DataKind,
+    };
+
+    #[test]
+    fn determines_data_kind_from_path() {
+        pretty_assertions::assert_eq!(
+            DataKind::from_path(Path::new("data/moves/gen1.json")),
+            Some(DataKind::Move)
+        );
+        pretty_assertions::assert_eq!(
+            DataKind::from_path(Path::new("data/move-bases.json")),
+            Some(DataKind::Condition)
+        );
+        pretty_assertions::assert_eq!(DataKind::from_path(Path::new("data/type-chart.json")), None);
+    }
+
+    #[test]
+    fn finds_programs_of_effects() {
+        let document = DataDocument::parse(
+            DataKind::Move,
+            r#"{
+                "thunder": {
+                    "name": "Thunder",
+                    "secondary_effects": [{ "effect": { "callbacks": { "on_hit": "return" } } }],
+                    "condition": {
+                        "callbacks": {
+                            "on_activate": {
+                                "program": ["if true:", ["return"]],
+                                "metadata": { "parameters": ["value"] }
+                            }
+                        }
+                    }
+                }
+            }"#,
+        )
+        .unwrap();
+        let programs = document.programs();
+        pretty_assertions::assert_eq!(
+            programs
+                .iter()
+                .map(|program| (
+                    program.effect.as_str(),
+                    program.callback.as_deref(),
+                    program.event,
+                    program.parameters.clone(),
+                    program
+                        .statements()
+                        .into_iter()
+                        .map(|statement| statement.value.as_str())
+                        .collect::<Vec<_>>(),
+                ))
+                .collect::<Vec<_>>(),
+            [
+                (
+                    "move:thunder/condition",
+                    Some("on_activate"),
+                    Some(BattleEvent::Activate),
+                    vec!["value".to_owned()],
+                    vec!["if true:", "return"],
+                ),
+                (
+                    "move:thunder/secondary_effects/0",
+                    Some("on_hit"),
+                    Some(BattleEvent::Hit),
+                    Vec::new(),
+                    vec!["return"],
+                ),
+            ]
+        );
+    }
+}
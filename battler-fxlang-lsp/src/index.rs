@@ -0,0 +1,255 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+use battler::effect::EffectHandle;
+use battler_data::{
+    Id,
+    SubroutineData,
+};
+use battler_local_data::LocalDataStore;
+use lsp_types::{
+    Location,
+    Url,
+};
+
+use crate::{
+    document::{
+        DataDocument,
+        DataKind,
+    },
+    line_index::LineIndex,
+};
+
+/// An entry of a data file.
+#[derive(Debug, Clone)]
+struct IndexedEntry {
+    /// The location of the key of the entry.
+    key: Location,
+    /// The location of the key of the condition on the entry, if any.
+    condition: Option<Location>,
+}
+
+/// The entries of a single data file.
+#[derive(Debug, Default, Clone)]
+struct IndexedFile {
+    kind: Option<DataKind>,
+    entries: BTreeMap<Id, IndexedEntry>,
+    subroutines: BTreeMap<Id, SubroutineData>,
+}
+
+/// An index of every entry in a battle data directory.
+///
+/// The index is built from files on disk and kept up to date with the text of open documents, so
+/// that definitions and subroutines can be resolved across files.
+#[derive(Debug, Default)]
+pub struct DataIndex {
+    files: BTreeMap<PathBuf, IndexedFile>,
+}
+
+impl DataIndex {
+    /// Indexes every data file under the data directory.
+    ///
+    /// Files that cannot be read or parsed are skipped.
+    pub fn load(root: &Path) -> Self {
+        let mut index = Self::default();
+        let mut paths = [
+            LocalDataStore::CLAUSES_FILE,
+            LocalDataStore::CONDITIONS_FILE,
+            LocalDataStore::SUBROUTINES_FILE,
+            LocalDataStore::MOVE_BASES_FILE,
+            LocalDataStore::ABILITY_BASES_FILE,
+            LocalDataStore::ITEM_BASES_FILE,
+        ]
+        .into_iter()
+        .map(|file| root.join(file))
+        .collect::<Vec<_>>();
+        for dir in [
+            LocalDataStore::ABILITIES_DIR,
+            LocalDataStore::ITEMS_DIR,
+            LocalDataStore::MOVES_DIR,
+            LocalDataStore::SPECIES_DIR,
+        ] {
+            if let Ok(entries) = fs::read_dir(root.join(dir)) {
+                paths.extend(entries.filter_map(|entry| Some(entry.ok()?.path())));
+            }
+        }
+        for path in paths {
+            if let Ok(text) = fs::read_to_string(&path) {
+                index.update(&path, &text);
+            }
+        }
+        index
+    }
+
+    /// Updates the entries of a single data file.
+    pub fn update(&mut self, path: &Path, text: &str) {
+        let (Some(kind), Ok(uri)) = (DataKind::from_path(path), Url::from_file_path(path)) else {
+            return;
+        };
+        let Ok(document) = DataDocument::parse(kind, text) else {
+            // Keep the previous entries while the document is invalid.
+            return;
+        };
+        let line_index = LineIndex::new(text);
+        let location = |range| Location::new(uri.clone(), line_index.range(range));
+
+        let mut file = IndexedFile {
+            kind: Some(kind),
+            ..Default::default()
+        };
+        for (key, entry) in document.entries() {
+            let id = Id::from(key.name.as_str());
+            let condition = match kind {
+                DataKind::Condition => None,
+                _ => entry
+                    .entries()
+                    .and_then(|fields| fields.iter().find(|(key, _)| key.name == "condition"))
+                    .map(|(key, _)| location(key.range.clone())),
+            };
+            if kind == DataKind::Subroutine
+                && let Ok(subroutine) = serde_json::from_value(entry.to_value())
+            {
+                file.subroutines.insert(id.clone(), subroutine);
+            }
+            file.entries.insert(
+                id,
+                IndexedEntry {
+                    key: location(key.range.clone()),
+                    condition,
+                },
+            );
+        }
+        self.files.insert(path.to_owned(), file);
+    }
+
+    fn entry(&self, kind: DataKind, id: &Id) -> Option<&IndexedEntry> {
+        self.files
+            .values()
+            .filter(|file| file.kind == Some(kind))
+            .find_map(|file| file.entries.get(id))
+    }
+
+    /// Every subroutine, by ID.
+    pub fn subroutines(&self) -> BTreeMap<Id, SubroutineData> {
+        self.files
+            .values()
+            .flat_map(|file| file.subroutines.clone())
+            .collect()
+    }
+
+    /// Looks up a subroutine.
+    pub fn subroutine(&self, id: &Id) -> Option<&SubroutineData> {
+        self.files
+            .values()
+            .find_map(|file| file.subroutines.get(id))
+    }
+
+    /// The location of a subroutine.
+    pub fn subroutine_location(&self, id: &Id) -> Option<Location> {
+        self.entry(DataKind::Subroutine, id)
+            .map(|entry| entry.key.clone())
+    }
+
+    /// The location of an effect, by its fxlang ID (e.g., `movecondition:protect`).
+    pub fn effect_location(&self, fxlang_id: &str) -> Option<Location> {
+        let (kind, id, condition) = match EffectHandle::from_fxlang_id(fxlang_id) {
+            EffectHandle::Ability(id) => (DataKind::Ability, id, false),
+            EffectHandle::AbilityCondition(id) => (DataKind::Ability, id, true),
+            EffectHandle::Clause(id) => (DataKind::Clause, id, false),
+            EffectHandle::Item(id) => (DataKind::Item, id, false),
+            EffectHandle::ItemCondition(id) => (DataKind::Item, id, true),
+            EffectHandle::InactiveMove(id) => (DataKind::Move, id, false),
+            EffectHandle::MoveCondition(id) => (DataKind::Move, id, true),
+            EffectHandle::Species(id) => (DataKind::Species, id, false),
+            EffectHandle::Condition(id) => (DataKind::Condition, id, false),
+            _ => return None,
+        };
+        let entry = self.entry(kind, &id)?;
+        match condition {
+            true => entry.condition.clone().or_else(|| Some(entry.key.clone())),
+            false => Some(entry.key.clone()),
+        }
+    }
+
+    /// The location of an effect referenced by name in a program.
+    ///
+    /// Effects are looked up in the same order as the battle engine looks up effects by ID.
+    pub fn effect_location_by_id(&self, id: &Id) -> Option<Location> {
+        if let Some(entry) = self.entry(DataKind::Condition, id) {
+            return Some(entry.key.clone());
+        }
+        for kind in [DataKind::Ability, DataKind::Item, DataKind::Move] {
+            if let Some(location) = self
+                .entry(kind, id)
+                .and_then(|entry| entry.condition.clone())
+            {
+                return Some(location);
+            }
+        }
+        [DataKind::Clause, DataKind::Species]
+            .into_iter()
+            .find_map(|kind| self.entry(kind, id))
+            .map(|entry| entry.key.clone())
+    }
+}
+
+/// Finds the battle data directory containing a file, which is the nearest ancestor containing
+/// the conditions file.
+pub fn find_data_root(path: &Path) -> Option<PathBuf> {
+    path.ancestors()
+        .skip(1)
+        .find(|dir| dir.join(LocalDataStore::CONDITIONS_FILE).is_file())
+        .map(|dir| dir.to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use battler_data::Id;
+
+    use crate::index::DataIndex;
+
+    #[test]
+    fn resolves_effect_locations() {
+        let mut index = DataIndex::default();
+        index.update(
+            Path::new("/data/moves/moves.json"),
+            "{\n  \"protect\": {\n    \"condition\": {}\n  }\n}",
+        );
+        index.update(Path::new("/data/conditions.json"), "{\n  \"stall\": {}\n}");
+
+        let location = index.effect_location("movecondition:protect").unwrap();
+        pretty_assertions::assert_eq!(location.uri.path(), "/data/moves/moves.json");
+        pretty_assertions::assert_eq!(location.range.start.line, 2);
+        pretty_assertions::assert_eq!(
+            index
+                .effect_location("move:protect")
+                .unwrap()
+                .range
+                .start
+                .line,
+            1
+        );
+        pretty_assertions::assert_eq!(
+            index.effect_location("stall").unwrap().uri.path(),
+            "/data/conditions.json"
+        );
+        pretty_assertions::assert_eq!(
+            index
+                .effect_location_by_id(&Id::from("protect"))
+                .unwrap()
+                .range
+                .start
+                .line,
+            2
+        );
+        assert!(index.effect_location("ability:protect").is_none());
+    }
+}
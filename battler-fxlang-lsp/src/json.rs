@@ -0,0 +1,387 @@
+//! A JSON parser that keeps the location of every value in the source text.
+//!
+//! fxlang programs are embedded in JSON battle data, so the location of a program in the document
+//! is needed to report diagnostics and resolve positions. Locations are byte offsets into the
+//! source text.
+
+use std::ops::Range;
+
+use anyhow::{
+    Error,
+    Result,
+};
+
+/// A string value, with the location of each decoded byte in the source text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonString {
+    pub value: String,
+    /// The source offset of each byte of the decoded value, followed by the offset of the closing
+    /// quote.
+    offsets: Vec<usize>,
+}
+
+impl JsonString {
+    /// The source offset of a byte in the decoded value.
+    ///
+    /// An offset past the end of the value maps to the closing quote.
+    pub fn source_offset(&self, offset: usize) -> usize {
+        self.offsets[offset.min(self.offsets.len() - 1)]
+    }
+
+    /// The source range of a range in the decoded value.
+    pub fn source_range(&self, range: Range<usize>) -> Range<usize> {
+        self.source_offset(range.start)..self.source_offset(range.end)
+    }
+
+    /// The offset in the decoded value of a source offset, if it is inside of the string.
+    pub fn value_offset(&self, source_offset: usize) -> Option<usize> {
+        if source_offset < self.offsets[0] || source_offset > self.offsets[self.offsets.len() - 1] {
+            return None;
+        }
+        Some(
+            self.offsets
+                .iter()
+                .position(|offset| *offset >= source_offset)
+                .unwrap_or(self.value.len()),
+        )
+    }
+}
+
+/// A JSON value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JsonValue {
+    Null,
+    Bool(bool),
+    Number(serde_json::Number),
+    String(JsonString),
+    Array(Vec<JsonNode>),
+    Object(Vec<(JsonKey, JsonNode)>),
+}
+
+/// A key of a JSON object.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonKey {
+    pub name: String,
+    pub range: Range<usize>,
+}
+
+/// A JSON value, with its location in the source text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonNode {
+    pub value: JsonValue,
+    pub range: Range<usize>,
+}
+
+impl JsonNode {
+    /// Looks up a member of an object.
+    pub fn get(&self, key: &str) -> Option<&JsonNode> {
+        self.entries()?
+            .iter()
+            .find(|(entry, _)| entry.name == key)
+            .map(|(_, node)| node)
+    }
+
+    /// The entries of an object.
+    pub fn entries(&self) -> Option<&[(JsonKey, JsonNode)]> {
+        match &self.value {
+            JsonValue::Object(entries) => Some(entries),
+            _ => None,
+        }
+    }
+
+    /// The elements of an array.
+    pub fn elements(&self) -> Option<&[JsonNode]> {
+        match &self.value {
+            JsonValue::Array(elements) => Some(elements),
+            _ => None,
+        }
+    }
+
+    /// The string value.
+    pub fn string(&self) -> Option<&JsonString> {
+        match &self.value {
+            JsonValue::String(string) => Some(string),
+            _ => None,
+        }
+    }
+
+    /// Checks if the node contains the source offset.
+    pub fn contains(&self, offset: usize) -> bool {
+        self.range.start <= offset && offset <= self.range.end
+    }
+
+    /// Converts the node to a [`serde_json::Value`], for deserializing battle data.
+    pub fn to_value(&self) -> serde_json::Value {
+        match &self.value {
+            JsonValue::Null => serde_json::Value::Null,
+            JsonValue::Bool(value) => serde_json::Value::Bool(*value),
+            JsonValue::Number(number) => serde_json::Value::Number(number.clone()),
+            JsonValue::String(string) => serde_json::Value::String(string.value.clone()),
+            JsonValue::Array(elements) => {
+                serde_json::Value::Array(elements.iter().map(|node| node.to_value()).collect())
+            }
+            JsonValue::Object(entries) => serde_json::Value::Object(
+                entries
+                    .iter()
+                    .map(|(key, node)| (key.name.clone(), node.to_value()))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+/// An error from parsing a JSON document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonError {
+    pub message: String,
+    pub offset: usize,
+}
+
+impl From<JsonError> for Error {
+    fn from(err: JsonError) -> Self {
+        Error::msg(format!("{} at offset {}", err.message, err.offset))
+    }
+}
+
+/// Parses a JSON document.
+pub fn parse(input: &str) -> Result<JsonNode, JsonError> {
+    let mut parser = Parser {
+        input: input.as_bytes(),
+        offset: 0,
+    };
+    let node = parser.parse_value()?;
+    parser.skip_whitespace();
+    if parser.offset < parser.input.len() {
+        return Err(parser.error("unexpected trailing characters"));
+    }
+    Ok(node)
+}
+
+struct Parser<'s> {
+    input: &'s [u8],
+    offset: usize,
+}
+
+impl<'s> Parser<'s> {
+    fn error(&self, message: &str) -> JsonError {
+        JsonError {
+            message: message.to_owned(),
+            offset: self.offset,
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.input.get(self.offset).cloned()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ' | b'\t' | b'\r' | b'\n') = self.peek() {
+            self.offset += 1;
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), JsonError> {
+        self.skip_whitespace();
+        if self.peek() != Some(byte) {
+            return Err(self.error(&format!("expected {}", byte as char)));
+        }
+        self.offset += 1;
+        Ok(())
+    }
+
+    fn expect_literal(&mut self, literal: &str) -> Result<(), JsonError> {
+        if !self.input[self.offset..].starts_with(literal.as_bytes()) {
+            return Err(self.error("invalid literal"));
+        }
+        self.offset += literal.len();
+        Ok(())
+    }
+
+    fn parse_value(&mut self) -> Result<JsonNode, JsonError> {
+        self.skip_whitespace();
+        let start = self.offset;
+        let value = match self.peek() {
+            Some(b'{') => self.parse_object()?,
+            Some(b'[') => self.parse_array()?,
+            Some(b'"') => JsonValue::String(self.parse_string()?),
+            Some(b't') => {
+                self.expect_literal("true")?;
+                JsonValue::Bool(true)
+            }
+            Some(b'f') => {
+                self.expect_literal("false")?;
+                JsonValue::Bool(false)
+            }
+            Some(b'n') => {
+                self.expect_literal("null")?;
+                JsonValue::Null
+            }
+            Some(b'-' | b'0'..=b'9') => {
+                while let Some(b'-' | b'+' | b'.' | b'e' | b'E' | b'0'..=b'9') = self.peek() {
+                    self.offset += 1;
+                }
+                let number = std::str::from_utf8(&self.input[start..self.offset])
+                    .ok()
+                    .and_then(|number| serde_json::from_str(number).ok())
+                    .ok_or_else(|| self.error("invalid number"))?;
+                JsonValue::Number(number)
+            }
+            Some(_) => return Err(self.error("unexpected character")),
+            None => return Err(self.error("unexpected end of input")),
+        };
+        Ok(JsonNode {
+            value,
+            range: start..self.offset,
+        })
+    }
+
+    fn parse_object(&mut self) -> Result<JsonValue, JsonError> {
+        self.expect(b'{')?;
+        let mut entries = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(b'}') {
+            self.offset += 1;
+            return Ok(JsonValue::Object(entries));
+        }
+        loop {
+            self.skip_whitespace();
+            let start = self.offset;
+            if self.peek() != Some(b'"') {
+                return Err(self.error("expected object key"));
+            }
+            let name = self.parse_string()?.value;
+            let key = JsonKey {
+                name,
+                range: start..self.offset,
+            };
+            self.expect(b':')?;
+            let value = self.parse_value()?;
+            entries.push((key, value));
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.offset += 1,
+                Some(b'}') => {
+                    self.offset += 1;
+                    return Ok(JsonValue::Object(entries));
+                }
+                _ => return Err(self.error("expected , or }")),
+            }
+        }
+    }
+
+    fn parse_array(&mut self) -> Result<JsonValue, JsonError> {
+        self.expect(b'[')?;
+        let mut elements = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(b']') {
+            self.offset += 1;
+            return Ok(JsonValue::Array(elements));
+        }
+        loop {
+            elements.push(self.parse_value()?);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.offset += 1,
+                Some(b']') => {
+                    self.offset += 1;
+                    return Ok(JsonValue::Array(elements));
+                }
+                _ => return Err(self.error("expected , or ]")),
+            }
+        }
+    }
+
+    fn parse_string(&mut self) -> Result<JsonString, JsonError> {
+        self.expect(b'"')?;
+        let mut value = Vec::new();
+        let mut offsets = Vec::new();
+        loop {
+            let start = self.offset;
+            match self.peek() {
+                None | Some(b'\n') => return Err(self.error("unterminated string")),
+                Some(b'"') => {
+                    offsets.push(start);
+                    self.offset += 1;
+                    break;
+                }
+                Some(b'\\') => {
+                    self.offset += 1;
+                    let decoded = match self.peek() {
+                        Some(b'"') => '"',
+                        Some(b'\\') => '\\',
+                        Some(b'/') => '/',
+                        Some(b'b') => '\u{8}',
+                        Some(b'f') => '\u{c}',
+                        Some(b'n') => '\n',
+                        Some(b'r') => '\r',
+                        Some(b't') => '\t',
+                        Some(b'u') => {
+                            let hex = self
+                                .input
+                                .get(self.offset + 1..self.offset + 5)
+                                .and_then(|hex| std::str::from_utf8(hex).ok())
+                                .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                                .ok_or_else(|| self.error("invalid unicode escape"))?;
+                            self.offset += 4;
+                            char::from_u32(hex).unwrap_or(char::REPLACEMENT_CHARACTER)
+                        }
+                        _ => return Err(self.error("invalid escape")),
+                    };
+                    self.offset += 1;
+                    let mut buffer = [0; 4];
+                    for byte in decoded.encode_utf8(&mut buffer).bytes() {
+                        value.push(byte);
+                        offsets.push(start);
+                    }
+                }
+                Some(byte) => {
+                    value.push(byte);
+                    offsets.push(start);
+                    self.offset += 1;
+                }
+            }
+        }
+        let value = String::from_utf8(value).map_err(|_| self.error("invalid utf-8"))?;
+        Ok(JsonString { value, offsets })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::json::{
+        JsonValue,
+        parse,
+    };
+
+    #[test]
+    fn parses_locations_of_values() {
+        let input = r#"{ "a": [1, "b"], "c": null }"#;
+        let node = parse(input).unwrap();
+        let a = node.get("a").unwrap();
+        pretty_assertions::assert_eq!(&input[a.range.clone()], r#"[1, "b"]"#);
+        let b = &a.elements().unwrap()[1];
+        pretty_assertions::assert_eq!(&input[b.range.clone()], r#""b""#);
+        pretty_assertions::assert_eq!(node.get("c").unwrap().value, JsonValue::Null);
+        pretty_assertions::assert_eq!(
+            node.to_value(),
+            serde_json::json!({ "a": [1, "b"], "c": null })
+        );
+    }
+
+    #[test]
+    fn maps_escaped_strings_to_source() {
+        let input = r#""a\"bc""#;
+        let node = parse(input).unwrap();
+        let string = node.string().unwrap();
+        pretty_assertions::assert_eq!(string.value, "a\"bc");
+        pretty_assertions::assert_eq!(string.source_offset(2), 4);
+        pretty_assertions::assert_eq!(string.source_range(2..4), 4..6);
+        pretty_assertions::assert_eq!(string.value_offset(4), Some(2));
+        pretty_assertions::assert_eq!(string.value_offset(0), None);
+    }
+
+    #[test]
+    fn fails_invalid_json() {
+        pretty_assertions::assert_eq!(parse(r#"{ "a": }"#).unwrap_err().offset, 7);
+    }
+}
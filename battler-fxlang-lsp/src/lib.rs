@@ -0,0 +1,32 @@
+//! A language server for fxlang programs embedded in battle data.
+//!
+//! The server works on the JSON files of a battle data directory, laid out like
+//! [`battler_local_data::LocalDataStore`]. It provides:
+//! - Diagnostics for parse errors and static check errors, on the statement that caused them.
+//! - Hover documentation for built-in functions, subroutines, and input variables.
+//! - Completion of variables and functions.
+//! - Go-to-definition for delegate effects, subroutines, and effects referenced by name.
+//!
+//! Native functions registered by applications are not known to the server, so calls to them are
+//! reported as undefined.
+
+mod analysis;
+mod docs;
+mod document;
+mod index;
+mod json;
+mod line_index;
+mod server;
+
+pub use analysis::Analysis;
+pub use docs::{
+    FunctionDoc,
+    FunctionParam,
+    function_docs,
+};
+pub use document::DataKind;
+pub use index::{
+    DataIndex,
+    find_data_root,
+};
+pub use server::run;
@@ -0,0 +1,75 @@
+use std::ops::Range;
+
+use lsp_types::Position;
+
+/// Converts between byte offsets and LSP positions in a document.
+///
+/// LSP positions count characters in UTF-16 code units by default.
+#[derive(Debug, Clone)]
+pub struct LineIndex {
+    text: String,
+    /// The byte offset of the start of every line.
+    line_starts: Vec<usize>,
+}
+
+impl LineIndex {
+    pub fn new(text: &str) -> Self {
+        let line_starts = [0]
+            .into_iter()
+            .chain(text.match_indices('\n').map(|(i, _)| i + 1))
+            .collect();
+        Self {
+            text: text.to_owned(),
+            line_starts,
+        }
+    }
+
+    /// The position of a byte offset.
+    pub fn position(&self, offset: usize) -> Position {
+        let offset = offset.min(self.text.len());
+        let line = self.line_starts.partition_point(|start| *start <= offset) - 1;
+        let character = self.text[self.line_starts[line]..offset]
+            .chars()
+            .map(|c| c.len_utf16())
+            .sum::<usize>();
+        Position::new(line as u32, character as u32)
+    }
+
+    /// The range of positions of a byte range.
+    pub fn range(&self, range: Range<usize>) -> lsp_types::Range {
+        lsp_types::Range::new(self.position(range.start), self.position(range.end))
+    }
+
+    /// The byte offset of a position.
+    pub fn offset(&self, position: Position) -> usize {
+        let Some(start) = self.line_starts.get(position.line as usize) else {
+            return self.text.len();
+        };
+        let mut character = 0;
+        for (i, c) in self.text[*start..].char_indices() {
+            if character >= position.character as usize || c == '\n' {
+                return start + i;
+            }
+            character += c.len_utf16();
+        }
+        self.text.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use lsp_types::Position;
+
+    use crate::line_index::LineIndex;
+
+    #[test]
+    fn converts_offsets_and_positions() {
+        let index = LineIndex::new("ab\n\"é\": 1\n");
+        pretty_assertions::assert_eq!(index.position(0), Position::new(0, 0));
+        pretty_assertions::assert_eq!(index.position(4), Position::new(1, 1));
+        pretty_assertions::assert_eq!(index.position(6), Position::new(1, 2));
+        pretty_assertions::assert_eq!(index.offset(Position::new(1, 2)), 6);
+        pretty_assertions::assert_eq!(index.offset(Position::new(1, 100)), 10);
+        pretty_assertions::assert_eq!(index.offset(Position::new(5, 0)), 11);
+    }
+}
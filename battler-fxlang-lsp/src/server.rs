@@ -0,0 +1,275 @@
+use std::{
+    collections::HashMap,
+    fs,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+use anyhow::{
+    Error,
+    Result,
+};
+use lsp_server::{
+    Connection,
+    Message,
+    Notification,
+    Request,
+    RequestId,
+    Response,
+};
+use lsp_types::{
+    CompletionOptions,
+    CompletionResponse,
+    DidChangeTextDocumentParams,
+    DidCloseTextDocumentParams,
+    DidOpenTextDocumentParams,
+    GotoDefinitionResponse,
+    HoverProviderCapability,
+    OneOf,
+    PublishDiagnosticsParams,
+    ServerCapabilities,
+    TextDocumentPositionParams,
+    TextDocumentSyncCapability,
+    TextDocumentSyncKind,
+    Url,
+    notification::{
+        self,
+        Notification as _,
+    },
+    request::{
+        self,
+        Request as _,
+    },
+};
+
+use crate::{
+    analysis::Analysis,
+    document::DataKind,
+    index::{
+        DataIndex,
+        find_data_root,
+    },
+};
+
+/// An open battle data document.
+struct OpenDocument {
+    path: PathBuf,
+    root: PathBuf,
+    analysis: Analysis,
+}
+
+/// The state of the language server.
+#[derive(Default)]
+struct Server {
+    documents: HashMap<Url, OpenDocument>,
+    /// Indexes of data directories with open documents, by the path of the data directory.
+    indexes: HashMap<PathBuf, DataIndex>,
+}
+
+impl Server {
+    fn open(&mut self, uri: Url, text: &str) -> Vec<Url> {
+        let Some((path, kind)) = uri
+            .to_file_path()
+            .ok()
+            .and_then(|path| Some((path.clone(), DataKind::from_path(&path)?)))
+        else {
+            return Vec::new();
+        };
+        let root = find_data_root(&path)
+            .or_else(|| path.parent().map(Path::to_owned))
+            .unwrap_or_default();
+        self.indexes
+            .entry(root.clone())
+            .or_insert_with(|| DataIndex::load(&root))
+            .update(&path, text);
+        self.documents.insert(
+            uri.clone(),
+            OpenDocument {
+                path,
+                root: root.clone(),
+                analysis: Analysis::new(kind, text),
+            },
+        );
+
+        // Subroutines are callable from every program, so all documents must be checked again.
+        match kind {
+            DataKind::Subroutine => self
+                .documents
+                .iter()
+                .filter(|(_, document)| document.root == root)
+                .map(|(uri, _)| uri.clone())
+                .collect(),
+            _ => Vec::from_iter([uri]),
+        }
+    }
+
+    fn close(&mut self, uri: &Url) {
+        let Some(document) = self.documents.remove(uri) else {
+            return;
+        };
+        // Unsaved changes are discarded, so the file on disk is indexed again.
+        if let (Some(index), Ok(text)) = (
+            self.indexes.get_mut(&document.root),
+            fs::read_to_string(&document.path),
+        ) {
+            index.update(&document.path, &text);
+        }
+    }
+
+    fn document(&self, uri: &Url) -> Option<(&Analysis, &DataIndex)> {
+        let document = self.documents.get(uri)?;
+        Some((&document.analysis, self.indexes.get(&document.root)?))
+    }
+
+    fn publish_diagnostics(&self, connection: &Connection, uri: Url) -> Result<()> {
+        let diagnostics = self
+            .document(&uri)
+            .map(|(analysis, index)| analysis.diagnostics(index))
+            .unwrap_or_default();
+        send_notification::<notification::PublishDiagnostics>(
+            connection,
+            PublishDiagnosticsParams::new(uri, diagnostics, None),
+        )
+    }
+
+    fn handle_notification(
+        &mut self,
+        connection: &Connection,
+        notification: Notification,
+    ) -> Result<()> {
+        let uris = match notification.method.as_str() {
+            notification::DidOpenTextDocument::METHOD => {
+                let params: DidOpenTextDocumentParams =
+                    serde_json::from_value(notification.params)?;
+                self.open(params.text_document.uri, &params.text_document.text)
+            }
+            notification::DidChangeTextDocument::METHOD => {
+                let params: DidChangeTextDocumentParams =
+                    serde_json::from_value(notification.params)?;
+                // Documents are synced in full, so the last change is the entire document.
+                match params.content_changes.into_iter().last() {
+                    Some(change) => self.open(params.text_document.uri, &change.text),
+                    None => Vec::new(),
+                }
+            }
+            notification::DidCloseTextDocument::METHOD => {
+                let params: DidCloseTextDocumentParams =
+                    serde_json::from_value(notification.params)?;
+                self.close(&params.text_document.uri);
+                send_notification::<notification::PublishDiagnostics>(
+                    connection,
+                    PublishDiagnosticsParams::new(params.text_document.uri, Vec::new(), None),
+                )?;
+                Vec::new()
+            }
+            _ => Vec::new(),
+        };
+        for uri in uris {
+            self.publish_diagnostics(connection, uri)?;
+        }
+        Ok(())
+    }
+
+    fn handle_request(&self, request: Request) -> Result<serde_json::Value> {
+        match request.method.as_str() {
+            request::HoverRequest::METHOD => {
+                let params = position_params::<request::HoverRequest>(request)?;
+                let hover = self
+                    .document(&params.text_document.uri)
+                    .and_then(|(analysis, index)| analysis.hover(params.position, index));
+                Ok(serde_json::to_value(hover)?)
+            }
+            request::Completion::METHOD => {
+                let params = position_params::<request::Completion>(request)?;
+                let completions = self
+                    .document(&params.text_document.uri)
+                    .map(|(analysis, index)| analysis.completions(params.position, index))
+                    .unwrap_or_default();
+                Ok(serde_json::to_value(CompletionResponse::Array(
+                    completions,
+                ))?)
+            }
+            request::GotoDefinition::METHOD => {
+                let params = position_params::<request::GotoDefinition>(request)?;
+                let definition = self
+                    .document(&params.text_document.uri)
+                    .and_then(|(analysis, index)| analysis.definition(params.position, index))
+                    .map(GotoDefinitionResponse::Scalar);
+                Ok(serde_json::to_value(definition)?)
+            }
+            method => Err(Error::msg(format!("unsupported request {method}"))),
+        }
+    }
+}
+
+/// The text document position of a request, which is flattened into the parameters of every
+/// supported request.
+fn position_params<R>(request: Request) -> Result<TextDocumentPositionParams>
+where
+    R: request::Request,
+{
+    let (_, params) = request
+        .extract::<serde_json::Value>(R::METHOD)
+        .map_err(|err| Error::msg(format!("{err:?}")))?;
+    Ok(serde_json::from_value(params)?)
+}
+
+fn send_notification<N>(connection: &Connection, params: N::Params) -> Result<()>
+where
+    N: notification::Notification,
+{
+    connection
+        .sender
+        .send(Message::Notification(Notification::new(
+            N::METHOD.to_owned(),
+            params,
+        )))?;
+    Ok(())
+}
+
+fn capabilities() -> ServerCapabilities {
+    ServerCapabilities {
+        text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+        hover_provider: Some(HoverProviderCapability::Simple(true)),
+        completion_provider: Some(CompletionOptions {
+            trigger_characters: Some(Vec::from_iter(["$", "(", ":"].map(String::from))),
+            ..Default::default()
+        }),
+        definition_provider: Some(OneOf::Left(true)),
+        ..Default::default()
+    }
+}
+
+/// Runs the language server on the connection until the client shuts it down.
+pub fn run(connection: &Connection) -> Result<()> {
+    connection.initialize(serde_json::to_value(capabilities())?)?;
+    let mut server = Server::default();
+    for message in &connection.receiver {
+        match message {
+            Message::Request(request) => {
+                if connection.handle_shutdown(&request)? {
+                    return Ok(());
+                }
+                let id: RequestId = request.id.clone();
+                let response = match server.handle_request(request) {
+                    Ok(result) => Response::new_ok(id, result),
+                    Err(err) => Response::new_err(
+                        id,
+                        lsp_server::ErrorCode::InvalidRequest as i32,
+                        format!("{err:#}"),
+                    ),
+                };
+                connection.sender.send(Message::Response(response))?;
+            }
+            Message::Notification(notification) => {
+                if let Err(err) = server.handle_notification(connection, notification) {
+                    eprintln!("{err:#}");
+                }
+            }
+            Message::Response(_) => (),
+        }
+    }
+    Ok(())
+}
@@ -22,11 +22,26 @@ use battler_data::{
     ItemData,
     MoveData,
     SpeciesData,
+    SubroutineData,
     TypeChart,
     deserialize_aliases,
 };
 use serde::de::DeserializeOwned;
 
+mod overlay;
+mod watched;
+
+pub use battler_data::ResourceType;
+pub use overlay::{
+    DataLayer,
+    OverlayDataStore,
+    ResourceSource,
+};
+pub use watched::{
+    DataStoreWatcher,
+    WatchedLocalDataStore,
+};
+
 /// An implementation of [`DataStore`] that reads all data locally from disk.
 pub struct LocalDataStore {
     root: String,
@@ -38,6 +53,7 @@ pub struct LocalDataStore {
     pub items: HashMap<Id, ItemData>,
     pub moves: HashMap<Id, MoveData>,
     pub species: HashMap<Id, SpeciesData>,
+    pub subroutines: HashMap<Id, SubroutineData>,
 
     abilities_by_name: RwLock<HashMap<String, Id>>,
     clauses_by_name: RwLock<HashMap<String, Id>>,
@@ -56,6 +72,8 @@ impl LocalDataStore {
     pub const CLAUSES_FILE: &str = "clauses.json";
     /// Conditions file name.
     pub const CONDITIONS_FILE: &str = "conditions.json";
+    /// Subroutines file name.
+    pub const SUBROUTINES_FILE: &str = "subroutines.json";
     /// Move bases file name.
     pub const MOVE_BASES_FILE: &str = "move-bases.json";
     /// Ability bases file name.
@@ -81,7 +99,19 @@ impl LocalDataStore {
                 "Root directory for LocalDataStore ({root}) does not exist",
             )));
         }
-        let mut store = Self {
+        let mut store = Self::empty(root);
+        store.initialize()?;
+        Ok(store)
+    }
+
+    /// Creates a new instance of [`LocalDataStore`] that reads from the root directory at the given
+    /// environment variable.
+    pub fn new_from_env(env_var: &str) -> Result<Self> {
+        Self::new(env::var(env_var).context("DATA_DIR not defined")?)
+    }
+
+    fn empty(root: String) -> Self {
+        Self {
             root,
             type_chart: TypeChart::new(),
             abilities: HashMap::default(),
@@ -91,21 +121,14 @@ impl LocalDataStore {
             items: HashMap::default(),
             moves: HashMap::default(),
             species: HashMap::default(),
+            subroutines: HashMap::default(),
             abilities_by_name: RwLock::new(HashMap::default()),
             clauses_by_name: RwLock::new(HashMap::default()),
             conditions_by_name: RwLock::new(HashMap::default()),
             items_by_name: RwLock::new(HashMap::default()),
             moves_by_name: RwLock::new(HashMap::default()),
             species_by_name: RwLock::new(HashMap::default()),
-        };
-        store.initialize()?;
-        Ok(store)
-    }
-
-    /// Creates a new instance of [`LocalDataStore`] that reads from the root directory at the given
-    /// environment variable.
-    pub fn new_from_env(env_var: &str) -> Result<Self> {
-        Self::new(env::var(env_var).context("DATA_DIR not defined")?)
+        }
     }
 
     fn initialize(&mut self) -> Result<()> {
@@ -137,6 +160,16 @@ impl LocalDataStore {
         .context("failed to parse conditions")?;
         self.conditions.extend(conditions);
 
+        // Subroutines are optional, so data directories without them still load.
+        let subroutines_path = Path::new(&self.root).join(Self::SUBROUTINES_FILE);
+        if subroutines_path.is_file() {
+            let subroutines: HashMap<Id, SubroutineData> = serde_json::from_reader(
+                File::open(subroutines_path).context("failed to read subroutines")?,
+            )
+            .context("failed to parse subroutines")?;
+            self.subroutines.extend(subroutines);
+        }
+
         let move_bases: HashMap<Id, ConditionData> = serde_json::from_reader(
             File::open(Path::new(&self.root).join(Self::MOVE_BASES_FILE))
                 .context("failed to read move bases")?,
@@ -194,23 +227,49 @@ impl LocalDataStore {
         );
         Ok(map)
     }
+
+    fn filter_ids<T>(resources: &HashMap<Id, T>, filter: &dyn Fn(&T) -> bool) -> Vec<Id> {
+        resources
+            .iter()
+            .filter(|(_, resource)| filter(resource))
+            .map(|(id, _)| id.clone())
+            .collect()
+    }
 }
 
 impl DataStore for LocalDataStore {
+    fn all_ability_ids(&self, filter: &dyn Fn(&AbilityData) -> bool) -> Result<Vec<Id>> {
+        Ok(Self::filter_ids(&self.abilities, filter))
+    }
+
+    fn all_clause_ids(&self, filter: &dyn Fn(&ClauseData) -> bool) -> Result<Vec<Id>> {
+        Ok(Self::filter_ids(&self.clauses, filter))
+    }
+
+    fn all_condition_ids(&self, filter: &dyn Fn(&ConditionData) -> bool) -> Result<Vec<Id>> {
+        Ok(Self::filter_ids(&self.conditions, filter))
+    }
+
+    fn all_item_ids(&self, filter: &dyn Fn(&ItemData) -> bool) -> Result<Vec<Id>> {
+        Ok(Self::filter_ids(&self.items, filter))
+    }
+
     fn all_move_ids(&self, filter: &dyn Fn(&MoveData) -> bool) -> Result<Vec<Id>> {
-        let mut move_ids = Vec::new();
-        for (id, move_data) in self.moves.iter() {
-            if filter(move_data) {
-                move_ids.push(id.clone());
-            }
-        }
-        Ok(move_ids)
+        Ok(Self::filter_ids(&self.moves, filter))
+    }
+
+    fn all_species_ids(&self, filter: &dyn Fn(&SpeciesData) -> bool) -> Result<Vec<Id>> {
+        Ok(Self::filter_ids(&self.species, filter))
     }
 
     fn get_type_chart(&self) -> Result<TypeChart> {
         Ok(self.type_chart.clone())
     }
 
+    fn all_aliases(&self) -> Result<Aliases> {
+        Ok(self.aliases.clone())
+    }
+
     fn translate_alias(&self, id: &Id) -> Result<Option<Id>> {
         Ok(self.aliases.get(id).cloned())
     }
@@ -238,6 +297,10 @@ impl DataStore for LocalDataStore {
     fn get_species(&self, id: &Id) -> Result<Option<SpeciesData>> {
         Ok(self.species.get(id).cloned())
     }
+
+    fn get_subroutine(&self, id: &Id) -> Result<Option<SubroutineData>> {
+        Ok(self.subroutines.get(id).cloned())
+    }
 }
 
 impl DataStoreByName for LocalDataStore {
@@ -0,0 +1,391 @@
+use std::{
+    fs,
+    path::Path,
+};
+
+use ahash::HashMap;
+use anyhow::{
+    Context,
+    Error,
+    Result,
+};
+use battler_data::{
+    AbilityData,
+    Aliases,
+    ClauseData,
+    ConditionData,
+    DataStore,
+    DataStoreByName,
+    Id,
+    ItemData,
+    MoveData,
+    ResourceType,
+    SpeciesData,
+    SubroutineData,
+    TypeChart,
+    deserialize_aliases,
+};
+use serde::de::DeserializeOwned;
+use serde_json::{
+    Map,
+    Value,
+};
+
+use crate::LocalDataStore;
+
+/// A layer of data in an [`OverlayDataStore`].
+///
+/// A layer is a directory laid out like the root directory of a [`LocalDataStore`], except that
+/// every file and directory is optional.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataLayer {
+    /// The name of the layer, reported as the source of resources.
+    pub name: String,
+    /// The root directory of the layer.
+    pub root: String,
+}
+
+impl DataLayer {
+    pub fn new(name: String, root: String) -> Self {
+        Self { name, root }
+    }
+}
+
+/// The layers that supplied a resource in an [`OverlayDataStore`].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ResourceSource {
+    /// The layer that defined the resource.
+    pub defined_by: String,
+    /// The layers that patched the resource, from lowest to highest priority.
+    pub patched_by: Vec<String>,
+}
+
+impl ResourceSource {
+    /// The highest priority layer that supplied the resource.
+    pub fn layer(&self) -> &str {
+        self.patched_by.last().unwrap_or(&self.defined_by)
+    }
+}
+
+/// Applies a JSON merge patch, as defined by RFC 7396.
+fn merge_patch(target: &mut Value, patch: &Value) {
+    let Value::Object(patch) = patch else {
+        *target = patch.clone();
+        return;
+    };
+    if !target.is_object() {
+        *target = Value::Object(Map::new());
+    }
+    if let Value::Object(target) = target {
+        for (key, value) in patch {
+            if value.is_null() {
+                target.remove(key);
+            } else {
+                merge_patch(target.entry(key).or_insert(Value::Null), value);
+            }
+        }
+    }
+}
+
+/// The entries of a single table of data, merged across layers.
+#[derive(Default)]
+struct MergedTable {
+    entries: HashMap<Id, (Value, ResourceSource)>,
+}
+
+impl MergedTable {
+    fn apply(&mut self, layer: &str, table: Vec<(String, Value)>) {
+        for (id, patch) in table {
+            let id = Id::from(id);
+            // An entry set to null is a deletion marker.
+            if patch.is_null() {
+                self.entries.remove(&id);
+                continue;
+            }
+            match self.entries.get_mut(&id) {
+                Some((value, source)) => {
+                    merge_patch(value, &patch);
+                    source.patched_by.push(layer.to_owned());
+                }
+                None => {
+                    let mut value = Value::Null;
+                    merge_patch(&mut value, &patch);
+                    self.entries.insert(
+                        id,
+                        (
+                            value,
+                            ResourceSource {
+                                defined_by: layer.to_owned(),
+                                patched_by: Vec::new(),
+                            },
+                        ),
+                    );
+                }
+            }
+        }
+    }
+
+    fn deserialize<T: DeserializeOwned>(
+        self,
+        resource_type: ResourceType,
+        sources: &mut HashMap<(ResourceType, Id), ResourceSource>,
+    ) -> Result<HashMap<Id, T>> {
+        let mut data = HashMap::default();
+        for (id, (value, source)) in self.entries {
+            let resource = serde_json::from_value(value).context(format!(
+                "failed to read {resource_type:?} {id} supplied by {}",
+                source.layer()
+            ))?;
+            data.insert(id.clone(), resource);
+            sources.insert((resource_type, id), source);
+        }
+        Ok(data)
+    }
+}
+
+/// Reads a table of data from a file, if it exists.
+fn read_table(path: &Path) -> Result<Vec<(String, Value)>> {
+    if !path.is_file() {
+        return Ok(Vec::new());
+    }
+    let path_name = path.to_string_lossy().to_string();
+    let table: Map<String, Value> = serde_json::from_str(
+        &fs::read_to_string(path).context(format!("{path_name} could not be opened"))?,
+    )
+    .context(format!("failed to parse {path_name}"))?;
+    Ok(table.into_iter().collect())
+}
+
+/// Reads all tables of data from files in a directory, if it exists.
+fn read_tables_in_directory(dir: &Path) -> Result<Vec<(String, Value)>> {
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    let mut paths = dir
+        .read_dir()
+        .context(format!("failed to read {} directory", dir.display()))?
+        .filter_map(|entry| Some(entry.ok()?.path()))
+        .filter(|path| path.is_file())
+        .collect::<Vec<_>>();
+    // Files are read in a stable order, so that duplicate entries in a layer resolve consistently.
+    paths.sort();
+    let mut table = Vec::new();
+    for path in paths {
+        table.extend(read_table(&path)?);
+    }
+    Ok(table)
+}
+
+/// An implementation of [`DataStore`] that stacks multiple layers of data, such as a base data
+/// directory and mod packs on top of it.
+///
+/// Layers are applied in order, from lowest to highest priority. Every entry in a layer is a JSON
+/// merge patch (RFC 7396) on the same entry in the layers below it:
+/// - An entry that does not exist in lower layers is defined by the layer.
+/// - Fields of an existing entry are replaced, and objects are merged recursively. A field set to
+///   `null` is removed.
+/// - An entry set to `null` is a deletion marker, which removes the resource from all lower layers.
+///
+/// Aliases and the type chart are patched the same way, so layers can add aliases.
+///
+/// All layers are merged when the store is created. The store uses a [`LocalDataStore`] for the
+/// merged data, so it behaves exactly like a single data directory.
+pub struct OverlayDataStore {
+    store: LocalDataStore,
+    layers: Vec<String>,
+    sources: HashMap<(ResourceType, Id), ResourceSource>,
+}
+
+impl OverlayDataStore {
+    /// Creates a new instance of [`OverlayDataStore`] from layers, ordered from lowest to highest
+    /// priority.
+    ///
+    /// Fails if any layer cannot be read, if merged data is invalid, or if no layer defines a type
+    /// chart.
+    pub fn new(layers: Vec<DataLayer>) -> Result<Self> {
+        let mut type_chart = Value::Null;
+        let mut aliases = Value::Null;
+        let mut abilities = MergedTable::default();
+        let mut clauses = MergedTable::default();
+        let mut conditions = MergedTable::default();
+        let mut items = MergedTable::default();
+        let mut moves = MergedTable::default();
+        let mut species = MergedTable::default();
+        let mut subroutines = MergedTable::default();
+
+        for layer in &layers {
+            let root = Path::new(&layer.root);
+            if !root.is_dir() {
+                return Err(Error::msg(format!(
+                    "Root directory for layer {} ({}) does not exist",
+                    layer.name, layer.root
+                )));
+            }
+            let read = |file: &str| {
+                read_table(&root.join(file)).context(format!("failed to read layer {}", layer.name))
+            };
+            let read_dir = |dir: &str| {
+                read_tables_in_directory(&root.join(dir))
+                    .context(format!("failed to read layer {}", layer.name))
+            };
+
+            let type_chart_patch = read(LocalDataStore::TYPE_CHART_FILE)?;
+            if !type_chart_patch.is_empty() {
+                merge_patch(
+                    &mut type_chart,
+                    &Value::Object(type_chart_patch.into_iter().collect()),
+                );
+            }
+            merge_patch(
+                &mut aliases,
+                &Value::Object(read(LocalDataStore::ALIASES_FILE)?.into_iter().collect()),
+            );
+            clauses.apply(&layer.name, read(LocalDataStore::CLAUSES_FILE)?);
+            conditions.apply(&layer.name, read(LocalDataStore::CONDITIONS_FILE)?);
+            subroutines.apply(&layer.name, read(LocalDataStore::SUBROUTINES_FILE)?);
+            conditions.apply(&layer.name, read(LocalDataStore::MOVE_BASES_FILE)?);
+            conditions.apply(&layer.name, read(LocalDataStore::ABILITY_BASES_FILE)?);
+            conditions.apply(&layer.name, read(LocalDataStore::ITEM_BASES_FILE)?);
+            abilities.apply(&layer.name, read_dir(LocalDataStore::ABILITIES_DIR)?);
+            items.apply(&layer.name, read_dir(LocalDataStore::ITEMS_DIR)?);
+            moves.apply(&layer.name, read_dir(LocalDataStore::MOVES_DIR)?);
+            species.apply(&layer.name, read_dir(LocalDataStore::SPECIES_DIR)?);
+        }
+
+        if type_chart.is_null() {
+            return Err(Error::msg("no layer defines a type chart"));
+        }
+
+        let mut sources = HashMap::default();
+        let mut store = LocalDataStore::empty(String::new());
+        store.type_chart =
+            serde_json::from_value(type_chart).context("failed to parse type chart")?;
+        store.aliases = deserialize_aliases(
+            serde_json::from_value(aliases).context("failed to parse aliases")?,
+        );
+        store.abilities = abilities.deserialize(ResourceType::Ability, &mut sources)?;
+        store.clauses = clauses.deserialize(ResourceType::Clause, &mut sources)?;
+        store.conditions = conditions.deserialize(ResourceType::Condition, &mut sources)?;
+        store.items = items.deserialize(ResourceType::Item, &mut sources)?;
+        store.moves = moves.deserialize(ResourceType::Move, &mut sources)?;
+        store.species = species.deserialize(ResourceType::Species, &mut sources)?;
+        store.subroutines = subroutines.deserialize(ResourceType::Subroutine, &mut sources)?;
+
+        Ok(Self {
+            store,
+            layers: layers.into_iter().map(|layer| layer.name).collect(),
+            sources,
+        })
+    }
+
+    /// The names of all layers, from lowest to highest priority.
+    pub fn layers(&self) -> &[String] {
+        &self.layers
+    }
+
+    /// The layers that supplied a resource.
+    ///
+    /// Returns [`None`] if the resource does not exist, including if it was deleted by a layer.
+    /// Aliases are not translated.
+    pub fn source(&self, resource_type: ResourceType, id: &Id) -> Option<&ResourceSource> {
+        self.sources.get(&(resource_type, id.clone()))
+    }
+
+    /// The merged data, for tools that work on a [`LocalDataStore`] directly (e.g., the fxlang
+    /// checker).
+    pub fn store(&self) -> &LocalDataStore {
+        &self.store
+    }
+}
+
+impl DataStore for OverlayDataStore {
+    fn all_ability_ids(&self, filter: &dyn Fn(&AbilityData) -> bool) -> Result<Vec<Id>> {
+        self.store.all_ability_ids(filter)
+    }
+
+    fn all_clause_ids(&self, filter: &dyn Fn(&ClauseData) -> bool) -> Result<Vec<Id>> {
+        self.store.all_clause_ids(filter)
+    }
+
+    fn all_condition_ids(&self, filter: &dyn Fn(&ConditionData) -> bool) -> Result<Vec<Id>> {
+        self.store.all_condition_ids(filter)
+    }
+
+    fn all_item_ids(&self, filter: &dyn Fn(&ItemData) -> bool) -> Result<Vec<Id>> {
+        self.store.all_item_ids(filter)
+    }
+
+    fn all_move_ids(&self, filter: &dyn Fn(&MoveData) -> bool) -> Result<Vec<Id>> {
+        self.store.all_move_ids(filter)
+    }
+
+    fn all_species_ids(&self, filter: &dyn Fn(&SpeciesData) -> bool) -> Result<Vec<Id>> {
+        self.store.all_species_ids(filter)
+    }
+
+    fn get_type_chart(&self) -> Result<TypeChart> {
+        self.store.get_type_chart()
+    }
+
+    fn all_aliases(&self) -> Result<Aliases> {
+        self.store.all_aliases()
+    }
+
+    fn translate_alias(&self, id: &Id) -> Result<Option<Id>> {
+        self.store.translate_alias(id)
+    }
+
+    fn get_ability(&self, id: &Id) -> Result<Option<AbilityData>> {
+        self.store.get_ability(id)
+    }
+
+    fn get_clause(&self, id: &Id) -> Result<Option<ClauseData>> {
+        self.store.get_clause(id)
+    }
+
+    fn get_condition(&self, id: &Id) -> Result<Option<ConditionData>> {
+        self.store.get_condition(id)
+    }
+
+    fn get_item(&self, id: &Id) -> Result<Option<ItemData>> {
+        self.store.get_item(id)
+    }
+
+    fn get_move(&self, id: &Id) -> Result<Option<MoveData>> {
+        self.store.get_move(id)
+    }
+
+    fn get_species(&self, id: &Id) -> Result<Option<SpeciesData>> {
+        self.store.get_species(id)
+    }
+
+    fn get_subroutine(&self, id: &Id) -> Result<Option<SubroutineData>> {
+        self.store.get_subroutine(id)
+    }
+}
+
+impl DataStoreByName for OverlayDataStore {
+    fn get_ability_by_name(&self, name: &str) -> Result<Option<AbilityData>> {
+        self.store.get_ability_by_name(name)
+    }
+
+    fn get_clause_by_name(&self, name: &str) -> Result<Option<ClauseData>> {
+        self.store.get_clause_by_name(name)
+    }
+
+    fn get_condition_by_name(&self, name: &str) -> Result<Option<ConditionData>> {
+        self.store.get_condition_by_name(name)
+    }
+
+    fn get_item_by_name(&self, name: &str) -> Result<Option<ItemData>> {
+        self.store.get_item_by_name(name)
+    }
+
+    fn get_move_by_name(&self, name: &str) -> Result<Option<MoveData>> {
+        self.store.get_move_by_name(name)
+    }
+
+    fn get_species_by_name(&self, name: &str) -> Result<Option<SpeciesData>> {
+        self.store.get_species_by_name(name)
+    }
+}
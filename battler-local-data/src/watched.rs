@@ -0,0 +1,278 @@
+use std::{
+    env,
+    fs,
+    path::{
+        Path,
+        PathBuf,
+    },
+    sync::{
+        Arc,
+        Mutex,
+        RwLock,
+        RwLockReadGuard,
+        atomic::{
+            AtomicBool,
+            AtomicU64,
+            Ordering,
+        },
+    },
+    thread::{
+        self,
+        JoinHandle,
+    },
+    time::{
+        Duration,
+        SystemTime,
+    },
+};
+
+use anyhow::{
+    Context,
+    Error,
+    Result,
+};
+use battler_data::{
+    AbilityData,
+    Aliases,
+    ClauseData,
+    ConditionData,
+    DataStore,
+    DataStoreByName,
+    Id,
+    ItemData,
+    MoveData,
+    SpeciesData,
+    SubroutineData,
+    TypeChart,
+};
+
+use crate::LocalDataStore;
+
+/// The modification time and size of every file under a directory, sorted by path.
+type Fingerprint = Vec<(PathBuf, SystemTime, u64)>;
+
+fn fingerprint(root: &Path) -> Result<Fingerprint> {
+    let mut files = Vec::new();
+    let mut dirs = vec![root.to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        for entry in dir
+            .read_dir()
+            .context(format!("failed to read {} directory", dir.display()))?
+        {
+            let path = entry?.path();
+            let metadata = fs::metadata(&path)?;
+            if metadata.is_dir() {
+                dirs.push(path);
+            } else if metadata.is_file() {
+                files.push((path, metadata.modified()?, metadata.len()));
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// An implementation of [`DataStore`] that wraps a [`LocalDataStore`] and reloads all data when
+/// files under its root directory change.
+///
+/// Intended for development, so that data authors can edit data files without restarting battles.
+/// After data is reloaded, running battles must invalidate their caches (with
+/// `PublicCoreBattle::invalidate_data_caches`) before edited data is used.
+pub struct WatchedLocalDataStore {
+    root: String,
+    store: RwLock<LocalDataStore>,
+    fingerprint: Mutex<Fingerprint>,
+    generation: AtomicU64,
+}
+
+impl WatchedLocalDataStore {
+    /// Creates a new instance of [`WatchedLocalDataStore`] that reads from the given root
+    /// directory.
+    pub fn new(root: String) -> Result<Self> {
+        let fingerprint = fingerprint(Path::new(&root))?;
+        let store = LocalDataStore::new(root.clone())?;
+        Ok(Self {
+            root,
+            store: RwLock::new(store),
+            fingerprint: Mutex::new(fingerprint),
+            generation: AtomicU64::new(0),
+        })
+    }
+
+    /// Creates a new instance of [`WatchedLocalDataStore`] that reads from the root directory at
+    /// the given environment variable.
+    pub fn new_from_env(env_var: &str) -> Result<Self> {
+        Self::new(env::var(env_var).with_context(|| format!("{env_var} not defined"))?)
+    }
+
+    /// The number of times data has been reloaded.
+    ///
+    /// Useful for detecting a reload done by a [`DataStoreWatcher`].
+    pub fn generation(&self) -> u64 {
+        self.generation.load(Ordering::Acquire)
+    }
+
+    /// Reloads all data if any file under the root directory changed since data was last loaded.
+    ///
+    /// Returns `true` if data was reloaded. If the changed data cannot be loaded (e.g., a file is
+    /// malformed), the previous data is kept and the error is returned. The reload is attempted
+    /// again on the next call.
+    pub fn reload_if_changed(&self) -> Result<bool> {
+        let mut fingerprint_guard = self
+            .fingerprint
+            .lock()
+            .map_err(|_| Error::msg("fingerprint lock is poisoned"))?;
+        let fingerprint = fingerprint(Path::new(&self.root))?;
+        if fingerprint == *fingerprint_guard {
+            return Ok(false);
+        }
+
+        let store = LocalDataStore::new(self.root.clone()).context("failed to reload data")?;
+        *self
+            .store
+            .write()
+            .map_err(|_| Error::msg("data store lock is poisoned"))? = store;
+        *fingerprint_guard = fingerprint;
+        self.generation.fetch_add(1, Ordering::AcqRel);
+        Ok(true)
+    }
+
+    /// Starts watching the root directory for changes on a background thread, checking at the
+    /// given interval.
+    ///
+    /// Errors from reloading are ignored, since the reload is attempted again at the next check.
+    /// The watcher stops when the returned [`DataStoreWatcher`] is dropped.
+    pub fn watch(self: &Arc<Self>, interval: Duration) -> DataStoreWatcher {
+        let store = self.clone();
+        let stopped = Arc::new(AtomicBool::new(false));
+        let thread = thread::spawn({
+            let stopped = stopped.clone();
+            move || {
+                while !stopped.load(Ordering::Acquire) {
+                    store.reload_if_changed().ok();
+                    thread::park_timeout(interval);
+                }
+            }
+        });
+        DataStoreWatcher {
+            stopped,
+            thread: Some(thread),
+        }
+    }
+
+    fn store(&self) -> Result<RwLockReadGuard<'_, LocalDataStore>> {
+        self.store
+            .read()
+            .map_err(|_| Error::msg("data store lock is poisoned"))
+    }
+}
+
+/// A background thread watching a [`WatchedLocalDataStore`] for changes.
+///
+/// The thread is stopped when the watcher is dropped.
+pub struct DataStoreWatcher {
+    stopped: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Drop for DataStoreWatcher {
+    fn drop(&mut self) {
+        self.stopped.store(true, Ordering::Release);
+        if let Some(thread) = self.thread.take() {
+            thread.thread().unpark();
+            thread.join().ok();
+        }
+    }
+}
+
+impl DataStore for WatchedLocalDataStore {
+    fn all_ability_ids(&self, filter: &dyn Fn(&AbilityData) -> bool) -> Result<Vec<Id>> {
+        self.store()?.all_ability_ids(filter)
+    }
+
+    fn all_clause_ids(&self, filter: &dyn Fn(&ClauseData) -> bool) -> Result<Vec<Id>> {
+        self.store()?.all_clause_ids(filter)
+    }
+
+    fn all_condition_ids(&self, filter: &dyn Fn(&ConditionData) -> bool) -> Result<Vec<Id>> {
+        self.store()?.all_condition_ids(filter)
+    }
+
+    fn all_item_ids(&self, filter: &dyn Fn(&ItemData) -> bool) -> Result<Vec<Id>> {
+        self.store()?.all_item_ids(filter)
+    }
+
+    fn all_move_ids(&self, filter: &dyn Fn(&MoveData) -> bool) -> Result<Vec<Id>> {
+        self.store()?.all_move_ids(filter)
+    }
+
+    fn all_species_ids(&self, filter: &dyn Fn(&SpeciesData) -> bool) -> Result<Vec<Id>> {
+        self.store()?.all_species_ids(filter)
+    }
+
+    fn get_type_chart(&self) -> Result<TypeChart> {
+        self.store()?.get_type_chart()
+    }
+
+    fn all_aliases(&self) -> Result<Aliases> {
+        self.store()?.all_aliases()
+    }
+
+    fn translate_alias(&self, id: &Id) -> Result<Option<Id>> {
+        self.store()?.translate_alias(id)
+    }
+
+    fn get_ability(&self, id: &Id) -> Result<Option<AbilityData>> {
+        self.store()?.get_ability(id)
+    }
+
+    fn get_clause(&self, id: &Id) -> Result<Option<ClauseData>> {
+        self.store()?.get_clause(id)
+    }
+
+    fn get_condition(&self, id: &Id) -> Result<Option<ConditionData>> {
+        self.store()?.get_condition(id)
+    }
+
+    fn get_item(&self, id: &Id) -> Result<Option<ItemData>> {
+        self.store()?.get_item(id)
+    }
+
+    fn get_move(&self, id: &Id) -> Result<Option<MoveData>> {
+        self.store()?.get_move(id)
+    }
+
+    fn get_species(&self, id: &Id) -> Result<Option<SpeciesData>> {
+        self.store()?.get_species(id)
+    }
+
+    fn get_subroutine(&self, id: &Id) -> Result<Option<SubroutineData>> {
+        self.store()?.get_subroutine(id)
+    }
+}
+
+impl DataStoreByName for WatchedLocalDataStore {
+    fn get_ability_by_name(&self, name: &str) -> Result<Option<AbilityData>> {
+        self.store()?.get_ability_by_name(name)
+    }
+
+    fn get_clause_by_name(&self, name: &str) -> Result<Option<ClauseData>> {
+        self.store()?.get_clause_by_name(name)
+    }
+
+    fn get_condition_by_name(&self, name: &str) -> Result<Option<ConditionData>> {
+        self.store()?.get_condition_by_name(name)
+    }
+
+    fn get_item_by_name(&self, name: &str) -> Result<Option<ItemData>> {
+        self.store()?.get_item_by_name(name)
+    }
+
+    fn get_move_by_name(&self, name: &str) -> Result<Option<MoveData>> {
+        self.store()?.get_move_by_name(name)
+    }
+
+    fn get_species_by_name(&self, name: &str) -> Result<Option<SpeciesData>> {
+        self.store()?.get_species_by_name(name)
+    }
+}
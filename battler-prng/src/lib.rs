@@ -1,3 +1,16 @@
+//! Random number generation for battler.
+//!
+//! Battles draw all random numbers from a [`PseudoRandomNumberGenerator`], so that a battle can be
+//! replayed deterministically from its initial seed.
+//!
+//! # Generator State
+//!
+//! Battle snapshots and forks resume the random number generation sequence from the current
+//! internal state of the generator, which is accessed through
+//! [`PseudoRandomNumberGenerator::state`] and [`PseudoRandomNumberGenerator::set_state`]. These
+//! methods were added after the trait was first published, so they have default implementations
+//! that fail with [`UnsupportedStateError`]. Existing generators keep working, but they must
+//! implement both methods to support snapshots and forks.
 #![no_std]
 
 #[cfg(feature = "alloc")]
@@ -5,7 +18,11 @@ extern crate alloc;
 
 pub mod rand_util;
 
-use core::any::Any;
+use core::{
+    any::Any,
+    error::Error,
+    fmt::Display,
+};
 
 #[cfg(feature = "std")]
 use rand::RngExt;
@@ -21,10 +38,45 @@ pub trait PseudoRandomNumberGenerator: Send + Sync {
     /// Returns the next integer in the sequence.
     fn next(&mut self) -> u64;
 
+    /// Returns the current internal state of the generator.
+    ///
+    /// The state can be used to resume the random number generation sequence at this exact point
+    /// with [`Self::set_state`].
+    ///
+    /// By default, the state is not accessible and this method fails.
+    fn state(&self) -> Result<u64, UnsupportedStateError> {
+        Err(UnsupportedStateError)
+    }
+
+    /// Sets the current internal state of the generator.
+    ///
+    /// The next call to [`Self::next`] continues the sequence from the given state.
+    ///
+    /// By default, the state is not accessible and this method fails.
+    fn set_state(&mut self, state: u64) -> Result<(), UnsupportedStateError> {
+        let _ = state;
+        Err(UnsupportedStateError)
+    }
+
     /// Mutable cast to [`Any`]` for testing.
     fn as_any_mut(&mut self) -> &mut dyn Any;
 }
 
+/// Error for a [`PseudoRandomNumberGenerator`] that does not support accessing its internal state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnsupportedStateError;
+
+impl Display for UnsupportedStateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "random number generator does not support accessing its state"
+        )
+    }
+}
+
+impl Error for UnsupportedStateError {}
+
 /// A real implementation of [`PseudoRandomNumberGenerator`].
 pub struct RealPseudoRandomNumberGenerator {
     initial_seed: u64,
@@ -74,6 +126,15 @@ impl PseudoRandomNumberGenerator for RealPseudoRandomNumberGenerator {
         self.seed >> 32
     }
 
+    fn state(&self) -> Result<u64, UnsupportedStateError> {
+        Ok(self.seed)
+    }
+
+    fn set_state(&mut self, state: u64) -> Result<(), UnsupportedStateError> {
+        self.seed = state;
+        Ok(())
+    }
+
     fn as_any_mut(&mut self) -> &mut dyn Any {
         self
     }
@@ -81,9 +142,12 @@ impl PseudoRandomNumberGenerator for RealPseudoRandomNumberGenerator {
 
 #[cfg(test)]
 mod prng_test {
+    use core::any::Any;
+
     use crate::{
         PseudoRandomNumberGenerator,
         RealPseudoRandomNumberGenerator,
+        UnsupportedStateError,
     };
 
     #[test]
@@ -97,4 +161,44 @@ mod prng_test {
             6789100000
         );
     }
+
+    #[test]
+    fn resumes_sequence_from_state() {
+        let mut prng = RealPseudoRandomNumberGenerator::new(Some(12345));
+        prng.next();
+        prng.next();
+        let state = prng.state().unwrap();
+        let expected: [u64; 10] = core::array::from_fn(|_| prng.next());
+
+        let mut resumed = RealPseudoRandomNumberGenerator::new(Some(0));
+        assert_eq!(resumed.set_state(state), Ok(()));
+        assert_eq!(resumed.state(), Ok(state));
+        assert_eq!(
+            core::array::from_fn::<_, 10, _>(|_| resumed.next()),
+            expected
+        );
+    }
+
+    struct StatelessPseudoRandomNumberGenerator;
+
+    impl PseudoRandomNumberGenerator for StatelessPseudoRandomNumberGenerator {
+        fn initial_seed(&self) -> u64 {
+            0
+        }
+
+        fn next(&mut self) -> u64 {
+            0
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    #[test]
+    fn does_not_support_state_by_default() {
+        let mut prng = StatelessPseudoRandomNumberGenerator;
+        assert_eq!(prng.state(), Err(UnsupportedStateError));
+        assert_eq!(prng.set_state(12345), Err(UnsupportedStateError));
+    }
 }
@@ -33,6 +33,7 @@ use crate::{
     MonPhysicalAppearance,
     Player,
     Side,
+    TypeChartOverride,
     ui,
 };
 
@@ -824,6 +825,7 @@ fn alter_battle_state_for_entry(
         | "prepare"
         | "primal"
         | "protectweaken"
+        | "raidshields"
         | "resettypechange"
         | "resisted"
         | "restorepp"
@@ -977,7 +979,7 @@ fn alter_battle_state_for_entry(
                 state.battle_type = battle_type.to_lowercase();
                 state.field.max_side_length = match state.battle_type.as_str() {
                     "doubles" => 2,
-                    "triples" => 3,
+                    "triples" | "rotation" => 3,
                     _ => 1,
                 };
             }
@@ -991,6 +993,9 @@ fn alter_battle_state_for_entry(
                 state.field.time = Some(time);
             }
         }
+        "inversetypechart" => {
+            state.field.inverse_type_chart = true;
+        }
         "learnedmove" => {
             let mon = entry.value_or_else("mon")?;
             let move_name: String = entry.value_or_else("move")?;
@@ -1167,6 +1172,11 @@ fn alter_battle_state_for_entry(
                 },
             );
         }
+        "raidfailed" => {
+            let player = entry.value_or_else("player")?;
+            let reason = entry.value_or_else("reason")?;
+            ui_log.push(ui::UiLogEntry::RaidFailed { player, reason });
+        }
         "residual" => (),
         "side" => {
             let id: usize = entry.value_or_else("id")?;
@@ -1308,6 +1318,13 @@ fn alter_battle_state_for_entry(
         "turnlimit" => {
             ui_log.push(ui::UiLogEntry::TurnLimit);
         }
+        "typechartoverride" => {
+            state.field.type_chart_overrides.push(TypeChartOverride {
+                offense: entry.value_or_else("offense")?,
+                defense: entry.value_or_else("defense")?,
+                multiplier: entry.value_or_else("multiplier")?,
+            });
+        }
         "useitem" => {
             let player = entry.value_or_else("player")?;
             let item = entry.value_or_else("name")?;
@@ -1355,6 +1372,17 @@ fn alter_battle_state_for_entry(
                 }
             }
         }
+        "rotate" => {
+            let mon_name = entry.value_or_else::<MonName>("mon")?;
+            let direction: String = entry.value_or_else("direction")?;
+            let side_index = state.field.side_for_player(&mon_name.player)?;
+            let side = state.field.side_mut_or_else(side_index)?;
+            match direction.as_str() {
+                "left" => side.active.rotate_left(1),
+                "right" => side.active.rotate_right(1),
+                _ => return Err(Error::msg(format!("invalid rotate direction: {direction}"))),
+            }
+        }
         "swapplayer" => {
             let player_id: String = entry.value_or_else("player")?;
             let position: usize = entry.value_or_else("position")?;
@@ -25,4 +25,5 @@ pub use types::{
     MonVolatileData,
     Player,
     Side,
+    TypeChartOverride,
 };
@@ -16,6 +16,7 @@ mod state_test {
             BattlePhase,
             BattleState,
             MonBattleAppearanceReference,
+            TypeChartOverride,
             alter_battle_state,
         },
         state_selectors,
@@ -94,6 +95,32 @@ mod state_test {
         assert!(state.ui_log.iter().all(|l| l.is_empty()));
     }
 
+    #[test]
+    fn records_type_chart_overlay() {
+        let log = Log::new(&[
+            "info|battletype:Singles",
+            "info|rule:Inverse Battle: Type effectiveness is inverted",
+            "inversetypechart",
+            "typechartoverride|offense:Normal|defense:Ghost|multiplier:0.5",
+            "side|id:0|name:Side 1",
+            "side|id:1|name:Side 2",
+            "battlestart",
+            "turn|turn:1",
+        ])
+        .unwrap();
+
+        let state = alter_battle_state(BattleState::default(), &log).unwrap();
+        assert!(state.field.inverse_type_chart);
+        assert_eq!(
+            state.field.type_chart_overrides,
+            Vec::from_iter([TypeChartOverride {
+                offense: "Normal".to_owned(),
+                defense: "Ghost".to_owned(),
+                multiplier: "0.5".to_owned(),
+            }])
+        );
+    }
+
     #[test]
     fn adds_mon_for_initial_switch_in() {
         let state = setup_singles_battle(&[]);
@@ -2772,6 +2799,50 @@ mod state_test {
         assert!(state.ui_log[1].is_empty());
     }
 
+    #[test]
+    fn records_active_position_rotation() {
+        let log = Log::new(&[
+            "info|battletype:Rotation",
+            "side|id:0|name:Side 1",
+            "side|id:1|name:Side 2",
+            "maxsidelength|length:3",
+            "player|id:player-1|name:Player 1|side:0|position:0",
+            "player|id:player-2|name:Player 2|side:1|position:0",
+            "teamsize|player:player-1|size:3",
+            "teamsize|player:player-2|size:3",
+            "battlestart",
+            "switch|player:player-1|position:1|name:Squirtle|health:100/100|species:Squirtle|level:5|gender:M",
+            "switch|player:player-1|position:2|name:Wartortle|health:100/100|species:Wartortle|level:5|gender:M",
+            "switch|player:player-1|position:3|name:Blastoise|health:100/100|species:Blastoise|level:5|gender:M",
+            "switch|player:player-2|position:1|name:Charmander|health:100/100|species:Charmander|level:5|gender:M",
+            "switch|player:player-2|position:2|name:Charmeleon|health:100/100|species:Charmeleon|level:5|gender:M",
+            "switch|player:player-2|position:3|name:Charizard|health:100/100|species:Charizard|level:5|gender:M",
+            "turn|turn:1",
+            "rotate|mon:Wartortle,player-1,2|direction:left",
+            "rotate|mon:Charizard,player-2,3|direction:right",
+        ])
+        .unwrap();
+        let state = alter_battle_state(BattleState::default(), &log).unwrap();
+        assert_eq!(state.field.max_side_length, 3);
+        assert_eq!(
+            state.field.sides[0]
+                .active
+                .iter()
+                .map(|mon| mon.as_ref().unwrap().mon_index)
+                .collect::<Vec<_>>(),
+            Vec::from_iter([1, 2, 0]) // Wartortle, Blastoise, Squirtle
+        );
+        assert_eq!(
+            state.field.sides[1]
+                .active
+                .iter()
+                .map(|mon| mon.as_ref().unwrap().mon_index)
+                .collect::<Vec<_>>(),
+            Vec::from_iter([2, 0, 1]) // Charizard, Charmander, Charmeleon
+        );
+        assert!(state.ui_log[1].is_empty());
+    }
+
     #[test]
     fn records_swap_side_conditions() {
         let state = setup_singles_battle(&[
@@ -2986,6 +3057,18 @@ mod state_test {
         assert!(state.ui_log.iter().all(|l| l.is_empty()));
     }
 
+    #[test]
+    fn records_raid_failed() {
+        let state = setup_singles_battle(&["raidfailed|player:player-2|reason:turnlimit"]);
+        assert_eq!(
+            state.ui_log[1],
+            Vec::from_iter([ui::UiLogEntry::RaidFailed {
+                player: "player-2".to_owned(),
+                reason: "turnlimit".to_owned(),
+            }])
+        );
+    }
+
     #[test]
     fn records_turn_limit() {
         let state = setup_singles_battle(&["turnlimit"]);
@@ -1015,6 +1015,16 @@ impl Side {
     }
 }
 
+/// An override of type effectiveness applied to the battle's type chart.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct TypeChartOverride {
+    pub offense: String,
+    pub defense: String,
+    pub multiplier: String,
+}
+
 #[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 #[cfg_attr(feature = "typescript", ts(export))]
@@ -1025,6 +1035,8 @@ pub struct Field {
     pub weather: Option<String>,
     pub conditions: BTreeMap<String, ConditionData>,
     pub rules: Vec<String>,
+    pub inverse_type_chart: bool,
+    pub type_chart_overrides: Vec<TypeChartOverride>,
     pub max_side_length: usize,
 }
 
@@ -172,6 +172,8 @@ pub enum UiLogEntry {
     },
     /// A Mon is waiting for another Mon.
     Waiting { mon: Mon, on: Mon },
+    /// A raid failed.
+    RaidFailed { player: String, reason: String },
     /// A Mon revived.
     Revive { effect: EffectData },
     /// A Mon's health update directly.
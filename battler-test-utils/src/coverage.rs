@@ -0,0 +1,75 @@
+use std::{
+    env,
+    fs,
+    path::PathBuf,
+    process,
+    sync::{
+        atomic::{
+            AtomicUsize,
+            Ordering,
+        },
+        Arc,
+    },
+};
+
+use battler::{
+    CoverageCollector,
+    TraceRecord,
+    Tracer,
+};
+
+/// Environment variable for the directory that fxlang coverage is written to.
+///
+/// When set, every battle built by [`TestBattleBuilder`][`crate::TestBattleBuilder`] collects
+/// coverage of the fxlang programs it runs.
+pub const COVERAGE_DIR_ENV_VAR: &str = "FXLANG_COVERAGE_DIR";
+
+static NEXT_COVERAGE_FILE: AtomicUsize = AtomicUsize::new(0);
+
+/// A [`Tracer`] that collects fxlang coverage for a single battle, writing it to a new file in a
+/// directory when dropped.
+#[derive(Debug)]
+pub struct CoverageFileWriter {
+    collector: CoverageCollector,
+    dir: PathBuf,
+}
+
+impl CoverageFileWriter {
+    pub fn new(dir: PathBuf) -> Self {
+        Self {
+            collector: CoverageCollector::new(),
+            dir,
+        }
+    }
+
+    /// Creates a new coverage writer for the directory in the environment, if any.
+    pub fn from_env() -> Option<Arc<dyn Tracer>> {
+        let dir = env::var(COVERAGE_DIR_ENV_VAR).ok()?;
+        Some(Arc::new(Self::new(PathBuf::from(dir))))
+    }
+}
+
+impl Tracer for CoverageFileWriter {
+    fn record(&self, record: TraceRecord) {
+        self.collector.record(record);
+    }
+}
+
+impl Drop for CoverageFileWriter {
+    fn drop(&mut self) {
+        let coverage = self.collector.coverage();
+        if coverage.programs.is_empty() {
+            return;
+        }
+        let path = self.dir.join(format!(
+            "{}-{}.json",
+            process::id(),
+            NEXT_COVERAGE_FILE.fetch_add(1, Ordering::Relaxed)
+        ));
+        // Coverage is best effort, so it never fails a test.
+        if let Ok(coverage) = serde_json::to_string(&coverage) {
+            fs::create_dir_all(&self.dir).ok();
+            fs::write(path, coverage).ok();
+        }
+    }
+}
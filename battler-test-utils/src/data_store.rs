@@ -7,6 +7,7 @@ use ahash::{
 use anyhow::Result;
 use battler::{
     AbilityData,
+    Aliases,
     ClauseData,
     ConditionData,
     DataStore,
@@ -14,6 +15,7 @@ use battler::{
     ItemData,
     MoveData,
     SpeciesData,
+    SubroutineData,
     TypeChart,
 };
 use battler_local_data::LocalDataStore;
@@ -36,6 +38,7 @@ pub fn static_local_data_store() -> &'static LocalDataStore {
 pub struct TestDataStore {
     local: LocalDataStore,
     fake_moves: HashMap<Id, MoveData>,
+    fake_subroutines: HashMap<Id, SubroutineData>,
 }
 
 impl TestDataStore {
@@ -44,15 +47,36 @@ impl TestDataStore {
         Ok(Self {
             local,
             fake_moves: HashMap::new(),
+            fake_subroutines: HashMap::new(),
         })
     }
 
     pub fn add_fake_move(&mut self, id: Id, move_data: MoveData) {
         self.fake_moves.insert(id, move_data);
     }
+
+    pub fn add_fake_subroutine(&mut self, id: Id, subroutine_data: SubroutineData) {
+        self.fake_subroutines.insert(id, subroutine_data);
+    }
 }
 
 impl DataStore for TestDataStore {
+    fn all_ability_ids(&self, filter: &dyn Fn(&AbilityData) -> bool) -> Result<Vec<Id>> {
+        self.local.all_ability_ids(filter)
+    }
+
+    fn all_clause_ids(&self, filter: &dyn Fn(&ClauseData) -> bool) -> Result<Vec<Id>> {
+        self.local.all_clause_ids(filter)
+    }
+
+    fn all_condition_ids(&self, filter: &dyn Fn(&ConditionData) -> bool) -> Result<Vec<Id>> {
+        self.local.all_condition_ids(filter)
+    }
+
+    fn all_item_ids(&self, filter: &dyn Fn(&ItemData) -> bool) -> Result<Vec<Id>> {
+        self.local.all_item_ids(filter)
+    }
+
     fn all_move_ids(&self, filter: &dyn Fn(&MoveData) -> bool) -> Result<Vec<Id>> {
         let mut all_moves = self.local.all_move_ids(filter)?;
         let mut fake_moves = self
@@ -64,10 +88,18 @@ impl DataStore for TestDataStore {
         Ok(all_moves)
     }
 
+    fn all_species_ids(&self, filter: &dyn Fn(&SpeciesData) -> bool) -> Result<Vec<Id>> {
+        self.local.all_species_ids(filter)
+    }
+
     fn get_type_chart(&self) -> Result<TypeChart> {
         self.local.get_type_chart()
     }
 
+    fn all_aliases(&self) -> Result<Aliases> {
+        self.local.all_aliases()
+    }
+
     fn translate_alias(&self, id: &Id) -> Result<Option<Id>> {
         self.local.translate_alias(id)
     }
@@ -98,4 +130,11 @@ impl DataStore for TestDataStore {
     fn get_species(&self, id: &Id) -> Result<Option<SpeciesData>> {
         self.local.get_species(id)
     }
+
+    fn get_subroutine(&self, id: &Id) -> Result<Option<SubroutineData>> {
+        match self.fake_subroutines.get(id) {
+            Some(fake_subroutine) => Ok(Some(fake_subroutine.clone())),
+            None => self.local.get_subroutine(id),
+        }
+    }
 }
@@ -1,11 +1,17 @@
 mod cases;
+mod coverage;
 mod data_store;
 mod log_util;
 mod logging;
 mod rng;
 mod test_battle_builder;
+mod trace;
 
 pub use cases::read_test_cases;
+pub use coverage::{
+    CoverageFileWriter,
+    COVERAGE_DIR_ENV_VAR,
+};
 pub use data_store::{
     local_data_store,
     static_local_data_store,
@@ -25,3 +31,7 @@ pub use rng::{
     ControlledRandomNumberGenerator,
 };
 pub use test_battle_builder::TestBattleBuilder;
+pub use trace::{
+    assert_traced_callbacks_eq,
+    TraceCollector,
+};
@@ -11,6 +11,7 @@ use battler::PublicCoreBattle;
 use battler_prng::{
     PseudoRandomNumberGenerator,
     RealPseudoRandomNumberGenerator,
+    UnsupportedStateError,
 };
 
 /// A controlled random number generator, for tests that need fine-grained control over battle RNG.
@@ -46,6 +47,14 @@ impl PseudoRandomNumberGenerator for ControlledRandomNumberGenerator {
         }
     }
 
+    fn state(&self) -> Result<u64, UnsupportedStateError> {
+        self.real.state()
+    }
+
+    fn set_state(&mut self, state: u64) -> Result<(), UnsupportedStateError> {
+        self.real.set_state(state)
+    }
+
     fn as_any_mut(&mut self) -> &mut dyn Any {
         self
     }
@@ -1,4 +1,7 @@
-use std::str::FromStr;
+use std::{
+    str::FromStr,
+    sync::Arc,
+};
 
 use ahash::HashMap;
 use anyhow::Result;
@@ -9,25 +12,34 @@ use battler::{
     CoreBattleEngineSpeedSortTieResolution,
     CoreBattleOptions,
     DataStore,
+    DexCache,
+    EvaluationLimits,
     ExperienceOptions,
     FieldData,
     FieldEnvironment,
     FormatData,
+    Generation,
     Id,
+    NativeFunctionRegistry,
     PlayerData,
     PlayerDex,
     PlayerOptions,
     PlayerType,
     PublicCoreBattle,
+    RaidBossOptions,
     Rule,
     SerializedRuleSet,
     SideData,
     TeamData,
     TimeOfDay,
+    Tracer,
     WildPlayerOptions,
 };
 
-use crate::ControlledRandomNumberGenerator;
+use crate::{
+    ControlledRandomNumberGenerator,
+    CoverageFileWriter,
+};
 
 /// Battle builder object for integration tests.
 pub struct TestBattleBuilder {
@@ -60,6 +72,7 @@ impl TestBattleBuilder {
             },
             engine_options: CoreBattleEngineOptions {
                 log_time: false,
+                tracer: CoverageFileWriter::from_env(),
                 ..Default::default()
             },
             teams: HashMap::default(),
@@ -144,11 +157,41 @@ impl TestBattleBuilder {
         self
     }
 
+    pub fn with_turn_history(mut self, turn_history: bool) -> Self {
+        self.engine_options.keep_turn_history = turn_history;
+        self
+    }
+
+    pub fn with_max_turn_history(mut self, max_turn_history: u32) -> Self {
+        self.engine_options.max_turn_history = max_turn_history;
+        self
+    }
+
     pub fn with_team_validation(mut self, team_validation: bool) -> Self {
         self.engine_options.validate_teams = team_validation;
         self
     }
 
+    pub fn with_native_functions(mut self, native_functions: NativeFunctionRegistry) -> Self {
+        self.engine_options.native_functions = native_functions;
+        self
+    }
+
+    pub fn with_fxlang_limits(mut self, fxlang_limits: EvaluationLimits) -> Self {
+        self.engine_options.fxlang_limits = fxlang_limits;
+        self
+    }
+
+    pub fn with_tracer(mut self, tracer: Arc<dyn Tracer>) -> Self {
+        self.engine_options.tracer = Some(tracer);
+        self
+    }
+
+    pub fn with_dex_cache(mut self, dex_cache: Arc<dyn DexCache>) -> Self {
+        self.engine_options.dex_cache = Some(dex_cache);
+        self
+    }
+
     pub fn with_battle_type(mut self, battle_type: BattleType) -> Self {
         self.options.format.battle_type = battle_type;
         self
@@ -227,6 +270,45 @@ impl TestBattleBuilder {
         self
     }
 
+    /// Adds a horde of wild Mons to side 2.
+    ///
+    /// Unlike [`Self::with_team`], the team is set directly on the player, so that the horde is
+    /// split into one wild player per Mon when the battle is created.
+    pub fn add_horde_to_side_2(
+        mut self,
+        id: &str,
+        name: &str,
+        options: WildPlayerOptions,
+        team: TeamData,
+    ) -> Self {
+        self.options.side_2.players.push(PlayerData {
+            id: id.to_owned(),
+            name: name.to_owned(),
+            player_type: PlayerType::Wild(options),
+            player_options: PlayerOptions::default(),
+            team,
+            dex: PlayerDex::default(),
+        });
+        self
+    }
+
+    pub fn add_raid_boss_to_side_2(
+        mut self,
+        id: &str,
+        name: &str,
+        options: RaidBossOptions,
+    ) -> Self {
+        self.options.side_2.players.push(PlayerData {
+            id: id.to_owned(),
+            name: name.to_owned(),
+            player_type: PlayerType::RaidBoss(Box::new(options)),
+            player_options: PlayerOptions::default(),
+            team: TeamData::default(),
+            dex: PlayerDex::default(),
+        });
+        self
+    }
+
     pub fn with_team(mut self, player_id: &str, team: TeamData) -> Self {
         self.teams.insert(player_id.to_owned(), team);
         self
@@ -255,6 +337,14 @@ impl TestBattleBuilder {
         self
     }
 
+    pub fn with_mechanics(mut self, mechanics: Generation) -> Self {
+        self.options.format.rules.insert(Rule::Value {
+            name: Id::from("Mechanics"),
+            value: mechanics.number().to_string(),
+        });
+        self
+    }
+
     fn with_boolean_rule(mut self, name: &str, value: bool) -> Self {
         let rule = Rule::Value {
             name: Id::from(name),
@@ -0,0 +1,55 @@
+use std::sync::{
+    Arc,
+    Mutex,
+};
+
+use battler::{
+    TraceRecord,
+    Tracer,
+};
+
+/// A [`Tracer`] that collects fxlang trace records in memory.
+#[derive(Debug, Default)]
+pub struct TraceCollector {
+    records: Mutex<Vec<TraceRecord>>,
+}
+
+impl TraceCollector {
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self::default())
+    }
+
+    /// All records collected so far.
+    pub fn records(&self) -> Vec<TraceRecord> {
+        self.records.lock().unwrap().clone()
+    }
+
+    /// Clears all records collected so far.
+    pub fn clear(&self) {
+        self.records.lock().unwrap().clear();
+    }
+
+    /// The callbacks evaluated so far, in order, formatted as `{effect}:{event}`.
+    pub fn callbacks(&self) -> Vec<String> {
+        self.records
+            .lock()
+            .unwrap()
+            .iter()
+            .filter_map(|record| match record {
+                TraceRecord::Callback { event, effect, .. } => Some(format!("{effect}:{event}")),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+impl Tracer for TraceCollector {
+    fn record(&self, record: TraceRecord) {
+        self.records.lock().unwrap().push(record);
+    }
+}
+
+/// Asserts that the given callbacks were evaluated in order, since the collector was last cleared.
+pub fn assert_traced_callbacks_eq(collector: &TraceCollector, expected: &[&str]) {
+    pretty_assertions::assert_eq!(collector.callbacks(), expected);
+}
@@ -1,4 +1,9 @@
+use battler_choice::RotateDirection;
 use battler_data::Id;
+use serde::{
+    Deserialize,
+    Serialize,
+};
 
 use crate::battle::{
     MonHandle,
@@ -8,7 +13,7 @@ use crate::battle::{
 };
 
 /// A Mon action.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct MonAction {
     pub mon: MonHandle,
     pub speed: u32,
@@ -29,7 +34,7 @@ pub struct TeamActionInput {
 }
 
 /// A Team Preview action.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TeamAction {
     pub mon_action: MonAction,
     pub index: usize,
@@ -57,7 +62,7 @@ pub struct SwitchActionInput {
 }
 
 /// A switch action.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SwitchAction {
     pub instant: bool,
     pub mon_action: MonAction,
@@ -89,10 +94,11 @@ pub struct MoveActionInput {
     pub ultra: bool,
     pub dyna: bool,
     pub tera: bool,
+    pub rotate: Option<RotateDirection>,
 }
 
 /// A move action.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct MoveAction {
     pub action_id: usize,
     pub id: Id,
@@ -105,6 +111,8 @@ pub struct MoveAction {
     pub ultra: bool,
     pub dyna: bool,
     pub tera: bool,
+    #[serde(default)]
+    pub rotate: Option<RotateDirection>,
     pub order: Option<u32>,
     pub priority: i32,
     pub sub_priority: i32,
@@ -130,6 +138,7 @@ impl MoveAction {
             ultra: input.ultra,
             dyna: input.dyna,
             tera: input.tera,
+            rotate: input.rotate,
             order: None,
             priority: 0,
             sub_priority: 0,
@@ -155,7 +164,7 @@ pub struct BeforeMoveActionInput {
 }
 
 /// A before move action.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct BeforeMoveAction {
     pub id: Id,
     pub mon_action: MonAction,
@@ -178,7 +187,7 @@ impl BeforeMoveAction {
 }
 
 /// An experience action.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ExperienceAction {
     pub mon: MonHandle,
     pub player_index: usize,
@@ -188,21 +197,21 @@ pub struct ExperienceAction {
 }
 
 /// A level up action.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct LevelUpAction {
     pub mon: MonHandle,
     pub level: Option<u8>,
 }
 
 /// A learn move action.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct LearnMoveAction {
     pub mon: MonHandle,
     pub forget_move_slot: usize,
 }
 
 /// An end action, which ends the battle.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct EndAction {
     pub winning_side: Option<usize>,
 }
@@ -214,7 +223,7 @@ pub struct EscapeActionInput {
 }
 
 /// An escape action.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct EscapeAction {
     pub mon_action: MonAction,
 }
@@ -229,7 +238,7 @@ impl EscapeAction {
 }
 
 /// A switch events action.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SwitchEventsAction {
     pub mon_action: MonAction,
 }
@@ -244,7 +253,7 @@ impl SwitchEventsAction {
 }
 
 /// A forfeit action.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ForfeitAction {
     pub player: usize,
     pub order: u32,
@@ -259,7 +268,7 @@ pub struct ItemActionInput {
 }
 
 /// An item action.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ItemAction {
     pub mon_action: MonAction,
     pub item: Id,
@@ -280,7 +289,7 @@ impl ItemAction {
 }
 
 /// A shift action.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ShiftAction {
     pub mon_action: MonAction,
     pub position: usize,
@@ -304,7 +313,7 @@ pub struct SelectActionInput {
 }
 
 /// A select action.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SelectAction {
     pub mon_action: MonAction,
     pub position: usize,
@@ -321,7 +330,7 @@ impl SelectAction {
 }
 
 /// An outside effect action.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OutsideEffectAction {
     pub outside_effect: OutsideEffect,
     pub order: u32,
@@ -340,7 +349,7 @@ impl Eq for OutsideEffectAction {}
 /// Actions are the core of a battle. A turn of a battle consists of several actions running
 /// sequentially. Actions can also be run outside of a turn for configuration and miscellaneous
 /// purposes.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Action {
     Start,
     End(EndAction),
@@ -1,6 +1,8 @@
 use alloc::{
     boxed::Box,
     format,
+    sync::Arc,
+    vec,
 };
 
 use anyhow::Result;
@@ -18,11 +20,19 @@ use crate::{
         BattleType,
         FieldData,
         PlayerData,
+        RaidBossOptions,
         SideData,
     },
     common::Clock,
     config::FormatData,
+    dex::DexCache,
+    effect::fxlang::{
+        EvaluationLimits,
+        NativeFunctionRegistry,
+        Tracer,
+    },
     error::general_error,
+    teams::TeamData,
 };
 
 /// Battle engine option for how base damage should be randomized in the damage calculation.
@@ -212,6 +222,49 @@ pub struct CoreBattleEngineOptions {
     /// If false, a `continue` log will be added instead.
     #[serde(default = "default_true")]
     pub log_time: bool,
+
+    /// Should a restorable checkpoint be saved at the start of each turn?
+    ///
+    /// If set to `true`, the battle can be rewound to the start of any earlier turn with
+    /// [`PublicCoreBattle::rewind_to_turn`][`crate::battle::PublicCoreBattle::rewind_to_turn`].
+    /// This is useful for practice battles, but should be disabled for competitive battles.
+    #[serde(default)]
+    pub keep_turn_history: bool,
+
+    /// The maximum number of turn checkpoints kept when [`Self::keep_turn_history`] is enabled.
+    ///
+    /// When a new checkpoint is saved and the limit is reached, the checkpoint for the oldest turn
+    /// is removed, so the battle can no longer be rewound to that turn.
+    #[serde(default = "default_100")]
+    pub max_turn_history: u32,
+
+    /// Native functions that can be called from fxlang programs, in addition to built-in
+    /// functions.
+    ///
+    /// Allows embedding code to extend battle mechanics without modifying the battle engine.
+    #[serde(skip)]
+    pub native_functions: NativeFunctionRegistry,
+
+    /// Tracer that receives structured records of fxlang evaluation.
+    ///
+    /// Records every event dispatch, callback, statement, and variable access. Helpful for
+    /// debugging interactions between effects. Tracing is disabled when no tracer is set.
+    #[serde(skip)]
+    pub tracer: Option<Arc<dyn Tracer>>,
+
+    /// Limits on fxlang evaluation.
+    ///
+    /// Should be set when the battle runs untrusted programs, such as outside effects and outside
+    /// conditions uploaded by players.
+    #[serde(default)]
+    pub fxlang_limits: EvaluationLimits,
+
+    /// Cache of resources and compiled fxlang programs, shared with other battles.
+    ///
+    /// Battles that use the same data should share a single cache, so that each resource is only
+    /// read and each fxlang program is only compiled once.
+    #[serde(skip)]
+    pub dex_cache: Option<Arc<dyn DexCache>>,
 }
 
 impl Default for CoreBattleEngineOptions {
@@ -233,6 +286,12 @@ impl Default for CoreBattleEngineOptions {
             disambiguate_identical_names: false,
             log_catch_rate: false,
             log_time: true,
+            keep_turn_history: false,
+            max_turn_history: 100,
+            native_functions: NativeFunctionRegistry::default(),
+            tracer: None,
+            fxlang_limits: EvaluationLimits::default(),
+            dex_cache: None,
         }
     }
 }
@@ -280,6 +339,31 @@ impl CoreBattleOptions {
                     )));
                 }
             }
+            BattleType::Rotation => {
+                if players_on_side > 1 {
+                    return Err(general_error(format!(
+                        "{} has too many players for a rotation battle",
+                        side.name
+                    )));
+                }
+            }
+            BattleType::Horde => {
+                if players_on_side > 1
+                    && side.players.iter().any(|player| !player.player_type.wild())
+                {
+                    return Err(general_error(format!(
+                        "{} has too many players for a horde battle (only wild players can form a horde)",
+                        side.name
+                    )));
+                }
+                if players_on_side > BattleType::MAX_HORDE_SIZE {
+                    return Err(general_error(format!(
+                        "{} has too many wild Mons for a horde battle (at most {} are allowed)",
+                        side.name,
+                        BattleType::MAX_HORDE_SIZE,
+                    )));
+                }
+            }
             _ => (),
         }
         for player in &side.players {
@@ -288,7 +372,38 @@ impl CoreBattleOptions {
         Ok(())
     }
 
-    fn validate_player(&self, _: &SideData, _: &PlayerData) -> Result<()> {
+    fn validate_player(&self, side: &SideData, player: &PlayerData) -> Result<()> {
+        if player.player_type.raid_boss().is_some() {
+            if side.players.len() > 1 {
+                return Err(general_error(format!(
+                    "{} must be the only player on {} to be a raid boss",
+                    player.name, side.name,
+                )));
+            }
+            if self.format.battle_type.active_per_player() != 1 {
+                return Err(general_error(
+                    "raid bosses can only battle in battle types with one active mon per player",
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    fn validate_raid(&self, boss_side: &SideData, raid_side: &SideData) -> Result<()> {
+        if !boss_side
+            .players
+            .iter()
+            .any(|player| player.player_type.raid_boss().is_some())
+        {
+            return Ok(());
+        }
+        if raid_side.players.len() > RaidBossOptions::MAX_RAIDERS {
+            return Err(general_error(format!(
+                "{} has too many players for a raid (at most {} are allowed)",
+                raid_side.name,
+                RaidBossOptions::MAX_RAIDERS,
+            )));
+        }
         Ok(())
     }
 
@@ -296,6 +411,66 @@ impl CoreBattleOptions {
     pub fn validate(&self) -> Result<()> {
         self.validate_side(&self.side_1)?;
         self.validate_side(&self.side_2)?;
+        self.validate_raid(&self.side_1, &self.side_2)?;
+        self.validate_raid(&self.side_2, &self.side_1)?;
+        if self.format.battle_type == BattleType::Horde
+            && self.side_1.players.len() > 1
+            && self.side_2.players.len() > 1
+        {
+            return Err(general_error(
+                "a horde battle must have a single player on one side",
+            ));
+        }
         Ok(())
     }
+
+    /// Splits wild players with multiple Mons into hordes.
+    ///
+    /// In a horde battle, each wild Mon must be controlled by its own wild player, so that each
+    /// Mon occupies its own position on the field. This allows a horde to be described
+    /// declaratively by a single wild player whose team consists of every Mon in the horde. Each
+    /// Mon is split out into a new wild player with ID `{id}-{i}`, in team order. The original
+    /// player's bag is given to the first Mon.
+    ///
+    /// Hordes are only split when the battle is created, so a horde cannot be formed by updating
+    /// a wild player's team later.
+    ///
+    /// Has no effect in other battle types.
+    pub fn split_hordes(&mut self) {
+        if self.format.battle_type != BattleType::Horde {
+            return;
+        }
+        Self::split_hordes_on_side(&mut self.side_1);
+        Self::split_hordes_on_side(&mut self.side_2);
+    }
+
+    fn split_hordes_on_side(side: &mut SideData) {
+        side.players = side
+            .players
+            .drain(..)
+            .flat_map(|player| {
+                if !player.player_type.wild() || player.team.members.len() <= 1 {
+                    return vec![player];
+                }
+                let mut bag = Some(player.team.bag);
+                player
+                    .team
+                    .members
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, mon)| PlayerData {
+                        id: format!("{}-{i}", player.id),
+                        name: player.name.clone(),
+                        player_type: player.player_type.clone(),
+                        player_options: player.player_options.clone(),
+                        team: TeamData {
+                            members: vec![mon],
+                            bag: bag.take().unwrap_or_default(),
+                        },
+                        dex: player.dex.clone(),
+                    })
+                    .collect()
+            })
+            .collect();
+    }
 }
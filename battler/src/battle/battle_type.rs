@@ -23,9 +23,23 @@ pub enum BattleType {
     /// Three Mons from one player battles at a time.
     #[string = "Triples"]
     Triples,
+    /// Three Mons from one player are on the field at a time, but only the Mon at the front
+    /// battles.
+    ///
+    /// Mons rotate to the front before using a move.
+    #[string = "Rotation"]
+    Rotation,
+    /// One Mon from a single player battles a horde of up to five wild Mons at a time.
+    ///
+    /// Each wild Mon in the horde is controlled by its own wild player.
+    #[string = "Horde"]
+    Horde,
 }
 
 impl BattleType {
+    /// The maximum number of wild Mons in a horde.
+    pub const MAX_HORDE_SIZE: usize = 5;
+
     /// The number of active Mons per player.
     pub fn active_per_player(&self) -> usize {
         match self {
@@ -33,6 +47,16 @@ impl BattleType {
             Self::Doubles => 2,
             Self::Multi => 1,
             Self::Triples => 3,
+            Self::Rotation => 3,
+            Self::Horde => 1,
+        }
+    }
+
+    /// The number of active Mons per player that can act in a single turn.
+    pub fn acting_per_player(&self) -> usize {
+        match self {
+            Self::Rotation => 1,
+            _ => self.active_per_player(),
         }
     }
 
@@ -48,16 +72,29 @@ impl BattleType {
             Self::Doubles => 4,
             Self::Multi => 3,
             Self::Triples => 6,
+            Self::Rotation => 4,
+            Self::Horde => 3,
         }
     }
 
     /// Returns true if the battle type supports uneven sides.
     pub fn can_have_uneven_sides(&self) -> bool {
         match self {
-            Self::Multi => true,
+            Self::Multi | Self::Horde => true,
             _ => false,
         }
     }
+
+    /// The default adjacency reach for the battle format.
+    ///
+    /// A horde requires a reach of 3, since the Mons on the edges of the horde are three steps
+    /// away from the single Mon in the center.
+    pub fn default_adjacency_reach(&self) -> u8 {
+        match self {
+            Self::Horde => 3,
+            _ => 2,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -76,6 +113,8 @@ mod battle_type_test {
         test_string_serialization(BattleType::Doubles, "Doubles");
         test_string_serialization(BattleType::Multi, "Multi");
         test_string_serialization(BattleType::Triples, "Triples");
+        test_string_serialization(BattleType::Rotation, "Rotation");
+        test_string_serialization(BattleType::Horde, "Horde");
     }
 
     #[test]
@@ -84,5 +123,7 @@ mod battle_type_test {
         test_string_deserialization("doubles", BattleType::Doubles);
         test_string_deserialization("multi", BattleType::Multi);
         test_string_deserialization("triples", BattleType::Triples);
+        test_string_deserialization("rotation", BattleType::Rotation);
+        test_string_deserialization("horde", BattleType::Horde);
     }
 }
@@ -17,6 +17,7 @@ use core::{
 };
 
 use anyhow::Result;
+use battler_choice::RotateDirection;
 use battler_data::{
     ConditionData,
     DataStore,
@@ -38,6 +39,10 @@ use hashbrown::{
     hash_map::Entry,
 };
 use itertools::Itertools;
+use serde::{
+    Deserialize,
+    Serialize,
+};
 use zone_alloc::{
     ElementRef,
     ElementRefMut,
@@ -49,11 +54,17 @@ use crate::{
         Action,
         BattleQueue,
         BattleRegistry,
+        BattleReplay,
+        BattleType,
         Context,
         CoreBattleEngineOptions,
         CoreBattleEngineRandomizeBaseDamage,
         CoreBattleOptions,
+        CoreBattleSnapshot,
         EndAction,
+        EventExplanation,
+        EventSource,
+        EventTarget,
         Field,
         FieldEffectCache,
         LearnMoveRequest,
@@ -68,6 +79,7 @@ use crate::{
         Player,
         PlayerBattleData,
         PlayerContext,
+        ReplayChoice,
         Request,
         RequestType,
         SelectPosition,
@@ -76,21 +88,27 @@ use crate::{
         SpeedOrderable,
         SwitchRequest,
         TeamPreviewRequest,
+        TurnHistory,
         TurnRequest,
         core_battle_actions,
         core_battle_effects,
         core_battle_logs,
         evaluate_outside_effect,
+        raid,
         shift,
         speed_sort,
     },
     battle_log_entry,
     common::{
+        ChunkedVec,
         Clock,
         UnsafelyDetachBorrowMut,
     },
     conditions::Condition,
-    config::Format,
+    config::{
+        Format,
+        Generation,
+    },
     dex::Dex,
     effect::{
         Effect,
@@ -101,6 +119,7 @@ use crate::{
         fxlang,
     },
     error::{
+        ConvertError,
         ValidationError,
         WrapError,
         WrapOptionError,
@@ -126,9 +145,17 @@ use crate::{
 pub struct PublicCoreBattle<'d> {
     /// The internal [`CoreBattle`], which contains all battle objects and logic.
     pub internal: CoreBattle<'d>,
+    turn_history: TurnHistory<'d>,
 }
 
 impl<'d> PublicCoreBattle<'d> {
+    fn from_internal(internal: CoreBattle<'d>) -> Self {
+        Self {
+            internal,
+            turn_history: TurnHistory::new(),
+        }
+    }
+
     /// Creates a new battle.
     pub fn new(
         options: CoreBattleOptions,
@@ -136,10 +163,69 @@ impl<'d> PublicCoreBattle<'d> {
         engine_options: CoreBattleEngineOptions,
     ) -> Result<Self> {
         let internal = CoreBattle::new(options, data, engine_options)?;
-        Ok(Self { internal })
+        Ok(Self::from_internal(internal))
+    }
+
+    /// Restores a battle from a snapshot created by [`Self::snapshot`].
+    ///
+    /// The battle must be restored with the same data that the original battle was created with.
+    /// Engine options are taken from the snapshot.
+    pub fn restore(snapshot: CoreBattleSnapshot, data: &'d dyn DataStore) -> Result<Self> {
+        let internal = CoreBattle::restore(snapshot, data)?;
+        Ok(Self::from_internal(internal))
+    }
+
+    /// Creates a snapshot of the entire battle state, which can be serialized and later restored
+    /// with [`Self::restore`].
+    pub fn snapshot(&self) -> Result<CoreBattleSnapshot> {
+        self.internal.snapshot()
+    }
+
+    /// Forks the battle, creating an independent copy of the entire battle state.
+    ///
+    /// Forking is designed to be cheap enough to be done many times for lookahead search: parsed
+    /// fxlang effects are shared between the original battle and the fork, and no battle data is
+    /// serialized. Choices made on the fork do not affect the original battle.
+    ///
+    /// The fork continues the random number sequence of the original battle, so the fork
+    /// produces the same outcome as the original battle for the same choices. Use
+    /// [`Self::fork_with_prng`] to sample different outcomes.
+    pub fn fork(&self) -> Result<Self> {
+        let internal = self.internal.fork_with_same_prng()?;
+        Ok(Self::from_internal(internal))
+    }
+
+    /// Forks the battle, like [`Self::fork`], but uses the given random number generator in the
+    /// fork.
+    pub fn fork_with_prng(&self, prng: Box<dyn PseudoRandomNumberGenerator>) -> Result<Self> {
+        let internal = self.internal.fork(prng)?;
+        Ok(Self::from_internal(internal))
+    }
+
+    /// Creates a replay of the battle so far, which can be serialized and later replayed with
+    /// [`Self::replay`].
+    ///
+    /// Fails if the battle has not started.
+    pub fn replay_record(&self) -> Result<BattleReplay> {
+        self.internal.replay_record()
+    }
+
+    /// Replays a battle from a replay created by [`Self::replay_record`].
+    ///
+    /// The battle must be replayed with the same data that the original battle was created with.
+    /// Engine options are taken from the replay.
+    ///
+    /// Fails with a [`ReplayDivergenceError`][`crate::error::ReplayDivergenceError`] if the
+    /// regenerated battle log does not match the recorded battle log.
+    pub fn replay(replay: &BattleReplay, data: &'d dyn DataStore) -> Result<Self> {
+        let internal = CoreBattle::replay(replay, data)?;
+        Ok(Self::from_internal(internal))
     }
 
     /// Updates a player's team.
+    ///
+    /// In a horde battle, a wild player cannot be given multiple Mons. Hordes must be declared in
+    /// the [`CoreBattleOptions`] instead (see [`CoreBattleOptions::split_hordes`]).
     pub fn update_team(&mut self, player_id: &str, team: TeamData) -> Result<()> {
         self.internal.update_team(player_id, team)
     }
@@ -182,7 +268,8 @@ impl<'d> PublicCoreBattle<'d> {
 
     /// Starts the battle.
     pub fn start(&mut self) -> Result<()> {
-        self.internal.start()
+        self.internal.start()?;
+        self.save_turn_checkpoint()
     }
 
     /// Is the battle ready to continue?
@@ -195,7 +282,8 @@ impl<'d> PublicCoreBattle<'d> {
     /// [`Self::ready_to_continue`] should return `Ok(true)` before this method
     /// is called.
     pub fn continue_battle(&mut self) -> Result<()> {
-        self.internal.continue_battle()
+        self.internal.continue_battle()?;
+        self.save_turn_checkpoint()
     }
 
     /// Returns the player data for the battle by player ID.
@@ -218,7 +306,56 @@ impl<'d> PublicCoreBattle<'d> {
 
     /// Sets the player's choice for their active request.
     pub fn set_player_choice(&mut self, player_id: &str, input: &str) -> Result<()> {
-        self.internal.set_player_choice(player_id, input)
+        self.internal.set_player_choice(player_id, input)?;
+        self.save_turn_checkpoint()
+    }
+
+    /// Returns all turns that the battle can be rewound to with [`Self::rewind_to_turn`].
+    pub fn turn_history(&self) -> impl Iterator<Item = u64> + '_ {
+        self.turn_history.turns()
+    }
+
+    /// Rewinds the battle to the start of the given turn.
+    ///
+    /// All battle state is restored to the checkpoint saved when the turn started, the battle log
+    /// is truncated to the `turn` log entry, and the requests for the turn are reissued. Logs that
+    /// remain after truncation are considered read, so clients should use [`Self::full_log`] to
+    /// resynchronize.
+    ///
+    /// Requires [`CoreBattleEngineOptions::keep_turn_history`] to be enabled.
+    pub fn rewind_to_turn(&mut self, turn: u64) -> Result<()> {
+        if !self.internal.engine_options.keep_turn_history {
+            return Err(general_error("turn history is disabled"));
+        }
+        let mut internal = self
+            .turn_history
+            .checkpoint(turn)
+            .wrap_not_found_error_with_format(format_args!("checkpoint for turn {turn}"))?
+            .fork_with_same_prng()?;
+        internal.log.mark_read();
+        self.turn_history.truncate(turn);
+        self.internal = internal;
+        Ok(())
+    }
+
+    fn save_turn_checkpoint(&mut self) -> Result<()> {
+        if !self.internal.engine_options.keep_turn_history
+            || self.internal.ended
+            || self.internal.request != Some(RequestType::Turn)
+            || self
+                .turn_history
+                .last_turn()
+                .is_some_and(|turn| turn >= self.internal.turn)
+        {
+            return Ok(());
+        }
+        let checkpoint = self.internal.fork_with_same_prng()?;
+        self.turn_history.save(
+            self.internal.turn,
+            checkpoint,
+            self.internal.engine_options.max_turn_history as usize,
+        );
+        Ok(())
     }
 
     /// Automatically ends the battle, deciding the winner dynamically.
@@ -242,9 +379,47 @@ impl<'d> PublicCoreBattle<'d> {
     pub fn push_outside_condition(&mut self, outside_condition: ConditionData) -> Result<()> {
         self.internal.push_outside_condition(outside_condition)
     }
+
+    /// Invalidates all cached battle data and fxlang effects, so that the next lookup of each
+    /// resource and the next evaluation of each callback reads from the underlying
+    /// [`DataStore`].
+    ///
+    /// Intended for development, after data has been edited and reloaded by the [`DataStore`].
+    /// Battle state carries over unchanged. Data already copied into battle state, such as a move
+    /// that is currently being used, is not refreshed. The type chart is reloaded, and the type
+    /// chart overlay of the format's rules is applied again.
+    ///
+    /// If the battle uses a shared
+    /// [`CoreBattleEngineOptions::dex_cache`][`crate::battle::CoreBattleEngineOptions::dex_cache`],
+    /// that cache must be invalidated first.
+    pub fn invalidate_data_caches(&mut self) -> Result<()> {
+        self.internal.invalidate_data_caches()?;
+        for checkpoint in self.turn_history.checkpoints_mut() {
+            checkpoint.invalidate_data_caches()?;
+        }
+        Ok(())
+    }
+
+    /// Explains the ordered list of callbacks the engine would run if the event were dispatched
+    /// to the target now, including the callbacks that would be excluded and why.
+    ///
+    /// The source Mon and effect of the event are optional, and their callbacks are explained
+    /// like they would be when the event is run.
+    ///
+    /// The explanation is computed on a fork, so the battle itself is not affected.
+    pub fn explain_event(
+        &self,
+        event: fxlang::BattleEvent,
+        target: EventTarget,
+        source: EventSource,
+    ) -> Result<EventExplanation> {
+        let mut fork = self.internal.fork_with_same_prng()?;
+        core_battle_effects::explain_event(&mut fork.context(), event, &target, &source)
+    }
 }
 
 /// An entry in the faint queue.
+#[derive(Clone, Serialize, Deserialize)]
 pub struct FaintEntry {
     pub target: MonHandle,
     pub source: Option<MonHandle>,
@@ -252,6 +427,7 @@ pub struct FaintEntry {
 }
 
 /// An entry in the catch queue.
+#[derive(Clone, Serialize, Deserialize)]
 pub struct CatchEntry {
     pub target: MonHandle,
     pub player: usize,
@@ -284,7 +460,7 @@ pub struct CoreBattle<'d> {
     pub faint_queue: VecDeque<FaintEntry>,
     pub catch_queue: VecDeque<CatchEntry>,
     pub engine_options: CoreBattleEngineOptions,
-    pub format: Format,
+    pub format: Arc<Format>,
     pub field: Field,
     pub sides: [Side; 2],
     pub players: Vec<Player>,
@@ -295,7 +471,7 @@ pub struct CoreBattle<'d> {
     player_ids: HashMap<String, usize>,
     effect_handle_cache: HashMap<Id, EffectHandle>,
 
-    outside_conditions: KeyedRegistry<Id, Condition>,
+    outside_conditions: KeyedRegistry<Id, Arc<Condition>>,
 
     turn: u64,
     request: Option<RequestType>,
@@ -313,6 +489,8 @@ pub struct CoreBattle<'d> {
     last_item_log: Option<usize>,
 
     input_log: HashMap<usize, HashMap<u64, String>>,
+    options: Arc<CoreBattleOptions>,
+    choices: ChunkedVec<ReplayChoice>,
 
     _pin: PhantomPinned,
 }
@@ -320,17 +498,24 @@ pub struct CoreBattle<'d> {
 // Block for constructors.
 impl<'d> CoreBattle<'d> {
     fn new(
-        options: CoreBattleOptions,
+        mut options: CoreBattleOptions,
         data: &'d dyn DataStore,
         engine_options: CoreBattleEngineOptions,
     ) -> Result<Self> {
+        options.split_hordes();
         options
             .validate()
             .wrap_error_with_message("battle options are invalid")?;
 
-        let dex = Dex::new(data)?;
+        let mut replay_options = options.clone();
+        let mut dex = Dex::new(data)?;
+        if let Some(dex_cache) = &engine_options.dex_cache {
+            dex.set_shared_cache(dex_cache.clone());
+        }
         let format = Format::new(options.format, &dex)?;
+        dex.apply_type_chart_overlay(&format.rules.type_chart_overlay()?);
         let prng = (engine_options.rng_factory)(options.seed);
+        replay_options.seed = Some(prng.initial_seed());
         let clock = engine_options
             .clock_factory
             .map(|clock_factory| clock_factory());
@@ -378,7 +563,7 @@ impl<'d> CoreBattle<'d> {
             faint_queue,
             catch_queue,
             engine_options,
-            format,
+            format: Arc::new(format),
             field,
             sides: [side_1, side_2],
             players,
@@ -403,6 +588,8 @@ impl<'d> CoreBattle<'d> {
             last_exited: None,
             last_item_log: None,
             input_log,
+            options: Arc::new(replay_options),
+            choices: ChunkedVec::new(),
             _pin: PhantomPinned,
         };
         Self::initialize(&mut battle.context())?;
@@ -411,6 +598,250 @@ impl<'d> CoreBattle<'d> {
     }
 }
 
+// Block for snapshots, forks, and replays.
+impl<'d> CoreBattle<'d> {
+    fn fork_with_same_prng(&self) -> Result<Self> {
+        let mut prng = (self.engine_options.rng_factory)(Some(self.prng.initial_seed()));
+        prng.set_state(self.prng.state().map_err(general_error)?)
+            .map_err(general_error)?;
+        self.fork(prng)
+    }
+
+    fn replay_record(&self) -> Result<BattleReplay> {
+        if !self.started {
+            return Err(general_error(
+                "cannot record a replay of a battle that has not started",
+            ));
+        }
+        Ok(BattleReplay {
+            options: self.options.as_ref().clone(),
+            engine_options: self.engine_options.clone(),
+            choices: self.choices.iter().cloned().collect(),
+            log: self.full_log().map(|line| line.to_owned()).collect(),
+        })
+    }
+
+    fn replay(replay: &BattleReplay, data: &'d dyn DataStore) -> Result<Self> {
+        let mut battle = Self::new(replay.options.clone(), data, replay.engine_options.clone())?;
+        battle.start()?;
+        if !battle.engine_options.auto_continue && battle.ready_to_continue()? {
+            battle.continue_battle()?;
+        }
+
+        for choice in &replay.choices {
+            if battle.turn != choice.turn {
+                replay.check_log(battle.full_log(), true)?;
+                return Err(general_error(format!(
+                    "replay expected {} to make a choice on turn {}, but the battle is on turn {}",
+                    choice.player, choice.turn, battle.turn
+                )));
+            }
+            if let Err(err) = battle.set_player_choice(&choice.player, &choice.input) {
+                replay.check_log(battle.full_log(), true)?;
+                return Err(err).wrap_error_with_format(format_args!(
+                    "failed to replay choice \"{}\" for {} on turn {}",
+                    choice.input, choice.player, choice.turn
+                ));
+            }
+            if !battle.engine_options.auto_continue && battle.ready_to_continue()? {
+                battle.continue_battle()?;
+            }
+        }
+
+        replay.check_log(battle.full_log(), false)?;
+        Ok(battle)
+    }
+
+    fn fork(&self, prng: Box<dyn PseudoRandomNumberGenerator>) -> Result<Self> {
+        let clock = self
+            .engine_options
+            .clock_factory
+            .map(|clock_factory| clock_factory());
+
+        let outside_conditions = KeyedRegistry::default();
+        for (id, condition) in self.outside_conditions.iter() {
+            let condition =
+                condition.map_err(|err| err.convert_error_with_message("outside condition"))?;
+            outside_conditions.register(id.clone(), Arc::clone(&condition));
+        }
+
+        Ok(Self {
+            log: self.log.clone(),
+            prng,
+            clock,
+            dex: self.dex.clone(),
+            queue: self.queue.clone(),
+            faint_queue: self.faint_queue.clone(),
+            catch_queue: self.catch_queue.clone(),
+            engine_options: self.engine_options.clone(),
+            format: self.format.clone(),
+            field: self.field.clone(),
+            sides: self.sides.clone(),
+            players: self.players.clone(),
+            effect_manager: self.effect_manager.clone(),
+            linked_effects_manager: self.linked_effects_manager.clone(),
+            registry: self.registry.try_clone()?,
+            player_ids: self.player_ids.clone(),
+            effect_handle_cache: self.effect_handle_cache.clone(),
+            outside_conditions,
+            turn: self.turn,
+            request: self.request,
+            mid_turn: self.mid_turn,
+            started: self.started,
+            in_pre_battle: self.in_pre_battle,
+            in_residual: self.in_residual,
+            ending: self.ending,
+            ended: self.ended,
+            next_effect_order: self.next_effect_order,
+            next_effect_linked_id: self.next_effect_linked_id,
+            last_move: self.last_move,
+            last_successful_move: self.last_successful_move,
+            last_exited: self.last_exited,
+            last_item_log: self.last_item_log,
+            input_log: self.input_log.clone(),
+            options: self.options.clone(),
+            choices: self.choices.clone(),
+            _pin: PhantomPinned,
+        })
+    }
+
+    fn snapshot(&self) -> Result<CoreBattleSnapshot> {
+        let outside_conditions = self
+            .outside_conditions
+            .values()
+            .map(|condition| {
+                condition
+                    .map(|condition| condition.data.clone())
+                    .map_err(|err| err.convert_error_with_message("outside condition"))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(CoreBattleSnapshot {
+            version: CoreBattleSnapshot::VERSION,
+            format: self.format.data(),
+            engine_options: self.engine_options.clone(),
+            prng_initial_seed: self.prng.initial_seed(),
+            prng_state: self.prng.state().map_err(general_error)?,
+            turn_instructions: self.effect_manager.instruction_count(),
+            log: self.log.clone(),
+            queue: self.queue.clone(),
+            faint_queue: self.faint_queue.clone(),
+            catch_queue: self.catch_queue.clone(),
+            field: self.field.clone(),
+            sides: self.sides.clone(),
+            players: self.players.clone(),
+            linked_effects_manager: self.linked_effects_manager.clone(),
+            registry: self.registry.try_clone()?,
+            effect_handle_cache: self.effect_handle_cache.clone(),
+            outside_conditions,
+            turn: self.turn,
+            request: self.request,
+            mid_turn: self.mid_turn,
+            started: self.started,
+            in_pre_battle: self.in_pre_battle,
+            in_residual: self.in_residual,
+            ending: self.ending,
+            ended: self.ended,
+            next_effect_order: self.next_effect_order,
+            next_effect_linked_id: self.next_effect_linked_id,
+            last_move: self.last_move,
+            last_successful_move: self.last_successful_move,
+            last_exited: self.last_exited,
+            last_item_log: self.last_item_log,
+            input_log: self.input_log.clone(),
+            options: self.options.as_ref().clone(),
+            choices: self.choices.iter().cloned().collect(),
+        })
+    }
+
+    fn restore(snapshot: CoreBattleSnapshot, data: &'d dyn DataStore) -> Result<Self> {
+        if snapshot.version != CoreBattleSnapshot::VERSION {
+            return Err(general_error(format!(
+                "unsupported snapshot version: {} (expected {})",
+                snapshot.version,
+                CoreBattleSnapshot::VERSION
+            )));
+        }
+
+        let mut dex = Dex::new(data)?;
+        if let Some(dex_cache) = &snapshot.engine_options.dex_cache {
+            dex.set_shared_cache(dex_cache.clone());
+        }
+        let format = Format::new(snapshot.format, &dex)?;
+        dex.apply_type_chart_overlay(&format.rules.type_chart_overlay()?);
+        let mut prng = (snapshot.engine_options.rng_factory)(Some(snapshot.prng_initial_seed));
+        prng.set_state(snapshot.prng_state).map_err(general_error)?;
+        let clock = snapshot
+            .engine_options
+            .clock_factory
+            .map(|clock_factory| clock_factory());
+
+        let player_ids = snapshot
+            .players
+            .iter()
+            .enumerate()
+            .map(|(player_index, player)| (player.id.to_owned(), player_index))
+            .collect::<HashMap<_, _>>();
+
+        let mut effect_manager = EffectManager::new();
+        effect_manager.set_instruction_count(snapshot.turn_instructions);
+
+        let outside_conditions = KeyedRegistry::default();
+        for outside_condition in snapshot.outside_conditions {
+            let id = Id::from(outside_condition.name.as_str());
+            outside_conditions
+                .register(id.clone(), Arc::new(Condition::new(id, outside_condition)));
+        }
+
+        Ok(Self {
+            log: snapshot.log,
+            prng,
+            clock,
+            dex,
+            queue: snapshot.queue,
+            faint_queue: snapshot.faint_queue,
+            catch_queue: snapshot.catch_queue,
+            engine_options: snapshot.engine_options,
+            format: Arc::new(format),
+            field: snapshot.field,
+            sides: snapshot.sides,
+            players: snapshot.players,
+            effect_manager,
+            linked_effects_manager: snapshot.linked_effects_manager,
+            registry: snapshot.registry,
+            player_ids,
+            effect_handle_cache: snapshot.effect_handle_cache,
+            outside_conditions,
+            turn: snapshot.turn,
+            request: snapshot.request,
+            mid_turn: snapshot.mid_turn,
+            started: snapshot.started,
+            in_pre_battle: snapshot.in_pre_battle,
+            in_residual: snapshot.in_residual,
+            ending: snapshot.ending,
+            ended: snapshot.ended,
+            next_effect_order: snapshot.next_effect_order,
+            next_effect_linked_id: snapshot.next_effect_linked_id,
+            last_move: snapshot.last_move,
+            last_successful_move: snapshot.last_successful_move,
+            last_exited: snapshot.last_exited,
+            last_item_log: snapshot.last_item_log,
+            input_log: snapshot.input_log,
+            options: Arc::new(snapshot.options),
+            choices: snapshot.choices.into_iter().collect(),
+            _pin: PhantomPinned,
+        })
+    }
+
+    fn invalidate_data_caches(&mut self) -> Result<()> {
+        self.dex.clear_caches()?;
+        self.dex
+            .apply_type_chart_overlay(&self.format.rules.type_chart_overlay()?);
+        self.effect_manager.clear();
+        self.effect_handle_cache.clear();
+        Self::invalidate_effect_caches(&mut self.context())
+    }
+}
+
 // Block for all basic getters.
 impl<'d> CoreBattle<'d> {
     pub fn context<'b>(&'b mut self) -> Context<'b, 'd> {
@@ -624,6 +1055,11 @@ impl<'d> CoreBattle<'d> {
     pub fn turn(&self) -> u64 {
         self.turn
     }
+
+    /// The generation of battle mechanics used by the battle.
+    pub fn mechanics(&self) -> Generation {
+        self.format.rules.numeric_rules.mechanics
+    }
 }
 
 // Block for methods that are only called from the public interface.
@@ -703,6 +1139,28 @@ impl<'d> CoreBattle<'d> {
         }
 
         let player = self.player_index_by_id(player_id)?;
+
+        // Hordes are split into one player per Mon when the battle is created, so they cannot be
+        // formed afterwards.
+        if self.format.battle_type == BattleType::Horde
+            && self.player(player)?.player_type.wild()
+            && team.members.len() > 1
+        {
+            return Err(general_error(
+                "a wild player cannot have multiple Mons in a horde battle (declare the horde in the battle options instead)",
+            ));
+        }
+
+        let options = Arc::make_mut(&mut self.options);
+        if let Some(player_data) = options
+            .side_1
+            .players
+            .iter_mut()
+            .chain(options.side_2.players.iter_mut())
+            .find(|player_data| player_data.id == player_id)
+        {
+            player_data.team = team.clone();
+        }
         let player = self.player_mut(player)?;
 
         // SAFETY: Players, dex, and registry are disjoint. We could use a context instead, but this
@@ -864,6 +1322,9 @@ impl<'d> CoreBattle<'d> {
         if context.player().team_size() == 0 {
             problems.push("Empty team is not allowed.".to_owned());
         }
+        if context.player().player_type.raid_boss().is_some() && context.player().team_size() > 1 {
+            problems.push("Raid bosses must have exactly one Mon.".to_owned());
+        }
         for mon in context.player().mon_handles().cloned().collect::<Vec<_>>() {
             let mut context = context.mon_context(mon)?;
 
@@ -967,6 +1428,34 @@ impl<'d> CoreBattle<'d> {
                 .map(|rule_log| battle_log_entry!("info", ("rule", rule_log))),
         );
 
+        // Log the type chart overlay separately from rule logs, so that clients do not need to
+        // parse rule values.
+        let overlay = context.battle().format.rules.type_chart_overlay()?;
+        if overlay.inverse {
+            context
+                .battle_mut()
+                .log(battle_log_entry!("inversetypechart"));
+        }
+        let mut overrides = overlay
+            .overrides
+            .iter()
+            .flat_map(|(offense, row)| {
+                row.iter()
+                    .map(|(defense, effectiveness)| (*offense, *defense, *effectiveness))
+            })
+            .collect::<Vec<_>>();
+        overrides.sort_by_key(|(offense, defense, _)| (*offense, *defense));
+        context.battle_mut().log_many(overrides.into_iter().map(
+            |(offense, defense, effectiveness)| {
+                battle_log_entry!(
+                    "typechartoverride",
+                    ("offense", offense),
+                    ("defense", defense),
+                    ("multiplier", Into::<f32>::into(effectiveness))
+                )
+            },
+        ));
+
         let side_logs = context
             .battle()
             .sides()
@@ -1133,7 +1622,14 @@ impl<'d> CoreBattle<'d> {
                     let mut context = context.as_battle_context_mut().player_context(player)?;
                     allies.push(Player::request_data(&mut context)?);
                 }
-                Ok(Some(Request::Turn(TurnRequest { active, allies })))
+                let can_rotate = context.battle().format.battle_type == BattleType::Rotation
+                    && active.len() > 1
+                    && active.first().is_some_and(|mon| !mon.locked_into_move);
+                Ok(Some(Request::Turn(TurnRequest {
+                    active,
+                    allies,
+                    can_rotate,
+                })))
             }
             RequestType::Switch => {
                 // We only make a request if there are Mons that need to switch out.
@@ -1204,6 +1700,11 @@ impl<'d> CoreBattle<'d> {
             .get_mut(&player)
             .wrap_not_found_error_with_format(format_args!("input_log for player {player}"))?
             .insert(turn, input.to_owned());
+        context.battle_mut().choices.push(ReplayChoice {
+            turn,
+            player: player_id.to_owned(),
+            input: input.to_owned(),
+        });
 
         if context.battle().engine_options.auto_continue && Self::all_player_choices_done(context)?
         {
@@ -1380,6 +1881,8 @@ impl<'d> CoreBattle<'d> {
                     }
                 }
 
+                raid::log_initial_shields(context)?;
+
                 core_battle_actions::set_default_weather(context)?;
                 core_battle_actions::set_default_terrain(context)?;
 
@@ -1415,6 +1918,9 @@ impl<'d> CoreBattle<'d> {
                 if !context.mon().active || !context.mon().active {
                     return Ok(());
                 }
+                if let Some(direction) = action.rotate {
+                    core_battle_actions::rotate_to_front(&mut context, direction)?;
+                }
                 core_battle_actions::do_move_action(
                     &mut context,
                     action
@@ -1796,8 +2302,33 @@ impl<'d> CoreBattle<'d> {
         Ok(())
     }
 
+    fn rotate_to_fill_front(context: &mut Context) -> Result<()> {
+        for player in context.battle().player_indices().collect::<Vec<_>>() {
+            let mut context = context.player_context(player)?;
+            if context.player().active_mon_handle(0).is_some() {
+                continue;
+            }
+            let rotation = [RotateDirection::Left, RotateDirection::Right]
+                .into_iter()
+                .find_map(|direction| {
+                    context
+                        .player()
+                        .active_mon_handle(context.player().rotation_position(direction))
+                        .map(|mon| (mon, direction))
+                });
+            if let Some((mon, direction)) = rotation {
+                core_battle_actions::rotate_to_front(&mut context.mon_context(mon)?, direction)?;
+            }
+        }
+        Ok(())
+    }
+
     fn next_turn(context: &mut Context) -> Result<()> {
         context.battle_mut().turn += 1;
+        context
+            .battle_mut()
+            .effect_manager
+            .reset_instruction_count();
 
         if context.battle().turn >= 1000 {
             context.battle_mut().log(battle_log_entry!("turnlimit"));
@@ -1856,7 +2387,16 @@ impl<'d> CoreBattle<'d> {
             return Ok(());
         }
 
-        Self::ensure_adjacency(context)?;
+        if let Some(side) = raid::check_turn_limit(context)? {
+            Self::end_battle(context, Some(side))?;
+            return Ok(());
+        }
+
+        if context.battle().format.battle_type == BattleType::Rotation {
+            Self::rotate_to_fill_front(context)?;
+        } else {
+            Self::ensure_adjacency(context)?;
+        }
 
         let turn_event = battle_log_entry!("turn", ("turn", context.battle().turn));
         context.battle_mut().log(turn_event);
@@ -2251,6 +2791,17 @@ impl<'d> CoreBattle<'d> {
         } else {
             context.side().index
         };
+        if context.battle().format.battle_type == BattleType::Rotation {
+            // Only the Mons at the front can be targeted, and the user always acts from the front.
+            if target_location.unsigned_abs() != 1 {
+                return Ok(false);
+            }
+            let relative_location = if target_location > 0 { 1 } else { 0 };
+            return Ok(move_target.valid_target(
+                relative_location,
+                context.battle().format.rules.numeric_rules.adjacency_reach,
+            ));
+        }
         let target_location = target_location.abs() as usize;
         let target_location = target_location - 1;
         if !Mon::relative_location_of_target(&context, target_side, target_location).map_or(
@@ -2372,6 +2923,18 @@ impl<'d> CoreBattle<'d> {
 
     /// Randomizes damage, as part of the damage calculation formula.
     pub fn randomize_base_damage(&mut self, base_damage: u32) -> u32 {
+        if self.mechanics() <= Generation::Gen2 {
+            // Damage is multiplied by a random number between 217 and 255, then divided by 255.
+            let random_factor = match self.engine_options.randomize_base_damage {
+                CoreBattleEngineRandomizeBaseDamage::Randomize => {
+                    rand_util::range(self.prng.as_mut(), 217, 256) as u32
+                }
+                CoreBattleEngineRandomizeBaseDamage::Max => 255,
+                CoreBattleEngineRandomizeBaseDamage::Min => 217,
+            };
+            return base_damage * random_factor / 255;
+        }
+
         let random_factor = match self.engine_options.randomize_base_damage {
             CoreBattleEngineRandomizeBaseDamage::Randomize => {
                 rand_util::range(self.prng.as_mut(), 0, 16) as u32
@@ -2428,6 +2991,10 @@ impl<'d> CoreBattle<'d> {
             context.player_mut().fainted_this_turn = true;
         }
 
+        if let Some(side) = raid::check_faint_limit(context)? {
+            Self::schedule_win(context, Some(side))?;
+        }
+
         Self::check_win(context)?;
 
         if !context.battle().ending {
@@ -2626,6 +3193,7 @@ impl<'d> CoreBattle<'d> {
                     .battle()
                     .outside_conditions
                     .get(id)
+                    .map(|condition| Arc::clone(&condition))
                     .wrap_error_with_format(format_args!("outside condition {id} not found"))?,
             )),
             EffectHandle::NonExistent(effect) => Ok(Effect::for_non_existent(effect.clone())),
@@ -2713,7 +3281,7 @@ impl<'d> CoreBattle<'d> {
         if !context
             .battle_mut()
             .outside_conditions
-            .register(condition.id().clone(), condition)
+            .register(condition.id().clone(), Arc::new(condition))
         {
             return Err(general_error(format!(
                 "outside condition {id} already exists"
@@ -12,6 +12,7 @@ use alloc::{
 use core::marker::PhantomData;
 
 use anyhow::Result;
+use battler_choice::RotateDirection;
 use battler_data::{
     Boost,
     BoostOrderIterator,
@@ -83,8 +84,10 @@ use crate::{
         core_battle_logs,
         modify_32,
         mon_states,
+        raid,
     },
     common::UnsafelyDetachBorrowMut,
+    config::Generation,
     effect::{
         AppliedEffectHandle,
         AppliedEffectLocation,
@@ -1033,6 +1036,13 @@ pub fn modify_move_type(context: &mut ActiveMoveContext, target: Option<MonHandl
     )
     .unwrap_or(context.active_move().data.primary_type);
     context.active_move_mut().data.primary_type = typ;
+
+    // Before the physical/special split, the category of a damaging move depends on its type.
+    if !context.battle().mechanics().has_physical_special_split()
+        && context.active_move().data.category != MoveCategory::Status
+    {
+        context.active_move_mut().data.category = Generation::category_for_type(typ);
+    }
     Ok(())
 }
 
@@ -1079,6 +1089,23 @@ fn use_active_move_internal(
         return Ok(MoveOutcome::Skipped);
     }
 
+    if context
+        .battle()
+        .format
+        .rules
+        .predicate_rules
+        .battle_banned_moves
+        .as_ref()
+        .is_some_and(|battle_banned_moves| battle_banned_moves.matches(&context.active_move().data))
+    {
+        core_battle_logs::fail(
+            context.as_mon_context_mut(),
+            None,
+            Some(EffectHandle::Clause(Id::from_known("battlebannedmoves"))),
+        )?;
+        return Ok(MoveOutcome::Failed);
+    }
+
     let try_move_result = core_battle_effects::run_active_move_event::<EventResult>(
         context,
         fxlang::BattleEvent::TryMove,
@@ -2004,9 +2031,27 @@ pub fn calculate_damage(context: &mut ActiveTargetContext) -> Result<Option<u16>
         fxlang::BattleEvent::ModifyCritRatio,
         crit_ratio,
     );
-    let crit_ratio = crit_ratio.max(0).min(4);
-    let crit_chance = if crit_ratio > 0 {
-        let crit_chance = [24, 8, 2, 1][(crit_ratio - 1) as usize];
+    let mechanics = context.battle().mechanics();
+    // Gen 1 critical hits are based on base Speed, so the critical hit ratio is only used to
+    // distinguish moves with a high critical hit ratio.
+    let crit_chances: &[u32] = match mechanics {
+        Generation::Gen1 => &[],
+        Generation::Gen2 | Generation::Gen3 | Generation::Gen4 | Generation::Gen5 => {
+            &[16, 8, 4, 3, 2]
+        }
+        Generation::Gen6 => &[16, 8, 2, 1],
+        _ => &[24, 8, 2, 1],
+    };
+    let max_crit_ratio = if mechanics == Generation::Gen1 {
+        2
+    } else {
+        crit_chances.len() as u8
+    };
+    let crit_ratio = crit_ratio.max(0).min(max_crit_ratio);
+    let (crit_numerator, crit_chance) = if mechanics == Generation::Gen1 {
+        (gen1_crit_threshold(context, crit_ratio)?, 256)
+    } else if crit_ratio > 0 {
+        let crit_chance = crit_chances[(crit_ratio - 1) as usize];
 
         // Modify chance directly based on fraction, which is easier to reason about.
         let crit_chance = Fraction::new(1, crit_chance);
@@ -2017,21 +2062,27 @@ pub fn calculate_damage(context: &mut ActiveTargetContext) -> Result<Option<u16>
         );
 
         // Convert back to integer.
-        if crit_chance < 0 {
+        let crit_chance = if crit_chance < 0 {
             0
         } else {
             crit_chance.min(1u32.into()).inverse().floor()
-        }
+        };
+        (1, crit_chance)
     } else {
-        0
+        (0, 0)
     };
 
     context
         .active_move_mut()
         .hit_data_mut(target_mon_handle)
         .crit = context.active_move().data.will_crit
-        || (crit_chance > 0
-            && rand_util::chance(context.battle_mut().prng.as_mut(), 1, crit_chance as u64));
+        || (crit_numerator > 0
+            && crit_chance > 0
+            && rand_util::chance(
+                context.battle_mut().prng.as_mut(),
+                crit_numerator as u64,
+                crit_chance as u64,
+            ));
 
     if context
         .active_move_mut()
@@ -2076,18 +2127,23 @@ pub fn calculate_damage(context: &mut ActiveTargetContext) -> Result<Option<u16>
         .boosts
         .get(defense_stat.try_into()?);
 
-    let ignore_offensive = context.active_move().data.ignore_offensive
-        || (context
-            .active_move_mut()
-            .hit_data_mut(target_mon_handle)
-            .crit
-            && attack_boosts < 0);
-    let ignore_defensive = context.active_move().data.ignore_defensive
-        || (context
-            .active_move_mut()
-            .hit_data_mut(target_mon_handle)
-            .crit
-            && defense_boosts > 0);
+    // Critical hits ignore stat stages that would be unfavorable to the attacker.
+    let (ignore_offensive_on_crit, ignore_defensive_on_crit) = match mechanics {
+        Generation::Gen1 => (true, true),
+        Generation::Gen2 => {
+            let ignore = defense_boosts >= attack_boosts;
+            (ignore, ignore)
+        }
+        _ => (attack_boosts < 0, defense_boosts > 0),
+    };
+    let crit = context
+        .active_move_mut()
+        .hit_data_mut(target_mon_handle)
+        .crit;
+    let ignore_offensive =
+        context.active_move().data.ignore_offensive || (crit && ignore_offensive_on_crit);
+    let ignore_defensive =
+        context.active_move().data.ignore_defensive || (crit && ignore_defensive_on_crit);
 
     if ignore_offensive {
         attack_boosts = 0;
@@ -2127,6 +2183,28 @@ pub fn calculate_damage(context: &mut ActiveTargetContext) -> Result<Option<u16>
     modify_damage(context, base_damage).map(|val| Some(val))
 }
 
+/// Calculates the critical hit threshold of an active move under Gen 1 mechanics, out of 256.
+///
+/// The critical hit chance is based on the user's base Speed, and moves with a high critical hit
+/// ratio are eight times as likely to land a critical hit.
+fn gen1_crit_threshold(context: &mut ActiveTargetContext, crit_ratio: u8) -> Result<u32> {
+    if crit_ratio == 0 {
+        return Ok(0);
+    }
+    let species = context
+        .battle()
+        .dex
+        .species
+        .get_by_id(&context.mon().volatile_state.species)?;
+    let threshold = species.data.base_stats.spe as u32 / 2;
+    let threshold = if crit_ratio > 1 {
+        threshold * 8
+    } else {
+        threshold
+    };
+    Ok(threshold.min(255))
+}
+
 /// Calculates the type effectiveness of an effect against a target.
 pub fn type_effectiveness(context: &mut ApplyingEffectContext) -> Result<i8> {
     if context
@@ -2201,7 +2279,11 @@ pub fn type_modifier(context: &mut ApplyingEffectContext) -> Result<i8> {
 fn modify_damage(context: &mut ActiveTargetContext, mut base_damage: u32) -> Result<u16> {
     base_damage += 2;
     if context.active_move().spread_hit {
-        let spread_modifier = Fraction::new(3, 4);
+        let spread_modifier = if context.battle().mechanics() <= Generation::Gen3 {
+            Fraction::new(1, 2)
+        } else {
+            Fraction::new(3, 4)
+        };
         base_damage = modify_32(base_damage, spread_modifier);
     }
 
@@ -2219,7 +2301,16 @@ fn modify_damage(context: &mut ActiveTargetContext, mut base_damage: u32) -> Res
         .hit_data_mut(target_mon_handle)
         .crit;
     if crit {
-        let crit_modifier = Fraction::new(3, 2);
+        let crit_modifier = match context.battle().mechanics() {
+            Generation::Gen1 => {
+                let level = context.mon().level as u32;
+                Fraction::new(2 * level + 5, level + 5)
+            }
+            Generation::Gen2 | Generation::Gen3 | Generation::Gen4 | Generation::Gen5 => {
+                Fraction::new(2, 1)
+            }
+            _ => Fraction::new(3, 2),
+        };
         base_damage = modify_32(base_damage, crit_modifier);
     }
 
@@ -2273,6 +2364,9 @@ fn modify_damage(context: &mut ActiveTargetContext, mut base_damage: u32) -> Res
         base_damage,
     );
 
+    // Raid shields.
+    base_damage = raid::modify_damage_for_shields(context, base_damage)?;
+
     let base_damage = base_damage as u16;
     let base_damage = base_damage.max(1);
     Ok(base_damage)
@@ -2368,6 +2462,7 @@ mod direct_move_step {
             core_battle_logs,
             mon_states,
         },
+        config::Generation,
         effect::fxlang,
     };
 
@@ -2579,7 +2674,8 @@ mod direct_move_step {
                     fxlang::BattleEvent::ModifyAccuracy,
                     *accuracy,
                 );
-                let mut boost = 0;
+                let mut accuracy_boost = 0;
+                let mut evasion_boost = 0;
                 if !context.active_move().data.ignore_accuracy {
                     let boosts = context.mon().volatile_state.boosts.clone();
                     let boosts = core_battle_effects::run_event_with_relay::<_, BoostTable>(
@@ -2587,7 +2683,7 @@ mod direct_move_step {
                         fxlang::BattleEvent::ModifyBoosts,
                         boosts,
                     );
-                    boost = boosts.acc.max(-6).min(6);
+                    accuracy_boost = boosts.acc.clamp(-6, 6);
                 }
                 if !context.active_move().data.ignore_evasion {
                     let boosts = context.target_mon().volatile_state.boosts.clone();
@@ -2596,14 +2692,36 @@ mod direct_move_step {
                         fxlang::BattleEvent::ModifyBoosts,
                         boosts,
                     );
-                    boost = (boost - boosts.eva).max(-6).min(6);
+                    evasion_boost = boosts.eva.clamp(-6, 6);
                 }
-                let multiplier = if boost > 0 {
-                    Fraction::new((3 + boost) as u16, 3)
+                let mechanics = context.battle().mechanics();
+                if mechanics <= Generation::Gen2 {
+                    // Accuracy and evasion stages are applied separately, using truncated
+                    // percentages.
+                    //
+                    // Gen 1 uses the same ratios as stat stages.
+                    static GEN1_ACCURACY_TABLE: [u32; 13] =
+                        [25, 28, 33, 40, 50, 66, 100, 150, 200, 250, 300, 350, 400];
+                    static GEN2_ACCURACY_TABLE: [u32; 13] =
+                        [33, 36, 43, 50, 60, 75, 100, 133, 166, 200, 233, 266, 300];
+                    let table = if mechanics <= Generation::Gen1 {
+                        &GEN1_ACCURACY_TABLE
+                    } else {
+                        &GEN2_ACCURACY_TABLE
+                    };
+                    let value = *accuracy as u32 * table[(6 + accuracy_boost) as usize] / 100
+                        * table[(6 - evasion_boost) as usize]
+                        / 100;
+                    *accuracy = value.min(u8::MAX as u32) as u8;
                 } else {
-                    Fraction::new(3, (3 - boost) as u16)
-                };
-                *accuracy = multiplier.mul(*accuracy as u16).floor() as u8;
+                    let boost = (accuracy_boost - evasion_boost).clamp(-6, 6);
+                    let multiplier = if boost > 0 {
+                        Fraction::new((3 + boost) as u16, 3)
+                    } else {
+                        Fraction::new(3, (3 - boost) as u16)
+                    };
+                    *accuracy = multiplier.mul(*accuracy as u16).floor() as u8;
+                }
             }
         }
 
@@ -2625,6 +2743,11 @@ mod direct_move_step {
         }
 
         let hit = match accuracy {
+            Accuracy::Chance(accuracy) if context.battle().mechanics() == Generation::Gen1 => {
+                // Accuracy is out of 255, so moves with perfect accuracy can still miss.
+                let accuracy = (accuracy as u64 * 255 / 100).min(255);
+                rand_util::chance(context.battle_mut().prng.as_mut(), accuracy, 256)
+            }
             Accuracy::Chance(accuracy) => {
                 rand_util::chance(context.battle_mut().prng.as_mut(), accuracy as u64, 100)
             }
@@ -2674,6 +2797,8 @@ pub fn direct_damage(
         );
     }
 
+    raid::run_thresholds(&mut context.target_context()?)?;
+
     Ok(damage)
 }
 
@@ -2751,6 +2876,8 @@ fn apply_spread_damage(
             source_handle,
         )?;
 
+        raid::break_shield(&mut context)?;
+
         apply_drain(&mut context, *damage)?;
 
         core_battle_effects::run_event_with_input::<_, _, ()>(
@@ -2758,6 +2885,8 @@ fn apply_spread_damage(
             fxlang::BattleEvent::AfterDamage,
             *damage,
         );
+
+        raid::run_thresholds(&mut context.target_context()?)?;
     }
     Ok(())
 }
@@ -6160,7 +6289,8 @@ pub fn try_catch(context: &mut MonContext, target: MonHandle, item: &Id) -> Resu
         .as_battle_context_mut()
         .mon_context(target)?
         .player()
-        .player_type;
+        .player_type
+        .clone();
     if !player_type.catchable() {
         core_battle_logs::uncatchable(context.as_player_context_mut(), target, player_type.wild())?;
         Player::put_item_in_bag(context.as_player_context_mut(), item_id);
@@ -7211,6 +7341,46 @@ pub fn swap_position(
     Ok(EventResult::Advance)
 }
 
+/// Rotates all active Mons of a player in the given direction, so that the Mon rotates to the
+/// front.
+///
+/// Rotating is only legal in Rotation battles, in which only the Mon at the front of the field can
+/// act.
+pub fn rotate_to_front(
+    context: &mut MonContext,
+    direction: RotateDirection,
+) -> Result<EventResult> {
+    if !context.mon().active || context.mon().hp == 0 {
+        return Ok(EventResult::Fail);
+    }
+
+    if context.mon().active_position != Some(context.player().rotation_position(direction)) {
+        return Ok(EventResult::Fail);
+    }
+
+    // Log before applying the rotation, so that the old Mon position is used in the log.
+    core_battle_logs::rotate(context, direction)?;
+
+    context.player_mut().rotate_active_positions(direction);
+
+    let mons = context
+        .player()
+        .field_positions_with_active_or_exited_mon()
+        .map(|(position, mon)| (position, *mon))
+        .collect::<Vec<_>>();
+    for (position, mon) in mons {
+        let mut context = context.as_player_context_mut().mon_context(mon)?;
+        if context.mon().active {
+            context.mon_mut().active_position = Some(position);
+            context.mon_mut().effective_team_position = position;
+        } else {
+            context.mon_mut().old_active_position = Some(position);
+        }
+    }
+
+    Ok(EventResult::Advance)
+}
+
 /// Swaps the positions of two players on the same side.
 ///
 /// This functionality only exists for complex Multi- battles. If two Mons remain that cannot be
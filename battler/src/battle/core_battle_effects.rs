@@ -16,8 +16,13 @@ use battler_data::{
     Type,
 };
 use hashbrown::HashSet;
+use serde::{
+    Deserialize,
+    Serialize,
+};
 
 use crate::{
+    WrapOptionError,
     battle::{
         ActiveMoveContext,
         ApplyingEffectContext,
@@ -95,6 +100,112 @@ pub(crate) enum AllEffectsTarget {
     Residual,
 }
 
+/// The target of an event explained by [`explain_event`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EventTarget {
+    #[serde(rename = "field")]
+    Field,
+    #[serde(rename = "side")]
+    Side { index: usize },
+    #[serde(rename = "player")]
+    Player { id: String },
+    #[serde(rename = "mon")]
+    Mon { player: String, position: usize },
+    /// All effects in the battle, as done for residual events at the end of each turn.
+    #[serde(rename = "residual")]
+    Residual,
+}
+
+/// The source of an event explained by [`explain_event`].
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventSource {
+    /// The active Mon that caused the event.
+    #[serde(default)]
+    pub mon: Option<EventSourceMon>,
+    /// The fxlang ID of the effect that caused the event (e.g., `move:tackle`).
+    #[serde(default)]
+    pub effect: Option<String>,
+}
+
+/// An active Mon that caused an event explained by [`explain_event`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventSourceMon {
+    pub player: String,
+    pub position: usize,
+}
+
+/// The reason a callback found for an event is not run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CallbackExclusionReason {
+    /// The effect is suppressed (e.g., a suppressed ability or item), and the event does not force
+    /// the default callback to run.
+    Suppressed,
+    /// The effect is ending.
+    Ending,
+    /// The effect has not started, and the event excludes unstarted effects.
+    Unstarted,
+    /// The effect has no callback for the event.
+    NoCallback,
+    /// The callback is identical to the callback directly before it, so it is only run once.
+    Duplicate,
+}
+
+/// The values used to order a callback relative to other callbacks for the same event.
+///
+/// Callbacks are ordered by lowest order, then highest priority, then highest speed, then lowest
+/// sub-order, then lowest effect order.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CallbackOrdering {
+    pub order: u32,
+    pub priority: i32,
+    pub sub_order: u32,
+    pub speed: u32,
+    /// Only used for events that order callbacks by the order effects were applied in.
+    pub effect_order: u32,
+}
+
+/// An explanation of a single callback found for an event.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CallbackExplanation {
+    /// The effect the callback belongs to.
+    pub effect: EffectHandle,
+    /// The name of the effect.
+    pub name: String,
+    /// Where the effect is applied in the battle, which determines its owner.
+    pub location: AppliedEffectLocation,
+    /// The modifier of the callback, relative to the event target.
+    pub modifier: fxlang::BattleEventModifier,
+    /// The values used to order the callback.
+    ///
+    /// Not set for callbacks excluded before ordering.
+    pub ordering: Option<CallbackOrdering>,
+    /// Is the effect suppressed?
+    ///
+    /// Suppressed effects still run for events that force the default callback to run.
+    pub suppressed: bool,
+    /// Is the callback tied with an adjacent callback?
+    ///
+    /// Ties are resolved by the battle's speed sort tie resolution when the event actually runs,
+    /// so the order of tied callbacks is not guaranteed.
+    pub speed_tie: bool,
+    /// Why the callback is not run, if it is excluded.
+    pub excluded: Option<CallbackExclusionReason>,
+}
+
+/// An explanation of how an event is dispatched to the callbacks of all applicable effects.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventExplanation {
+    /// The event.
+    pub event: fxlang::BattleEvent,
+    /// The callback lookup layer of the event, which determines which effects are looked up.
+    pub callback_lookup_layer: usize,
+    /// All callbacks found for the event.
+    ///
+    /// Callbacks that run are listed first, in the order they run. Excluded callbacks follow.
+    pub callbacks: Vec<CallbackExplanation>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 struct CallbackHandle {
     applied_effect_handle: AppliedEffectHandle,
@@ -1097,6 +1208,39 @@ fn relay_output(event: fxlang::BattleEvent, options: &RunEventOptions) -> bool {
 }
 
 fn run_callbacks_with_errors<'battle, 'data, Context>(
+    context: &mut Context,
+    event: fxlang::BattleEvent,
+    input: fxlang::VariableInput,
+    options: &RunEventOptions,
+    callbacks: Vec<CallbackHandle>,
+) -> Result<Option<fxlang::Value>>
+where
+    'data: 'battle,
+    Context: EventContext<'battle, 'data>,
+{
+    let tracer = EffectManager::tracer(context.as_battle_context_mut());
+    if let Some(tracer) = &tracer {
+        tracer.record(fxlang::TraceRecord::Event {
+            event,
+            callbacks: callbacks.len(),
+        });
+    }
+
+    let result = run_callbacks_internal_with_errors(context, event, input, options, callbacks);
+
+    if let Some(tracer) = tracer
+        && let Ok(value) = &result
+    {
+        tracer.record(fxlang::TraceRecord::EventResult {
+            event,
+            value: value.clone(),
+        });
+    }
+
+    result
+}
+
+fn run_callbacks_internal_with_errors<'battle, 'data, Context>(
     context: &mut Context,
     event: fxlang::BattleEvent,
     mut input: fxlang::VariableInput,
@@ -1164,6 +1308,13 @@ where
     'data: 'battle,
     Context: EventContext<'battle, 'data>,
 {
+    if let Some(tracer) = EffectManager::tracer(context.as_battle_context_mut()) {
+        tracer.record(fxlang::TraceRecord::Event {
+            event: fxlang::BattleEvent::Residual,
+            callbacks: callbacks.len(),
+        });
+    }
+
     // Ensure we only decrease the duration of each event once.
     let mut duration_decreased = HashSet::new();
 
@@ -1280,16 +1431,25 @@ where
 
 mod callbacks {
     use alloc::{
+        borrow::ToOwned,
         format,
         vec::Vec,
     };
+    use core::cmp::{
+        Ordering,
+        Reverse,
+    };
 
     use anyhow::Result;
     use battler_data::Id;
+    use hashbrown::HashMap;
 
     use super::{
         AllEffectsTarget,
+        CallbackExclusionReason,
+        CallbackExplanation,
         CallbackHandle,
+        CallbackOrdering,
     };
     use crate::{
         WrapOptionError,
@@ -1300,8 +1460,10 @@ mod callbacks {
             Mon,
             MonHandle,
             SpeedOrderable,
+            compare_priority,
             mon_states,
         },
+        config::Generation,
         effect::{
             AppliedEffectLocation,
             EffectHandle,
@@ -1367,29 +1529,27 @@ mod callbacks {
             let ability = context.mon().volatile_state.ability_slot.ability.clone();
             let effective_ability = mon_states::effective_ability(&mut context);
             let suppressed = effective_ability.is_none();
-            if event.force_default_callback() || !suppressed {
-                let ability = effective_ability.unwrap_or(ability);
+            let ability = effective_ability.unwrap_or(ability);
+            let mut callback_handle = CallbackHandle::new(
+                EffectHandle::Ability(ability.id),
+                event,
+                modifier,
+                origin,
+                AppliedEffectLocation::MonAbility(mon),
+            );
+            callback_handle.suppressed = suppressed;
+            callbacks.push(callback_handle);
+
+            for sub_ability in ability.sub_abilities {
                 let mut callback_handle = CallbackHandle::new(
-                    EffectHandle::Ability(ability.id),
+                    EffectHandle::Ability(sub_ability),
                     event,
                     modifier,
                     origin,
-                    AppliedEffectLocation::MonAbility(mon),
+                    AppliedEffectLocation::MonSubAbility(mon),
                 );
                 callback_handle.suppressed = suppressed;
                 callbacks.push(callback_handle);
-
-                for sub_ability in ability.sub_abilities {
-                    let mut callback_handle = CallbackHandle::new(
-                        EffectHandle::Ability(sub_ability),
-                        event,
-                        modifier,
-                        origin,
-                        AppliedEffectLocation::MonSubAbility(mon),
-                    );
-                    callback_handle.suppressed = suppressed;
-                    callbacks.push(callback_handle);
-                }
             }
         }
 
@@ -1399,18 +1559,16 @@ mod callbacks {
         {
             let effective_item = mon_states::effective_item(&mut context);
             let suppressed = effective_item.is_none();
-            if event.force_default_callback() || !suppressed {
-                let item = effective_item.unwrap_or(item);
-                let mut callback_handle = CallbackHandle::new(
-                    EffectHandle::Item(item),
-                    event,
-                    modifier,
-                    origin,
-                    AppliedEffectLocation::MonItem(mon),
-                );
-                callback_handle.suppressed = suppressed;
-                callbacks.push(callback_handle);
-            }
+            let item = effective_item.unwrap_or(item);
+            let mut callback_handle = CallbackHandle::new(
+                EffectHandle::Item(item),
+                event,
+                modifier,
+                origin,
+                AppliedEffectLocation::MonItem(mon),
+            );
+            callback_handle.suppressed = suppressed;
+            callbacks.push(callback_handle);
         }
 
         callbacks.push(CallbackHandle::new(
@@ -1567,20 +1725,18 @@ mod callbacks {
             if let Some(weather) = context.battle().field.weather.clone() {
                 let effective_weather = Field::effective_weather(context);
                 let suppressed = effective_weather.is_none();
-                if event.force_default_callback() || !suppressed {
-                    let weather_handle = context
-                        .battle_mut()
-                        .get_effect_handle_by_id(&effective_weather.unwrap_or(weather))?;
-                    let mut callback_handle = CallbackHandle::new(
-                        weather_handle.clone(),
-                        event,
-                        modifier,
-                        origin,
-                        AppliedEffectLocation::Weather,
-                    );
-                    callback_handle.suppressed = suppressed;
-                    callbacks.push(callback_handle);
-                }
+                let weather_handle = context
+                    .battle_mut()
+                    .get_effect_handle_by_id(&effective_weather.unwrap_or(weather))?;
+                let mut callback_handle = CallbackHandle::new(
+                    weather_handle.clone(),
+                    event,
+                    modifier,
+                    origin,
+                    AppliedEffectLocation::Weather,
+                );
+                callback_handle.suppressed = suppressed;
+                callbacks.push(callback_handle);
             }
         }
 
@@ -1590,20 +1746,18 @@ mod callbacks {
             if let Some(terrain) = context.battle().field.terrain.clone() {
                 let effective_terrain = Field::effective_terrain(context);
                 let suppressed = effective_terrain.is_none();
-                if event.force_default_callback() || !suppressed {
-                    let terrain_handle = context
-                        .battle_mut()
-                        .get_effect_handle_by_id(&effective_terrain.unwrap_or(terrain))?;
-                    let mut callback_handle = CallbackHandle::new(
-                        terrain_handle.clone(),
-                        event,
-                        modifier,
-                        origin,
-                        AppliedEffectLocation::Terrain,
-                    );
-                    callback_handle.suppressed = suppressed;
-                    callbacks.push(callback_handle);
-                }
+                let terrain_handle = context
+                    .battle_mut()
+                    .get_effect_handle_by_id(&effective_terrain.unwrap_or(terrain))?;
+                let mut callback_handle = CallbackHandle::new(
+                    terrain_handle.clone(),
+                    event,
+                    modifier,
+                    origin,
+                    AppliedEffectLocation::Terrain,
+                );
+                callback_handle.suppressed = suppressed;
+                callbacks.push(callback_handle);
             }
         }
 
@@ -1649,9 +1803,7 @@ mod callbacks {
             let terrain = context.battle().field.terrain.clone();
             let effective_terrain = mon_states::effective_terrain(&mut context);
             let suppressed = terrain.is_some() && effective_terrain.is_none();
-            if (effective_terrain.is_some() && !suppressed)
-                || (terrain.is_some() && event.force_default_callback())
-            {
+            if effective_terrain.is_some() || terrain.is_some() {
                 let terrain_handle = context.battle_mut().get_effect_handle_by_id(
                     &effective_terrain
                         .or(terrain)
@@ -1675,9 +1827,7 @@ mod callbacks {
             let weather = context.battle().field.weather.clone();
             let effective_weather = mon_states::effective_weather(&mut context, origin)?;
             let suppressed = weather.is_some() && effective_weather.is_none();
-            if (effective_weather.is_some() && !suppressed)
-                || (weather.is_some() && event.force_default_callback())
-            {
+            if effective_weather.is_some() || weather.is_some() {
                 let weather_handle = context.battle_mut().get_effect_handle_by_id(
                     &effective_weather
                         .or(weather.clone())
@@ -2001,6 +2151,7 @@ mod callbacks {
         pub speed: u32,
         pub sub_order: u32,
         pub effect_order: u32,
+        pub speed_tie: bool,
     }
 
     impl SpeedOrderableCallbackHandle {
@@ -2012,6 +2163,7 @@ mod callbacks {
                 speed,
                 sub_order: 0,
                 effect_order: 0,
+                speed_tie: false,
             }
         }
     }
@@ -2046,7 +2198,7 @@ mod callbacks {
         context: &mut Context,
         event: fxlang::BattleEvent,
         callback_handle: CallbackHandle,
-    ) -> Result<Option<SpeedOrderableCallbackHandle>> {
+    ) -> Result<Result<SpeedOrderableCallbackHandle, CallbackExclusionReason>> {
         // Ensure the effect is not ending.
         let effect_order = if let Some(effect_state) = callback_handle
             .applied_effect_handle
@@ -2054,10 +2206,11 @@ mod callbacks {
             && effect_state.exists(context)?
         {
             let effect_state = effect_state.get_mut(context)?;
-            if effect_state.ending()
-                || (event.exclude_unstarted_effects() && !effect_state.started())
-            {
-                return Ok(None);
+            if effect_state.ending() {
+                return Ok(Err(CallbackExclusionReason::Ending));
+            }
+            if event.exclude_unstarted_effects() && !effect_state.started() {
+                return Ok(Err(CallbackExclusionReason::Unstarted));
             }
 
             effect_state.effect_order()
@@ -2084,7 +2237,7 @@ mod callbacks {
             .flatten()
         {
             Some(callback) => callback,
-            None => return Ok(None),
+            None => return Ok(Err(CallbackExclusionReason::NoCallback)),
         };
 
         let mut result = SpeedOrderableCallbackHandle::new(callback_handle, speed);
@@ -2092,26 +2245,67 @@ mod callbacks {
         result.priority = callback.priority();
         result.sub_order = callback.sub_order();
         result.effect_order = effect_order;
-        Ok(Some(result))
+        Ok(Ok(result))
     }
 
     fn get_speed_orderable_effect_handle(
         context: &mut Context,
         event: fxlang::BattleEvent,
         callback_handle: CallbackHandle,
-    ) -> Result<Option<SpeedOrderableCallbackHandle>> {
+    ) -> Result<Result<SpeedOrderableCallbackHandle, CallbackExclusionReason>> {
+        // Suppressed effects are only kept for events that must run the default callback.
+        if callback_handle.suppressed && !event.force_default_callback() {
+            return Ok(Err(CallbackExclusionReason::Suppressed));
+        }
         match get_speed_orderable_effect_handle_internal(context, event, callback_handle.clone())? {
-            Some(handle) => Ok(Some(handle)),
-            None => {
+            Ok(handle) => Ok(Ok(handle)),
+            Err(reason) => {
                 if callback_handle.event.force_default_callback() {
-                    Ok(Some(SpeedOrderableCallbackHandle::new(callback_handle, 0)))
+                    Ok(Ok(SpeedOrderableCallbackHandle::new(callback_handle, 0)))
                 } else {
-                    Ok(None)
+                    Ok(Err(reason))
                 }
             }
         }
     }
 
+    /// Orders residual effects for mechanics before Gen 5.
+    ///
+    /// Before Gen 5, field and side effects resolve first. Then, all effects on a single Mon
+    /// resolve together, one Mon at a time in order of Speed. The relative order of effects on the
+    /// same Mon is kept as is. Gen 1 and Gen 2 resolve some effects immediately after a Mon moves
+    /// instead, which is approximated by this same ordering.
+    fn order_residual_effects_by_mon(handles: &mut [SpeedOrderableCallbackHandle]) {
+        // Use the first position of each Mon to resolve speed ties consistently with the original
+        // speed sort.
+        let mut first_index = HashMap::new();
+        for (i, handle) in handles.iter().enumerate() {
+            if let Some(mon) = handle
+                .callback_handle
+                .applied_effect_handle
+                .location
+                .mon_handle()
+            {
+                first_index.entry(mon).or_insert(i);
+            }
+        }
+        handles.sort_by_key(|handle| {
+            match handle
+                .callback_handle
+                .applied_effect_handle
+                .location
+                .mon_handle()
+            {
+                Some(mon) => (
+                    1,
+                    Reverse(handle.speed),
+                    first_index.get(&mon).cloned().unwrap_or(0),
+                ),
+                None => (0, Reverse(0), 0),
+            }
+        });
+    }
+
     pub fn filter_and_order_effects_for_event(
         context: &mut Context,
         event: fxlang::BattleEvent,
@@ -2120,18 +2314,115 @@ mod callbacks {
         let mut speed_orderable_handles = Vec::new();
         speed_orderable_handles.reserve(callback_handles.len());
         for effect_handle in callback_handles {
-            match get_speed_orderable_effect_handle(context, event, effect_handle)? {
-                Some(handle) => speed_orderable_handles.push(handle),
-                None => (),
+            if let Ok(handle) = get_speed_orderable_effect_handle(context, event, effect_handle)? {
+                speed_orderable_handles.push(handle);
             }
         }
 
         CoreBattle::speed_sort(context, speed_orderable_handles.as_mut_slice());
+        if event == fxlang::BattleEvent::Residual
+            && context.battle().mechanics() <= Generation::Gen4
+        {
+            order_residual_effects_by_mon(speed_orderable_handles.as_mut_slice());
+        }
         Ok(speed_orderable_handles
             .into_iter()
             .map(|handle| handle.callback_handle)
             .collect())
     }
+
+    fn explain_callback(
+        context: &Context,
+        callback_handle: CallbackHandle,
+        ordering: Option<CallbackOrdering>,
+        excluded: Option<CallbackExclusionReason>,
+    ) -> CallbackExplanation {
+        let effect = callback_handle.applied_effect_handle.effect_handle;
+        let name = match CoreBattle::get_effect_by_handle(context, &effect) {
+            Ok(effect) => effect.name().to_owned(),
+            Err(_) => format!("{effect:?}"),
+        };
+        CallbackExplanation {
+            effect,
+            name,
+            location: callback_handle.applied_effect_handle.location,
+            modifier: callback_handle.modifier,
+            ordering,
+            suppressed: callback_handle.suppressed,
+            speed_tie: false,
+            excluded,
+        }
+    }
+
+    /// Explains how [`filter_and_order_effects_for_event`] and the deduplication that follows it
+    /// treat the given callbacks.
+    ///
+    /// Speed ties are kept in their original order, so no random numbers are consumed.
+    pub fn explain_effects_for_event(
+        context: &mut Context,
+        event: fxlang::BattleEvent,
+        callback_handles: Vec<CallbackHandle>,
+    ) -> Result<Vec<CallbackExplanation>> {
+        let mut speed_orderable_handles = Vec::new();
+        let mut excluded = Vec::new();
+        for callback_handle in callback_handles {
+            match get_speed_orderable_effect_handle(context, event, callback_handle.clone())? {
+                Ok(handle) => speed_orderable_handles.push(handle),
+                Err(reason) => excluded.push(explain_callback(
+                    context,
+                    callback_handle,
+                    None,
+                    Some(reason),
+                )),
+            }
+        }
+
+        speed_orderable_handles.sort_by(|a, b| compare_priority(a, b));
+        let speed_ties = (0..speed_orderable_handles.len())
+            .map(|i| {
+                let tied = |j: usize| {
+                    speed_orderable_handles.get(j).is_some_and(|other| {
+                        compare_priority(&speed_orderable_handles[i], other) == Ordering::Equal
+                    })
+                };
+                (i > 0 && tied(i - 1)) || tied(i + 1)
+            })
+            .collect::<Vec<_>>();
+        for (handle, speed_tie) in speed_orderable_handles.iter_mut().zip(speed_ties) {
+            handle.speed_tie = speed_tie;
+        }
+        if event == fxlang::BattleEvent::Residual
+            && context.battle().mechanics() <= Generation::Gen4
+        {
+            order_residual_effects_by_mon(speed_orderable_handles.as_mut_slice());
+        }
+
+        let mut callbacks = Vec::with_capacity(speed_orderable_handles.len() + excluded.len());
+        let mut previous: Option<CallbackHandle> = None;
+        for handle in speed_orderable_handles {
+            let ordering = CallbackOrdering {
+                order: handle.order,
+                priority: handle.priority,
+                sub_order: handle.sub_order,
+                speed: handle.speed,
+                effect_order: handle.effect_order,
+            };
+            let duplicate = previous
+                .as_ref()
+                .is_some_and(|previous| *previous == handle.callback_handle);
+            previous = Some(handle.callback_handle.clone());
+            let mut explanation = explain_callback(
+                context,
+                handle.callback_handle,
+                Some(ordering),
+                duplicate.then_some(CallbackExclusionReason::Duplicate),
+            );
+            explanation.speed_tie = handle.speed_tie;
+            callbacks.push(explanation);
+        }
+        callbacks.extend(excluded);
+        Ok(callbacks)
+    }
 }
 
 fn run_event_with_errors<'battle, 'data, Context>(
@@ -2186,6 +2477,75 @@ where
     }
 }
 
+/// Explains how an event would be dispatched to the callbacks of all applicable effects, without
+/// running any callbacks.
+///
+/// Finding effects may run suppression events, so this should be called on a fork of the battle.
+pub fn explain_event(
+    context: &mut Context,
+    event: fxlang::BattleEvent,
+    target: &EventTarget,
+    source: &EventSource,
+) -> Result<EventExplanation> {
+    let (target, target_mon) = match target {
+        EventTarget::Field => (AllEffectsTarget::Field, None),
+        EventTarget::Side { index } => {
+            context.battle().side(*index)?;
+            (AllEffectsTarget::Side(*index), None)
+        }
+        EventTarget::Player { id } => (
+            AllEffectsTarget::Player(context.battle().player_index_by_id(id)?),
+            None,
+        ),
+        EventTarget::Mon { player, position } => {
+            let mon_handle = active_mon_handle(context, player, *position)?;
+            (AllEffectsTarget::Mon(mon_handle), Some(mon_handle))
+        }
+        EventTarget::Residual => (AllEffectsTarget::Residual, None),
+    };
+    let source_mon = match &source.mon {
+        Some(mon) => Some(active_mon_handle(context, &mon.player, mon.position)?),
+        None => None,
+    };
+    let source_effect = match &source.effect {
+        Some(effect) => {
+            let effect_handle = EffectHandle::from_fxlang_id(effect);
+            CoreBattle::get_effect_by_handle(context, &effect_handle)?;
+            Some(effect_handle)
+        }
+        None => None,
+    };
+
+    let origin = event_origin_mon_handle(event, target_mon, source_mon);
+    let mut callbacks = callbacks::find_all_callbacks(context, event, target, source_mon, origin)?;
+    if event.run_callback_on_source_effect()
+        && let Some(source_effect) = source_effect
+    {
+        callbacks.push(CallbackHandle::new(
+            source_effect,
+            event,
+            fxlang::BattleEventModifier::None,
+            origin,
+            AppliedEffectLocation::None,
+        ));
+    }
+    let callbacks = callbacks::explain_effects_for_event(context, event, callbacks)?;
+    Ok(EventExplanation {
+        event,
+        callback_lookup_layer: event.callback_lookup_layer(),
+        callbacks,
+    })
+}
+
+fn active_mon_handle(context: &Context, player: &str, position: usize) -> Result<MonHandle> {
+    let player = context.battle().player_index_by_id(player)?;
+    context
+        .battle()
+        .player(player)?
+        .active_mon_handle(position)
+        .wrap_expectation_with_format(format_args!("no active mon in position {position}"))
+}
+
 fn event_origin_mon_handle(
     event: fxlang::BattleEvent,
     target: Option<MonHandle>,
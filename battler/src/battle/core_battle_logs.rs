@@ -9,6 +9,7 @@ use alloc::{
 };
 
 use anyhow::Result;
+use battler_choice::RotateDirection;
 use battler_data::{
     Boost,
     CopyVolatileType,
@@ -1511,6 +1512,44 @@ pub fn swap(context: &mut ApplyingEffectContext, position: usize) -> Result<()>
     )
 }
 
+pub fn rotate(context: &mut MonContext, direction: RotateDirection) -> Result<()> {
+    let activation = EffectActivationContext {
+        target: Some(context.mon_handle()),
+        additional: Vec::from_iter([format!("direction:{direction}")]),
+        ..Default::default()
+    };
+    effect_activation(
+        context.as_battle_context_mut(),
+        "rotate".to_owned(),
+        activation,
+    )
+}
+
+pub fn raid_shields(context: &mut MonContext, effect: Option<EffectHandle>) -> Result<()> {
+    let shields = context.player().raid_shields;
+    let activation = EffectActivationContext {
+        target: Some(context.mon_handle()),
+        source_effect: effect,
+        additional: Vec::from_iter([format!("shields:{shields}")]),
+        ..Default::default()
+    };
+    effect_activation(
+        context.as_battle_context_mut(),
+        "raidshields".to_owned(),
+        activation,
+    )
+}
+
+pub fn raid_failed(context: &mut PlayerContext, reason: &str) -> Result<()> {
+    let event = battle_log_entry!(
+        "raidfailed",
+        ("player", &context.player().id),
+        ("reason", reason)
+    );
+    context.battle_mut().log(event);
+    Ok(())
+}
+
 pub fn swap_player(context: &mut PlayerContext, position: usize) -> Result<()> {
     let activation = EffectActivationContext {
         player: Some(context.player().index),
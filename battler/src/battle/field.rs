@@ -95,7 +95,7 @@ pub struct FieldData {
 }
 
 /// Cache for field effects.
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct FieldEffectCache {
     pub effective_weather: Option<Option<Id>>,
     pub effective_terrain: Option<Option<Id>>,
@@ -105,6 +105,7 @@ pub struct FieldEffectCache {
 /// on.
 ///
 /// Effects can be applied to the entire field, which will affect all Mons.
+#[derive(Clone, Serialize, Deserialize)]
 pub struct Field {
     pub default_weather: Option<Id>,
     pub default_terrain: Option<Id>,
@@ -16,11 +16,15 @@ mod move_outcome;
 mod outside_effect;
 mod player;
 mod queue;
+pub mod raid;
 mod registry;
+mod replay;
 mod request;
 mod shift;
 mod side;
+mod snapshot;
 mod speed_order;
+mod turn_history;
 pub mod weather_states;
 
 pub use action::{
@@ -77,9 +81,20 @@ pub use context::{
 };
 pub use context_cache::ContextCache;
 pub use core_battle::{
+    CatchEntry,
     CoreBattle,
+    FaintEntry,
     PublicCoreBattle,
 };
+pub use core_battle_effects::{
+    CallbackExclusionReason,
+    CallbackExplanation,
+    CallbackOrdering,
+    EventExplanation,
+    EventSource,
+    EventSourceMon,
+    EventTarget,
+};
 pub use event_result::{
     EventResult,
     EventResultOutput,
@@ -135,6 +150,8 @@ pub use player::{
     PlayerDex,
     PlayerOptions,
     PlayerType,
+    RaidBossOptions,
+    RaidThreshold,
     WildEncounterType,
     WildPlayerOptions,
 };
@@ -146,6 +163,10 @@ pub use registry::{
     MoveHandle,
     MoveRegistry,
 };
+pub use replay::{
+    BattleReplay,
+    ReplayChoice,
+};
 pub use request::{
     LearnMoveRequest,
     Request,
@@ -161,8 +182,10 @@ pub use side::{
     Side,
     SideData,
 };
+pub use snapshot::CoreBattleSnapshot;
 pub use speed_order::{
     SpeedOrderable,
     compare_priority,
     speed_sort,
 };
+pub use turn_history::TurnHistory;
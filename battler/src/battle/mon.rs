@@ -43,10 +43,10 @@ use serde::{
     Deserialize,
     Serialize,
 };
-use zone_alloc::ElementRef;
 
 use crate::{
     battle::{
+        BattleType,
         CoreBattle,
         MonContext,
         MonHandle,
@@ -66,8 +66,10 @@ use crate::{
         core_battle_effects,
         core_battle_logs,
         mon_states,
+        raid,
     },
     battle_log_entry,
+    config::Generation,
     dex::Dex,
     effect::{
         AppliedEffectHandle,
@@ -85,8 +87,6 @@ use crate::{
         BattleLoggable,
         UncommittedBattleLogEntry,
     },
-    mons::Species,
-    moves::Move,
     teams::MonData,
 };
 
@@ -97,7 +97,7 @@ fn default_ball() -> String {
 /// The physical details of a [`Mon`].
 ///
 /// Copied by "Illusion."
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PhysicalMonDetails {
     pub name: String,
     pub species: String,
@@ -169,7 +169,7 @@ impl Display for MonPositionDetails {
 }
 
 /// A single move slot for a Mon.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct MoveSlot {
     pub id: Id,
     pub name: String,
@@ -222,7 +222,7 @@ impl MoveSlot {
 }
 
 /// The effective ability of a [`Mon`][`crate::battle::Mon`].
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct EffectiveAbility {
     /// Ability ID.
     pub id: Id,
@@ -231,7 +231,7 @@ pub struct EffectiveAbility {
 }
 
 /// A single ability slot for a Mon.
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct AbilitySlot {
     pub ability: EffectiveAbility,
     pub effect_state: fxlang::EffectState,
@@ -422,7 +422,7 @@ impl SpeedOrderable for SpeedOrderableMon {
 }
 
 /// Information about a single attack received by a [`Mon`].
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ReceivedAttackEntry {
     pub source: MonHandle,
     pub source_side: usize,
@@ -439,7 +439,7 @@ pub struct CalculateStatContext {
 }
 
 /// How a Mon exited the battle.
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum MonExitType {
     Fainted,
     Caught,
@@ -478,7 +478,7 @@ impl RecalculateStatsHpPolicy {
 }
 
 /// The type of forme change the Mon has undergone.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum MonSpecialFormeChangeType {
     MegaEvolution,
     PrimalReversion,
@@ -487,7 +487,7 @@ pub enum MonSpecialFormeChangeType {
 }
 
 /// State for the Mon going into the next turn.
-#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct MonNextTurnState {
     pub locked_move: Option<String>,
     pub trapped: bool,
@@ -500,7 +500,7 @@ pub struct MonNextTurnState {
 }
 
 /// Switch data for a Mon.
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct MonSwitchState {
     /// The Mon needs to switch out at the end of the turn.
     pub needs_switch: Option<SwitchType>,
@@ -515,13 +515,13 @@ pub struct MonSwitchState {
 }
 
 /// Cache for move slot effects.
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct MoveSlotEffectCache {
     pub typ: Option<Type>,
 }
 
 /// Cache for Mon effects.
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct MonEffectCache {
     pub effective_types: Option<Vec<Type>>,
     pub effective_types_no_added_type: Option<Vec<Type>>,
@@ -554,7 +554,7 @@ pub struct MonEffectCache {
 }
 
 /// Volatile state for a Mon.
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct MonVolatileState {
     /// The current species.
     pub species: Id,
@@ -637,6 +637,7 @@ pub struct MonVolatileState {
 }
 
 /// A Mon in a battle, which battles against other Mons.
+#[derive(Clone, Serialize, Deserialize)]
 pub struct Mon {
     pub player: usize,
     pub side: usize,
@@ -1271,6 +1272,11 @@ impl Mon {
             None => return Ok(false),
         };
 
+        if context.battle().format.battle_type == BattleType::Rotation {
+            // Only the Mons at the front are adjacent to one another.
+            return Ok(context.mon_handle() == other || (position == 0 && other_position == 0));
+        }
+
         if side != other_side {
             let mons_per_side = context.battle().max_side_length();
             if position >= mons_per_side {
@@ -1416,14 +1422,25 @@ impl Mon {
 
             static BOOST_TABLE: [(u16, u16); 7] =
                 [(1, 1), (3, 2), (2, 1), (5, 2), (3, 1), (7, 2), (4, 1)];
+            // Gen 1 and Gen 2 use truncated percentages for negative stat stages.
+            static GEN2_NEGATIVE_BOOST_TABLE: [u16; 7] = [100, 66, 50, 40, 33, 28, 25];
             let boost = boost.max(-6).min(6);
             let (num, den) = BOOST_TABLE[boost.abs() as usize];
             let boost_fraction = Fraction::new(num, den);
+            let mechanics = context.battle().mechanics();
             if boost >= 0 {
                 value = boost_fraction.mul(value).floor();
+            } else if mechanics <= Generation::Gen2 {
+                value = (value as u32
+                    * GEN2_NEGATIVE_BOOST_TABLE[boost.unsigned_abs() as usize] as u32
+                    / 100) as u16;
             } else {
                 value = boost_fraction.inverse().mul(value).floor();
             }
+            // Gen 1 and Gen 2 cap boosted stats at 999.
+            if mechanics <= Generation::Gen2 {
+                value = value.clamp(1, 999);
+            }
         }
         if !unmodified {
             if let Some(modify_event) = match stat {
@@ -2023,6 +2040,12 @@ impl Mon {
             context.mon().base_max_hp
         };
 
+        // Raid bosses have scaled HP.
+        let new_max_hp = match context.player().player_type.raid_boss() {
+            Some(raid_boss) => new_max_hp.saturating_mul(raid_boss.health_multiplier),
+            None => new_max_hp,
+        };
+
         // Mon is being initialized.
         if context.mon().max_hp == 0 || hp_policy == RecalculateStatsHpPolicy::DoNotUpdate {
             context.mon_mut().max_hp = new_max_hp;
@@ -2142,11 +2165,6 @@ impl Mon {
     ) -> Result<bool> {
         let species = context.battle().dex.species.get_by_id(species)?;
 
-        // SAFETY: Nothing we do below will invalidate any data.
-        let species = unsafe {
-            core::mem::transmute::<ElementRef<'_, Species>, ElementRef<'_, Species>>(species)
-        };
-
         let previous_species = context.mon().volatile_state.species.clone();
 
         context.mon_mut().volatile_state.species = species.id().clone();
@@ -2791,10 +2809,6 @@ impl Mon {
         forget_move_slot: usize,
     ) -> Result<()> {
         let mov = context.battle().dex.moves.get_by_id(move_id)?;
-        // SAFETY: The move is borrowed with reference counting, so no mutable reference can be
-        // taken without causing an error elsewhere.
-        let mov =
-            unsafe { core::mem::transmute::<ElementRef<'_, Move>, ElementRef<'_, Move>>(mov) };
         let (forget_move_slot, forget_move_slot_index) =
             match context.mon_mut().base_move_slots.get_mut(forget_move_slot) {
                 None => {
@@ -2938,6 +2952,11 @@ impl Mon {
         }
 
         core_battle_logs::set_hp(context, None, None)?;
+
+        if delta > 0 {
+            raid::run_thresholds(context)?;
+        }
+
         Ok(delta)
     }
 
@@ -1,4 +1,7 @@
-use alloc::string::String;
+use alloc::{
+    format,
+    string::String,
+};
 
 use anyhow::Result;
 use serde::{
@@ -8,7 +11,10 @@ use serde::{
 
 use crate::{
     WrapOptionError,
-    battle::Context,
+    battle::{
+        Context,
+        core_battle_logs,
+    },
     effect::{
         EffectManager,
         fxlang::{
@@ -97,6 +103,17 @@ pub fn evaluate_outside_effect(
             )
         }
     };
-    EffectManager::evaluate_outside_effect(&mut context, event, &outside_effect.program)?;
+    // The program itself may be untrusted, so errors from evaluating it (e.g., exceeding evaluation
+    // limits) only end the effect.
+    if let Err(error) =
+        EffectManager::evaluate_outside_effect(&mut context, event, &outside_effect.program)
+    {
+        core_battle_logs::debug_event_failure(
+            context.battle_context_mut(),
+            event,
+            &outside_effect.name,
+            &format!("{error:#}"),
+        );
+    }
     Ok(())
 }
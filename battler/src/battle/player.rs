@@ -1,4 +1,5 @@
 use alloc::{
+    boxed::Box,
     collections::btree_set::BTreeSet,
     format,
     string::{
@@ -19,6 +20,8 @@ use battler_choice::{
     ItemChoice,
     LearnMoveChoice,
     MoveChoice,
+    RotateChoice,
+    RotateDirection,
     SelectChoice,
     SwitchChoice,
     TeamSelectionChoice,
@@ -152,9 +155,105 @@ impl Default for WildPlayerOptions {
     }
 }
 
+/// A scripted event for a raid boss, which runs when the boss's HP falls to a threshold.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct RaidThreshold {
+    /// Name of the event.
+    ///
+    /// Used as the source effect of anything the program does.
+    pub name: String,
+    /// The HP percentage at or below which the event runs.
+    pub hp_percent: u8,
+    /// Program to execute when the threshold is reached.
+    ///
+    /// Runs like an [`OutsideEffect`][`crate::battle::OutsideEffect`] targeting the raid boss, so
+    /// the boss is available as `$target`.
+    #[cfg_attr(feature = "typescript", ts(type = "string | any[]"))]
+    pub program: fxlang::Program,
+}
+
+/// Options for a raid boss [`Player`].
+///
+/// For use on [`PlayerType`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct RaidBossOptions {
+    /// Multiplier applied to the maximum HP of the boss.
+    #[serde(default = "default_raid_boss_health_multiplier")]
+    pub health_multiplier: u16,
+    /// The number of shields the boss starts the battle with.
+    ///
+    /// Each hit from a move while the boss has shields up breaks one shield.
+    #[serde(default)]
+    pub shields: u16,
+    /// The modifier applied to damage dealt by moves to the boss while it has shields up.
+    #[serde(default = "default_raid_boss_shield_damage_modifier")]
+    #[cfg_attr(
+        feature = "typescript",
+        ts(type = "string | number | [number, number]")
+    )]
+    pub shield_damage_modifier: Fraction<u32>,
+    /// The number of actions the boss takes every turn.
+    ///
+    /// The boss player must make one choice for each action.
+    #[serde(default = "default_raid_boss_actions_per_turn")]
+    pub actions_per_turn: u8,
+    /// Scripted events that run when the boss's HP falls to a threshold.
+    ///
+    /// Each event runs at most once per battle.
+    #[serde(default)]
+    pub thresholds: Vec<RaidThreshold>,
+    /// The number of turns the raid lasts.
+    ///
+    /// If the boss has not been defeated when the limit is exceeded, the raid fails.
+    #[serde(default)]
+    pub turn_limit: Option<u64>,
+    /// The number of times Mons on the opposing side can faint, in total.
+    ///
+    /// If the limit is reached, the raid fails.
+    #[serde(default)]
+    pub faint_limit: Option<u64>,
+}
+
+impl RaidBossOptions {
+    /// The maximum number of players that can battle a raid boss.
+    pub const MAX_RAIDERS: usize = 4;
+}
+
+fn default_raid_boss_health_multiplier() -> u16 {
+    1
+}
+
+fn default_raid_boss_shield_damage_modifier() -> Fraction<u32> {
+    Fraction::new(1, 4)
+}
+
+fn default_raid_boss_actions_per_turn() -> u8 {
+    1
+}
+
+impl Default for RaidBossOptions {
+    fn default() -> Self {
+        Self {
+            health_multiplier: default_raid_boss_health_multiplier(),
+            shields: 0,
+            shield_damage_modifier: default_raid_boss_shield_damage_modifier(),
+            actions_per_turn: default_raid_boss_actions_per_turn(),
+            thresholds: Vec::new(),
+            turn_limit: None,
+            faint_limit: None,
+        }
+    }
+}
+
 /// The type of the [`Player`], which controls some of the operations that can be done in the
 /// battle.
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// Not [`Copy`], since raid boss options own a list of thresholds.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(tag = "type")]
 #[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 #[cfg_attr(feature = "typescript", ts(export))]
@@ -168,6 +267,9 @@ pub enum PlayerType {
     /// If this type is used, each player should have exactly one Mon to emulate wild battles
     /// (where each wild Mon can escape separately). If a "wild" player has multiple Mons,
     /// switch-ins can occur (logged as "appearances").
+    ///
+    /// In horde battles, a wild player with multiple Mons is split into one wild player per Mon
+    /// (see [`CoreBattleOptions::split_hordes`][`crate::battle::CoreBattleOptions::split_hordes`]).
     #[serde(rename = "wild")]
     Wild(WildPlayerOptions),
     /// The protagonist, who can gain experience.
@@ -176,6 +278,14 @@ pub enum PlayerType {
     /// gain experience. When you do not wish to simulate experience, simply use `Trainer`.
     #[serde(rename = "protagonist")]
     Protagonist,
+    /// The boss of a raid.
+    ///
+    /// A raid boss must be the only player on its side and must have exactly one Mon, which is
+    /// battled by up to [`RaidBossOptions::MAX_RAIDERS`] players on the other side.
+    ///
+    /// Options are boxed so that other player types stay small.
+    #[serde(rename = "raidboss")]
+    RaidBoss(Box<RaidBossOptions>),
 }
 
 impl PlayerType {
@@ -230,6 +340,14 @@ impl PlayerType {
             _ => None,
         }
     }
+
+    /// The raid boss options, if applicable.
+    pub fn raid_boss(&self) -> Option<&RaidBossOptions> {
+        match self {
+            Self::RaidBoss(raid_boss) => Some(raid_boss),
+            _ => None,
+        }
+    }
 }
 
 /// Options for an Exp. Share activated for a player.
@@ -345,6 +463,7 @@ pub struct PlayerData {
 ///
 /// Shared state for multiple choices in a turn (think of a double battle) ensures that choices do
 /// not overlap with one another in a conflicting way (such as switching a Mon in twice).
+#[derive(Clone, Serialize, Deserialize)]
 pub struct ChoiceState {
     /// Can the choice be undone?
     pub undo_allowed: bool,
@@ -379,6 +498,9 @@ pub struct ChoiceState {
     pub tera: bool,
     /// Did the Player choose to leave?
     pub forfeiting: bool,
+    /// The direction the player chose to rotate before the next move, if any.
+    #[serde(default)]
+    pub rotate: Option<RotateDirection>,
 }
 
 impl ChoiceState {
@@ -398,6 +520,7 @@ impl ChoiceState {
             dyna: false,
             tera: false,
             forfeiting: false,
+            rotate: None,
         }
     }
 }
@@ -423,6 +546,7 @@ pub struct PlayerBattleData {
 /// A single player of a battle.
 ///
 /// See [`PlayerData`] for an explanation of what a player represents.
+#[derive(Clone, Serialize, Deserialize)]
 pub struct Player {
     pub id: String,
     pub name: String,
@@ -464,6 +588,11 @@ pub struct Player {
     pub bag: HashMap<Id, u16>,
     pub dex: PlayerDex,
     pub caught: Vec<MonHandle>,
+
+    /// The number of shields protecting the player's raid boss.
+    pub raid_shields: u16,
+    /// Indices of raid thresholds that have already been reached.
+    pub raid_thresholds_reached: HashSet<usize>,
 }
 
 // Construction and initialization logic.
@@ -496,6 +625,11 @@ impl Player {
             && format.rules.has_rule(&Id::from_known("dynamax"));
         let can_terastallize = !data.player_options.cannot_terastallize
             && format.rules.has_rule(&Id::from_known("terastallization"));
+        let raid_shields = data
+            .player_type
+            .raid_boss()
+            .map(|raid_boss| raid_boss.shields)
+            .unwrap_or(0);
         let mut player = Self {
             id: data.id,
             name: data.name,
@@ -522,6 +656,8 @@ impl Player {
             bag: HashMap::default(),
             dex: player_dex,
             caught: Vec::new(),
+            raid_shields,
+            raid_thresholds_reached: HashSet::default(),
         };
         player.update_team(data.team, dex, registry)?;
         Ok(player)
@@ -605,6 +741,32 @@ impl Player {
         self.active.len()
     }
 
+    /// The active position of the Mon that rotates to the front when rotating in the given
+    /// direction.
+    pub fn rotation_position(&self, direction: RotateDirection) -> usize {
+        match direction {
+            RotateDirection::Left => 1 % self.active.len().max(1),
+            RotateDirection::Right => self.active.len().saturating_sub(1),
+        }
+    }
+
+    /// Rotates all active positions in the given direction.
+    pub fn rotate_active_positions(&mut self, direction: RotateDirection) {
+        if self.active.is_empty() {
+            return;
+        }
+        match direction {
+            RotateDirection::Left => {
+                self.active.rotate_left(1);
+                self.active_or_exited.rotate_left(1);
+            }
+            RotateDirection::Right => {
+                self.active.rotate_right(1);
+                self.active_or_exited.rotate_right(1);
+            }
+        }
+    }
+
     /// Sets the active position.
     pub fn set_active_position(&mut self, position: usize, mon: Option<MonHandle>) -> Result<()> {
         *self
@@ -754,7 +916,7 @@ impl Player {
         Ok(PlayerBattleData {
             name: context.player().name.clone(),
             id: context.player().id.clone(),
-            player_type: context.player().player_type,
+            player_type: context.player().player_type.clone(),
             side: context.player().side,
             position: context.player().position,
             mons: mon_handles
@@ -796,7 +958,8 @@ impl Player {
                 }
                 // Choose passes for as many Mons as we can.
                 Self::get_position_for_next_choice(context, false)?;
-                Ok(context.player().choice.actions.len() >= context.player().active.len())
+                Ok(context.player().choice.actions.len()
+                    >= context.player().active.len() * Self::actions_per_active_mon(context))
             }
         }
     }
@@ -988,6 +1151,10 @@ impl Player {
         Self::clear_choice(context);
 
         for (i, choice) in choice_results_from_string(input).into_iter().enumerate() {
+            if context.player().choice.rotate.is_some() && !matches!(choice, Ok(Choice::Move(_))) {
+                return Err(general_error("rotate must be followed by a move")
+                    .wrap_error_with_message(format!("invalid choice {i}")));
+            }
             let result =
                 match choice {
                     Ok(Choice::Team(choice)) => Self::choose_team(context, choice)
@@ -1013,6 +1180,8 @@ impl Player {
                     Ok(Choice::Shift) => {
                         Self::choose_shift(context).wrap_error_with_message("cannot shift")
                     }
+                    Ok(Choice::Rotate(choice)) => Self::choose_rotate(context, choice)
+                        .wrap_error_with_message("cannot rotate"),
                     Ok(Choice::Select(choice)) => Self::choose_select(context, choice)
                         .wrap_error_with_message("cannot select"),
                     Ok(Choice::Random) => {
@@ -1027,6 +1196,12 @@ impl Player {
             }
         }
 
+        if context.player().choice.rotate.is_some() {
+            return Err(general_error(
+                "incomplete choice: rotate must be followed by a move",
+            ));
+        }
+
         if !Self::choice_done(context)? {
             return Err(general_error("incomplete choice: missing actions for mons"));
         }
@@ -1165,12 +1340,17 @@ impl Player {
         }
 
         // Choices generate a single action, so there should be once choice for each active Mon.
+        //
+        // Raid bosses make multiple choices for their single Mon.
+        let actions_per_mon = Self::actions_per_active_mon(context);
         let mut next_mon = context.player().choice.actions.len();
         if !pass {
             match context.player().request_type() {
                 Some(RequestType::Turn) => {
+                    let acting_per_player = context.battle().format.battle_type.acting_per_player();
                     while context.player().active.get(next_mon).is_some_and(|mon| {
-                        mon.is_none()
+                        next_mon >= acting_per_player
+                            || mon.is_none()
                             || mon.is_some_and(|mon| context.mon(mon).is_ok_and(|mon| !mon.active))
                     }) {
                         Self::choose_pass(context, false)?;
@@ -1226,7 +1406,20 @@ impl Player {
                 _ => (),
             }
         }
-        Ok(next_mon)
+        Ok(next_mon / actions_per_mon)
+    }
+
+    /// The number of actions each active Mon takes in the current request.
+    fn actions_per_active_mon(context: &PlayerContext) -> usize {
+        match context.player().request_type() {
+            Some(RequestType::Turn) => context
+                .player()
+                .player_type
+                .raid_boss()
+                .map(|raid_boss| (raid_boss.actions_per_turn as usize).max(1))
+                .unwrap_or(1),
+            _ => 1,
+        }
     }
 
     fn choose_pass(context: &mut PlayerContext, chosen_by_player: bool) -> Result<()> {
@@ -1247,7 +1440,10 @@ impl Player {
                 }
             }
             Some(RequestType::Turn) => {
-                if let Some(mon) = context.player().active_mon_handle(position) {
+                // Mons that cannot act this turn (e.g., Mons in the back of a Rotation battle) must
+                // always pass.
+                let can_act = position < context.battle().format.battle_type.acting_per_player();
+                if can_act && let Some(mon) = context.player().active_mon_handle(position) {
                     let context = context.mon_context(mon)?;
                     if context.mon().exited.is_none()
                         && !context.battle().engine_options.allow_pass_for_unfainted_mon
@@ -1279,11 +1475,18 @@ impl Player {
         if active_position >= context.player().active.len() {
             return Err(general_error("you sent more choices than active mons"));
         }
+
+        // If the player chose to rotate, the Mon rotating to the front uses the move.
+        let rotate = context.player_mut().choice.rotate.take();
+        let mon_position = match rotate {
+            Some(direction) => context.player().rotation_position(direction),
+            None => active_position,
+        };
         let mon_handle = context
             .player()
-            .active_mon_handle(active_position)
+            .active_mon_handle(mon_position)
             .wrap_expectation_with_format(format_args!(
-                "expected an active mon in position {active_position}"
+                "expected an active mon in position {mon_position}"
             ))?;
 
         // This becomes our new context for the rest of the choice.
@@ -1398,6 +1601,7 @@ impl Player {
                     ultra: false,
                     dyna: false,
                     tera: false,
+                    rotate,
                 })));
             // Locked move, the Mon cannot do anything else.
             return Ok(());
@@ -1436,7 +1640,7 @@ impl Player {
             }
         }
 
-        let target_required = context.battle().format.battle_type.active_per_player() > 1;
+        let target_required = context.battle().format.battle_type.acting_per_player() > 1;
         match (move_target.choosable(), choice.target) {
             (true, None) => {
                 if target_required {
@@ -1470,6 +1674,7 @@ impl Player {
                 ultra: choice.ultra,
                 dyna: choice.dyna,
                 tera: choice.tera,
+                rotate,
             })));
 
         if choice.mega {
@@ -1774,6 +1979,47 @@ impl Player {
         Ok(())
     }
 
+    fn choose_rotate(context: &mut PlayerContext, choice: RotateChoice) -> Result<()> {
+        if context.battle().format.battle_type != BattleType::Rotation {
+            return Err(general_error("you can only rotate in rotation battles"));
+        }
+
+        match context.player().request_type() {
+            Some(RequestType::Turn) => (),
+            _ => return Err(general_error("you cannot rotate out of turn")),
+        }
+        if context.player().choice.rotate.is_some() {
+            return Err(general_error("you can only rotate once per turn"));
+        }
+        let active_position = Self::get_position_for_next_choice(context, false)?;
+        if active_position != 0 {
+            return Err(general_error(
+                "you can only rotate before your front mon's action",
+            ));
+        }
+
+        if let Some(front) = context.player().active_mon_handle(active_position) {
+            let front = context.mon(front)?;
+            if front.next_turn_state.locked_move.is_some() {
+                return Err(general_error(format!(
+                    "{} must use its locked move",
+                    front.name
+                )));
+            }
+        }
+
+        let position = context.player().rotation_position(choice.direction);
+        if context.player().active_mon_handle(position).is_none() {
+            return Err(general_error(format!(
+                "no mon to rotate {} to the front",
+                choice.direction
+            )));
+        }
+
+        context.player_mut().choice.rotate = Some(choice.direction);
+        Ok(())
+    }
+
     fn choose_select(context: &mut PlayerContext, choice: SelectChoice) -> Result<()> {
         match context.player().request_type() {
             Some(RequestType::Select) => (),
@@ -2036,10 +2282,13 @@ impl Player {
 
 #[cfg(test)]
 mod player_type_test {
+    use alloc::boxed::Box;
+
     use crate::{
         WildEncounterType,
         battle::{
             PlayerType,
+            RaidBossOptions,
             WildPlayerOptions,
         },
     };
@@ -2082,5 +2331,24 @@ mod player_type_test {
             .unwrap(),
             PlayerType::Protagonist
         );
+        assert_eq!(
+            serde_json::from_str::<PlayerType>(
+                r#"{
+                    "type": "raidboss",
+                    "health_multiplier": 5,
+                    "shields": 3,
+                    "actions_per_turn": 2,
+                    "turn_limit": 10
+                }"#
+            )
+            .unwrap(),
+            PlayerType::RaidBoss(Box::new(RaidBossOptions {
+                health_multiplier: 5,
+                shields: 3,
+                actions_per_turn: 2,
+                turn_limit: Some(10),
+                ..Default::default()
+            }))
+        );
     }
 }
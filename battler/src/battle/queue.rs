@@ -8,6 +8,10 @@ use battler_prng::{
     PseudoRandomNumberGenerator,
     rand_util,
 };
+use serde::{
+    Deserialize,
+    Serialize,
+};
 
 use crate::{
     WrapOptionError,
@@ -31,7 +35,7 @@ use crate::{
 /// A queue of [`Action`]s to be run in a [`CoreBattle`][`crate::battle::CoreBattle`].
 ///
 /// Actions are ordered in complex ways, so this queue type encapsulates all ordering logic.
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct BattleQueue {
     actions: VecDeque<Action>,
     next_added_action_id: usize,
@@ -519,6 +523,7 @@ mod queue_test {
             ultra: false,
             dyna: false,
             tera: false,
+            rotate: None,
             priority,
             sub_priority,
             order: None,
@@ -0,0 +1,160 @@
+use alloc::vec::Vec;
+
+use anyhow::Result;
+
+use crate::{
+    battle::{
+        ActiveTargetContext,
+        ApplyingEffectContext,
+        Context,
+        MonContext,
+        OutsideEffect,
+        OutsideEffectTarget,
+        core_battle_logs,
+        evaluate_outside_effect,
+        modify_32,
+    },
+    error::WrapOptionError,
+};
+
+/// Modifies the damage dealt by a move to a raid boss with shields up.
+pub fn modify_damage_for_shields(context: &mut ActiveTargetContext, damage: u32) -> Result<u32> {
+    let context = context.target_mon_context()?;
+    if context.player().raid_shields == 0 {
+        return Ok(damage);
+    }
+    match context.player().player_type.raid_boss() {
+        Some(raid_boss) => Ok(modify_32(damage, raid_boss.shield_damage_modifier)),
+        None => Ok(damage),
+    }
+}
+
+/// Breaks one shield protecting a raid boss, if the boss was hit by a move.
+pub fn break_shield(context: &mut ApplyingEffectContext) -> Result<()> {
+    if !context.effect_handle().is_active_move() {
+        return Ok(());
+    }
+    let mut target_context = context.target_context()?;
+    if target_context.player().raid_shields == 0
+        || target_context.player().player_type.raid_boss().is_none()
+    {
+        return Ok(());
+    }
+    target_context.player_mut().raid_shields -= 1;
+    core_battle_logs::raid_shields(&mut target_context, None)
+}
+
+/// Adds shields to a raid boss.
+///
+/// Returns `false` if the target is not a raid boss.
+pub fn add_shields(context: &mut ApplyingEffectContext, shields: u16) -> Result<bool> {
+    let mut target_context = context.target_context()?;
+    if target_context.player().player_type.raid_boss().is_none() || !target_context.mon().active {
+        return Ok(false);
+    }
+    let player = target_context.player_mut();
+    player.raid_shields = player.raid_shields.saturating_add(shields);
+    let effect = context.effect_handle().clone();
+    core_battle_logs::raid_shields(&mut context.target_context()?, Some(effect))?;
+    Ok(true)
+}
+
+/// Logs the shields protecting all raid bosses at the start of the battle.
+pub fn log_initial_shields(context: &mut Context) -> Result<()> {
+    for player in context.battle().player_indices().collect::<Vec<_>>() {
+        let mut context = context.player_context(player)?;
+        if context.player().player_type.raid_boss().is_none() || context.player().raid_shields == 0
+        {
+            continue;
+        }
+        for mon in context
+            .player()
+            .active_mon_handles()
+            .cloned()
+            .collect::<Vec<_>>()
+        {
+            core_battle_logs::raid_shields(&mut context.mon_context(mon)?, None)?;
+        }
+    }
+    Ok(())
+}
+
+/// Runs the scripted events for all raid thresholds that a raid boss's HP has fallen to.
+///
+/// Runs after every loss of HP: regular damage, direct damage (such as recoil), and setting HP
+/// directly.
+pub fn run_thresholds(context: &mut MonContext) -> Result<()> {
+    let thresholds = match context.player().player_type.raid_boss() {
+        Some(raid_boss) => raid_boss.thresholds.clone(),
+        None => return Ok(()),
+    };
+    for (i, threshold) in thresholds.into_iter().enumerate() {
+        let mon = context.mon();
+        if mon.hp == 0 || !mon.active {
+            return Ok(());
+        }
+        if context.player().raid_thresholds_reached.contains(&i)
+            || mon.hp as u64 * 100 > threshold.hp_percent as u64 * mon.max_hp as u64
+        {
+            continue;
+        }
+        context.player_mut().raid_thresholds_reached.insert(i);
+
+        let position = context
+            .mon()
+            .active_position
+            .wrap_expectation("expected raid boss to have an active position")?;
+        let outside_effect = OutsideEffect {
+            name: threshold.name,
+            target: OutsideEffectTarget::Mon {
+                player: context.player().id.clone(),
+                position,
+            },
+            source_effect: None,
+            program: threshold.program,
+        };
+        evaluate_outside_effect(context.as_battle_context_mut(), &outside_effect)?;
+    }
+    Ok(())
+}
+
+/// Checks if any raid has exceeded its turn limit.
+///
+/// Returns the side of the raid boss that won the battle, if any.
+pub fn check_turn_limit(context: &mut Context) -> Result<Option<usize>> {
+    let turn = context.battle().turn();
+    for player in context.battle().player_indices().collect::<Vec<_>>() {
+        let mut context = context.player_context(player)?;
+        let turn_limit = match context.player().player_type.raid_boss() {
+            Some(raid_boss) => raid_boss.turn_limit,
+            None => continue,
+        };
+        if turn_limit.is_some_and(|turn_limit| turn > turn_limit) {
+            core_battle_logs::raid_failed(&mut context, "turnlimit")?;
+            return Ok(Some(context.player().side));
+        }
+    }
+    Ok(None)
+}
+
+/// Checks if any raid has reached its faint limit.
+///
+/// Returns the side of the raid boss that won the battle, if any. Does nothing if the battle is
+/// already ending, so that the raid does not fail again for later faints.
+pub fn check_faint_limit(context: &mut Context) -> Result<Option<usize>> {
+    if context.battle().ending() {
+        return Ok(None);
+    }
+    for player in context.battle().player_indices().collect::<Vec<_>>() {
+        let mut context = context.player_context(player)?;
+        let faint_limit = match context.player().player_type.raid_boss() {
+            Some(raid_boss) => raid_boss.faint_limit,
+            None => continue,
+        };
+        if faint_limit.is_some_and(|faint_limit| context.foe_side().total_fainted >= faint_limit) {
+            core_battle_logs::raid_failed(&mut context, "faintlimit")?;
+            return Ok(Some(context.player().side));
+        }
+    }
+    Ok(None)
+}
@@ -1,10 +1,24 @@
-use alloc::format;
+use alloc::{
+    format,
+    vec::Vec,
+};
 use core::{
     fmt,
     fmt::Display,
 };
 
 use anyhow::Result;
+use serde::{
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+    de::Error as _,
+    ser::{
+        Error as _,
+        SerializeStruct,
+    },
+};
 use zone_alloc::{
     BorrowError,
     ElementRef,
@@ -25,7 +39,9 @@ use crate::{
 };
 
 /// A [`Mon`] handle.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, StrongHandle)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, StrongHandle, Serialize, Deserialize,
+)]
 pub struct MonHandle(Handle);
 
 impl Display for MonHandle {
@@ -35,7 +51,9 @@ impl Display for MonHandle {
 }
 
 /// A [`Move`] handle.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, StrongHandle)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, StrongHandle, Serialize, Deserialize,
+)]
 pub struct MoveHandle(Handle);
 
 impl Display for MoveHandle {
@@ -158,6 +176,32 @@ impl BattleRegistry {
         Ok(())
     }
 
+    /// Clones the registry, including all [`Mon`]s and [`Move`]s.
+    ///
+    /// Fails if any object in the registry is mutably borrowed.
+    pub fn try_clone(&self) -> Result<Self> {
+        let registry = Self::new();
+        for mon in self.mons.iter() {
+            let mon = mon.map_err(|err| err.convert_error_with_message("mon"))?;
+            registry.register_mon((*mon).clone());
+        }
+        for (source, target) in [
+            (&self.last_turn_moves, &registry.last_turn_moves),
+            (&self.this_turn_moves, &registry.this_turn_moves),
+        ] {
+            for (handle, mov) in source.iter() {
+                let mov = mov.map_err(|err| {
+                    err.convert_error_with_message(format!("active move {handle}"))
+                })?;
+                target.register(*handle, (*mov).clone());
+            }
+        }
+        Ok(Self {
+            next_active_move_handle: self.next_active_move_handle,
+            ..registry
+        })
+    }
+
     /// Move the registry to the next turn.
     ///
     /// All move objects from last turn are dropped. Moves from this turn are moved to the last turn
@@ -175,3 +219,86 @@ impl BattleRegistry {
         Ok(())
     }
 }
+
+impl BattleRegistry {
+    fn moves_for_serialization(
+        moves: &MoveRegistry,
+    ) -> Result<Vec<(MoveHandle, ElementRef<'_, Move>)>, BorrowError> {
+        moves
+            .iter()
+            .map(|(handle, mov)| mov.map(|mov| (*handle, mov)))
+            .collect()
+    }
+}
+
+impl Serialize for BattleRegistry {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mons = self
+            .mons
+            .iter()
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(S::Error::custom)?;
+        let last_turn_moves =
+            Self::moves_for_serialization(&self.last_turn_moves).map_err(S::Error::custom)?;
+        let this_turn_moves =
+            Self::moves_for_serialization(&self.this_turn_moves).map_err(S::Error::custom)?;
+
+        let mut state = serializer.serialize_struct("BattleRegistry", 4)?;
+        state.serialize_field("mons", &mons.iter().map(|mon| &**mon).collect::<Vec<_>>())?;
+        state.serialize_field(
+            "last_turn_moves",
+            &last_turn_moves
+                .iter()
+                .map(|(handle, mov)| (handle, &**mov))
+                .collect::<Vec<_>>(),
+        )?;
+        state.serialize_field(
+            "this_turn_moves",
+            &this_turn_moves
+                .iter()
+                .map(|(handle, mov)| (handle, &**mov))
+                .collect::<Vec<_>>(),
+        )?;
+        state.serialize_field("next_active_move_handle", &self.next_active_move_handle)?;
+        state.end()
+    }
+}
+
+/// Owned form of a serialized [`BattleRegistry`].
+#[derive(Deserialize)]
+struct BattleRegistryData {
+    mons: Vec<Mon>,
+    last_turn_moves: Vec<(MoveHandle, Move)>,
+    this_turn_moves: Vec<(MoveHandle, Move)>,
+    next_active_move_handle: usize,
+}
+
+impl<'de> Deserialize<'de> for BattleRegistry {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let data = BattleRegistryData::deserialize(deserializer)?;
+        let registry = Self::new();
+        for (i, mon) in data.mons.into_iter().enumerate() {
+            // Mon handles are assigned sequentially, so they are preserved by registering Mons in
+            // their original order.
+            if registry.register_mon(mon) != MonHandle::from(i) {
+                return Err(D::Error::custom(format!("mon {i} registered out of order")));
+            }
+        }
+        registry
+            .last_turn_moves
+            .register_extend(data.last_turn_moves);
+        registry
+            .this_turn_moves
+            .register_extend(data.this_turn_moves);
+        Ok(Self {
+            next_active_move_handle: data.next_active_move_handle,
+            ..registry
+        })
+    }
+}
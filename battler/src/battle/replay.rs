@@ -0,0 +1,160 @@
+use alloc::{
+    string::String,
+    vec::Vec,
+};
+
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+use crate::{
+    battle::{
+        CoreBattleEngineOptions,
+        CoreBattleOptions,
+    },
+    error::ReplayDivergenceError,
+};
+
+/// A single choice made by a player, as recorded in a [`BattleReplay`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReplayChoice {
+    /// The turn the choice was made on.
+    pub turn: u64,
+    /// The player who made the choice.
+    pub player: String,
+    /// The choice input.
+    pub input: String,
+}
+
+/// A serializable record of a battle, which can be used to reproduce the battle exactly.
+///
+/// Replays are created by
+/// [`PublicCoreBattle::replay_record`][`crate::battle::PublicCoreBattle::replay_record`]
+/// and replayed by [`PublicCoreBattle::replay`][`crate::battle::PublicCoreBattle::replay`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BattleReplay {
+    /// Options the battle was created with.
+    ///
+    /// The seed is always set to the initial seed of the battle's random number generator, and
+    /// each player's team is the team used in the battle, after validation.
+    pub options: CoreBattleOptions,
+    /// Engine options.
+    ///
+    /// Factory functions are not serialized, so they are reset to their defaults when a replay is
+    /// deserialized. They can be overwritten before the replay is run.
+    pub engine_options: CoreBattleEngineOptions,
+    /// Every accepted player choice, in order.
+    pub choices: Vec<ReplayChoice>,
+    /// The recorded battle log.
+    pub log: Vec<String>,
+}
+
+impl BattleReplay {
+    /// Checks the given battle log against the recorded battle log, returning an error for the
+    /// first line that differs.
+    ///
+    /// If `partial` is true, the given log only needs to be a prefix of the recorded log.
+    ///
+    /// Timestamps are not compared, since they are expected to differ between runs.
+    pub fn check_log<'a, I>(&self, log: I, partial: bool) -> Result<(), ReplayDivergenceError>
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        let mut log = log.into_iter();
+        for (line, expected) in self.log.iter().enumerate() {
+            let actual = match log.next() {
+                Some(actual) => actual,
+                None if partial => return Ok(()),
+                None => {
+                    return Err(ReplayDivergenceError {
+                        line,
+                        expected: Some(expected.clone()),
+                        actual: None,
+                    });
+                }
+            };
+            if !Self::lines_match(expected, actual) {
+                return Err(ReplayDivergenceError {
+                    line,
+                    expected: Some(expected.clone()),
+                    actual: Some(actual.into()),
+                });
+            }
+        }
+        match log.next() {
+            Some(actual) => Err(ReplayDivergenceError {
+                line: self.log.len(),
+                expected: None,
+                actual: Some(actual.into()),
+            }),
+            None => Ok(()),
+        }
+    }
+
+    fn lines_match(expected: &str, actual: &str) -> bool {
+        const TIME_PREFIX: &str = "time|";
+        expected == actual || (expected.starts_with(TIME_PREFIX) && actual.starts_with(TIME_PREFIX))
+    }
+}
+
+#[cfg(test)]
+mod replay_test {
+    use alloc::{
+        borrow::ToOwned,
+        vec::Vec,
+    };
+
+    use crate::battle::{
+        BattleReplay,
+        CoreBattleEngineOptions,
+        CoreBattleOptions,
+    };
+
+    fn replay(log: &[&str]) -> BattleReplay {
+        BattleReplay {
+            options: CoreBattleOptions::default(),
+            engine_options: CoreBattleEngineOptions::default(),
+            choices: Vec::new(),
+            log: log.iter().map(|line| (*line).to_owned()).collect(),
+        }
+    }
+
+    #[test]
+    fn finds_first_divergent_line() {
+        let replay = replay(&["info|battletype:Singles", "turn|turn:1", "move|mon:a"]);
+        assert_matches::assert_matches!(
+            replay.check_log(
+                ["info|battletype:Singles", "turn|turn:1", "move|mon:a"],
+                false
+            ),
+            Ok(())
+        );
+        assert_matches::assert_matches!(replay.check_log(["info|battletype:Singles", "turn|turn:1", "move|mon:b"], false), Err(err) => {
+            assert_eq!(err.line, 2);
+            assert_eq!(err.expected, Some("move|mon:a".to_owned()));
+            assert_eq!(err.actual, Some("move|mon:b".to_owned()));
+        });
+        assert_matches::assert_matches!(replay.check_log(["info|battletype:Singles"], false), Err(err) => {
+            assert_eq!(err.line, 1);
+            assert_eq!(err.actual, None);
+        });
+        assert_matches::assert_matches!(
+            replay.check_log(["info|battletype:Singles"], true),
+            Ok(())
+        );
+        assert_matches::assert_matches!(replay.check_log(["info|battletype:Singles", "turn|turn:1", "move|mon:a", "turn|turn:2"], false), Err(err) => {
+            assert_eq!(err.line, 3);
+            assert_eq!(err.expected, None);
+        });
+    }
+
+    #[test]
+    fn ignores_timestamps() {
+        let replay = replay(&["time|value:100", "turn|turn:1"]);
+        assert_matches::assert_matches!(
+            replay.check_log(["time|value:200", "turn|turn:1"], false),
+            Ok(())
+        );
+    }
+}
@@ -16,7 +16,7 @@ use crate::battle::{
 };
 
 /// Type type of [`Request`] that should be requested from a player.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 #[cfg_attr(feature = "typescript", ts(export))]
 pub enum RequestType {
@@ -51,6 +51,10 @@ pub struct TurnRequest {
     #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub allies: Vec<PlayerBattleData>,
+    /// Can the player rotate before their front Mon moves (Rotation battle only)?
+    #[serde(default)]
+    #[serde(skip_serializing_if = "core::ops::Not::not")]
+    pub can_rotate: bool,
 }
 
 /// A request for a Mon to be switched in.
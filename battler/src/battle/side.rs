@@ -46,6 +46,7 @@ pub struct SideData {
 /// A single side of a battle.
 ///
 /// See [`SideData`] for details.
+#[derive(Clone, Serialize, Deserialize)]
 pub struct Side {
     pub name: String,
     pub index: usize,
@@ -0,0 +1,107 @@
+use alloc::{
+    collections::VecDeque,
+    string::String,
+    vec::Vec,
+};
+
+use battler_data::{
+    ConditionData,
+    Id,
+};
+use hashbrown::HashMap;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+use crate::{
+    battle::{
+        BattleQueue,
+        BattleRegistry,
+        CatchEntry,
+        CoreBattleEngineOptions,
+        CoreBattleOptions,
+        FaintEntry,
+        Field,
+        MonHandle,
+        MoveHandle,
+        Player,
+        ReplayChoice,
+        RequestType,
+        Side,
+    },
+    config::FormatData,
+    effect::{
+        EffectHandle,
+        LinkedEffectsManager,
+    },
+    log::BattleLog,
+};
+
+/// A serializable snapshot of the entire state of a [`CoreBattle`][`crate::battle::CoreBattle`].
+///
+/// A snapshot can be restored into a new battle that continues exactly where the original battle
+/// left off, as long as the battle is restored with the same data.
+///
+/// Snapshots are versioned. A snapshot can only be restored by an engine that supports its
+/// version (see [`Self::VERSION`]).
+#[derive(Serialize, Deserialize)]
+pub struct CoreBattleSnapshot {
+    /// Version of the snapshot format.
+    pub version: u32,
+
+    /// Format of the battle.
+    pub format: FormatData,
+    /// Engine options.
+    ///
+    /// Factory functions are not serialized, so they are reset to their defaults when a snapshot
+    /// is deserialized. They can be overwritten before the snapshot is restored.
+    pub engine_options: CoreBattleEngineOptions,
+
+    /// The initial seed of the random number generator.
+    pub prng_initial_seed: u64,
+    /// The current state of the random number generator.
+    pub prng_state: u64,
+    /// The number of fxlang instructions executed this turn, which count against
+    /// [`EvaluationLimits::turn_instructions`][`crate::effect::fxlang::EvaluationLimits::turn_instructions`].
+    pub turn_instructions: u64,
+
+    pub log: BattleLog,
+    pub queue: BattleQueue,
+    pub faint_queue: VecDeque<FaintEntry>,
+    pub catch_queue: VecDeque<CatchEntry>,
+    pub field: Field,
+    pub sides: [Side; 2],
+    pub players: Vec<Player>,
+    pub linked_effects_manager: LinkedEffectsManager,
+    pub registry: BattleRegistry,
+    pub effect_handle_cache: HashMap<Id, EffectHandle>,
+    pub outside_conditions: Vec<ConditionData>,
+
+    pub turn: u64,
+    pub request: Option<RequestType>,
+    pub mid_turn: bool,
+    pub started: bool,
+    pub in_pre_battle: bool,
+    pub in_residual: bool,
+    pub ending: bool,
+    pub ended: bool,
+    pub next_effect_order: u32,
+    pub next_effect_linked_id: u32,
+    pub last_move: Option<MoveHandle>,
+    pub last_successful_move: Option<MoveHandle>,
+    pub last_exited: Option<MonHandle>,
+    pub last_item_log: Option<usize>,
+
+    pub input_log: HashMap<usize, HashMap<u64, String>>,
+    pub options: CoreBattleOptions,
+    pub choices: Vec<ReplayChoice>,
+}
+
+impl CoreBattleSnapshot {
+    /// The current version of the snapshot format, which is written to all new snapshots.
+    ///
+    /// Must be incremented whenever battle state changes in a way that is incompatible with older
+    /// snapshots.
+    pub const VERSION: u32 = 4;
+}
@@ -0,0 +1,65 @@
+use alloc::collections::VecDeque;
+
+use crate::battle::CoreBattle;
+
+/// Restorable checkpoints of a battle, saved at the start of each turn.
+///
+/// Only the most recent checkpoints are kept, up to a limit.
+///
+/// Used by [`PublicCoreBattle`][`crate::battle::PublicCoreBattle`] for rewinding the battle to an
+/// earlier turn.
+#[derive(Default)]
+pub struct TurnHistory<'d> {
+    checkpoints: VecDeque<(u64, CoreBattle<'d>)>,
+}
+
+impl<'d> TurnHistory<'d> {
+    /// Creates a new, empty turn history.
+    pub fn new() -> Self {
+        Self {
+            checkpoints: VecDeque::new(),
+        }
+    }
+
+    /// The last turn that has a checkpoint.
+    pub fn last_turn(&self) -> Option<u64> {
+        self.checkpoints.back().map(|(turn, _)| *turn)
+    }
+
+    /// All turns that have a checkpoint, in order.
+    pub fn turns(&self) -> impl Iterator<Item = u64> + '_ {
+        self.checkpoints.iter().map(|(turn, _)| *turn)
+    }
+
+    /// Saves the checkpoint for the given turn.
+    ///
+    /// Checkpoints for the oldest turns are removed, so that at most `limit` checkpoints are kept.
+    pub fn save(&mut self, turn: u64, battle: CoreBattle<'d>, limit: usize) {
+        if limit == 0 {
+            return;
+        }
+        while self.checkpoints.len() >= limit {
+            self.checkpoints.pop_front();
+        }
+        self.checkpoints.push_back((turn, battle));
+    }
+
+    /// Returns the checkpoint for the given turn.
+    pub fn checkpoint(&self, turn: u64) -> Option<&CoreBattle<'d>> {
+        self.checkpoints
+            .iter()
+            .find(|(checkpoint_turn, _)| *checkpoint_turn == turn)
+            .map(|(_, battle)| battle)
+    }
+
+    /// Returns all checkpoints, in order.
+    pub fn checkpoints_mut(&mut self) -> impl Iterator<Item = &mut CoreBattle<'d>> {
+        self.checkpoints.iter_mut().map(|(_, battle)| battle)
+    }
+
+    /// Removes all checkpoints after the given turn.
+    pub fn truncate(&mut self, turn: u64) {
+        self.checkpoints
+            .retain(|(checkpoint_turn, _)| *checkpoint_turn <= turn);
+    }
+}
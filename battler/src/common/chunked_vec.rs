@@ -0,0 +1,235 @@
+use alloc::{
+    sync::Arc,
+    vec::Vec,
+};
+use core::{
+    fmt,
+    fmt::Debug,
+    marker::PhantomData,
+};
+
+use serde::{
+    Deserialize,
+    Serialize,
+    de::{
+        SeqAccess,
+        Visitor,
+    },
+    ser::SerializeSeq,
+};
+
+/// A growable list that is cheap to clone.
+///
+/// Elements are stored in fixed-size chunks, which are shared between clones. Cloning the list
+/// only copies references to its chunks. A chunk is copied the first time it is modified through
+/// a list that shares it.
+pub struct ChunkedVec<T> {
+    chunks: Vec<Arc<Vec<T>>>,
+    len: usize,
+}
+
+impl<T> ChunkedVec<T> {
+    const CHUNK_SIZE: usize = 64;
+
+    /// Creates a new, empty list.
+    pub fn new() -> Self {
+        Self {
+            chunks: Vec::new(),
+            len: 0,
+        }
+    }
+
+    /// The number of elements in the list.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks if the list is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns a reference to the element at the given index.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.chunks
+            .get(index / Self::CHUNK_SIZE)?
+            .get(index % Self::CHUNK_SIZE)
+    }
+
+    /// Returns an iterator over all elements.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.iter_from(0)
+    }
+
+    /// Returns an iterator over all elements, starting at the given index.
+    pub fn iter_from(&self, index: usize) -> impl Iterator<Item = &T> {
+        self.chunks
+            .iter()
+            .skip(index / Self::CHUNK_SIZE)
+            .flat_map(|chunk| chunk.iter())
+            .skip(index % Self::CHUNK_SIZE)
+    }
+}
+
+impl<T> ChunkedVec<T>
+where
+    T: Clone,
+{
+    /// Pushes a new element to the end of the list.
+    pub fn push(&mut self, value: T) {
+        match self.chunks.last_mut() {
+            Some(chunk) if chunk.len() < Self::CHUNK_SIZE => Arc::make_mut(chunk).push(value),
+            _ => {
+                let mut chunk = Vec::with_capacity(Self::CHUNK_SIZE);
+                chunk.push(value);
+                self.chunks.push(Arc::new(chunk));
+            }
+        }
+        self.len += 1;
+    }
+}
+
+impl<T> Clone for ChunkedVec<T> {
+    fn clone(&self) -> Self {
+        Self {
+            chunks: self.chunks.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<T> Default for ChunkedVec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Debug for ChunkedVec<T>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> Extend<T> for ChunkedVec<T>
+where
+    T: Clone,
+{
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for ChunkedVec<T>
+where
+    T: Clone,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut list = Self::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T> Serialize for ChunkedVec<T>
+where
+    T: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.len))?;
+        for value in self.iter() {
+            seq.serialize_element(value)?;
+        }
+        seq.end()
+    }
+}
+
+struct ChunkedVecVisitor<T>(PhantomData<T>);
+
+impl<'de, T> Visitor<'de> for ChunkedVecVisitor<T>
+where
+    T: Clone + Deserialize<'de>,
+{
+    type Value = ChunkedVec<T>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a sequence")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut list = ChunkedVec::new();
+        while let Some(value) = seq.next_element()? {
+            list.push(value);
+        }
+        Ok(list)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for ChunkedVec<T>
+where
+    T: Clone + Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(ChunkedVecVisitor(PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod chunked_vec_test {
+    use alloc::vec::Vec;
+
+    use crate::common::ChunkedVec;
+
+    #[test]
+    fn pushes_and_iterates_across_chunks() {
+        let list = (0..200).collect::<ChunkedVec<u32>>();
+        assert_eq!(list.len(), 200);
+        assert_eq!(list.get(0), Some(&0));
+        assert_eq!(list.get(64), Some(&64));
+        assert_eq!(list.get(199), Some(&199));
+        assert_eq!(list.get(200), None);
+        assert_eq!(
+            list.iter_from(130).copied().collect::<Vec<_>>(),
+            (130..200).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn clones_are_independent() {
+        let mut list = (0..100).collect::<ChunkedVec<u32>>();
+        let mut clone = list.clone();
+        list.push(100);
+        clone.push(1000);
+        assert_eq!(list.get(100), Some(&100));
+        assert_eq!(clone.get(100), Some(&1000));
+        assert!(list.iter().take(100).eq(clone.iter().take(100)));
+    }
+
+    #[test]
+    fn serializes_as_sequence() {
+        let list = (0..3).collect::<ChunkedVec<u32>>();
+        let json = serde_json::to_string(&list).unwrap();
+        assert_eq!(json, "[0,1,2]");
+        let list = serde_json::from_str::<ChunkedVec<u32>>(&json).unwrap();
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [0, 1, 2]);
+    }
+}
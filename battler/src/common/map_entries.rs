@@ -0,0 +1,62 @@
+//! Serialization helpers for maps whose keys cannot be serialized as strings.
+//!
+//! Maps are serialized as a list of key-value pairs. Use as
+//! `#[serde(with = "crate::common::map_entries")]`.
+
+use alloc::vec::Vec;
+use core::hash::Hash;
+
+use hashbrown::HashMap;
+use serde::{
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+};
+
+/// Serializes the map as a list of key-value pairs.
+pub fn serialize<K, V, S>(map: &HashMap<K, V>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    K: Serialize,
+    V: Serialize,
+    S: Serializer,
+{
+    serializer.collect_seq(map.iter())
+}
+
+/// Deserializes the map from a list of key-value pairs.
+pub fn deserialize<'de, K, V, D>(deserializer: D) -> Result<HashMap<K, V>, D::Error>
+where
+    K: Deserialize<'de> + Eq + Hash,
+    V: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    Ok(Vec::<(K, V)>::deserialize(deserializer)?
+        .into_iter()
+        .collect())
+}
+
+#[cfg(test)]
+mod map_entries_test {
+    use hashbrown::HashMap;
+    use serde::{
+        Deserialize,
+        Serialize,
+    };
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Wrapper {
+        #[serde(with = "crate::common::map_entries")]
+        map: HashMap<(u8, u8), u8>,
+    }
+
+    #[test]
+    fn serializes_tuple_keys() {
+        let wrapper = Wrapper {
+            map: HashMap::from_iter([((1, 2), 3)]),
+        };
+        let json = serde_json::to_string(&wrapper).unwrap();
+        assert_eq!(json, r#"{"map":[[[1,2],3]]}"#);
+        assert_eq!(serde_json::from_str::<Wrapper>(&json).unwrap(), wrapper);
+    }
+}
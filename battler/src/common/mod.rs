@@ -1,12 +1,15 @@
 mod captures;
+mod chunked_vec;
 mod clock;
 mod lru;
+pub mod map_entries;
 mod maybe_owned;
 mod reference;
 mod strings;
 mod test_util;
 
 pub use captures::Captures;
+pub use chunked_vec::ChunkedVec;
 pub use clock::Clock;
 #[cfg(feature = "std")]
 pub use clock::system_time_clock::SystemTimeClock;
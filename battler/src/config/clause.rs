@@ -6,7 +6,10 @@ use battler_data::{
     ClauseValueType,
     Id,
     Identifiable,
+    MovePredicate,
+    SpeciesPredicate,
     Type,
+    TypeChartOverlay,
 };
 
 use crate::{
@@ -56,6 +59,13 @@ impl Clause {
                 Some(ClauseValueType::NonNegativeInteger) => {
                     value.parse::<u32>().map_err(general_error).map(|_| ())
                 }
+                Some(ClauseValueType::TypeChartOverride) => {
+                    TypeChartOverlay::parse_overrides(value).map(|_| ())
+                }
+                Some(ClauseValueType::SpeciesPredicate) => {
+                    SpeciesPredicate::from_str(value).map(|_| ())
+                }
+                Some(ClauseValueType::MovePredicate) => MovePredicate::from_str(value).map(|_| ()),
                 _ => Ok(()),
             }
         }
@@ -0,0 +1,133 @@
+use alloc::format;
+use core::fmt::Display;
+
+use anyhow::Error;
+use battler_data::{
+    MoveCategory,
+    Type,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+use crate::error::general_error;
+
+/// A generation of battle mechanics.
+///
+/// Battles use the mechanics of the latest generation by default. Retro formats can use the
+/// mechanics of an older generation with the "Mechanics" rule, which changes how the battle engine
+/// behaves (such as the damage formula and critical hit rules), independently of which species,
+/// moves, and abilities are available.
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+pub enum Generation {
+    Gen1 = 1,
+    Gen2 = 2,
+    Gen3 = 3,
+    Gen4 = 4,
+    Gen5 = 5,
+    Gen6 = 6,
+    Gen7 = 7,
+    Gen8 = 8,
+    #[default]
+    Gen9 = 9,
+}
+
+impl Generation {
+    /// The generation number.
+    pub fn number(&self) -> u8 {
+        *self as u8
+    }
+
+    /// Are moves split into physical and special moves individually?
+    ///
+    /// Before Generation 4, the category of a damaging move is determined by its type.
+    pub fn has_physical_special_split(&self) -> bool {
+        *self >= Self::Gen4
+    }
+
+    /// The category of a damaging move of the given type before the physical/special split.
+    pub fn category_for_type(typ: Type) -> MoveCategory {
+        match typ {
+            Type::Normal
+            | Type::Fighting
+            | Type::Flying
+            | Type::Poison
+            | Type::Ground
+            | Type::Rock
+            | Type::Bug
+            | Type::Ghost
+            | Type::Steel
+            | Type::None
+            | Type::Stellar => MoveCategory::Physical,
+            Type::Fire
+            | Type::Water
+            | Type::Grass
+            | Type::Electric
+            | Type::Psychic
+            | Type::Ice
+            | Type::Dragon
+            | Type::Dark
+            | Type::Fairy => MoveCategory::Special,
+        }
+    }
+}
+
+impl TryFrom<u8> for Generation {
+    type Error = Error;
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Self::Gen1),
+            2 => Ok(Self::Gen2),
+            3 => Ok(Self::Gen3),
+            4 => Ok(Self::Gen4),
+            5 => Ok(Self::Gen5),
+            6 => Ok(Self::Gen6),
+            7 => Ok(Self::Gen7),
+            8 => Ok(Self::Gen8),
+            9 => Ok(Self::Gen9),
+            _ => Err(general_error(format!("generation {value} is unsupported"))),
+        }
+    }
+}
+
+impl Display for Generation {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Gen {}", self.number())
+    }
+}
+
+#[cfg(test)]
+mod generation_test {
+    use battler_data::{
+        MoveCategory,
+        Type,
+    };
+
+    use crate::config::Generation;
+
+    #[test]
+    fn converts_from_number() {
+        assert_matches::assert_matches!(Generation::try_from(1), Ok(Generation::Gen1));
+        assert_matches::assert_matches!(Generation::try_from(9), Ok(Generation::Gen9));
+        assert_matches::assert_matches!(Generation::try_from(0), Err(_));
+        assert_matches::assert_matches!(Generation::try_from(10), Err(_));
+        assert_eq!(Generation::Gen4.number(), 4);
+    }
+
+    #[test]
+    fn determines_category_by_type_before_split() {
+        assert!(!Generation::Gen3.has_physical_special_split());
+        assert!(Generation::Gen4.has_physical_special_split());
+        assert_eq!(
+            Generation::category_for_type(Type::Dark),
+            MoveCategory::Special
+        );
+        assert_eq!(
+            Generation::category_for_type(Type::Ghost),
+            MoveCategory::Physical
+        );
+    }
+}
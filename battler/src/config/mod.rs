@@ -1,5 +1,6 @@
 mod clause;
 mod format;
+mod generation;
 mod ruleset;
 
 pub use clause::Clause;
@@ -7,6 +8,7 @@ pub use format::{
     Format,
     FormatData,
 };
+pub use generation::Generation;
 pub use ruleset::{
     ResourceCheck,
     RuleSet,
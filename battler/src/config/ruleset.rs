@@ -1,6 +1,7 @@
 use alloc::{
     format,
     string::String,
+    sync::Arc,
 };
 use core::str::FromStr;
 
@@ -8,18 +9,23 @@ use anyhow::Result;
 use battler_data::{
     Id,
     Identifiable,
+    MovePredicate,
     Rule,
     SerializedRuleSet,
+    SpeciesPredicate,
+    TypeChartOverlay,
 };
 use hashbrown::{
     HashMap,
     HashSet,
 };
-use zone_alloc::ElementRef;
 
 use crate::{
     battle::BattleType,
-    config::Clause,
+    config::{
+        Clause,
+        Generation,
+    },
     dex::Dex,
     error::{
         WrapOptionError,
@@ -71,6 +77,9 @@ pub struct NumericRules {
 
     /// The maximum level that will obey its player if it originates from a different trainer.
     pub obedience_cap: u8,
+
+    /// The generation of battle mechanics used by the battle engine.
+    pub mechanics: Generation,
 }
 
 impl NumericRules {
@@ -182,10 +191,19 @@ impl NumericRules {
             .unwrap_or(510);
         rules.adjacency_reach = ruleset
             .numeric_value(&Id::from_known("adjacencyreach"))
-            .unwrap_or(2);
+            .unwrap_or(battle_type.default_adjacency_reach());
         rules.obedience_cap = ruleset
             .numeric_value(&Id::from_known("obediencecap"))
             .unwrap_or(u8::MAX);
+        rules.mechanics = match ruleset.numeric_value::<u32>(&Id::from_known("mechanics")) {
+            Some(value) => u8::try_from(value)
+                .ok()
+                .and_then(|value| Generation::try_from(value).ok())
+                .wrap_expectation_with_format(format_args!(
+                    "Mechanics = {value} is unsupported (must be between 1 and 9)"
+                ))?,
+            None => Generation::default(),
+        };
 
         if let Some("Auto") = ruleset.value(&Id::from_known("pickedteamsize")) {
             rules.picked_team_size = Some(battle_type.default_picked_team_size() as u32);
@@ -195,6 +213,34 @@ impl NumericRules {
     }
 }
 
+/// Predicate rules that are easier to parse out once than parse every time they are evaluated.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct PredicateRules {
+    /// Predicate that every Mon must match to be allowed on a team.
+    pub allowed_species: Option<SpeciesPredicate>,
+    /// Predicate for moves that fail when used in battle.
+    ///
+    /// Unlike banned moves, these moves may still be on a Mon's moveset.
+    pub battle_banned_moves: Option<MovePredicate>,
+}
+
+impl PredicateRules {
+    fn parse_from_ruleset(ruleset: &RuleSet) -> Result<Self> {
+        Ok(PredicateRules {
+            allowed_species: ruleset
+                .value(&Id::from_known("allowspecies"))
+                .map(SpeciesPredicate::from_str)
+                .transpose()
+                .wrap_error_with_message("rule Allow Species is invalid")?,
+            battle_banned_moves: ruleset
+                .value(&Id::from_known("battlebannedmoves"))
+                .map(MovePredicate::from_str)
+                .transpose()
+                .wrap_error_with_message("rule Battle Banned Moves is invalid")?,
+        })
+    }
+}
+
 /// The result of checking if a resource is allowed.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ResourceCheck {
@@ -228,6 +274,7 @@ pub struct RuleSet {
     unbans: HashSet<Id>,
     rules: HashMap<Id, String>,
     pub numeric_rules: NumericRules,
+    pub predicate_rules: PredicateRules,
 }
 
 impl RuleSet {
@@ -239,10 +286,12 @@ impl RuleSet {
             unbans: HashSet::default(),
             rules: HashMap::default(),
             numeric_rules: NumericRules::default(),
+            predicate_rules: PredicateRules::default(),
         };
         ruleset.store_flattened_ruleset(rules, dex);
         ruleset.resolve_numbers(battle_type)?;
         ruleset.validate_clauses(dex)?;
+        ruleset.resolve_predicates()?;
 
         Ok(ruleset)
     }
@@ -301,6 +350,12 @@ impl RuleSet {
         Ok(())
     }
 
+    /// Resolves predicate rules that are evaluated during validation and battle.
+    fn resolve_predicates(&mut self) -> Result<()> {
+        self.predicate_rules = PredicateRules::parse_from_ruleset(self)?;
+        Ok(())
+    }
+
     /// Validates all clauses in the ruleset.
     fn validate_clauses(&mut self, dex: &Dex) -> Result<()> {
         for clause in self.clauses(dex) {
@@ -321,10 +376,7 @@ impl RuleSet {
     /// ruleset.
     ///
     /// A [`Clause`] wraps one or more rules to impact different parts of the battle.
-    pub fn clauses<'s, 'd>(
-        &'s self,
-        dex: &'d Dex<'d>,
-    ) -> impl Iterator<Item = ElementRef<'d, Clause>> + 's
+    pub fn clauses<'s, 'd>(&'s self, dex: &'d Dex<'d>) -> impl Iterator<Item = Arc<Clause>> + 's
     where
         'd: 's,
     {
@@ -368,6 +420,19 @@ impl RuleSet {
         self.value(id)?.parse().ok()
     }
 
+    /// Returns the overlay that the ruleset applies to the type chart.
+    pub fn type_chart_overlay(&self) -> Result<TypeChartOverlay> {
+        let overrides = match self.value(&Id::from_known("typechartoverride")) {
+            Some(value) if !value.is_empty() => TypeChartOverlay::parse_overrides(value)
+                .wrap_error_with_message("rule Type Chart Override is invalid")?,
+            _ => Default::default(),
+        };
+        Ok(TypeChartOverlay {
+            inverse: self.has_rule(&Id::from_known("inversebattle")),
+            overrides,
+        })
+    }
+
     /// Returns a serialized form of the ruleset.
     ///
     /// This method makes a clone of all rules, so it can be a bit expensive.
@@ -411,6 +476,7 @@ mod rule_set_test {
     use crate::{
         battle::BattleType,
         config::{
+            Generation,
             ResourceCheck,
             RuleSet,
         },
@@ -547,7 +613,8 @@ mod rule_set_test {
                 "Adjust Level Down = 150",
                 "EV Limit = 1500",
                 "Adjacency Reach = 3",
-                "Obedience Cap = 50"
+                "Obedience Cap = 50",
+                "Mechanics = 3"
             ]"#,
             &BattleType::Singles,
         )
@@ -565,6 +632,7 @@ mod rule_set_test {
         assert_eq!(ruleset.numeric_rules.ev_limit, 1500);
         assert_eq!(ruleset.numeric_rules.adjacency_reach, 3);
         assert_eq!(ruleset.numeric_rules.obedience_cap, 50);
+        assert_eq!(ruleset.numeric_rules.mechanics, Generation::Gen3);
     }
 
     #[test]
@@ -583,6 +651,7 @@ mod rule_set_test {
         assert_eq!(ruleset.numeric_rules.ev_limit, 510);
         assert_eq!(ruleset.numeric_rules.adjacency_reach, 2);
         assert_eq!(ruleset.numeric_rules.obedience_cap, u8::MAX);
+        assert_eq!(ruleset.numeric_rules.mechanics, Generation::Gen9);
     }
 
     #[test]
@@ -621,6 +690,12 @@ mod rule_set_test {
         assert_eq!(ruleset.numeric_rules.picked_team_size, Some(3));
     }
 
+    #[test]
+    fn default_adjacency_reach_horde() {
+        let ruleset = construct_ruleset("[]", &BattleType::Horde).unwrap();
+        assert_eq!(ruleset.numeric_rules.adjacency_reach, 3);
+    }
+
     fn resolves_numbers_fails_with_error(input: &str, battle_type: BattleType, error: &str) {
         assert!(
             format!(
@@ -693,6 +768,11 @@ mod rule_set_test {
             BattleType::Doubles,
             "EV Limit = 10000 has no effect because it is not less than 1512 (252 x 6)",
         );
+        resolves_numbers_fails_with_error(
+            r#"["Mechanics = 10"]"#,
+            BattleType::Singles,
+            "Mechanics = 10 is unsupported",
+        );
     }
 
     #[test]
@@ -1,23 +1,25 @@
-use alloc::format;
-use core::marker::PhantomData;
+use alloc::{
+    format,
+    sync::Arc,
+};
+use core::{
+    cell::RefCell,
+    marker::PhantomData,
+};
 
 use anyhow::Result;
 use battler_data::{
     DataStore,
     Id,
 };
-use zone_alloc::{
-    BorrowError,
-    ElementRef,
-    KeyedRegistry,
-};
+use hashbrown::HashMap;
 
-use crate::error::{
-    ConvertError,
-    general_error,
+use crate::{
+    dex::SharedCache,
+    error::general_error,
 };
 
-type DataTable<T> = KeyedRegistry<Id, T>;
+type DataTable<T> = HashMap<Id, Arc<T>>;
 
 /// The result of a [`ResourceLookup::lookup_alias`] call.
 pub struct LookupAliasOutput<T> {
@@ -62,31 +64,65 @@ pub trait ResourceWrapper<D, T> {
 
 /// A resource cache that can be used internally by [`Dex`][`crate::dex::Dex`] for caching resource
 /// instances.
+///
+/// Cached instances are shared between clones, so cloning the cache does not copy them. The
+/// table of instances is copied the first time a clone saves a new instance.
 pub struct ResourceCache<T> {
-    cache: DataTable<T>,
+    cache: RefCell<Arc<DataTable<T>>>,
 }
 
 impl<T> ResourceCache<T> {
     /// Creates a new resource cache.
     pub fn new() -> Self {
         Self {
-            cache: DataTable::new(),
+            cache: RefCell::new(Arc::new(DataTable::new())),
         }
     }
 
     /// Checks if the given ID is cached.
     pub fn is_cached(&self, id: &Id) -> bool {
-        self.cache.contains_key(id)
+        self.cache.borrow().contains_key(id)
     }
 
     /// Gets the data for a cached ID.
-    pub fn get(&self, id: &Id) -> Result<ElementRef<'_, T>, BorrowError> {
-        self.cache.get(id)
+    pub fn get(&self, id: &Id) -> Option<Arc<T>> {
+        self.cache.borrow().get(id).cloned()
     }
 
     /// Caches the given reference for future lookups.
-    pub fn save(&self, id: &Id, data: T) -> bool {
-        self.cache.register(id.clone(), data)
+    ///
+    /// Returns `false` if the ID is already cached.
+    pub fn save(&self, id: &Id, data: Arc<T>) -> bool {
+        let mut cache = self.cache.borrow_mut();
+        let cache = Arc::make_mut(&mut cache);
+        if cache.contains_key(id) {
+            return false;
+        }
+        cache.insert(id.clone(), data);
+        true
+    }
+}
+
+impl<T> Clone for ResourceCache<T> {
+    fn clone(&self) -> Self {
+        Self {
+            cache: RefCell::new(self.cache.borrow().clone()),
+        }
+    }
+}
+
+/// A [`SharedCache`] used by a [`ResourceDex`], along with the generation the dex reads from.
+struct SharedResourceCache<T> {
+    cache: Arc<dyn SharedCache<Id, T>>,
+    generation: u64,
+}
+
+impl<T> Clone for SharedResourceCache<T> {
+    fn clone(&self) -> Self {
+        Self {
+            cache: self.cache.clone(),
+            generation: self.generation,
+        }
     }
 }
 
@@ -95,6 +131,9 @@ pub struct ResourceDex<'d, D, T, L, W> {
     data: &'d dyn DataStore,
     /// Cache of resource instances, so each ID is only looked up once.
     cache: ResourceCache<T>,
+    /// Cache of resource instances shared with other dexes, so each ID is only looked up once
+    /// across all of them.
+    shared_cache: Option<SharedResourceCache<T>>,
     lookup: L,
     phantom_data: PhantomData<D>,
     phantom_wrapper: PhantomData<W>,
@@ -109,16 +148,41 @@ where
     pub fn new(data: &'d dyn DataStore) -> Self {
         Self {
             data,
-            cache: ResourceCache::new().into(),
+            cache: ResourceCache::new(),
+            shared_cache: None,
             lookup: L::new(data),
             phantom_data: PhantomData,
             phantom_wrapper: PhantomData,
         }
     }
 
+    /// Clears all cached resource instances, so that each ID is looked up again.
+    pub fn clear_cache(&mut self) {
+        self.cache = ResourceCache::new();
+    }
+
+    /// Shares resource instances with other dexes through the given cache.
+    ///
+    /// Only resource instances saved for the given generation of the cache are used.
+    pub fn set_shared_cache(&mut self, cache: Arc<dyn SharedCache<Id, T>>, generation: u64) {
+        self.clear_cache();
+        self.shared_cache = Some(SharedResourceCache { cache, generation });
+    }
+
     fn cache_data(&self, id: &Id, real_id: &Id) -> Result<()> {
-        let output = self.lookup_data_by_id(id, real_id)?;
-        let resource = W::wrap(output.id, output.data);
+        let resource = match &self.shared_cache {
+            Some(shared) => match shared.cache.get(shared.generation, id) {
+                Some(resource) => resource,
+                None => {
+                    let resource = Arc::new(self.create_resource(id, real_id)?);
+                    shared
+                        .cache
+                        .save(shared.generation, id.clone(), resource.clone());
+                    resource
+                }
+            },
+            None => Arc::new(self.create_resource(id, real_id)?),
+        };
         if !self.cache.save(id, resource) {
             Err(general_error(format!(
                 "failed to save data for {id} in cache"
@@ -129,25 +193,20 @@ where
     }
 
     /// Retrieves a resource by name.
-    pub fn get(&self, name: &str) -> Result<ElementRef<'_, T>> {
+    pub fn get(&self, name: &str) -> Result<Arc<T>> {
         self.get_by_id(&Id::from(name))
     }
 
     /// Retrieves a resource by ID.
-    pub fn get_by_id(&self, id: &Id) -> Result<ElementRef<'_, T>> {
-        let real_id = self.resolve_alias(id.clone())?;
-        // The borrow checker struggles if we use pattern matching here, so we have to do two
-        // lookups.
-        if self.cache.is_cached(id) {
-            return self
-                .cache
-                .get(id)
-                .map_err(|err| err.convert_error_with_message(format!("cached resource {id}")));
+    pub fn get_by_id(&self, id: &Id) -> Result<Arc<T>> {
+        if let Some(resource) = self.cache.get(id) {
+            return Ok(resource);
         }
+        let real_id = self.resolve_alias(id.clone())?;
         self.cache_data(id, &real_id)?;
         self.cache
             .get(id)
-            .map_err(|err| err.convert_error_with_message(format!("cached resource {id}")))
+            .ok_or_else(|| general_error(format!("cached resource {id} not found")))
     }
 
     fn resolve_alias(&self, mut id: Id) -> Result<Id> {
@@ -162,6 +221,11 @@ where
         }
     }
 
+    fn create_resource(&self, id: &Id, real_id: &Id) -> Result<T> {
+        let output = self.lookup_data_by_id(id, real_id)?;
+        Ok(W::wrap(output.id, output.data))
+    }
+
     /// Looks up a resource by ID using the internal [`ResourceLookup`] implementation.
     fn lookup_data_by_id(&self, id: &Id, real_id: &Id) -> Result<LookupAliasOutput<D>> {
         self.lookup.lookup_alias(&id, real_id)
@@ -175,7 +239,8 @@ where
     fn clone(&self) -> Self {
         Self {
             data: self.data,
-            cache: ResourceCache::new(),
+            cache: self.cache.clone(),
+            shared_cache: self.shared_cache.clone(),
             lookup: self.lookup.clone(),
             phantom_data: PhantomData,
             phantom_wrapper: PhantomData,
@@ -183,17 +248,20 @@ where
     }
 }
 
-#[derive(Clone)]
+/// A single resource, shared between clones.
 pub struct SingleValueDex<'d, T> {
     #[allow(unused)]
     data: &'d dyn DataStore,
-    value: T,
+    value: Arc<T>,
 }
 
 impl<'d, T> SingleValueDex<'d, T> {
     /// Creates a new single value dex, wrapping the given value.
     pub fn new(data: &'d dyn DataStore, value: T) -> Self {
-        Self { data, value }
+        Self {
+            data,
+            value: Arc::new(value),
+        }
     }
 
     /// Retrieves the inner resource.
@@ -202,8 +270,19 @@ impl<'d, T> SingleValueDex<'d, T> {
     }
 }
 
+impl<'d, T> Clone for SingleValueDex<'d, T> {
+    fn clone(&self) -> Self {
+        Self {
+            data: self.data,
+            value: self.value.clone(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod resource_cache_test {
+    use alloc::sync::Arc;
+
     use battler_data::Id;
 
     use crate::dex::ResourceCache;
@@ -218,7 +297,7 @@ mod resource_cache_test {
         let cache = ResourceCache::<Data>::new();
         let id = Id::from("first");
         assert!(!cache.is_cached(&id));
-        cache.save(&id, Data { number: 123 });
+        cache.save(&id, Arc::new(Data { number: 123 }));
         assert!(cache.is_cached(&id));
     }
 
@@ -227,7 +306,7 @@ mod resource_cache_test {
         let cache = ResourceCache::<Data>::new();
         let id = Id::from("first");
         let data = Data { number: 123 };
-        let inserted = cache.save(&id, data.clone());
+        let inserted = cache.save(&id, Arc::new(data.clone()));
         assert!(inserted);
         let fetched = cache.get(&id);
         assert_eq!(fetched.unwrap().number, 123);
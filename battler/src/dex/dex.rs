@@ -1,21 +1,42 @@
-use alloc::vec::Vec;
+use alloc::{
+    string::String,
+    sync::Arc,
+    vec::Vec,
+};
 
 use anyhow::Result;
 use battler_data::{
+    AbilityData,
+    ClauseData,
+    ConditionData,
     DataStore,
     Id,
+    ItemData,
     MoveData,
+    SpeciesData,
+    SubroutineData,
     TypeChart,
+    TypeChartOverlay,
 };
+use hashbrown::HashMap;
 
-use crate::dex::{
-    AbilityDex,
-    ClauseDex,
-    ConditionDex,
-    ItemDex,
-    MoveDex,
-    SingleValueDex,
-    SpeciesDex,
+use crate::{
+    dex::{
+        AbilityDex,
+        ClauseDex,
+        ConditionDex,
+        DexCache,
+        DexQuery,
+        ItemDex,
+        MoveDex,
+        SharedCache,
+        SingleValueDex,
+        SpeciesDex,
+    },
+    effect::fxlang::{
+        ParsedEffect,
+        ParsedSubroutine,
+    },
 };
 
 /// Collection of all resources indexed by ID.
@@ -35,6 +56,18 @@ pub struct Dex<'d> {
     pub species: SpeciesDex<'d>,
     /// Type chart.
     type_chart: SingleValueDex<'d, TypeChart>,
+    /// fxlang effects compiled from the underlying data, by fxlang ID.
+    ///
+    /// Shared between clones until a clone compiles a new effect.
+    compiled_effects: Arc<HashMap<String, Arc<ParsedEffect>>>,
+    /// fxlang subroutines compiled from the underlying data.
+    ///
+    /// Shared between clones until a clone compiles a new subroutine.
+    ///
+    /// Not bounded, since only subroutines defined in the underlying data are saved.
+    compiled_subroutines: Arc<HashMap<Id, Arc<ParsedSubroutine>>>,
+    /// Cache shared with other dexes, along with the generation this dex reads from.
+    shared_cache: Option<(Arc<dyn DexCache>, u64)>,
 
     data: &'d dyn DataStore,
 }
@@ -51,17 +84,157 @@ impl<'d> Dex<'d> {
             moves: MoveDex::new(data),
             species: SpeciesDex::new(data),
             type_chart,
+            compiled_effects: Arc::default(),
+            compiled_subroutines: Arc::default(),
+            shared_cache: None,
             data,
         })
     }
 
+    /// Shares resources and compiled fxlang programs with other dexes through the given cache.
+    ///
+    /// Resources and programs that are already cached by this dex are cleared.
+    pub fn set_shared_cache(&mut self, cache: Arc<dyn DexCache>) {
+        let generation = cache.generation();
+        self.abilities.set_shared_cache(cache.clone(), generation);
+        self.clauses.set_shared_cache(cache.clone(), generation);
+        self.conditions.set_shared_cache(cache.clone(), generation);
+        self.items.set_shared_cache(cache.clone(), generation);
+        self.moves.set_shared_cache(cache.clone(), generation);
+        self.species.set_shared_cache(cache.clone(), generation);
+        self.compiled_effects = Arc::default();
+        self.compiled_subroutines = Arc::default();
+        self.shared_cache = Some((cache, generation));
+    }
+
     /// Returns the cached type chart.
     pub fn type_chart(&self) -> &TypeChart {
         self.type_chart.get()
     }
 
+    /// Applies an overlay to the cached type chart.
+    ///
+    /// The type chart in the underlying data is not modified.
+    pub fn apply_type_chart_overlay(&mut self, overlay: &TypeChartOverlay) {
+        if overlay.is_empty() {
+            return;
+        }
+        self.type_chart = SingleValueDex::new(self.data, overlay.apply(self.type_chart()));
+    }
+
     /// Gets all move IDs, applying the given filter on the underlying data.
     pub fn all_move_ids(&self, filter: &dyn Fn(&MoveData) -> bool) -> Result<Vec<Id>> {
         self.data.all_move_ids(filter)
     }
+
+    /// Creates a query over all abilities in the underlying data.
+    pub fn query_abilities(&self) -> DexQuery<'d, AbilityData> {
+        DexQuery::new(self.data)
+    }
+
+    /// Creates a query over all clauses in the underlying data.
+    pub fn query_clauses(&self) -> DexQuery<'d, ClauseData> {
+        DexQuery::new(self.data)
+    }
+
+    /// Creates a query over all conditions in the underlying data.
+    pub fn query_conditions(&self) -> DexQuery<'d, ConditionData> {
+        DexQuery::new(self.data)
+    }
+
+    /// Creates a query over all items in the underlying data.
+    pub fn query_items(&self) -> DexQuery<'d, ItemData> {
+        DexQuery::new(self.data)
+    }
+
+    /// Creates a query over all moves in the underlying data.
+    pub fn query_moves(&self) -> DexQuery<'d, MoveData> {
+        DexQuery::new(self.data)
+    }
+
+    /// Creates a query over all species in the underlying data.
+    pub fn query_species(&self) -> DexQuery<'d, SpeciesData> {
+        DexQuery::new(self.data)
+    }
+
+    /// Gets a subroutine by ID.
+    ///
+    /// Subroutine data is not cached by the [`Dex`], since subroutines are cached after
+    /// compiling.
+    pub fn get_subroutine(&self, id: &Id) -> Result<Option<SubroutineData>> {
+        self.data.get_subroutine(id)
+    }
+
+    /// Returns the compiled fxlang effect with the given fxlang ID, if it has been compiled.
+    ///
+    /// The effect only contains its own callbacks; delegates are not merged in.
+    ///
+    /// Effects found in the shared cache are saved locally, so the shared cache is only read once
+    /// for each effect.
+    pub fn compiled_effect(&mut self, fxlang_id: &str) -> Option<Arc<ParsedEffect>> {
+        if let Some(effect) = self.compiled_effects.get(fxlang_id) {
+            return Some(effect.clone());
+        }
+        let (cache, generation) = self.shared_cache.as_ref()?;
+        let effect = SharedCache::<String, ParsedEffect>::get(
+            cache.as_ref(),
+            *generation,
+            &fxlang_id.into(),
+        )?;
+        Arc::make_mut(&mut self.compiled_effects).insert(fxlang_id.into(), effect.clone());
+        Some(effect)
+    }
+
+    /// Saves a compiled fxlang effect, so that it is only compiled once.
+    pub fn save_compiled_effect(&mut self, fxlang_id: String, effect: Arc<ParsedEffect>) {
+        if let Some((cache, generation)) = &self.shared_cache {
+            cache.save(*generation, fxlang_id.clone(), effect.clone());
+        }
+        Arc::make_mut(&mut self.compiled_effects).insert(fxlang_id, effect);
+    }
+
+    /// Returns the compiled fxlang subroutine with the given ID, if it has been compiled.
+    ///
+    /// Subroutines found in the shared cache are saved locally, so the shared cache is only read
+    /// once for each subroutine.
+    pub fn compiled_subroutine(&mut self, id: &Id) -> Option<Arc<ParsedSubroutine>> {
+        if let Some(subroutine) = self.compiled_subroutines.get(id) {
+            return Some(subroutine.clone());
+        }
+        let (cache, generation) = self.shared_cache.as_ref()?;
+        let subroutine = SharedCache::<Id, ParsedSubroutine>::get(cache.as_ref(), *generation, id)?;
+        Arc::make_mut(&mut self.compiled_subroutines).insert(id.clone(), subroutine.clone());
+        Some(subroutine)
+    }
+
+    /// Saves a compiled fxlang subroutine, so that it is only compiled once.
+    pub fn save_compiled_subroutine(&mut self, id: Id, subroutine: Arc<ParsedSubroutine>) {
+        if let Some((cache, generation)) = &self.shared_cache {
+            cache.save(*generation, id.clone(), subroutine.clone());
+        }
+        Arc::make_mut(&mut self.compiled_subroutines).insert(id, subroutine);
+    }
+
+    /// Clears all cached resources and compiled fxlang programs, so that they are read from the
+    /// underlying data again.
+    ///
+    /// If a shared cache is used, resources and programs are read from its current generation,
+    /// which only changes when the shared cache is invalidated.
+    ///
+    /// The type chart is reloaded without any overlay, so overlays must be applied again.
+    pub fn clear_caches(&mut self) -> Result<()> {
+        self.abilities.clear_cache();
+        self.clauses.clear_cache();
+        self.conditions.clear_cache();
+        self.items.clear_cache();
+        self.moves.clear_cache();
+        self.species.clear_cache();
+        self.compiled_effects = Arc::default();
+        self.compiled_subroutines = Arc::default();
+        self.type_chart = SingleValueDex::new(self.data, self.data.get_type_chart()?);
+        if let Some((cache, _)) = self.shared_cache.take() {
+            self.set_shared_cache(cache);
+        }
+        Ok(())
+    }
 }
@@ -5,6 +5,8 @@ mod condition_dex;
 mod dex;
 mod item_dex;
 mod move_dex;
+mod query;
+mod shared_cache;
 mod species_dex;
 
 pub use ability_dex::AbilityDex;
@@ -21,4 +23,14 @@ pub use condition_dex::ConditionDex;
 pub use dex::Dex;
 pub use item_dex::ItemDex;
 pub use move_dex::MoveDex;
+pub use query::{
+    DexQuery,
+    Queryable,
+};
+#[cfg(feature = "std")]
+pub use shared_cache::shared_dex_cache::SharedDexCache;
+pub use shared_cache::{
+    DexCache,
+    SharedCache,
+};
 pub use species_dex::SpeciesDex;
@@ -0,0 +1,333 @@
+use alloc::{
+    boxed::Box,
+    vec::Vec,
+};
+
+use anyhow::Result;
+use battler_data::{
+    AbilityData,
+    AbilityFlag,
+    ClauseData,
+    ConditionData,
+    DataStore,
+    Id,
+    ItemData,
+    ItemFlag,
+    MoveCategory,
+    MoveData,
+    MoveFlag,
+    SpeciesData,
+    SpeciesFlag,
+    Type,
+};
+use hashbrown::HashSet;
+
+/// A type of resource that can be queried in a [`Dex`][`crate::dex::Dex`].
+pub trait Queryable: Sized {
+    /// Gets all resource IDs, applying the given filter on the underlying data.
+    fn all_ids(data: &dyn DataStore, filter: &dyn Fn(&Self) -> bool) -> Result<Vec<Id>>;
+}
+
+impl Queryable for AbilityData {
+    fn all_ids(data: &dyn DataStore, filter: &dyn Fn(&Self) -> bool) -> Result<Vec<Id>> {
+        data.all_ability_ids(filter)
+    }
+}
+
+impl Queryable for ClauseData {
+    fn all_ids(data: &dyn DataStore, filter: &dyn Fn(&Self) -> bool) -> Result<Vec<Id>> {
+        data.all_clause_ids(filter)
+    }
+}
+
+impl Queryable for ConditionData {
+    fn all_ids(data: &dyn DataStore, filter: &dyn Fn(&Self) -> bool) -> Result<Vec<Id>> {
+        data.all_condition_ids(filter)
+    }
+}
+
+impl Queryable for ItemData {
+    fn all_ids(data: &dyn DataStore, filter: &dyn Fn(&Self) -> bool) -> Result<Vec<Id>> {
+        data.all_item_ids(filter)
+    }
+}
+
+impl Queryable for MoveData {
+    fn all_ids(data: &dyn DataStore, filter: &dyn Fn(&Self) -> bool) -> Result<Vec<Id>> {
+        data.all_move_ids(filter)
+    }
+}
+
+impl Queryable for SpeciesData {
+    fn all_ids(data: &dyn DataStore, filter: &dyn Fn(&Self) -> bool) -> Result<Vec<Id>> {
+        data.all_species_ids(filter)
+    }
+}
+
+/// A filter on resources in a [`DexQuery`].
+type Filter<'d, T> = Box<dyn Fn(&T) -> bool + 'd>;
+
+/// A filter on resource IDs in a [`DexQuery`], which may read other resources from the data store.
+type IdFilter<'d> = Box<dyn Fn(&Id) -> Result<bool> + 'd>;
+
+/// A query over all resources of a single type in a [`Dex`][`crate::dex::Dex`].
+///
+/// A query is a set of filters, all of which must match for a resource to be included in the
+/// results. Queries run against the underlying [`DataStore`], so resources do not need to be
+/// loaded into the dex beforehand.
+///
+/// ID filters run after all other filters, outside of the data store scan, since the data store
+/// may not allow reading other resources during the scan.
+pub struct DexQuery<'d, T> {
+    data: &'d dyn DataStore,
+    filters: Vec<Filter<'d, T>>,
+    id_filters: Vec<IdFilter<'d>>,
+}
+
+impl<'d, T> DexQuery<'d, T>
+where
+    T: Queryable,
+{
+    /// Creates a new query that matches all resources.
+    pub fn new(data: &'d dyn DataStore) -> Self {
+        Self {
+            data,
+            filters: Vec::new(),
+            id_filters: Vec::new(),
+        }
+    }
+
+    /// Adds a custom filter to the query.
+    pub fn filter<F>(mut self, filter: F) -> Self
+    where
+        F: Fn(&T) -> bool + 'd,
+    {
+        self.filters.push(Box::new(filter));
+        self
+    }
+
+    /// Adds a filter on resource IDs to the query.
+    fn filter_id<F>(mut self, filter: F) -> Self
+    where
+        F: Fn(&Id) -> Result<bool> + 'd,
+    {
+        self.id_filters.push(Box::new(filter));
+        self
+    }
+
+    /// Runs the query, returning the IDs of all matching resources in sorted order.
+    pub fn ids(&self) -> Result<Vec<Id>> {
+        let ids = T::all_ids(self.data, &|resource| {
+            self.filters.iter().all(|filter| filter(resource))
+        })?;
+        let mut matching = Vec::with_capacity(ids.len());
+        'ids: for id in ids {
+            for filter in &self.id_filters {
+                if !filter(&id)? {
+                    continue 'ids;
+                }
+            }
+            matching.push(id);
+        }
+        matching.sort();
+        Ok(matching)
+    }
+}
+
+impl<'d> DexQuery<'d, AbilityData> {
+    /// Filters abilities with the given flag.
+    pub fn with_flag(self, flag: AbilityFlag) -> Self {
+        self.filter(move |ability| ability.flags.contains(&flag))
+    }
+}
+
+impl<'d> DexQuery<'d, ItemData> {
+    /// Filters items with the given flag.
+    pub fn with_flag(self, flag: ItemFlag) -> Self {
+        self.filter(move |item| item.flags.contains(&flag))
+    }
+}
+
+impl<'d> DexQuery<'d, MoveData> {
+    /// Filters moves with the given flag.
+    pub fn with_flag(self, flag: MoveFlag) -> Self {
+        self.filter(move |mov| mov.flags.contains(&flag))
+    }
+
+    /// Filters moves of the given type.
+    pub fn with_type(self, typ: Type) -> Self {
+        self.filter(move |mov| mov.primary_type == typ)
+    }
+
+    /// Filters moves of the given category.
+    pub fn with_category(self, category: MoveCategory) -> Self {
+        self.filter(move |mov| mov.category == category)
+    }
+}
+
+impl<'d> DexQuery<'d, SpeciesData> {
+    /// Filters species with the given flag.
+    pub fn with_flag(self, flag: SpeciesFlag) -> Self {
+        self.filter(move |species| species.flags.contains(&flag))
+    }
+
+    /// Filters species with the given type.
+    pub fn with_type(self, typ: Type) -> Self {
+        self.filter(move |species| species.types_iter().any(|species_type| species_type == typ))
+    }
+
+    /// Filters species with a base stat total of at least the given value.
+    pub fn with_min_bst(self, bst: u32) -> Self {
+        self.filter(move |species| species.bst() >= bst)
+    }
+
+    /// Filters species with a base stat total of at most the given value.
+    pub fn with_max_bst(self, bst: u32) -> Self {
+        self.filter(move |species| species.bst() <= bst)
+    }
+
+    /// Filters species that can learn the given move.
+    ///
+    /// Moves learned by a base forme or pre-evolution, moves from events, and moves obtainable by
+    /// Sketch are included. The level at which a move is learned is not considered.
+    pub fn learns_move(self, move_id: Id) -> Self {
+        let data = self.data;
+        self.filter_id(move |id| match data.get_species(id)? {
+            Some(species) => Ok(learns_move(
+                data,
+                &species,
+                &move_id,
+                &mut HashSet::default(),
+            )),
+            None => Ok(false),
+        })
+    }
+}
+
+/// Checks if a species can learn a move, following the same species chain as team validation.
+fn learns_move(
+    data: &dyn DataStore,
+    species: &SpeciesData,
+    move_id: &Id,
+    seen: &mut HashSet<Id>,
+) -> bool {
+    if !seen.insert(Id::from(species.name.as_str())) {
+        return false;
+    }
+    if species.learnset.contains_key(move_id.as_ref())
+        || species.learnset.contains_key("sketch")
+        || species
+            .events
+            .values()
+            .any(|event| event.moves.contains(move_id.as_ref()))
+    {
+        return true;
+    }
+    // Formes inherit the learnset of their base forme, and evolutions inherit the learnset of their
+    // pre-evolution.
+    let parent = match species
+        .changes_from
+        .as_ref()
+        .or(species.base_forme.as_ref())
+        .or(species.prevo.as_ref())
+    {
+        Some(parent) => Id::from(parent.as_str()),
+        None => return false,
+    };
+    match data.get_species(&parent) {
+        Ok(Some(parent)) => learns_move(data, &parent, move_id, seen),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod query_test {
+    use battler_data::{
+        Id,
+        ItemFlag,
+        MoveCategory,
+        MoveFlag,
+        Type,
+    };
+    use battler_test_utils::static_local_data_store;
+
+    use crate::dex::Dex;
+
+    #[test]
+    fn queries_species_by_type_bst_and_learnset() {
+        let dex = Dex::new(static_local_data_store()).unwrap();
+        let ids = dex
+            .query_species()
+            .with_type(Type::Fire)
+            .with_min_bst(500)
+            .learns_move(Id::from("willowisp"))
+            .ids()
+            .unwrap();
+        assert!(ids.contains(&Id::from("arcanine")));
+        assert!(ids.contains(&Id::from("charizard")));
+        // Learned by the base forme.
+        assert!(ids.contains(&Id::from("charizardmegax")));
+        assert!(!ids.contains(&Id::from("charmander")));
+        assert!(!ids.contains(&Id::from("blastoise")));
+        assert!(ids.is_sorted());
+    }
+
+    #[test]
+    fn learns_move_through_pre_evolution_and_sketch() {
+        let dex = Dex::new(static_local_data_store()).unwrap();
+        let ids = dex
+            .query_species()
+            .learns_move(Id::from("willowisp"))
+            .ids()
+            .unwrap();
+        assert!(ids.contains(&Id::from("smeargle")));
+        assert!(!ids.contains(&Id::from("bulbasaur")));
+    }
+
+    #[test]
+    fn queries_items_by_flag() {
+        let dex = Dex::new(static_local_data_store()).unwrap();
+        let ids = dex.query_items().with_flag(ItemFlag::Berry).ids().unwrap();
+        assert!(ids.contains(&Id::from("sitrusberry")));
+        assert!(!ids.contains(&Id::from("leftovers")));
+    }
+
+    #[test]
+    fn queries_moves_by_flag_type_and_category() {
+        let dex = Dex::new(static_local_data_store()).unwrap();
+        let ids = dex.query_moves().with_flag(MoveFlag::Sound).ids().unwrap();
+        assert!(ids.contains(&Id::from("boomburst")));
+        assert!(ids.contains(&Id::from("bugbuzz")));
+        assert!(!ids.contains(&Id::from("tackle")));
+
+        let ids = dex
+            .query_moves()
+            .with_flag(MoveFlag::Sound)
+            .with_type(Type::Bug)
+            .with_category(MoveCategory::Special)
+            .ids()
+            .unwrap();
+        assert!(ids.contains(&Id::from("bugbuzz")));
+        assert!(!ids.contains(&Id::from("boomburst")));
+    }
+
+    #[test]
+    fn queries_with_custom_filters() {
+        let dex = Dex::new(static_local_data_store()).unwrap();
+        pretty_assertions::assert_eq!(
+            dex.query_abilities()
+                .filter(|ability| ability.name.starts_with("Speed B"))
+                .ids()
+                .unwrap(),
+            Vec::from_iter([Id::from("speedboost")])
+        );
+        pretty_assertions::assert_eq!(
+            dex.query_conditions()
+                .filter(|condition| condition.name == "Rain")
+                .ids()
+                .unwrap(),
+            Vec::from_iter([Id::from("rainweather")])
+        );
+        assert!(!dex.query_clauses().ids().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,290 @@
+use alloc::{
+    string::String,
+    sync::Arc,
+};
+use core::fmt::Debug;
+
+use battler_data::Id;
+
+use crate::{
+    abilities::Ability,
+    conditions::Condition,
+    config::Clause,
+    effect::fxlang::{
+        ParsedEffect,
+        ParsedSubroutine,
+    },
+    items::Item,
+    mons::Species,
+    moves::Move,
+};
+
+/// A table of immutable values shared by many dexes.
+///
+/// Every value is saved for a single generation of the cache. A dex only reads values saved for
+/// its own generation, so values created from different versions of the underlying data are never
+/// mixed.
+pub trait SharedCache<K, V>: Send + Sync {
+    /// Gets a cached value, if it was saved for the given generation.
+    fn get(&self, generation: u64, key: &K) -> Option<Arc<V>>;
+
+    /// Saves a value created for the given generation.
+    ///
+    /// The value is dropped if the cache was invalidated since.
+    fn save(&self, generation: u64, key: K, value: Arc<V>);
+}
+
+/// A cache of resources and compiled fxlang programs, shared by many battles.
+///
+/// Set with [`CoreBattleEngineOptions::dex_cache`][`crate::battle::CoreBattleEngineOptions::dex_cache`].
+pub trait DexCache:
+    SharedCache<Id, Ability>
+    + SharedCache<Id, Clause>
+    + SharedCache<Id, Condition>
+    + SharedCache<Id, Item>
+    + SharedCache<Id, Move>
+    + SharedCache<Id, Species>
+    + SharedCache<String, ParsedEffect>
+    + SharedCache<Id, ParsedSubroutine>
+    + Debug
+{
+    /// The current generation of the cache.
+    ///
+    /// The generation changes whenever the cache is invalidated.
+    fn generation(&self) -> u64;
+}
+
+#[cfg(feature = "std")]
+pub mod shared_dex_cache {
+    use alloc::{
+        string::String,
+        sync::Arc,
+    };
+    use core::fmt::{
+        self,
+        Debug,
+    };
+    use std::sync::{
+        PoisonError,
+        RwLock,
+        RwLockReadGuard,
+        RwLockWriteGuard,
+    };
+
+    use battler_data::Id;
+    use hashbrown::HashMap;
+
+    use crate::{
+        abilities::Ability,
+        conditions::Condition,
+        config::Clause,
+        dex::{
+            DexCache,
+            SharedCache,
+        },
+        effect::fxlang::{
+            ParsedEffect,
+            ParsedSubroutine,
+        },
+        items::Item,
+        mons::Species,
+        moves::Move,
+    };
+
+    #[derive(Default)]
+    struct Tables {
+        generation: u64,
+        data_generation: u64,
+        abilities: HashMap<Id, Arc<Ability>>,
+        clauses: HashMap<Id, Arc<Clause>>,
+        conditions: HashMap<Id, Arc<Condition>>,
+        items: HashMap<Id, Arc<Item>>,
+        moves: HashMap<Id, Arc<Move>>,
+        species: HashMap<Id, Arc<Species>>,
+        effects: HashMap<String, Arc<ParsedEffect>>,
+        subroutines: HashMap<Id, Arc<ParsedSubroutine>>,
+    }
+
+    /// A thread-safe [`DexCache`], shared by many battles.
+    ///
+    /// Each resource is read from the underlying data and each fxlang program is compiled once,
+    /// by the first battle that needs it. Every other battle using the cache copies the resource
+    /// or reuses the compiled program, without reading the underlying data again.
+    ///
+    /// The cache must be invalidated when the underlying data changes. Running battles must also
+    /// invalidate their own caches (with
+    /// [`PublicCoreBattle::invalidate_data_caches`][`crate::battle::PublicCoreBattle::invalidate_data_caches`])
+    /// before they use the new data.
+    #[derive(Default)]
+    pub struct SharedDexCache {
+        tables: RwLock<Tables>,
+    }
+
+    impl SharedDexCache {
+        /// Creates a new, empty cache.
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Clears all cached values, so that they are created again from the underlying data.
+        pub fn invalidate(&self) {
+            let mut tables = self.write();
+            *tables = Tables {
+                generation: tables.generation + 1,
+                data_generation: tables.data_generation,
+                ..Default::default()
+            };
+        }
+
+        /// Invalidates the cache if the generation of the underlying data changed since the last
+        /// call.
+        ///
+        /// Intended for data stores that count reloads, such as `WatchedLocalDataStore` from
+        /// `battler-local-data`. Returns `true` if the cache was invalidated.
+        pub fn invalidate_if_changed(&self, data_generation: u64) -> bool {
+            let mut tables = self.write();
+            if tables.data_generation == data_generation {
+                return false;
+            }
+            *tables = Tables {
+                generation: tables.generation + 1,
+                data_generation,
+                ..Default::default()
+            };
+            true
+        }
+
+        fn read(&self) -> RwLockReadGuard<'_, Tables> {
+            // Cached values are immutable, so a panic while the lock was held cannot leave a table
+            // in an inconsistent state.
+            self.tables.read().unwrap_or_else(PoisonError::into_inner)
+        }
+
+        fn write(&self) -> RwLockWriteGuard<'_, Tables> {
+            self.tables.write().unwrap_or_else(PoisonError::into_inner)
+        }
+    }
+
+    impl Debug for SharedDexCache {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("SharedDexCache")
+                .field("generation", &self.generation())
+                .finish_non_exhaustive()
+        }
+    }
+
+    macro_rules! shared_dex_cache_table {
+        ($key:ty, $value:ty, $table:ident) => {
+            impl SharedCache<$key, $value> for SharedDexCache {
+                fn get(&self, generation: u64, key: &$key) -> Option<Arc<$value>> {
+                    let tables = self.read();
+                    if tables.generation != generation {
+                        return None;
+                    }
+                    tables.$table.get(key).cloned()
+                }
+
+                fn save(&self, generation: u64, key: $key, value: Arc<$value>) {
+                    let mut tables = self.write();
+                    if tables.generation == generation {
+                        tables.$table.insert(key, value);
+                    }
+                }
+            }
+        };
+    }
+
+    shared_dex_cache_table!(Id, Ability, abilities);
+    shared_dex_cache_table!(Id, Clause, clauses);
+    shared_dex_cache_table!(Id, Condition, conditions);
+    shared_dex_cache_table!(Id, Item, items);
+    shared_dex_cache_table!(Id, Move, moves);
+    shared_dex_cache_table!(Id, Species, species);
+    shared_dex_cache_table!(String, ParsedEffect, effects);
+    shared_dex_cache_table!(Id, ParsedSubroutine, subroutines);
+
+    impl DexCache for SharedDexCache {
+        fn generation(&self) -> u64 {
+            self.read().generation
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod shared_cache_test {
+    use alloc::sync::Arc;
+
+    use battler_data::Id;
+    use battler_test_utils::static_local_data_store;
+
+    use crate::{
+        dex::{
+            Dex,
+            DexCache,
+            SharedCache,
+            SharedDexCache,
+        },
+        items::Item,
+    };
+
+    fn item(id: &str) -> Arc<Item> {
+        let dex = Dex::new(static_local_data_store()).unwrap();
+        dex.items.get_by_id(&Id::from(id)).unwrap()
+    }
+
+    #[test]
+    fn saves_values_for_current_generation() {
+        let cache = SharedDexCache::new();
+        let generation = cache.generation();
+        cache.save(generation, Id::from("leftovers"), item("leftovers"));
+        assert_matches::assert_matches!(
+            SharedCache::<Id, Item>::get(&cache, generation, &Id::from("leftovers")),
+            Some(item) => assert_eq!(item.data.name, "Leftovers")
+        );
+        assert_matches::assert_matches!(
+            SharedCache::<Id, Item>::get(&cache, generation + 1, &Id::from("leftovers")),
+            None
+        );
+    }
+
+    #[test]
+    fn drops_values_from_previous_generation() {
+        let cache = SharedDexCache::new();
+        let generation = cache.generation();
+        cache.save(generation, Id::from("leftovers"), item("leftovers"));
+        cache.invalidate();
+        assert_eq!(cache.generation(), generation + 1);
+        assert_matches::assert_matches!(
+            SharedCache::<Id, Item>::get(&cache, generation, &Id::from("leftovers")),
+            None
+        );
+
+        cache.save(generation, Id::from("leftovers"), item("leftovers"));
+        assert_matches::assert_matches!(
+            SharedCache::<Id, Item>::get(&cache, cache.generation(), &Id::from("leftovers")),
+            None
+        );
+    }
+
+    #[test]
+    fn dexes_share_resource_instances() {
+        let cache = Arc::new(SharedDexCache::new());
+        let mut dex_1 = Dex::new(static_local_data_store()).unwrap();
+        dex_1.set_shared_cache(cache.clone());
+        let mut dex_2 = Dex::new(static_local_data_store()).unwrap();
+        dex_2.set_shared_cache(cache);
+        assert!(Arc::ptr_eq(
+            &dex_1.moves.get_by_id(&Id::from("tackle")).unwrap(),
+            &dex_2.moves.get_by_id(&Id::from("tackle")).unwrap(),
+        ));
+    }
+
+    #[test]
+    fn invalidates_when_data_generation_changes() {
+        let cache = SharedDexCache::new();
+        assert!(!cache.invalidate_if_changed(0));
+        assert!(cache.invalidate_if_changed(1));
+        assert!(!cache.invalidate_if_changed(1));
+        assert_eq!(cache.generation(), 1);
+    }
+}
@@ -1,4 +1,8 @@
 use anyhow::Result;
+use serde::{
+    Deserialize,
+    Serialize,
+};
 
 use crate::{
     battle::{
@@ -29,7 +33,7 @@ use crate::{
     error::WrapOptionError,
 };
 /// The location of an applied effect, which is an effect that is active in a battle.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum AppliedEffectLocation {
     None,
     ActiveMove(MoveHandle),
@@ -119,7 +123,7 @@ impl AppliedEffectLocation {
 }
 
 /// Handle to an applied effect, which is active in some part of a battle.
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct AppliedEffectHandle {
     pub effect_handle: EffectHandle,
     pub location: AppliedEffectLocation,
@@ -5,6 +5,7 @@ use alloc::{
         String,
         ToString,
     },
+    sync::Arc,
 };
 use core::ops::{
     Deref,
@@ -16,10 +17,11 @@ use battler_data::{
     Id,
     Identifiable,
 };
-use zone_alloc::{
-    ElementRef,
-    ElementRefMut,
+use serde::{
+    Deserialize,
+    Serialize,
 };
+use zone_alloc::ElementRefMut;
 
 use crate::{
     abilities::Ability,
@@ -40,7 +42,7 @@ use crate::{
 };
 
 /// A non-existent effect.
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct NonExistentEffect {
     pub name: String,
     pub id: Id,
@@ -127,7 +129,7 @@ pub enum EffectType {
 /// An [`Effect`] handle.
 ///
 /// A stable way to identify an [`Effect`].
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum EffectHandle {
     /// An active move, which is being used or was recently used by a Mon.
     ActiveMove(MoveHandle, MoveHitEffectType),
@@ -295,23 +297,23 @@ pub enum Effect<'borrow> {
     /// A move currently being used by a Mon.
     ActiveMove(&'borrow mut Move, MoveHitEffectType),
     /// A condition induced by a previously-used move.
-    MoveCondition(ElementRef<'borrow, Move>),
+    MoveCondition(Arc<Move>),
     /// An inactive move, which is not currently being used by a Mon.
-    InactiveMove(ElementRef<'borrow, Move>),
+    InactiveMove(Arc<Move>),
     /// An ability, which is permanently applied to a Mon.
-    Ability(ElementRef<'borrow, Ability>),
+    Ability(Arc<Ability>),
     /// A condition induced by an ability.
-    AbilityCondition(ElementRef<'borrow, Ability>),
+    AbilityCondition(Arc<Ability>),
     /// A condition, which is applied to a Mon for some number of turns.
-    Condition(ElementRef<'borrow, Condition>),
+    Condition(Arc<Condition>),
     /// An item, which is held by a Mon.
-    Item(ElementRef<'borrow, Item>),
+    Item(Arc<Item>),
     /// A condition induced by an item.
-    ItemCondition(ElementRef<'borrow, Item>),
+    ItemCondition(Arc<Item>),
     /// A clause applied to a battle format.
-    Clause(ElementRef<'borrow, Clause>),
+    Clause(Arc<Clause>),
     /// A species.
-    Species(ElementRef<'borrow, Species>),
+    Species(Arc<Species>),
     /// A non-existent effect, which does nothing.
     NonExistent(NonExistentEffect),
 }
@@ -326,47 +328,47 @@ impl<'borrow> Effect<'borrow> {
     }
 
     /// Creates a new effect for the ability.
-    pub fn for_ability(ability: ElementRef<'borrow, Ability>) -> Self {
+    pub fn for_ability(ability: Arc<Ability>) -> Self {
         Self::Ability(ability)
     }
 
     /// Creates a new effect for the ability condition.
-    pub fn for_ability_condition(ability: ElementRef<'borrow, Ability>) -> Self {
+    pub fn for_ability_condition(ability: Arc<Ability>) -> Self {
         Self::AbilityCondition(ability)
     }
 
     /// Creates a new effect for the condition.
-    pub fn for_condition(condition: ElementRef<'borrow, Condition>) -> Self {
+    pub fn for_condition(condition: Arc<Condition>) -> Self {
         Self::Condition(condition)
     }
 
     /// Creates a new effect for the move condition.
-    pub fn for_move_condition(mov: ElementRef<'borrow, Move>) -> Self {
+    pub fn for_move_condition(mov: Arc<Move>) -> Self {
         Self::MoveCondition(mov)
     }
 
     /// Creates a new effect for the item.
-    pub fn for_item(item: ElementRef<'borrow, Item>) -> Self {
+    pub fn for_item(item: Arc<Item>) -> Self {
         Self::Item(item)
     }
 
     /// Creates a new effect for the item condition.
-    pub fn for_item_condition(item: ElementRef<'borrow, Item>) -> Self {
+    pub fn for_item_condition(item: Arc<Item>) -> Self {
         Self::ItemCondition(item)
     }
 
     /// Creates a new effect for the clause.
-    pub fn for_clause(clause: ElementRef<'borrow, Clause>) -> Self {
+    pub fn for_clause(clause: Arc<Clause>) -> Self {
         Self::Clause(clause)
     }
 
     /// Creates a new effect for the species.
-    pub fn for_species(species: ElementRef<'borrow, Species>) -> Self {
+    pub fn for_species(species: Arc<Species>) -> Self {
         Self::Species(species)
     }
 
     /// Creates a new effect for the move.
-    pub fn for_inactive_move(mov: ElementRef<'borrow, Move>) -> Self {
+    pub fn for_inactive_move(mov: Arc<Move>) -> Self {
         Self::InactiveMove(mov)
     }
 
@@ -455,6 +457,37 @@ impl<'borrow> Effect<'borrow> {
         }
     }
 
+    /// The ID of the effect in battle data, if the effect comes from battle data.
+    ///
+    /// Effects on moves, abilities, and items are qualified by where they appear in the data (e.g.,
+    /// `move:tackle/condition`).
+    pub fn data_id(&self) -> Option<String> {
+        if self.unlinked() {
+            return None;
+        }
+        match self {
+            Self::ActiveMove(active_move, hit_effect_type) => {
+                match hit_effect_type.secondary_index() {
+                    None => Some(format!("move:{}", active_move.id())),
+                    Some((_, _, secondary_index)) => Some(format!(
+                        "move:{}/secondary_effects/{secondary_index}",
+                        active_move.id()
+                    )),
+                }
+            }
+            Self::MoveCondition(mov) => Some(format!("move:{}/condition", mov.id())),
+            Self::InactiveMove(mov) => Some(format!("move:{}", mov.id())),
+            Self::Ability(ability) => Some(format!("ability:{}", ability.id())),
+            Self::AbilityCondition(ability) => Some(format!("ability:{}/condition", ability.id())),
+            Self::Condition(condition) => Some(format!("condition:{}", condition.id())),
+            Self::Item(item) => Some(format!("item:{}", item.id())),
+            Self::ItemCondition(item) => Some(format!("item:{}/condition", item.id())),
+            Self::Clause(clause) => Some(format!("clause:{}", clause.id())),
+            Self::Species(species) => Some(format!("species:{}", species.id())),
+            Self::NonExistent(_) => None,
+        }
+    }
+
     /// The underlying move, if any.
     pub fn move_effect<'effect>(&'effect self) -> Option<&'effect Move> {
         match self {
@@ -1,4 +1,6 @@
 use alloc::{
+    borrow::ToOwned,
+    collections::VecDeque,
     format,
     string::String,
     sync::Arc,
@@ -8,6 +10,7 @@ use anyhow::{
     Error,
     Result,
 };
+use battler_data::Id;
 
 use crate::{
     WrapResultError,
@@ -26,16 +29,20 @@ use crate::{
         fxlang::{
             BattleEvent,
             BattleEventModifier,
+            CompiledProgram,
             DynamicEffectStateConnector,
             EvaluationContext,
             Evaluator,
             EventState,
             ParsedCallback,
             ParsedEffect,
-            ParsedProgram,
+            ParsedSubroutine,
             Program,
             ProgramEvalResult,
             ProgramMetadata,
+            TraceRecord,
+            Tracer,
+            Value,
             VariableInput,
         },
     },
@@ -63,10 +70,29 @@ impl<'s> TryFrom<&'s str> for EventCallbackMapping<'s> {
     }
 }
 
+/// A call to an fxlang subroutine from another program.
+///
+/// The event inputs are taken from the calling program, so that the subroutine is evaluated in the
+/// same context.
+pub struct SubroutineCall<'eval> {
+    pub name: &'eval str,
+    pub args: VecDeque<Value>,
+    pub event: BattleEvent,
+    pub event_state: &'eval EventState,
+    pub effect_state_connector: Option<DynamicEffectStateConnector>,
+    pub effect_mon_handle: Option<MonHandle>,
+    pub event_origin_mon_handle: Option<MonHandle>,
+    pub untrusted: bool,
+}
+
 /// Module for managing fxlang effect programs and their evaluation.
+///
+/// Cloning an effect manager is cheap, since parsed effects are shared.
+#[derive(Clone)]
 pub struct EffectManager {
     effects: LruCache<String, Arc<ParsedEffect>>,
     stack: usize,
+    instructions: u64,
 }
 
 impl EffectManager {
@@ -80,6 +106,48 @@ impl EffectManager {
         Self {
             effects: LruCache::new(Self::MAX_SAVED_EFFECTS),
             stack: 0,
+            instructions: 0,
+        }
+    }
+
+    /// Clears all parsed effects, so that they are parsed again on next use.
+    pub fn clear(&mut self) {
+        self.effects = LruCache::new(Self::MAX_SAVED_EFFECTS);
+    }
+
+    /// Counts an instruction executed by an fxlang program, returning the number of instructions
+    /// executed this turn.
+    ///
+    /// Instructions are only counted when a turn budget is set.
+    pub fn count_instruction(&mut self) -> u64 {
+        self.instructions += 1;
+        self.instructions
+    }
+
+    /// The number of instructions executed this turn.
+    pub fn instruction_count(&self) -> u64 {
+        self.instructions
+    }
+
+    /// Sets the number of instructions executed this turn, for restoring a battle mid-turn.
+    pub fn set_instruction_count(&mut self, instructions: u64) {
+        self.instructions = instructions;
+    }
+
+    /// Resets the number of instructions executed this turn.
+    pub fn reset_instruction_count(&mut self) {
+        self.instructions = 0;
+    }
+
+    /// The tracer for fxlang evaluation, if tracing is enabled.
+    pub fn tracer(context: &Context) -> Option<Arc<dyn Tracer>> {
+        context.battle().engine_options.tracer.clone()
+    }
+
+    fn effect_name(context: &Context, effect_handle: &EffectHandle) -> String {
+        match CoreBattle::get_effect_by_handle(context, effect_handle) {
+            Ok(effect) => effect.name().to_owned(),
+            Err(_) => format!("{effect_handle:?}"),
         }
     }
 
@@ -108,6 +176,18 @@ impl EffectManager {
             )));
         }
 
+        let tracer = Self::tracer(context.battle_context());
+        let effect_name = tracer.as_ref().map(|tracer| {
+            let effect_name = Self::effect_name(context.battle_context(), effect_handle);
+            tracer.record(TraceRecord::Callback {
+                event,
+                modifier,
+                effect: effect_name.clone(),
+                input: input.clone().into_iter().collect(),
+            });
+            effect_name
+        });
+
         let result = Self::evaluate_internal(
             context,
             effect_handle,
@@ -120,6 +200,20 @@ impl EffectManager {
             event_origin_mon_handle,
         );
 
+        if let Some(tracer) = tracer {
+            let (value, error) = match &result {
+                Ok(result) => (result.value.clone(), None),
+                Err(error) => (None, Some(format!("{error:#}"))),
+            };
+            tracer.record(TraceRecord::CallbackResult {
+                event,
+                modifier,
+                effect: effect_name.unwrap_or_default(),
+                value,
+                error,
+            });
+        }
+
         context
             .battle_context_mut()
             .battle_mut()
@@ -163,11 +257,26 @@ impl EffectManager {
         // Parse the effect's callbacks.
         let parsed_effect = match effect.fxlang_effect() {
             Some(fxlang_effect) => {
-                let parsed_effect = ParsedEffect::new(
-                    &fxlang_effect.callbacks,
-                    fxlang_effect.attributes.condition.clone(),
-                    fxlang_effect.local_data.clone(),
-                )?;
+                // Compiled programs do not depend on the battle, so they are shared through the
+                // Dex. Unlinked effects are unique to the battle, so they are never shared.
+                let parsed_effect = match context.battle_mut().dex.compiled_effect(&id) {
+                    Some(parsed_effect) if !effect.unlinked() => parsed_effect.as_ref().clone(),
+                    _ => {
+                        let parsed_effect = ParsedEffect::new(
+                            &fxlang_effect.callbacks,
+                            fxlang_effect.attributes.condition.clone(),
+                            fxlang_effect.local_data.clone(),
+                            effect.data_id().as_deref(),
+                        )?;
+                        if !effect.unlinked() {
+                            context
+                                .battle_mut()
+                                .dex
+                                .save_compiled_effect(id.clone(), Arc::new(parsed_effect.clone()));
+                        }
+                        parsed_effect
+                    }
+                };
                 let mut combined_effect = ParsedEffect::default();
                 // If we are delegating to other effects, look them up and merge our callbacks in at
                 // the end.
@@ -243,6 +352,108 @@ impl EffectManager {
             .cloned())
     }
 
+    /// Looks up the parsed subroutine with the given name.
+    ///
+    /// Subroutines are compiled once and cached in the Dex. Nothing is cached for names that are
+    /// not defined in the data, so the cache never holds more than every subroutine in the data.
+    pub fn parsed_subroutine(
+        context: &mut Context,
+        name: &str,
+    ) -> Result<Option<Arc<ParsedSubroutine>>> {
+        let id = Id::from(name);
+        if let Some(subroutine) = context.battle_mut().dex.compiled_subroutine(&id) {
+            return Ok(Some(subroutine));
+        }
+
+        let subroutine = match context.battle().dex.get_subroutine(&id)? {
+            Some(subroutine) => subroutine,
+            None => return Ok(None),
+        };
+        let subroutine = Arc::new(
+            ParsedSubroutine::new(&subroutine)
+                .wrap_error_with_format(format_args!("error parsing subroutine {name}"))?,
+        );
+
+        context
+            .battle_mut()
+            .dex
+            .save_compiled_subroutine(id, subroutine.clone());
+        Ok(Some(subroutine))
+    }
+
+    /// Evaluates a subroutine called from another program.
+    ///
+    /// A subroutine called by an untrusted program is evaluated as untrusted.
+    pub fn evaluate_subroutine(
+        context: &mut EvaluationContext,
+        subroutine: &ParsedSubroutine,
+        call: SubroutineCall,
+    ) -> Result<Option<Value>> {
+        let SubroutineCall {
+            name,
+            args,
+            event,
+            event_state,
+            effect_state_connector,
+            effect_mon_handle,
+            event_origin_mon_handle,
+            untrusted,
+        } = call;
+        if context.battle_context().battle().effect_manager.stack >= Self::MAX_STACK_SIZE {
+            return Err(general_error(format!(
+                "fxlang effect callback stack size exceeded for subroutine {name}"
+            )));
+        }
+
+        context
+            .battle_context_mut()
+            .battle_mut()
+            .effect_manager
+            .stack += 1;
+
+        let tracer = Self::tracer(context.battle_context());
+        if let Some(tracer) = &tracer {
+            tracer.record(TraceRecord::Subroutine {
+                name: name.to_owned(),
+                args: args.iter().cloned().collect(),
+            });
+        }
+
+        let mut evaluator = Evaluator::new(event, event_state)
+            .with_tracer(tracer.clone())
+            .with_untrusted(untrusted);
+        let result = evaluator
+            .evaluate_subroutine(
+                context,
+                args,
+                subroutine,
+                effect_state_connector,
+                effect_mon_handle,
+                event_origin_mon_handle,
+            )
+            .wrap_error_with_format(format_args!("error in subroutine {name}"));
+
+        if let Some(tracer) = tracer {
+            let (value, error) = match &result {
+                Ok(value) => (value.clone(), None),
+                Err(error) => (None, Some(format!("{error:#}"))),
+            };
+            tracer.record(TraceRecord::SubroutineResult {
+                name: name.to_owned(),
+                value,
+                error,
+            });
+        }
+
+        context
+            .battle_context_mut()
+            .battle_mut()
+            .effect_manager
+            .stack -= 1;
+
+        result
+    }
+
     fn evaluate_internal(
         context: &mut EvaluationContext,
         effect_handle: &EffectHandle,
@@ -254,7 +465,9 @@ impl EffectManager {
         effect_mon_handle: Option<MonHandle>,
         event_origin_mon_handle: Option<MonHandle>,
     ) -> Result<ProgramEvalResult> {
-        let mut evaluator = Evaluator::new(event, event_state);
+        let mut evaluator = Evaluator::new(event, event_state)
+            .with_tracer(Self::tracer(context.battle_context()))
+            .with_untrusted(matches!(effect_handle, EffectHandle::OutsideCondition(_)));
         let effect = Self::parsed_effect(context.battle_context_mut(), effect_handle)?;
         match effect
             .as_ref()
@@ -280,13 +493,17 @@ impl EffectManager {
         program: &Program,
     ) -> Result<ProgramEvalResult> {
         let event_state = EventState::default();
-        let mut evaluator = Evaluator::new(event, &event_state);
+        let mut evaluator = Evaluator::new(event, &event_state)
+            .with_tracer(Self::tracer(context.battle_context()))
+            .with_untrusted(true);
         evaluator.evaluate_program(
             context,
             VariableInput::default(),
             &ParsedCallback {
-                program: ParsedProgram::from(program)
-                    .wrap_error_with_format(format_args!("error parsing outside effect program"))?,
+                program: Arc::new(CompiledProgram::from(program).wrap_error_with_format(
+                    format_args!("error parsing outside effect program"),
+                )?),
+                source: None,
                 order: 0,
                 priority: 0,
                 sub_order: 0,
@@ -1,5 +1,9 @@
 use anyhow::Result;
 use battler_data::Id;
+use serde::{
+    Deserialize,
+    Serialize,
+};
 
 use crate::{
     battle::{
@@ -9,12 +13,15 @@ use crate::{
     },
     effect::{
         AppliedEffectLocation,
-        fxlang,
+        fxlang::{
+            self,
+            EffectStateConnector,
+        },
     },
 };
 /// [`EffectStateConnector`][`crate::effect::fxlang::EffectStateConnector`] implementation for an
 /// active move.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ActiveMoveEffectStateConnector {
     active_move: MoveHandle,
 }
@@ -43,11 +50,15 @@ impl fxlang::EffectStateConnector for ActiveMoveEffectStateConnector {
     fn make_dynamic(&self) -> fxlang::DynamicEffectStateConnector {
         fxlang::DynamicEffectStateConnector::new(self.clone())
     }
+
+    fn data(&self) -> EffectStateConnectorData {
+        EffectStateConnectorData::ActiveMove(self.clone())
+    }
 }
 
 /// [`EffectStateConnector`][`crate::effect::fxlang::EffectStateConnector`] implementation for a
 /// Mon.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MonEffectStateConnector {
     mon: MonHandle,
 }
@@ -74,11 +85,15 @@ impl fxlang::EffectStateConnector for MonEffectStateConnector {
     fn make_dynamic(&self) -> fxlang::DynamicEffectStateConnector {
         fxlang::DynamicEffectStateConnector::new(self.clone())
     }
+
+    fn data(&self) -> EffectStateConnectorData {
+        EffectStateConnectorData::Mon(self.clone())
+    }
 }
 
 /// [`EffectStateConnector`][`crate::effect::fxlang::EffectStateConnector`] implementation for the
 /// volatile state of a Mon.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MonVolatileEffectStateConnector {
     mon: MonHandle,
 }
@@ -110,11 +125,15 @@ impl fxlang::EffectStateConnector for MonVolatileEffectStateConnector {
     fn make_dynamic(&self) -> fxlang::DynamicEffectStateConnector {
         fxlang::DynamicEffectStateConnector::new(self.clone())
     }
+
+    fn data(&self) -> EffectStateConnectorData {
+        EffectStateConnectorData::MonVolatile(self.clone())
+    }
 }
 
 /// [`EffectStateConnector`][`crate::effect::fxlang::EffectStateConnector`] implementation for an
 /// ability on a Mon.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MonAbilityEffectStateConnector {
     mon: MonHandle,
 }
@@ -147,11 +166,15 @@ impl fxlang::EffectStateConnector for MonAbilityEffectStateConnector {
     fn make_dynamic(&self) -> fxlang::DynamicEffectStateConnector {
         fxlang::DynamicEffectStateConnector::new(self.clone())
     }
+
+    fn data(&self) -> EffectStateConnectorData {
+        EffectStateConnectorData::MonAbility(self.clone())
+    }
 }
 
 /// [`EffectStateConnector`][`crate::effect::fxlang::EffectStateConnector`] implementation for an
 /// item on a Mon.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MonItemEffectStateConnector {
     mon: MonHandle,
 }
@@ -183,11 +206,15 @@ impl fxlang::EffectStateConnector for MonItemEffectStateConnector {
     fn make_dynamic(&self) -> fxlang::DynamicEffectStateConnector {
         fxlang::DynamicEffectStateConnector::new(self.clone())
     }
+
+    fn data(&self) -> EffectStateConnectorData {
+        EffectStateConnectorData::MonItem(self.clone())
+    }
 }
 
 /// [`EffectStateConnector`][`crate::effect::fxlang::EffectStateConnector`] implementation for a
 /// status on a Mon.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MonStatusEffectStateConnector {
     mon: MonHandle,
 }
@@ -214,11 +241,15 @@ impl fxlang::EffectStateConnector for MonStatusEffectStateConnector {
     fn make_dynamic(&self) -> fxlang::DynamicEffectStateConnector {
         fxlang::DynamicEffectStateConnector::new(self.clone())
     }
+
+    fn data(&self) -> EffectStateConnectorData {
+        EffectStateConnectorData::MonStatus(self.clone())
+    }
 }
 
 /// [`EffectStateConnector`][`crate::effect::fxlang::EffectStateConnector`] implementation for the
 /// Terastallization of a Mon.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MonTerastallizationEffectStateConnector {
     mon: MonHandle,
 }
@@ -245,11 +276,15 @@ impl fxlang::EffectStateConnector for MonTerastallizationEffectStateConnector {
     fn make_dynamic(&self) -> fxlang::DynamicEffectStateConnector {
         fxlang::DynamicEffectStateConnector::new(self.clone())
     }
+
+    fn data(&self) -> EffectStateConnectorData {
+        EffectStateConnectorData::MonTerastallization(self.clone())
+    }
 }
 
 /// [`EffectStateConnector`][`crate::effect::fxlang::EffectStateConnector`] implementation for a
 /// volatile status on a Mon.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MonVolatileStatusEffectStateConnector {
     mon: MonHandle,
     volatile: Id,
@@ -285,11 +320,15 @@ impl fxlang::EffectStateConnector for MonVolatileStatusEffectStateConnector {
     fn make_dynamic(&self) -> fxlang::DynamicEffectStateConnector {
         fxlang::DynamicEffectStateConnector::new(self.clone())
     }
+
+    fn data(&self) -> EffectStateConnectorData {
+        EffectStateConnectorData::MonVolatileStatus(self.clone())
+    }
 }
 
 /// [`EffectStateConnector`][`crate::effect::fxlang::EffectStateConnector`] implementation for a
 /// condition on a side.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SideConditionEffectStateConnector {
     side: usize,
     condition: Id,
@@ -325,11 +364,15 @@ impl fxlang::EffectStateConnector for SideConditionEffectStateConnector {
     fn make_dynamic(&self) -> fxlang::DynamicEffectStateConnector {
         fxlang::DynamicEffectStateConnector::new(self.clone())
     }
+
+    fn data(&self) -> EffectStateConnectorData {
+        EffectStateConnectorData::SideCondition(self.clone())
+    }
 }
 
 /// [`EffectStateConnector`][`crate::effect::fxlang::EffectStateConnector`] implementation for a
 /// condition on a slot.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SlotConditionEffectStateConnector {
     side: usize,
     slot: usize,
@@ -375,11 +418,15 @@ impl fxlang::EffectStateConnector for SlotConditionEffectStateConnector {
     fn make_dynamic(&self) -> fxlang::DynamicEffectStateConnector {
         fxlang::DynamicEffectStateConnector::new(self.clone())
     }
+
+    fn data(&self) -> EffectStateConnectorData {
+        EffectStateConnectorData::SlotCondition(self.clone())
+    }
 }
 
 /// [`EffectStateConnector`][`crate::effect::fxlang::EffectStateConnector`] implementation for the
 /// terrain on the field.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TerrainEffectStateConnector {}
 
 impl TerrainEffectStateConnector {
@@ -404,11 +451,15 @@ impl fxlang::EffectStateConnector for TerrainEffectStateConnector {
     fn make_dynamic(&self) -> fxlang::DynamicEffectStateConnector {
         fxlang::DynamicEffectStateConnector::new(self.clone())
     }
+
+    fn data(&self) -> EffectStateConnectorData {
+        EffectStateConnectorData::Terrain(self.clone())
+    }
 }
 
 /// [`EffectStateConnector`][`crate::effect::fxlang::EffectStateConnector`] implementation for
 /// weather on the field.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WeatherEffectStateConnector {}
 
 impl WeatherEffectStateConnector {
@@ -433,11 +484,15 @@ impl fxlang::EffectStateConnector for WeatherEffectStateConnector {
     fn make_dynamic(&self) -> fxlang::DynamicEffectStateConnector {
         fxlang::DynamicEffectStateConnector::new(self.clone())
     }
+
+    fn data(&self) -> EffectStateConnectorData {
+        EffectStateConnectorData::Weather(self.clone())
+    }
 }
 
 /// [`EffectStateConnector`][`crate::effect::fxlang::EffectStateConnector`] implementation for a
 /// pseudo-weather on the field.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PseudoWeatherEffectStateConnector {
     pseudo_weather: Id,
 }
@@ -472,4 +527,50 @@ impl fxlang::EffectStateConnector for PseudoWeatherEffectStateConnector {
     fn make_dynamic(&self) -> fxlang::DynamicEffectStateConnector {
         fxlang::DynamicEffectStateConnector::new(self.clone())
     }
+
+    fn data(&self) -> EffectStateConnectorData {
+        EffectStateConnectorData::PseudoWeather(self.clone())
+    }
+}
+
+/// The serializable form of every
+/// [`EffectStateConnector`][`crate::effect::fxlang::EffectStateConnector`] implementation.
+///
+/// Allows effect state connections stored in fxlang values to be persisted.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum EffectStateConnectorData {
+    ActiveMove(ActiveMoveEffectStateConnector),
+    Mon(MonEffectStateConnector),
+    MonVolatile(MonVolatileEffectStateConnector),
+    MonAbility(MonAbilityEffectStateConnector),
+    MonItem(MonItemEffectStateConnector),
+    MonStatus(MonStatusEffectStateConnector),
+    MonTerastallization(MonTerastallizationEffectStateConnector),
+    MonVolatileStatus(MonVolatileStatusEffectStateConnector),
+    SideCondition(SideConditionEffectStateConnector),
+    SlotCondition(SlotConditionEffectStateConnector),
+    Terrain(TerrainEffectStateConnector),
+    Weather(WeatherEffectStateConnector),
+    PseudoWeather(PseudoWeatherEffectStateConnector),
+}
+
+impl EffectStateConnectorData {
+    /// Creates the dynamic connector for the data.
+    pub fn make_dynamic(&self) -> fxlang::DynamicEffectStateConnector {
+        match self {
+            Self::ActiveMove(connector) => connector.make_dynamic(),
+            Self::Mon(connector) => connector.make_dynamic(),
+            Self::MonVolatile(connector) => connector.make_dynamic(),
+            Self::MonAbility(connector) => connector.make_dynamic(),
+            Self::MonItem(connector) => connector.make_dynamic(),
+            Self::MonStatus(connector) => connector.make_dynamic(),
+            Self::MonTerastallization(connector) => connector.make_dynamic(),
+            Self::MonVolatileStatus(connector) => connector.make_dynamic(),
+            Self::SideCondition(connector) => connector.make_dynamic(),
+            Self::SlotCondition(connector) => connector.make_dynamic(),
+            Self::Terrain(connector) => connector.make_dynamic(),
+            Self::Weather(connector) => connector.make_dynamic(),
+            Self::PseudoWeather(connector) => connector.make_dynamic(),
+        }
+    }
 }
@@ -0,0 +1,212 @@
+use alloc::{
+    borrow::ToOwned,
+    string::String,
+    vec::Vec,
+};
+
+use battler_data::Fraction;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+use crate::effect::fxlang::{
+    Function,
+    GLOBAL_CONTEXT_VARS,
+    Member,
+    Value,
+    tree,
+};
+
+/// A constant value embedded in a [`CompiledProgram`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Constant {
+    Undefined,
+    Boolean(bool),
+    Fraction(Fraction<i64>),
+    UFraction(Fraction<u64>),
+    String(String),
+}
+
+impl From<&Constant> for Value {
+    fn from(value: &Constant) -> Self {
+        match value {
+            Constant::Undefined => Self::Undefined,
+            Constant::Boolean(val) => Self::Boolean(*val),
+            Constant::Fraction(val) => Self::Fraction(*val),
+            Constant::UFraction(val) => Self::UFraction(*val),
+            Constant::String(val) => Self::String(val.clone()),
+        }
+    }
+}
+
+/// An access of a variable, resolved to its slot and members.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VariableAccess {
+    pub slot: usize,
+    pub member_access: Vec<Member>,
+}
+
+/// A segment of a formatted string template.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FormatSegment {
+    /// Literal text.
+    Text(String),
+    /// The next positional argument.
+    Argument,
+    /// An invalid format group, which fails evaluation when reached.
+    InvalidGroup(String),
+}
+
+/// A single bytecode instruction.
+///
+/// Instructions operate on a stack of values. Jump targets are absolute instruction indices.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Instruction {
+    /// Begins the statement at the given position in the program.
+    Statement(usize),
+    /// Skips statements that are not evaluated, for error reporting.
+    SkipStatements(usize),
+    /// Pushes a constant.
+    Constant(usize),
+    /// Pushes the value of a variable.
+    Load(usize),
+    /// Pops a value and assigns it to a variable.
+    Store(usize),
+    /// Pops values into a new list.
+    MakeList(usize),
+    /// Pops values into a formatted string.
+    Format { template: usize, args: usize },
+    /// Pops arguments and calls a function, pushing its result.
+    Call { function: usize, args: usize },
+    /// Discards the top value.
+    Pop,
+    /// Applies a prefix operator to the top value.
+    Unary(tree::Operator),
+    /// Pops two values and applies a binary operator.
+    Binary(tree::Operator),
+    /// Converts the top value to a boolean and jumps if it determines the result of an `and` or
+    /// `or` expression.
+    ShortCircuit { op: tree::Operator, target: usize },
+    /// Jumps unconditionally.
+    Jump(usize),
+    /// Pops an if statement condition, records it, and jumps if it is false.
+    TestCondition { condition: usize, target: usize },
+    /// Skips statements and jumps if a recorded condition is true.
+    SkipIfConditionMet {
+        condition: usize,
+        skip: usize,
+        target: usize,
+    },
+    /// Pops a list and begins iterating over it.
+    StartLoop,
+    /// Assigns the next list element to a variable, or ends the loop and jumps.
+    NextIteration { slot: usize, target: usize },
+    /// Ends the loop and jumps.
+    Break(usize),
+    /// Returns no value.
+    Return,
+    /// Pops a value and returns it.
+    ReturnValue,
+    /// Pops a condition and returns it if it is false.
+    Require,
+    /// Pops a condition and returns no value if it is false.
+    RequireOrReturn,
+    /// Pops a condition and jumps if it is true.
+    ///
+    /// Otherwise, evaluation continues to the value that should be returned.
+    RequireOrElse(usize),
+    /// Fails evaluation.
+    Fail(usize),
+}
+
+/// A block of statements in a [`CompiledProgram`] that only runs conditionally.
+///
+/// Recorded for coverage reporting.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Branch {
+    /// The statement that decides if the block runs, if any.
+    ///
+    /// Loops do not have a numbered header statement.
+    pub header: Option<usize>,
+    /// The first statement of the block.
+    pub first: usize,
+    /// The last statement of the block.
+    pub last: usize,
+}
+
+/// An fxlang program compiled to bytecode.
+///
+/// Programs are compiled once and can be shared by any number of evaluations. Variables are
+/// resolved to slots at compile time, so they do not need to be looked up by name during
+/// evaluation.
+///
+/// Compiled programs are serializable, so they can be stored and loaded without parsing.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompiledProgram {
+    pub(in crate::effect::fxlang) instructions: Vec<Instruction>,
+    pub(in crate::effect::fxlang) constants: Vec<Constant>,
+    pub(in crate::effect::fxlang) slots: Vec<String>,
+    pub(in crate::effect::fxlang) variables: Vec<VariableAccess>,
+    pub(in crate::effect::fxlang) functions: Vec<Function>,
+    pub(in crate::effect::fxlang) templates: Vec<Vec<FormatSegment>>,
+    pub(in crate::effect::fxlang) messages: Vec<String>,
+    pub(in crate::effect::fxlang) conditions: usize,
+    #[serde(default)]
+    pub(in crate::effect::fxlang) statements: usize,
+    #[serde(default)]
+    pub(in crate::effect::fxlang) branches: Vec<Branch>,
+}
+
+impl Default for CompiledProgram {
+    fn default() -> Self {
+        Self {
+            instructions: Vec::default(),
+            constants: Vec::default(),
+            slots: GLOBAL_CONTEXT_VARS
+                .iter()
+                .map(|var| var.name.to_owned())
+                .collect(),
+            variables: Vec::default(),
+            functions: Vec::default(),
+            templates: Vec::default(),
+            messages: Vec::default(),
+            conditions: 0,
+            statements: 0,
+            branches: Vec::default(),
+        }
+    }
+}
+
+impl CompiledProgram {
+    /// The version of the serialized form of compiled programs.
+    ///
+    /// Must be incremented whenever the serialized form changes, so that programs compiled by an
+    /// older version are not loaded.
+    pub const FORMAT_VERSION: u32 = 1;
+
+    // Slots of the first variables in `GLOBAL_CONTEXT_VARS`, which are assigned to the first slots.
+    pub(in crate::effect::fxlang) const EFFECT_STATE_SLOT: usize = 0;
+    pub(in crate::effect::fxlang) const EFFECT_TARGET_SLOT: usize = 1;
+    pub(in crate::effect::fxlang) const EVENT_ORIGIN_SLOT: usize = 2;
+
+    /// Checks if the program is completely empty.
+    pub fn is_empty(&self) -> bool {
+        self.instructions.is_empty()
+    }
+
+    /// The number of statements in the program.
+    pub fn statements(&self) -> usize {
+        self.statements
+    }
+
+    /// The conditional blocks of statements in the program.
+    pub fn branches(&self) -> &[Branch] {
+        &self.branches
+    }
+
+    /// The slot of the variable with the given name, if it is used by the program.
+    pub fn slot(&self, name: &str) -> Option<usize> {
+        self.slots.iter().position(|slot| slot == name)
+    }
+}
@@ -0,0 +1,1412 @@
+use alloc::{
+    borrow::ToOwned,
+    format,
+    string::String,
+    vec::Vec,
+};
+use core::{
+    fmt,
+    fmt::Display,
+};
+
+use battler_data::{
+    Id,
+    SubroutineData,
+};
+use hashbrown::{
+    HashMap,
+    HashSet,
+};
+use itertools::Itertools;
+
+use crate::effect::fxlang::{
+    BattleEvent,
+    Callback,
+    Effect,
+    Function,
+    FunctionSignature,
+    GLOBAL_CONTEXT_VARS,
+    Member,
+    NativeFunctionRegistry,
+    ParsedEffect,
+    ParsedProgram,
+    ParsedProgramBlock,
+    ParsedSubroutine,
+    ValueType,
+    tree,
+};
+
+/// An error found by statically checking an fxlang [`Effect`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckError {
+    /// The ID of the effect.
+    pub effect: String,
+    /// The name of the callback the error was found in, if any.
+    pub callback: Option<String>,
+    /// The index of the statement the error was found on, if any.
+    ///
+    /// Statements are counted from 0 in program order, skipping empty statements and comments.
+    pub statement: Option<usize>,
+    /// The error message.
+    pub message: String,
+}
+
+impl Display for CheckError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.callback {
+            Some(callback) => write!(f, "{}: {callback}: {}", self.effect, self.message),
+            None => write!(f, "{}: {}", self.effect, self.message),
+        }
+    }
+}
+
+/// Functions, defined outside of the battle engine, that checked programs may call.
+#[derive(Debug, Default, Clone)]
+pub struct ExternalFunctions {
+    native_functions: HashMap<String, FunctionSignature>,
+    subroutines: HashMap<Id, FunctionSignature>,
+}
+
+impl ExternalFunctions {
+    /// Adds all functions in the registry.
+    pub fn add_native_functions(&mut self, native_functions: &NativeFunctionRegistry) {
+        for name in native_functions.names() {
+            if let Some(signature) = native_functions.signature(name) {
+                self.native_functions.insert(name.to_owned(), signature);
+            }
+        }
+    }
+
+    /// Adds a subroutine.
+    pub fn add_subroutine(&mut self, id: Id, subroutine: &SubroutineData) {
+        self.subroutines.insert(
+            id,
+            FunctionSignature {
+                required_args: subroutine.parameters.len(),
+                returns_value: true,
+            },
+        );
+    }
+
+    fn signature(&self, name: &str) -> Option<FunctionSignature> {
+        // Resolve the function the same way the compiler does. Named functions are native functions
+        // or subroutines, which are looked up in that order by `run_function`.
+        match Function::new(name) {
+            Function::Builtin(function) => Some(function.signature()),
+            Function::Named(name) => self
+                .native_functions
+                .get(&name)
+                .or_else(|| self.subroutines.get(&Id::from(name)))
+                .cloned(),
+        }
+    }
+}
+
+/// Statically checks an fxlang effect, in its serialized form, without evaluating it.
+///
+/// Effects that fail to deserialize are silently ignored when loaded for a battle, so this check
+/// reports them as an error.
+pub fn check_effect_data(
+    effect_id: &str,
+    effect: &serde_json::Value,
+    functions: &ExternalFunctions,
+) -> Vec<CheckError> {
+    if effect.is_null() {
+        return Vec::new();
+    }
+    match Effect::try_from(effect.clone()) {
+        Ok(effect) => check_effect(effect_id, &effect, functions),
+        Err(err) => Vec::from_iter([CheckError {
+            effect: effect_id.to_owned(),
+            callback: None,
+            statement: None,
+            message: format!("{err:#}"),
+        }]),
+    }
+}
+
+/// Statically checks an fxlang [`Effect`] without evaluating it.
+///
+/// Every callback is parsed and checked for:
+/// - An event that does not exist.
+/// - Calls to undefined functions, or with too few arguments. Functions in `functions` are defined
+///   alongside built-in functions.
+/// - Using the output of a function that never returns a value.
+/// - Variables that are not an input to the event and are never assigned.
+/// - Accessing or assigning a member that does not exist on an input variable of a known type.
+/// - Assigning a literal value or a typed member to a member of an incompatible type.
+/// - Returning a literal value that the event does not allow.
+///
+/// Argument types are not checked, since function signatures only describe the number of
+/// arguments.
+///
+/// Errors are returned in callback name order.
+pub fn check_effect(
+    effect_id: &str,
+    effect: &Effect,
+    functions: &ExternalFunctions,
+) -> Vec<CheckError> {
+    effect
+        .callbacks
+        .iter()
+        .sorted_by(|(a, _), (b, _)| a.cmp(b))
+        .flat_map(|(name, callback)| {
+            check_callback(name, callback, functions)
+                .into_iter()
+                .map(|(statement, message)| CheckError {
+                    effect: effect_id.to_owned(),
+                    callback: Some(name.clone()),
+                    statement,
+                    message,
+                })
+        })
+        .collect()
+}
+
+/// Statically checks an fxlang subroutine without evaluating it.
+///
+/// Subroutines are checked like callbacks, except that their only input variables are their
+/// parameters, and they may return any value.
+pub fn check_subroutine_data(
+    subroutine_id: &str,
+    subroutine: &SubroutineData,
+    functions: &ExternalFunctions,
+) -> Vec<CheckError> {
+    let errors = match ParsedSubroutine::program(subroutine)
+        .and_then(|program| ParsedProgram::from(&program))
+    {
+        Ok(program) => {
+            let mut checker = ProgramChecker::new(None, &subroutine.parameters, functions);
+            checker.collect_assigned_vars(&program.block);
+            checker.check_block(&program.block);
+            checker.errors
+        }
+        Err(err) => Vec::from_iter([(None, format!("{err:#}"))]),
+    };
+    errors
+        .into_iter()
+        .map(|(statement, message)| CheckError {
+            effect: subroutine_id.to_owned(),
+            callback: None,
+            statement,
+            message,
+        })
+        .collect()
+}
+
+fn check_callback(
+    name: &str,
+    callback: &Callback,
+    functions: &ExternalFunctions,
+) -> Vec<(Option<usize>, String)> {
+    let (event, _) = match ParsedEffect::callback_name_to_event_key(name) {
+        Ok(key) => key,
+        Err(_) => return Vec::from_iter([(None, format!("invalid callback {name}"))]),
+    };
+    let program = match callback.program() {
+        Some(program) => program,
+        None => return Vec::new(),
+    };
+    let program = match ParsedProgram::from(program) {
+        Ok(program) => program,
+        Err(err) => {
+            return Vec::from_iter([(None, format!("error parsing {event} callback: {err:#}"))]);
+        }
+    };
+
+    let mut checker = ProgramChecker::new(
+        Some(event),
+        callback
+            .metadata()
+            .map(|metadata| metadata.parameters.as_slice())
+            .unwrap_or_default(),
+        functions,
+    );
+    checker.collect_assigned_vars(&program.block);
+    checker.check_block(&program.block);
+    checker.errors
+}
+
+/// A variable that is defined when an fxlang program starts, before any assignments.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputVar {
+    pub name: String,
+    /// The type of the variable, if it is known without evaluating the program.
+    pub value_type: Option<ValueType>,
+}
+
+/// The variables that are defined when an fxlang program starts.
+///
+/// `event` is [`None`] for subroutines. Custom `parameters` are only defined for subroutines and
+/// events that allow custom input variables.
+pub fn input_vars(event: Option<BattleEvent>, parameters: &[String]) -> Vec<InputVar> {
+    let mut vars = Vec::<InputVar>::new();
+    let mut add = |name: &str, value_type: Option<ValueType>| {
+        if !vars.iter().any(|var| var.name == name) {
+            vars.push(InputVar {
+                name: name.to_owned(),
+                value_type,
+            });
+        }
+    };
+
+    for var in &GLOBAL_CONTEXT_VARS {
+        add(var.name, Some(var.value_type));
+    }
+
+    let event = match event {
+        Some(event) => event,
+        None => {
+            for name in parameters {
+                add(name, None);
+            }
+            return vars;
+        }
+    };
+
+    for var in event.context_vars() {
+        add(var.name, Some(var.value_type));
+    }
+
+    if event.allows_custom_input_vars() {
+        for name in parameters {
+            add(name, None);
+        }
+    } else {
+        for (name, value_type, _) in event.input_vars() {
+            add(name, Some(*value_type));
+        }
+    }
+    vars
+}
+
+struct ProgramChecker<'f> {
+    event: Option<BattleEvent>,
+    functions: &'f ExternalFunctions,
+    statement: usize,
+    vars: HashSet<String>,
+    /// Types of input variables that are never reassigned.
+    var_types: HashMap<String, ValueType>,
+    errors: Vec<(Option<usize>, String)>,
+}
+
+impl<'f> ProgramChecker<'f> {
+    fn new(
+        event: Option<BattleEvent>,
+        parameters: &[String],
+        functions: &'f ExternalFunctions,
+    ) -> Self {
+        let input_vars = input_vars(event, parameters);
+        Self {
+            event,
+            functions,
+            statement: 0,
+            vars: input_vars.iter().map(|var| var.name.clone()).collect(),
+            var_types: input_vars
+                .into_iter()
+                .filter_map(|var| Some((var.name, var.value_type?)))
+                .collect(),
+            errors: Vec::new(),
+        }
+    }
+
+    fn collect_assigned_vars(&mut self, block: &ParsedProgramBlock) {
+        match block {
+            ParsedProgramBlock::Leaf(statement) => {
+                match statement {
+                    tree::Statement::Assignment(assignment) => {
+                        self.collect_assigned_var(assignment)
+                    }
+                    tree::Statement::ForEachStatement(statement) => {
+                        self.vars.insert(statement.var.name.0.clone());
+                        self.var_types.remove(&statement.var.name.0);
+                    }
+                    _ => (),
+                }
+
+                // Assignments can also be nested in values.
+                let mut assignments = Vec::new();
+                StatementVisitor {
+                    f: &mut |value: &tree::Value| {
+                        if let tree::Value::ValueAssignment(assignment) = value {
+                            assignments.push(assignment.0.as_ref().clone());
+                        }
+                    },
+                }
+                .visit_statement(statement);
+                for assignment in &assignments {
+                    self.collect_assigned_var(assignment);
+                }
+            }
+            ParsedProgramBlock::Branch(blocks) => {
+                for block in blocks {
+                    self.collect_assigned_vars(block);
+                }
+            }
+        }
+    }
+
+    fn collect_assigned_var(&mut self, assignment: &tree::Assignment) {
+        if assignment.lhs.member_access.is_empty() {
+            self.vars.insert(assignment.lhs.name.0.clone());
+            self.var_types.remove(&assignment.lhs.name.0);
+        }
+    }
+
+    fn error(&mut self, message: String) {
+        self.errors.push((
+            Some(self.statement),
+            format!("error on statement {}: {message}", self.statement),
+        ));
+    }
+
+    fn check_block(&mut self, block: &ParsedProgramBlock) {
+        match block {
+            ParsedProgramBlock::Leaf(statement) => {
+                self.check_statement(statement);
+                self.statement += 1;
+            }
+            ParsedProgramBlock::Branch(blocks) => {
+                for block in blocks {
+                    self.check_block(block);
+                }
+            }
+        }
+    }
+
+    fn check_statement(&mut self, statement: &tree::Statement) {
+        match statement {
+            tree::Statement::ForEachStatement(statement)
+                if !statement.var.member_access.is_empty() =>
+            {
+                self.error(format!(
+                    "invalid variable in foreach statement: ${}",
+                    statement.var.full_name(),
+                ));
+            }
+            tree::Statement::Assignment(assignment) if !assignment.lhs.member_access.is_empty() => {
+                self.check_member_assignment(assignment);
+            }
+            tree::Statement::ReturnStatement(statement) => {
+                self.check_return(statement.0.as_ref());
+            }
+            tree::Statement::RequireStatement(tree::RequireStatement {
+                else_return: Some(value),
+                ..
+            }) => {
+                self.check_return(value.as_ref());
+            }
+            _ => (),
+        }
+
+        let mut values = Vec::new();
+        StatementVisitor {
+            f: &mut |value: &tree::Value| values.push(value.clone()),
+        }
+        .visit_statement(statement);
+        if let tree::Statement::FunctionCall(function_call) = statement {
+            self.check_function_call(function_call, false);
+        }
+        for value in &values {
+            self.check_value(value);
+        }
+    }
+
+    fn check_value(&mut self, value: &tree::Value) {
+        match value {
+            tree::Value::Var(var) => self.check_var(var),
+            tree::Value::ValueAssignment(assignment)
+                if !assignment.0.lhs.member_access.is_empty() =>
+            {
+                self.check_member_assignment(&assignment.0)
+            }
+            tree::Value::ValueFunctionCall(function_call) => {
+                self.check_function_call(&function_call.0, true)
+            }
+            _ => (),
+        }
+    }
+
+    fn check_var(&mut self, var: &tree::Var) {
+        if !self.vars.contains(&var.name.0) {
+            self.error(format!("undefined variable ${}", var.name.0));
+            return;
+        }
+        self.resolve_member_access(var, false);
+    }
+
+    fn check_member_assignment(&mut self, assignment: &tree::Assignment) {
+        if !self.vars.contains(&assignment.lhs.name.0) {
+            self.error(format!("undefined variable ${}", assignment.lhs.name.0));
+            return;
+        }
+        let member_type = match self.resolve_member_access(&assignment.lhs, true) {
+            Some(member_type) => member_type,
+            None => return,
+        };
+        let value_type = match &assignment.rhs {
+            tree::Expr::Value(tree::Value::Var(var)) if self.vars.contains(&var.name.0) => {
+                self.resolve_member_access(var, false)
+            }
+            tree::Expr::Value(value) => Self::literal_value_type(value),
+            _ => None,
+        };
+        if let Some(value_type) = value_type
+            && !assignable(member_type, value_type)
+        {
+            self.error(format!(
+                "invalid assignment to ${}: cannot convert from {value_type} to {member_type}",
+                assignment.lhs.full_name(),
+            ));
+        }
+    }
+
+    /// Resolves the type of a variable, following its member access.
+    ///
+    /// Members are only resolved on input variables of a known type. Returns [`None`] if the type
+    /// is unknown or the member access is invalid, which is reported as an error.
+    fn resolve_member_access(&mut self, var: &tree::Var, mutable: bool) -> Option<ValueType> {
+        let mut value_type = *self.var_types.get(&var.name.0)?;
+        for member in &var.member_access {
+            let member = Member::new(&member.0);
+            let member_type = if mutable {
+                write_member_type(value_type, &member)
+            } else {
+                read_member_type(value_type, &member)
+            };
+            value_type = match member_type {
+                MemberType::Typed(member_type) => member_type,
+                MemberType::Untyped => return None,
+                MemberType::Missing if mutable => {
+                    self.error(format!(
+                        "invalid member access ${}: value of type {value_type} has no member {} or the member is immutable",
+                        var.full_name(),
+                        member.name(),
+                    ));
+                    return None;
+                }
+                MemberType::Missing => {
+                    self.error(format!(
+                        "invalid member access ${}: value of type {value_type} has no member {}",
+                        var.full_name(),
+                        member.name(),
+                    ));
+                    return None;
+                }
+            };
+        }
+        Some(value_type)
+    }
+
+    fn check_function_call(&mut self, function_call: &tree::FunctionCall, uses_output: bool) {
+        let name = &function_call.function.0;
+        let signature = match self.functions.signature(name) {
+            Some(signature) => signature,
+            None => {
+                self.error(format!("undefined function: {name}"));
+                return;
+            }
+        };
+        let args = function_call.args.0.len();
+        if args < signature.required_args {
+            self.error(format!(
+                "{name} requires {} arguments, found {args}",
+                signature.required_args,
+            ));
+        }
+        if uses_output && !signature.returns_value {
+            self.error(format!("{name} does not return a value"));
+        }
+    }
+
+    fn check_return(&mut self, expr: Option<&tree::Expr>) {
+        // Subroutines may return any value.
+        let event = match self.event {
+            Some(event) => event,
+            None => return,
+        };
+        let value_type = match expr {
+            None => None,
+            Some(tree::Expr::Value(value)) => match Self::literal_value_type(value) {
+                Some(value_type) => Some(value_type),
+                // The type of non-literal values is only known at runtime.
+                None => return,
+            },
+            Some(_) => return,
+        };
+        if !event.output_type_allowed(value_type) {
+            match value_type {
+                Some(value_type) => self.error(format!("{event:?} cannot return a {value_type}")),
+                None => self.error(format!("{event:?} must return a value")),
+            }
+        }
+    }
+
+    fn literal_value_type(value: &tree::Value) -> Option<ValueType> {
+        match value {
+            tree::Value::UndefinedLiteral => Some(ValueType::Undefined),
+            tree::Value::BoolLiteral(_) => Some(ValueType::Boolean),
+            tree::Value::NumberLiteral(tree::NumberLiteral::Unsigned(_)) => {
+                Some(ValueType::UFraction)
+            }
+            tree::Value::NumberLiteral(tree::NumberLiteral::Signed(_)) => Some(ValueType::Fraction),
+            tree::Value::StringLiteral(_) | tree::Value::FormattedString(_) => {
+                Some(ValueType::String)
+            }
+            tree::Value::List(_) => Some(ValueType::List),
+            _ => None,
+        }
+    }
+}
+
+/// The type of a member accessed on a value.
+enum MemberType {
+    /// The member is a value of the given type, or undefined.
+    Typed(ValueType),
+    /// The member may be a value of any type.
+    Untyped,
+    /// The value has no such member.
+    Missing,
+}
+
+/// Resolves the type of a member read from a value.
+///
+/// Mirrors `Variable::get_ref`, which resolves members at runtime.
+fn read_member_type(value_type: ValueType, member: &Member) -> MemberType {
+    match member {
+        Member::IsBoolean
+        | Member::IsDefined
+        | Member::IsEmpty
+        | Member::IsTrue
+        | Member::IsUndefined => return MemberType::Typed(ValueType::Boolean),
+        Member::Length => return MemberType::Typed(ValueType::UFraction),
+        Member::ToString => return MemberType::Typed(ValueType::String),
+        _ => (),
+    }
+    match value_type {
+        // An effect may be an active move, which has additional members.
+        ValueType::Effect | ValueType::ActiveMove => match effect_member_type(member) {
+            MemberType::Missing => active_move_member_type(member),
+            member_type => member_type,
+        },
+        ValueType::Mon => match member {
+            Member::Active
+            | Member::AteItem
+            | Member::BeingCalledBack
+            | Member::CanHeal
+            | Member::CanSuppressAbility
+            | Member::CanSuppressItem
+            | Member::DamagedThisTurn
+            | Member::Dynamaxed
+            | Member::Exited
+            | Member::Fainted
+            | Member::ForceSwitch
+            | Member::IsAsleep
+            | Member::IsAwayFromField
+            | Member::IsBehindSubstitute
+            | Member::IsChoiceLocked
+            | Member::IsGrounded
+            | Member::IsImmuneToEntryHazards
+            | Member::IsSemiInvulnerable
+            | Member::IsSoundproof
+            | Member::ItemUsedThisTurn
+            | Member::MoveLastTurnFailed
+            | Member::MoveLastTurnSucceeded
+            | Member::MoveThisTurnFailed
+            | Member::MovedThisTurn
+            | Member::NeedsSwitch
+            | Member::NewlySwitched
+            | Member::NotFullyEvolved
+            | Member::StatsLoweredThisTurn
+            | Member::StatsRaisedThisTurn
+            | Member::Transformed
+            | Member::Trapped => MemberType::Typed(ValueType::Boolean),
+            Member::Boosts => MemberType::Typed(ValueType::BoostTable),
+            Member::ActiveMove
+            | Member::EffectiveAbility
+            | Member::EffectiveItem
+            | Member::EffectiveTerrain
+            | Member::LastMove
+            | Member::LastMoveUsed
+            | Member::NonExternalActiveMove => MemberType::Typed(ValueType::Effect),
+            Member::EffectState | Member::VolatileEffectState => {
+                MemberType::Typed(ValueType::EffectState)
+            }
+            Member::LastTargetLocation => MemberType::Typed(ValueType::Fraction),
+            Member::Gender => MemberType::Typed(ValueType::Gender),
+            Member::BaseMoveSlots
+            | Member::EffectiveTypes
+            | Member::EffectiveTypesNoAddedType
+            | Member::MoveSlots
+            | Member::Types => MemberType::Typed(ValueType::List),
+            Member::Nature | Member::TrueNature => MemberType::Typed(ValueType::Nature),
+            Member::Player => MemberType::Typed(ValueType::Player),
+            Member::FoeSide | Member::Side => MemberType::Typed(ValueType::Side),
+            Member::BaseStats | Member::Stats => MemberType::Typed(ValueType::StatTable),
+            Member::Ability
+            | Member::BaseSpecies
+            | Member::Illusion
+            | Member::Item
+            | Member::LastItem
+            | Member::Name
+            | Member::OriginalBaseAbility
+            | Member::OriginalBaseSpecies
+            | Member::OriginalItem
+            | Member::PositionDetails
+            | Member::Species
+            | Member::Status => MemberType::Typed(ValueType::String),
+            Member::AddedType | Member::HiddenPowerType | Member::Terastallized => {
+                MemberType::Typed(ValueType::Type)
+            }
+            Member::ActiveMoveActions
+            | Member::ActivePosition
+            | Member::ActiveTurns
+            | Member::AffectionLevel
+            | Member::BaseMaxHp
+            | Member::BerryEatingHealth
+            | Member::Friendship
+            | Member::Hp
+            | Member::Level
+            | Member::MaxHp
+            | Member::Position
+            | Member::PositiveBoosts
+            | Member::TimesAttacked
+            | Member::UndynamaxedHp
+            | Member::Weight => MemberType::Typed(ValueType::UFraction),
+            _ => MemberType::Missing,
+        },
+        ValueType::Player => match member {
+            Member::CanEscape | Member::MonFaintedLastTurn => MemberType::Typed(ValueType::Boolean),
+            Member::Team | Member::TeamByEffectivePosition => MemberType::Typed(ValueType::List),
+            Member::TotalActivePositions => MemberType::Typed(ValueType::UFraction),
+            Member::WildEncounterType => MemberType::Typed(ValueType::WildEncounterType),
+            _ => MemberType::Missing,
+        },
+        ValueType::MoveSlot => match member {
+            Member::Used => MemberType::Typed(ValueType::Boolean),
+            Member::Id | Member::Name => MemberType::Typed(ValueType::String),
+            Member::MaxPp | Member::Pp => MemberType::Typed(ValueType::UFraction),
+            _ => MemberType::Missing,
+        },
+        ValueType::Battle => match member {
+            Member::Ending | Member::InResidual => MemberType::Typed(ValueType::Boolean),
+            Member::LastMove | Member::LastSuccessfulMove => MemberType::Typed(ValueType::Effect),
+            Member::Turn => MemberType::Typed(ValueType::UFraction),
+            _ => MemberType::Missing,
+        },
+        ValueType::Field => match member {
+            Member::EffectiveTerrain
+            | Member::EffectiveWeather
+            | Member::Terrain
+            | Member::Weather => MemberType::Typed(ValueType::Effect),
+            Member::Environment => MemberType::Typed(ValueType::FieldEnvironment),
+            Member::Sides => MemberType::Typed(ValueType::List),
+            Member::Time => MemberType::Typed(ValueType::TimeOfDay),
+            _ => MemberType::Missing,
+        },
+        ValueType::Format => match member {
+            Member::Mechanics | Member::MonsPerSide | Member::ObedienceCap => {
+                MemberType::Typed(ValueType::UFraction)
+            }
+            _ => MemberType::Missing,
+        },
+        ValueType::Side => match member {
+            Member::Players => MemberType::Typed(ValueType::List),
+            Member::FoeSide => MemberType::Typed(ValueType::Side),
+            Member::Index | Member::MonsLeft | Member::TotalFainted => {
+                MemberType::Typed(ValueType::UFraction)
+            }
+            _ => MemberType::Missing,
+        },
+        ValueType::HitEffect => match member {
+            Member::Boosts => MemberType::Typed(ValueType::BoostTable),
+            Member::VolatileStatus => MemberType::Typed(ValueType::String),
+            _ => MemberType::Missing,
+        },
+        ValueType::SecondaryHitEffect => match member {
+            Member::Target | Member::User => MemberType::Typed(ValueType::HitEffect),
+            Member::Chance => MemberType::Typed(ValueType::UFraction),
+            _ => MemberType::Missing,
+        },
+        ValueType::BoostTable => match member {
+            Member::Acc
+            | Member::Atk
+            | Member::Def
+            | Member::Eva
+            | Member::Spa
+            | Member::Spd
+            | Member::Spe => MemberType::Typed(ValueType::Fraction),
+            _ => MemberType::Missing,
+        },
+        ValueType::StatTable => match member {
+            Member::Atk | Member::Def | Member::Hp | Member::Spa | Member::Spd | Member::Spe => {
+                MemberType::Typed(ValueType::Fraction)
+            }
+            _ => MemberType::Missing,
+        },
+        ValueType::Nature => match member {
+            Member::Boosts | Member::Drops => MemberType::Typed(ValueType::Boost),
+            _ => MemberType::Missing,
+        },
+        ValueType::MoveTarget => match member {
+            Member::AffectsMonsDirectly => MemberType::Typed(ValueType::Boolean),
+            _ => MemberType::Missing,
+        },
+        ValueType::SpecialItemData => match member {
+            Member::Fling => MemberType::Typed(ValueType::FlingData),
+            Member::Judgment => MemberType::Typed(ValueType::JudgmentData),
+            Member::MultiAttack => MemberType::Typed(ValueType::MultiAttackData),
+            Member::NaturalGift => MemberType::Typed(ValueType::NaturalGiftData),
+            Member::TechnoBlast => MemberType::Typed(ValueType::TechnoBlastData),
+            _ => MemberType::Missing,
+        },
+        ValueType::FlingData => match member {
+            Member::UseItem => MemberType::Typed(ValueType::Boolean),
+            Member::HitEffect => MemberType::Typed(ValueType::HitEffect),
+            Member::Power => MemberType::Typed(ValueType::UFraction),
+            _ => MemberType::Missing,
+        },
+        ValueType::NaturalGiftData => match member {
+            Member::Type => MemberType::Typed(ValueType::Type),
+            Member::Power => MemberType::Typed(ValueType::UFraction),
+            _ => MemberType::Missing,
+        },
+        ValueType::MultihitType => match member {
+            Member::Max | Member::Min => MemberType::Typed(ValueType::UFraction),
+            _ => MemberType::Missing,
+        },
+        ValueType::JudgmentData => match member {
+            Member::Type => MemberType::Typed(ValueType::Type),
+            _ => MemberType::Missing,
+        },
+        ValueType::MultiAttackData => match member {
+            Member::Type => MemberType::Typed(ValueType::Type),
+            _ => MemberType::Missing,
+        },
+        ValueType::TechnoBlastData => match member {
+            Member::Type => MemberType::Typed(ValueType::Type),
+            _ => MemberType::Missing,
+        },
+        ValueType::EffectState | ValueType::Object => MemberType::Untyped,
+        _ => MemberType::Missing,
+    }
+}
+
+fn effect_member_type(member: &Member) -> MemberType {
+    match member {
+        Member::Accuracy => MemberType::Typed(ValueType::Accuracy),
+        Member::Callable
+        | Member::IsAbility
+        | Member::IsItem
+        | Member::IsMove
+        | Member::IsMoveSecondary
+        | Member::IsRaining
+        | Member::IsSnowing
+        | Member::IsSunny
+        | Member::Multiaccuracy
+        | Member::Ohko => MemberType::Typed(ValueType::Boolean),
+        Member::ZPowerBoosts => MemberType::Typed(ValueType::BoostTable),
+        Member::Condition => MemberType::Typed(ValueType::Effect),
+        Member::Priority => MemberType::Typed(ValueType::Fraction),
+        Member::DamagedTargets | Member::OriginalTargets => MemberType::Typed(ValueType::List),
+        Member::Category => MemberType::Typed(ValueType::MoveCategory),
+        Member::Target => MemberType::Typed(ValueType::MoveTarget),
+        Member::Multihit => MemberType::Typed(ValueType::MultihitType),
+        Member::Id | Member::Name | Member::ZPowerEffect => MemberType::Typed(ValueType::String),
+        Member::Type => MemberType::Typed(ValueType::Type),
+        Member::BasePower
+        | Member::Damage
+        | Member::DrainPercent
+        | Member::MaxMoveBasePower
+        | Member::RecoilPercent
+        | Member::ZMoveBasePower => MemberType::Typed(ValueType::UFraction),
+        _ => MemberType::Missing,
+    }
+}
+
+fn active_move_member_type(member: &Member) -> MemberType {
+    match member {
+        Member::External
+        | Member::IgnoreAllSecondaryEffects
+        | Member::SpreadHit
+        | Member::Upgraded
+        | Member::UpgradedZMove => MemberType::Typed(ValueType::Boolean),
+        Member::EffectState => MemberType::Typed(ValueType::EffectState),
+        Member::HitEffect | Member::UserEffect => MemberType::Typed(ValueType::HitEffect),
+        Member::SecondaryEffects => MemberType::Typed(ValueType::List),
+        Member::Source | Member::User => MemberType::Typed(ValueType::Mon),
+        Member::OverrideDefensiveStat | Member::OverrideOffensiveStat => {
+            MemberType::Typed(ValueType::Stat)
+        }
+        Member::UpgradedBaseMove => MemberType::Typed(ValueType::String),
+        Member::Hit | Member::TotalDamage | Member::UserEffectChance => {
+            MemberType::Typed(ValueType::UFraction)
+        }
+        _ => MemberType::Missing,
+    }
+}
+
+/// Resolves the type of a member of a value that is assigned to.
+///
+/// Mirrors `VariableMut::get_ref_mut`, which resolves members at runtime.
+fn write_member_type(value_type: ValueType, member: &Member) -> MemberType {
+    match value_type {
+        ValueType::Mon => match member {
+            Member::SkipBeforeSwitchOut => MemberType::Typed(ValueType::Boolean),
+            Member::Boosts => MemberType::Typed(ValueType::BoostTable),
+            Member::EffectState | Member::StatusState | Member::VolatileEffectState => {
+                MemberType::Typed(ValueType::EffectState)
+            }
+            Member::LastTargetLocation => MemberType::Typed(ValueType::Fraction),
+            Member::Stats => MemberType::Typed(ValueType::StatTable),
+            Member::LastItem => MemberType::Typed(ValueType::String),
+            _ => MemberType::Missing,
+        },
+        // Only active moves can be modified.
+        ValueType::Effect | ValueType::ActiveMove => match member {
+            Member::Accuracy => MemberType::Typed(ValueType::Accuracy),
+            Member::ForceStab
+            | Member::IgnoreAllSecondaryEffects
+            | Member::IgnoreEvasion
+            | Member::Multiaccuracy
+            | Member::TracksTarget => MemberType::Typed(ValueType::Boolean),
+            Member::EffectState => MemberType::Typed(ValueType::EffectState),
+            Member::ForceTryHitResult => MemberType::Typed(ValueType::EventResult),
+            Member::Priority => MemberType::Typed(ValueType::Fraction),
+            Member::HitEffect | Member::UserEffect => MemberType::Typed(ValueType::HitEffect),
+            Member::SecondaryEffects => MemberType::Typed(ValueType::List),
+            Member::Category => MemberType::Typed(ValueType::MoveCategory),
+            Member::Target => MemberType::Typed(ValueType::MoveTarget),
+            Member::Multihit => MemberType::Typed(ValueType::MultihitType),
+            Member::OverrideDefensiveStat | Member::OverrideOffensiveStat => {
+                MemberType::Typed(ValueType::Stat)
+            }
+            Member::Type => MemberType::Typed(ValueType::Type),
+            Member::BasePower | Member::Damage | Member::TotalDamage | Member::UserEffectChance => {
+                MemberType::Typed(ValueType::UFraction)
+            }
+            _ => MemberType::Missing,
+        },
+        ValueType::HitEffect => match member {
+            Member::Boosts => MemberType::Typed(ValueType::BoostTable),
+            Member::SideCondition | Member::Status | Member::VolatileStatus => {
+                MemberType::Typed(ValueType::String)
+            }
+            Member::HealPercent => MemberType::Typed(ValueType::UFraction),
+            _ => MemberType::Missing,
+        },
+        ValueType::SecondaryHitEffect => match member {
+            Member::Target | Member::User => MemberType::Typed(ValueType::HitEffect),
+            Member::SourceEffect => MemberType::Typed(ValueType::String),
+            Member::Chance => MemberType::Typed(ValueType::UFraction),
+            _ => MemberType::Missing,
+        },
+        ValueType::BoostTable => match member {
+            Member::Acc
+            | Member::Atk
+            | Member::Def
+            | Member::Eva
+            | Member::Spa
+            | Member::Spd
+            | Member::Spe => MemberType::Typed(ValueType::Fraction),
+            _ => MemberType::Missing,
+        },
+        ValueType::StatTable => match member {
+            Member::Atk | Member::Def | Member::Hp | Member::Spa | Member::Spd | Member::Spe => {
+                MemberType::Typed(ValueType::UFraction)
+            }
+            _ => MemberType::Missing,
+        },
+        ValueType::EffectState | ValueType::Object => MemberType::Untyped,
+        _ => MemberType::Missing,
+    }
+}
+
+/// Checks if a value can be assigned to a member, following the conversions of
+/// `ValueRefMut::assign`.
+fn assignable(member_type: ValueType, value_type: ValueType) -> bool {
+    if member_type == value_type {
+        return true;
+    }
+    match (member_type, value_type) {
+        // Optional members can be cleared, and other members fail at runtime, depending on
+        // whether the member is optional.
+        (_, ValueType::Undefined) => true,
+        (ValueType::Boolean, ValueType::EventResult) => true,
+        (ValueType::Fraction | ValueType::UFraction, value_type) => value_type.is_number(),
+        (ValueType::Accuracy, value_type) => {
+            value_type.is_number() || value_type == ValueType::String
+        }
+        (ValueType::EventResult, ValueType::Boolean | ValueType::String) => true,
+        (
+            ValueType::MoveCategory | ValueType::MoveTarget | ValueType::Stat | ValueType::Type,
+            ValueType::String,
+        ) => true,
+        (ValueType::MultihitType, value_type) => {
+            value_type.is_number() || value_type == ValueType::List
+        }
+        (ValueType::EffectState | ValueType::Object, _) => true,
+        _ => false,
+    }
+}
+
+/// Visits every value in a statement, including values nested in other values.
+struct StatementVisitor<'f, F> {
+    f: &'f mut F,
+}
+
+impl<'f, F> StatementVisitor<'f, F>
+where
+    F: FnMut(&tree::Value),
+{
+    fn visit_statement(&mut self, statement: &tree::Statement) {
+        match statement {
+            tree::Statement::Empty
+            | tree::Statement::Continue(_)
+            | tree::Statement::Break(_)
+            | tree::Statement::ElseIfStatement(tree::ElseIfStatement(None)) => (),
+            tree::Statement::FunctionCall(function_call) => self.visit_values(&function_call.args),
+            tree::Statement::Assignment(assignment) => self.visit_expr(&assignment.rhs),
+            tree::Statement::IfStatement(statement) => self.visit_expr(&statement.0),
+            tree::Statement::ElseIfStatement(tree::ElseIfStatement(Some(statement))) => {
+                self.visit_expr(&statement.0)
+            }
+            tree::Statement::ForEachStatement(statement) => self.visit_value(&statement.range),
+            tree::Statement::ReturnStatement(statement) => {
+                if let Some(expr) = &statement.0 {
+                    self.visit_expr(expr);
+                }
+            }
+            tree::Statement::RequireStatement(statement) => {
+                self.visit_expr(&statement.condition);
+                if let Some(Some(expr)) = &statement.else_return {
+                    self.visit_expr(expr);
+                }
+            }
+        }
+    }
+
+    fn visit_expr(&mut self, expr: &tree::Expr) {
+        match expr {
+            tree::Expr::Value(value) => self.visit_value(value),
+            tree::Expr::PrefixUnaryExpr(expr) => self.visit_expr(&expr.expr),
+            tree::Expr::BinaryExpr(expr) => {
+                self.visit_expr(&expr.lhs);
+                for rhs in &expr.rhs {
+                    self.visit_expr(&rhs.expr);
+                }
+            }
+        }
+    }
+
+    fn visit_values(&mut self, values: &tree::Values) {
+        for value in &values.0 {
+            self.visit_value(value);
+        }
+    }
+
+    fn visit_value(&mut self, value: &tree::Value) {
+        (self.f)(value);
+        match value {
+            tree::Value::List(list) => self.visit_values(&list.0),
+            tree::Value::ValueExpr(expr) => self.visit_expr(&expr.0),
+            tree::Value::ValueFunctionCall(function_call) => {
+                self.visit_values(&function_call.0.args)
+            }
+            tree::Value::ValueAssignment(assignment) => self.visit_expr(&assignment.0.rhs),
+            tree::Value::FormattedString(formatted_string) => {
+                self.visit_values(&formatted_string.args)
+            }
+            _ => (),
+        }
+    }
+}
+
+#[cfg(test)]
+mod checker_test {
+    use alloc::{
+        borrow::ToOwned,
+        string::{
+            String,
+            ToString,
+        },
+        vec::Vec,
+    };
+
+    use battler_data::{
+        Id,
+        SubroutineData,
+    };
+    use pretty_assertions::assert_eq;
+
+    use crate::effect::fxlang::{
+        BattleEvent,
+        ExternalFunctions,
+        FunctionSignature,
+        InputVar,
+        NativeFunctionRegistry,
+        ValueType,
+        check_effect_data,
+        check_subroutine_data,
+        input_vars,
+    };
+
+    fn check(effect: &str) -> Vec<String> {
+        check_with_functions(effect, &ExternalFunctions::default())
+    }
+
+    fn check_with_functions(effect: &str, functions: &ExternalFunctions) -> Vec<String> {
+        check_effect_data(
+            "move:test",
+            &serde_json::from_str(effect).unwrap(),
+            functions,
+        )
+        .into_iter()
+        .map(|err| err.to_string())
+        .collect()
+    }
+
+    fn subroutine(subroutine: &str) -> SubroutineData {
+        serde_json::from_str(subroutine).unwrap()
+    }
+
+    #[test]
+    fn valid_effect_has_no_errors() {
+        assert_eq!(
+            check(
+                r#"{
+                    "callbacks": {
+                        "on_base_power": [
+                            "$power = $base_power",
+                            "if func_call(has_type: $target fire):",
+                            ["$power = $power * 2"],
+                            "return $power"
+                        ],
+                        "on_hit": [
+                            "foreach $mon in func_call(adjacent_foes: $source):",
+                            ["log_activate: str('mon:{}', $mon.position_details)"],
+                            "return true"
+                        ]
+                    }
+                }"#
+            ),
+            Vec::<String>::new()
+        );
+    }
+
+    #[test]
+    fn fails_invalid_effect() {
+        assert_eq!(
+            check(r#"{ "delegates": "move:tackle" }"#),
+            Vec::from_iter([
+                "move:test: invalid fxlang effect: invalid type: string \"move:tackle\", expected a sequence".to_owned()
+            ])
+        );
+    }
+
+    #[test]
+    fn fails_invalid_callback() {
+        assert_eq!(
+            check(r#"{ "callbacks": { "on_nothing": ["return"], "on_hit": ["if true"] } }"#),
+            Vec::from_iter([
+                "move:test: on_hit: error parsing Hit callback: invalid statement on line 1: unexpected end of line (expected :)".to_owned(),
+                "move:test: on_nothing: invalid callback on_nothing".to_owned(),
+            ])
+        );
+    }
+
+    #[test]
+    fn fails_invalid_function_calls() {
+        assert_eq!(
+            check(
+                r#"{
+                    "callbacks": {
+                        "on_hit": [
+                            "damage_target: $target",
+                            "$x = func_call(log_activate)",
+                            "ability_has_flag: $target",
+                            "return $x"
+                        ]
+                    }
+                }"#
+            ),
+            Vec::from_iter([
+                "move:test: on_hit: error on statement 0: undefined function: damage_target"
+                    .to_owned(),
+                "move:test: on_hit: error on statement 1: log_activate does not return a value"
+                    .to_owned(),
+                "move:test: on_hit: error on statement 2: ability_has_flag requires 2 arguments, found 1"
+                    .to_owned(),
+            ])
+        );
+    }
+
+    #[test]
+    fn allows_native_functions() {
+        let mut native_functions = NativeFunctionRegistry::default();
+        native_functions
+            .register(
+                "custom_damage",
+                FunctionSignature {
+                    required_args: 1,
+                    returns_value: false,
+                },
+                |_| Ok(None),
+            )
+            .unwrap();
+        let mut functions = ExternalFunctions::default();
+        functions.add_native_functions(&native_functions);
+        assert_eq!(
+            check_with_functions(
+                r#"{
+                    "callbacks": {
+                        "on_hit": [
+                            "custom_damage: $target",
+                            "custom_damage",
+                            "$x = func_call(custom_damage: $target)"
+                        ]
+                    }
+                }"#,
+                &functions
+            ),
+            Vec::from_iter([
+                "move:test: on_hit: error on statement 1: custom_damage requires 1 arguments, found 0"
+                    .to_owned(),
+                "move:test: on_hit: error on statement 2: custom_damage does not return a value"
+                    .to_owned(),
+            ])
+        );
+    }
+
+    #[test]
+    fn allows_subroutines() {
+        let mut functions = ExternalFunctions::default();
+        functions.add_subroutine(
+            Id::from("double_damage"),
+            &subroutine(r#"{ "parameters": ["damage"], "program": ["return $damage * 2"] }"#),
+        );
+        assert_eq!(
+            check_with_functions(
+                r#"{
+                    "callbacks": {
+                        "on_modify_damage": [
+                            "double_damage",
+                            "return func_call(double_damage: $damage)"
+                        ]
+                    }
+                }"#,
+                &functions
+            ),
+            Vec::from_iter([
+                "move:test: on_modify_damage: error on statement 0: double_damage requires 1 arguments, found 0"
+                    .to_owned(),
+            ])
+        );
+    }
+
+    #[test]
+    fn checks_subroutines() {
+        let functions = ExternalFunctions::default();
+        assert_eq!(
+            check_subroutine_data(
+                "subroutine:test",
+                &subroutine(
+                    r#"{
+                        "parameters": ["mon"],
+                        "program": [
+                            "$item = $mon.effective_item",
+                            "require $item.is_defined else return",
+                            "return func_call(special_item_data: $item)"
+                        ]
+                    }"#
+                ),
+                &functions,
+            )
+            .into_iter()
+            .map(|err| err.to_string())
+            .collect::<Vec<_>>(),
+            Vec::<String>::new()
+        );
+        assert_eq!(
+            check_subroutine_data(
+                "subroutine:test",
+                &subroutine(
+                    r#"{
+                        "program": [
+                            "damage_target: $target",
+                            "return 'fail'"
+                        ]
+                    }"#
+                ),
+                &functions,
+            )
+            .into_iter()
+            .map(|err| err.to_string())
+            .collect::<Vec<_>>(),
+            Vec::from_iter([
+                "subroutine:test: error on statement 0: undefined function: damage_target"
+                    .to_owned(),
+                "subroutine:test: error on statement 0: undefined variable $target".to_owned(),
+            ])
+        );
+        assert_eq!(
+            check_subroutine_data(
+                "subroutine:test",
+                &subroutine(r#"{ "program": ["if true"] }"#),
+                &functions,
+            )
+            .into_iter()
+            .map(|err| err.to_string())
+            .collect::<Vec<_>>(),
+            Vec::from_iter([
+                "subroutine:test: invalid statement on line 1: unexpected end of line (expected :)"
+                    .to_owned(),
+            ])
+        );
+    }
+
+    #[test]
+    fn fails_undefined_variables() {
+        assert_eq!(
+            check(
+                r#"{
+                    "callbacks": {
+                        "on_modify_spa": [
+                            "$x = 2",
+                            "$y.z = 3",
+                            "return $atk * $x"
+                        ],
+                        "on_activate": {
+                            "program": ["return $value"],
+                            "metadata": { "parameters": ["value"] }
+                        }
+                    }
+                }"#
+            ),
+            Vec::from_iter([
+                "move:test: on_modify_spa: error on statement 1: undefined variable $y".to_owned(),
+                "move:test: on_modify_spa: error on statement 2: undefined variable $atk"
+                    .to_owned(),
+            ])
+        );
+    }
+
+    #[test]
+    fn fails_invalid_member_access() {
+        assert_eq!(
+            check(
+                r#"{
+                    "callbacks": {
+                        "on_hit": [
+                            "$x = $target.nonexistent",
+                            "$y = $move.hit_effect.boosts.nonexistent",
+                            "$z = $move.effect_state.anything.at.all",
+                            "$mon = $target",
+                            "$w = $mon.nonexistent",
+                            "return $target.is_defined"
+                        ]
+                    }
+                }"#
+            ),
+            Vec::from_iter([
+                "move:test: on_hit: error on statement 0: invalid member access $target.nonexistent: value of type Mon has no member nonexistent".to_owned(),
+                "move:test: on_hit: error on statement 1: invalid member access $move.hit_effect.boosts.nonexistent: value of type BoostTable has no member nonexistent".to_owned(),
+            ])
+        );
+    }
+
+    #[test]
+    fn fails_invalid_member_assignments() {
+        assert_eq!(
+            check(
+                r#"{
+                    "callbacks": {
+                        "on_use_move": [
+                            "$move.base_power = 'high'",
+                            "$move.name = 'Tackle'",
+                            "$move.type = 'fire'",
+                            "$move.accuracy = 50",
+                            "$move.damage = undefined",
+                            "$move.force_stab = $move.name",
+                            "$move.hit_effect.boosts.atk = 1",
+                            "$move.effect_state.anything = true"
+                        ]
+                    }
+                }"#
+            ),
+            Vec::from_iter([
+                "move:test: on_use_move: error on statement 0: invalid assignment to $move.base_power: cannot convert from String to UFraction".to_owned(),
+                "move:test: on_use_move: error on statement 1: invalid member access $move.name: value of type ActiveMove has no member name or the member is immutable".to_owned(),
+                "move:test: on_use_move: error on statement 5: invalid assignment to $move.force_stab: cannot convert from String to Boolean".to_owned(),
+            ])
+        );
+    }
+
+    #[test]
+    fn fails_invalid_return_types() {
+        assert_eq!(
+            check(
+                r#"{
+                    "callbacks": {
+                        "on_modify_damage": ["return true"],
+                        "on_after_hit": ["require false else return 1"],
+                        "on_try_hit": ["return 'fail'"]
+                    }
+                }"#
+            ),
+            Vec::from_iter([
+                "move:test: on_after_hit: error on statement 0: AfterHit cannot return a UFraction"
+                    .to_owned(),
+                "move:test: on_modify_damage: error on statement 0: ModifyDamage cannot return a Boolean"
+                    .to_owned(),
+            ])
+        );
+    }
+
+    #[test]
+    fn reports_statement_index_of_errors() {
+        assert_eq!(
+            check_effect_data(
+                "move:test",
+                &serde_json::from_str(
+                    r##"{
+                        "callbacks": {
+                            "on_hit": ["# Comment.", "", "$x = 1", "log_activate: $y"]
+                        }
+                    }"##
+                )
+                .unwrap(),
+                &ExternalFunctions::default(),
+            )
+            .into_iter()
+            .map(|err| (err.statement, err.message))
+            .collect::<Vec<_>>(),
+            Vec::from_iter([(
+                Some(1),
+                "error on statement 1: undefined variable $y".to_owned()
+            )])
+        );
+    }
+
+    #[test]
+    fn lists_input_vars() {
+        let vars = input_vars(Some(BattleEvent::ModifyDamage), &[]);
+        assert!(vars.contains(&InputVar {
+            name: "damage".to_owned(),
+            value_type: Some(ValueType::UFraction),
+        }));
+        assert!(vars.contains(&InputVar {
+            name: "this".to_owned(),
+            value_type: Some(ValueType::Effect),
+        }));
+
+        let vars = input_vars(None, &["mon".to_owned()]);
+        assert_eq!(
+            vars.last(),
+            Some(&InputVar {
+                name: "mon".to_owned(),
+                value_type: None,
+            })
+        );
+    }
+}
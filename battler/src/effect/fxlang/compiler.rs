@@ -0,0 +1,864 @@
+use alloc::{
+    borrow::ToOwned,
+    format,
+    string::String,
+    vec::Vec,
+};
+use core::{
+    iter::Peekable,
+    slice,
+};
+
+use anyhow::Result;
+
+use crate::{
+    effect::fxlang::{
+        Function,
+        Member,
+        ParsedProgram,
+        ParsedProgramBlock,
+        Program,
+        bytecode::{
+            Branch,
+            CompiledProgram,
+            Constant,
+            FormatSegment,
+            Instruction,
+            VariableAccess,
+        },
+        tree,
+    },
+    error::general_error,
+};
+
+impl CompiledProgram {
+    /// Parses and compiles a [`Program`].
+    pub fn from(program: &Program) -> Result<Self> {
+        Self::compile(&ParsedProgram::from(program)?)
+    }
+
+    /// Compiles a [`ParsedProgram`] to bytecode.
+    ///
+    /// Compilation does not check that the program is semantically valid. Errors are reported
+    /// during evaluation exactly like they are for the parsed program.
+    pub fn compile(program: &ParsedProgram) -> Result<Self> {
+        Compiler::new().compile(program)
+    }
+}
+
+struct Loop {
+    start: usize,
+    breaks: Vec<usize>,
+}
+
+struct Compiler {
+    program: CompiledProgram,
+    loops: Vec<Loop>,
+    statements: usize,
+}
+
+impl Compiler {
+    fn new() -> Self {
+        Self {
+            program: CompiledProgram::default(),
+            loops: Vec::new(),
+            statements: 0,
+        }
+    }
+
+    fn compile(mut self, program: &ParsedProgram) -> Result<CompiledProgram> {
+        self.compile_blocks(slice::from_ref(&program.block))?;
+        self.program.statements = self.statements;
+        Ok(self.program)
+    }
+
+    fn emit(&mut self, instruction: Instruction) -> usize {
+        self.program.instructions.push(instruction);
+        self.program.instructions.len() - 1
+    }
+
+    /// Points the jump at the given index to the next instruction.
+    fn patch(&mut self, index: usize) -> Result<()> {
+        let next = self.program.instructions.len();
+        match self.program.instructions.get_mut(index) {
+            Some(
+                Instruction::Jump(target)
+                | Instruction::ShortCircuit { target, .. }
+                | Instruction::TestCondition { target, .. }
+                | Instruction::SkipIfConditionMet { target, .. }
+                | Instruction::NextIteration { target, .. }
+                | Instruction::Break(target)
+                | Instruction::RequireOrElse(target),
+            ) => {
+                *target = next;
+                Ok(())
+            }
+            _ => Err(general_error(format!("instruction {index} is not a jump"))),
+        }
+    }
+
+    fn statement(&mut self) {
+        self.statements += 1;
+        self.emit(Instruction::Statement(self.statements));
+    }
+
+    fn skip_statements(&mut self, count: usize) {
+        if count > 0 {
+            self.emit(Instruction::SkipStatements(count));
+        }
+    }
+
+    /// Compiles a block of statements, recording it as a branch if it contains any statements.
+    fn compile_branch(
+        &mut self,
+        header: Option<usize>,
+        blocks: &[ParsedProgramBlock],
+    ) -> Result<()> {
+        let first = self.statements + 1;
+        self.compile_blocks(blocks)?;
+        if self.statements >= first {
+            self.program.branches.push(Branch {
+                header,
+                first,
+                last: self.statements,
+            });
+        }
+        Ok(())
+    }
+
+    fn constant(&mut self, constant: Constant) {
+        let index = match self
+            .program
+            .constants
+            .iter()
+            .position(|existing| *existing == constant)
+        {
+            Some(index) => index,
+            None => {
+                self.program.constants.push(constant);
+                self.program.constants.len() - 1
+            }
+        };
+        self.emit(Instruction::Constant(index));
+    }
+
+    fn slot(&mut self, name: &str) -> usize {
+        match self.program.slot(name) {
+            Some(slot) => slot,
+            None => {
+                self.program.slots.push(name.to_owned());
+                self.program.slots.len() - 1
+            }
+        }
+    }
+
+    fn variable(&mut self, var: &tree::Var) -> usize {
+        let access = VariableAccess {
+            slot: self.slot(&var.name.0),
+            member_access: var
+                .member_access
+                .iter()
+                .map(|member| Member::new(&member.0))
+                .collect(),
+        };
+        match self
+            .program
+            .variables
+            .iter()
+            .position(|existing| *existing == access)
+        {
+            Some(index) => index,
+            None => {
+                self.program.variables.push(access);
+                self.program.variables.len() - 1
+            }
+        }
+    }
+
+    fn function(&mut self, name: &str) -> usize {
+        match self
+            .program
+            .functions
+            .iter()
+            .position(|function| function.name() == name)
+        {
+            Some(index) => index,
+            None => {
+                self.program.functions.push(Function::new(name));
+                self.program.functions.len() - 1
+            }
+        }
+    }
+
+    fn fail(&mut self, message: String) {
+        self.program.messages.push(message);
+        self.emit(Instruction::Fail(self.program.messages.len() - 1));
+    }
+
+    fn new_condition(&mut self) -> usize {
+        self.program.conditions += 1;
+        self.program.conditions - 1
+    }
+
+    fn take_body<'program>(
+        iter: &mut Peekable<slice::Iter<'program, ParsedProgramBlock>>,
+    ) -> Option<&'program Vec<ParsedProgramBlock>> {
+        match iter.peek() {
+            Some(ParsedProgramBlock::Branch(body)) => {
+                iter.next();
+                Some(body)
+            }
+            _ => None,
+        }
+    }
+
+    fn compile_blocks(&mut self, blocks: &[ParsedProgramBlock]) -> Result<()> {
+        let mut iter = blocks.iter().peekable();
+        // The condition recording the result of the last if statement, if it can be followed by an
+        // else statement.
+        let mut last_condition = None;
+
+        while let Some(block) = iter.next() {
+            match block {
+                ParsedProgramBlock::Leaf(statement) => match statement {
+                    tree::Statement::IfStatement(statement) => {
+                        let condition = self.new_condition();
+                        self.statement();
+                        self.compile_expr(&statement.0)?;
+                        self.compile_conditional_body(condition, Self::take_body(&mut iter))?;
+                        last_condition = Some(condition);
+                    }
+                    tree::Statement::ElseIfStatement(statement) => {
+                        let body = Self::take_body(&mut iter);
+                        let skip = 1 + body.map(|body| body.len()).unwrap_or_default();
+                        match last_condition {
+                            // There is no if statement to follow, so this block never runs.
+                            None => self.skip_statements(skip),
+                            Some(condition) => {
+                                let skip_if_met = self.emit(Instruction::SkipIfConditionMet {
+                                    condition,
+                                    skip,
+                                    target: 0,
+                                });
+                                self.statement();
+                                match &statement.0 {
+                                    Some(statement) => self.compile_expr(&statement.0)?,
+                                    None => self.constant(Constant::Boolean(true)),
+                                }
+                                self.compile_conditional_body(condition, body)?;
+                                self.patch(skip_if_met)?;
+                            }
+                        }
+                    }
+                    tree::Statement::ForEachStatement(statement) => {
+                        last_condition = None;
+                        if !statement.var.member_access.is_empty() {
+                            self.fail(format!(
+                                "invalid variable in foreach statement: ${}",
+                                statement.var.full_name(),
+                            ));
+                            Self::take_body(&mut iter);
+                            continue;
+                        }
+                        if let Some(body) = Self::take_body(&mut iter) {
+                            self.compile_for_each(statement, body)?;
+                        }
+                    }
+                    tree::Statement::Empty => {
+                        last_condition = None;
+                    }
+                    statement => {
+                        last_condition = None;
+                        self.statement();
+                        self.compile_statement(statement)?;
+                    }
+                },
+                ParsedProgramBlock::Branch(blocks) => self.compile_blocks(blocks)?,
+            }
+        }
+        Ok(())
+    }
+
+    fn compile_conditional_body(
+        &mut self,
+        condition: usize,
+        body: Option<&Vec<ParsedProgramBlock>>,
+    ) -> Result<()> {
+        let test = self.emit(Instruction::TestCondition {
+            condition,
+            target: 0,
+        });
+        match body {
+            Some(body) if !body.is_empty() => {
+                self.compile_branch(Some(self.statements), body)?;
+                let jump = self.emit(Instruction::Jump(0));
+                self.patch(test)?;
+                self.skip_statements(body.len());
+                self.patch(jump)
+            }
+            _ => self.patch(test),
+        }
+    }
+
+    fn compile_for_each(
+        &mut self,
+        statement: &tree::ForEachStatement,
+        body: &[ParsedProgramBlock],
+    ) -> Result<()> {
+        self.compile_value(&statement.range)?;
+        self.emit(Instruction::StartLoop);
+        let slot = self.slot(&statement.var.name.0);
+        let start = self.emit(Instruction::NextIteration { slot, target: 0 });
+        self.loops.push(Loop {
+            start,
+            breaks: Vec::new(),
+        });
+        self.compile_branch(None, body)?;
+        self.emit(Instruction::Jump(start));
+        let done = self
+            .loops
+            .pop()
+            .ok_or_else(|| general_error("foreach loop was not saved"))?;
+        self.patch(start)?;
+        for index in done.breaks {
+            self.patch(index)?;
+        }
+        Ok(())
+    }
+
+    fn compile_statement(&mut self, statement: &tree::Statement) -> Result<()> {
+        match statement {
+            tree::Statement::Empty => (),
+            tree::Statement::Assignment(assignment) => {
+                self.compile_expr(&assignment.rhs)?;
+                let var = self.variable(&assignment.lhs);
+                self.emit(Instruction::Store(var));
+            }
+            tree::Statement::FunctionCall(function_call) => {
+                self.compile_function_call(function_call)?;
+                self.emit(Instruction::Pop);
+            }
+            tree::Statement::IfStatement(_)
+            | tree::Statement::ElseIfStatement(_)
+            | tree::Statement::ForEachStatement(_) => {
+                return Err(general_error("unexpected control flow statement"));
+            }
+            tree::Statement::ReturnStatement(statement) => match &statement.0 {
+                Some(expr) => {
+                    self.compile_expr(expr)?;
+                    self.emit(Instruction::ReturnValue);
+                }
+                None => {
+                    self.emit(Instruction::Return);
+                }
+            },
+            // Outside of a loop, continue and break statements end the program.
+            tree::Statement::Continue(_) => match self.loops.last() {
+                Some(current) => {
+                    self.emit(Instruction::Jump(current.start));
+                }
+                None => {
+                    self.emit(Instruction::Return);
+                }
+            },
+            tree::Statement::Break(_) => {
+                if self.loops.is_empty() {
+                    self.emit(Instruction::Return);
+                } else {
+                    let index = self.emit(Instruction::Break(0));
+                    if let Some(current) = self.loops.last_mut() {
+                        current.breaks.push(index);
+                    }
+                }
+            }
+            tree::Statement::RequireStatement(statement) => {
+                self.compile_expr(&statement.condition)?;
+                match &statement.else_return {
+                    None => {
+                        self.emit(Instruction::Require);
+                    }
+                    Some(None) => {
+                        self.emit(Instruction::RequireOrReturn);
+                    }
+                    Some(Some(expr)) => {
+                        let require = self.emit(Instruction::RequireOrElse(0));
+                        self.compile_expr(expr)?;
+                        self.emit(Instruction::ReturnValue);
+                        self.patch(require)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn compile_function_call(&mut self, function_call: &tree::FunctionCall) -> Result<()> {
+        self.compile_values(&function_call.args)?;
+        let function = self.function(&function_call.function.0);
+        self.emit(Instruction::Call {
+            function,
+            args: function_call.args.0.len(),
+        });
+        Ok(())
+    }
+
+    fn compile_expr(&mut self, expr: &tree::Expr) -> Result<()> {
+        match expr {
+            tree::Expr::Value(value) => self.compile_value(value)?,
+            tree::Expr::PrefixUnaryExpr(prefix_expr) => {
+                self.compile_expr(&prefix_expr.expr)?;
+                for op in &prefix_expr.ops {
+                    self.emit(Instruction::Unary(*op));
+                }
+            }
+            tree::Expr::BinaryExpr(binary_expr) => {
+                self.compile_expr(&binary_expr.lhs)?;
+                for rhs in &binary_expr.rhs {
+                    match rhs.op {
+                        tree::Operator::And | tree::Operator::Or => {
+                            let short_circuit = self.emit(Instruction::ShortCircuit {
+                                op: rhs.op,
+                                target: 0,
+                            });
+                            self.compile_expr(&rhs.expr)?;
+                            self.emit(Instruction::Binary(rhs.op));
+                            self.patch(short_circuit)?;
+                        }
+                        _ => {
+                            self.compile_expr(&rhs.expr)?;
+                            self.emit(Instruction::Binary(rhs.op));
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn compile_value(&mut self, value: &tree::Value) -> Result<()> {
+        match value {
+            tree::Value::UndefinedLiteral => self.constant(Constant::Undefined),
+            tree::Value::BoolLiteral(bool) => self.constant(Constant::Boolean(bool.0)),
+            tree::Value::NumberLiteral(tree::NumberLiteral::Unsigned(number)) => {
+                self.constant(Constant::UFraction(*number))
+            }
+            tree::Value::NumberLiteral(tree::NumberLiteral::Signed(number)) => {
+                self.constant(Constant::Fraction(*number))
+            }
+            tree::Value::StringLiteral(string) => self.constant(Constant::String(string.0.clone())),
+            tree::Value::List(list) => {
+                self.compile_values(&list.0)?;
+                self.emit(Instruction::MakeList(list.0.0.len()));
+            }
+            tree::Value::Var(var) => {
+                let var = self.variable(var);
+                self.emit(Instruction::Load(var));
+            }
+            tree::Value::ValueExpr(expr) => self.compile_expr(&expr.0)?,
+            tree::Value::ValueFunctionCall(function_call) => {
+                self.compile_function_call(&function_call.0)?
+            }
+            tree::Value::ValueAssignment(assignment) => {
+                self.compile_expr(&assignment.0.rhs)?;
+                let var = self.variable(&assignment.0.lhs);
+                self.emit(Instruction::Store(var));
+                self.emit(Instruction::Load(var));
+            }
+            tree::Value::FormattedString(formatted_string) => {
+                self.compile_values(&formatted_string.args)?;
+                self.program
+                    .templates
+                    .push(Self::compile_template(&formatted_string.template.0));
+                self.emit(Instruction::Format {
+                    template: self.program.templates.len() - 1,
+                    args: formatted_string.args.0.len(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn compile_values(&mut self, values: &tree::Values) -> Result<()> {
+        for value in &values.0 {
+            self.compile_value(value)?;
+        }
+        Ok(())
+    }
+
+    fn compile_template(template: &str) -> Vec<FormatSegment> {
+        let mut segments = Vec::new();
+        let mut string = String::new();
+        let mut group = String::new();
+        let mut group_start = None;
+
+        for (i, c) in template.char_indices() {
+            match c {
+                '{' => {
+                    if i > 0 && group_start == Some(i - 1) {
+                        // Two left brackets in a row result in an escape.
+                        group_start = None;
+                        string.push(c);
+                    } else {
+                        // Open a new group.
+                        group_start = Some(i);
+                    }
+                }
+                '}' if group_start.is_some() => {
+                    if !string.is_empty() {
+                        segments.push(FormatSegment::Text(core::mem::take(&mut string)));
+                    }
+                    if group.is_empty() {
+                        // Use next positional argument.
+                        segments.push(FormatSegment::Argument);
+                    } else {
+                        segments.push(FormatSegment::InvalidGroup(group.clone()));
+                    }
+
+                    // Reset the state, since the group was closed.
+                    group_start = None;
+                    group.clear();
+                }
+                _ => {
+                    if group_start.is_some() {
+                        group.push(c);
+                    } else {
+                        string.push(c);
+                    }
+                }
+            }
+        }
+
+        if !string.is_empty() {
+            segments.push(FormatSegment::Text(string));
+        }
+        segments
+    }
+}
+
+#[cfg(test)]
+mod compiler_test {
+    use alloc::{
+        borrow::ToOwned,
+        vec,
+    };
+
+    use battler_data::Fraction;
+    use pretty_assertions::assert_eq;
+
+    use crate::effect::fxlang::{
+        CompiledProgram,
+        Member,
+        bytecode::{
+            Branch,
+            Constant,
+            FormatSegment,
+            Instruction,
+            VariableAccess,
+        },
+        tree,
+    };
+
+    fn compile(program: &str) -> CompiledProgram {
+        CompiledProgram::from(&serde_json::from_str(program).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn resolves_variables_to_slots() {
+        let program = compile(
+            r#"[
+                "$a = $target.hp",
+                "$b = $a",
+                "damage: $target $b"
+            ]"#,
+        );
+        assert_eq!(program.slot("target"), Some(7));
+        assert_eq!(program.slot("a"), Some(8));
+        assert_eq!(program.slot("b"), Some(9));
+        assert_eq!(program.slot("effect_state"), Some(0));
+        assert_eq!(program.slot("c"), None);
+        assert_eq!(
+            program.variables,
+            vec![
+                VariableAccess {
+                    slot: 7,
+                    member_access: vec![Member::Hp],
+                },
+                VariableAccess {
+                    slot: 8,
+                    member_access: vec![],
+                },
+                VariableAccess {
+                    slot: 9,
+                    member_access: vec![],
+                },
+                VariableAccess {
+                    slot: 7,
+                    member_access: vec![],
+                },
+            ]
+        );
+        assert_eq!(
+            program.instructions,
+            vec![
+                Instruction::Statement(1),
+                Instruction::Load(0),
+                Instruction::Store(1),
+                Instruction::Statement(2),
+                Instruction::Load(1),
+                Instruction::Store(2),
+                Instruction::Statement(3),
+                Instruction::Load(3),
+                Instruction::Load(2),
+                Instruction::Call {
+                    function: 0,
+                    args: 2,
+                },
+                Instruction::Pop,
+            ]
+        );
+    }
+
+    #[test]
+    fn compiles_branches() {
+        let program = compile(
+            r#"[
+                "if $a:",
+                [
+                    "return 1"
+                ],
+                "else if $b:",
+                [
+                    "return 2",
+                    "return 3"
+                ],
+                "else:",
+                [
+                    "return 4"
+                ]
+            ]"#,
+        );
+        assert_eq!(program.conditions, 1);
+        assert_eq!(
+            program.instructions,
+            vec![
+                Instruction::Statement(1),
+                Instruction::Load(0),
+                Instruction::TestCondition {
+                    condition: 0,
+                    target: 7,
+                },
+                Instruction::Statement(2),
+                Instruction::Constant(0),
+                Instruction::ReturnValue,
+                Instruction::Jump(8),
+                Instruction::SkipStatements(1),
+                Instruction::SkipIfConditionMet {
+                    condition: 0,
+                    skip: 3,
+                    target: 20,
+                },
+                Instruction::Statement(3),
+                Instruction::Load(1),
+                Instruction::TestCondition {
+                    condition: 0,
+                    target: 19,
+                },
+                Instruction::Statement(4),
+                Instruction::Constant(1),
+                Instruction::ReturnValue,
+                Instruction::Statement(5),
+                Instruction::Constant(2),
+                Instruction::ReturnValue,
+                Instruction::Jump(20),
+                Instruction::SkipStatements(2),
+                Instruction::SkipIfConditionMet {
+                    condition: 0,
+                    skip: 2,
+                    target: 29,
+                },
+                Instruction::Statement(6),
+                Instruction::Constant(3),
+                Instruction::TestCondition {
+                    condition: 0,
+                    target: 28,
+                },
+                Instruction::Statement(7),
+                Instruction::Constant(4),
+                Instruction::ReturnValue,
+                Instruction::Jump(29),
+                Instruction::SkipStatements(1),
+            ]
+        );
+        assert_eq!(program.statements(), 7);
+        assert_eq!(
+            program.branches(),
+            [
+                Branch {
+                    header: Some(1),
+                    first: 2,
+                    last: 2,
+                },
+                Branch {
+                    header: Some(3),
+                    first: 4,
+                    last: 5,
+                },
+                Branch {
+                    header: Some(6),
+                    first: 7,
+                    last: 7,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn skips_else_without_if() {
+        let program = compile(
+            r#"[
+                "$a = 1",
+                "else:",
+                [
+                    "return 1"
+                ]
+            ]"#,
+        );
+        assert_eq!(
+            program.instructions,
+            vec![
+                Instruction::Statement(1),
+                Instruction::Constant(0),
+                Instruction::Store(0),
+                Instruction::SkipStatements(2),
+            ]
+        );
+    }
+
+    #[test]
+    fn compiles_loops() {
+        let program = compile(
+            r#"[
+                "foreach $mon in $mons:",
+                [
+                    "if $mon.fainted:",
+                    [
+                        "continue"
+                    ],
+                    "break"
+                ],
+                "continue"
+            ]"#,
+        );
+        assert_eq!(
+            program.instructions,
+            vec![
+                Instruction::Load(0),
+                Instruction::StartLoop,
+                Instruction::NextIteration {
+                    slot: 8,
+                    target: 13,
+                },
+                Instruction::Statement(1),
+                Instruction::Load(1),
+                Instruction::TestCondition {
+                    condition: 0,
+                    target: 9,
+                },
+                Instruction::Statement(2),
+                Instruction::Jump(2),
+                Instruction::Jump(10),
+                Instruction::SkipStatements(1),
+                Instruction::Statement(3),
+                Instruction::Break(13),
+                Instruction::Jump(2),
+                Instruction::Statement(4),
+                Instruction::Return,
+            ]
+        );
+        assert_eq!(program.statements(), 4);
+        assert_eq!(
+            program.branches(),
+            [
+                Branch {
+                    header: Some(1),
+                    first: 2,
+                    last: 2,
+                },
+                Branch {
+                    header: None,
+                    first: 1,
+                    last: 3,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn compiles_short_circuiting_operators() {
+        let program = compile(r#""return $a or $b + 1 and $c""#);
+        assert_eq!(
+            program.instructions,
+            vec![
+                Instruction::Statement(1),
+                Instruction::Load(0),
+                Instruction::ShortCircuit {
+                    op: tree::Operator::Or,
+                    target: 10,
+                },
+                Instruction::Load(1),
+                Instruction::Constant(0),
+                Instruction::Binary(tree::Operator::Add),
+                Instruction::ShortCircuit {
+                    op: tree::Operator::And,
+                    target: 9,
+                },
+                Instruction::Load(2),
+                Instruction::Binary(tree::Operator::And),
+                Instruction::Binary(tree::Operator::Or),
+                Instruction::ReturnValue,
+            ]
+        );
+    }
+
+    #[test]
+    fn compiles_formatted_string_templates() {
+        let program = compile(r#""log: str('a{}b{{c}{}{x}', 1, 2)""#);
+        assert_eq!(
+            program.templates,
+            vec![vec![
+                FormatSegment::Text("a".to_owned()),
+                FormatSegment::Argument,
+                FormatSegment::Text("b{c}".to_owned()),
+                FormatSegment::Argument,
+                FormatSegment::InvalidGroup("x".to_owned()),
+            ]]
+        );
+        assert_eq!(
+            program.constants,
+            vec![
+                Constant::UFraction(Fraction::from(1u64)),
+                Constant::UFraction(Fraction::from(2u64)),
+            ]
+        );
+    }
+
+    #[test]
+    fn serializes_and_deserializes() {
+        let program = compile(
+            r#"[
+                "$a = -1/2",
+                "$b = 'text'",
+                "$c = -1",
+                "require $a < 0 else return",
+                "foreach $mon in func_call(all_active_mons):",
+                [
+                    "log: str('{}', $mon.name)"
+                ],
+                "return [$a, $b, $c, expr(!true), undefined]"
+            ]"#,
+        );
+        let serialized = serde_json::to_string(&program).unwrap();
+        let deserialized: CompiledProgram = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, program);
+    }
+}
@@ -0,0 +1,363 @@
+use alloc::{
+    collections::{
+        BTreeMap,
+        BTreeSet,
+    },
+    format,
+    string::String,
+    vec::Vec,
+};
+use core::fmt::{
+    self,
+    Display,
+};
+
+use battler_data::SubroutineData;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+use crate::effect::fxlang::{
+    CompiledProgram,
+    Effect,
+    ParsedSubroutine,
+    bytecode::Branch,
+};
+
+/// The callback name used for subroutines in coverage data, since a subroutine is a single program.
+pub const SUBROUTINE_CALLBACK_NAME: &str = "program";
+
+/// The statements of fxlang programs that ran.
+///
+/// Programs are identified by the ID of their effect in battle data (e.g., `move:tackle/condition`)
+/// and the name of their callback (e.g., `on_residual`). Subroutines are identified by
+/// `subroutine:{id}` and [`SUBROUTINE_CALLBACK_NAME`].
+///
+/// Coverage from any number of battles can be merged together.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Coverage {
+    pub programs: BTreeMap<String, BTreeMap<String, BTreeSet<usize>>>,
+}
+
+impl Coverage {
+    /// Records that a program started running.
+    pub fn record_program(&mut self, effect: &str, callback: &str) {
+        self.statements_mut(effect, callback);
+    }
+
+    /// Records that a statement of a program ran.
+    pub fn record_statement(&mut self, effect: &str, callback: &str, statement: usize) {
+        self.statements_mut(effect, callback).insert(statement);
+    }
+
+    fn statements_mut(&mut self, effect: &str, callback: &str) -> &mut BTreeSet<usize> {
+        self.programs
+            .entry(effect.into())
+            .or_default()
+            .entry(callback.into())
+            .or_default()
+    }
+
+    /// The statements of a program that ran, if the program ran at all.
+    pub fn statements(&self, effect: &str, callback: &str) -> Option<&BTreeSet<usize>> {
+        self.programs.get(effect)?.get(callback)
+    }
+
+    /// Merges other coverage into this coverage.
+    pub fn merge(&mut self, other: Self) {
+        for (effect, callbacks) in other.programs {
+            let existing = self.programs.entry(effect).or_default();
+            for (callback, statements) in callbacks {
+                existing.entry(callback).or_default().extend(statements);
+            }
+        }
+    }
+}
+
+/// A program that did not completely run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UncoveredProgram {
+    pub effect: String,
+    pub callback: String,
+    /// Whether the program ran at all.
+    pub ran: bool,
+    /// Statements that never ran.
+    pub statements: Vec<usize>,
+    /// Conditional blocks of statements that never ran.
+    pub branches: Vec<Branch>,
+}
+
+/// A report of how much of a set of fxlang programs is covered by [`Coverage`].
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CoverageReport {
+    pub programs: usize,
+    pub programs_covered: usize,
+    pub statements: usize,
+    pub statements_covered: usize,
+    pub branches: usize,
+    pub branches_covered: usize,
+    /// Programs that did not completely run, sorted by effect ID and callback name.
+    pub uncovered: Vec<UncoveredProgram>,
+}
+
+impl CoverageReport {
+    /// Creates a report for the given effects and subroutines from battle data.
+    ///
+    /// Effects and subroutines are keyed by the same IDs used in [`Coverage`]. Effects and programs
+    /// that fail to parse are skipped, since they can never run.
+    pub fn new<'a, E, S>(coverage: &Coverage, effects: E, subroutines: S) -> Self
+    where
+        E: IntoIterator<Item = (String, &'a serde_json::Value)>,
+        S: IntoIterator<Item = (String, &'a SubroutineData)>,
+    {
+        let mut report = Self::default();
+        for (id, effect) in effects {
+            if effect.is_null() {
+                continue;
+            }
+            let Ok(effect) = Effect::try_from(effect.clone()) else {
+                continue;
+            };
+            for (name, callback) in &effect.callbacks {
+                if let Ok(Some(program)) = callback.compile() {
+                    report.add_program(coverage, &id, name, &program);
+                }
+            }
+        }
+        for (id, subroutine) in subroutines {
+            if let Ok(subroutine) = ParsedSubroutine::new(subroutine) {
+                report.add_program(coverage, &id, SUBROUTINE_CALLBACK_NAME, &subroutine.program);
+            }
+        }
+        report.uncovered.sort_by(|a, b| {
+            a.effect
+                .cmp(&b.effect)
+                .then_with(|| a.callback.cmp(&b.callback))
+        });
+        report
+    }
+
+    fn add_program(
+        &mut self,
+        coverage: &Coverage,
+        effect: &str,
+        callback: &str,
+        program: &CompiledProgram,
+    ) {
+        if program.statements() == 0 {
+            return;
+        }
+
+        let ran = coverage.statements(effect, callback);
+        let statement_ran = |statement: &usize| ran.is_some_and(|ran| ran.contains(statement));
+
+        let statements = (1..=program.statements())
+            .filter(|statement| !statement_ran(statement))
+            .collect::<Vec<_>>();
+        let branches = program
+            .branches()
+            .iter()
+            .filter(|branch| {
+                !(branch.first..=branch.last).any(|statement| statement_ran(&statement))
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+
+        self.programs += 1;
+        self.statements += program.statements();
+        self.statements_covered += program.statements() - statements.len();
+        self.branches += program.branches().len();
+        self.branches_covered += program.branches().len() - branches.len();
+        if ran.is_some() {
+            self.programs_covered += 1;
+        }
+
+        if ran.is_none() || !statements.is_empty() {
+            self.uncovered.push(UncoveredProgram {
+                effect: effect.into(),
+                callback: callback.into(),
+                ran: ran.is_some(),
+                statements,
+                branches,
+            });
+        }
+    }
+}
+
+fn percent(covered: usize, total: usize) -> f64 {
+    if total == 0 {
+        100f64
+    } else {
+        covered as f64 * 100f64 / total as f64
+    }
+}
+
+fn format_statements(statements: &[usize]) -> String {
+    let mut ranges = Vec::<(usize, usize)>::new();
+    for statement in statements {
+        match ranges.last_mut() {
+            Some((_, last)) if *last + 1 == *statement => *last = *statement,
+            _ => ranges.push((*statement, *statement)),
+        }
+    }
+    ranges
+        .into_iter()
+        .map(|(first, last)| format_range(first, last))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn format_range(first: usize, last: usize) -> String {
+    if first == last {
+        format!("{first}")
+    } else {
+        format!("{first}-{last}")
+    }
+}
+
+impl Display for CoverageReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "programs: {}/{} ({:.1}%)",
+            self.programs_covered,
+            self.programs,
+            percent(self.programs_covered, self.programs)
+        )?;
+        writeln!(
+            f,
+            "statements: {}/{} ({:.1}%)",
+            self.statements_covered,
+            self.statements,
+            percent(self.statements_covered, self.statements)
+        )?;
+        writeln!(
+            f,
+            "branches: {}/{} ({:.1}%)",
+            self.branches_covered,
+            self.branches,
+            percent(self.branches_covered, self.branches)
+        )?;
+        for program in &self.uncovered {
+            write!(f, "{} {}: ", program.effect, program.callback)?;
+            if !program.ran {
+                writeln!(f, "never ran")?;
+                continue;
+            }
+            write!(
+                f,
+                "statements {} never ran",
+                format_statements(&program.statements)
+            )?;
+            let headers = program
+                .branches
+                .iter()
+                .filter_map(|branch| branch.header)
+                .collect::<Vec<_>>();
+            if !headers.is_empty() {
+                write!(
+                    f,
+                    "; branches at statements {} never taken",
+                    format_statements(&headers)
+                )?;
+            }
+            let loops = program
+                .branches
+                .iter()
+                .filter(|branch| branch.header.is_none())
+                .map(|branch| format_range(branch.first, branch.last))
+                .collect::<Vec<_>>();
+            if !loops.is_empty() {
+                write!(f, "; loops over statements {} never run", loops.join(", "))?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+pub mod coverage_collector {
+    use alloc::{
+        format,
+        string::String,
+        vec::Vec,
+    };
+    use std::sync::Mutex;
+
+    use battler_data::Id;
+
+    use crate::effect::fxlang::{
+        Coverage,
+        SUBROUTINE_CALLBACK_NAME,
+        TraceRecord,
+        Tracer,
+    };
+
+    #[derive(Debug, Default)]
+    struct CollectorState {
+        coverage: Coverage,
+        // The program of every callback and subroutine being evaluated, innermost last.
+        //
+        // Callbacks that are not from battle data do not have a program.
+        programs: Vec<Option<(String, String)>>,
+    }
+
+    /// A [`Tracer`] that collects [`Coverage`] of fxlang programs from battle data.
+    #[derive(Debug, Default)]
+    pub struct CoverageCollector {
+        state: Mutex<CollectorState>,
+    }
+
+    impl CoverageCollector {
+        /// Creates a new coverage collector.
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// The coverage collected so far.
+        pub fn coverage(&self) -> Coverage {
+            self.state
+                .lock()
+                .map(|state| state.coverage.clone())
+                .unwrap_or_default()
+        }
+    }
+
+    impl Tracer for CoverageCollector {
+        fn record(&self, record: TraceRecord) {
+            let Ok(mut state) = self.state.lock() else {
+                return;
+            };
+            let state = &mut *state;
+            match record {
+                TraceRecord::Callback { .. } => state.programs.push(None),
+                TraceRecord::Program { effect, callback } => {
+                    state.coverage.record_program(&effect, &callback);
+                    if let Some(program) = state.programs.last_mut() {
+                        *program = Some((effect, callback));
+                    }
+                }
+                TraceRecord::Subroutine { name, .. } => {
+                    let effect = format!("subroutine:{}", Id::from(name.as_str()));
+                    state
+                        .coverage
+                        .record_program(&effect, SUBROUTINE_CALLBACK_NAME);
+                    state
+                        .programs
+                        .push(Some((effect, SUBROUTINE_CALLBACK_NAME.into())));
+                }
+                TraceRecord::CallbackResult { .. } | TraceRecord::SubroutineResult { .. } => {
+                    state.programs.pop();
+                }
+                TraceRecord::Statement { statement } => {
+                    if let Some(Some((effect, callback))) = state.programs.last() {
+                        state.coverage.record_statement(effect, callback, statement);
+                    }
+                }
+                _ => (),
+            }
+        }
+    }
+}
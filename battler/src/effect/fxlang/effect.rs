@@ -1,4 +1,5 @@
 use alloc::{
+    boxed::Box,
     string::{
         String,
         ToString,
@@ -6,7 +7,10 @@ use alloc::{
     vec::Vec,
 };
 
-use anyhow::Error;
+use anyhow::{
+    Error,
+    Result,
+};
 use hashbrown::HashMap;
 use serde::{
     Deserialize,
@@ -21,6 +25,7 @@ use crate::{
     WrapResultError,
     battle::SpeedOrderable,
     effect::fxlang::{
+        CompiledProgram,
         LocalData,
         ValueType,
     },
@@ -58,6 +63,163 @@ pub mod CallbackFlag {
     pub const ReturnsVoid: u32 = 1 << 31;
 }
 
+/// Where the value of a [`ContextVar`] comes from when an fxlang program starts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContextVarSource {
+    /// The state of the effect being evaluated, if it exists.
+    EffectState,
+    /// The Mon the effect being evaluated is attached to.
+    EffectTarget,
+    /// The Mon that is the origin of the event.
+    EventOrigin,
+    /// The effect being evaluated.
+    This,
+    Battle,
+    Field,
+    Format,
+    /// The target Mon of the event.
+    TargetMon,
+    /// The source Mon of the event.
+    SourceMon,
+    /// The source effect of the event.
+    SourceEffect,
+    /// The source effect of the event, which must be an active move.
+    ActiveMove,
+    /// The side of the event.
+    Side,
+    /// The player of the event.
+    Player,
+}
+
+/// A variable that is defined from the battle context when an fxlang program starts, before any
+/// assignments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContextVar {
+    pub name: &'static str,
+    pub value_type: ValueType,
+    pub source: ContextVarSource,
+    /// Whether evaluation fails if the value is missing from the context.
+    pub required: bool,
+}
+
+impl ContextVar {
+    const fn new(
+        name: &'static str,
+        value_type: ValueType,
+        source: ContextVarSource,
+        required: bool,
+    ) -> Self {
+        Self {
+            name,
+            value_type,
+            source,
+            required,
+        }
+    }
+}
+
+/// Variables that are defined for every fxlang program.
+///
+/// Compiled programs assign these variables to the first slots, in order.
+pub const GLOBAL_CONTEXT_VARS: [ContextVar; 7] = [
+    ContextVar::new(
+        "effect_state",
+        ValueType::EffectState,
+        ContextVarSource::EffectState,
+        false,
+    ),
+    ContextVar::new(
+        "effect_target",
+        ValueType::Mon,
+        ContextVarSource::EffectTarget,
+        false,
+    ),
+    ContextVar::new(
+        "event_origin",
+        ValueType::Mon,
+        ContextVarSource::EventOrigin,
+        false,
+    ),
+    ContextVar::new("this", ValueType::Effect, ContextVarSource::This, false),
+    ContextVar::new("battle", ValueType::Battle, ContextVarSource::Battle, false),
+    ContextVar::new("field", ValueType::Field, ContextVarSource::Field, false),
+    ContextVar::new("format", ValueType::Format, ContextVarSource::Format, false),
+];
+
+/// Variables that are defined for event callbacks, by the [`CallbackFlag`] that defines them.
+///
+/// Variables are assigned in order, so a later variable overwrites an earlier variable with the
+/// same name.
+const EVENT_CONTEXT_VARS: [(u32, ContextVar); 11] = [
+    (
+        CallbackFlag::TakesGeneralMon,
+        ContextVar::new("mon", ValueType::Mon, ContextVarSource::TargetMon, true),
+    ),
+    (
+        CallbackFlag::TakesTargetMon,
+        ContextVar::new("target", ValueType::Mon, ContextVarSource::TargetMon, false),
+    ),
+    (
+        CallbackFlag::TakesSourceMon,
+        ContextVar::new("source", ValueType::Mon, ContextVarSource::SourceMon, false),
+    ),
+    // The user is the target of the effect.
+    (
+        CallbackFlag::TakesUserMon,
+        ContextVar::new("user", ValueType::Mon, ContextVarSource::TargetMon, true),
+    ),
+    // The target is the source of the effect.
+    (
+        CallbackFlag::TakesSourceTargetMon,
+        ContextVar::new("target", ValueType::Mon, ContextVarSource::SourceMon, false),
+    ),
+    (
+        CallbackFlag::TakesEffect,
+        ContextVar::new(
+            "effect",
+            ValueType::Effect,
+            ContextVarSource::SourceEffect,
+            true,
+        ),
+    ),
+    // Only defined if the event does not also take the effect, which is the same value.
+    (
+        CallbackFlag::TakesSourceEffect,
+        ContextVar::new(
+            "source_effect",
+            ValueType::Effect,
+            ContextVarSource::SourceEffect,
+            true,
+        ),
+    ),
+    (
+        CallbackFlag::TakesActiveMove,
+        ContextVar::new(
+            "move",
+            ValueType::ActiveMove,
+            ContextVarSource::ActiveMove,
+            true,
+        ),
+    ),
+    (
+        CallbackFlag::TakesOptionalEffect,
+        ContextVar::new(
+            "effect",
+            ValueType::Effect,
+            ContextVarSource::SourceEffect,
+            false,
+        ),
+    ),
+    (
+        CallbackFlag::TakesSide,
+        ContextVar::new("side", ValueType::Side, ContextVarSource::Side, true),
+    ),
+    (
+        CallbackFlag::TakesPlayer,
+        ContextVar::new("player", ValueType::Player, ContextVarSource::Player, true),
+    ),
+];
+
 /// Common types of [`Callback`]s, defined for convenience.
 ///
 /// - `ApplyingEffect` - An effect being applied to a target Mon, potentially from a source Mon. The
@@ -1719,6 +1881,19 @@ impl BattleEvent {
         self.has_flag(CallbackFlag::TakesGeneralMon) || self.has_flag(CallbackFlag::TakesUserMon)
     }
 
+    /// The variables that are defined from the battle context for the event's callbacks, in the
+    /// order they are assigned.
+    pub fn context_vars(&self) -> impl Iterator<Item = &'static ContextVar> {
+        EVENT_CONTEXT_VARS
+            .iter()
+            .filter(|(flag, _)| {
+                self.has_flag(*flag)
+                    && !(*flag == CallbackFlag::TakesSourceEffect
+                        && self.has_flag(CallbackFlag::TakesEffect))
+            })
+            .map(|(_, var)| var)
+    }
+
     /// Does the event allow custom input variables?
     pub fn allows_custom_input_vars(&self) -> bool {
         // Maintain alphabetical order.
@@ -2041,7 +2216,7 @@ impl BattleEvent {
 /// applied in battle.
 ///
 /// Internally represented as a tree-like structure for interpretation.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum Program {
     /// A single statement.
@@ -2067,6 +2242,11 @@ pub struct ProgramWithPriority {
     pub priority: Option<i32>,
     pub sub_order: Option<u32>,
     pub metadata: Option<ProgramMetadata>,
+    /// The program, compiled ahead of time.
+    ///
+    /// If set, used instead of [`Self::program`], which does not need to be parsed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub compiled: Option<Box<CompiledProgram>>,
 }
 
 /// The input to the [`Callback`] type.
@@ -2087,7 +2267,9 @@ impl Callback {
     pub fn has_program(&self) -> bool {
         match &self.0 {
             Some(CallbackInput::Regular(_)) => true,
-            Some(CallbackInput::WithPriority(program)) => program.program.is_some(),
+            Some(CallbackInput::WithPriority(program)) => {
+                program.program.is_some() || program.compiled.is_some()
+            }
             None => false,
         }
     }
@@ -2106,6 +2288,45 @@ impl Callback {
             CallbackInput::WithPriority(program) => program.metadata.as_ref(),
         }
     }
+
+    /// Compiles the callback's program, if it has one.
+    ///
+    /// A program compiled ahead of time is returned as is.
+    pub fn compile(&self) -> Result<Option<CompiledProgram>> {
+        if let Some(CallbackInput::WithPriority(ProgramWithPriority {
+            compiled: Some(compiled),
+            ..
+        })) = &self.0
+        {
+            return Ok(Some(compiled.as_ref().clone()));
+        }
+        self.program().map(CompiledProgram::from).transpose()
+    }
+
+    /// Compiles the callback's program ahead of time, replacing the source program.
+    pub fn precompile(&mut self) -> Result<()> {
+        let compiled = match self.compile()? {
+            Some(compiled) => compiled,
+            None => return Ok(()),
+        };
+        let program = match self.0.take() {
+            Some(CallbackInput::WithPriority(program)) => program,
+            _ => ProgramWithPriority {
+                program: None,
+                order: None,
+                priority: None,
+                sub_order: None,
+                metadata: None,
+                compiled: None,
+            },
+        };
+        self.0 = Some(CallbackInput::WithPriority(ProgramWithPriority {
+            program: None,
+            compiled: Some(Box::new(compiled)),
+            ..program
+        }));
+        Ok(())
+    }
 }
 
 impl SpeedOrderable for Callback {
@@ -2208,6 +2429,19 @@ pub struct Effect {
     pub attributes: EffectAttributes,
 }
 
+impl Effect {
+    /// Compiles all callback programs ahead of time, so that the effect can be stored and loaded
+    /// without parsing any programs.
+    pub fn precompile(&mut self) -> Result<()> {
+        for (name, callback) in &mut self.callbacks {
+            callback
+                .precompile()
+                .wrap_error_with_format(format_args!("error compiling {name} callback"))?;
+        }
+        Ok(())
+    }
+}
+
 impl TryFrom<serde_json::Value> for Effect {
     type Error = Error;
     fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
@@ -9,6 +9,12 @@ use core::fmt::Debug;
 use anyhow::Result;
 use battler_data::Fraction;
 use hashbrown::HashMap;
+use serde::{
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+};
 
 use crate::{
     battle::{
@@ -20,6 +26,7 @@ use crate::{
     effect::{
         AppliedEffectLocation,
         EffectHandle,
+        EffectStateConnectorData,
         fxlang::Value,
     },
     error::{
@@ -29,7 +36,7 @@ use crate::{
 };
 
 /// The activation state of an effect.
-#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
 enum EffectActivationState {
     Starting,
     Started,
@@ -41,7 +48,7 @@ enum EffectActivationState {
 /// The persisted state of an individual [`Effect`][`crate::effect::Effect`].
 ///
 /// Allows fxlang variables to be persisted across multiple callbacks.
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct EffectState {
     initialized: bool,
     activation_state: EffectActivationState,
@@ -290,7 +297,10 @@ impl EffectState {
 /// An object that connects an [`EffectState`] instance to the [`Context`] of a battle.
 ///
 /// Used for dynamically reading an [`EffectState`] instance during fxlang program evaluation.
-pub trait EffectStateConnector: Debug + Send {
+///
+/// Connectors must be [`Sync`], since resources holding effect state can be shared between
+/// threads by a [`DexCache`][`crate::dex::DexCache`].
+pub trait EffectStateConnector: Debug + Send + Sync {
     /// Checks if the underlying effect state exists.
     fn exists(&self, context: &mut Context) -> Result<bool>;
 
@@ -302,6 +312,9 @@ pub trait EffectStateConnector: Debug + Send {
 
     /// Clones the connection into a dynamic value.
     fn make_dynamic(&self) -> DynamicEffectStateConnector;
+
+    /// The serializable form of the connection.
+    fn data(&self) -> EffectStateConnectorData;
 }
 
 /// A dynamic [`EffectStateConnector`], which can be passed around like a value.
@@ -371,3 +384,21 @@ impl Clone for DynamicEffectStateConnector {
         self.0.make_dynamic()
     }
 }
+
+impl Serialize for DynamicEffectStateConnector {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.data().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for DynamicEffectStateConnector {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(EffectStateConnectorData::deserialize(deserializer)?.make_dynamic())
+    }
+}
@@ -2,8 +2,11 @@ use alloc::{
     collections::VecDeque,
     format,
     string::String,
+    sync::Arc,
+    vec,
     vec::Vec,
 };
+use core::mem;
 
 use anyhow::Result;
 
@@ -11,19 +14,30 @@ use crate::{
     battle::MonHandle,
     effect::fxlang::{
         BattleEvent,
-        CallbackFlag,
+        ContextVar,
+        ContextVarSource,
         DynamicEffectStateConnector,
         EvaluationContext,
         EventState,
+        Function,
+        GLOBAL_CONTEXT_VARS,
         MaybeReferenceValue,
         MaybeReferenceValueForOperation,
-        ParsedProgramBlock,
+        ParsedSubroutine,
         ProgramMetadata,
+        TraceRecord,
+        Tracer,
         Value,
         ValueType,
         Variable,
         VariableMut,
         VariableRegistry,
+        bytecode::{
+            CompiledProgram,
+            FormatSegment,
+            Instruction,
+            VariableAccess,
+        },
         parsed_effect::ParsedCallback,
         run_function,
         tree,
@@ -69,43 +83,13 @@ impl IntoIterator for VariableInput {
     }
 }
 
-/// Context for executing a [`ParsedProgramBlock`] over a list.
-///
-/// The list itself must be evaluated once at the beginning of the loop.
-#[derive(Clone)]
-struct ExecuteProgramBlockOverListContext {
-    index: usize,
+/// A list being iterated over by a foreach loop.
+struct Loop {
+    list: Vec<Value>,
+    next: usize,
 }
 
-impl ExecuteProgramBlockOverListContext {
-    fn new(index: usize) -> Self {
-        Self { index }
-    }
-}
-
-/// The inherited evaluation state from parent blocks.
-#[derive(Clone, Default)]
-struct ParentEvalState {
-    for_each_context: Option<ExecuteProgramBlockOverListContext>,
-    is_subsequent_iteration: bool,
-}
-
-impl ParentEvalState {
-    fn is_subsequent(&self) -> bool {
-        self.for_each_context
-            .as_ref()
-            .is_some_and(|ctx| ctx.index > 0)
-            || self.is_subsequent_iteration
-    }
-}
-
-enum ProgramStatementEvalResult {
-    ReturnStatement(Option<Value>),
-    ContinueStatement,
-    BreakStatement,
-}
-
-/// The result of evaluating a [`ParsedProgram`].
+/// The result of evaluating a [`CompiledProgram`].
 #[derive(Default)]
 pub struct ProgramEvalResult {
     pub value: Option<Value>,
@@ -119,192 +103,140 @@ impl ProgramEvalResult {
 
 /// An fxlang evaluator.
 ///
-/// Holds the global state of an fxlang [`ParsedProgram`] during evaluation. Individual blocks
-/// ([`ParsedProgramBlock`]) are evaluated recursively and get their own local state.
+/// Runs a [`CompiledProgram`] on a stack-based virtual machine. Each evaluation gets its own
+/// variables, which are stored in the slots resolved by the compiler.
 pub struct Evaluator<'event_state> {
     statement: usize,
-    vars: VariableRegistry,
     event: BattleEvent,
     event_state: &'event_state EventState,
+    tracer: Option<Arc<dyn Tracer>>,
+    untrusted: bool,
+    instructions: u64,
 }
 
 impl<'event_state> Evaluator<'event_state> {
     pub fn new(event: BattleEvent, event_state: &'event_state EventState) -> Self {
         Self {
             statement: 0,
-            vars: VariableRegistry::new(),
             event,
             event_state,
+            tracer: None,
+            untrusted: false,
+            instructions: 0,
         }
     }
 
-    fn increment_statement(&mut self, parent_eval_state: &ParentEvalState, amount: usize) {
-        if !parent_eval_state.is_subsequent() {
-            self.statement += amount;
-        }
+    /// Sets the [`Tracer`] that receives records of statements and variable accesses.
+    pub fn with_tracer(mut self, tracer: Option<Arc<dyn Tracer>>) -> Self {
+        self.tracer = tracer;
+        self
     }
 
-    fn evaluate_conditional_branch<'eval, 'program>(
-        &'eval mut self,
-        context: &mut EvaluationContext,
-        iter: &mut core::iter::Peekable<core::slice::Iter<'program, ParsedProgramBlock>>,
-        parent_eval_state: &ParentEvalState,
-        statement: Option<&'program tree::IfStatement>,
-    ) -> Result<(bool, Option<ProgramStatementEvalResult>)>
+    /// Marks the program as untrusted, so that it can only call functions allowed by the
+    /// [`EvaluationLimits`][`crate::effect::fxlang::EvaluationLimits`] of the battle.
+    pub fn with_untrusted(mut self, untrusted: bool) -> Self {
+        self.untrusted = untrusted;
+        self
+    }
+
+    fn trace<F>(&self, record: F)
     where
-        'program: 'eval,
+        F: FnOnce() -> TraceRecord,
     {
-        self.increment_statement(parent_eval_state, 1);
-        let condition_met = match statement {
-            Some(statement) => self.evaluate_if_statement(context, statement)?,
-            None => true,
+        if let Some(tracer) = &self.tracer {
+            tracer.record(record());
+        }
+    }
+
+    fn context_var_value(
+        &self,
+        context: &mut EvaluationContext,
+        var: &ContextVar,
+    ) -> Result<Option<Value>> {
+        let value = match var.source {
+            ContextVarSource::EffectState
+            | ContextVarSource::EffectTarget
+            | ContextVarSource::EventOrigin => None,
+            ContextVarSource::This => Some(Value::Effect(context.effect_handle().clone())),
+            ContextVarSource::Battle => Some(Value::Battle),
+            ContextVarSource::Field => Some(Value::Field),
+            ContextVarSource::Format => Some(Value::Format),
+            ContextVarSource::TargetMon => context.target_handle().map(Value::Mon),
+            ContextVarSource::SourceMon => context.source_handle().map(Value::Mon),
+            ContextVarSource::SourceEffect => {
+                context.source_effect_handle().cloned().map(Value::Effect)
+            }
+            ContextVarSource::ActiveMove => match context.source_effect_handle().cloned() {
+                Some(source_effect) => {
+                    if !source_effect.is_active_move() {
+                        return Err(general_error("source effect is not an active move"));
+                    }
+                    Some(Value::Effect(source_effect))
+                }
+                None => None,
+            },
+            ContextVarSource::Side => context.side_index().map(Value::Side),
+            ContextVarSource::Player => context.player_index().map(Value::Player),
         };
+        if value.is_none() && var.required {
+            return Err(general_error(format!("context has no {}", var.name)));
+        }
+        Ok(value)
+    }
 
-        let mut eval_result = None;
-        if let Some(ParsedProgramBlock::Branch(body)) = iter.peek() {
-            iter.next();
-            if condition_met {
-                eval_result = self.evaluate_program_blocks_once(
-                    context,
-                    body.as_slice(),
-                    parent_eval_state.clone(),
-                )?;
-            } else {
-                self.increment_statement(parent_eval_state, body.len());
+    fn initialize_global_vars(
+        &self,
+        context: &mut EvaluationContext,
+        vars: &VariableRegistry,
+        mut effect_state_connector: Option<DynamicEffectStateConnector>,
+        effect_mon_handle: Option<MonHandle>,
+        event_origin_mon_handle: Option<MonHandle>,
+    ) -> Result<()> {
+        // Global variables are assigned to the first slots of every program.
+        for (slot, var) in GLOBAL_CONTEXT_VARS.iter().enumerate() {
+            let value = match var.source {
+                ContextVarSource::EffectState => match effect_state_connector.take() {
+                    Some(effect_state_connector)
+                        if effect_state_connector.exists(context.battle_context_mut())? =>
+                    {
+                        Some(Value::EffectState(effect_state_connector))
+                    }
+                    _ => None,
+                },
+                ContextVarSource::EffectTarget => effect_mon_handle.map(Value::Mon),
+                ContextVarSource::EventOrigin => event_origin_mon_handle.map(Value::Mon),
+                _ => self.context_var_value(context, var)?,
+            };
+            if let Some(value) = value {
+                vars.set(slot, value)?;
             }
         }
-        Ok((condition_met, eval_result))
+        Ok(())
     }
 
     fn initialize_vars(
         &self,
         context: &mut EvaluationContext,
+        vars: &VariableRegistry,
         metadata: &ProgramMetadata,
         mut input: VariableInput,
         effect_state_connector: Option<DynamicEffectStateConnector>,
         effect_mon_handle: Option<MonHandle>,
         event_origin_mon_handle: Option<MonHandle>,
     ) -> Result<()> {
-        if let Some(effect_state_connector) = effect_state_connector {
-            if effect_state_connector.exists(context.battle_context_mut())? {
-                self.vars
-                    .set("effect_state", Value::EffectState(effect_state_connector))?;
-            }
-        }
-
-        if let Some(effect_mon_handle) = effect_mon_handle {
-            self.vars
-                .set("effect_target", Value::Mon(effect_mon_handle))?;
-        }
-
-        if let Some(event_origin_mon_handle) = event_origin_mon_handle {
-            self.vars
-                .set("event_origin", Value::Mon(event_origin_mon_handle))?;
-        }
-
-        self.vars
-            .set("this", Value::Effect(context.effect_handle().clone()))?;
-        self.vars.set("battle", Value::Battle)?;
-        self.vars.set("field", Value::Field)?;
-        self.vars.set("format", Value::Format)?;
+        self.initialize_global_vars(
+            context,
+            vars,
+            effect_state_connector,
+            effect_mon_handle,
+            event_origin_mon_handle,
+        )?;
 
-        if self.event.has_flag(CallbackFlag::TakesGeneralMon) {
-            self.vars.set(
-                "mon",
-                Value::Mon(
-                    context
-                        .target_handle()
-                        .wrap_expectation("context has no mon")?,
-                ),
-            )?;
-        }
-        if self.event.has_flag(CallbackFlag::TakesTargetMon) {
-            match context.target_handle() {
-                Some(target_handle) => self.vars.set("target", Value::Mon(target_handle))?,
-                None => (),
-            }
-        }
-        if self.event.has_flag(CallbackFlag::TakesSourceMon) {
-            match context.source_handle() {
-                Some(source_handle) => self.vars.set("source", Value::Mon(source_handle))?,
-                None => (),
+        for var in self.event.context_vars() {
+            if let Some(value) = self.context_var_value(context, var)? {
+                vars.set_by_name(var.name, value)?;
             }
         }
-        if self.event.has_flag(CallbackFlag::TakesUserMon) {
-            // The user is the target of the effect.
-            self.vars.set(
-                "user",
-                Value::Mon(
-                    context
-                        .target_handle()
-                        .wrap_expectation("context has no user")?,
-                ),
-            )?;
-        }
-        if self.event.has_flag(CallbackFlag::TakesSourceTargetMon) {
-            // The target is the source of the effect.
-            match context.source_handle() {
-                Some(source_handle) => self.vars.set("target", Value::Mon(source_handle))?,
-                None => (),
-            }
-        }
-        if self
-            .event
-            .has_flag(CallbackFlag::TakesEffect | CallbackFlag::TakesSourceEffect)
-        {
-            let effect_name = if self.event.has_flag(CallbackFlag::TakesEffect) {
-                "effect"
-            } else if self.event.has_flag(CallbackFlag::TakesSourceEffect) {
-                "source_effect"
-            } else {
-                unreachable!()
-            };
-            self.vars.set(
-                effect_name,
-                Value::Effect(
-                    context
-                        .source_effect_handle()
-                        .cloned()
-                        .wrap_expectation("context has no effect")?,
-                ),
-            )?;
-        }
-        if self.event.has_flag(CallbackFlag::TakesActiveMove) {
-            let source_effect = context
-                .source_effect_handle()
-                .cloned()
-                .wrap_expectation("context has no source effect")?;
-            if !source_effect.is_active_move() {
-                return Err(general_error("source effect is not an active move"));
-            }
-            self.vars.set("move", Value::Effect(source_effect))?;
-        }
-        if self.event.has_flag(CallbackFlag::TakesOptionalEffect) {
-            if let Some(source_effect_handle) = context.source_effect_handle().cloned() {
-                self.vars
-                    .set("effect", Value::Effect(source_effect_handle))?;
-            }
-        }
-        if self.event.has_flag(CallbackFlag::TakesSide) {
-            self.vars.set(
-                "side",
-                Value::Side(
-                    context
-                        .side_index()
-                        .wrap_expectation("context has no side")?,
-                ),
-            )?;
-        }
-        if self.event.has_flag(CallbackFlag::TakesPlayer) {
-            self.vars.set(
-                "player",
-                Value::Player(
-                    context
-                        .player_index()
-                        .wrap_expectation("context has no player")?,
-                ),
-            )?;
-        }
 
         // Reverse the input so we can efficiently pop elements out of it.
         input.values.reverse();
@@ -339,7 +271,7 @@ impl<'event_state> Evaluator<'event_state> {
                     } else {
                         value.convert_to(*value_type).wrap_error_with_format(format_args!("input at position {} for variable {name} of type {real_value_type} cannot be converted to {value_type}", i + 1))?
                     };
-                    self.vars.set(name, value)?;
+                    vars.set_by_name(name, value)?;
                 }
             }
         }
@@ -364,26 +296,26 @@ impl<'event_state> Evaluator<'event_state> {
         effect_mon_handle: Option<MonHandle>,
         event_origin_mon_handle: Option<MonHandle>,
     ) -> Result<ProgramEvalResult> {
+        if let Some(source) = &callback.source {
+            self.trace(|| TraceRecord::Program {
+                effect: source.effect.clone(),
+                callback: source.callback.clone(),
+            });
+        }
+        let program = callback.program.as_ref();
+        let vars = VariableRegistry::new(&program.slots);
         self.initialize_vars(
             context,
+            &vars,
             &callback.metadata,
             input,
             effect_state_connector,
             effect_mon_handle,
             event_origin_mon_handle,
         )?;
-        let parent_eval_state = ParentEvalState::default();
-        let value = match self
-            .evaluate_program_blocks_once(
-                context,
-                core::slice::from_ref(&callback.program.block),
-                parent_eval_state,
-            )
-            .wrap_error_with_format(format_args!("error on statement {}", self.statement))?
-        {
-            Some(ProgramStatementEvalResult::ReturnStatement(value)) => value,
-            _ => None,
-        };
+        let value = self
+            .run(context, program, &vars)
+            .wrap_error_with_format(format_args!("error on statement {}", self.statement))?;
         if !self
             .event
             .output_type_allowed(value.as_ref().map(|val| val.value_type()))
@@ -412,283 +344,431 @@ impl<'event_state> Evaluator<'event_state> {
         Ok(ProgramEvalResult::new(value))
     }
 
-    fn evaluate_program_blocks_once<'eval, 'program>(
-        &'eval mut self,
+    /// Evaluates the given subroutine, which was called from another program.
+    ///
+    /// Subroutines only have access to global variables and their parameters. The event is only
+    /// used for calling functions, so the return value is not checked against it.
+    pub fn evaluate_subroutine(
+        &mut self,
         context: &mut EvaluationContext,
-        blocks: &'program [ParsedProgramBlock],
-        parent_eval_state: ParentEvalState,
-    ) -> Result<Option<ProgramStatementEvalResult>>
-    where
-        'program: 'eval,
-    {
-        let mut iter = blocks.iter().peekable();
-        let mut last_if_statement_result: Option<bool> = None;
+        args: VecDeque<Value>,
+        subroutine: &ParsedSubroutine,
+        effect_state_connector: Option<DynamicEffectStateConnector>,
+        effect_mon_handle: Option<MonHandle>,
+        event_origin_mon_handle: Option<MonHandle>,
+    ) -> Result<Option<Value>> {
+        if args.len() != subroutine.parameters.len() {
+            return Err(general_error(format!(
+                "expected {} arguments, found {}",
+                subroutine.parameters.len(),
+                args.len(),
+            )));
+        }
+        let program = &subroutine.program;
+        let vars = VariableRegistry::new(&program.slots);
+        self.initialize_global_vars(
+            context,
+            &vars,
+            effect_state_connector,
+            effect_mon_handle,
+            event_origin_mon_handle,
+        )?;
+        for (name, value) in subroutine.parameters.iter().zip(args) {
+            vars.set_by_name(name, value)?;
+        }
+        let value = self
+            .run(context, program, &vars)
+            .wrap_error_with_format(format_args!("error on statement {}", self.statement))?;
+        match value {
+            Some(Value::Undefined) => Ok(None),
+            value => Ok(value),
+        }
+    }
 
-        while let Some(block) = iter.next() {
-            match block {
-                ParsedProgramBlock::Leaf(statement) => {
-                    match statement {
-                        tree::Statement::IfStatement(statement) => {
-                            let (condition_met, result) = self.evaluate_conditional_branch(
-                                context,
-                                &mut iter,
-                                &parent_eval_state,
-                                Some(statement),
-                            )?;
-                            last_if_statement_result = Some(condition_met);
-                            if let Some(result) = result {
-                                return Ok(Some(result));
-                            }
-                        }
-                        tree::Statement::ElseIfStatement(statement) => {
-                            if last_if_statement_result.is_none_or(|result| result) {
-                                // Skip the condition.
-                                self.increment_statement(&parent_eval_state, 1);
-                                if let Some(ParsedProgramBlock::Branch(body)) = iter.peek() {
-                                    // Skip the body.
-                                    iter.next();
-                                    self.increment_statement(&parent_eval_state, body.len());
-                                }
-                            } else {
-                                // Last condition failed, so this block should run.
-                                let (condition_met, result) = self.evaluate_conditional_branch(
-                                    context,
-                                    &mut iter,
-                                    &parent_eval_state,
-                                    statement.0.as_ref(),
-                                )?;
-                                last_if_statement_result = Some(condition_met);
-                                if let Some(result) = result {
-                                    return Ok(Some(result));
-                                }
-                            }
+    fn run(
+        &mut self,
+        context: &mut EvaluationContext,
+        program: &CompiledProgram,
+        vars: &VariableRegistry,
+    ) -> Result<Option<Value>> {
+        let mut stack = Vec::new();
+        let mut loops = Vec::new();
+        let mut conditions = vec![false; program.conditions];
+        // Statements are only counted on the first iteration of every loop.
+        let mut subsequent_iterations = 0;
+        let mut next = 0;
+
+        let limits = &context
+            .battle_context()
+            .battle()
+            .engine_options
+            .fxlang_limits;
+        let callback_instructions = limits.callback_instructions;
+        let turn_instructions = limits.turn_instructions;
+        let list_length = limits.list_length;
+
+        while let Some(instruction) = program.instructions.get(next) {
+            next += 1;
+            self.count_instruction(context, callback_instructions, turn_instructions)?;
+            match instruction {
+                Instruction::Statement(statement) => {
+                    if subsequent_iterations == 0 {
+                        self.statement += 1;
+                    }
+                    self.trace(|| TraceRecord::Statement {
+                        statement: *statement,
+                    });
+                }
+                Instruction::SkipStatements(count) => {
+                    if subsequent_iterations == 0 {
+                        self.statement += count;
+                    }
+                }
+                Instruction::Constant(index) => {
+                    let constant = program
+                        .constants
+                        .get(*index)
+                        .wrap_expectation_with_format(format_args!("invalid constant {index}"))?;
+                    stack.push(Value::from(constant));
+                }
+                Instruction::Load(index) => {
+                    let access = Self::variable_access(program, *index)?;
+                    let value = Self::load_var(context, vars, access)?;
+                    self.trace(|| TraceRecord::VariableRead {
+                        name: Self::full_name(program, access),
+                        value: value.clone(),
+                    });
+                    stack.push(value);
+                }
+                Instruction::Store(index) => {
+                    let access = Self::variable_access(program, *index)?;
+                    let value = Self::pop(&mut stack)?;
+                    self.trace(|| TraceRecord::VariableWrite {
+                        name: Self::full_name(program, access),
+                        value: value.clone(),
+                    });
+                    Self::assign_var(context, program, vars, access, value)?;
+                }
+                Instruction::MakeList(len) => {
+                    let list = Self::pop_many(&mut stack, *len)?;
+                    Self::check_list_length(list.len(), list_length)?;
+                    stack.push(Value::List(list));
+                }
+                Instruction::Format { template, args } => {
+                    let template = program
+                        .templates
+                        .get(*template)
+                        .wrap_expectation_with_format(format_args!(
+                            "invalid formatted string {template}"
+                        ))?;
+                    let args = Self::pop_many(&mut stack, *args)?;
+                    stack.push(Value::String(Self::evaluate_formatted_string(
+                        template, &args,
+                    )?));
+                }
+                Instruction::Call { function, args } => {
+                    let function = program
+                        .functions
+                        .get(*function)
+                        .wrap_expectation_with_format(format_args!(
+                            "invalid function {function}"
+                        ))?;
+                    let args = Self::pop_many(&mut stack, *args)?;
+                    let value = self.run_function(context, vars, function, args.into())?;
+                    if let Some(Value::List(list)) = &value {
+                        Self::check_list_length(list.len(), list_length)?;
+                    }
+                    stack.push(value.unwrap_or_default());
+                }
+                Instruction::Pop => {
+                    Self::pop(&mut stack)?;
+                }
+                Instruction::Unary(op) => {
+                    let value = Self::pop(&mut stack)?;
+                    let result = Self::evaluate_prefix_operator(
+                        *op,
+                        MaybeReferenceValueForOperation::from(&value),
+                    )?
+                    .to_owned();
+                    stack.push(result);
+                }
+                Instruction::Binary(op) => {
+                    let rhs = Self::pop(&mut stack)?;
+                    let lhs = Self::pop(&mut stack)?;
+                    let result = Self::evaluate_binary_operator(
+                        MaybeReferenceValueForOperation::from(&lhs),
+                        *op,
+                        MaybeReferenceValueForOperation::from(&rhs),
+                    )?
+                    .to_owned();
+                    if let Value::List(list) = &result {
+                        Self::check_list_length(list.len(), list_length)?;
+                    }
+                    stack.push(result);
+                }
+                Instruction::ShortCircuit { op, target } => {
+                    let value = stack.last_mut().wrap_expectation("stack is empty")?;
+                    if let Some(result) = Self::short_circuit(*op, value)? {
+                        *value = result;
+                        next = *target;
+                    }
+                }
+                Instruction::Jump(target) => {
+                    next = *target;
+                }
+                Instruction::TestCondition { condition, target } => {
+                    let value = Self::pop(&mut stack)?;
+                    let condition_met = Self::evaluate_condition(value)?;
+                    *conditions
+                        .get_mut(*condition)
+                        .wrap_expectation_with_format(format_args!(
+                            "invalid condition {condition}"
+                        ))? = condition_met;
+                    if !condition_met {
+                        next = *target;
+                    }
+                }
+                Instruction::SkipIfConditionMet {
+                    condition,
+                    skip,
+                    target,
+                } => {
+                    if *conditions
+                        .get(*condition)
+                        .wrap_expectation_with_format(format_args!(
+                            "invalid condition {condition}"
+                        ))?
+                    {
+                        if subsequent_iterations == 0 {
+                            self.statement += skip;
                         }
-                        tree::Statement::ForEachStatement(statement) => {
-                            if !statement.var.member_access.is_empty() {
-                                return Err(general_error(format!(
-                                    "invalid variable in foreach statement: ${}",
-                                    statement.var.full_name(),
-                                )));
-                            }
-                            last_if_statement_result = None;
-
-                            if let Some(ParsedProgramBlock::Branch(body)) = iter.peek() {
-                                iter.next();
-
-                                let item = &statement.var.name.0;
-                                let list = &statement.range;
-
-                                let list = self.resolve_value(context, list)?;
-                                if !list.supports_list_iteration() {
-                                    return Err(general_error(format!(
-                                        "cannot iterate over a {}",
-                                        list.value_type()
-                                    )));
-                                }
-                                let len = list.len().wrap_expectation(
-                                    "value supports iteration but is missing a length",
-                                )?;
-
-                                // SAFETY: We only use this immutable borrow at the beginning of
-                                // each loop, at the start of each execution.
-                                //
-                                // This list value can only potentially contain a reference to a
-                                // stored variable. If so, we are also storing the object that does
-                                // runtime borrow checking, so borrow errors will trigger during
-                                // evaluation.
-                                let list = unsafe {
-                                    core::mem::transmute::<
-                                        MaybeReferenceValue<'_>,
-                                        MaybeReferenceValue<'_>,
-                                    >(list)
-                                };
-
-                                for i in 0..len {
-                                    let current_item = list
-                                        .list_index(i)
-                                        .wrap_expectation_with_format(format_args!(
-                                            "list has no element at index {i}, but length at beginning of foreach loop was {len}"
-                                        ))?
-                                        .to_owned();
-                                    self.vars.set(item, current_item)?;
-
-                                    let parent_eval_state = ParentEvalState {
-                                        for_each_context: Some(
-                                            ExecuteProgramBlockOverListContext::new(i),
-                                        ),
-                                        is_subsequent_iteration: parent_eval_state.is_subsequent(),
-                                    };
-
-                                    match self.evaluate_program_blocks_once(
-                                        context,
-                                        body.as_slice(),
-                                        parent_eval_state,
-                                    )? {
-                                        Some(ProgramStatementEvalResult::ContinueStatement) => {
-                                            continue;
-                                        }
-                                        Some(ProgramStatementEvalResult::BreakStatement) => {
-                                            break;
-                                        }
-                                        res @ Some(_) => return Ok(res),
-                                        None => {}
-                                    }
-                                }
+                        next = *target;
+                    }
+                }
+                Instruction::StartLoop => match Self::pop(&mut stack)? {
+                    Value::List(list) => loops.push(Loop { list, next: 0 }),
+                    value => {
+                        return Err(general_error(format!(
+                            "cannot iterate over a {}",
+                            value.value_type()
+                        )));
+                    }
+                },
+                Instruction::NextIteration { slot, target } => {
+                    let current = loops.last_mut().wrap_expectation("no loop to iterate")?;
+                    match current.list.get_mut(current.next) {
+                        Some(item) => {
+                            let item = mem::take(item);
+                            current.next += 1;
+                            if current.next == 2 {
+                                subsequent_iterations += 1;
                             }
+                            self.trace(|| TraceRecord::VariableWrite {
+                                name: program.slots.get(*slot).cloned().unwrap_or_default(),
+                                value: item.clone(),
+                            });
+                            vars.set(*slot, item)?;
                         }
-                        other_statement => {
-                            self.increment_statement(&parent_eval_state, 1);
-                            last_if_statement_result = None;
-                            if let Some(result) =
-                                self.evaluate_statement(context, other_statement)?
-                            {
-                                return Ok(Some(result));
-                            }
+                        None => {
+                            Self::end_loop(&mut loops, &mut subsequent_iterations)?;
+                            next = *target;
                         }
                     }
                 }
-                ParsedProgramBlock::Branch(blocks) => {
-                    if let Some(result) = self.evaluate_program_blocks_once(
-                        context,
-                        blocks.as_slice(),
-                        parent_eval_state.clone(),
-                    )? {
-                        return Ok(Some(result));
+                Instruction::Break(target) => {
+                    Self::end_loop(&mut loops, &mut subsequent_iterations)?;
+                    next = *target;
+                }
+                Instruction::Return => return Ok(None),
+                Instruction::ReturnValue => return Ok(Some(Self::pop(&mut stack)?)),
+                Instruction::Require => {
+                    let condition = Self::pop(&mut stack)?;
+                    if let Some(failed_requirement) = Self::failed_requirement(&condition)? {
+                        return Ok(Some(failed_requirement));
+                    }
+                }
+                Instruction::RequireOrReturn => {
+                    let condition = Self::pop(&mut stack)?;
+                    if Self::failed_requirement(&condition)?.is_some() {
+                        return Ok(None);
+                    }
+                }
+                Instruction::RequireOrElse(target) => {
+                    let condition = Self::pop(&mut stack)?;
+                    if Self::failed_requirement(&condition)?.is_none() {
+                        next = *target;
                     }
                 }
+                Instruction::Fail(message) => {
+                    return Err(general_error(
+                        program.messages.get(*message).cloned().unwrap_or_default(),
+                    ));
+                }
             }
         }
+
         Ok(None)
     }
 
-    fn evaluate_statement<'eval, 'program>(
-        &'eval mut self,
-        context: &'eval mut EvaluationContext,
-        statement: &'program tree::Statement,
-    ) -> Result<Option<ProgramStatementEvalResult>>
-    where
-        'program: 'eval,
-    {
-        match statement {
-            tree::Statement::Empty => Ok(None),
-            tree::Statement::Assignment(assignment) => {
-                let value = self.evaluate_expr(context, &assignment.rhs)?;
-                // SAFETY: The value produced by the expression should be some newly generated
-                // value. If it is a reference to the variable that is being assigned to, the
-                // program evaluation will error out because the variable registry has runtime
-                // borrow checking. Thus, we allow the context to be borrowed again.
-                let value = unsafe {
-                    core::mem::transmute::<MaybeReferenceValue<'_>, MaybeReferenceValue<'_>>(value)
-                };
-                self.assign_var(context, &assignment.lhs, value)?;
-                Ok(None)
-            }
-            tree::Statement::FunctionCall(statement) => {
-                self.evaluate_function_call(context, &statement)?;
-                Ok(None)
-            }
-            tree::Statement::IfStatement(_)
-            | tree::Statement::ElseIfStatement(_)
-            | tree::Statement::ForEachStatement(_) => {
-                Err(general_error("unexpected control flow statement"))
-            }
-            tree::Statement::ReturnStatement(statement) => {
-                let value = match &statement.0 {
-                    None => None,
-                    Some(expr) => Some(self.evaluate_expr(context, expr)?),
-                };
-                Ok(Some(ProgramStatementEvalResult::ReturnStatement(
-                    value.map(|value| value.to_owned()),
-                )))
-            }
-            tree::Statement::Continue(_) => Ok(Some(ProgramStatementEvalResult::ContinueStatement)),
-            tree::Statement::Break(_) => Ok(Some(ProgramStatementEvalResult::BreakStatement)),
-            tree::Statement::RequireStatement(statement) => {
-                let failed_requirement = {
-                    let condition = self.evaluate_expr(context, &statement.condition)?;
-                    let condition = MaybeReferenceValueForOperation::from(&condition);
-                    let value = condition.boolean().wrap_error_with_message(
-                        "require statement condition must convert to boolean",
-                    )?;
-                    if !value.clone().boolean()? {
-                        Some(value)
-                    } else {
-                        None
-                    }
-                };
-                if let Some(failed_requirement) = failed_requirement {
-                    let return_val = match &statement.else_return {
-                        None => Some(failed_requirement),
-                        Some(None) => None,
-                        Some(Some(expr)) => Some(self.evaluate_expr(context, expr)?.to_owned()),
-                    };
-                    return Ok(Some(ProgramStatementEvalResult::ReturnStatement(
-                        return_val,
-                    )));
-                }
-                Ok(None)
-            }
+    fn count_instruction(
+        &mut self,
+        context: &mut EvaluationContext,
+        callback_instructions: Option<u64>,
+        turn_instructions: Option<u64>,
+    ) -> Result<()> {
+        self.instructions += 1;
+        if let Some(limit) = callback_instructions
+            && self.instructions > limit
+        {
+            return Err(general_error(format!(
+                "exceeded the budget of {limit} instructions per callback"
+            )));
+        }
+        if let Some(limit) = turn_instructions
+            && context
+                .battle_context_mut()
+                .battle_mut()
+                .effect_manager
+                .count_instruction()
+                > limit
+        {
+            return Err(general_error(format!(
+                "exceeded the budget of {limit} instructions per turn"
+            )));
+        }
+        Ok(())
+    }
+
+    fn check_list_length(length: usize, limit: Option<usize>) -> Result<()> {
+        match limit {
+            Some(limit) if length > limit => Err(general_error(format!(
+                "list of length {length} exceeds the maximum list length of {limit}"
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    fn pop(stack: &mut Vec<Value>) -> Result<Value> {
+        stack.pop().wrap_expectation("stack is empty")
+    }
+
+    fn pop_many(stack: &mut Vec<Value>, len: usize) -> Result<Vec<Value>> {
+        let start = stack
+            .len()
+            .checked_sub(len)
+            .wrap_expectation_with_format(format_args!(
+                "stack has {} values, but {len} are needed",
+                stack.len()
+            ))?;
+        Ok(stack.split_off(start))
+    }
+
+    fn end_loop(loops: &mut Vec<Loop>, subsequent_iterations: &mut usize) -> Result<()> {
+        let done = loops.pop().wrap_expectation("no loop to end")?;
+        if done.next >= 2 {
+            *subsequent_iterations -= 1;
+        }
+        Ok(())
+    }
+
+    fn variable_access(program: &CompiledProgram, index: usize) -> Result<&VariableAccess> {
+        program
+            .variables
+            .get(index)
+            .wrap_expectation_with_format(format_args!("invalid variable {index}"))
+    }
+
+    fn full_name(program: &CompiledProgram, access: &VariableAccess) -> String {
+        let mut name = program.slots.get(access.slot).cloned().unwrap_or_default();
+        for member in &access.member_access {
+            name.push('.');
+            name.push_str(member.name());
         }
+        name
     }
 
-    fn evaluate_if_statement<'eval, 'program>(
-        &'eval self,
-        context: &'eval mut EvaluationContext,
-        statement: &'program tree::IfStatement,
-    ) -> Result<bool> {
-        let condition = self.evaluate_expr(context, &statement.0)?;
-        let condition = condition.boolean().ok_or_else(|| {
-            general_error(format!(
+    fn evaluate_condition(value: Value) -> Result<bool> {
+        match value {
+            Value::Undefined | Value::Boolean(_) | Value::EventResult(_) => value.boolean(),
+            _ => Err(general_error(format!(
                 "if statement condition must return a boolean, got {}",
-                condition.value_type(),
-            ))
-        })?;
-        Ok(condition)
+                value.value_type(),
+            ))),
+        }
     }
 
-    fn evaluate_function_call<'eval, 'program>(
-        &'eval self,
-        context: &'eval mut EvaluationContext,
-        function_call: &'program tree::FunctionCall,
-    ) -> Result<Option<MaybeReferenceValue<'eval>>>
-    where
-        'program: 'eval,
-    {
-        let args = self.resolve_values(context, &function_call.args)?;
-        // Functions call code outside of the evaluator, so there can be no internal references.
-        let args = args.into_iter().map(|arg| arg.to_owned()).collect();
-        self.run_function(context, &function_call.function.0, args)
+    fn failed_requirement(condition: &Value) -> Result<Option<Value>> {
+        let value = MaybeReferenceValueForOperation::from(condition)
+            .boolean()
+            .wrap_error_with_message("require statement condition must convert to boolean")?;
+        if !value.clone().boolean()? {
+            Ok(Some(value))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn short_circuit(op: tree::Operator, value: &Value) -> Result<Option<Value>> {
+        let lhs = MaybeReferenceValueForOperation::from(value);
+        let short_circuit = match op {
+            tree::Operator::Or => lhs.boolean()?.boolean()?,
+            tree::Operator::And => !lhs.boolean()?.boolean()?,
+            _ => {
+                return Err(general_error(format!(
+                    "invalid short-circuiting operator: {op}"
+                )));
+            }
+        };
+        if short_circuit {
+            Ok(Some(lhs.boolean()?))
+        } else {
+            Ok(None)
+        }
     }
 
-    fn run_function<'eval, 'program>(
-        &'eval self,
+    fn run_function(
+        &self,
         context: &mut EvaluationContext,
-        function_name: &'program str,
+        vars: &VariableRegistry,
+        function: &Function,
         args: VecDeque<Value>,
-    ) -> Result<Option<MaybeReferenceValue<'eval>>> {
-        let effect_state = self
-            .vars
-            .get("effect_state")?
-            .and_then(|val| (*val).clone().effect_state().ok());
-        let effect_mon_handle = self
-            .vars
-            .get("effect_target")?
-            .and_then(|val| (*val).clone().mon_handle().ok());
-        let event_origin_mon_handle = self
-            .vars
-            .get("event_origin")?
-            .and_then(|val| (*val).clone().mon_handle().ok());
+    ) -> Result<Option<Value>> {
+        let function_name = function.name();
+        if self.untrusted
+            && !context
+                .battle_context()
+                .battle()
+                .engine_options
+                .fxlang_limits
+                .untrusted_function_allowed(function_name)
+        {
+            return Err(general_error(format!(
+                "function {function_name} is not allowed in untrusted programs"
+            )));
+        }
+        let effect_state = (*vars.get(CompiledProgram::EFFECT_STATE_SLOT)?)
+            .clone()
+            .effect_state()
+            .ok();
+        let effect_mon_handle = (*vars.get(CompiledProgram::EFFECT_TARGET_SLOT)?)
+            .clone()
+            .mon_handle()
+            .ok();
+        let event_origin_mon_handle = (*vars.get(CompiledProgram::EVENT_ORIGIN_SLOT)?)
+            .clone()
+            .mon_handle()
+            .ok();
         run_function(
             context,
-            function_name,
+            function,
             args,
             self.event,
             self.event_state,
             effect_state,
             effect_mon_handle,
             event_origin_mon_handle,
+            self.untrusted,
         )
-        .map(|val| val.map(|val| MaybeReferenceValue::from(val)))
     }
 
     fn evaluate_prefix_operator<'eval>(
@@ -728,298 +808,57 @@ impl<'event_state> Evaluator<'event_state> {
         }
     }
 
-    fn evaluate_expr<'eval, 'program>(
-        &'eval self,
-        context: &'eval mut EvaluationContext,
-        expr: &'program tree::Expr,
-    ) -> Result<MaybeReferenceValue<'eval>>
-    where
-        'program: 'eval,
-    {
-        match expr {
-            tree::Expr::Value(value) => self.resolve_value(context, value),
-            tree::Expr::PrefixUnaryExpr(prefix_expr) => {
-                let mut value = self.evaluate_expr(context, prefix_expr.expr.as_ref())?;
-                for op in &prefix_expr.ops {
-                    let value_for_operation = MaybeReferenceValueForOperation::from(&value);
-                    let result = Self::evaluate_prefix_operator(*op, value_for_operation)?;
-                    // SAFETY: `value_for_operation` was consumed by `evaluate_prefix_operator`.
-                    let result = unsafe {
-                        core::mem::transmute::<MaybeReferenceValue<'_>, MaybeReferenceValue<'eval>>(
-                            result,
-                        )
-                    };
-                    value = result;
-                }
-                Ok(value)
-            }
-            tree::Expr::BinaryExpr(binary_expr) => {
-                let value = self.evaluate_expr(context, binary_expr.lhs.as_ref())?;
-                // SAFETY: `context` is not really borrowed mutably when we hold an immutable
-                // reference to some value in the battle or evaluation state.
-                let mut value = unsafe {
-                    core::mem::transmute::<MaybeReferenceValue<'_>, MaybeReferenceValue<'_>>(value)
-                };
-                for rhs_expr in &binary_expr.rhs {
-                    let lhs = MaybeReferenceValueForOperation::from(&value);
-
-                    // Short-circuiting logic.
-                    //
-                    // Important for cases where we might check if a variable exists before
-                    // accessing it.
-                    match rhs_expr.op {
-                        tree::Operator::Or => {
-                            if lhs.boolean()?.boolean()? {
-                                let result = lhs.boolean()?;
-                                drop(lhs);
-                                value = result.into();
-                                continue;
-                            }
-                        }
-                        tree::Operator::And => {
-                            if !lhs.boolean()?.boolean()? {
-                                let result = lhs.boolean()?;
-                                drop(lhs);
-                                value = result.into();
-                                continue;
-                            }
-                        }
-                        _ => (),
-                    }
-
-                    let rhs_value = self.evaluate_expr(context, rhs_expr.expr.as_ref())?;
-                    let rhs = MaybeReferenceValueForOperation::from(&rhs_value);
-                    let result = Self::evaluate_binary_operator(lhs, rhs_expr.op, rhs)?;
-                    // SAFETY: Both `lhs` and `rhs` were consumed by `evaluate_binary_operator`.
-                    let result = unsafe {
-                        core::mem::transmute::<MaybeReferenceValue<'_>, MaybeReferenceValue<'eval>>(
-                            result,
-                        )
-                    };
-                    value = result;
-                }
-                Ok(value)
-            }
-        }
-    }
-
-    fn evaluate_formatted_string<'eval, 'program>(
-        &'eval self,
-        context: &'eval mut EvaluationContext,
-        formatted_string: &'program tree::FormattedString,
-    ) -> Result<MaybeReferenceValue<'eval>>
-    where
-        'program: 'eval,
-    {
-        let args = self.resolve_values(context, &formatted_string.args)?;
-        let template = formatted_string.template.0.as_str();
+    fn evaluate_formatted_string(template: &[FormatSegment], args: &[Value]) -> Result<String> {
         let mut string = String::new();
-        string.reserve(template.len());
-
-        let mut group = String::new();
-        let mut group_start = None;
         let mut next_arg_index = 0;
-
-        for (i, c) in template.char_indices() {
-            match c {
-                '{' => {
-                    if i > 0 && group_start == Some(i - 1) {
-                        // Two left brackets in a row result in an escape.
-                        group_start = None;
-                        string.push(c);
-                    } else {
-                        // Open a new group.
-                        group_start = Some(i);
-                    }
-                }
-                '}' if group_start.is_some() => {
-                    if group.is_empty() {
-                        // Use next positional argument.
-                        let next_arg = args
-                            .get(next_arg_index)
-                            .wrap_expectation_with_format(format_args!("formatted string is missing positional argument for index {next_arg_index}"))?;
-                        next_arg_index += 1;
-                        group = MaybeReferenceValueForOperation::from(next_arg)
-                            .for_formatted_string()?;
-                    } else {
-                        return Err(general_error(format!("invalid format group: {group}")));
-                    }
-
-                    // Add the replaced group to the string.
-                    string.push_str(&group);
-
-                    // Reset the state, since the group was closed.
-                    group_start = None;
-                    group.clear();
-                }
-                _ => {
-                    if group_start.is_some() {
-                        group.push(c);
-                    } else {
-                        string.push(c);
-                    }
+        for segment in template {
+            match segment {
+                FormatSegment::Text(text) => string.push_str(text),
+                FormatSegment::Argument => {
+                    // Use next positional argument.
+                    let next_arg = args
+                        .get(next_arg_index)
+                        .wrap_expectation_with_format(format_args!("formatted string is missing positional argument for index {next_arg_index}"))?;
+                    next_arg_index += 1;
+                    string.push_str(
+                        &MaybeReferenceValueForOperation::from(next_arg).for_formatted_string()?,
+                    );
                 }
-            }
-        }
-
-        Ok(MaybeReferenceValue::String(string))
-    }
-
-    fn resolve_value<'eval, 'program>(
-        &'eval self,
-        context: &'eval mut EvaluationContext,
-        value: &'program tree::Value,
-    ) -> Result<MaybeReferenceValue<'eval>>
-    where
-        'program: 'eval,
-    {
-        match value {
-            tree::Value::UndefinedLiteral => Ok(MaybeReferenceValue::Undefined),
-            tree::Value::BoolLiteral(bool) => Ok(MaybeReferenceValue::Boolean(bool.0)),
-            tree::Value::NumberLiteral(tree::NumberLiteral::Unsigned(number)) => {
-                Ok(MaybeReferenceValue::UFraction(*number))
-            }
-            tree::Value::NumberLiteral(tree::NumberLiteral::Signed(number)) => {
-                Ok(MaybeReferenceValue::Fraction(*number))
-            }
-            tree::Value::StringLiteral(string) => Ok(MaybeReferenceValue::String(string.0.clone())),
-            tree::Value::List(list) => Ok(MaybeReferenceValue::List(
-                self.resolve_values(context, &list.0)?,
-            )),
-            tree::Value::Var(var) => {
-                let var = self.create_var(var)?;
-                Ok(MaybeReferenceValue::from(var.get(context)?))
-            }
-            tree::Value::ValueExpr(expr) => Ok(MaybeReferenceValue::from(
-                self.evaluate_expr(context, &expr.0)?,
-            )),
-            tree::Value::ValueFunctionCall(function_call) => {
-                match self.evaluate_function_call(context, &function_call.0)? {
-                    Some(value) => Ok(MaybeReferenceValue::from(value)),
-                    None => Ok(MaybeReferenceValue::Undefined),
+                FormatSegment::InvalidGroup(group) => {
+                    return Err(general_error(format!("invalid format group: {group}")));
                 }
             }
-            tree::Value::ValueAssignment(assignment) => {
-                let value = self.evaluate_expr(context, &assignment.0.rhs)?;
-                // SAFETY: The value produced by the expression is either a new value or a reference
-                // to a value in the battle/evaluation state. If it refers to the variable being
-                // assigned to, the variable registry's runtime borrow checking will catch the
-                // conflict. Transmuting allows us to temporarily decouple the value's lifetime from
-                // context's mutable borrow, so that context can be mutably borrowed again in
-                // assign_var.
-                let value_to_assign = unsafe {
-                    core::mem::transmute::<MaybeReferenceValue<'_>, MaybeReferenceValue<'_>>(value)
-                };
-                let assigned_value =
-                    self.assign_var_and_reborrow(context, &assignment.0.lhs, value_to_assign)?;
-                Ok(assigned_value)
-            }
-            tree::Value::FormattedString(formatted_string) => {
-                self.evaluate_formatted_string(context, formatted_string)
-            }
         }
+        Ok(string)
     }
 
-    fn resolve_values<'eval, 'program>(
-        &'eval self,
-        context: &'eval mut EvaluationContext,
-        values: &'program tree::Values,
-    ) -> Result<Vec<MaybeReferenceValue<'eval>>>
-    where
-        'program: 'eval,
-    {
-        let mut resolved = Vec::new();
-        for value in &values.0 {
-            // SAFETY: It is safe to have an immutable reference into the battle state. The
-            // context is not really borrowed mutably.
-            let value = self.resolve_value(context, value)?;
-            let value = unsafe {
-                core::mem::transmute::<MaybeReferenceValue<'_>, MaybeReferenceValue<'eval>>(value)
-            };
-            resolved.push(value);
-        }
-        Ok(resolved)
+    fn load_var(
+        context: &mut EvaluationContext,
+        vars: &VariableRegistry,
+        access: &VariableAccess,
+    ) -> Result<Value> {
+        let value = vars.get(access.slot)?;
+        Ok(Variable::new(Some(value), &access.member_access)
+            .get(context)?
+            .to_owned())
     }
 
-    fn assign_var<'eval, 'program>(
-        &'eval self,
-        context: &'eval mut EvaluationContext,
-        var: &'program tree::Var,
-        value: MaybeReferenceValue<'eval>,
-    ) -> Result<()>
-    where
-        'program: 'eval,
-    {
-        // Drop the reference as soon as possible, because holding it might block a mutable
-        // reference to what we want to assign to.
-        //
-        // For instance, assigning one property of an object to another property on the same
-        // object results in a borrow error without this drop.
-        let owned_value = value.to_owned();
-        drop(value);
-
-        let mut runtime_var = self.create_var_mut(var)?;
+    fn assign_var(
+        context: &mut EvaluationContext,
+        program: &CompiledProgram,
+        vars: &VariableRegistry,
+        access: &VariableAccess,
+        value: Value,
+    ) -> Result<()> {
+        let stored = vars.get_mut(access.slot)?;
+        let mut runtime_var = VariableMut::new(stored, &access.member_access);
         let runtime_var_ref = runtime_var.get_mut(context)?;
 
         runtime_var_ref
-            .assign(owned_value)
-            .wrap_error_with_format(format_args!("failed to assign to ${}", var.full_name()))
-    }
-
-    fn assign_var_and_reborrow<'eval, 'program>(
-        &'eval self,
-        context: &'eval mut EvaluationContext,
-        var: &'program tree::Var,
-        value: MaybeReferenceValue<'eval>,
-    ) -> Result<MaybeReferenceValue<'eval>>
-    where
-        'program: 'eval,
-    {
-        self.assign_var(context, var, value)?;
-        let var = self.create_var(var)?;
-        Ok(MaybeReferenceValue::from(var.get(context)?))
-    }
-
-    fn create_var<'eval, 'program>(
-        &'eval self,
-        var: &'program tree::Var,
-    ) -> Result<Variable<'eval, 'program>>
-    where
-        'program: 'eval,
-    {
-        let value = self.vars.get(&var.name.0)?;
-        let member_access = var
-            .member_access
-            .iter()
-            .map(|ident| ident.0.as_str())
-            .collect();
-        Ok(Variable::new(value, member_access))
-    }
-
-    fn create_var_mut<'eval, 'program>(
-        &'eval self,
-        var: &'program tree::Var,
-    ) -> Result<VariableMut<'eval, 'program>>
-    where
-        'program: 'eval,
-    {
-        let value = match self.vars.get_mut(&var.name.0)? {
-            None => {
-                self.vars.set(&var.name.0, Value::Undefined)?;
-                self.vars
-                    .get_mut(&var.name.0)?
-                    .wrap_expectation_with_format(format_args!(
-                        "variable ${} is undefined even after initialization",
-                        var.name.0
-                    ))?
-            }
-            Some(value) => value,
-        };
-        let member_access = var
-            .member_access
-            .iter()
-            .map(|ident| ident.0.as_str())
-            .collect();
-        Ok(VariableMut::new(value, member_access))
+            .assign(value)
+            .wrap_error_with_format(format_args!(
+                "failed to assign to ${}",
+                Self::full_name(program, access)
+            ))
     }
 }
@@ -38,6 +38,11 @@ use hashbrown::{
     HashMap,
     HashSet,
 };
+use itertools::Itertools;
+use serde::{
+    Deserialize,
+    Serialize,
+};
 
 use crate::{
     battle::{
@@ -64,17 +69,20 @@ use crate::{
         core_battle_effects,
         core_battle_logs,
         mon_states,
+        raid,
     },
     battle_log_entry,
     effect::{
         AppliedEffectHandle,
         AppliedEffectLocation,
         EffectHandle,
+        EffectManager,
         MonAbilityEffectStateConnector,
         MonStatusEffectStateConnector,
         MonVolatileStatusEffectStateConnector,
         PseudoWeatherEffectStateConnector,
         SideConditionEffectStateConnector,
+        SubroutineCall,
         fxlang::{
             BattleEvent,
             DynamicEffectStateConnector,
@@ -99,18 +107,1848 @@ use crate::{
     },
 };
 
+/// The implementation of a built-in fxlang function.
+type BuiltinFunctionImpl = fn(FunctionContext<'_, '_, '_, '_, '_>) -> Result<Option<Value>>;
+
+/// A row of the table of built-in fxlang functions.
+struct BuiltinFunctionEntry {
+    name: &'static str,
+    implementation: BuiltinFunctionImpl,
+    signature: FunctionSignature,
+    doc: &'static [&'static str],
+}
+
+macro_rules! builtin_functions {
+    ($(
+        $(#[doc = $doc:literal])*
+        $name:ident($required_args:literal, $returns_value:literal) => $implementation:expr,
+    )*) => {
+        /// All built-in fxlang functions, sorted by name.
+        const BUILTIN_FUNCTIONS: &[BuiltinFunctionEntry] = &[$(
+            BuiltinFunctionEntry {
+                name: stringify!($name),
+                implementation: $implementation,
+                signature: FunctionSignature {
+                    required_args: $required_args,
+                    returns_value: $returns_value,
+                },
+                doc: &[$($doc,)*],
+            },
+        )*];
+    };
+}
+
+// Each function is written as `name(required_args, returns_value) => implementation`, documented
+// for data authors by its doc comment.
+//
+// Maintain alphabetical order, so that functions can be resolved by binary search.
+builtin_functions! {
+    /// Gets the effect state for an ability.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon whose ability state to retrieve. Defaults to the
+    /// current source.
+    /// @returns {[`ValueType::EffectState`] | [`ValueType::Undefined`]}
+    ability_effect_state(0, true) => ability_effect_state,
+    /// Returns whether the ability has the given flag.
+    ///
+    /// @param {[`ValueType::String`] | [`ValueType::Effect`]} ability_handle The ability to check.
+    /// @param {[`ValueType::String`]} flag The flag ID to check.
+    /// @returns {[`ValueType::Boolean`]}
+    ability_has_flag(2, true) => |context| {
+        ability_has_flag(context).map(|val| Some(val))
+    },
+    /// Activates a Mon's ability.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon whose ability to activate. Defaults to the current
+    /// source.
+    /// @returns {[`ValueType::Boolean`] | [`ValueType::Undefined`]}
+    activate_ability(0, true) => activate_ability,
+    /// Activates an applying effect.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to modify.
+    /// @param {[`ValueType::String`] | [`ValueType::Effect`]} effect The effect ID.
+    /// @returns {[`ValueType::Boolean`] | [`ValueType::Undefined`]}
+    activate_applying_effect(1, true) => activate_applying_effect,
+    /// Adds an attribute to the last move used.
+    ///
+    /// @param {[`ValueType::Effect`]} [active_move] The active move.
+    /// @param {[`ValueType::String`]} attribute The attribute to add.
+    add_attribute_to_last_move(1, false) => |context| {
+        add_attribute_to_last_move(context).map(|()| None)
+    },
+    /// Adds an attribute and value to the last move used.
+    ///
+    /// @param {[`ValueType::Effect`]} [active_move] The active move.
+    /// @param {[`ValueType::String`]} attribute The attribute key to add.
+    /// @param {[`ValueType::String`]} value The value to add.
+    add_attribute_value_to_last_move(2, false) => |context| {
+        add_attribute_value_to_last_move(context).map(|()| None)
+    },
+    /// Adds a move action to the battle queue.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon performing the move.
+    /// @param {[`ValueType::String`] | [`ValueType::Effect`]} move_handle The move to perform.
+    /// @param {[`ValueType::Mon`]} [target] The target of the move.
+    /// @returns {[`ValueType::Boolean`]} Whether the action was successfully added.
+    add_move_action(1, true) => |context| {
+        add_move_action(context).map(|val| Some(val))
+    },
+    /// Adds a flag to a move.
+    ///
+    /// @param {[`ValueType::Effect`]} [move_handle] The move to modify.
+    /// @param {[`ValueType::String`]} flag The flag to add.
+    add_move_flag(1, false) => |context| {
+        add_move_flag(context).map(|()| None)
+    },
+    /// Adds pseudo-weather to the field.
+    ///
+    /// @param {[`ValueType::String`]} weather The pseudo-weather ID.
+    /// @returns {[`ValueType::EventResult`]} Whether the pseudo-weather was successfully added.
+    add_pseudo_weather(1, true) => |context| {
+        add_pseudo_weather(context).map(|val| Some(val))
+    },
+    /// Adds shields to a raid boss.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The raid boss.
+    /// @param {[`ValueType::UFraction`]} shields The number of shields to add.
+    /// @returns {[`ValueType::Boolean`]} Whether the shields were added.
+    add_raid_shields(1, true) => |context| {
+        add_raid_shields(context).map(|val| Some(val))
+    },
+    /// Adds a secondary effect to a move.
+    ///
+    /// @param {[`ValueType::Effect`]} [move_handle] The move to modify.
+    /// @param {[`ValueType::SecondaryHitEffect`]} effect The secondary effect to add.
+    add_secondary_effect_to_move(1, false) => |context| {
+        add_secondary_effect_to_move(context).map(|()| None)
+    },
+    /// Adds a side condition to a side.
+    ///
+    /// @param {[`ValueType::Side`]} [side] The side to modify.
+    /// @param {[`ValueType::String`]} [condition] The condition effect ID.
+    /// @returns {[`ValueType::EventResult`]} Whether the condition was added.
+    add_side_condition(0, true) => |context| {
+        add_side_condition(context).map(|val| Some(val))
+    },
+    /// Adds a slot condition.
+    ///
+    /// @param {[`ValueType::Side`]} [side] The side to modify.
+    /// @param {[`ValueType::UFraction`]} slot The slot index.
+    /// @param {[`ValueType::String`]} condition The slot condition ID.
+    /// @returns {[`ValueType::EventResult`]} Whether the slot condition was successfully added.
+    add_slot_condition(2, true) => |context| {
+        add_slot_condition(context).map(|val| Some(val))
+    },
+    /// Adds a sub-ability to a Mon's ability.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to modify.
+    /// @param {[`ValueType::String`] | [`ValueType::Effect`]} ability The sub-ability ID.
+    /// @returns {[`ValueType::EventResult`]} Whether the sub-ability was successfully added.
+    add_sub_ability(1, true) => |context| {
+        add_sub_ability(context).map(|val| Some(val))
+    },
+    /// Adds a type to a Mon.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to modify.
+    /// @param {[`ValueType::Type`]} type_to_add The type to add.
+    /// @returns {[`ValueType::EventResult`]} Whether the type was successfully added.
+    add_type(1, true) => |context| add_type(context).map(|val| Some(val)),
+    /// Adds a volatile effect to a Mon.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to modify.
+    /// @param {[`ValueType::String`] | [`ValueType::Effect`]} [effect] The volatile effect ID.
+    /// @flag link If set, the volatile effect is linked to the current effect.
+    /// @returns {[`ValueType::EventResult`]} Whether the volatile effect was added.
+    add_volatile(0, true) => |context| {
+        add_volatile(context).map(|val| Some(val))
+    },
+    /// Gets all adjacent allies of a Mon.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The reference Mon.
+    /// @returns {[`ValueType::List`]} A list of adjacent ally Mons.
+    /// @returnsitem {[`ValueType::Mon`]}
+    adjacent_allies(0, true) => |context| {
+        adjacent_allies(context).map(|val| Some(val))
+    },
+    /// Gets all adjacent foes of a Mon.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The reference Mon.
+    /// @returns {[`ValueType::List`]} A list of adjacent foe Mons.
+    /// @returnsitem {[`ValueType::Mon`]}
+    adjacent_foes(0, true) => |context| {
+        adjacent_foes(context).map(|val| Some(val))
+    },
+    /// Gets all active Mons in the battle.
+    ///
+    /// @returns {[`ValueType::List`]} A list of all active Mons.
+    /// @returnsitem {[`ValueType::Mon`]}
+    all_active_mons(0, true) => |context| {
+        all_active_mons(context).map(|val| Some(val))
+    },
+    /// Gets all active Mons in speed order.
+    ///
+    /// @returns {[`ValueType::List`]} A list of active Mons sorted by speed.
+    /// @returnsitem {[`ValueType::Mon`]}
+    all_active_mons_in_speed_order(0, true) => |context| {
+        all_active_mons_in_speed_order(context).map(|val| Some(val))
+    },
+    /// Gets all active Mons in speed and ability effect order.
+    ///
+    /// @returns {[`ValueType::List`]} A list of active Mons in speed and ability order.
+    /// @returnsitem {[`ValueType::Mon`]}
+    all_active_mons_in_speed_order_and_ability_effect_order(0, true) => |context| {
+        all_active_mons_in_speed_order_and_ability_effect_order(context).map(|val| Some(val))
+    },
+    /// Gets all active Mons on a specific side.
+    ///
+    /// @param {[`ValueType::Side`]} [side] The side to query. Defaults to the current side.
+    /// @returns {[`ValueType::List`]} A list of active Mons on the side.
+    /// @returnsitem {[`ValueType::Mon`]}
+    all_active_mons_on_side(0, true) => |context| {
+        all_active_mons_on_side(context).map(|val| Some(val))
+    },
+    /// Gets all active or recently exited Mons.
+    ///
+    /// @returns {[`ValueType::List`]} A list of active or exited Mons.
+    /// @returnsitem {[`ValueType::Mon`]}
+    all_active_or_exited_mons(0, true) => |context| {
+        all_active_or_exited_mons(context).map(|val| Some(val))
+    },
+    /// Gets all foes of a Mon.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The reference Mon.
+    /// @returns {[`ValueType::List`]} A list of foe Mons.
+    /// @returnsitem {[`ValueType::Mon`]}
+    all_foes(0, true) => |context| all_foes(context).map(|val| Some(val)),
+    /// Gets all Mons in a player's party.
+    ///
+    /// @param {[`ValueType::Player`]} player The player.
+    /// @returns {[`ValueType::List`]} A list of Mons in the party.
+    /// @returnsitem {[`ValueType::Mon`]}
+    all_mons_in_party(1, true) => |context| {
+        all_mons_in_party(context).map(|val| Some(val))
+    },
+    /// Gets all Mons on a specific side, including those in the party.
+    ///
+    /// @param {[`ValueType::Side`]} [side] The side to query. Defaults to the current side.
+    /// @returns {[`ValueType::List`]} A list of all Mons on the side.
+    /// @returnsitem {[`ValueType::Mon`]}
+    all_mons_on_side(0, true) => |context| {
+        all_mons_on_side(context).map(|val| Some(val))
+    },
+    /// Gets a list of all defined types in the battle.
+    ///
+    /// @returns {[`ValueType::List`]} A list of type IDs.
+    /// @returnsitem {[`ValueType::Type`]}
+    all_types(0, true) => |context| {
+        all_types(context).map(|val| Some(val))
+    },
+    /// Gets all allies of a Mon and the Mon itself.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The reference Mon.
+    /// @returns {[`ValueType::List`]} A list of ally Mons including the reference Mon.
+    /// @returnsitem {[`ValueType::Mon`]}
+    allies_and_self(0, true) => |context| {
+        allies_and_self(context).map(|val| Some(val))
+    },
+    /// Checks if any Mon will perform a move this turn.
+    ///
+    /// @returns {[`ValueType::Boolean`]} Whether any Mon will move.
+    any_mon_will_move_this_turn(0, true) => |context| {
+        any_mon_will_move_this_turn(context).map(|val| Some(val))
+    },
+    /// Appends an element to a list.
+    ///
+    /// @param {[`ValueType::List`]} list The list to modify.
+    /// @param {[`ValueType::Object`]} element The element to append.
+    /// @returns {[`ValueType::List`]} The modified list.
+    append(2, true) => |context| append(context).map(|val| Some(val)),
+    /// Applies drain effects (healing based on damage dealt).
+    ///
+    /// @param {[`ValueType::Mon`]} [target] The target Mon.
+    /// @param {[`ValueType::Mon`]} [source] The source Mon.
+    /// @param {[`ValueType::UFraction`]} damage The amount of damage healed.
+    apply_drain(1, true) => |context| {
+        apply_drain(context).map(|val| Some(val))
+    },
+    /// Applies recoil damage to the move user.
+    ///
+    /// @param {[`ValueType::UFraction`]} damage The amount of damage dealt to the target.
+    /// @returns {[`ValueType::UFraction`]} Damage dealt.
+    apply_recoil_damage(1, true) => |context| {
+        apply_recoil_damage(context).map(|val| Some(val))
+    },
+    /// Gets a Mon's base species ID.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to query.
+    /// @returns {[`ValueType::String`]} The base species ID.
+    base_species(0, true) => |context| {
+        base_species(context).map(|val| Some(val))
+    },
+    /// Gets a Mon's best stat.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to query.
+    /// @flag unboosted Ignore boosts.
+    /// @flag unmodified Ignore effect modifiers.
+    /// @returns {[`ValueType::Stat`]} The best stat.
+    best_stat(0, true) => |context| {
+        best_stat(context).map(|val| Some(val))
+    },
+    /// Boosts or unboosts a Mon's stats.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to boost.
+    /// @param {[`ValueType::BoostTable`]} boosts The boosts to apply.
+    /// @returns {[`ValueType::EventResult`]} The outcome of the boost.
+    boost(1, true) => |context| boost(context).map(|val| Some(val)),
+    /// Creates a boost table from a list of stat:amount strings.
+    ///
+    /// @param {[`ValueType::String`]} ... Boost strings (e.g., "atk:1").
+    /// @returns {[`ValueType::Object`]} The created boost table.
+    boost_table(0, true) => |context| {
+        boost_table(context).map(|val| Some(val))
+    },
+    /// Returns a list of all boostable stats.
+    ///
+    /// @returns {[`ValueType::List`]} A list of stats.
+    /// @returnsitem {[`ValueType::Boost`]}
+    boostable_stats(0, true) => |_| Ok(Some(boostable_stats())),
+    /// Calculates the base damage of a move.
+    ///
+    /// @param {[`ValueType::UFraction`]} level The user level.
+    /// @param {[`ValueType::UFraction`]} base_power The move base_power.
+    /// @param {[`ValueType::UFraction`]} attack The user attack stat.
+    /// @param {[`ValueType::UFraction`]} defense The target defense stat.
+    /// @returns {[`ValueType::UFraction`]} Base damage.
+    calculate_base_damage(4, true) => |context| {
+        calculate_base_damage(context).map(|val| Some(val))
+    },
+    /// Calculates confusion damage for a Mon.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to damage.
+    /// @param {[`ValueType::UFraction`]} base_power The base power of the confusion attack.
+    /// @returns {[`ValueType::UFraction`]} The calculated confusion damage.
+    calculate_confusion_damage(1, true) => |context| {
+        calculate_confusion_damage(context).map(|val| Some(val))
+    },
+    /// Calculates damage for a move.
+    ///
+    /// @param {[`ValueType::Mon`]} [target] The move target.
+    /// @returns {[`ValueType::UFraction`]} The calculated damage amount.
+    calculate_damage(0, true) => |context| {
+        calculate_damage(context).map(|val| Some(val))
+    },
+    /// Checks if a Mon can receive a boost.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to check.
+    /// @param {[`ValueType::BoostTable`]} boosts The boosts to check.
+    /// @returns {[`ValueType::Boolean`]} Whether the boosts can be received.
+    can_boost(1, true) => |context| {
+        can_boost(context).map(|val| Some(val))
+    },
+    /// Checks if a Mon can escape from battle.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to check.
+    /// @returns {[`ValueType::Boolean`]} Whether the Mon can escape.
+    can_escape(0, true) => |context| {
+        can_escape(context).map(|val| Some(val))
+    },
+    /// Checks if a Mon can switch out.
+    ///
+    /// @param {[`ValueType::Player`]} player The player whose Mon to check.
+    /// @returns {[`ValueType::Boolean`]} Whether the Mon can switch.
+    can_switch(1, true) => |context| {
+        can_switch(context).map(|val| Some(val))
+    },
+    /// Cancels a Mon's pending action.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon whose action to cancel.
+    /// @returns {[`ValueType::Boolean`]} Whether the action was cancelled.
+    cancel_action(0, true) => |context| {
+        cancel_action(context).map(|val| Some(val))
+    },
+    /// Cancels a Mon's pending move.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon whose move to cancel.
+    /// @returns {[`ValueType::Boolean`]} Whether the move was cancelled.
+    cancel_move(0, true) => |context| {
+        cancel_move(context).map(|val| Some(val))
+    },
+    /// Performs a random chance check.
+    ///
+    /// @param {[`ValueType::UFraction`]} numerator The numerator of the probability.
+    /// @param {[`ValueType::UFraction`]} [denominator] The denominator of the probability.
+    /// @returns {[`ValueType::Boolean`]} Whether the check succeeded.
+    chance(1, true) => |context| chance(context).map(|val| Some(val)),
+    /// Checks if a Mon is immune to an effect.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to check.
+    /// @param {[`ValueType::String`] | [`ValueType::Effect`]} effect The effect ID to check.
+    /// @returns {[`ValueType::Boolean`]} Whether the Mon is immune.
+    check_immunity(1, true) => |context| {
+        check_immunity(context).map(|val| Some(val))
+    },
+    /// Checks if a Mon is immune to a move.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to check.
+    /// @param {[`ValueType::ActiveMove`]} move The move to check.
+    /// @returns {[`ValueType::Boolean`]} Whether the Mon is immune.
+    check_move_immunity(1, true) => |context| {
+        check_move_immunity(context).map(|val| Some(val))
+    },
+    /// Clamps a number between a minimum and maximum value.
+    ///
+    /// @param {[`ValueType::UFraction`]} value The value to clamp.
+    /// @param {[`ValueType::UFraction`]} min The minimum value.
+    /// @param {[`ValueType::UFraction`]} max The maximum value.
+    /// @returns {[`ValueType::UFraction`]} The clamped value.
+    clamp_number(3, true) => |context| {
+        clamp_number(context).map(|val| Some(val))
+    },
+    /// Gets the integer value of a battle clause.
+    ///
+    /// @param {[`ValueType::String`] | [`ValueType::Effect`]} clause The clause ID.
+    /// @returns {[`ValueType::UFraction`] | [`ValueType::Undefined`]} The clause value.
+    clause_integer_value(1, true) => clause_integer_value,
+    /// Gets the type value of a battle clause.
+    ///
+    /// @param {[`ValueType::String`] | [`ValueType::Effect`]} clause The clause ID.
+    /// @returns {[`ValueType::Type`] | [`ValueType::Undefined`]} The clause value.
+    clause_type_value(1, true) => clause_type_value,
+    /// Clears all boosts from a Mon.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to clear.
+    /// @flag silent If set, no message is displayed.
+    clear_boosts(0, false) => |context| {
+        clear_boosts(context).map(|()| None)
+    },
+    /// Clears negative boosts from a Mon.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to clear.
+    clear_negative_boosts(0, false) => |context| {
+        clear_negative_boosts(context).map(|()| None)
+    },
+    /// Clears positive boosts from a Mon.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to clear.
+    clear_positive_boosts(0, false) => |context| {
+        clear_positive_boosts(context).map(|()| None)
+    },
+    /// Clears all sub-abilities from a Mon's ability.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to modify.
+    /// @returns {[`ValueType::EventResult`]} Whether the sub-ability was successfully added.
+    clear_sub_abilities(0, true) => |context| {
+        clear_sub_abilities(context).map(|val| Some(val))
+    },
+    /// Clears the field terrain.
+    ///
+    /// @returns {[`ValueType::EventResult`]} Whether the terrain was successfully cleared.
+    clear_terrain(0, true) => |context| {
+        clear_terrain(context).map(|val| Some(val))
+    },
+    /// Clears the field weather.
+    ///
+    /// @returns {[`ValueType::EventResult`]} Whether the weather was successfully cleared.
+    clear_weather(0, true) => |context| {
+        clear_weather(context).map(|val| Some(val))
+    },
+    /// Clones the currently active move.
+    ///
+    /// @param {[`ValueType::Effect`]} active_move The active move to clone.
+    /// @param {[`ValueType::Mon`]} user The Mon using the cloned move.
+    /// @returns {[`ValueType::ActiveMove`]} The cloned active move.
+    clone_active_move(2, true) => |context| {
+        clone_active_move(context).map(|val| Some(val))
+    },
+    /// Copies boosts (and associated volatiles) from the target Mon.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to copy boosts to.
+    /// @param {[`ValueType::Mon`]} target The Mon to copy boosts from.
+    /// @flag silent If set, no message is displayed.
+    /// @returns {[`ValueType::EventResult`]} The outcome of the copy.
+    copy_boosts(1, true) => |context| {
+        copy_boosts(context).map(|val| Some(val))
+    },
+    /// Cures a Mon's status condition.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to cure.
+    /// @returns {[`ValueType::EventResult`]} Whether the status was cured.
+    cure_status(0, true) => |context| {
+        cure_status(context).map(|val| Some(val))
+    },
+    /// Deals damage to a Mon.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to damage.
+    /// @param {[`ValueType::UFraction`]} amount The amount of damage.
+    /// @param {[`ValueType::Effect`]} [effect] The effect causing the damage.
+    /// @returns {[`ValueType::UFraction`]} The amount of damage dealt, rounded up to the nearest
+    /// whole number.
+    damage(1, true) => |context| damage(context).map(|val| Some(val)),
+    /// Logs a debug message to the battle log.
+    ///
+    /// @param {[`ValueType::String`]} message The message to log.
+    debug_log(1, false) => |context| debug_log(context).map(|()| None),
+    /// Decreases a Mon's friendship.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to modify.
+    /// @param {[`ValueType::UFraction`]} delta_1 The friendship decrease at level 1.
+    /// @param {[`ValueType::UFraction`]} delta_2 The friendship decrease at level 2.
+    /// @param {[`ValueType::UFraction`]} delta_3 The friendship decrease at level 3.
+    decrease_friendship(3, false) => |context| {
+        decrease_friendship(context).map(|()| None)
+    },
+    /// Decreases a Mon's weight.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to modify.
+    /// @param {[`ValueType::UFraction`]} amount The amount to decrease.
+    decrease_weight(1, false) => |context| {
+        decrease_weight(context).map(|()| None)
+    },
+    /// Deducts PP from a Mon's move.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon whose move to modify.
+    /// @param {[`ValueType::String`] | [`ValueType::Effect`]} [move_handle] The move to deduct
+    /// from.
+    /// @param {[`ValueType::UFraction`]} amount The amount of PP to deduct.
+    /// @returns {[`ValueType::Boolean`]} Whether PP was successfully deducted.
+    deduct_pp(1, true) => |context| {
+        deduct_pp(context).map(|val| Some(val))
+    },
+    /// Deprioritizes a Mon's move.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon whose move to deprioritize.
+    /// @param {[`ValueType::UFraction`]} [action_id] The action ID to deprioritize.
+    deprioritize_move(0, false) => |context| {
+        deprioritize_move(context).map(|()| None)
+    },
+    /// Deals direct damage to a Mon (bypasses most calculations).
+    ///
+    /// @param {[`ValueType::Mon`]} [target] The Mon to damage.
+    /// @param {[`ValueType::UFraction`]} amount The amount of damage.
+    /// @param {[`ValueType::Effect`]} [damaging_effect] The effect causing the damage.
+    direct_damage(1, false) => |context| {
+        direct_damage(context).map(|()| None)
+    },
+    /// Disables a move for a Mon.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon whose move is disabled.
+    /// @param {[`ValueType::String`] | [`ValueType::Effect`]} move_id The move ID to disable.
+    disable_move(1, false) => |context| {
+        disable_move(context).map(|()| None)
+    },
+    /// Makes a Mon discard its held item.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon whose item to discard.
+    /// @returns {[`ValueType::Boolean`]} Whether the item was discarded.
+    discard_item(0, true) => |context| {
+        discard_item(context).map(|val| Some(val))
+    },
+    /// Executes a move.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon using the move.
+    /// @param {[`ValueType::String`] | [`ValueType::Effect`]} move_id The move ID to execute.
+    /// @param {[`ValueType::UFraction`]} [target_position] The target field position.
+    /// @param {[`ValueType::Mon`]} [target] The target Mon.
+    do_move(1, false) => |context| do_move(context).map(|()| None),
+    /// Prevents the last move from being animated.
+    ///
+    /// @param {[`ValueType::Effect`]} [active_move] The active move.
+    do_not_animate_last_move(0, false) => |context| {
+        do_not_animate_last_move(context).map(|()| None)
+    },
+    /// Makes a Mon eat a specific item.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to eat.
+    /// @param {[`ValueType::String`]} item The item ID to eat.
+    /// @returns {[`ValueType::EventResult`]} Whether the item was eaten.
+    eat_given_item(1, true) => |context| {
+        eat_given_item(context).map(|val| Some(val))
+    },
+    /// Makes a Mon eat its held item.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to eat.
+    /// @flag force If set, the consumption cannot be stopped.
+    /// @returns {[`ValueType::EventResult`]} Whether the item was eaten.
+    eat_item(0, true) => |context| eat_item(context).map(|val| Some(val)),
+    /// Checks if an effect has a specific event callback.
+    ///
+    /// @param {[`ValueType::Effect`]} effect The effect handle.
+    /// @param {[`ValueType::String`]} event The event ID.
+    /// @returns {[`ValueType::Boolean`]} Whether the effect has the callback.
+    effect_has_event_callback(2, true) => |context| {
+        effect_has_event_callback(context).map(|val| Some(val))
+    },
+    /// Removes a key from an effect state object.
+    ///
+    /// @param {[`ValueType::EffectState`]} effect_state The effect state to modify.
+    /// @param {[`ValueType::String`]} key The key to remove.
+    /// @returns {[`ValueType::Any`]} The value that was present, if any.
+    effect_state_remove_key(2, true) => effect_state_remove_key,
+    /// Gets the effective weather for a Mon.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to check.
+    /// @param {[`ValueType::Mon`]} [origin] The origin Mon.
+    /// @returns {[`ValueType::Effect`] | [`ValueType::Undefined`]} The effective weather.
+    effective_weather(0, true) => effective_weather,
+    /// Ends a Mon's ability.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to modify.
+    end_ability(0, false) => |context| end_ability(context).map(|()| None),
+    /// Ends the current battle immediately.
+    ///
+    /// @param {[`ValueType::Side`]} [winning_side] The winning side index.
+    end_battle(0, false) => |context| end_battle(context).map(|()| None),
+    /// Ends a Mon's Dynamax state.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon whose Dynamax to end.
+    end_dynamax(0, false) => |context| end_dynamax(context).map(|()| None),
+    /// Ends a Mon's illusion.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon whose illusion to end.
+    /// @returns {[`ValueType::EventResult`]} Whether the illusion was successfully ended.
+    end_illusion(0, true) => |context| {
+        end_illusion(context).map(|val| Some(val))
+    },
+    /// Ends a Mon's item.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to modify.
+    end_item(0, false) => |context| end_item(context).map(|()| None),
+    /// Makes a Mon escape from battle.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to escape.
+    /// @returns {[`ValueType::EventResult`]} Whether the Mon escaped.
+    escape(0, true) => |context| escape(context).map(|val| Some(val)),
+    /// Converts a value to an EventResult.
+    ///
+    /// @param {[`ValueType::Boolean`] | [`ValueType::String`]} value Value.
+    /// @returns {[`ValueType::EventResult`]} EventResult.
+    event_result(1, true) => |context| {
+        event_result(context).map(|val| Some(val))
+    },
+    /// Makes a Mon faint.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon that faints.
+    faint(0, false) => |context| faint(context).map(|()| None),
+    /// Processes faint messages for the battle.
+    faint_messages(0, false) => |context| {
+        faint_messages(context).map(|()| None)
+    },
+    /// Rounds a number down to the nearest whole number.
+    ///
+    /// @param {[`ValueType::Fraction`]} value The value to floor.
+    /// @returns {[`ValueType::UFraction`]} The floored value.
+    floor(1, true) => |context| floor(context).map(|val| Some(val)),
+    /// Forces a Mon to fully heal.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to heal.
+    force_fully_heal(0, false) => |context| {
+        force_fully_heal(context).map(|()| None)
+    },
+    /// Forces a Mon to switch out.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to switch.
+    /// @returns {[`ValueType::EventResult`]} Whether the Mon was forced to switch.
+    force_switch(0, true) => |context| {
+        force_switch(context).map(|val| Some(val))
+    },
+    /// Changes a Mon's forme.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to modify.
+    /// @param {[`ValueType::String`]} forme The new forme ID.
+    /// @flag permanent If set, the forme change is permanent.
+    /// @flag revertible If set with the `permanent` flag, the forme change will revert on exit.
+    /// ability.
+    /// @returns {[`ValueType::EventResult`]} Whether the forme was successfully changed.
+    forme_change(1, true) => |context| {
+        forme_change(context).map(|val| Some(val))
+    },
+    /// Gets an ability by ID.
+    ///
+    /// @param {[`ValueType::String`] | [`ValueType::Effect`]} ability_id The ability ID.
+    /// @returns {[`ValueType::Effect`] | [`ValueType::Undefined`]}
+    get_ability(1, true) => get_ability,
+    /// Gets all moves from the registry.
+    ///
+    /// @returns {[`ValueType::List`]} A list of all moves.
+    /// @returnsitem {[`ValueType::Effect`]}
+    get_all_moves(0, true) => |context| {
+        get_all_moves(context).map(|val| Some(val))
+    },
+    /// Gets a boost level from a boost table.
+    ///
+    /// @param {[`ValueType::BoostTable`]} boosts The boost table to query.
+    /// @param {[`ValueType::Stat`]} stat The stat to query.
+    /// @returns {[`ValueType::Fraction`]} The boost level.
+    get_boost(2, true) => |context| {
+        get_boost(context).map(|val| Some(val))
+    },
+    /// Gets an item by ID.
+    ///
+    /// @param {[`ValueType::String`] | [`ValueType::Effect`]} item_id The item ID.
+    /// @returns {[`ValueType::Effect`] | [`ValueType::Undefined`]}
+    get_item(1, true) => get_item,
+    /// Gets move data from the registry.
+    ///
+    /// @param {[`ValueType::String`] | [`ValueType::Effect`]} move_id The move ID.
+    /// @returns {[`ValueType::Effect`] | [`ValueType::Undefined`]} The move data.
+    get_move(1, true) => get_move,
+    /// Gets valid targets for a move.
+    ///
+    /// @param {[`ValueType::Effect`]} active_move The active move.
+    /// @param {[`ValueType::Mon`]} user The Mon using the move.
+    /// @param {[`ValueType::Mon`]} [target] A potential target.
+    /// @returns {[`ValueType::List`]} A list of valid target Mons.
+    /// @returnsitem {[`ValueType::Mon`]}
+    get_move_targets(2, true) => |context| {
+        get_move_targets(context).map(|val| Some(val))
+    },
+    /// Gets species data from the registry.
+    ///
+    /// @param {[`ValueType::String`] | [`ValueType::Effect`]} species_id The species ID.
+    /// @returns {[`ValueType::Effect`] | [`ValueType::Undefined`]} The species data.
+    get_species(1, true) => get_species,
+    /// Gets a Mon's stat value.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to query.
+    /// @param {[`ValueType::Stat`]} stat The stat to query.
+    /// @flag unboosted Ignore boosts.
+    /// @flag unmodified Ignore effect modifiers.
+    /// @returns {[`ValueType::UFraction`]} The stat value.
+    get_stat(1, true) => |context| get_stat(context).map(|val| Some(val)),
+    /// Checks if a Mon has a specific ability.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to check.
+    /// @param {[`ValueType::String`] | [`ValueType::Effect`]} ability The ability ID to check.
+    /// @returns {[`ValueType::Boolean`]} Whether the Mon has the ability.
+    has_ability(1, true) => |context| {
+        has_ability(context).map(|val| Some(val))
+    },
+    /// Checks if a Mon has any of the given types.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to check.
+    /// @param {[`ValueType::List`]} types The list of types to check.
+    /// @returns {[`ValueType::Boolean`]} Whether the Mon has any of the types.
+    has_any_type(1, true) => |context| {
+        has_any_type(context).map(|val| Some(val))
+    },
+    /// Checks if a Mon has a specific item.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to check.
+    /// @param {[`ValueType::String`] | [`ValueType::Effect`]} item The item ID to check.
+    /// @returns {[`ValueType::Boolean`]} Whether the Mon has the item.
+    has_item(1, true) => |context| has_item(context).map(|val| Some(val)),
+    /// Checks if a Mon has a specific move.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to check.
+    /// @param {[`ValueType::String`] | [`ValueType::Effect`]} move_id The move ID to check.
+    /// @returns {[`ValueType::Boolean`]} Whether the Mon has the move.
+    has_move(1, true) => |context| has_move(context).map(|val| Some(val)),
+    /// Checks if a pseudo-weather is active on the field.
+    ///
+    /// @param {[`ValueType::String`]} weather The pseudo-weather ID.
+    /// @returns {[`ValueType::Boolean`]} Whether the pseudo-weather is active.
+    has_pseudo_weather(1, true) => |context| {
+        has_pseudo_weather(context).map(|val| Some(val))
+    },
+    /// Checks if a side has a specific condition.
+    ///
+    /// @param {[`ValueType::Side`]} [side] The side to check.
+    /// @param {[`ValueType::String`]} condition The condition ID.
+    /// @returns {[`ValueType::Boolean`]} Whether the side has the condition.
+    has_side_condition(1, true) => |context| {
+        has_side_condition(context).map(|val| Some(val))
+    },
+    /// Checks if a Mon has a specific species registered.
+    ///
+    /// @param {[`ValueType::Player`]} player The player to check.
+    /// @param {[`ValueType::String`] | [`ValueType::Effect`]} species The species ID to check.
+    /// @returns {[`ValueType::Boolean`]} Whether the species is registered.
+    has_species_registered(2, true) => |context| {
+        has_species_registered(context).map(|val| Some(val))
+    },
+    /// Checks if a Mon has a specific type.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to check.
+    /// @param {[`ValueType::Type`]} target_type The type to check.
+    /// @returns {[`ValueType::Boolean`]} Whether the Mon has the type.
+    has_type(1, true) => |context| has_type(context).map(|val| Some(val)),
+    /// Checks if a Mon has a specific type before forced type changes.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to check.
+    /// @param {[`ValueType::Type`]} target_type The type to check.
+    /// @returns {[`ValueType::Boolean`]} Whether the Mon has the type.
+    has_type_before_forced_types(1, true) => |context| {
+        has_type_before_forced_types(context).map(|val| Some(val))
+    },
+    /// Checks if a Mon has a specific volatile effect.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to check.
+    /// @param {[`ValueType::String`] | [`ValueType::Effect`]} effect The volatile effect ID.
+    /// @returns {[`ValueType::Boolean`]} Whether the Mon has the volatile effect.
+    has_volatile(1, true) => |context| {
+        has_volatile(context).map(|val| Some(val))
+    },
+    /// Heals a Mon.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to heal.
+    /// @param {[`ValueType::UFraction`]} amount The amount to heal.
+    /// @returns {[`ValueType::UFraction`]} The actual amount healed.
+    heal(1, true) => |context| heal(context).map(|val| Some(val)),
+    /// Creates a new hit effect object.
+    ///
+    /// @returns {[`ValueType::HitEffect`]} A new hit effect.
+    hit_effect(0, true) => |_| hit_effect().map(|val| Some(val)),
+    /// Increases a Mon's friendship.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to modify.
+    /// @param {[`ValueType::UFraction`]} delta_1 The friendship increase at level 1.
+    /// @param {[`ValueType::UFraction`]} delta_2 The friendship increase at level 2.
+    /// @param {[`ValueType::UFraction`]} delta_3 The friendship increase at level 3.
+    increase_friendship(3, false) => |context| {
+        increase_friendship(context).map(|()| None)
+    },
+    /// Gets the item at a specific index from the list.
+    ///
+    /// @param {[`ValueType::List`]} list The list.
+    /// @param {[`ValueType::UFraction`]} item The index of the item.
+    /// @returns_item_from_list
+    index(2, true) => index,
+    /// Inverts a Mon's boosts.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to modify.
+    /// @returns {[`ValueType::EventResult`]} The outcome of the boost inversion.
+    invert_boosts(0, true) => |context| {
+        invert_boosts(context).map(|val| Some(val))
+    },
+    /// Checks if two Mons are adjacent.
+    ///
+    /// @param {[`ValueType::Mon`]} mon The first Mon.
+    /// @param {[`ValueType::Mon`]} [other] The second Mon.
+    /// @returns {[`ValueType::Boolean`]} Whether the Mons are adjacent.
+    is_adjacent(1, true) => |context| {
+        is_adjacent(context).map(|val| Some(val))
+    },
+    /// Checks if two Mons are allies.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The first Mon.
+    /// @param {[`ValueType::Mon`]} other The second Mon.
+    /// @returns {[`ValueType::Boolean`]} Whether the Mons are allies.
+    is_ally(1, true) => |context| is_ally(context).map(|val| Some(val)),
+    /// Checks if an item has a specific flag.
+    ///
+    /// @param {[`ValueType::String`] | [`ValueType::Effect`]} item_handle The item to check.
+    /// @param {[`ValueType::String`]} flag The flag ID to check.
+    /// @returns {[`ValueType::Boolean`]} Whether the item has the flag.
+    item_has_flag(2, true) => |context| {
+        item_has_flag(context).map(|val| Some(val))
+    },
+    /// Joins a list into a string.
+    ///
+    /// @param {[`ValueType::List`]} list The list.
+    /// @param {[`ValueType::String`]} separator The separator.
+    /// @returns {[`ValueType::String`]} The joined string.
+    join(2, true) => |context| join(context).map(|val| Some(val)),
+    /// Gets the last attack directed at a Mon.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to check.
+    /// @flag has_damage If set, only attacks that dealt damage are considered.
+    /// @flag different_side If set, only attacks from the opposite side are considered.
+    /// @returns {[`ValueType::Object`] | [`ValueType::Undefined`]} The last attack data.
+    last_attack(0, true) => last_attack,
+    /// Logs a message to the battle log.
+    ///
+    /// @param {[`ValueType::String`]} title The log title.
+    /// @param {[`ValueType::String`]} ... Additional log entries.
+    log(1, false) => |context| log(context).map(|()| None),
+    /// Logs an ability activation to the battle log.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon whose ability activated.
+    /// @param {[`ValueType::String`] | [`ValueType::Effect`]} [ability] The ability ID.
+    log_ability(0, false) => |context| log_ability(context).map(|()| None),
+    /// Logs an effect activation to the battle log.
+    ///
+    /// @param {[`ValueType::String`]} ... Additional log entries.
+    log_activate(0, false) => |context| {
+        log_activate(context).map(|()| None)
+    },
+    /// Logs a move animation to the battle log.
+    ///
+    /// @param {[`ValueType::Effect`]} active_move The active move.
+    /// @param {[`ValueType::Mon`]} [target] The target of the move.
+    log_animate_move(1, false) => |context| {
+        log_animate_move(context).map(|()| None)
+    },
+    /// Logs an item announcement to the battle log.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon whose item is announced.
+    log_announce_item(0, false) => |context| {
+        log_announce_item(context).map(|()| None)
+    },
+    /// Logs a block effect to the battle log.
+    ///
+    /// @param {[`ValueType::String`]} ... Additional log entries.
+    log_block(0, false) => |context| log_block(context).map(|()| None),
+    /// Logs that a Mon cannot perform an action.
+    ///
+    /// @param {[`ValueType::String`]} ... Additional log entries.
+    log_cant(0, false) => |context| log_cant(context).map(|()| None),
+    /// Logs a custom effect to the battle log.
+    ///
+    /// @param {[`ValueType::String`]} header The log header.
+    /// @param {[`ValueType::String`]} ... Additional log entries.
+    log_custom_effect(1, false) => |context| {
+        log_custom_effect(context).map(|()| None)
+    },
+    /// Logs the end of an effect to the battle log.
+    ///
+    /// @param {[`ValueType::String`]} ... Additional log entries.
+    log_end(0, false) => |context| log_end(context).map(|()| None),
+    /// Logs an effect failure to the battle log.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon the effect failed on.
+    /// @param {[`ValueType::Effect`]} [what] The effect that failed.
+    log_fail(0, false) => |context| log_fail(context).map(|()| None),
+    /// Logs a heal failure to the battle log.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon that failed to heal.
+    log_fail_heal(0, false) => |context| {
+        log_fail_heal(context).map(|()| None)
+    },
+    /// Logs an unboost failure to the battle log.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon whose unboost failed.
+    /// @param {[`ValueType::List`] | [`ValueType::String`]} ... The boosts that failed to unboost.
+    log_fail_unboost(0, false) => |context| {
+        log_fail_unboost(context).map(|()| None)
+    },
+    /// Logs a field effect activation to the battle log.
+    ///
+    /// @param {[`ValueType::String`]} ... Additional log entries.
+    log_field_activate(0, false) => |context| {
+        log_field_activate(context).map(|()| None)
+    },
+    /// Logs the end of a field effect to the battle log.
+    ///
+    /// @param {[`ValueType::String`]} ... Additional log entries.
+    log_field_end(0, false) => |context| {
+        log_field_end(context).map(|()| None)
+    },
+    /// Logs the start of a field effect to the battle log.
+    ///
+    /// @param {[`ValueType::String`]} ... Additional log entries.
+    log_field_start(0, false) => |context| {
+        log_field_start(context).map(|()| None)
+    },
+    /// Logs immunity to an effect.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon that is immune.
+    log_immune(0, false) => |context| log_immune(context).map(|()| None),
+    /// Logs an OHKO move to the battle log.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon that was hit by the OHKO move.
+    log_ohko(0, false) => |context| log_ohko(context).map(|()| None),
+    /// Logs move preparation to the battle log.
+    ///
+    /// @param {[`ValueType::Mon`]} [target] The target of the move.
+    log_prepare_move(0, false) => |context| {
+        log_prepare_move(context).map(|()| None)
+    },
+    /// Logs the end of a side condition to the battle log.
+    ///
+    /// @param {[`ValueType::String`]} ... Additional log entries.
+    log_side_end(0, false) => |context| {
+        log_side_end(context).map(|()| None)
+    },
+    /// Logs the start of a side condition to the battle log.
+    ///
+    /// @param {[`ValueType::String`]} ... Additional log entries.
+    log_side_start(0, false) => |context| {
+        log_side_start(context).map(|()| None)
+    },
+    /// Logs a single-move effect activation to the battle log.
+    ///
+    /// @param {[`ValueType::String`]} ... Additional log entries.
+    log_single_move(0, false) => |context| {
+        log_single_move(context).map(|()| None)
+    },
+    /// Logs a single-turn effect activation to the battle log.
+    ///
+    /// @param {[`ValueType::String`]} ... Additional log entries.
+    log_single_turn(0, false) => |context| {
+        log_single_turn(context).map(|()| None)
+    },
+    /// Logs the start of an effect to the battle log.
+    ///
+    /// @param {[`ValueType::String`]} ... Additional log entries.
+    log_start(0, false) => |context| log_start(context).map(|()| None),
+    /// Logs a status change to the battle log.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon whose status changed.
+    /// @param {[`ValueType::String`]} [status] The status ID.
+    log_status(0, false) => |context| log_status(context).map(|()| None),
+    /// Logs a move usage to the battle log.
+    ///
+    /// @param {[`ValueType::Effect`]} active_move The active move.
+    /// @param {[`ValueType::Mon`]} [target] The target of the move.
+    log_use_move(1, false) => |context| {
+        log_use_move(context).map(|()| None)
+    },
+    /// Logs that a Mon is waiting.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon waiting.
+    /// @param {[`ValueType::Mon`]} target The target being waited for.
+    log_waiting(1, false) => |context| log_waiting(context).map(|()| None),
+    /// Logs weather change or continuation to the battle log.
+    ///
+    /// @param {[`ValueType::String`]} [weather] The weather ID.
+    log_weather(0, false) => |context| log_weather(context).map(|()| None),
+    /// Looks up a base species ID.
+    ///
+    /// @param {[`ValueType::String`]} species The species to query.
+    /// @returns {[`ValueType::String`]} The base species ID.
+    lookup_base_species(1, true) => |context| {
+        lookup_base_species(context).map(|val| Some(val))
+    },
+    /// Returns the maximum of two values.
+    ///
+    /// @param {[`ValueType::UFraction`]} a The first value.
+    /// @param {[`ValueType::UFraction`]} b The second value.
+    /// @returns {[`ValueType::UFraction`]} The larger value.
+    max(2, true) => |context| max(context).map(|val| Some(val)),
+    /// Executes a Max Move.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon using the move.
+    /// @param {[`ValueType::Effect`]} move The move to execute.
+    /// @returns {[`ValueType::String`] | [`ValueType::Undefined`]} The Max Move ID.
+    max_move(1, true) => max_move,
+    /// Returns the minimum of two values.
+    ///
+    /// @param {[`ValueType::UFraction`]} a The first value.
+    /// @param {[`ValueType::UFraction`]} b The second value.
+    /// @returns {[`ValueType::UFraction`]} The smaller value.
+    min(2, true) => |context| min(context).map(|val| Some(val)),
+    /// Modifies the type of an active move.
+    ///
+    /// @param {[`ValueType::Effect`]} [active_move] The active move to modify.
+    modify_move_type(0, false) => |context| {
+        modify_move_type(context).map(|()| None)
+    },
+    /// Gets the Mon at a specific target location relative to a Mon.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The reference Mon.
+    /// @param {[`ValueType::Fraction`]} location The relative location index.
+    /// @returns {[`ValueType::Mon`] | [`ValueType::Undefined`]} The Mon at the location.
+    mon_at_target_location(1, true) => mon_at_target_location,
+    /// Checks if a Mon is at a specific field position.
+    ///
+    /// @param {[`ValueType::Side`]} [side] The side to check.
+    /// @param {[`ValueType::UFraction`]} position The position index.
+    /// @returns {[`ValueType::Mon`] | [`ValueType::Undefined`]} The Mon at the position.
+    mon_in_position(1, true) => mon_in_position,
+    /// Gets the move at a specific move slot index.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to check.
+    /// @param {[`ValueType::UFraction`]} index The move slot index.
+    /// @returns {[`ValueType::Effect`] | [`ValueType::Undefined`]} The move at the index.
+    move_at_move_slot_index(1, true) => move_at_move_slot_index,
+    /// Checks if a move dealt a critical hit to its target.
+    ///
+    /// @param {[`ValueType::Effect`]} move_handle The move to check.
+    /// @param {[`ValueType::Mon`]} [mon] The target Mon.
+    /// @returns {[`ValueType::Boolean`]} Whether the move dealt a critical hit.
+    move_crit_target(1, true) => |context| {
+        move_crit_target(context).map(|val| Some(val))
+    },
+    /// Checks if a move has a specific flag.
+    ///
+    /// @param {[`ValueType::String`] | [`ValueType::Effect`]} move_handle The move to check.
+    /// @param {[`ValueType::String`]} flag The flag ID to check.
+    /// @returns {[`ValueType::Boolean`]} Whether the move has the flag.
+    move_has_flag(2, true) => |context| {
+        move_has_flag(context).map(|val| Some(val))
+    },
+    /// Checks if a move's hit data has a flag for a target.
+    ///
+    /// @param {[`ValueType::Effect`]} active_move The active move.
+    /// @param {[`ValueType::Mon`]} [mon] The target Mon.
+    /// @param {[`ValueType::String`]} flag The flag ID to check.
+    /// @returns {[`ValueType::Boolean`]} Whether the hit data has the flag.
+    move_hit_data_has_flag_against_target(2, true) => |context| {
+        move_hit_data_has_flag_against_target(context).map(|val| Some(val))
+    },
+    /// Checks if a move hit its target.
+    ///
+    /// @param {[`ValueType::Effect`]} move_handle The move to check.
+    /// @param {[`ValueType::Mon`]} [mon] The target Mon.
+    /// @returns {[`ValueType::Boolean`]} Whether the move hit the target.
+    move_hit_target(1, true) => |context| {
+        move_hit_target(context).map(|val| Some(val))
+    },
+    /// Checks if a move makes contact.
+    ///
+    /// @param {[`ValueType::Effect`]} [move_handle] The move to check.
+    /// @returns {[`ValueType::Boolean`]} Whether the move makes contact.
+    move_makes_contact(0, true) => |context| {
+        move_makes_contact(context).map(|val| Some(val))
+    },
+    /// Creates a simulated move slot from an active move.
+    ///
+    /// @param {[`ValueType::Effect`] | [`ValueType::String`]} active_move The active move.
+    /// @returns {[`ValueType::MoveSlot`]} The simulated move slot.
+    move_slot(1, true) => |context| {
+        move_slot(context).map(|val| Some(val))
+    },
+    /// Gets the move slot at a specific index.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to query.
+    /// @param {[`ValueType::UFraction`]} index The move slot index.
+    /// @returns {[`ValueType::MoveSlot`] | [`ValueType::Undefined`]} The move slot at the index.
+    move_slot_at_index(1, true) => move_slot_at_index,
+    /// Gets the index of a move in a Mon's move slots.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to check.
+    /// @param {[`ValueType::String`] | [`ValueType::Effect`]} move_id The move ID to check.
+    /// @returns {[`ValueType::UFraction`] | [`ValueType::Undefined`]} The index of the move.
+    move_slot_index(1, true) => move_slot_index,
+    /// Reads the target's original HP before the move applied any hits.
+    ///
+    /// @param {[`ValueType::Effect`]} active_move The active move.
+    /// @param {[`ValueType::Mon`]} [mon] The target Mon.
+    /// @returns {[`ValueType::UFraction`] | [`ValueType::Undefined`]} The target's original HP.
+    move_target_original_hp(1, true) => move_target_original_hp,
+    /// Creates a new active move.
+    ///
+    /// @param {[`ValueType::String`] | [`ValueType::Effect`]} move_id The move ID.
+    /// @param {[`ValueType::Mon`]} [mon] The Mon using the move.
+    /// @returns {[`ValueType::Effect`]} The new active move.
+    new_active_move(1, true) => |context| {
+        new_active_move(context).map(|val| Some(val))
+    },
+    /// Creates a new active move from local data.
+    ///
+    /// @param {[`ValueType::Effect`]} effect The effect containing local data.
+    /// @param {[`ValueType::String`] | [`ValueType::Effect`]} move_id The move ID in local data.
+    /// @param {[`ValueType::Mon`]} [mon] The Mon using the move.
+    /// @returns {[`ValueType::Effect`]} The new active move.
+    new_active_move_from_local_data(2, true) => |context| {
+        new_active_move_from_local_data(context).map(|val| Some(val))
+    },
+    /// Creates a new empty object.
+    ///
+    /// @returns {[`ValueType::Object`]} The new object.
+    new_object(0, true) => |context| Ok(Some(new_object(context))),
+    /// Gets a value from an object.
+    ///
+    /// @param {[`ValueType::Object`]} object The object to query.
+    /// @param {[`ValueType::String`]} key The key to retrieve.
+    /// @returns {[`ValueType::Any`] | [`ValueType::Undefined`]} The value at the key.
+    object_get(2, true) => object_get,
+    /// Increments an integer value in an object.
+    ///
+    /// @param {[`ValueType::Object`]} object The object to modify.
+    /// @param {[`ValueType::String`]} key The key to increment.
+    /// @returns {[`ValueType::Object`]} The modified object.
+    object_increment(2, true) => |context| {
+        object_increment(context).map(|val| Some(val))
+    },
+    /// Gets the keys of an object.
+    ///
+    /// @param {[`ValueType::Object`]} object The object to query.
+    /// @returns {[`ValueType::List`]} A list of keys.
+    /// @returnsitem {[`ValueType::String`]}
+    object_keys(1, true) => |context| {
+        object_keys(context).map(|val| Some(val))
+    },
+    /// Sets a value on an object.
+    ///
+    /// @param {[`ValueType::Object`]} object The object to modify.
+    /// @param {[`ValueType::String`]} key The key to set.
+    /// @param {[`ValueType::Any`]} value The value to set.
+    /// @returns {[`ValueType::Object`]} The modified object.
+    object_set(3, true) => |context| {
+        object_set(context).map(|val| Some(val))
+    },
+    /// Gets a value from an object.
+    ///
+    /// @param {[`ValueType::Object`]} object The object to query.
+    /// @param {[`ValueType::String`]} key The key to retrieve.
+    /// @returns {[`ValueType::Any`] | [`ValueType::Undefined`]} The value at the key.
+    object_value(2, true) => object_value,
+    /// Overwrites a move slot on a Mon.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to modify.
+    /// @param {[`ValueType::UFraction`]} index The move slot index.
+    /// @param {[`ValueType::MoveSlot`]} move_slot The new move slot data.
+    /// @flag override_base_slot If set, the base move slot is also overridden.
+    overwrite_move_slot(2, false) => |context| {
+        overwrite_move_slot(context).map(|()| None)
+    },
+    /// Gets a pending move action for a Mon this turn.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to check.
+    /// @returns {[`ValueType::Object`] | [`ValueType::Undefined`]} The pending move action.
+    pending_move_action_this_turn(0, true) => pending_move_action_this_turn,
+    /// Gets all pending move actions this turn.
+    ///
+    /// @returns {[`ValueType::List`]} A list of all pending move actions.
+    /// @returnsitem {[`ValueType::Object`]}
+    pending_move_actions_this_turn(0, true) => |context| {
+        pending_move_actions_this_turn(context).map(|val| Some(val))
+    },
+    /// Returns an 's' if the number is not 1.
+    ///
+    /// @param {[`ValueType::UFraction`]} number The number to check.
+    /// @returns {[`ValueType::String`]} "s" or empty string.
+    plural(1, true) => |context| plural(context).map(|val| Some(val)),
+    /// Prepares a direct move for execution.
+    ///
+    /// @param {[`ValueType::List`]} targets The list of target Mons.
+    /// @returns {[`ValueType::List`]} The list of Mons that were hit.
+    /// @returnsitem {[`ValueType::Mon`]}
+    prepare_direct_move(1, true) => |context| {
+        prepare_direct_move(context).map(|val| Some(val))
+    },
+    /// Executes a primal reversion.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to modify.
+    /// @param {[`ValueType::String`]} forme The primal forme ID.
+    /// @returns {[`ValueType::EventResult`]} Whether the primal reversion was successful.
+    primal_reversion(1, true) => |context| {
+        primal_reversion(context).map(|val| Some(val))
+    },
+    /// Prioritizes a Mon's move.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon whose move to prioritize.
+    /// @param {[`ValueType::UFraction`]} [action_id] The action ID to prioritize.
+    /// @param {[`ValueType::Effect`]} [source_effect] The source effect.
+    /// @param {[`ValueType::Mon`]} [source] The source Mon.
+    prioritize_move(0, false) => |context| {
+        prioritize_move(context).map(|()| None)
+    },
+    /// Gets the effect state for a pseudo-weather condition.
+    ///
+    /// @param {[`ValueType::String`] | [`ValueType::Effect`]} pseudo_weather_id The pseudo-weather
+    /// ID.
+    /// @returns {[`ValueType::EffectState`] | [`ValueType::Undefined`]} The pseudo-weather effect
+    /// state.
+    pseudo_weather_effect_state(1, true) => pseudo_weather_effect_state,
+    /// The number of shields protecting a raid boss.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The raid boss.
+    /// @returns {[`ValueType::UFraction`]} The number of shields.
+    raid_shields(0, true) => |context| {
+        raid_shields(context).map(|val| Some(val))
+    },
+    /// Generates a random number.
+    ///
+    /// @param {[`ValueType::UFraction`]} [min] The minimum value.
+    /// @param {[`ValueType::UFraction`]} [max] The maximum value.
+    /// @returns {[`ValueType::UFraction`]} A random number.
+    random(0, true) => |context| random(context).map(|val| Some(val)),
+    /// Selects a random target for a move.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon using the move.
+    /// @param {[`ValueType::MoveTarget`]} [move_target] The target type.
+    /// @returns {[`ValueType::Mon`] | [`ValueType::Undefined`]} The selected target.
+    random_target(0, true) => random_target,
+    /// Reads an arbitrary value from the event state.
+    ///
+    /// @param {[`ValueType::String`]} key Key.
+    /// @returns {[`ValueType::Any`]} Value.
+    read_event_state_value(1, true) => read_event_state_value,
+    /// Checks if a Mon received an attack this turn.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to check.
+    /// @param {[`ValueType::Mon`]} source The source Mon of the attack.
+    /// @flag has_damage If set, only attacks that dealt damage are counted.
+    /// @flag this_turn If set, only attacks received this turn are counted.
+    /// @returns {[`ValueType::Boolean`]} Whether the Mon was attacked.
+    received_attack(1, true) => |context| {
+        received_attack(context).map(|val| Some(val))
+    },
+    /// Removes an item from a list.
+    ///
+    /// @param {[`ValueType::List`]} list The list.
+    /// @param {[`ValueType::Any`]} item The item to remove.
+    /// @returns {[`ValueType::Boolean`]} Whether the item was removed.
+    remove(2, true) => |context| remove(context).map(|val| Some(val)),
+    /// Removes a flag from a move.
+    ///
+    /// @param {[`ValueType::Effect`]} [move_handle] The move to modify.
+    /// @param {[`ValueType::String`]} flag The flag to remove.
+    remove_move_flag(1, false) => |context| {
+        remove_move_flag(context).map(|()| None)
+    },
+    /// Removes pseudo-weather from the field.
+    ///
+    /// @param {[`ValueType::String`]} weather The pseudo-weather ID.
+    /// @returns {[`ValueType::EventResult`]} Whether the pseudo-weather was successfully removed.
+    remove_pseudo_weather(1, true) => |context| {
+        remove_pseudo_weather(context).map(|val| Some(val))
+    },
+    /// Removes a side condition.
+    ///
+    /// @param {[`ValueType::Side`]} [side] The side to modify.
+    /// @param {[`ValueType::String`] | [`ValueType::Effect`]} side_condition The side condition ID.
+    /// @returns {[`ValueType::EventResult`]} Whether the side condition was removed.
+    remove_side_condition(1, true) => |context| {
+        remove_side_condition(context).map(|val| Some(val))
+    },
+    /// Removes a slot condition.
+    ///
+    /// @param {[`ValueType::Side`]} [side] The side to modify.
+    /// @param {[`ValueType::UFraction`]} slot The slot index.
+    /// @param {[`ValueType::String`]} condition The slot condition ID.
+    /// @returns {[`ValueType::EventResult`]} Whether the slot condition was removed.
+    remove_slot_condition(2, true) => |context| {
+        remove_slot_condition(context).map(|val| Some(val))
+    },
+    /// Removes a volatile effect from a Mon.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to modify.
+    /// @param {[`ValueType::String`] | [`ValueType::Effect`]} [effect] The volatile effect ID.
+    /// @returns {[`ValueType::EventResult`]} Whether the volatile effect was removed.
+    remove_volatile(0, true) => |context| {
+        remove_volatile(context).map(|val| Some(val))
+    },
+    /// Requests the Mon's player to a select a Mon.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to select for.
+    /// @param {[`ValueType::String`]} reason The selection reason.
+    request_mon_selection(1, false) => |context| {
+        request_mon_selection(context).map(|()| None)
+    },
+    /// Resets a Mon's types.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to modify.
+    /// @returns {[`ValueType::EventResult`]} Whether the types were successfully reset.
+    reset_types(0, true) => |context| {
+        reset_types(context).map(|val| Some(val))
+    },
+    /// Restores PP to a Mon's move.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon whose move to modify.
+    /// @param {[`ValueType::String`] | [`ValueType::Effect`]} [move_handle] The move to restore.
+    /// @param {[`ValueType::UFraction`]} amount The amount of PP to restore.
+    /// @returns {[`ValueType::Boolean`]} Whether PP was successfully restored.
+    restore_pp(1, true) => |context| {
+        restore_pp(context).map(|val| Some(val))
+    },
+    /// Reverses a list.
+    ///
+    /// @param {[`ValueType::List`]} list The list.
+    /// @returns {[`ValueType::List`]} The reversed list.
+    reverse(1, true) => |context| reverse(context).map(|val| Some(val)),
+    /// Revives a fainted Mon.
+    ///
+    /// @param {[`ValueType::Mon`]} mon The Mon to revive.
+    /// @param {[`ValueType::UFraction`]} hp_percent The percentage of HP to restore.
+    /// @returns {[`ValueType::UFraction`]} The amount of HP restored.
+    revive(2, true) => |context| revive(context).map(|val| Some(val)),
+    /// Runs a battle event.
+    ///
+    /// @param {[`ValueType::String`]} event The event name.
+    /// @returns {[`ValueType::Boolean`]} The event result.
+    run_event(1, true) => |context| {
+        run_event(context).map(|val| Some(val))
+    },
+    /// Runs an event on each active Mon.
+    ///
+    /// @param {[`ValueType::String`]} event The event ID.
+    run_event_for_each_active_mon(1, false) => |context| {
+        run_event_for_each_active_mon(context).map(|()| None)
+    },
+    /// Runs an event on a Mon.
+    ///
+    /// @param {[`ValueType::String`]} event The event ID.
+    /// @returns {[`ValueType::Boolean`]} Whether the event was successful.
+    run_event_for_mon(1, true) => |context| {
+        run_event_for_mon(context).map(|val| Some(val))
+    },
+    /// Runs an event on a Mon's ability.
+    ///
+    /// @param {[`ValueType::String`]} event The event ID.
+    /// @returns {[`ValueType::Boolean`] | [`ValueType::Undefined`]}
+    run_event_on_mon_ability(1, true) => run_event_on_mon_ability,
+    /// Runs an event on a Mon's item.
+    ///
+    /// @param {[`ValueType::String`]} event The event ID.
+    /// @returns {[`ValueType::Boolean`] | [`ValueType::Undefined`]}
+    run_event_on_mon_item(1, true) => run_event_on_mon_item,
+    /// Runs an event on a Mon's species.
+    ///
+    /// @param {[`ValueType::String`]} event The event ID.
+    /// @returns {[`ValueType::Boolean`] | [`ValueType::Undefined`]}
+    run_event_on_mon_species(1, true) => run_event_on_mon_species,
+    /// Runs an event on a Mon's volatile effect.
+    ///
+    /// @param {[`ValueType::String`]} volatile The volatile effect ID.
+    /// @param {[`ValueType::String`]} event The event ID.
+    /// @returns {[`ValueType::Boolean`] | [`ValueType::Undefined`]}
+    run_event_on_mon_volatile(2, true) => run_event_on_mon_volatile,
+    /// Runs an event on an active move.
+    ///
+    /// @param {[`ValueType::String`]} event The event ID.
+    /// @returns {[`ValueType::Boolean`] | [`ValueType::Undefined`]}
+    run_event_on_move(1, true) => run_event_on_move,
+    /// Randomly samples an element from a list.
+    ///
+    /// @param {[`ValueType::List`]} list The list to sample from.
+    /// @returns_item_from_list
+    sample(1, true) => sample,
+    /// Saves an arbitrary value to the event state.
+    ///
+    /// @param {[`ValueType::String`]} key Key.
+    /// @param {[`ValueType::Any`]} value Value.
+    save_event_state_value(2, false) => |context| {
+        save_event_state_value(context).map(|()| None)
+    },
+    /// Saves a flag on a move's hit data for a target.
+    ///
+    /// @param {[`ValueType::Effect`]} active_move The active move.
+    /// @param {[`ValueType::Mon`]} [mon] The target Mon.
+    /// @param {[`ValueType::String`]} flag The flag ID to save.
+    save_move_hit_data_flag_against_target(2, false) => |context| {
+        save_move_hit_data_flag_against_target(context).map(|()| None)
+    },
+    /// Creates a new secondary hit effect object.
+    ///
+    /// @returns {[`ValueType::SecondaryHitEffect`]} A new secondary hit effect.
+    secondary_hit_effect(0, true) => |_| {
+        secondary_hit_effect().map(|val| Some(val))
+    },
+    /// Sets a Mon's ability.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to modify.
+    /// @param {[`ValueType::String`] | [`ValueType::Effect`]} ability The new ability ID.
+    /// @flag dry_run If set, the ability is not actually changed.
+    /// @flag silent If set, no message is displayed.
+    /// @returns {[`ValueType::EventResult`]} Whether the ability was successfully set.
+    set_ability(1, true) => |context| {
+        set_ability(context).map(|val| Some(val))
+    },
+    /// Sets a boost value in a boost table.
+    ///
+    /// @param {[`ValueType::Object`]} boosts The boost table.
+    /// @param {[`ValueType::Stat`]} boost The stat to boost.
+    /// @param {[`ValueType::Fraction`]} value The boost value.
+    /// @returns {[`ValueType::Object`]} The modified boost table.
+    set_boost(3, true) => |context| {
+        set_boost(context).map(|val| Some(val))
+    },
+    /// Sets a Mon's friendship value.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to modify.
+    /// @param {[`ValueType::UFraction`]} friendship The new friendship value.
+    set_friendship(1, false) => |context| {
+        set_friendship(context).map(|()| None)
+    },
+    /// Sets a Mon's HP.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to modify.
+    /// @param {[`ValueType::UFraction`]} hp The new HP value.
+    /// @returns {[`ValueType::Boolean`]} Whether the HP was successfully set.
+    set_hp(1, true) => |context| set_hp(context).map(|val| Some(val)),
+    /// Sets a Mon's illusion species.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon using the illusion.
+    /// @param {[`ValueType::Mon`]} target The Mon to imitate.
+    /// @returns {[`ValueType::EventResult`]} Whether the illusion was successfully set.
+    set_illusion(1, true) => |context| {
+        set_illusion(context).map(|val| Some(val))
+    },
+    /// Sets a Mon's held item.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to modify.
+    /// @param {[`ValueType::String`] | [`ValueType::Effect`]} item The item ID.
+    /// @returns {[`ValueType::EventResult`]} Whether the item was successfully set.
+    set_item(1, true) => |context| set_item(context).map(|val| Some(val)),
+    /// Sets whether a Mon needs to switch out.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to modify.
+    /// @param {[`ValueType::Boolean`]} needs_switch Whether the Mon needs to switch.
+    set_needs_switch(1, false) => |context| {
+        set_needs_switch(context).map(|()| None)
+    },
+    /// Sets a Mon's move PP.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon whose move to modify.
+    /// @param {[`ValueType::String`] | [`ValueType::Effect`]} [move_handle] The move to modify.
+    /// @param {[`ValueType::UFraction`]} pp The new PP value.
+    /// @returns {[`ValueType::Boolean`]} Whether the PP was successfully set.
+    set_pp(1, true) => |context| set_pp(context).map(|val| Some(val)),
+    /// Sets a Mon's status condition.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to modify.
+    /// @param {[`ValueType::String`]} status The status ID.
+    /// @returns {[`ValueType::EventResult`]} Whether the status was successfully set.
+    set_status(1, true) => |context| {
+        set_status(context).map(|val| Some(val))
+    },
+    /// Sets the field terrain.
+    ///
+    /// @param {[`ValueType::String`]} terrain The terrain ID.
+    /// @returns {[`ValueType::EventResult`]} Whether the terrain was successfully set.
+    set_terrain(1, true) => |context| {
+        set_terrain(context).map(|val| Some(val))
+    },
+    /// Sets a Mon's types.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to modify.
+    /// @param {[`ValueType::List`]} types The list of types.
+    /// @returns {[`ValueType::EventResult`]} Whether the types were successfully set.
+    set_types(1, true) => |context| {
+        set_types(context).map(|val| Some(val))
+    },
+    /// Marks an active move as upgraded to a Max Move.
+    ///
+    /// @param {[`ValueType::Effect`]} active_move The active move.
+    /// @param {[`ValueType::String`]} base_move The base move ID.
+    set_upgraded_to_max_move(2, false) => |context| {
+        set_upgraded_to_max_move(context).map(|()| None)
+    },
+    /// Marks an active move as upgraded to a Z-Move.
+    ///
+    /// @param {[`ValueType::Effect`]} active_move The active move.
+    /// @param {[`ValueType::String`]} base_move The base move ID.
+    set_upgraded_to_z_move(2, false) => |context| {
+        set_upgraded_to_z_move(context).map(|()| None)
+    },
+    /// Sets the field weather.
+    ///
+    /// @param {[`ValueType::String`]} weather The weather ID.
+    /// @returns {[`ValueType::EventResult`]} Whether the weather was successfully set.
+    set_weather(1, true) => |context| {
+        set_weather(context).map(|val| Some(val))
+    },
+    /// Sets Z-Power boosts for a move.
+    ///
+    /// @param {[`ValueType::Effect`]} active_move The active move.
+    /// @param {[`ValueType::Object`]} boost_table The boost table.
+    set_z_power_boosts(2, false) => |context| {
+        set_z_power_boosts(context).map(|()| None)
+    },
+    /// Gets the effect state for a side condition.
+    ///
+    /// @param {[`ValueType::Side`]} [side] The side whose condition state to retrieve.
+    /// @param {[`ValueType::String`] | [`ValueType::Effect`]} condition_id The side condition ID.
+    /// @returns {[`ValueType::EffectState`] | [`ValueType::Undefined`]} The side condition effect
+    /// state.
+    side_condition_effect_state(1, true) => side_condition_effect_state,
+    /// Skips an effect callback.
+    ///
+    /// @param {[`ValueType::String`] | [`ValueType::Effect`]} effect The effect ID.
+    skip_effect_callback(1, false) => |context| {
+        skip_effect_callback(context).map(|()| None)
+    },
+    /// Gets special data for an item.
+    ///
+    /// @param {[`ValueType::String`] | [`ValueType::Effect`]} item The item ID.
+    /// @returns {[`ValueType::SpecialItemData`]} The special item data.
+    special_item_data(1, true) => |context| {
+        special_item_data(context).map(|val| Some(val))
+    },
+    /// Checks if a species has a specific flag.
+    ///
+    /// @param {[`ValueType::String`] | [`ValueType::Effect`]} species_handle The species to check.
+    /// @param {[`ValueType::String`]} flag The flag ID to check.
+    /// @returns {[`ValueType::Boolean`]} Whether the species has the flag.
+    species_has_flag(2, true) => |context| {
+        species_has_flag(context).map(|val| Some(val))
+    },
+    /// Sorts a list of Mons by speed.
+    ///
+    /// @param {[`ValueType::List`]} mons The list of Mons to sort.
+    /// @returns {[`ValueType::List`]} The sorted list.
+    speed_sort_mons(1, true) => |context| {
+        speed_sort_mons(context).map(|val| Some(val))
+    },
+    /// Starts a Mon's ability.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to modify.
+    start_ability(0, false) => |context| {
+        start_ability(context).map(|()| None)
+    },
+    /// Starts a Mon's item.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to modify.
+    start_item(0, false) => |context| start_item(context).map(|()| None),
+    /// Gets the effect state for a status condition.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon whose status state to retrieve.
+    /// @returns {[`ValueType::EffectState`] | [`ValueType::Undefined`]} The status effect state.
+    status_effect_state(0, true) => status_effect_state,
+    /// Swaps boosts between two Mons.
+    ///
+    /// @param {[`ValueType::Mon`]} [target] The target Mon.
+    /// @param {[`ValueType::Mon`]} [source] The source Mon.
+    /// @param {[`ValueType::List`]} [boosts] The list of boosts to swap.
+    swap_boosts(0, false) => |context| swap_boosts(context).map(|()| None),
+    /// Swaps a Mon's position with another index.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to swap.
+    /// @param {[`ValueType::UFraction`]} position The target position index.
+    /// @returns {[`ValueType::EventResult`]} Whether the swap was successful.
+    swap_position(1, true) => |context| {
+        swap_position(context).map(|val| Some(val))
+    },
+    /// Swaps all eligible side conditions between two sides.
+    ///
+    /// @param {[`ValueType::Side`]} [target_side] The target side.
+    /// @param {[`ValueType::Side`]} [source_side] The source side to swap with.
+    /// @param {[`ValueType::List`]} conditions Eligible conditions to swap.
+    /// @returns {[`ValueType::EventResult`]} Whether at least one swap occurred.
+    swap_side_conditions(1, true) => |context| {
+        swap_side_conditions(context).map(|val| Some(val))
+    },
+    /// Forces a Mon to switch out.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to switch.
+    /// @returns {[`ValueType::Boolean`]} Whether the switch was successful.
+    switch_out(0, true) => |context| {
+        switch_out(context).map(|val| Some(val))
+    },
+    /// Takes a Mon's held item.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon whose item to take.
+    /// @flag dry_run If set, the item is not actually taken.
+    /// @flag silent If set, no message is displayed.
+    /// @returns {[`ValueType::String`] | [`ValueType::EventResult`]} The ID of the item that was
+    /// taken.
+    take_item(0, true) => |context| {
+        take_item(context).map(|val| Some(val))
+    },
+    /// Gets the target location of a Mon relative to another Mon.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The reference Mon.
+    /// @param {[`ValueType::Mon`]} target The target Mon.
+    /// @returns {[`ValueType::Fraction`]} The target location index.
+    target_location_of_mon(1, true) => |context| {
+        target_location_of_mon(context).map(|val| Some(val))
+    },
+    /// Transforms a Mon into another species.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to transform.
+    /// @param {[`ValueType::Mon`]} target The Mon to transform into.
+    /// @returns {[`ValueType::EventResult`]} Whether the transformation was successful.
+    transform_into(1, true) => |context| {
+        transform_into(context).map(|val| Some(val))
+    },
+    /// Gets type chart effectiveness.
+    ///
+    /// @param {[`ValueType::Type`]} attack_type The attacking type.
+    /// @param {[`ValueType::Type`]} defense_type The defending type.
+    /// @returns {[`ValueType::UFraction`]} The effectiveness multiplier.
+    type_chart_effectiveness(2, true) => |context| {
+        type_chart_effectiveness(context).map(|val| Some(val))
+    },
+    /// Checks type chart immunity.
+    ///
+    /// @param {[`ValueType::Type`]} attack_type The attacking type.
+    /// @param {[`ValueType::Type`]} defense_type The defending type.
+    /// @returns {[`ValueType::Boolean`]} Whether the defense type is immune.
+    type_chart_immunity(2, true) => |context| {
+        type_chart_immunity(context).map(|val| Some(val))
+    },
+    /// Calculates type effectiveness.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The target Mon.
+    /// @returns {[`ValueType::UFraction`]} The effectiveness multiplier.
+    type_effectiveness(0, true) => |context| {
+        type_effectiveness(context).map(|val| Some(val))
+    },
+    /// Calculates a type modifier.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The target Mon.
+    /// @returns {[`ValueType::UFraction`]} The type modifier.
+    type_modifier(0, true) => |context| {
+        type_modifier(context).map(|val| Some(val))
+    },
+    /// Calculates a type modifier against a target Mon.
+    ///
+    /// @param {[`ValueType::Effect`]} move_handle The move to check.
+    /// @param {[`ValueType::Mon`]} [mon] The target Mon.
+    /// @returns {[`ValueType::Fraction`] | [`ValueType::Undefined`]} The type modifier.
+    type_modifier_against_target(1, true) => type_modifier_against_target,
+    /// Calculates the un-Dynamaxed HP for a Mon.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The target Mon.
+    /// @param {[`ValueType::UFraction`]} hp HP value.
+    /// @returns {[`ValueType::UFraction`]} Un-Dynamaxed HP value.
+    undynamaxed_hp_calculation(1, true) => |context| {
+        undynamaxed_hp_calculation(context).map(|val| Some(val))
+    },
+    /// Uses an active move.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon using the move.
+    /// @param {[`ValueType::Effect`]} active_move The active move to use.
+    /// @param {[`ValueType::Mon`]} [target] The target of the move.
+    /// @flag indirect If set, the move is considered indirect.
+    /// @flag preventable If set, the move can be prevented by certain effects.
+    /// @returns {[`ValueType::Boolean`]} Whether the move was successful.
+    use_active_move(1, true) => |context| {
+        use_active_move(context).map(|val| Some(val))
+    },
+    /// Uses a specific item.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon using the item.
+    /// @param {[`ValueType::String`] | [`ValueType::Effect`]} item The item ID to use.
+    /// @returns {[`ValueType::EventResult`]} Whether the item was successfully used.
+    use_given_item(1, true) => |context| {
+        use_given_item(context).map(|val| Some(val))
+    },
+    /// Uses a Mon's held item.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon using the item.
+    /// @returns {[`ValueType::EventResult`]} Whether the item was successfully used.
+    use_item(0, true) => |context| use_item(context).map(|val| Some(val)),
+    /// Uses a move by ID.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon using the move.
+    /// @param {[`ValueType::String`] | [`ValueType::Effect`]} move_id The move ID.
+    /// @param {[`ValueType::Mon`]} [target] The target Mon.
+    /// @returns {[`ValueType::Boolean`]} Whether the move was successful.
+    use_move(1, true) => |context| use_move(context).map(|val| Some(val)),
+    /// Checks if a target is valid for a move.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon using the move.
+    /// @param {[`ValueType::Mon`]} target The target Mon to check.
+    /// @param {[`ValueType::MoveTarget`]} [move_target] The move target type.
+    /// @returns {[`ValueType::Boolean`]} Whether the target is valid.
+    valid_target(1, true) => |context| {
+        valid_target(context).map(|val| Some(val))
+    },
+    /// Gets a value from an effect's local data.
+    ///
+    /// @param {[`ValueType::Effect`]} [effect] The effect handle.
+    /// @param {[`ValueType::String`]} key The key to retrieve.
+    /// @returns {[`ValueType::String`] | [`ValueType::Undefined`]} The value from local data.
+    value_from_local_data(1, true) => value_from_local_data,
+    /// Gets the effect state for a volatile status condition.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon whose volatile state to retrieve.
+    /// @param {[`ValueType::String`] | [`ValueType::Effect`]} [effect] The volatile effect ID.
+    /// @returns {[`ValueType::EffectState`] | [`ValueType::Undefined`]} The volatile effect state.
+    volatile_status_effect_state(0, true) => volatile_status_effect_state,
+    /// Checks if a Mon will move this turn.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon to check.
+    /// @returns {[`ValueType::Boolean`]} Whether the Mon will move.
+    will_move_this_turn(0, true) => |context| {
+        will_move_this_turn(context).map(|val| Some(val))
+    },
+    /// Executes a Z-Move.
+    ///
+    /// @param {[`ValueType::Mon`]} [mon] The Mon using the move.
+    /// @param {[`ValueType::Effect`]} move The move to execute.
+    /// @returns {[`ValueType::String`] | [`ValueType::Undefined`]} The Z-Move ID.
+    z_move(1, true) => z_move,
+}
+
+/// A built-in fxlang function, resolved by name when a program is compiled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BuiltinFunction(usize);
+
+impl BuiltinFunction {
+    /// Resolves a built-in function by name.
+    pub fn new(name: &str) -> Option<Self> {
+        BUILTIN_FUNCTIONS
+            .binary_search_by_key(&name, |entry| entry.name)
+            .ok()
+            .map(Self)
+    }
+
+    /// All built-in functions, in alphabetical order.
+    pub fn all() -> impl Iterator<Item = Self> {
+        (0..BUILTIN_FUNCTIONS.len()).map(Self)
+    }
+
+    /// The name of the function.
+    pub fn name(&self) -> &'static str {
+        BUILTIN_FUNCTIONS[self.0].name
+    }
+
+    /// The signature of the function.
+    pub fn signature(&self) -> FunctionSignature {
+        BUILTIN_FUNCTIONS[self.0].signature
+    }
+
+    /// The documentation of the function, taken from its doc comment in the table of built-in
+    /// functions.
+    ///
+    /// Empty if the function is undocumented.
+    pub fn doc(&self) -> String {
+        BUILTIN_FUNCTIONS[self.0]
+            .doc
+            .iter()
+            .map(|line| line.strip_prefix(' ').unwrap_or(line))
+            .join("\n")
+    }
+
+    fn run(&self, context: FunctionContext) -> Result<Option<Value>> {
+        (BUILTIN_FUNCTIONS[self.0].implementation)(context)
+    }
+}
+
+/// A function called from an fxlang program, resolved when the program is compiled.
+///
+/// Built-in functions are resolved ahead of time. Native functions and subroutines are looked up
+/// by name when they are called, since they depend on the battle.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum Function {
+    Builtin(BuiltinFunction),
+    Named(String),
+}
+
+impl Function {
+    /// Resolves a function by name.
+    pub fn new(name: &str) -> Self {
+        match BuiltinFunction::new(name) {
+            Some(function) => Self::Builtin(function),
+            None => Self::Named(name.to_owned()),
+        }
+    }
+
+    /// The name of the function.
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Builtin(function) => function.name(),
+            Self::Named(name) => name,
+        }
+    }
+}
+
+impl From<String> for Function {
+    fn from(value: String) -> Self {
+        Self::new(&value)
+    }
+}
+
+impl From<Function> for String {
+    fn from(value: Function) -> Self {
+        match value {
+            Function::Builtin(function) => function.name().to_owned(),
+            Function::Named(name) => name,
+        }
+    }
+}
+
 /// Runs an fxlang function.
 ///
 /// This function serves as the boundary between fxlang program evaluation and the battle engine.
+///
+/// If the calling program is untrusted, subroutines run as untrusted programs as well.
 pub fn run_function(
     context: &mut EvaluationContext,
-    function_name: &str,
+    function: &Function,
     args: VecDeque<Value>,
     event: BattleEvent,
     event_state: &EventState,
     effect_state: Option<DynamicEffectStateConnector>,
     effect_mon_handle: Option<MonHandle>,
     event_origin_mon_handle: Option<MonHandle>,
+    untrusted: bool,
 ) -> Result<Option<Value>> {
     let context = FunctionContext::new(
         context,
@@ -120,280 +1958,70 @@ pub fn run_function(
         effect_state,
         effect_mon_handle,
         event_origin_mon_handle,
+        untrusted,
     );
-    // Maintain alphabetical order.
-    match function_name {
-        "ability_effect_state" => ability_effect_state(context),
-        "ability_has_flag" => ability_has_flag(context).map(|val| Some(val)),
-        "activate_ability" => activate_ability(context),
-        "activate_applying_effect" => activate_applying_effect(context),
-        "add_attribute_to_last_move" => add_attribute_to_last_move(context).map(|()| None),
-        "add_attribute_value_to_last_move" => {
-            add_attribute_value_to_last_move(context).map(|()| None)
-        }
-        "add_move_action" => add_move_action(context).map(|val| Some(val)),
-        "add_move_flag" => add_move_flag(context).map(|()| None),
-        "add_pseudo_weather" => add_pseudo_weather(context).map(|val| Some(val)),
-        "add_secondary_effect_to_move" => add_secondary_effect_to_move(context).map(|()| None),
-        "add_side_condition" => add_side_condition(context).map(|val| Some(val)),
-        "add_slot_condition" => add_slot_condition(context).map(|val| Some(val)),
-        "add_sub_ability" => add_sub_ability(context).map(|val| Some(val)),
-        "add_type" => add_type(context).map(|val| Some(val)),
-        "add_volatile" => add_volatile(context).map(|val| Some(val)),
-        "adjacent_allies" => adjacent_allies(context).map(|val| Some(val)),
-        "adjacent_foes" => adjacent_foes(context).map(|val| Some(val)),
-        "all_active_mons" => all_active_mons(context).map(|val| Some(val)),
-        "all_active_mons_in_speed_order" => {
-            all_active_mons_in_speed_order(context).map(|val| Some(val))
-        }
-        "all_active_mons_in_speed_order_and_ability_effect_order" => {
-            all_active_mons_in_speed_order_and_ability_effect_order(context).map(|val| Some(val))
-        }
-        "all_active_mons_on_side" => all_active_mons_on_side(context).map(|val| Some(val)),
-        "all_active_or_exited_mons" => all_active_or_exited_mons(context).map(|val| Some(val)),
-        "all_foes" => all_foes(context).map(|val| Some(val)),
-        "all_mons_in_party" => all_mons_in_party(context).map(|val| Some(val)),
-        "all_mons_on_side" => all_mons_on_side(context).map(|val| Some(val)),
-        "all_types" => all_types(context).map(|val| Some(val)),
-        "allies_and_self" => allies_and_self(context).map(|val| Some(val)),
-        "any_mon_will_move_this_turn" => any_mon_will_move_this_turn(context).map(|val| Some(val)),
-        "append" => append(context).map(|val| Some(val)),
-        "apply_drain" => apply_drain(context).map(|val| Some(val)),
-        "apply_recoil_damage" => apply_recoil_damage(context).map(|val| Some(val)),
-        "base_species" => base_species(context).map(|val| Some(val)),
-        "best_stat" => best_stat(context).map(|val| Some(val)),
-        "boost" => boost(context).map(|val| Some(val)),
-        "boost_table" => boost_table(context).map(|val| Some(val)),
-        "boostable_stats" => Ok(Some(boostable_stats())),
-        "calculate_base_damage" => calculate_base_damage(context).map(|val| Some(val)),
-        "calculate_confusion_damage" => calculate_confusion_damage(context).map(|val| Some(val)),
-        "calculate_damage" => calculate_damage(context).map(|val| Some(val)),
-        "can_boost" => can_boost(context).map(|val| Some(val)),
-        "can_escape" => can_escape(context).map(|val| Some(val)),
-        "can_switch" => can_switch(context).map(|val| Some(val)),
-        "cancel_action" => cancel_action(context).map(|val| Some(val)),
-        "cancel_move" => cancel_move(context).map(|val| Some(val)),
-        "chance" => chance(context).map(|val| Some(val)),
-        "check_immunity" => check_immunity(context).map(|val| Some(val)),
-        "check_move_immunity" => check_move_immunity(context).map(|val| Some(val)),
-        "clamp_number" => clamp_number(context).map(|val| Some(val)),
-        "clause_integer_value" => clause_integer_value(context),
-        "clause_type_value" => clause_type_value(context),
-        "clear_boosts" => clear_boosts(context).map(|()| None),
-        "clear_negative_boosts" => clear_negative_boosts(context).map(|()| None),
-        "clear_positive_boosts" => clear_positive_boosts(context).map(|()| None),
-        "clear_sub_abilities" => clear_sub_abilities(context).map(|val| Some(val)),
-        "clear_terrain" => clear_terrain(context).map(|val| Some(val)),
-        "clear_weather" => clear_weather(context).map(|val| Some(val)),
-        "clone_active_move" => clone_active_move(context).map(|val| Some(val)),
-        "copy_boosts" => copy_boosts(context).map(|val| Some(val)),
-        "cure_status" => cure_status(context).map(|val| Some(val)),
-        "damage" => damage(context).map(|val| Some(val)),
-        "debug_log" => debug_log(context).map(|()| None),
-        "decrease_friendship" => decrease_friendship(context).map(|()| None),
-        "decrease_weight" => decrease_weight(context).map(|()| None),
-        "deduct_pp" => deduct_pp(context).map(|val| Some(val)),
-        "deprioritize_move" => deprioritize_move(context).map(|()| None),
-        "direct_damage" => direct_damage(context).map(|()| None),
-        "disable_move" => disable_move(context).map(|()| None),
-        "discard_item" => discard_item(context).map(|val| Some(val)),
-        "do_move" => do_move(context).map(|()| None),
-        "do_not_animate_last_move" => do_not_animate_last_move(context).map(|()| None),
-        "eat_item" => eat_item(context).map(|val| Some(val)),
-        "eat_given_item" => eat_given_item(context).map(|val| Some(val)),
-        "effect_has_event_callback" => effect_has_event_callback(context).map(|val| Some(val)),
-        "effect_state_remove_key" => effect_state_remove_key(context),
-        "effective_weather" => effective_weather(context),
-        "end_ability" => end_ability(context).map(|()| None),
-        "end_battle" => end_battle(context).map(|()| None),
-        "end_dynamax" => end_dynamax(context).map(|()| None),
-        "end_illusion" => end_illusion(context).map(|val| Some(val)),
-        "end_item" => end_item(context).map(|()| None),
-        "escape" => escape(context).map(|val| Some(val)),
-        "event_result" => event_result(context).map(|val| Some(val)),
-        "faint" => faint(context).map(|()| None),
-        "faint_messages" => faint_messages(context).map(|()| None),
-        "floor" => floor(context).map(|val| Some(val)),
-        "force_fully_heal" => force_fully_heal(context).map(|()| None),
-        "force_switch" => force_switch(context).map(|val| Some(val)),
-        "forme_change" => forme_change(context).map(|val| Some(val)),
-        "get_all_moves" => get_all_moves(context).map(|val| Some(val)),
-        "get_ability" => get_ability(context),
-        "get_boost" => get_boost(context).map(|val| Some(val)),
-        "get_item" => get_item(context),
-        "get_move" => get_move(context),
-        "get_move_targets" => get_move_targets(context).map(|val| Some(val)),
-        "get_species" => get_species(context),
-        "get_stat" => get_stat(context).map(|val| Some(val)),
-        "has_ability" => has_ability(context).map(|val| Some(val)),
-        "has_any_type" => has_any_type(context).map(|val| Some(val)),
-        "has_item" => has_item(context).map(|val| Some(val)),
-        "has_move" => has_move(context).map(|val| Some(val)),
-        "has_pseudo_weather" => has_pseudo_weather(context).map(|val| Some(val)),
-        "has_side_condition" => has_side_condition(context).map(|val| Some(val)),
-        "has_species_registered" => has_species_registered(context).map(|val| Some(val)),
-        "has_type" => has_type(context).map(|val| Some(val)),
-        "has_type_before_forced_types" => {
-            has_type_before_forced_types(context).map(|val| Some(val))
-        }
-        "has_volatile" => has_volatile(context).map(|val| Some(val)),
-        "heal" => heal(context).map(|val| Some(val)),
-        "hit_effect" => hit_effect().map(|val| Some(val)),
-        "increase_friendship" => increase_friendship(context).map(|()| None),
-        "index" => index(context),
-        "invert_boosts" => invert_boosts(context).map(|val| Some(val)),
-        "is_adjacent" => is_adjacent(context).map(|val| Some(val)),
-        "is_ally" => is_ally(context).map(|val| Some(val)),
-        "item_has_flag" => item_has_flag(context).map(|val| Some(val)),
-        "join" => join(context).map(|val| Some(val)),
-        "last_attack" => last_attack(context),
-        "log" => log(context).map(|()| None),
-        "log_ability" => log_ability(context).map(|()| None),
-        "log_activate" => log_activate(context).map(|()| None),
-        "log_animate_move" => log_animate_move(context).map(|()| None),
-        "log_announce_item" => log_announce_item(context).map(|()| None),
-        "log_block" => log_block(context).map(|()| None),
-        "log_cant" => log_cant(context).map(|()| None),
-        "log_custom_effect" => log_custom_effect(context).map(|()| None),
-        "log_end" => log_end(context).map(|()| None),
-        "log_fail" => log_fail(context).map(|()| None),
-        "log_fail_heal" => log_fail_heal(context).map(|()| None),
-        "log_fail_unboost" => log_fail_unboost(context).map(|()| None),
-        "log_field_activate" => log_field_activate(context).map(|()| None),
-        "log_field_end" => log_field_end(context).map(|()| None),
-        "log_field_start" => log_field_start(context).map(|()| None),
-        "log_immune" => log_immune(context).map(|()| None),
-        "log_ohko" => log_ohko(context).map(|()| None),
-        "log_prepare_move" => log_prepare_move(context).map(|()| None),
-        "log_side_end" => log_side_end(context).map(|()| None),
-        "log_side_start" => log_side_start(context).map(|()| None),
-        "log_single_move" => log_single_move(context).map(|()| None),
-        "log_single_turn" => log_single_turn(context).map(|()| None),
-        "log_start" => log_start(context).map(|()| None),
-        "log_status" => log_status(context).map(|()| None),
-        "log_use_move" => log_use_move(context).map(|()| None),
-        "log_waiting" => log_waiting(context).map(|()| None),
-        "log_weather" => log_weather(context).map(|()| None),
-        "lookup_base_species" => lookup_base_species(context).map(|val| Some(val)),
-        "max" => max(context).map(|val| Some(val)),
-        "max_move" => max_move(context),
-        "min" => min(context).map(|val| Some(val)),
-        "modify_move_type" => modify_move_type(context).map(|()| None),
-        "mon_at_target_location" => mon_at_target_location(context),
-        "mon_in_position" => mon_in_position(context),
-        "move_at_move_slot_index" => move_at_move_slot_index(context),
-        "move_crit_target" => move_crit_target(context).map(|val| Some(val)),
-        "move_has_flag" => move_has_flag(context).map(|val| Some(val)),
-        "move_hit_data_has_flag_against_target" => {
-            move_hit_data_has_flag_against_target(context).map(|val| Some(val))
-        }
-        "move_hit_target" => move_hit_target(context).map(|val| Some(val)),
-        "move_makes_contact" => move_makes_contact(context).map(|val| Some(val)),
-        "move_slot" => move_slot(context).map(|val| Some(val)),
-        "move_slot_at_index" => move_slot_at_index(context),
-        "move_slot_index" => move_slot_index(context),
-        "move_target_original_hp" => move_target_original_hp(context),
-        "new_active_move" => new_active_move(context).map(|val| Some(val)),
-        "new_active_move_from_local_data" => {
-            new_active_move_from_local_data(context).map(|val| Some(val))
-        }
-        "new_object" => Ok(Some(new_object(context))),
-        "object_get" => object_get(context),
-        "object_increment" => object_increment(context).map(|val| Some(val)),
-        "object_keys" => object_keys(context).map(|val| Some(val)),
-        "object_set" => object_set(context).map(|val| Some(val)),
-        "object_value" => object_value(context),
-        "overwrite_move_slot" => overwrite_move_slot(context).map(|()| None),
-        "pending_move_action_this_turn" => pending_move_action_this_turn(context),
-        "pending_move_actions_this_turn" => {
-            pending_move_actions_this_turn(context).map(|val| Some(val))
-        }
-        "plural" => plural(context).map(|val| Some(val)),
-        "prepare_direct_move" => prepare_direct_move(context).map(|val| Some(val)),
-        "primal_reversion" => primal_reversion(context).map(|val| Some(val)),
-        "prioritize_move" => prioritize_move(context).map(|()| None),
-        "pseudo_weather_effect_state" => pseudo_weather_effect_state(context),
-        "random" => random(context).map(|val| Some(val)),
-        "random_target" => random_target(context),
-        "read_event_state_value" => read_event_state_value(context),
-        "received_attack" => received_attack(context).map(|val| Some(val)),
-        "remove" => remove(context).map(|val| Some(val)),
-        "remove_move_flag" => remove_move_flag(context).map(|()| None),
-        "remove_pseudo_weather" => remove_pseudo_weather(context).map(|val| Some(val)),
-        "remove_side_condition" => remove_side_condition(context).map(|val| Some(val)),
-        "remove_slot_condition" => remove_slot_condition(context).map(|val| Some(val)),
-        "remove_volatile" => remove_volatile(context).map(|val| Some(val)),
-        "request_mon_selection" => request_mon_selection(context).map(|()| None),
-        "reset_types" => reset_types(context).map(|val| Some(val)),
-        "restore_pp" => restore_pp(context).map(|val| Some(val)),
-        "reverse" => reverse(context).map(|val| Some(val)),
-        "revive" => revive(context).map(|val| Some(val)),
-        "run_event" => run_event(context).map(|val| Some(val)),
-        "run_event_for_each_active_mon" => run_event_for_each_active_mon(context).map(|()| None),
-        "run_event_for_mon" => run_event_for_mon(context).map(|val| Some(val)),
-        "run_event_on_mon_ability" => run_event_on_mon_ability(context),
-        "run_event_on_mon_item" => run_event_on_mon_item(context),
-        "run_event_on_mon_species" => run_event_on_mon_species(context),
-        "run_event_on_mon_volatile" => run_event_on_mon_volatile(context),
-        "run_event_on_move" => run_event_on_move(context),
-        "sample" => sample(context),
-        "save_event_state_value" => save_event_state_value(context).map(|()| None),
-        "save_move_hit_data_flag_against_target" => {
-            save_move_hit_data_flag_against_target(context).map(|()| None)
+    match function {
+        Function::Builtin(function) => function.run(context),
+        Function::Named(function_name) => {
+            let native_function = context
+                .evaluation_context()
+                .battle_context()
+                .battle()
+                .engine_options
+                .native_functions
+                .function(function_name);
+            match native_function {
+                Some(native_function) => native_function(context),
+                None => call_subroutine(context, function_name),
+            }
         }
-        "secondary_hit_effect" => secondary_hit_effect().map(|val| Some(val)),
-        "set_ability" => set_ability(context).map(|val| Some(val)),
-        "set_boost" => set_boost(context).map(|val| Some(val)),
-        "set_friendship" => set_friendship(context).map(|()| None),
-        "set_hp" => set_hp(context).map(|val| Some(val)),
-        "set_illusion" => set_illusion(context).map(|val| Some(val)),
-        "set_item" => set_item(context).map(|val| Some(val)),
-        "set_needs_switch" => set_needs_switch(context).map(|()| None),
-        "set_pp" => set_pp(context).map(|val| Some(val)),
-        "set_status" => set_status(context).map(|val| Some(val)),
-        "set_types" => set_types(context).map(|val| Some(val)),
-        "set_terrain" => set_terrain(context).map(|val| Some(val)),
-        "set_upgraded_to_max_move" => set_upgraded_to_max_move(context).map(|()| None),
-        "set_upgraded_to_z_move" => set_upgraded_to_z_move(context).map(|()| None),
-        "set_weather" => set_weather(context).map(|val| Some(val)),
-        "set_z_power_boosts" => set_z_power_boosts(context).map(|()| None),
-        "side_condition_effect_state" => side_condition_effect_state(context),
-        "skip_effect_callback" => skip_effect_callback(context).map(|()| None),
-        "special_item_data" => special_item_data(context).map(|val| Some(val)),
-        "species_has_flag" => species_has_flag(context).map(|val| Some(val)),
-        "speed_sort_mons" => speed_sort_mons(context).map(|val| Some(val)),
-        "start_ability" => start_ability(context).map(|()| None),
-        "start_item" => start_item(context).map(|()| None),
-        "status_effect_state" => status_effect_state(context),
-        "swap_boosts" => swap_boosts(context).map(|()| None),
-        "swap_position" => swap_position(context).map(|val| Some(val)),
-        "swap_side_conditions" => swap_side_conditions(context).map(|val| Some(val)),
-        "switch_out" => switch_out(context).map(|val| Some(val)),
-        "take_item" => take_item(context).map(|val| Some(val)),
-        "target_location_of_mon" => target_location_of_mon(context).map(|val| Some(val)),
-        "transform_into" => transform_into(context).map(|val| Some(val)),
-        "type_chart_effectiveness" => type_chart_effectiveness(context).map(|val| Some(val)),
-        "type_chart_immunity" => type_chart_immunity(context).map(|val| Some(val)),
-        "type_effectiveness" => type_effectiveness(context).map(|val| Some(val)),
-        "type_modifier" => type_modifier(context).map(|val| Some(val)),
-        "type_modifier_against_target" => type_modifier_against_target(context),
-        "undynamaxed_hp_calculation" => undynamaxed_hp_calculation(context).map(|val| Some(val)),
-        "use_active_move" => use_active_move(context).map(|val| Some(val)),
-        "use_given_item" => use_given_item(context).map(|val| Some(val)),
-        "use_item" => use_item(context).map(|val| Some(val)),
-        "use_move" => use_move(context).map(|val| Some(val)),
-        "valid_target" => valid_target(context).map(|val| Some(val)),
-        "value_from_local_data" => value_from_local_data(context),
-        "volatile_status_effect_state" => volatile_status_effect_state(context),
-        "will_move_this_turn" => will_move_this_turn(context).map(|val| Some(val)),
-        "z_move" => z_move(context),
-        _ => Err(general_error(format!(
-            "undefined function: {function_name}"
-        ))),
     }
 }
 
-struct FunctionContext<'eval, 'effect, 'context, 'battle, 'data> {
+fn call_subroutine(context: FunctionContext, function_name: &str) -> Result<Option<Value>> {
+    let subroutine =
+        EffectManager::parsed_subroutine(context.context.battle_context_mut(), function_name)?
+            .wrap_expectation_with_format(format_args!("undefined function: {function_name}"))?;
+    EffectManager::evaluate_subroutine(
+        context.context,
+        &subroutine,
+        SubroutineCall {
+            name: function_name,
+            args: context.args,
+            event: context.event,
+            event_state: context.event_state,
+            effect_state_connector: context.effect_state,
+            effect_mon_handle: context.effect_mon_handle,
+            event_origin_mon_handle: context.event_origin_mon_handle,
+            untrusted: context.untrusted,
+        },
+    )
+}
+
+/// The static signature of an fxlang function.
+///
+/// Used for checking programs without evaluating them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FunctionSignature {
+    /// The number of arguments that must be passed to the function.
+    ///
+    /// Optional arguments and flags are not counted.
+    pub required_args: usize,
+    /// Whether the function can return a value.
+    pub returns_value: bool,
+}
+
+/// Looks up the [`FunctionSignature`] of a built-in fxlang function.
+///
+/// Returns [`None`] if the function does not exist.
+pub fn function_signature(function_name: &str) -> Option<FunctionSignature> {
+    BuiltinFunction::new(function_name).map(|function| function.signature())
+}
+
+/// The context of an fxlang function call.
+///
+/// Provides access to the arguments of the function call and the battle it is evaluated in.
+pub struct FunctionContext<'eval, 'effect, 'context, 'battle, 'data> {
     context: &'eval mut EvaluationContext<'effect, 'context, 'battle, 'data>,
     args: VecDeque<Value>,
     event: BattleEvent,
@@ -401,6 +2029,7 @@ struct FunctionContext<'eval, 'effect, 'context, 'battle, 'data> {
     effect_state: Option<DynamicEffectStateConnector>,
     effect_mon_handle: Option<MonHandle>,
     event_origin_mon_handle: Option<MonHandle>,
+    untrusted: bool,
     flags: HashMap<String, bool>,
 }
 
@@ -415,6 +2044,7 @@ impl<'eval, 'effect, 'context, 'battle, 'data>
         effect_state: Option<DynamicEffectStateConnector>,
         effect_mon_handle: Option<MonHandle>,
         event_origin_mon_handle: Option<MonHandle>,
+        untrusted: bool,
     ) -> Self {
         Self {
             context,
@@ -424,22 +2054,25 @@ impl<'eval, 'effect, 'context, 'battle, 'data>
             effect_state,
             effect_mon_handle,
             event_origin_mon_handle,
+            untrusted,
             flags: HashMap::default(),
         }
     }
 
-    fn evaluation_context(&self) -> &EvaluationContext<'effect, 'context, 'battle, 'data> {
+    /// The [`EvaluationContext`] the function is called in.
+    pub fn evaluation_context(&self) -> &EvaluationContext<'effect, 'context, 'battle, 'data> {
         self.context
     }
 
-    fn evaluation_context_mut(
+    /// The mutable [`EvaluationContext`] the function is called in.
+    pub fn evaluation_context_mut(
         &mut self,
     ) -> &mut EvaluationContext<'effect, 'context, 'battle, 'data> {
         self.context
     }
 
-    #[allow(unused)]
-    fn event(&self) -> BattleEvent {
+    /// The event the function is called for.
+    pub fn event(&self) -> BattleEvent {
         self.event
     }
 
@@ -447,7 +2080,8 @@ impl<'eval, 'effect, 'context, 'battle, 'data>
         self.event_state
     }
 
-    fn effect_state(&self) -> Option<DynamicEffectStateConnector> {
+    /// The effect state of the effect whose program called the function.
+    pub fn effect_state(&self) -> Option<DynamicEffectStateConnector> {
         self.effect_state.clone()
     }
 
@@ -462,11 +2096,13 @@ impl<'eval, 'effect, 'context, 'battle, 'data>
         )))
     }
 
-    fn front(&self) -> Option<&Value> {
+    /// The next argument, without consuming it.
+    pub fn front(&self) -> Option<&Value> {
         self.args.front()
     }
 
-    fn pop_front(&mut self) -> Option<Value> {
+    /// Consumes the next argument.
+    pub fn pop_front(&mut self) -> Option<Value> {
         self.args.pop_front()
     }
 
@@ -485,7 +2121,10 @@ impl<'eval, 'effect, 'context, 'battle, 'data>
         }
     }
 
-    fn has_flag(&mut self, flag: &str) -> bool {
+    /// Checks if the given flag was passed as an argument.
+    ///
+    /// The flag is consumed from the arguments, wherever it appears.
+    pub fn has_flag(&mut self, flag: &str) -> bool {
         if self.flags.get(flag).cloned().is_some_and(|val| val) {
             // Still check the flag value so that it is removed, in case it was forcefully set but
             // is still set by the function call.
@@ -501,7 +2140,8 @@ impl<'eval, 'effect, 'context, 'battle, 'data>
         self.flags.insert(flag.to_owned(), val);
     }
 
-    fn rest_of_args(&mut self) -> impl Iterator<Item = Value> {
+    /// Consumes all remaining arguments.
+    pub fn rest_of_args(&mut self) -> impl Iterator<Item = Value> {
         let mut args = VecDeque::new();
         core::mem::swap(&mut args, &mut self.args);
         args.into_iter()
@@ -997,9 +2637,6 @@ impl<'eval, 'effect, 'context, 'battle, 'data>
     }
 }
 
-/// Logs a debug message to the battle log.
-///
-/// @param {[`ValueType::String`]} message The message to log.
 fn debug_log(mut context: FunctionContext) -> Result<()> {
     let mut event = battle_log_entry!("fxlang_debug");
     let mut i = 0;
@@ -1024,10 +2661,6 @@ fn log_internal(mut context: FunctionContext, title: String) -> Result<()> {
     Ok(())
 }
 
-/// Logs a message to the battle log.
-///
-/// @param {[`ValueType::String`]} title The log title.
-/// @param {[`ValueType::String`]} ... Additional log entries.
 fn log(mut context: FunctionContext) -> Result<()> {
     let title = context
         .pop_front()
@@ -1090,10 +2723,6 @@ fn log_effect_activation_base(
     core_battle_logs::effect_activation(context.battle_context_mut(), header.to_owned(), activation)
 }
 
-/// Logs a custom effect to the battle log.
-///
-/// @param {[`ValueType::String`]} header The log header.
-/// @param {[`ValueType::String`]} ... Additional log entries.
 fn log_custom_effect(mut context: FunctionContext) -> Result<()> {
     let header = context
         .pop_front()
@@ -1103,10 +2732,6 @@ fn log_custom_effect(mut context: FunctionContext) -> Result<()> {
     log_effect_activation_base(context, &header, LogEffectActivationBaseContext::default())
 }
 
-/// Logs an ability activation to the battle log.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon whose ability activated.
-/// @param {[`ValueType::String`] | [`ValueType::Effect`]} [ability] The ability ID.
 fn log_ability(mut context: FunctionContext) -> Result<()> {
     context.set_with_target(true);
     log_effect_activation_base(
@@ -1116,17 +2741,11 @@ fn log_ability(mut context: FunctionContext) -> Result<()> {
     )
 }
 
-/// Logs an item announcement to the battle log.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon whose item is announced.
 fn log_announce_item(mut context: FunctionContext) -> Result<()> {
     let target = context.target_handle_positional()?;
     core_battle_logs::item(&mut context.forward_to_applying_effect_context_with_target(target)?)
 }
 
-/// Logs an effect activation to the battle log.
-///
-/// @param {[`ValueType::String`]} ... Additional log entries.
 fn log_activate(context: FunctionContext) -> Result<()> {
     log_effect_activation_base(
         context,
@@ -1135,9 +2754,6 @@ fn log_activate(context: FunctionContext) -> Result<()> {
     )
 }
 
-/// Logs a block effect to the battle log.
-///
-/// @param {[`ValueType::String`]} ... Additional log entries.
 fn log_block(mut context: FunctionContext) -> Result<()> {
     context.set_use_source_effect(true);
     context.set_with_source_effect(true);
@@ -1146,9 +2762,6 @@ fn log_block(mut context: FunctionContext) -> Result<()> {
     log_effect_activation_base(context, "block", LogEffectActivationBaseContext::default())
 }
 
-/// Logs a field effect activation to the battle log.
-///
-/// @param {[`ValueType::String`]} ... Additional log entries.
 fn log_field_activate(context: FunctionContext) -> Result<()> {
     log_effect_activation_base(
         context,
@@ -1157,9 +2770,6 @@ fn log_field_activate(context: FunctionContext) -> Result<()> {
     )
 }
 
-/// Logs a single-turn effect activation to the battle log.
-///
-/// @param {[`ValueType::String`]} ... Additional log entries.
 fn log_single_turn(context: FunctionContext) -> Result<()> {
     log_effect_activation_base(
         context,
@@ -1168,9 +2778,6 @@ fn log_single_turn(context: FunctionContext) -> Result<()> {
     )
 }
 
-/// Logs a single-move effect activation to the battle log.
-///
-/// @param {[`ValueType::String`]} ... Additional log entries.
 fn log_single_move(context: FunctionContext) -> Result<()> {
     log_effect_activation_base(
         context,
@@ -1197,41 +2804,24 @@ fn log_move_internal(mut context: FunctionContext, animate_only: bool) -> Result
     core_battle_logs::use_move(&mut context, target_handle, animate_only)
 }
 
-/// Logs a move animation to the battle log.
-///
-/// @param {[`ValueType::Effect`]} active_move The active move.
-/// @param {[`ValueType::Mon`]} [target] The target of the move.
 fn log_animate_move(context: FunctionContext) -> Result<()> {
     log_move_internal(context, true)
 }
 
-/// Logs a move usage to the battle log.
-///
-/// @param {[`ValueType::Effect`]} active_move The active move.
-/// @param {[`ValueType::Mon`]} [target] The target of the move.
 fn log_use_move(context: FunctionContext) -> Result<()> {
     log_move_internal(context, false)
 }
 
-/// Logs the start of an effect to the battle log.
-///
-/// @param {[`ValueType::String`]} ... Additional log entries.
 fn log_start(mut context: FunctionContext) -> Result<()> {
     context.set_with_target(context.evaluation_context().target_handle().is_some());
     log_effect_activation_base(context, "start", LogEffectActivationBaseContext::default())
 }
 
-/// Logs the end of an effect to the battle log.
-///
-/// @param {[`ValueType::String`]} ... Additional log entries.
 fn log_end(mut context: FunctionContext) -> Result<()> {
     context.set_with_target(context.evaluation_context().target_handle().is_some());
     log_effect_activation_base(context, "end", LogEffectActivationBaseContext::default())
 }
 
-/// Logs the start of a side condition to the battle log.
-///
-/// @param {[`ValueType::String`]} ... Additional log entries.
 fn log_side_start(context: FunctionContext) -> Result<()> {
     log_effect_activation_base(
         context,
@@ -1243,9 +2833,6 @@ fn log_side_start(context: FunctionContext) -> Result<()> {
     )
 }
 
-/// Logs the end of a side condition to the battle log.
-///
-/// @param {[`ValueType::String`]} ... Additional log entries.
 fn log_side_end(context: FunctionContext) -> Result<()> {
     log_effect_activation_base(
         context,
@@ -1257,9 +2844,6 @@ fn log_side_end(context: FunctionContext) -> Result<()> {
     )
 }
 
-/// Logs the start of a field effect to the battle log.
-///
-/// @param {[`ValueType::String`]} ... Additional log entries.
 fn log_field_start(context: FunctionContext) -> Result<()> {
     log_effect_activation_base(
         context,
@@ -1268,9 +2852,6 @@ fn log_field_start(context: FunctionContext) -> Result<()> {
     )
 }
 
-/// Logs the end of a field effect to the battle log.
-///
-/// @param {[`ValueType::String`]} ... Additional log entries.
 fn log_field_end(context: FunctionContext) -> Result<()> {
     log_effect_activation_base(
         context,
@@ -1279,9 +2860,6 @@ fn log_field_end(context: FunctionContext) -> Result<()> {
     )
 }
 
-/// Logs move preparation to the battle log.
-///
-/// @param {[`ValueType::Mon`]} [target] The target of the move.
 fn log_prepare_move(mut context: FunctionContext) -> Result<()> {
     let target = match context.pop_front() {
         Some(value) => Some(
@@ -1309,9 +2887,6 @@ fn log_prepare_move(mut context: FunctionContext) -> Result<()> {
     Ok(())
 }
 
-/// Logs that a Mon cannot perform an action.
-///
-/// @param {[`ValueType::String`]} ... Additional log entries.
 fn log_cant(mut context: FunctionContext) -> Result<()> {
     let effect = context.effect_handle()?;
     let source = if context.with_source() {
@@ -1322,10 +2897,6 @@ fn log_cant(mut context: FunctionContext) -> Result<()> {
     core_battle_logs::cant(&mut context.target_context()?, effect, source)
 }
 
-/// Logs a status change to the battle log.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon whose status changed.
-/// @param {[`ValueType::String`]} [status] The status ID.
 fn log_status(mut context: FunctionContext) -> Result<()> {
     let status = context
         .pop_front()
@@ -1346,9 +2917,6 @@ fn log_status(mut context: FunctionContext) -> Result<()> {
     )
 }
 
-/// Logs weather change or continuation to the battle log.
-///
-/// @param {[`ValueType::String`]} [weather] The weather ID.
 fn log_weather(mut context: FunctionContext) -> Result<()> {
     let (title, mut additional) = match context.pop_front() {
         Some(value) => (
@@ -1375,10 +2943,6 @@ fn log_weather(mut context: FunctionContext) -> Result<()> {
     )
 }
 
-/// Logs an effect failure to the battle log.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon the effect failed on.
-/// @param {[`ValueType::Effect`]} [what] The effect that failed.
 fn log_fail(mut context: FunctionContext) -> Result<()> {
     let effect_handle = if context.from_effect() {
         Some(context.effect_handle()?)
@@ -1396,10 +2960,6 @@ fn log_fail(mut context: FunctionContext) -> Result<()> {
     core_battle_logs::fail(&mut context.mon_context(mon_handle)?, what, effect_handle)
 }
 
-/// Logs an unboost failure to the battle log.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon whose unboost failed.
-/// @param {[`ValueType::List`] | [`ValueType::String`]} ... The boosts that failed to unboost.
 fn log_fail_unboost(mut context: FunctionContext) -> Result<()> {
     let effect_handle = if context.from_effect() {
         Some(context.effect_handle()?)
@@ -1427,9 +2987,6 @@ fn log_fail_unboost(mut context: FunctionContext) -> Result<()> {
     )
 }
 
-/// Logs immunity to an effect.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon that is immune.
 fn log_immune(mut context: FunctionContext) -> Result<()> {
     let mon_handle = context.target_handle_positional()?;
     let effect = if context.from_effect() {
@@ -1440,27 +2997,16 @@ fn log_immune(mut context: FunctionContext) -> Result<()> {
     core_battle_logs::immune(&mut context.mon_context(mon_handle)?, effect)
 }
 
-/// Logs a heal failure to the battle log.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon that failed to heal.
 fn log_fail_heal(mut context: FunctionContext) -> Result<()> {
     let mon_handle = context.target_handle_positional()?;
     core_battle_logs::fail_heal(&mut context.mon_context(mon_handle)?)
 }
 
-/// Logs an OHKO move to the battle log.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon that was hit by the OHKO move.
 fn log_ohko(mut context: FunctionContext) -> Result<()> {
     let mon_handle = context.target_handle_positional()?;
     core_battle_logs::ohko(&mut context.mon_context(mon_handle)?)
 }
 
-/// Generates a random number.
-///
-/// @param {[`ValueType::UFraction`]} [min] The minimum value.
-/// @param {[`ValueType::UFraction`]} [max] The maximum value.
-/// @returns {[`ValueType::UFraction`]} A random number.
 fn random(mut context: FunctionContext) -> Result<Value> {
     let a = context
         .pop_front()
@@ -1487,11 +3033,6 @@ fn random(mut context: FunctionContext) -> Result<Value> {
     Ok(Value::UFraction(val.into()))
 }
 
-/// Performs a random chance check.
-///
-/// @param {[`ValueType::UFraction`]} numerator The numerator of the probability.
-/// @param {[`ValueType::UFraction`]} [denominator] The denominator of the probability.
-/// @returns {[`ValueType::Boolean`]} Whether the check succeeded.
 fn chance(mut context: FunctionContext) -> Result<Value> {
     let a = context
         .pop_front()
@@ -1518,10 +3059,6 @@ fn chance(mut context: FunctionContext) -> Result<Value> {
     Ok(Value::Boolean(val))
 }
 
-/// Randomly samples an element from a list.
-///
-/// @param {[`ValueType::List`]} list The list to sample from.
-/// @returns_item_from_list
 fn sample(mut context: FunctionContext) -> Result<Option<Value>> {
     let list = context
         .pop_front()
@@ -1535,13 +3072,6 @@ fn sample(mut context: FunctionContext) -> Result<Option<Value>> {
     .cloned())
 }
 
-/// Deals damage to a Mon.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to damage.
-/// @param {[`ValueType::UFraction`]} amount The amount of damage.
-/// @param {[`ValueType::Effect`]} [effect] The effect causing the damage.
-/// @returns {[`ValueType::UFraction`]} The amount of damage dealt, rounded up to the nearest whole
-/// number.
 fn damage(mut context: FunctionContext) -> Result<Value> {
     let target_handle = context.target_handle_positional()?;
 
@@ -1566,11 +3096,6 @@ fn damage(mut context: FunctionContext) -> Result<Value> {
     .map(|damage| Value::UFraction(damage.into()))
 }
 
-/// Deals direct damage to a Mon (bypasses most calculations).
-///
-/// @param {[`ValueType::Mon`]} [target] The Mon to damage.
-/// @param {[`ValueType::UFraction`]} amount The amount of damage.
-/// @param {[`ValueType::Effect`]} [damaging_effect] The effect causing the damage.
 fn direct_damage(mut context: FunctionContext) -> Result<()> {
     let target_handle = context.target_handle_positional()?;
 
@@ -1593,11 +3118,6 @@ fn direct_damage(mut context: FunctionContext) -> Result<()> {
     Ok(())
 }
 
-/// Checks if a Mon has a specific ability.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to check.
-/// @param {[`ValueType::String`] | [`ValueType::Effect`]} ability The ability ID to check.
-/// @returns {[`ValueType::Boolean`]} Whether the Mon has the ability.
 fn has_ability(mut context: FunctionContext) -> Result<Value> {
     let mon_handle = context.target_handle_positional()?;
 
@@ -1627,11 +3147,6 @@ fn has_ability(mut context: FunctionContext) -> Result<Value> {
     ))
 }
 
-/// Checks if a Mon has a specific item.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to check.
-/// @param {[`ValueType::String`] | [`ValueType::Effect`]} item The item ID to check.
-/// @returns {[`ValueType::Boolean`]} Whether the Mon has the item.
 fn has_item(mut context: FunctionContext) -> Result<Value> {
     let mon_handle = context.target_handle_positional()?;
     let item = context
@@ -1646,11 +3161,6 @@ fn has_item(mut context: FunctionContext) -> Result<Value> {
     )))
 }
 
-/// Checks if a Mon has a specific volatile effect.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to check.
-/// @param {[`ValueType::String`] | [`ValueType::Effect`]} effect The volatile effect ID.
-/// @returns {[`ValueType::Boolean`]} Whether the Mon has the volatile effect.
 fn has_volatile(mut context: FunctionContext) -> Result<Value> {
     let mon_handle = context.target_handle_positional()?;
 
@@ -1678,10 +3188,6 @@ fn has_volatile(mut context: FunctionContext) -> Result<Value> {
     })))
 }
 
-/// Cures a Mon's status condition.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to cure.
-/// @returns {[`ValueType::EventResult`]} Whether the status was cured.
 fn cure_status(mut context: FunctionContext) -> Result<Value> {
     let silent = context.silent();
     let no_effect = context.no_effect();
@@ -1691,11 +3197,6 @@ fn cure_status(mut context: FunctionContext) -> Result<Value> {
         .map(|val| Value::EventResult(val))
 }
 
-/// Checks if a move has a specific flag.
-///
-/// @param {[`ValueType::String`] | [`ValueType::Effect`]} move_handle The move to check.
-/// @param {[`ValueType::String`]} flag The flag ID to check.
-/// @returns {[`ValueType::Boolean`]} Whether the move has the flag.
 fn move_has_flag(mut context: FunctionContext) -> Result<Value> {
     let value = context.pop_front().wrap_expectation("missing move")?;
     let move_flag = context
@@ -1737,11 +3238,6 @@ fn move_has_flag(mut context: FunctionContext) -> Result<Value> {
     }
 }
 
-/// Checks if an item has a specific flag.
-///
-/// @param {[`ValueType::String`] | [`ValueType::Effect`]} item_handle The item to check.
-/// @param {[`ValueType::String`]} flag The flag ID to check.
-/// @returns {[`ValueType::Boolean`]} Whether the item has the flag.
 fn item_has_flag(mut context: FunctionContext) -> Result<Value> {
     let item_id = context
         .pop_front()
@@ -1768,11 +3264,6 @@ fn item_has_flag(mut context: FunctionContext) -> Result<Value> {
     ))
 }
 
-/// Returns whether the ability has the given flag.
-///
-/// @param {[`ValueType::String`] | [`ValueType::Effect`]} ability_handle The ability to check.
-/// @param {[`ValueType::String`]} flag The flag ID to check.
-/// @returns {[`ValueType::Boolean`]}
 fn ability_has_flag(mut context: FunctionContext) -> Result<Value> {
     let ability_id = context
         .pop_front()
@@ -1799,11 +3290,6 @@ fn ability_has_flag(mut context: FunctionContext) -> Result<Value> {
     ))
 }
 
-/// Checks if a species has a specific flag.
-///
-/// @param {[`ValueType::String`] | [`ValueType::Effect`]} species_handle The species to check.
-/// @param {[`ValueType::String`]} flag The flag ID to check.
-/// @returns {[`ValueType::Boolean`]} Whether the species has the flag.
 fn species_has_flag(mut context: FunctionContext) -> Result<Value> {
     let species_id = context
         .pop_front()
@@ -1830,10 +3316,6 @@ fn species_has_flag(mut context: FunctionContext) -> Result<Value> {
     ))
 }
 
-/// Removes a flag from a move.
-///
-/// @param {[`ValueType::Effect`]} [move_handle] The move to modify.
-/// @param {[`ValueType::String`]} flag The flag to remove.
 fn remove_move_flag(mut context: FunctionContext) -> Result<()> {
     let active_move = context
         .pop_front()
@@ -1855,10 +3337,6 @@ fn remove_move_flag(mut context: FunctionContext) -> Result<()> {
     Ok(())
 }
 
-/// Adds a flag to a move.
-///
-/// @param {[`ValueType::Effect`]} [move_handle] The move to modify.
-/// @param {[`ValueType::String`]} flag The flag to add.
 fn add_move_flag(mut context: FunctionContext) -> Result<()> {
     let active_move = context
         .pop_front()
@@ -1880,12 +3358,6 @@ fn add_move_flag(mut context: FunctionContext) -> Result<()> {
     Ok(())
 }
 
-/// Adds a volatile effect to a Mon.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to modify.
-/// @param {[`ValueType::String`] | [`ValueType::Effect`]} [effect] The volatile effect ID.
-/// @flag link If set, the volatile effect is linked to the current effect.
-/// @returns {[`ValueType::EventResult`]} Whether the volatile effect was added.
 fn add_volatile(mut context: FunctionContext) -> Result<Value> {
     let mon_handle = context.target_handle_positional()?;
     let volatile = context
@@ -1904,11 +3376,6 @@ fn add_volatile(mut context: FunctionContext) -> Result<Value> {
     .map(|val| Value::EventResult(val))
 }
 
-/// Removes a volatile effect from a Mon.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to modify.
-/// @param {[`ValueType::String`] | [`ValueType::Effect`]} [effect] The volatile effect ID.
-/// @returns {[`ValueType::EventResult`]} Whether the volatile effect was removed.
 fn remove_volatile(mut context: FunctionContext) -> Result<Value> {
     let mon_handle = context.target_handle_positional()?;
     let volatile = context
@@ -1924,10 +3391,6 @@ fn remove_volatile(mut context: FunctionContext) -> Result<Value> {
         .map(|val| Value::EventResult(val))
 }
 
-/// Runs a battle event.
-///
-/// @param {[`ValueType::String`]} event The event name.
-/// @returns {[`ValueType::Boolean`]} The event result.
 fn run_event(mut context: FunctionContext) -> Result<Value> {
     let event = context
         .pop_front()
@@ -1970,10 +3433,6 @@ fn run_event(mut context: FunctionContext) -> Result<Value> {
     }
 }
 
-/// Runs an event on a Mon.
-///
-/// @param {[`ValueType::String`]} event The event ID.
-/// @returns {[`ValueType::Boolean`]} Whether the event was successful.
 fn run_event_for_mon(mut context: FunctionContext) -> Result<Value> {
     let event = context
         .pop_front()
@@ -1987,9 +3446,6 @@ fn run_event_for_mon(mut context: FunctionContext) -> Result<Value> {
     >(&mut context.target_context()?, event)))
 }
 
-/// Runs an event on each active Mon.
-///
-/// @param {[`ValueType::String`]} event The event ID.
 fn run_event_for_each_active_mon(mut context: FunctionContext) -> Result<()> {
     let event = context
         .pop_front()
@@ -2003,10 +3459,6 @@ fn run_event_for_each_active_mon(mut context: FunctionContext) -> Result<()> {
     )
 }
 
-/// Runs an event on a Mon's ability.
-///
-/// @param {[`ValueType::String`]} event The event ID.
-/// @returns {[`ValueType::Boolean`] | [`ValueType::Undefined`]}
 fn run_event_on_mon_ability(mut context: FunctionContext) -> Result<Option<Value>> {
     let event = context
         .pop_front()
@@ -2019,10 +3471,6 @@ fn run_event_on_mon_ability(mut context: FunctionContext) -> Result<Option<Value
     Ok(core_battle_effects::run_ability_event::<_, _, Option<Value>>(&mut context, event, ()))
 }
 
-/// Runs an event on a Mon's item.
-///
-/// @param {[`ValueType::String`]} event The event ID.
-/// @returns {[`ValueType::Boolean`] | [`ValueType::Undefined`]}
 fn run_event_on_mon_item(mut context: FunctionContext) -> Result<Option<Value>> {
     let event = context
         .pop_front()
@@ -2039,10 +3487,6 @@ fn run_event_on_mon_item(mut context: FunctionContext) -> Result<Option<Value>>
     ))
 }
 
-/// Runs an event on a Mon's species.
-///
-/// @param {[`ValueType::String`]} event The event ID.
-/// @returns {[`ValueType::Boolean`] | [`ValueType::Undefined`]}
 fn run_event_on_mon_species(mut context: FunctionContext) -> Result<Option<Value>> {
     let event = context
         .pop_front()
@@ -2071,11 +3515,6 @@ fn run_event_on_mon_species(mut context: FunctionContext) -> Result<Option<Value
     ))
 }
 
-/// Runs an event on a Mon's volatile effect.
-///
-/// @param {[`ValueType::String`]} volatile The volatile effect ID.
-/// @param {[`ValueType::String`]} event The event ID.
-/// @returns {[`ValueType::Boolean`] | [`ValueType::Undefined`]}
 fn run_event_on_mon_volatile(mut context: FunctionContext) -> Result<Option<Value>> {
     let status = context
         .pop_front()
@@ -2113,10 +3552,6 @@ fn run_event_on_mon_volatile(mut context: FunctionContext) -> Result<Option<Valu
     ))
 }
 
-/// Runs an event on an active move.
-///
-/// @param {[`ValueType::String`]} event The event ID.
-/// @returns {[`ValueType::Boolean`] | [`ValueType::Undefined`]}
 fn run_event_on_move(mut context: FunctionContext) -> Result<Option<Value>> {
     let event = context
         .pop_front()
@@ -2154,9 +3589,6 @@ fn run_event_on_move(mut context: FunctionContext) -> Result<Option<Value>> {
     ))
 }
 
-/// Prevents the last move from being animated.
-///
-/// @param {[`ValueType::Effect`]} [active_move] The active move.
 fn do_not_animate_last_move(mut context: FunctionContext) -> Result<()> {
     core_battle_logs::do_not_animate_last_move(
         &mut context
@@ -2168,10 +3600,6 @@ fn do_not_animate_last_move(mut context: FunctionContext) -> Result<()> {
     Ok(())
 }
 
-/// Calculates damage for a move.
-///
-/// @param {[`ValueType::Mon`]} [target] The move target.
-/// @returns {[`ValueType::UFraction`]} The calculated damage amount.
 fn calculate_damage(mut context: FunctionContext) -> Result<Value> {
     let target_handle = context.target_handle_positional()?;
     let mut context = context
@@ -2185,11 +3613,6 @@ fn calculate_damage(mut context: FunctionContext) -> Result<Value> {
     }
 }
 
-/// Calculates confusion damage for a Mon.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to damage.
-/// @param {[`ValueType::UFraction`]} base_power The base power of the confusion attack.
-/// @returns {[`ValueType::UFraction`]} The calculated confusion damage.
 fn calculate_confusion_damage(mut context: FunctionContext) -> Result<Value> {
     let mon_handle = context.target_handle_positional()?;
     let base_power = context
@@ -2204,11 +3627,6 @@ fn calculate_confusion_damage(mut context: FunctionContext) -> Result<Value> {
     .map(|value| Value::UFraction(value.into()))
 }
 
-/// Returns the maximum of two values.
-///
-/// @param {[`ValueType::UFraction`]} a The first value.
-/// @param {[`ValueType::UFraction`]} b The second value.
-/// @returns {[`ValueType::UFraction`]} The larger value.
 fn max(mut context: FunctionContext) -> Result<Value> {
     let mut first = context
         .pop_front()
@@ -2225,11 +3643,6 @@ fn max(mut context: FunctionContext) -> Result<Value> {
     Ok(first)
 }
 
-/// Returns the minimum of two values.
-///
-/// @param {[`ValueType::UFraction`]} a The first value.
-/// @param {[`ValueType::UFraction`]} b The second value.
-/// @returns {[`ValueType::UFraction`]} The smaller value.
 fn min(mut context: FunctionContext) -> Result<Value> {
     let mut first = context
         .pop_front()
@@ -2246,10 +3659,6 @@ fn min(mut context: FunctionContext) -> Result<Value> {
     Ok(first)
 }
 
-/// Rounds a number down to the nearest whole number.
-///
-/// @param {[`ValueType::Fraction`]} value The value to floor.
-/// @returns {[`ValueType::UFraction`]} The floored value.
 fn floor(mut context: FunctionContext) -> Result<Value> {
     let value = match context.pop_front().wrap_expectation("missing number")? {
         Value::Fraction(number) => Value::Fraction(number.floor().into()),
@@ -2261,12 +3670,6 @@ fn floor(mut context: FunctionContext) -> Result<Value> {
     Ok(value)
 }
 
-/// Clamps a number between a minimum and maximum value.
-///
-/// @param {[`ValueType::UFraction`]} value The value to clamp.
-/// @param {[`ValueType::UFraction`]} min The minimum value.
-/// @param {[`ValueType::UFraction`]} max The maximum value.
-/// @returns {[`ValueType::UFraction`]} The clamped value.
 fn clamp_number(mut context: FunctionContext) -> Result<Value> {
     let number = context.pop_front().wrap_expectation("missing number")?;
     let min = context.pop_front().wrap_expectation("missing minimum")?;
@@ -2297,11 +3700,6 @@ fn clamp_number(mut context: FunctionContext) -> Result<Value> {
     }
 }
 
-/// Heals a Mon.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to heal.
-/// @param {[`ValueType::UFraction`]} amount The amount to heal.
-/// @returns {[`ValueType::UFraction`]} The actual amount healed.
 fn heal(mut context: FunctionContext) -> Result<Value> {
     let primary_effect = context.primary_effect();
     let mon_handle = context.target_handle_positional()?;
@@ -2318,11 +3716,6 @@ fn heal(mut context: FunctionContext) -> Result<Value> {
     .map(|val| Value::UFraction(val.into()))
 }
 
-/// Revives a fainted Mon.
-///
-/// @param {[`ValueType::Mon`]} mon The Mon to revive.
-/// @param {[`ValueType::UFraction`]} hp_percent The percentage of HP to restore.
-/// @returns {[`ValueType::UFraction`]} The amount of HP restored.
 fn revive(mut context: FunctionContext) -> Result<Value> {
     let mon_handle = context.target_handle_positional()?;
     let hp = context
@@ -2337,11 +3730,6 @@ fn revive(mut context: FunctionContext) -> Result<Value> {
     .map(|val| Value::UFraction(val.into()))
 }
 
-/// Applies drain effects (healing based on damage dealt).
-///
-/// @param {[`ValueType::Mon`]} [target] The target Mon.
-/// @param {[`ValueType::Mon`]} [source] The source Mon.
-/// @param {[`ValueType::UFraction`]} damage The amount of damage healed.
 fn apply_drain(mut context: FunctionContext) -> Result<Value> {
     let target_handle = context.target_handle_positional()?;
     let source_handle = context.source_handle_positional()?;
@@ -2361,10 +3749,6 @@ fn apply_drain(mut context: FunctionContext) -> Result<Value> {
     .map(|val| Value::UFraction(val.into()))
 }
 
-/// Applies recoil damage to the move user.
-///
-/// @param {[`ValueType::UFraction`]} damage The amount of damage dealt to the target.
-/// @returns {[`ValueType::UFraction`]} Damage dealt.
 fn apply_recoil_damage(mut context: FunctionContext) -> Result<Value> {
     let damage = context
         .pop_front()
@@ -2378,11 +3762,6 @@ fn apply_recoil_damage(mut context: FunctionContext) -> Result<Value> {
         .map(|val| Value::UFraction(val.into()))
 }
 
-/// Sets a Mon's status condition.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to modify.
-/// @param {[`ValueType::String`]} status The status ID.
-/// @returns {[`ValueType::EventResult`]} Whether the status was successfully set.
 fn set_status(mut context: FunctionContext) -> Result<Value> {
     let primary_effect = context.primary_effect();
     let mon_handle = context.target_handle_positional()?;
@@ -2401,11 +3780,6 @@ fn set_status(mut context: FunctionContext) -> Result<Value> {
     .map(|val| Value::EventResult(val))
 }
 
-/// Checks if two Mons are allies.
-///
-/// @param {[`ValueType::Mon`]} [mon] The first Mon.
-/// @param {[`ValueType::Mon`]} other The second Mon.
-/// @returns {[`ValueType::Boolean`]} Whether the Mons are allies.
 fn is_ally(mut context: FunctionContext) -> Result<Value> {
     let left_mon_handle = context.target_handle_positional()?;
     let right_mon_handle = context
@@ -2421,21 +3795,12 @@ fn is_ally(mut context: FunctionContext) -> Result<Value> {
     ))
 }
 
-/// Returns a list of all boostable stats.
-///
-/// @returns {[`ValueType::List`]} A list of stats.
-/// @returnsitem {[`ValueType::Boost`]}
 fn boostable_stats() -> Value {
     Value::List(Vec::from_iter(
         BoostOrderIterator::new().map(|boost| Value::Boost(boost)),
     ))
 }
 
-/// Gets a boost level from a boost table.
-///
-/// @param {[`ValueType::BoostTable`]} boosts The boost table to query.
-/// @param {[`ValueType::Stat`]} stat The stat to query.
-/// @returns {[`ValueType::Fraction`]} The boost level.
 fn get_boost(mut context: FunctionContext) -> Result<Value> {
     let boosts = context
         .pop_front()
@@ -2450,12 +3815,6 @@ fn get_boost(mut context: FunctionContext) -> Result<Value> {
     Ok(Value::Fraction(boosts.get(boost).into()))
 }
 
-/// Sets a boost value in a boost table.
-///
-/// @param {[`ValueType::Object`]} boosts The boost table.
-/// @param {[`ValueType::Stat`]} boost The stat to boost.
-/// @param {[`ValueType::Fraction`]} value The boost value.
-/// @returns {[`ValueType::Object`]} The modified boost table.
 fn set_boost(mut context: FunctionContext) -> Result<Value> {
     let mut boosts = context
         .pop_front()
@@ -2476,11 +3835,6 @@ fn set_boost(mut context: FunctionContext) -> Result<Value> {
     Ok(Value::BoostTable(boosts))
 }
 
-/// Checks if a Mon has a specific type.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to check.
-/// @param {[`ValueType::Type`]} target_type The type to check.
-/// @returns {[`ValueType::Boolean`]} Whether the Mon has the type.
 fn has_type(mut context: FunctionContext) -> Result<Value> {
     let mon_handle = context.target_handle_positional()?;
     let typ = context
@@ -2494,11 +3848,6 @@ fn has_type(mut context: FunctionContext) -> Result<Value> {
     )))
 }
 
-/// Checks if a Mon has any of the given types.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to check.
-/// @param {[`ValueType::List`]} types The list of types to check.
-/// @returns {[`ValueType::Boolean`]} Whether the Mon has any of the types.
 fn has_any_type(mut context: FunctionContext) -> Result<Value> {
     let mon_handle = context.target_handle_positional()?;
     let types = context
@@ -2516,11 +3865,6 @@ fn has_any_type(mut context: FunctionContext) -> Result<Value> {
     Ok(Value::Boolean(false))
 }
 
-/// Checks if a Mon has a specific type before forced type changes.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to check.
-/// @param {[`ValueType::Type`]} target_type The type to check.
-/// @returns {[`ValueType::Boolean`]} Whether the Mon has the type.
 fn has_type_before_forced_types(mut context: FunctionContext) -> Result<Value> {
     let mon_handle = context.target_handle_positional()?;
     let typ = context
@@ -2534,11 +3878,6 @@ fn has_type_before_forced_types(mut context: FunctionContext) -> Result<Value> {
     )))
 }
 
-/// Checks if a Mon is at a specific field position.
-///
-/// @param {[`ValueType::Side`]} [side] The side to check.
-/// @param {[`ValueType::UFraction`]} position The position index.
-/// @returns {[`ValueType::Mon`] | [`ValueType::Undefined`]} The Mon at the position.
 fn mon_in_position(mut context: FunctionContext) -> Result<Option<Value>> {
     let side_index = context.target_side_index_positional()?;
     let position = context
@@ -2553,10 +3892,6 @@ fn mon_in_position(mut context: FunctionContext) -> Result<Option<Value>> {
     .map(|mon| Value::Mon(mon)))
 }
 
-/// Disables a move for a Mon.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon whose move is disabled.
-/// @param {[`ValueType::String`] | [`ValueType::Effect`]} move_id The move ID to disable.
 fn disable_move(mut context: FunctionContext) -> Result<()> {
     let mon_handle = context.target_handle_positional()?;
     let move_id = context
@@ -2568,11 +3903,6 @@ fn disable_move(mut context: FunctionContext) -> Result<()> {
     Mon::disable_move(&mut context.mon_context(mon_handle)?, &move_id)
 }
 
-/// Gets the effect state for a volatile status condition.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon whose volatile state to retrieve.
-/// @param {[`ValueType::String`] | [`ValueType::Effect`]} [effect] The volatile effect ID.
-/// @returns {[`ValueType::EffectState`] | [`ValueType::Undefined`]} The volatile effect state.
 fn volatile_status_effect_state(mut context: FunctionContext) -> Result<Option<Value>> {
     let mon_handle = context.target_handle_positional()?;
     let volatile_id = context
@@ -2589,10 +3919,6 @@ fn volatile_status_effect_state(mut context: FunctionContext) -> Result<Option<V
     }
 }
 
-/// Gets the effect state for a status condition.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon whose status state to retrieve.
-/// @returns {[`ValueType::EffectState`] | [`ValueType::Undefined`]} The status effect state.
 fn status_effect_state(mut context: FunctionContext) -> Result<Option<Value>> {
     let mon_handle = context.target_handle_positional()?;
     let effect_state = MonStatusEffectStateConnector::new(mon_handle);
@@ -2603,10 +3929,6 @@ fn status_effect_state(mut context: FunctionContext) -> Result<Option<Value>> {
     }
 }
 
-/// Gets the effect state for an ability.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon whose ability state to retrieve. Defaults to the
-/// current source. @returns {[`ValueType::EffectState`] | [`ValueType::Undefined`]}
 fn ability_effect_state(mut context: FunctionContext) -> Result<Option<Value>> {
     let mon_handle = context.target_handle_positional()?;
     let effect_state = MonAbilityEffectStateConnector::new(mon_handle);
@@ -2617,11 +3939,6 @@ fn ability_effect_state(mut context: FunctionContext) -> Result<Option<Value>> {
     }
 }
 
-/// Gets the effect state for a pseudo-weather condition.
-///
-/// @param {[`ValueType::String`] | [`ValueType::Effect`]} pseudo_weather_id The pseudo-weather ID.
-/// @returns {[`ValueType::EffectState`] | [`ValueType::Undefined`]} The pseudo-weather effect
-/// state.
 fn pseudo_weather_effect_state(mut context: FunctionContext) -> Result<Option<Value>> {
     let pseudo_weather_id = context
         .pop_front()
@@ -2637,12 +3954,6 @@ fn pseudo_weather_effect_state(mut context: FunctionContext) -> Result<Option<Va
     }
 }
 
-/// Gets the effect state for a side condition.
-///
-/// @param {[`ValueType::Side`]} [side] The side whose condition state to retrieve.
-/// @param {[`ValueType::String`] | [`ValueType::Effect`]} condition_id The side condition ID.
-/// @returns {[`ValueType::EffectState`] | [`ValueType::Undefined`]} The side condition effect
-/// state.
 fn side_condition_effect_state(mut context: FunctionContext) -> Result<Option<Value>> {
     let side = context.target_side_index_positional()?;
     let condition_id = context
@@ -2672,11 +3983,6 @@ impl FromStr for StatBoost {
     }
 }
 
-/// Checks if a Mon can receive a boost.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to check.
-/// @param {[`ValueType::BoostTable`]} boosts The boosts to check.
-/// @returns {[`ValueType::Boolean`]} Whether the boosts can be received.
 fn can_boost(mut context: FunctionContext) -> Result<Value> {
     let mon_handle = context.target_handle_positional()?;
     let boosts = context.boosts_from_rest_of_args()?;
@@ -2685,11 +3991,6 @@ fn can_boost(mut context: FunctionContext) -> Result<Value> {
         .map(|val| Value::Boolean(val))
 }
 
-/// Boosts or unboosts a Mon's stats.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to boost.
-/// @param {[`ValueType::BoostTable`]} boosts The boosts to apply.
-/// @returns {[`ValueType::EventResult`]} The outcome of the boost.
 fn boost(mut context: FunctionContext) -> Result<Value> {
     let mon_handle = context.target_handle_positional()?;
 
@@ -2707,18 +4008,10 @@ fn boost(mut context: FunctionContext) -> Result<Value> {
         .map(|val| Value::EventResult(val))
 }
 
-/// Creates a boost table from a list of stat:amount strings.
-///
-/// @param {[`ValueType::String`]} ... Boost strings (e.g., "atk:1").
-/// @returns {[`ValueType::Object`]} The created boost table.
 fn boost_table(mut context: FunctionContext) -> Result<Value> {
     Ok(Value::BoostTable(context.boosts_from_rest_of_args()?))
 }
 
-/// Checks if a Mon can switch out.
-///
-/// @param {[`ValueType::Player`]} player The player whose Mon to check.
-/// @returns {[`ValueType::Boolean`]} Whether the Mon can switch.
 fn can_switch(mut context: FunctionContext) -> Result<Value> {
     let player_index = context
         .pop_front()
@@ -2730,11 +4023,6 @@ fn can_switch(mut context: FunctionContext) -> Result<Value> {
     )))
 }
 
-/// Checks if a Mon has a specific move.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to check.
-/// @param {[`ValueType::String`] | [`ValueType::Effect`]} move_id The move ID to check.
-/// @returns {[`ValueType::Boolean`]} Whether the Mon has the move.
 fn has_move(mut context: FunctionContext) -> Result<Value> {
     let mon_handle = context.target_handle_positional()?;
     let move_id = context
@@ -2751,11 +4039,6 @@ fn has_move(mut context: FunctionContext) -> Result<Value> {
     ))
 }
 
-/// Gets the index of a move in a Mon's move slots.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to check.
-/// @param {[`ValueType::String`] | [`ValueType::Effect`]} move_id The move ID to check.
-/// @returns {[`ValueType::UFraction`] | [`ValueType::Undefined`]} The index of the move.
 fn move_slot_index(mut context: FunctionContext) -> Result<Option<Value>> {
     let mon_handle = context.target_handle_positional()?;
     let move_id = context
@@ -2777,11 +4060,6 @@ fn move_slot_index(mut context: FunctionContext) -> Result<Option<Value>> {
     }
 }
 
-/// Gets the move slot at a specific index.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to query.
-/// @param {[`ValueType::UFraction`]} index The move slot index.
-/// @returns {[`ValueType::MoveSlot`] | [`ValueType::Undefined`]} The move slot at the index.
 fn move_slot_at_index(mut context: FunctionContext) -> Result<Option<Value>> {
     let mon_handle = context.target_handle_positional()?;
     let index = context
@@ -2799,10 +4077,6 @@ fn move_slot_at_index(mut context: FunctionContext) -> Result<Option<Value>> {
         .map(|move_slot| Value::MoveSlot(move_slot)))
 }
 
-/// Creates a simulated move slot from an active move.
-///
-/// @param {[`ValueType::Effect`] | [`ValueType::String`]} active_move The active move.
-/// @returns {[`ValueType::MoveSlot`]} The simulated move slot.
 fn move_slot(mut context: FunctionContext) -> Result<Value> {
     let mov = context
         .pop_front()
@@ -2845,12 +4119,6 @@ fn move_slot(mut context: FunctionContext) -> Result<Value> {
     Ok(Value::MoveSlot(move_slot))
 }
 
-/// Overwrites a move slot on a Mon.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to modify.
-/// @param {[`ValueType::UFraction`]} index The move slot index.
-/// @param {[`ValueType::MoveSlot`]} move_slot The new move slot data.
-/// @flag override_base_slot If set, the base move slot is also overridden.
 fn overwrite_move_slot(mut context: FunctionContext) -> Result<()> {
     let mon_handle = context.target_handle_positional()?;
     let index = context
@@ -2872,11 +4140,6 @@ fn overwrite_move_slot(mut context: FunctionContext) -> Result<()> {
         .overwrite_move_slot(index, move_slot, override_base_slot)
 }
 
-/// Checks if a move hit its target.
-///
-/// @param {[`ValueType::Effect`]} move_handle The move to check.
-/// @param {[`ValueType::Mon`]} [mon] The target Mon.
-/// @returns {[`ValueType::Boolean`]} Whether the move hit the target.
 fn move_hit_target(mut context: FunctionContext) -> Result<Value> {
     let active_move_handle = context
         .pop_front()
@@ -2893,11 +4156,6 @@ fn move_hit_target(mut context: FunctionContext) -> Result<Value> {
     ))
 }
 
-/// Checks if a move dealt a critical hit to its target.
-///
-/// @param {[`ValueType::Effect`]} move_handle The move to check.
-/// @param {[`ValueType::Mon`]} [mon] The target Mon.
-/// @returns {[`ValueType::Boolean`]} Whether the move dealt a critical hit.
 fn move_crit_target(mut context: FunctionContext) -> Result<Value> {
     let active_move_handle = context
         .pop_front()
@@ -2915,11 +4173,6 @@ fn move_crit_target(mut context: FunctionContext) -> Result<Value> {
     ))
 }
 
-/// Calculates a type modifier against a target Mon.
-///
-/// @param {[`ValueType::Effect`]} move_handle The move to check.
-/// @param {[`ValueType::Mon`]} [mon] The target Mon.
-/// @returns {[`ValueType::Fraction`] | [`ValueType::Undefined`]} The type modifier.
 fn type_modifier_against_target(mut context: FunctionContext) -> Result<Option<Value>> {
     let active_move_handle = context
         .pop_front()
@@ -2934,11 +4187,6 @@ fn type_modifier_against_target(mut context: FunctionContext) -> Result<Option<V
         .map(|hit_data| Value::Fraction(hit_data.type_modifier.into())))
 }
 
-/// Saves a flag on a move's hit data for a target.
-///
-/// @param {[`ValueType::Effect`]} active_move The active move.
-/// @param {[`ValueType::Mon`]} [mon] The target Mon.
-/// @param {[`ValueType::String`]} flag The flag ID to save.
 fn save_move_hit_data_flag_against_target(mut context: FunctionContext) -> Result<()> {
     let active_move_handle = context
         .pop_front()
@@ -2961,12 +4209,6 @@ fn save_move_hit_data_flag_against_target(mut context: FunctionContext) -> Resul
     Ok(())
 }
 
-/// Checks if a move's hit data has a flag for a target.
-///
-/// @param {[`ValueType::Effect`]} active_move The active move.
-/// @param {[`ValueType::Mon`]} [mon] The target Mon.
-/// @param {[`ValueType::String`]} flag The flag ID to check.
-/// @returns {[`ValueType::Boolean`]} Whether the hit data has the flag.
 fn move_hit_data_has_flag_against_target(mut context: FunctionContext) -> Result<Value> {
     let active_move_handle = context
         .pop_front()
@@ -2989,11 +4231,6 @@ fn move_hit_data_has_flag_against_target(mut context: FunctionContext) -> Result
             .unwrap_or(false),
     ))
 }
-/// Reads the target's original HP before the move applied any hits.
-///
-/// @param {[`ValueType::Effect`]} active_move The active move.
-/// @param {[`ValueType::Mon`]} [mon] The target Mon.
-/// @returns {[`ValueType::UFraction`] | [`ValueType::Undefined`]} The target's original HP.
 fn move_target_original_hp(mut context: FunctionContext) -> Result<Option<Value>> {
     let active_move_handle = context
         .pop_front()
@@ -3009,10 +4246,6 @@ fn move_target_original_hp(mut context: FunctionContext) -> Result<Option<Value>
         .map(|hp| Value::UFraction((*hp).into())))
 }
 
-/// Gets all active Mons in the battle.
-///
-/// @returns {[`ValueType::List`]} A list of all active Mons.
-/// @returnsitem {[`ValueType::Mon`]}
 fn all_active_mons(context: FunctionContext) -> Result<Value> {
     Ok(Value::List(
         context
@@ -3025,10 +4258,6 @@ fn all_active_mons(context: FunctionContext) -> Result<Value> {
     ))
 }
 
-/// Gets all active Mons in speed order.
-///
-/// @returns {[`ValueType::List`]} A list of active Mons sorted by speed.
-/// @returnsitem {[`ValueType::Mon`]}
 fn all_active_mons_in_speed_order(mut context: FunctionContext) -> Result<Value> {
     Ok(Value::List(
         CoreBattle::all_active_mon_handles_in_speed_order(context.battle_context_mut())?
@@ -3038,10 +4267,6 @@ fn all_active_mons_in_speed_order(mut context: FunctionContext) -> Result<Value>
     ))
 }
 
-/// Gets all active Mons in speed and ability effect order.
-///
-/// @returns {[`ValueType::List`]} A list of active Mons in speed and ability order.
-/// @returnsitem {[`ValueType::Mon`]}
 fn all_active_mons_in_speed_order_and_ability_effect_order(
     mut context: FunctionContext,
 ) -> Result<Value> {
@@ -3055,10 +4280,6 @@ fn all_active_mons_in_speed_order_and_ability_effect_order(
     ))
 }
 
-/// Gets all active or recently exited Mons.
-///
-/// @returns {[`ValueType::List`]} A list of active or exited Mons.
-/// @returnsitem {[`ValueType::Mon`]}
 fn all_active_or_exited_mons(context: FunctionContext) -> Result<Value> {
     Ok(Value::List(
         context
@@ -3071,11 +4292,6 @@ fn all_active_or_exited_mons(context: FunctionContext) -> Result<Value> {
     ))
 }
 
-/// Gets all active Mons on a specific side.
-///
-/// @param {[`ValueType::Side`]} [side] The side to query. Defaults to the current side.
-/// @returns {[`ValueType::List`]} A list of active Mons on the side.
-/// @returnsitem {[`ValueType::Mon`]}
 fn all_active_mons_on_side(mut context: FunctionContext) -> Result<Value> {
     let side = context.target_side_index_positional()?;
     Ok(Value::List(
@@ -3089,11 +4305,6 @@ fn all_active_mons_on_side(mut context: FunctionContext) -> Result<Value> {
     ))
 }
 
-/// Gets all Mons on a specific side, including those in the party.
-///
-/// @param {[`ValueType::Side`]} [side] The side to query. Defaults to the current side.
-/// @returns {[`ValueType::List`]} A list of all Mons on the side.
-/// @returnsitem {[`ValueType::Mon`]}
 fn all_mons_on_side(mut context: FunctionContext) -> Result<Value> {
     let side = context.target_side_index_positional()?;
     Ok(Value::List(
@@ -3107,11 +4318,6 @@ fn all_mons_on_side(mut context: FunctionContext) -> Result<Value> {
     ))
 }
 
-/// Gets all Mons in a player's party.
-///
-/// @param {[`ValueType::Player`]} player The player.
-/// @returns {[`ValueType::List`]} A list of Mons in the party.
-/// @returnsitem {[`ValueType::Mon`]}
 fn all_mons_in_party(mut context: FunctionContext) -> Result<Value> {
     let player = context
         .pop_front()
@@ -3130,11 +4336,6 @@ fn all_mons_in_party(mut context: FunctionContext) -> Result<Value> {
     ))
 }
 
-/// Gets all adjacent foes of a Mon.
-///
-/// @param {[`ValueType::Mon`]} [mon] The reference Mon.
-/// @returns {[`ValueType::List`]} A list of adjacent foe Mons.
-/// @returnsitem {[`ValueType::Mon`]}
 fn adjacent_foes(mut context: FunctionContext) -> Result<Value> {
     let mon_handle = context.target_handle_positional()?;
     Ok(Value::List(
@@ -3144,11 +4345,6 @@ fn adjacent_foes(mut context: FunctionContext) -> Result<Value> {
     ))
 }
 
-/// Gets all adjacent allies of a Mon.
-///
-/// @param {[`ValueType::Mon`]} [mon] The reference Mon.
-/// @returns {[`ValueType::List`]} A list of adjacent ally Mons.
-/// @returnsitem {[`ValueType::Mon`]}
 fn adjacent_allies(mut context: FunctionContext) -> Result<Value> {
     let mon_handle = context.target_handle_positional()?;
     Ok(Value::List(
@@ -3158,11 +4354,6 @@ fn adjacent_allies(mut context: FunctionContext) -> Result<Value> {
     ))
 }
 
-/// Gets all foes of a Mon.
-///
-/// @param {[`ValueType::Mon`]} [mon] The reference Mon.
-/// @returns {[`ValueType::List`]} A list of foe Mons.
-/// @returnsitem {[`ValueType::Mon`]}
 fn all_foes(mut context: FunctionContext) -> Result<Value> {
     let mon_handle = context.target_handle_positional()?;
     Ok(Value::List(
@@ -3172,11 +4363,6 @@ fn all_foes(mut context: FunctionContext) -> Result<Value> {
     ))
 }
 
-/// Gets all allies of a Mon and the Mon itself.
-///
-/// @param {[`ValueType::Mon`]} [mon] The reference Mon.
-/// @returns {[`ValueType::List`]} A list of ally Mons including the reference Mon.
-/// @returnsitem {[`ValueType::Mon`]}
 fn allies_and_self(mut context: FunctionContext) -> Result<Value> {
     let mon_handle = context.target_handle_positional()?;
     Ok(Value::List(
@@ -3186,10 +4372,6 @@ fn allies_and_self(mut context: FunctionContext) -> Result<Value> {
     ))
 }
 
-/// Clears all boosts from a Mon.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to clear.
-/// @flag silent If set, no message is displayed.
 fn clear_boosts(mut context: FunctionContext) -> Result<()> {
     let mon_handle = context.target_handle_positional()?;
     let silent = context.silent();
@@ -3199,9 +4381,6 @@ fn clear_boosts(mut context: FunctionContext) -> Result<()> {
     )
 }
 
-/// Clears negative boosts from a Mon.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to clear.
 fn clear_negative_boosts(mut context: FunctionContext) -> Result<()> {
     let mon_handle = context.target_handle_positional()?;
     core_battle_actions::clear_negative_boosts(
@@ -3209,9 +4388,6 @@ fn clear_negative_boosts(mut context: FunctionContext) -> Result<()> {
     )
 }
 
-/// Clears positive boosts from a Mon.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to clear.
 fn clear_positive_boosts(mut context: FunctionContext) -> Result<()> {
     let mon_handle = context.target_handle_positional()?;
     core_battle_actions::clear_positive_boosts(
@@ -3219,11 +4395,6 @@ fn clear_positive_boosts(mut context: FunctionContext) -> Result<()> {
     )
 }
 
-/// Selects a random target for a move.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon using the move.
-/// @param {[`ValueType::MoveTarget`]} [move_target] The target type.
-/// @returns {[`ValueType::Mon`] | [`ValueType::Undefined`]} The selected target.
 fn random_target(mut context: FunctionContext) -> Result<Option<Value>> {
     let mon_handle = context.target_handle_positional()?;
     let move_target = match context.pop_front() {
@@ -3238,11 +4409,6 @@ fn random_target(mut context: FunctionContext) -> Result<Option<Value>> {
     )
 }
 
-/// Clones the currently active move.
-///
-/// @param {[`ValueType::Effect`]} active_move The active move to clone.
-/// @param {[`ValueType::Mon`]} user The Mon using the cloned move.
-/// @returns {[`ValueType::ActiveMove`]} The cloned active move.
 fn clone_active_move(mut context: FunctionContext) -> Result<Value> {
     let active_move = context
         .pop_front()
@@ -3263,11 +4429,6 @@ fn clone_active_move(mut context: FunctionContext) -> Result<Value> {
     Ok(Value::Effect(active_move_handle.into()))
 }
 
-/// Creates a new active move.
-///
-/// @param {[`ValueType::String`] | [`ValueType::Effect`]} move_id The move ID.
-/// @param {[`ValueType::Mon`]} [mon] The Mon using the move.
-/// @returns {[`ValueType::Effect`]} The new active move.
 fn new_active_move(mut context: FunctionContext) -> Result<Value> {
     let move_id = context
         .pop_front()
@@ -3284,12 +4445,6 @@ fn new_active_move(mut context: FunctionContext) -> Result<Value> {
     Ok(Value::Effect(active_move_handle.into()))
 }
 
-/// Creates a new active move from local data.
-///
-/// @param {[`ValueType::Effect`]} effect The effect containing local data.
-/// @param {[`ValueType::String`] | [`ValueType::Effect`]} move_id The move ID in local data.
-/// @param {[`ValueType::Mon`]} [mon] The Mon using the move.
-/// @returns {[`ValueType::Effect`]} The new active move.
 fn new_active_move_from_local_data(mut context: FunctionContext) -> Result<Value> {
     let effect_handle = context
         .pop_front()
@@ -3325,14 +4480,6 @@ fn new_active_move_from_local_data(mut context: FunctionContext) -> Result<Value
     Ok(Value::Effect(active_move_handle.into()))
 }
 
-/// Uses an active move.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon using the move.
-/// @param {[`ValueType::Effect`]} active_move The active move to use.
-/// @param {[`ValueType::Mon`]} [target] The target of the move.
-/// @flag indirect If set, the move is considered indirect.
-/// @flag preventable If set, the move can be prevented by certain effects.
-/// @returns {[`ValueType::Boolean`]} Whether the move was successful.
 fn use_active_move(mut context: FunctionContext) -> Result<Value> {
     let indirect = context.has_flag("indirect");
     let preventable = context.has_flag("preventable");
@@ -3371,10 +4518,6 @@ fn use_active_move(mut context: FunctionContext) -> Result<Value> {
     .map(|result| Value::Boolean(result.outcome().succeeded()))
 }
 
-/// Logs that a Mon is waiting.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon waiting.
-/// @param {[`ValueType::Mon`]} target The target being waited for.
 fn log_waiting(mut context: FunctionContext) -> Result<()> {
     let mon = context
         .pop_front()
@@ -3389,12 +4532,6 @@ fn log_waiting(mut context: FunctionContext) -> Result<()> {
     core_battle_logs::waiting(&mut context.mon_context(mon)?, target)
 }
 
-/// Uses a move by ID.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon using the move.
-/// @param {[`ValueType::String`] | [`ValueType::Effect`]} move_id The move ID.
-/// @param {[`ValueType::Mon`]} [target] The target Mon.
-/// @returns {[`ValueType::Boolean`]} Whether the move was successful.
 fn use_move(mut context: FunctionContext) -> Result<Value> {
     let source_effect = context.source_effect_handle()?;
     let mon_handle = context.target_handle_positional()?;
@@ -3427,12 +4564,6 @@ fn use_move(mut context: FunctionContext) -> Result<Value> {
     .map(|val| Value::Boolean(val))
 }
 
-/// Executes a move.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon using the move.
-/// @param {[`ValueType::String`] | [`ValueType::Effect`]} move_id The move ID to execute.
-/// @param {[`ValueType::UFraction`]} [target_position] The target field position.
-/// @param {[`ValueType::Mon`]} [target] The target Mon.
 fn do_move(mut context: FunctionContext) -> Result<()> {
     let mon_handle = context.target_handle_positional()?;
     let move_id = context
@@ -3464,11 +4595,6 @@ fn do_move(mut context: FunctionContext) -> Result<()> {
     )
 }
 
-/// Gets the Mon at a specific target location relative to a Mon.
-///
-/// @param {[`ValueType::Mon`]} [mon] The reference Mon.
-/// @param {[`ValueType::Fraction`]} location The relative location index.
-/// @returns {[`ValueType::Mon`] | [`ValueType::Undefined`]} The Mon at the location.
 fn mon_at_target_location(mut context: FunctionContext) -> Result<Option<Value>> {
     let mon_handle = context.target_handle_positional()?;
     let relative_location = context
@@ -3480,11 +4606,6 @@ fn mon_at_target_location(mut context: FunctionContext) -> Result<Option<Value>>
         .map(|mon| Some(Value::Mon(mon?)))
 }
 
-/// Gets the target location of a Mon relative to another Mon.
-///
-/// @param {[`ValueType::Mon`]} [mon] The reference Mon.
-/// @param {[`ValueType::Mon`]} target The target Mon.
-/// @returns {[`ValueType::Fraction`]} The target location index.
 fn target_location_of_mon(mut context: FunctionContext) -> Result<Value> {
     let mon_handle = context.target_handle_positional()?;
     let target_handle = context
@@ -3514,10 +4635,6 @@ fn effect_handle_exists_or_none(
     .then(|| Value::Effect(effect_handle))
 }
 
-/// Gets move data from the registry.
-///
-/// @param {[`ValueType::String`] | [`ValueType::Effect`]} move_id The move ID.
-/// @returns {[`ValueType::Effect`] | [`ValueType::Undefined`]} The move data.
 fn get_move(mut context: FunctionContext) -> Result<Option<Value>> {
     let move_id = context
         .pop_front()
@@ -3530,10 +4647,6 @@ fn get_move(mut context: FunctionContext) -> Result<Option<Value>> {
     ))
 }
 
-/// Gets an ability by ID.
-///
-/// @param {[`ValueType::String`] | [`ValueType::Effect`]} ability_id The ability ID.
-/// @returns {[`ValueType::Effect`] | [`ValueType::Undefined`]}
 fn get_ability(mut context: FunctionContext) -> Result<Option<Value>> {
     let ability_id = context
         .pop_front()
@@ -3546,10 +4659,6 @@ fn get_ability(mut context: FunctionContext) -> Result<Option<Value>> {
     ))
 }
 
-/// Gets an item by ID.
-///
-/// @param {[`ValueType::String`] | [`ValueType::Effect`]} item_id The item ID.
-/// @returns {[`ValueType::Effect`] | [`ValueType::Undefined`]}
 fn get_item(mut context: FunctionContext) -> Result<Option<Value>> {
     let item_id = context
         .pop_front()
@@ -3562,10 +4671,6 @@ fn get_item(mut context: FunctionContext) -> Result<Option<Value>> {
     ))
 }
 
-/// Gets species data from the registry.
-///
-/// @param {[`ValueType::String`] | [`ValueType::Effect`]} species_id The species ID.
-/// @returns {[`ValueType::Effect`] | [`ValueType::Undefined`]} The species data.
 fn get_species(mut context: FunctionContext) -> Result<Option<Value>> {
     let species_id = context
         .pop_front()
@@ -3578,10 +4683,6 @@ fn get_species(mut context: FunctionContext) -> Result<Option<Value>> {
     ))
 }
 
-/// Gets all moves from the registry.
-///
-/// @returns {[`ValueType::List`]} A list of all moves.
-/// @returnsitem {[`ValueType::Effect`]}
 fn get_all_moves(mut context: FunctionContext) -> Result<Value> {
     let mut with_flags = HashSet::default();
     let mut without_flags = HashSet::default();
@@ -3620,11 +4721,6 @@ fn get_all_moves(mut context: FunctionContext) -> Result<Value> {
     ))
 }
 
-/// Gets the move at a specific move slot index.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to check.
-/// @param {[`ValueType::UFraction`]} index The move slot index.
-/// @returns {[`ValueType::Effect`] | [`ValueType::Undefined`]} The move at the index.
 fn move_at_move_slot_index(mut context: FunctionContext) -> Result<Option<Value>> {
     let mon_handle = context.target_handle_positional()?;
     let index = context
@@ -3641,11 +4737,6 @@ fn move_at_move_slot_index(mut context: FunctionContext) -> Result<Option<Value>
         .map(|move_slot| Value::Effect(EffectHandle::InactiveMove(move_slot.id.clone()))))
 }
 
-/// Sets a Mon's types.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to modify.
-/// @param {[`ValueType::List`]} types The list of types.
-/// @returns {[`ValueType::EventResult`]} Whether the types were successfully set.
 fn set_types(mut context: FunctionContext) -> Result<Value> {
     let mon_handle = context.target_handle_positional()?;
     let types = context
@@ -3657,21 +4748,12 @@ fn set_types(mut context: FunctionContext) -> Result<Value> {
     core_battle_actions::set_types(&mut context, types).map(|val| Value::EventResult(val))
 }
 
-/// Resets a Mon's types.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to modify.
-/// @returns {[`ValueType::EventResult`]} Whether the types were successfully reset.
 fn reset_types(mut context: FunctionContext) -> Result<Value> {
     let mon_handle = context.target_handle_positional()?;
     let mut context = context.forward_to_applying_effect_context_with_target(mon_handle)?;
     core_battle_actions::reset_types(&mut context).map(|val| Value::EventResult(val))
 }
 
-/// Adds a type to a Mon.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to modify.
-/// @param {[`ValueType::Type`]} type_to_add The type to add.
-/// @returns {[`ValueType::EventResult`]} Whether the type was successfully added.
 fn add_type(mut context: FunctionContext) -> Result<Value> {
     let mon_handle = context.target_handle_positional()?;
     let typ = context
@@ -3683,10 +4765,6 @@ fn add_type(mut context: FunctionContext) -> Result<Value> {
     core_battle_actions::add_type(&mut context, typ).map(|val| Value::EventResult(val))
 }
 
-/// Sets the field weather.
-///
-/// @param {[`ValueType::String`]} weather The weather ID.
-/// @returns {[`ValueType::EventResult`]} Whether the weather was successfully set.
 fn set_weather(mut context: FunctionContext) -> Result<Value> {
     let weather = context
         .pop_front()
@@ -3698,10 +4776,6 @@ fn set_weather(mut context: FunctionContext) -> Result<Value> {
         .map(Value::EventResult)
 }
 
-/// Sets the field terrain.
-///
-/// @param {[`ValueType::String`]} terrain The terrain ID.
-/// @returns {[`ValueType::EventResult`]} Whether the terrain was successfully set.
 fn set_terrain(mut context: FunctionContext) -> Result<Value> {
     let terrain = context
         .pop_front()
@@ -3713,28 +4787,16 @@ fn set_terrain(mut context: FunctionContext) -> Result<Value> {
         .map(Value::EventResult)
 }
 
-/// Clears the field weather.
-///
-/// @returns {[`ValueType::EventResult`]} Whether the weather was successfully cleared.
 fn clear_weather(mut context: FunctionContext) -> Result<Value> {
     core_battle_actions::clear_weather(&mut context.forward_to_field_effect()?)
         .map(Value::EventResult)
 }
 
-/// Clears the field terrain.
-///
-/// @returns {[`ValueType::EventResult`]} Whether the terrain was successfully cleared.
 fn clear_terrain(mut context: FunctionContext) -> Result<Value> {
     core_battle_actions::clear_terrain(&mut context.forward_to_field_effect()?)
         .map(Value::EventResult)
 }
 
-/// Copies boosts (and associated volatiles) from the target Mon.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to copy boosts to.
-/// @param {[`ValueType::Mon`]} target The Mon to copy boosts from.
-/// @flag silent If set, no message is displayed.
-/// @returns {[`ValueType::EventResult`]} The outcome of the copy.
 fn copy_boosts(mut context: FunctionContext) -> Result<Value> {
     let silent = context.silent();
     let mon_handle = context.target_handle_positional()?;
@@ -3752,11 +4814,6 @@ fn copy_boosts(mut context: FunctionContext) -> Result<Value> {
     .map(|val| Value::EventResult(val))
 }
 
-/// Transforms a Mon into another species.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to transform.
-/// @param {[`ValueType::Mon`]} target The Mon to transform into.
-/// @returns {[`ValueType::EventResult`]} Whether the transformation was successful.
 fn transform_into(mut context: FunctionContext) -> Result<Value> {
     let mon_handle = context.target_handle_positional()?;
     let transform_into_handle = context
@@ -3772,11 +4829,6 @@ fn transform_into(mut context: FunctionContext) -> Result<Value> {
     .map(|val| Value::EventResult(val))
 }
 
-/// Sets a Mon's illusion species.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon using the illusion.
-/// @param {[`ValueType::Mon`]} target The Mon to imitate.
-/// @returns {[`ValueType::EventResult`]} Whether the illusion was successfully set.
 fn set_illusion(mut context: FunctionContext) -> Result<Value> {
     let mon_handle = context.target_handle_positional()?;
     let transform_into_handle = context
@@ -3792,10 +4844,6 @@ fn set_illusion(mut context: FunctionContext) -> Result<Value> {
     .map(|val| Value::EventResult(val))
 }
 
-/// Ends a Mon's illusion.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon whose illusion to end.
-/// @returns {[`ValueType::EventResult`]} Whether the illusion was successfully ended.
 fn end_illusion(mut context: FunctionContext) -> Result<Value> {
     let mon_handle = context.target_handle_positional()?;
 
@@ -3805,43 +4853,25 @@ fn end_illusion(mut context: FunctionContext) -> Result<Value> {
     .map(|val| Value::EventResult(val))
 }
 
-/// Checks if a Mon can escape from battle.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to check.
-/// @returns {[`ValueType::Boolean`]} Whether the Mon can escape.
 fn can_escape(mut context: FunctionContext) -> Result<Value> {
     let mon_handle = context.target_handle_positional()?;
     Mon::can_escape(&mut context.mon_context(mon_handle)?).map(|val| Value::Boolean(val))
 }
 
-/// Makes a Mon escape from battle.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to escape.
-/// @returns {[`ValueType::EventResult`]} Whether the Mon escaped.
 fn escape(mut context: FunctionContext) -> Result<Value> {
     let mon_handle = context.target_handle_positional()?;
     core_battle_actions::try_escape(&mut context.mon_context(mon_handle)?, true)
         .map(|val| Value::EventResult(val))
 }
 
-/// Creates a new hit effect object.
-///
-/// @returns {[`ValueType::HitEffect`]} A new hit effect.
 fn hit_effect() -> Result<Value> {
     Ok(Value::HitEffect(HitEffect::default()))
 }
 
-/// Creates a new secondary hit effect object.
-///
-/// @returns {[`ValueType::SecondaryHitEffect`]} A new secondary hit effect.
 fn secondary_hit_effect() -> Result<Value> {
     Ok(Value::SecondaryHitEffect(SecondaryEffectData::default()))
 }
 
-/// Gets a list of all defined types in the battle.
-///
-/// @returns {[`ValueType::List`]} A list of type IDs.
-/// @returnsitem {[`ValueType::Type`]}
 fn all_types(context: FunctionContext) -> Result<Value> {
     let mut types = context
         .evaluation_context()
@@ -3858,11 +4888,6 @@ fn all_types(context: FunctionContext) -> Result<Value> {
     Ok(Value::List(types))
 }
 
-/// Appends an element to a list.
-///
-/// @param {[`ValueType::List`]} list The list to modify.
-/// @param {[`ValueType::Object`]} element The element to append.
-/// @returns {[`ValueType::List`]} The modified list.
 fn append(mut context: FunctionContext) -> Result<Value> {
     let mut list = context
         .pop_front()
@@ -3874,11 +4899,6 @@ fn append(mut context: FunctionContext) -> Result<Value> {
     Ok(Value::List(list))
 }
 
-/// Removes an item from a list.
-///
-/// @param {[`ValueType::List`]} list The list.
-/// @param {[`ValueType::Any`]} item The item to remove.
-/// @returns {[`ValueType::Boolean`]} Whether the item was removed.
 fn remove(mut context: FunctionContext) -> Result<Value> {
     let list = context
         .pop_front()
@@ -3897,11 +4917,6 @@ fn remove(mut context: FunctionContext) -> Result<Value> {
     Ok(Value::List(list))
 }
 
-/// Gets the item at a specific index from the list.
-///
-/// @param {[`ValueType::List`]} list The list.
-/// @param {[`ValueType::UFraction`]} item The index of the item.
-/// @returns_item_from_list
 fn index(mut context: FunctionContext) -> Result<Option<Value>> {
     let list = context
         .pop_front()
@@ -3916,10 +4931,6 @@ fn index(mut context: FunctionContext) -> Result<Option<Value>> {
     Ok(list.get(index).cloned())
 }
 
-/// Reverses a list.
-///
-/// @param {[`ValueType::List`]} list The list.
-/// @returns {[`ValueType::List`]} The reversed list.
 fn reverse(mut context: FunctionContext) -> Result<Value> {
     let mut list = context
         .pop_front()
@@ -3930,11 +4941,6 @@ fn reverse(mut context: FunctionContext) -> Result<Value> {
     Ok(Value::List(list))
 }
 
-/// Joins a list into a string.
-///
-/// @param {[`ValueType::List`]} list The list.
-/// @param {[`ValueType::String`]} separator The separator.
-/// @returns {[`ValueType::String`]} The joined string.
 fn join(mut context: FunctionContext) -> Result<Value> {
     let list = context
         .pop_front()
@@ -3954,9 +4960,6 @@ fn join(mut context: FunctionContext) -> Result<Value> {
     ))
 }
 
-/// Checks if any Mon will perform a move this turn.
-///
-/// @returns {[`ValueType::Boolean`]} Whether any Mon will move.
 fn any_mon_will_move_this_turn(context: FunctionContext) -> Result<Value> {
     Ok(Value::Boolean(
         context
@@ -3991,10 +4994,6 @@ fn move_action_to_value(move_action: &MoveAction) -> Value {
     ]))
 }
 
-/// Gets a pending move action for a Mon this turn.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to check.
-/// @returns {[`ValueType::Object`] | [`ValueType::Undefined`]} The pending move action.
 fn pending_move_action_this_turn(mut context: FunctionContext) -> Result<Option<Value>> {
     let mon_handle = context.target_handle_positional()?;
     Ok(context
@@ -4006,10 +5005,6 @@ fn pending_move_action_this_turn(mut context: FunctionContext) -> Result<Option<
         .map(|action| move_action_to_value(&action)))
 }
 
-/// Gets all pending move actions this turn.
-///
-/// @returns {[`ValueType::List`]} A list of all pending move actions.
-/// @returnsitem {[`ValueType::Object`]}
 fn pending_move_actions_this_turn(mut context: FunctionContext) -> Result<Value> {
     let actions = context
         .battle_context_mut()
@@ -4024,19 +5019,10 @@ fn pending_move_actions_this_turn(mut context: FunctionContext) -> Result<Value>
     ))
 }
 
-/// Checks if a Mon will move this turn.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to check.
-/// @returns {[`ValueType::Boolean`]} Whether the Mon will move.
 fn will_move_this_turn(context: FunctionContext) -> Result<Value> {
     pending_move_action_this_turn(context).map(|val| Value::Boolean(val.is_some()))
 }
 
-/// Removes a side condition.
-///
-/// @param {[`ValueType::Side`]} [side] The side to modify.
-/// @param {[`ValueType::String`] | [`ValueType::Effect`]} side_condition The side condition ID.
-/// @returns {[`ValueType::EventResult`]} Whether the side condition was removed.
 fn remove_side_condition(mut context: FunctionContext) -> Result<Value> {
     let side = context.target_side_index_positional()?;
     let condition = context
@@ -4052,9 +5038,6 @@ fn remove_side_condition(mut context: FunctionContext) -> Result<Value> {
     .map(|val| Value::EventResult(val))
 }
 
-/// Makes a Mon faint.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon that faints.
 fn faint(mut context: FunctionContext) -> Result<()> {
     let mon_handle = context.target_handle_positional()?;
     let source = context.source_handle();
@@ -4062,11 +5045,6 @@ fn faint(mut context: FunctionContext) -> Result<()> {
     core_battle_actions::faint(&mut context.mon_context(mon_handle)?, source, Some(&effect))
 }
 
-/// Prepares a direct move for execution.
-///
-/// @param {[`ValueType::List`]} targets The list of target Mons.
-/// @returns {[`ValueType::List`]} The list of Mons that were hit.
-/// @returnsitem {[`ValueType::Mon`]}
 fn prepare_direct_move(mut context: FunctionContext) -> Result<Value> {
     let targets = context
         .pop_front()
@@ -4085,11 +5063,6 @@ fn prepare_direct_move(mut context: FunctionContext) -> Result<Value> {
     ))
 }
 
-/// Checks if a Mon is immune to an effect.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to check.
-/// @param {[`ValueType::String`] | [`ValueType::Effect`]} effect The effect ID to check.
-/// @returns {[`ValueType::Boolean`]} Whether the Mon is immune.
 fn check_immunity(mut context: FunctionContext) -> Result<Value> {
     let mon_handle = context.target_handle_positional()?;
     let effect_id = context
@@ -4110,11 +5083,6 @@ fn check_immunity(mut context: FunctionContext) -> Result<Value> {
     .map(|val| Value::Boolean(val))
 }
 
-/// Checks if a Mon is immune to a move.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to check.
-/// @param {[`ValueType::ActiveMove`]} move The move to check.
-/// @returns {[`ValueType::Boolean`]} Whether the Mon is immune.
 fn check_move_immunity(mut context: FunctionContext) -> Result<Value> {
     let mon_handle = context.target_handle_positional()?;
     let move_handle = context
@@ -4132,9 +5100,6 @@ fn check_move_immunity(mut context: FunctionContext) -> Result<Value> {
     .map(|val| Value::Boolean(val))
 }
 
-/// Modifies the type of an active move.
-///
-/// @param {[`ValueType::Effect`]} [active_move] The active move to modify.
 fn modify_move_type(mut context: FunctionContext) -> Result<()> {
     let target = context.source_handle();
     let mut context = context
@@ -4144,11 +5109,6 @@ fn modify_move_type(mut context: FunctionContext) -> Result<()> {
     Ok(())
 }
 
-/// Executes a Z-Move.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon using the move.
-/// @param {[`ValueType::Effect`]} move The move to execute.
-/// @returns {[`ValueType::String`] | [`ValueType::Undefined`]} The Z-Move ID.
 fn z_move(mut context: FunctionContext) -> Result<Option<Value>> {
     let target = context.target_handle_positional()?;
     let move_handle = context
@@ -4165,11 +5125,6 @@ fn z_move(mut context: FunctionContext) -> Result<Option<Value>> {
         .map(|move_id| move_id.map(|val| Value::String(val.to_string())))
 }
 
-/// Executes a Max Move.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon using the move.
-/// @param {[`ValueType::Effect`]} move The move to execute.
-/// @returns {[`ValueType::String`] | [`ValueType::Undefined`]} The Max Move ID.
 fn max_move(mut context: FunctionContext) -> Result<Option<Value>> {
     let target = context.target_handle_positional()?;
     let move_handle = context
@@ -4187,11 +5142,6 @@ fn max_move(mut context: FunctionContext) -> Result<Option<Value>> {
         .map(|move_id| move_id.map(|val| Value::String(val.to_string())))
 }
 
-/// Sets a Mon's HP.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to modify.
-/// @param {[`ValueType::UFraction`]} hp The new HP value.
-/// @returns {[`ValueType::Boolean`]} Whether the HP was successfully set.
 fn set_hp(mut context: FunctionContext) -> Result<Value> {
     let mon_handle = context.target_handle_positional()?;
     let hp = context
@@ -4204,12 +5154,6 @@ fn set_hp(mut context: FunctionContext) -> Result<Value> {
     ))
 }
 
-/// Deducts PP from a Mon's move.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon whose move to modify.
-/// @param {[`ValueType::String`] | [`ValueType::Effect`]} [move_handle] The move to deduct from.
-/// @param {[`ValueType::UFraction`]} amount The amount of PP to deduct.
-/// @returns {[`ValueType::Boolean`]} Whether PP was successfully deducted.
 fn deduct_pp(mut context: FunctionContext) -> Result<Value> {
     let mon_handle = context.target_handle_positional()?;
     let move_id = context
@@ -4230,12 +5174,6 @@ fn deduct_pp(mut context: FunctionContext) -> Result<Value> {
     .map(|val| Value::UFraction(val.into()))
 }
 
-/// Restores PP to a Mon's move.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon whose move to modify.
-/// @param {[`ValueType::String`] | [`ValueType::Effect`]} [move_handle] The move to restore.
-/// @param {[`ValueType::UFraction`]} amount The amount of PP to restore.
-/// @returns {[`ValueType::Boolean`]} Whether PP was successfully restored.
 fn restore_pp(mut context: FunctionContext) -> Result<Value> {
     let silent = context.silent();
     let mon_handle = context.target_handle_positional()?;
@@ -4258,12 +5196,6 @@ fn restore_pp(mut context: FunctionContext) -> Result<Value> {
     .map(|val| Value::UFraction(val.into()))
 }
 
-/// Sets a Mon's move PP.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon whose move to modify.
-/// @param {[`ValueType::String`] | [`ValueType::Effect`]} [move_handle] The move to modify.
-/// @param {[`ValueType::UFraction`]} pp The new PP value.
-/// @returns {[`ValueType::Boolean`]} Whether the PP was successfully set.
 fn set_pp(mut context: FunctionContext) -> Result<Value> {
     let mon_handle = context.target_handle_positional()?;
     let move_id = context
@@ -4284,12 +5216,6 @@ fn set_pp(mut context: FunctionContext) -> Result<Value> {
     .map(|val| Value::UFraction(val.into()))
 }
 
-/// Adds a slot condition.
-///
-/// @param {[`ValueType::Side`]} [side] The side to modify.
-/// @param {[`ValueType::UFraction`]} slot The slot index.
-/// @param {[`ValueType::String`]} condition The slot condition ID.
-/// @returns {[`ValueType::EventResult`]} Whether the slot condition was successfully added.
 fn add_slot_condition(mut context: FunctionContext) -> Result<Value> {
     let side_index = context.target_side_index_positional()?;
     let slot = context
@@ -4314,12 +5240,6 @@ fn add_slot_condition(mut context: FunctionContext) -> Result<Value> {
     );
     value.map(|val| Value::EventResult(val))
 }
-/// Removes a slot condition.
-///
-/// @param {[`ValueType::Side`]} [side] The side to modify.
-/// @param {[`ValueType::UFraction`]} slot The slot index.
-/// @param {[`ValueType::String`]} condition The slot condition ID.
-/// @returns {[`ValueType::EventResult`]} Whether the slot condition was removed.
 fn remove_slot_condition(mut context: FunctionContext) -> Result<Value> {
     let side_index = context.target_side_index_positional()?;
     let slot = context
@@ -4339,11 +5259,6 @@ fn remove_slot_condition(mut context: FunctionContext) -> Result<Value> {
         .map(|val| Value::EventResult(val))
 }
 
-/// Adds a side condition to a side.
-///
-/// @param {[`ValueType::Side`]} [side] The side to modify.
-/// @param {[`ValueType::String`]} [condition] The condition effect ID.
-/// @returns {[`ValueType::EventResult`]} Whether the condition was added.
 fn add_side_condition(mut context: FunctionContext) -> Result<Value> {
     let side_index = context.target_side_index_positional()?;
     let condition = context
@@ -4359,11 +5274,6 @@ fn add_side_condition(mut context: FunctionContext) -> Result<Value> {
         .map(|val| Value::EventResult(val))
 }
 
-/// Checks if two Mons are adjacent.
-///
-/// @param {[`ValueType::Mon`]} mon The first Mon.
-/// @param {[`ValueType::Mon`]} [other] The second Mon.
-/// @returns {[`ValueType::Boolean`]} Whether the Mons are adjacent.
 fn is_adjacent(mut context: FunctionContext) -> Result<Value> {
     let mon = context.target_handle_positional()?;
     let other = context
@@ -4374,10 +5284,6 @@ fn is_adjacent(mut context: FunctionContext) -> Result<Value> {
     Mon::is_adjacent(&mut context.mon_context(mon)?, other).map(|val| Value::Boolean(val))
 }
 
-/// Cancels a Mon's pending move.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon whose move to cancel.
-/// @returns {[`ValueType::Boolean`]} Whether the move was cancelled.
 fn cancel_move(mut context: FunctionContext) -> Result<Value> {
     let mon = context.target_handle_positional()?;
     Ok(Value::Boolean(
@@ -4389,10 +5295,6 @@ fn cancel_move(mut context: FunctionContext) -> Result<Value> {
     ))
 }
 
-/// Cancels a Mon's pending action.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon whose action to cancel.
-/// @returns {[`ValueType::Boolean`]} Whether the action was cancelled.
 fn cancel_action(mut context: FunctionContext) -> Result<Value> {
     let mon = context.target_handle_positional()?;
     Ok(Value::Boolean(
@@ -4404,12 +5306,6 @@ fn cancel_action(mut context: FunctionContext) -> Result<Value> {
     ))
 }
 
-/// Prioritizes a Mon's move.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon whose move to prioritize.
-/// @param {[`ValueType::UFraction`]} [action_id] The action ID to prioritize.
-/// @param {[`ValueType::Effect`]} [source_effect] The source effect.
-/// @param {[`ValueType::Mon`]} [source] The source Mon.
 fn prioritize_move(mut context: FunctionContext) -> Result<()> {
     let mon_handle = context.target_handle_positional()?;
     let action_id = match context.front().map(|val| val.value_type()) {
@@ -4454,10 +5350,6 @@ fn prioritize_move(mut context: FunctionContext) -> Result<()> {
     Ok(())
 }
 
-/// Deprioritizes a Mon's move.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon whose move to deprioritize.
-/// @param {[`ValueType::UFraction`]} [action_id] The action ID to deprioritize.
 fn deprioritize_move(mut context: FunctionContext) -> Result<()> {
     let mon_handle = context.target_handle_positional()?;
     let action_id = match context.front().map(|val| val.value_type()) {
@@ -4475,12 +5367,6 @@ fn deprioritize_move(mut context: FunctionContext) -> Result<()> {
     Ok(())
 }
 
-/// Adds a move action to the battle queue.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon performing the move.
-/// @param {[`ValueType::String`] | [`ValueType::Effect`]} move_handle The move to perform.
-/// @param {[`ValueType::Mon`]} [target] The target of the move.
-/// @returns {[`ValueType::Boolean`]} Whether the action was successfully added.
 fn add_move_action(mut context: FunctionContext) -> Result<Value> {
     let mon_handle = context.target_handle_positional()?;
     let move_id = context
@@ -4505,17 +5391,12 @@ fn add_move_action(mut context: FunctionContext) -> Result<Value> {
             ultra: false,
             dyna: false,
             tera: false,
+            rotate: None,
         })),
     )?;
     Ok(Value::UFraction(TryInto::<u64>::try_into(id)?.into()))
 }
 
-/// Takes a Mon's held item.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon whose item to take.
-/// @flag dry_run If set, the item is not actually taken.
-/// @flag silent If set, no message is displayed.
-/// @returns {[`ValueType::String`] | [`ValueType::EventResult`]} The ID of the item that was taken.
 fn take_item(mut context: FunctionContext) -> Result<Value> {
     let mon = context.target_handle_positional()?;
     let dry_run = context.has_flag("dry_run");
@@ -4530,11 +5411,6 @@ fn take_item(mut context: FunctionContext) -> Result<Value> {
     .unwrap_or_else(|err| Value::EventResult(err)))
 }
 
-/// Sets a Mon's held item.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to modify.
-/// @param {[`ValueType::String`] | [`ValueType::Effect`]} item The item ID.
-/// @returns {[`ValueType::EventResult`]} Whether the item was successfully set.
 fn set_item(mut context: FunctionContext) -> Result<Value> {
     let mon = context.target_handle_positional()?;
     let item = context
@@ -4552,10 +5428,6 @@ fn set_item(mut context: FunctionContext) -> Result<Value> {
     .map(|val| Value::EventResult(val))
 }
 
-/// Decreases a Mon's weight.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to modify.
-/// @param {[`ValueType::UFraction`]} amount The amount to decrease.
 fn decrease_weight(mut context: FunctionContext) -> Result<()> {
     let mon_handle = context.target_handle_positional()?;
     let amount = context
@@ -4570,11 +5442,6 @@ fn decrease_weight(mut context: FunctionContext) -> Result<()> {
     Ok(())
 }
 
-/// Makes a Mon eat its held item.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to eat.
-/// @flag force If set, the consumption cannot be stopped.
-/// @returns {[`ValueType::EventResult`]} Whether the item was eaten.
 fn eat_item(mut context: FunctionContext) -> Result<Value> {
     let force = context.has_flag("force");
     let mon = context.target_handle_positional()?;
@@ -4585,11 +5452,6 @@ fn eat_item(mut context: FunctionContext) -> Result<Value> {
     .map(|val| Value::EventResult(val))
 }
 
-/// Makes a Mon eat a specific item.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to eat.
-/// @param {[`ValueType::String`]} item The item ID to eat.
-/// @returns {[`ValueType::EventResult`]} Whether the item was eaten.
 fn eat_given_item(mut context: FunctionContext) -> Result<Value> {
     let mon = context.target_handle_positional()?;
     let item = context
@@ -4605,21 +5467,12 @@ fn eat_given_item(mut context: FunctionContext) -> Result<Value> {
     .map(|val| Value::EventResult(val))
 }
 
-/// Uses a Mon's held item.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon using the item.
-/// @returns {[`ValueType::EventResult`]} Whether the item was successfully used.
 fn use_item(mut context: FunctionContext) -> Result<Value> {
     let mon = context.target_handle_positional()?;
     core_battle_actions::use_item(&mut context.forward_to_applying_effect_context_with_target(mon)?)
         .map(|val| Value::EventResult(val))
 }
 
-/// Uses a specific item.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon using the item.
-/// @param {[`ValueType::String`] | [`ValueType::Effect`]} item The item ID to use.
-/// @returns {[`ValueType::EventResult`]} Whether the item was successfully used.
 fn use_given_item(mut context: FunctionContext) -> Result<Value> {
     let mon = context.target_handle_positional()?;
     let item = context
@@ -4635,10 +5488,6 @@ fn use_given_item(mut context: FunctionContext) -> Result<Value> {
     .map(|val| Value::EventResult(val))
 }
 
-/// Makes a Mon discard its held item.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon whose item to discard.
-/// @returns {[`ValueType::Boolean`]} Whether the item was discarded.
 fn discard_item(mut context: FunctionContext) -> Result<Value> {
     let silent = context.has_flag("silent");
     let mon = context.target_handle_positional()?;
@@ -4649,12 +5498,6 @@ fn discard_item(mut context: FunctionContext) -> Result<Value> {
     .map(|val| Value::Boolean(val))
 }
 
-/// Checks if a target is valid for a move.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon using the move.
-/// @param {[`ValueType::Mon`]} target The target Mon to check.
-/// @param {[`ValueType::MoveTarget`]} [move_target] The move target type.
-/// @returns {[`ValueType::Boolean`]} Whether the target is valid.
 fn valid_target(mut context: FunctionContext) -> Result<Value> {
     let mon = context.target_handle_positional()?;
     let target = context
@@ -4673,13 +5516,6 @@ fn valid_target(mut context: FunctionContext) -> Result<Value> {
         .map(|val| Value::Boolean(val))
 }
 
-/// Sets a Mon's ability.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to modify.
-/// @param {[`ValueType::String`] | [`ValueType::Effect`]} ability The new ability ID.
-/// @flag dry_run If set, the ability is not actually changed.
-/// @flag silent If set, no message is displayed.
-/// @returns {[`ValueType::EventResult`]} Whether the ability was successfully set.
 fn set_ability(mut context: FunctionContext) -> Result<Value> {
     let silent = context.silent();
     let dry_run = context.has_flag("dry_run");
@@ -4699,13 +5535,6 @@ fn set_ability(mut context: FunctionContext) -> Result<Value> {
     .map(|val| Value::EventResult(val))
 }
 
-/// Checks if a Mon received an attack this turn.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to check.
-/// @param {[`ValueType::Mon`]} source The source Mon of the attack.
-/// @flag has_damage If set, only attacks that dealt damage are counted.
-/// @flag this_turn If set, only attacks received this turn are counted.
-/// @returns {[`ValueType::Boolean`]} Whether the Mon was attacked.
 fn received_attack(mut context: FunctionContext) -> Result<Value> {
     let has_damage = context.has_flag("has_damage");
     let this_turn = context.has_flag("this_turn");
@@ -4735,12 +5564,6 @@ fn received_attack(mut context: FunctionContext) -> Result<Value> {
     ))
 }
 
-/// Gets the last attack directed at a Mon.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to check.
-/// @flag has_damage If set, only attacks that dealt damage are considered.
-/// @flag different_side If set, only attacks from the opposite side are considered.
-/// @returns {[`ValueType::Object`] | [`ValueType::Undefined`]} The last attack data.
 fn last_attack(mut context: FunctionContext) -> Result<Option<Value>> {
     let has_damage = context.has_flag("has_damage");
     let different_side = context.has_flag("different_side");
@@ -4772,10 +5595,6 @@ fn last_attack(mut context: FunctionContext) -> Result<Option<Value>> {
         .transpose()
 }
 
-/// Adds a secondary effect to a move.
-///
-/// @param {[`ValueType::Effect`]} [move_handle] The move to modify.
-/// @param {[`ValueType::SecondaryHitEffect`]} effect The secondary effect to add.
 fn add_secondary_effect_to_move(mut context: FunctionContext) -> Result<()> {
     let active_move = context
         .pop_front()
@@ -4796,10 +5615,6 @@ fn add_secondary_effect_to_move(mut context: FunctionContext) -> Result<()> {
     Ok(())
 }
 
-/// Checks if a move makes contact.
-///
-/// @param {[`ValueType::Effect`]} [move_handle] The move to check.
-/// @returns {[`ValueType::Boolean`]} Whether the move makes contact.
 fn move_makes_contact(mut context: FunctionContext) -> Result<Value> {
     let active_move = context
         .pop_front()
@@ -4813,10 +5628,6 @@ fn move_makes_contact(mut context: FunctionContext) -> Result<Value> {
     )))
 }
 
-/// Calculates type effectiveness.
-///
-/// @param {[`ValueType::Mon`]} [mon] The target Mon.
-/// @returns {[`ValueType::UFraction`]} The effectiveness multiplier.
 fn type_effectiveness(mut context: FunctionContext) -> Result<Value> {
     let target = context.target_handle_positional()?;
     core_battle_actions::type_effectiveness(
@@ -4825,10 +5636,6 @@ fn type_effectiveness(mut context: FunctionContext) -> Result<Value> {
     .map(|val| Value::Fraction(val.into()))
 }
 
-/// Calculates a type modifier.
-///
-/// @param {[`ValueType::Mon`]} [mon] The target Mon.
-/// @returns {[`ValueType::UFraction`]} The type modifier.
 fn type_modifier(mut context: FunctionContext) -> Result<Value> {
     let target = context.target_handle_positional()?;
     core_battle_actions::type_modifier(
@@ -4837,11 +5644,6 @@ fn type_modifier(mut context: FunctionContext) -> Result<Value> {
     .map(|val| Value::Fraction(val.into()))
 }
 
-/// Gets type chart effectiveness.
-///
-/// @param {[`ValueType::Type`]} attack_type The attacking type.
-/// @param {[`ValueType::Type`]} defense_type The defending type.
-/// @returns {[`ValueType::UFraction`]} The effectiveness multiplier.
 fn type_chart_effectiveness(mut context: FunctionContext) -> Result<Value> {
     let offense = context
         .pop_front()
@@ -4868,11 +5670,6 @@ fn type_chart_effectiveness(mut context: FunctionContext) -> Result<Value> {
     ))
 }
 
-/// Checks type chart immunity.
-///
-/// @param {[`ValueType::Type`]} attack_type The attacking type.
-/// @param {[`ValueType::Type`]} defense_type The defending type.
-/// @returns {[`ValueType::Boolean`]} Whether the defense type is immune.
 fn type_chart_immunity(mut context: FunctionContext) -> Result<Value> {
     let offense = context
         .pop_front()
@@ -4893,13 +5690,6 @@ fn type_chart_immunity(mut context: FunctionContext) -> Result<Value> {
     ))
 }
 
-/// Changes a Mon's forme.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to modify.
-/// @param {[`ValueType::String`]} forme The new forme ID.
-/// @flag permanent If set, the forme change is permanent.
-/// @flag revertible If set with the `permanent` flag, the forme change will revert on exit.
-/// ability. @returns {[`ValueType::EventResult`]} Whether the forme was successfully changed.
 fn forme_change(mut context: FunctionContext) -> Result<Value> {
     let permanent = context.has_flag("permanent");
     let revertible = context.has_flag("revertible");
@@ -4924,11 +5714,6 @@ fn forme_change(mut context: FunctionContext) -> Result<Value> {
     .map(|val| Value::EventResult(val))
 }
 
-/// Executes a primal reversion.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to modify.
-/// @param {[`ValueType::String`]} forme The primal forme ID.
-/// @returns {[`ValueType::EventResult`]} Whether the primal reversion was successful.
 fn primal_reversion(mut context: FunctionContext) -> Result<Value> {
     let target = context.target_handle_positional()?;
     let forme = context
@@ -4944,9 +5729,6 @@ fn primal_reversion(mut context: FunctionContext) -> Result<Value> {
     .map(|val| Value::EventResult(val))
 }
 
-/// Ends a Mon's Dynamax state.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon whose Dynamax to end.
 fn end_dynamax(mut context: FunctionContext) -> Result<()> {
     let target = context.target_handle_positional()?;
     core_battle_actions::end_dynamax(
@@ -4954,12 +5736,6 @@ fn end_dynamax(mut context: FunctionContext) -> Result<()> {
     )
 }
 
-/// Increases a Mon's friendship.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to modify.
-/// @param {[`ValueType::UFraction`]} delta_1 The friendship increase at level 1.
-/// @param {[`ValueType::UFraction`]} delta_2 The friendship increase at level 2.
-/// @param {[`ValueType::UFraction`]} delta_3 The friendship increase at level 3.
 fn increase_friendship(mut context: FunctionContext) -> Result<()> {
     let mon_handle = context.target_handle_positional()?;
     let delta_1 = context
@@ -4984,12 +5760,6 @@ fn increase_friendship(mut context: FunctionContext) -> Result<()> {
     Ok(())
 }
 
-/// Decreases a Mon's friendship.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to modify.
-/// @param {[`ValueType::UFraction`]} delta_1 The friendship decrease at level 1.
-/// @param {[`ValueType::UFraction`]} delta_2 The friendship decrease at level 2.
-/// @param {[`ValueType::UFraction`]} delta_3 The friendship decrease at level 3.
 fn decrease_friendship(mut context: FunctionContext) -> Result<()> {
     let mon_handle = context.target_handle_positional()?;
     let delta_1 = context
@@ -5014,10 +5784,6 @@ fn decrease_friendship(mut context: FunctionContext) -> Result<()> {
     Ok(())
 }
 
-/// Sets a Mon's friendship value.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to modify.
-/// @param {[`ValueType::UFraction`]} friendship The new friendship value.
 fn set_friendship(mut context: FunctionContext) -> Result<()> {
     let mon_handle = context.target_handle_positional()?;
     let friendship = context
@@ -5029,11 +5795,6 @@ fn set_friendship(mut context: FunctionContext) -> Result<()> {
     Ok(())
 }
 
-/// Checks if a side has a specific condition.
-///
-/// @param {[`ValueType::Side`]} [side] The side to check.
-/// @param {[`ValueType::String`]} condition The condition ID.
-/// @returns {[`ValueType::Boolean`]} Whether the side has the condition.
 fn has_side_condition(mut context: FunctionContext) -> Result<Value> {
     let side_index = context.target_side_index_positional()?;
     let condition = context
@@ -5048,10 +5809,6 @@ fn has_side_condition(mut context: FunctionContext) -> Result<Value> {
     )))
 }
 
-/// Gets the integer value of a battle clause.
-///
-/// @param {[`ValueType::String`] | [`ValueType::Effect`]} clause The clause ID.
-/// @returns {[`ValueType::UFraction`] | [`ValueType::Undefined`]} The clause value.
 fn clause_integer_value(mut context: FunctionContext) -> Result<Option<Value>> {
     let clause = context
         .pop_front()
@@ -5068,10 +5825,6 @@ fn clause_integer_value(mut context: FunctionContext) -> Result<Option<Value>> {
         .map(|val| Value::UFraction(val.into())))
 }
 
-/// Gets the type value of a battle clause.
-///
-/// @param {[`ValueType::String`] | [`ValueType::Effect`]} clause The clause ID.
-/// @returns {[`ValueType::Type`] | [`ValueType::Undefined`]} The clause value.
 fn clause_type_value(mut context: FunctionContext) -> Result<Option<Value>> {
     let clause = context
         .pop_front()
@@ -5093,18 +5846,10 @@ fn clause_type_value(mut context: FunctionContext) -> Result<Option<Value>> {
     }
 }
 
-/// Creates a new empty object.
-///
-/// @returns {[`ValueType::Object`]} The new object.
 fn new_object(_: FunctionContext) -> Value {
     Value::Object(HashMap::default())
 }
 
-/// Gets the keys of an object.
-///
-/// @param {[`ValueType::Object`]} object The object to query.
-/// @returns {[`ValueType::List`]} A list of keys.
-/// @returnsitem {[`ValueType::String`]}
 fn object_keys(mut context: FunctionContext) -> Result<Value> {
     let object = context
         .pop_front()
@@ -5120,11 +5865,6 @@ fn object_keys(mut context: FunctionContext) -> Result<Value> {
     ))
 }
 
-/// Gets a value from an object.
-///
-/// @param {[`ValueType::Object`]} object The object to query.
-/// @param {[`ValueType::String`]} key The key to retrieve.
-/// @returns {[`ValueType::Any`] | [`ValueType::Undefined`]} The value at the key.
 fn object_value(mut context: FunctionContext) -> Result<Option<Value>> {
     let object = context
         .pop_front()
@@ -5139,11 +5879,6 @@ fn object_value(mut context: FunctionContext) -> Result<Option<Value>> {
     Ok(object.get(&key).cloned())
 }
 
-/// Increments an integer value in an object.
-///
-/// @param {[`ValueType::Object`]} object The object to modify.
-/// @param {[`ValueType::String`]} key The key to increment.
-/// @returns {[`ValueType::Object`]} The modified object.
 fn object_increment(mut context: FunctionContext) -> Result<Value> {
     let mut object = context
         .pop_front()
@@ -5166,11 +5901,6 @@ fn object_increment(mut context: FunctionContext) -> Result<Value> {
     Ok(Value::Object(object))
 }
 
-/// Gets a value from an object.
-///
-/// @param {[`ValueType::Object`]} object The object to query.
-/// @param {[`ValueType::String`]} key The key to retrieve.
-/// @returns {[`ValueType::Any`] | [`ValueType::Undefined`]} The value at the key.
 fn object_get(mut context: FunctionContext) -> Result<Option<Value>> {
     let object = context
         .pop_front()
@@ -5185,12 +5915,6 @@ fn object_get(mut context: FunctionContext) -> Result<Option<Value>> {
     Ok(object.get(&key).cloned())
 }
 
-/// Sets a value on an object.
-///
-/// @param {[`ValueType::Object`]} object The object to modify.
-/// @param {[`ValueType::String`]} key The key to set.
-/// @param {[`ValueType::Any`]} value The value to set.
-/// @returns {[`ValueType::Object`]} The modified object.
 fn object_set(mut context: FunctionContext) -> Result<Value> {
     let mut object = context
         .pop_front()
@@ -5207,11 +5931,6 @@ fn object_set(mut context: FunctionContext) -> Result<Value> {
     Ok(Value::Object(object))
 }
 
-/// Removes a key from an effect state object.
-///
-/// @param {[`ValueType::EffectState`]} effect_state The effect state to modify.
-/// @param {[`ValueType::String`]} key The key to remove.
-/// @returns {[`ValueType::Any`]} The value that was present, if any.
 fn effect_state_remove_key(mut context: FunctionContext) -> Result<Option<Value>> {
     let effect_state = context
         .pop_front()
@@ -5229,10 +5948,6 @@ fn effect_state_remove_key(mut context: FunctionContext) -> Result<Option<Value>
         .remove(&key))
 }
 
-/// Returns an 's' if the number is not 1.
-///
-/// @param {[`ValueType::UFraction`]} number The number to check.
-/// @returns {[`ValueType::String`]} "s" or empty string.
 fn plural(mut context: FunctionContext) -> Result<Value> {
     if context
         .pop_front()
@@ -5246,10 +5961,6 @@ fn plural(mut context: FunctionContext) -> Result<Value> {
     }
 }
 
-/// Adds pseudo-weather to the field.
-///
-/// @param {[`ValueType::String`]} weather The pseudo-weather ID.
-/// @returns {[`ValueType::EventResult`]} Whether the pseudo-weather was successfully added.
 fn add_pseudo_weather(mut context: FunctionContext) -> Result<Value> {
     let pseudo_weather = context
         .pop_front()
@@ -5267,10 +5978,6 @@ fn add_pseudo_weather(mut context: FunctionContext) -> Result<Value> {
     .map(|val| Value::EventResult(val))
 }
 
-/// Removes pseudo-weather from the field.
-///
-/// @param {[`ValueType::String`]} weather The pseudo-weather ID.
-/// @returns {[`ValueType::EventResult`]} Whether the pseudo-weather was successfully removed.
 fn remove_pseudo_weather(mut context: FunctionContext) -> Result<Value> {
     let pseudo_weather = context
         .pop_front()
@@ -5286,10 +5993,6 @@ fn remove_pseudo_weather(mut context: FunctionContext) -> Result<Value> {
     .map(|val| Value::EventResult(val))
 }
 
-/// Checks if a pseudo-weather is active on the field.
-///
-/// @param {[`ValueType::String`]} weather The pseudo-weather ID.
-/// @returns {[`ValueType::Boolean`]} Whether the pseudo-weather is active.
 fn has_pseudo_weather(mut context: FunctionContext) -> Result<Value> {
     let pseudo_weather = context
         .pop_front()
@@ -5308,9 +6011,6 @@ fn has_pseudo_weather(mut context: FunctionContext) -> Result<Value> {
     ))
 }
 
-/// Starts a Mon's ability.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to modify.
 fn start_ability(mut context: FunctionContext) -> Result<()> {
     let target_handle = context.target_handle_positional()?;
     let silent = context.silent();
@@ -5320,9 +6020,6 @@ fn start_ability(mut context: FunctionContext) -> Result<()> {
     )
 }
 
-/// Ends a Mon's ability.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to modify.
 fn end_ability(mut context: FunctionContext) -> Result<()> {
     let target_handle = context.target_handle_positional()?;
     let silent = context.silent();
@@ -5332,9 +6029,6 @@ fn end_ability(mut context: FunctionContext) -> Result<()> {
     )
 }
 
-/// Starts a Mon's item.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to modify.
 fn start_item(mut context: FunctionContext) -> Result<()> {
     let target_handle = context.target_handle_positional()?;
     let silent = context.silent();
@@ -5344,9 +6038,6 @@ fn start_item(mut context: FunctionContext) -> Result<()> {
     )
 }
 
-/// Ends a Mon's item.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to modify.
 fn end_item(mut context: FunctionContext) -> Result<()> {
     let target_handle = context.target_handle_positional()?;
     let silent = context.silent();
@@ -5356,13 +6047,6 @@ fn end_item(mut context: FunctionContext) -> Result<()> {
     )
 }
 
-/// Gets a Mon's stat value.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to query.
-/// @param {[`ValueType::Stat`]} stat The stat to query.
-/// @flag unboosted Ignore boosts.
-/// @flag unmodified Ignore effect modifiers.
-/// @returns {[`ValueType::UFraction`]} The stat value.
 fn get_stat(mut context: FunctionContext) -> Result<Value> {
     let unboosted = context.has_flag("unboosted");
     let unmodified = context.has_flag("unmodified");
@@ -5382,12 +6066,6 @@ fn get_stat(mut context: FunctionContext) -> Result<Value> {
     .map(|val| Value::UFraction(val.into()))
 }
 
-/// Gets a Mon's best stat.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to query.
-/// @flag unboosted Ignore boosts.
-/// @flag unmodified Ignore effect modifiers.
-/// @returns {[`ValueType::Stat`]} The best stat.
 fn best_stat(mut context: FunctionContext) -> Result<Value> {
     let unboosted = context.has_flag("unboosted");
     let unmodified = context.has_flag("unmodified");
@@ -5401,10 +6079,6 @@ fn best_stat(mut context: FunctionContext) -> Result<Value> {
     .map(|stat| Value::Stat(stat.into()))
 }
 
-/// Gets special data for an item.
-///
-/// @param {[`ValueType::String`] | [`ValueType::Effect`]} item The item ID.
-/// @returns {[`ValueType::SpecialItemData`]} The special item data.
 fn special_item_data(mut context: FunctionContext) -> Result<Value> {
     let item_id = context
         .pop_front()
@@ -5425,11 +6099,6 @@ fn special_item_data(mut context: FunctionContext) -> Result<Value> {
     ))
 }
 
-/// Swaps boosts between two Mons.
-///
-/// @param {[`ValueType::Mon`]} [target] The target Mon.
-/// @param {[`ValueType::Mon`]} [source] The source Mon.
-/// @param {[`ValueType::List`]} [boosts] The list of boosts to swap.
 fn swap_boosts(mut context: FunctionContext) -> Result<()> {
     let target = context.target_handle_positional()?;
     let source = context.source_handle_positional()?;
@@ -5445,10 +6114,6 @@ fn swap_boosts(mut context: FunctionContext) -> Result<()> {
     )
 }
 
-/// Inverts a Mon's boosts.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to modify.
-/// @returns {[`ValueType::EventResult`]} The outcome of the boost inversion.
 fn invert_boosts(mut context: FunctionContext) -> Result<Value> {
     let target = context.target_handle_positional()?;
 
@@ -5458,20 +6123,12 @@ fn invert_boosts(mut context: FunctionContext) -> Result<Value> {
     .map(|val| Value::EventResult(val))
 }
 
-/// Gets a Mon's base species ID.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to query.
-/// @returns {[`ValueType::String`]} The base species ID.
 fn base_species(mut context: FunctionContext) -> Result<Value> {
     let mon = context.target_handle_positional()?;
     let context = context.mon_context(mon)?;
     Mon::base_species_of_species(&context).map(|id| Value::String(id.to_string()))
 }
 
-/// Looks up a base species ID.
-///
-/// @param {[`ValueType::String`]} species The species to query.
-/// @returns {[`ValueType::String`]} The base species ID.
 fn lookup_base_species(mut context: FunctionContext) -> Result<Value> {
     let species = context
         .pop_front()
@@ -5482,9 +6139,6 @@ fn lookup_base_species(mut context: FunctionContext) -> Result<Value> {
         .map(|id| Value::String(id.to_string()))
 }
 
-/// Skips an effect callback.
-///
-/// @param {[`ValueType::String`] | [`ValueType::Effect`]} effect The effect ID.
 fn skip_effect_callback(mut context: FunctionContext) -> Result<()> {
     let effect = context
         .pop_front()
@@ -5502,10 +6156,6 @@ fn skip_effect_callback(mut context: FunctionContext) -> Result<()> {
     Ok(())
 }
 
-/// Saves an arbitrary value to the event state.
-///
-/// @param {[`ValueType::String`]} key Key.
-/// @param {[`ValueType::Any`]} value Value.
 fn save_event_state_value(mut context: FunctionContext) -> Result<()> {
     let key = context
         .pop_front()
@@ -5517,10 +6167,6 @@ fn save_event_state_value(mut context: FunctionContext) -> Result<()> {
     Ok(())
 }
 
-/// Reads an arbitrary value from the event state.
-///
-/// @param {[`ValueType::String`]} key Key.
-/// @returns {[`ValueType::Any`]} Value.
 fn read_event_state_value(mut context: FunctionContext) -> Result<Option<Value>> {
     let key = context
         .pop_front()
@@ -5530,11 +6176,6 @@ fn read_event_state_value(mut context: FunctionContext) -> Result<Option<Value>>
     Ok(context.event_state().read_value(&key))
 }
 
-/// Gets a value from an effect's local data.
-///
-/// @param {[`ValueType::Effect`]} [effect] The effect handle.
-/// @param {[`ValueType::String`]} key The key to retrieve.
-/// @returns {[`ValueType::String`] | [`ValueType::Undefined`]} The value from local data.
 fn value_from_local_data(mut context: FunctionContext) -> Result<Option<Value>> {
     let effect_handle = context.effect_handle_positional()?;
     let key = context
@@ -5553,11 +6194,6 @@ fn value_from_local_data(mut context: FunctionContext) -> Result<Option<Value>>
     .map(|val| val.clone().into()))
 }
 
-/// Checks if a Mon has a specific species registered.
-///
-/// @param {[`ValueType::Player`]} player The player to check.
-/// @param {[`ValueType::String`] | [`ValueType::Effect`]} species The species ID to check.
-/// @returns {[`ValueType::Boolean`]} Whether the species is registered.
 fn has_species_registered(mut context: FunctionContext) -> Result<Value> {
     let player = context
         .pop_front()
@@ -5575,18 +6211,11 @@ fn has_species_registered(mut context: FunctionContext) -> Result<Value> {
     )))
 }
 
-/// Forces a Mon to fully heal.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to heal.
 fn force_fully_heal(mut context: FunctionContext) -> Result<()> {
     let target = context.target_handle_positional()?;
     Mon::force_fully_heal(&mut context.mon_context(target)?)
 }
 
-/// Marks an active move as upgraded to a Z-Move.
-///
-/// @param {[`ValueType::Effect`]} active_move The active move.
-/// @param {[`ValueType::String`]} base_move The base move ID.
 fn set_upgraded_to_z_move(mut context: FunctionContext) -> Result<()> {
     let active_move = context
         .pop_front()
@@ -5605,10 +6234,6 @@ fn set_upgraded_to_z_move(mut context: FunctionContext) -> Result<()> {
     Ok(())
 }
 
-/// Marks an active move as upgraded to a Max Move.
-///
-/// @param {[`ValueType::Effect`]} active_move The active move.
-/// @param {[`ValueType::String`]} base_move The base move ID.
 fn set_upgraded_to_max_move(mut context: FunctionContext) -> Result<()> {
     let active_move = context
         .pop_front()
@@ -5627,13 +6252,6 @@ fn set_upgraded_to_max_move(mut context: FunctionContext) -> Result<()> {
     Ok(())
 }
 
-/// Gets valid targets for a move.
-///
-/// @param {[`ValueType::Effect`]} active_move The active move.
-/// @param {[`ValueType::Mon`]} user The Mon using the move.
-/// @param {[`ValueType::Mon`]} [target] A potential target.
-/// @returns {[`ValueType::List`]} A list of valid target Mons.
-/// @returnsitem {[`ValueType::Mon`]}
 fn get_move_targets(mut context: FunctionContext) -> Result<Value> {
     let active_move = context
         .pop_front()
@@ -5665,11 +6283,6 @@ fn get_move_targets(mut context: FunctionContext) -> Result<Value> {
     })
 }
 
-/// Swaps a Mon's position with another index.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to swap.
-/// @param {[`ValueType::UFraction`]} position The target position index.
-/// @returns {[`ValueType::EventResult`]} Whether the swap was successful.
 fn swap_position(mut context: FunctionContext) -> Result<Value> {
     let target_handle = context.target_handle_positional()?;
     let position = context
@@ -5685,10 +6298,6 @@ fn swap_position(mut context: FunctionContext) -> Result<Value> {
     .map(Value::EventResult)
 }
 
-/// Sets whether a Mon needs to switch out.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to modify.
-/// @param {[`ValueType::Boolean`]} needs_switch Whether the Mon needs to switch.
 fn set_needs_switch(mut context: FunctionContext) -> Result<()> {
     let target_handle = context.target_handle_positional()?;
     let value = context
@@ -5702,10 +6311,6 @@ fn set_needs_switch(mut context: FunctionContext) -> Result<()> {
     Ok(())
 }
 
-/// Forces a Mon to switch out.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to switch.
-/// @returns {[`ValueType::Boolean`]} Whether the switch was successful.
 fn switch_out(mut context: FunctionContext) -> Result<Value> {
     let target_handle = context.target_handle_positional()?;
     let mut context = context.mon_context(target_handle)?;
@@ -5713,10 +6318,6 @@ fn switch_out(mut context: FunctionContext) -> Result<Value> {
     core_battle_actions::switch_out(&mut context, None).map(|val| Value::Boolean(val))
 }
 
-/// Forces a Mon to switch out.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to switch.
-/// @returns {[`ValueType::EventResult`]} Whether the Mon was forced to switch.
 fn force_switch(mut context: FunctionContext) -> Result<Value> {
     let target_handle = context.target_handle_positional()?;
     core_battle_actions::force_switch(
@@ -5725,11 +6326,6 @@ fn force_switch(mut context: FunctionContext) -> Result<Value> {
     .map(|val| Value::EventResult(val))
 }
 
-/// Activates a Mon's ability.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon whose ability to activate. Defaults to the current
-/// source.
-/// @returns {[`ValueType::Boolean`] | [`ValueType::Undefined`]}
 fn activate_ability(mut context: FunctionContext) -> Result<Option<Value>> {
     let target_handle = context.target_handle_positional()?;
     // Parse out any flags early.
@@ -5746,11 +6342,6 @@ fn activate_ability(mut context: FunctionContext) -> Result<Option<Value>> {
     )
 }
 
-/// Activates an applying effect.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to modify.
-/// @param {[`ValueType::String`] | [`ValueType::Effect`]} effect The effect ID.
-/// @returns {[`ValueType::Boolean`] | [`ValueType::Undefined`]}
 fn activate_applying_effect(mut context: FunctionContext) -> Result<Option<Value>> {
     let target_handle = context.target_handle_positional()?;
     let effect = context
@@ -5770,15 +6361,10 @@ fn activate_applying_effect(mut context: FunctionContext) -> Result<Option<Value
     ))
 }
 
-/// Processes faint messages for the battle.
 fn faint_messages(mut context: FunctionContext) -> Result<()> {
     CoreBattle::faint_messages(context.battle_context_mut())
 }
 
-/// Adds an attribute to the last move used.
-///
-/// @param {[`ValueType::Effect`]} [active_move] The active move.
-/// @param {[`ValueType::String`]} attribute The attribute to add.
 fn add_attribute_to_last_move(mut context: FunctionContext) -> Result<()> {
     let attribute = context
         .pop_front()
@@ -5796,11 +6382,6 @@ fn add_attribute_to_last_move(mut context: FunctionContext) -> Result<()> {
     Ok(())
 }
 
-/// Adds an attribute and value to the last move used.
-///
-/// @param {[`ValueType::Effect`]} [active_move] The active move.
-/// @param {[`ValueType::String`]} attribute The attribute key to add.
-/// @param {[`ValueType::String`]} value The value to add.
 fn add_attribute_value_to_last_move(mut context: FunctionContext) -> Result<()> {
     let attribute = context
         .pop_front()
@@ -5824,10 +6405,6 @@ fn add_attribute_value_to_last_move(mut context: FunctionContext) -> Result<()>
     Ok(())
 }
 
-/// Sets Z-Power boosts for a move.
-///
-/// @param {[`ValueType::Effect`]} active_move The active move.
-/// @param {[`ValueType::Object`]} boost_table The boost table.
 fn set_z_power_boosts(mut context: FunctionContext) -> Result<()> {
     let mov = context
         .pop_front()
@@ -5850,11 +6427,6 @@ fn set_z_power_boosts(mut context: FunctionContext) -> Result<()> {
     Ok(())
 }
 
-/// Checks if an effect has a specific event callback.
-///
-/// @param {[`ValueType::Effect`]} effect The effect handle.
-/// @param {[`ValueType::String`]} event The event ID.
-/// @returns {[`ValueType::Boolean`]} Whether the effect has the callback.
 fn effect_has_event_callback(mut context: FunctionContext) -> Result<Value> {
     let effect = context
         .pop_front()
@@ -5875,10 +6447,6 @@ fn effect_has_event_callback(mut context: FunctionContext) -> Result<Value> {
     .map(|val| Value::Boolean(val))
 }
 
-/// Sorts a list of Mons by speed.
-///
-/// @param {[`ValueType::List`]} mons The list of Mons to sort.
-/// @returns {[`ValueType::List`]} The sorted list.
 fn speed_sort_mons(mut context: FunctionContext) -> Result<Value> {
     let mons = context
         .pop_front()
@@ -5889,11 +6457,6 @@ fn speed_sort_mons(mut context: FunctionContext) -> Result<Value> {
         .map(|mons| Value::List(mons.into_iter().map(|mon| Value::Mon(mon)).collect()))
 }
 
-/// Gets the effective weather for a Mon.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to check.
-/// @param {[`ValueType::Mon`]} [origin] The origin Mon.
-/// @returns {[`ValueType::Effect`] | [`ValueType::Undefined`]} The effective weather.
 fn effective_weather(mut context: FunctionContext) -> Result<Option<Value>> {
     let target_handle = context.target_handle_positional()?;
     let origin = context.origin_handle_positional()?;
@@ -5910,9 +6473,6 @@ fn effective_weather(mut context: FunctionContext) -> Result<Option<Value>> {
     }
 }
 
-/// Ends the current battle immediately.
-///
-/// @param {[`ValueType::Side`]} [winning_side] The winning side index.
 fn end_battle(mut context: FunctionContext) -> Result<()> {
     let winning_side = match context.pop_front() {
         Some(val) => Some(val.side_index().wrap_error_with_message("invalid side")?),
@@ -5921,11 +6481,6 @@ fn end_battle(mut context: FunctionContext) -> Result<()> {
     CoreBattle::end_battle(context.battle_context_mut(), winning_side)
 }
 
-/// Calculates the un-Dynamaxed HP for a Mon.
-///
-/// @param {[`ValueType::Mon`]} [mon] The target Mon.
-/// @param {[`ValueType::UFraction`]} hp HP value.
-/// @returns {[`ValueType::UFraction`]} Un-Dynamaxed HP value.
 fn undynamaxed_hp_calculation(mut context: FunctionContext) -> Result<Value> {
     let mon_handle = context.target_handle_positional()?;
     let hp = context
@@ -5942,11 +6497,6 @@ fn undynamaxed_hp_calculation(mut context: FunctionContext) -> Result<Value> {
     ))
 }
 
-/// Adds a sub-ability to a Mon's ability.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to modify.
-/// @param {[`ValueType::String`] | [`ValueType::Effect`]} ability The sub-ability ID.
-/// @returns {[`ValueType::EventResult`]} Whether the sub-ability was successfully added.
 fn add_sub_ability(mut context: FunctionContext) -> Result<Value> {
     let mon_handle = context.target_handle_positional()?;
     let ability_id = context
@@ -5961,10 +6511,6 @@ fn add_sub_ability(mut context: FunctionContext) -> Result<Value> {
     .map(|val| Value::EventResult(val))
 }
 
-/// Clears all sub-abilities from a Mon's ability.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to modify.
-/// @returns {[`ValueType::EventResult`]} Whether the sub-ability was successfully added.
 fn clear_sub_abilities(mut context: FunctionContext) -> Result<Value> {
     let mon_handle = context.target_handle_positional()?;
     core_battle_actions::clear_sub_abilities(
@@ -5973,12 +6519,6 @@ fn clear_sub_abilities(mut context: FunctionContext) -> Result<Value> {
     .map(|val| Value::EventResult(val))
 }
 
-/// Swaps all eligible side conditions between two sides.
-///
-/// @param {[`ValueType::Side`]} [target_side] The target side.
-/// @param {[`ValueType::Side`]} [source_side] The source side to swap with.
-/// @param {[`ValueType::List`]} conditions Eligible conditions to swap.
-/// @returns {[`ValueType::EventResult`]} Whether at least one swap occurred.
 fn swap_side_conditions(mut context: FunctionContext) -> Result<Value> {
     let target_side = context.target_side_index_positional()?;
     let source_side = context.source_side_index_positional()?;
@@ -5999,13 +6539,6 @@ fn swap_side_conditions(mut context: FunctionContext) -> Result<Value> {
     .map(Value::EventResult)
 }
 
-/// Calculates the base damage of a move.
-///
-/// @param {[`ValueType::UFraction`]} level The user level.
-/// @param {[`ValueType::UFraction`]} base_power The move base_power.
-/// @param {[`ValueType::UFraction`]} attack The user attack stat.
-/// @param {[`ValueType::UFraction`]} defense The target defense stat.
-/// @returns {[`ValueType::UFraction`]} Base damage.
 fn calculate_base_damage(mut context: FunctionContext) -> Result<Value> {
     let level = context
         .pop_front()
@@ -6032,10 +6565,6 @@ fn calculate_base_damage(mut context: FunctionContext) -> Result<Value> {
     ))
 }
 
-/// Requests the Mon's player to a select a Mon.
-///
-/// @param {[`ValueType::Mon`]} [mon] The Mon to select for.
-/// @param {[`ValueType::String`]} reason The selection reason.
 fn request_mon_selection(mut context: FunctionContext) -> Result<()> {
     let mon_handle = context.target_handle_positional()?;
     let reason = context
@@ -6049,10 +6578,6 @@ fn request_mon_selection(mut context: FunctionContext) -> Result<()> {
     Ok(())
 }
 
-/// Converts a value to an EventResult.
-///
-/// @param {[`ValueType::Boolean`] | [`ValueType::String`]} value Value.
-/// @returns {[`ValueType::EventResult`]} EventResult.
 fn event_result(mut context: FunctionContext) -> Result<Value> {
     context
         .pop_front()
@@ -6060,3 +6585,79 @@ fn event_result(mut context: FunctionContext) -> Result<Value> {
         .event_result()
         .map(|val| Value::EventResult(val))
 }
+
+fn add_raid_shields(mut context: FunctionContext) -> Result<Value> {
+    let mon_handle = context.target_handle_positional()?;
+    let shields = context
+        .pop_front()
+        .wrap_expectation("missing shields")?
+        .integer_u16()
+        .wrap_error_with_message("invalid shields")?;
+    Ok(Value::Boolean(raid::add_shields(
+        &mut context.forward_to_applying_effect_context_with_target(mon_handle)?,
+        shields,
+    )?))
+}
+
+fn raid_shields(mut context: FunctionContext) -> Result<Value> {
+    let mon_handle = context.target_handle_positional()?;
+    Ok(Value::UFraction(
+        context
+            .mon_context(mon_handle)?
+            .player()
+            .raid_shields
+            .into(),
+    ))
+}
+
+#[cfg(test)]
+mod functions_test {
+    use crate::effect::fxlang::{
+        BuiltinFunction,
+        Function,
+        FunctionSignature,
+        function_signature,
+        functions::BUILTIN_FUNCTIONS,
+    };
+
+    #[test]
+    fn builtin_functions_are_sorted() {
+        assert!(
+            BUILTIN_FUNCTIONS
+                .windows(2)
+                .all(|window| window[0].name < window[1].name)
+        );
+    }
+
+    #[test]
+    fn builtin_functions_have_signatures_and_docs() {
+        let function = BuiltinFunction::new("chance").unwrap();
+        assert_eq!(
+            function.signature(),
+            FunctionSignature {
+                required_args: 1,
+                returns_value: true,
+            }
+        );
+        assert_eq!(function_signature("chance"), Some(function.signature()));
+        assert_eq!(function_signature("my_subroutine"), None);
+        assert!(
+            function
+                .doc()
+                .starts_with("Performs a random chance check.")
+        );
+    }
+
+    #[test]
+    fn resolves_builtin_functions_by_name() {
+        let function = Function::new("chance");
+        assert_eq!(
+            function,
+            Function::Builtin(BuiltinFunction::new("chance").unwrap())
+        );
+        assert_eq!(function.name(), "chance");
+        let function = Function::new("my_subroutine");
+        assert_eq!(function, Function::Named("my_subroutine".into()));
+        assert_eq!(function.name(), "my_subroutine");
+    }
+}
@@ -0,0 +1,55 @@
+use alloc::{
+    collections::BTreeSet,
+    string::String,
+};
+
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+/// Limits on fxlang evaluation, for battles that run programs that are not trusted.
+///
+/// Programs from outside effects and outside conditions are considered untrusted, since they are
+/// not part of battle data. Instruction budgets and list limits apply to all programs, since
+/// untrusted programs can trigger callbacks of any other effect.
+///
+/// Exceeding a limit is an error, which ends the callback like any other evaluation error. No
+/// limits are enforced by default.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EvaluationLimits {
+    /// The maximum number of instructions a single callback or subroutine can execute.
+    ///
+    /// Callbacks of events triggered by a callback have their own budget.
+    #[serde(default)]
+    pub callback_instructions: Option<u64>,
+    /// The maximum number of instructions all programs can execute in a single turn.
+    ///
+    /// Once the budget is exhausted, every callback fails until the next turn starts. The budget
+    /// is reset when a new turn starts, so instructions executed when the battle starts are
+    /// counted separately from the first turn.
+    ///
+    /// The count is part of the battle state, so a forked or restored battle continues with the
+    /// budget that remains for the current turn.
+    #[serde(default)]
+    pub turn_instructions: Option<u64>,
+    /// The maximum length of any list created during evaluation.
+    #[serde(default)]
+    pub list_length: Option<usize>,
+    /// The functions that untrusted programs are allowed to call.
+    ///
+    /// If set, untrusted programs run in restricted mode, and calling any other function is an
+    /// error. Subroutines and native functions are called by name, so they must be allowed as
+    /// well. A subroutine called by an untrusted program runs in restricted mode too.
+    #[serde(default)]
+    pub untrusted_functions: Option<BTreeSet<String>>,
+}
+
+impl EvaluationLimits {
+    /// Checks if an untrusted program is allowed to call the given function.
+    pub fn untrusted_function_allowed(&self, function: &str) -> bool {
+        self.untrusted_functions
+            .as_ref()
+            .is_none_or(|functions| functions.contains(function))
+    }
+}
@@ -0,0 +1,288 @@
+use alloc::{
+    borrow::ToOwned,
+    string::String,
+};
+
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+macro_rules! members {
+    ($($variant:ident => $name:literal,)*) => {
+        /// A member accessed on an fxlang value, resolved when a program is compiled.
+        ///
+        /// Members that the battle engine defines on built-in value types are resolved to a
+        /// variant, so they are not compared by name during evaluation. Any other member, such as
+        /// a key of an object or an effect state, is accessed by name.
+        #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+        #[serde(from = "String", into = "String")]
+        pub enum Member {
+            $($variant,)*
+            Named(String),
+        }
+
+        impl Member {
+            /// Resolves a member by name.
+            pub fn new(name: &str) -> Self {
+                match name {
+                    $($name => Self::$variant,)*
+                    _ => Self::Named(name.to_owned()),
+                }
+            }
+
+            /// The name of the member.
+            pub fn name(&self) -> &str {
+                match self {
+                    $(Self::$variant => $name,)*
+                    Self::Named(name) => name,
+                }
+            }
+        }
+    };
+}
+
+// Maintain alphabetical order.
+members! {
+    Ability => "ability",
+    Acc => "acc",
+    Accuracy => "accuracy",
+    Active => "active",
+    ActiveMove => "active_move",
+    ActiveMoveActions => "active_move_actions",
+    ActivePosition => "active_position",
+    ActiveTurns => "active_turns",
+    AddedType => "added_type",
+    AffectionLevel => "affection_level",
+    AffectsMonsDirectly => "affects_mons_directly",
+    AteItem => "ate_item",
+    Atk => "atk",
+    BaseMaxHp => "base_max_hp",
+    BaseMoveSlots => "base_move_slots",
+    BasePower => "base_power",
+    BaseSpecies => "base_species",
+    BaseStats => "base_stats",
+    BeingCalledBack => "being_called_back",
+    BerryEatingHealth => "berry_eating_health",
+    Boosts => "boosts",
+    Callable => "callable",
+    CanEscape => "can_escape",
+    CanHeal => "can_heal",
+    CanSuppressAbility => "can_suppress_ability",
+    CanSuppressItem => "can_suppress_item",
+    Category => "category",
+    Chance => "chance",
+    Condition => "condition",
+    Damage => "damage",
+    DamagedTargets => "damaged_targets",
+    DamagedThisTurn => "damaged_this_turn",
+    Def => "def",
+    DrainPercent => "drain_percent",
+    Drops => "drops",
+    Dynamaxed => "dynamaxed",
+    EffectState => "effect_state",
+    EffectiveAbility => "effective_ability",
+    EffectiveItem => "effective_item",
+    EffectiveTerrain => "effective_terrain",
+    EffectiveTypes => "effective_types",
+    EffectiveTypesNoAddedType => "effective_types_no_added_type",
+    EffectiveWeather => "effective_weather",
+    Ending => "ending",
+    Environment => "environment",
+    Eva => "eva",
+    Exited => "exited",
+    External => "external",
+    Fainted => "fainted",
+    Fling => "fling",
+    FoeSide => "foe_side",
+    ForceStab => "force_stab",
+    ForceSwitch => "force_switch",
+    ForceTryHitResult => "force_try_hit_result",
+    Friendship => "friendship",
+    Gender => "gender",
+    HealPercent => "heal_percent",
+    HiddenPowerType => "hidden_power_type",
+    Hit => "hit",
+    HitEffect => "hit_effect",
+    Hp => "hp",
+    Id => "id",
+    IgnoreAllSecondaryEffects => "ignore_all_secondary_effects",
+    IgnoreEvasion => "ignore_evasion",
+    Illusion => "illusion",
+    InResidual => "in_residual",
+    Index => "index",
+    IsAbility => "is_ability",
+    IsAsleep => "is_asleep",
+    IsAwayFromField => "is_away_from_field",
+    IsBehindSubstitute => "is_behind_substitute",
+    IsBoolean => "is_boolean",
+    IsChoiceLocked => "is_choice_locked",
+    IsDefined => "is_defined",
+    IsEmpty => "is_empty",
+    IsGrounded => "is_grounded",
+    IsImmuneToEntryHazards => "is_immune_to_entry_hazards",
+    IsItem => "is_item",
+    IsMove => "is_move",
+    IsMoveSecondary => "is_move_secondary",
+    IsRaining => "is_raining",
+    IsSemiInvulnerable => "is_semi_invulnerable",
+    IsSnowing => "is_snowing",
+    IsSoundproof => "is_soundproof",
+    IsSunny => "is_sunny",
+    IsTrue => "is_true",
+    IsUndefined => "is_undefined",
+    Item => "item",
+    ItemUsedThisTurn => "item_used_this_turn",
+    Judgment => "judgment",
+    LastItem => "last_item",
+    LastMove => "last_move",
+    LastMoveUsed => "last_move_used",
+    LastSuccessfulMove => "last_successful_move",
+    LastTargetLocation => "last_target_location",
+    Length => "length",
+    Level => "level",
+    Max => "max",
+    MaxHp => "max_hp",
+    MaxMoveBasePower => "max_move_base_power",
+    MaxPp => "max_pp",
+    Mechanics => "mechanics",
+    Min => "min",
+    MonFaintedLastTurn => "mon_fainted_last_turn",
+    MonsLeft => "mons_left",
+    MonsPerSide => "mons_per_side",
+    MoveLastTurnFailed => "move_last_turn_failed",
+    MoveLastTurnSucceeded => "move_last_turn_succeeded",
+    MoveSlots => "move_slots",
+    MoveThisTurnFailed => "move_this_turn_failed",
+    MovedThisTurn => "moved_this_turn",
+    MultiAttack => "multi_attack",
+    Multiaccuracy => "multiaccuracy",
+    Multihit => "multihit",
+    Name => "name",
+    NaturalGift => "natural_gift",
+    Nature => "nature",
+    NeedsSwitch => "needs_switch",
+    NewlySwitched => "newly_switched",
+    NonExternalActiveMove => "non_external_active_move",
+    NotFullyEvolved => "not_fully_evolved",
+    ObedienceCap => "obedience_cap",
+    Ohko => "ohko",
+    OriginalBaseAbility => "original_base_ability",
+    OriginalBaseSpecies => "original_base_species",
+    OriginalItem => "original_item",
+    OriginalTargets => "original_targets",
+    OverrideDefensiveStat => "override_defensive_stat",
+    OverrideOffensiveStat => "override_offensive_stat",
+    Player => "player",
+    Players => "players",
+    Position => "position",
+    PositionDetails => "position_details",
+    PositiveBoosts => "positive_boosts",
+    Power => "power",
+    Pp => "pp",
+    Priority => "priority",
+    RecoilPercent => "recoil_percent",
+    SecondaryEffects => "secondary_effects",
+    Side => "side",
+    SideCondition => "side_condition",
+    Sides => "sides",
+    SkipBeforeSwitchOut => "skip_before_switch_out",
+    Source => "source",
+    SourceEffect => "source_effect",
+    Spa => "spa",
+    Spd => "spd",
+    Spe => "spe",
+    Species => "species",
+    SpreadHit => "spread_hit",
+    Stats => "stats",
+    StatsLoweredThisTurn => "stats_lowered_this_turn",
+    StatsRaisedThisTurn => "stats_raised_this_turn",
+    Status => "status",
+    StatusState => "status_state",
+    Target => "target",
+    Team => "team",
+    TeamByEffectivePosition => "team_by_effective_position",
+    TechnoBlast => "techno_blast",
+    Terastallized => "terastallized",
+    Terrain => "terrain",
+    Time => "time",
+    TimesAttacked => "times_attacked",
+    ToString => "to_string",
+    TotalActivePositions => "total_active_positions",
+    TotalDamage => "total_damage",
+    TotalFainted => "total_fainted",
+    TracksTarget => "tracks_target",
+    Transformed => "transformed",
+    Trapped => "trapped",
+    TrueNature => "true_nature",
+    Turn => "turn",
+    Type => "type",
+    Types => "types",
+    UndynamaxedHp => "undynamaxed_hp",
+    Upgraded => "upgraded",
+    UpgradedBaseMove => "upgraded_base_move",
+    UpgradedZMove => "upgraded_z_move",
+    UseItem => "use_item",
+    Used => "used",
+    User => "user",
+    UserEffect => "user_effect",
+    UserEffectChance => "user_effect_chance",
+    VolatileEffectState => "volatile_effect_state",
+    VolatileStatus => "volatile_status",
+    Weather => "weather",
+    Weight => "weight",
+    WildEncounterType => "wild_encounter_type",
+    ZMoveBasePower => "z_move_base_power",
+    ZPowerBoosts => "z_power_boosts",
+    ZPowerEffect => "z_power_effect",
+}
+
+impl From<String> for Member {
+    fn from(value: String) -> Self {
+        Self::new(&value)
+    }
+}
+
+impl From<Member> for String {
+    fn from(value: Member) -> Self {
+        match value {
+            Member::Named(name) => name,
+            _ => value.name().to_owned(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod member_test {
+    use crate::effect::fxlang::Member;
+
+    #[test]
+    fn resolves_built_in_members() {
+        assert_eq!(Member::new("hp"), Member::Hp);
+        assert_eq!(Member::new("max_hp"), Member::MaxHp);
+        assert_eq!(Member::Hp.name(), "hp");
+        assert_eq!(Member::MaxHp.name(), "max_hp");
+    }
+
+    #[test]
+    fn accesses_other_members_by_name() {
+        assert_eq!(Member::new("my_key"), Member::Named("my_key".into()));
+        assert_eq!(Member::new("my_key").name(), "my_key");
+    }
+
+    #[test]
+    fn serializes_as_name() {
+        assert_eq!(
+            serde_json::to_string(&Member::MaxHp).unwrap(),
+            r#""max_hp""#
+        );
+        assert_eq!(
+            serde_json::from_str::<Member>(r#""max_hp""#).unwrap(),
+            Member::MaxHp
+        );
+        assert_eq!(
+            serde_json::from_str::<Member>(r#""my_key""#).unwrap(),
+            Member::Named("my_key".into())
+        );
+    }
+}
@@ -1,18 +1,48 @@
+mod bytecode;
+mod checker;
+mod compiler;
 mod context;
+mod coverage;
 mod effect;
 mod effect_state;
 mod eval;
 mod event_state;
 mod functions;
+mod limits;
 mod local_data;
+mod member;
+mod native_functions;
 mod parsed_effect;
 mod program_parser;
 mod statement_parser;
+mod subroutine;
+mod trace;
 mod tree;
 mod value;
 mod variable;
 
+pub use bytecode::{
+    Branch,
+    CompiledProgram,
+};
+pub use checker::{
+    CheckError,
+    ExternalFunctions,
+    InputVar,
+    check_effect,
+    check_effect_data,
+    check_subroutine_data,
+    input_vars,
+};
 pub use context::EvaluationContext;
+#[cfg(feature = "std")]
+pub use coverage::coverage_collector::CoverageCollector;
+pub use coverage::{
+    Coverage,
+    CoverageReport,
+    SUBROUTINE_CALLBACK_NAME,
+    UncoveredProgram,
+};
 pub use effect::{
     BattleEvent,
     BattleEventModifier,
@@ -21,8 +51,11 @@ pub use effect::{
     CallbackInput,
     Callbacks,
     ConditionAttributes,
+    ContextVar,
+    ContextVarSource,
     Effect,
     EffectAttributes,
+    GLOBAL_CONTEXT_VARS,
     Program,
     ProgramMetadata,
     ProgramWithPriority,
@@ -38,16 +71,42 @@ pub use eval::{
     VariableInput,
 };
 pub use event_state::EventState;
-pub use functions::run_function;
+pub use functions::{
+    BuiltinFunction,
+    Function,
+    FunctionContext,
+    FunctionSignature,
+    function_signature,
+    run_function,
+};
+pub use limits::EvaluationLimits;
 pub use local_data::LocalData;
+pub use member::Member;
+pub use native_functions::{
+    NativeFunction,
+    NativeFunctionRegistry,
+};
 pub use parsed_effect::{
     ParsedCallback,
     ParsedEffect,
+    ProgramSource,
 };
 pub use program_parser::{
     ParsedProgram,
     ParsedProgramBlock,
 };
+pub use statement_parser::{
+    StatementToken,
+    StatementTokenKind,
+    tokenize_statement,
+};
+pub use subroutine::ParsedSubroutine;
+#[cfg(feature = "std")]
+pub use trace::writer_tracer::WriterTracer;
+pub use trace::{
+    TraceRecord,
+    Tracer,
+};
 pub use value::{
     MaybeReferenceValue,
     MaybeReferenceValueForOperation,
@@ -0,0 +1,150 @@
+use alloc::{
+    format,
+    string::{
+        String,
+        ToString,
+    },
+    sync::Arc,
+};
+use core::fmt::Debug;
+
+use anyhow::Result;
+use hashbrown::HashMap;
+
+use crate::{
+    effect::fxlang::{
+        FunctionContext,
+        FunctionSignature,
+        Value,
+        function_signature,
+    },
+    error::general_error,
+};
+
+/// A native function, implemented by code embedding the battle engine, that can be called from
+/// fxlang programs.
+///
+/// Native functions may capture state, such as a handle to an external service. Since engine
+/// options are shared with forks of the battle, captured state is shared as well.
+pub type NativeFunction =
+    Arc<dyn Fn(FunctionContext<'_, '_, '_, '_, '_>) -> Result<Option<Value>> + Send + Sync>;
+
+#[derive(Clone)]
+struct NativeFunctionDefinition {
+    function: NativeFunction,
+    signature: FunctionSignature,
+}
+
+impl Debug for NativeFunctionDefinition {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("NativeFunctionDefinition")
+            .field("signature", &self.signature)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A registry of [`NativeFunction`]s.
+///
+/// Registered functions are called from fxlang exactly like built-in functions. Built-in functions
+/// cannot be overridden.
+#[derive(Debug, Default, Clone)]
+pub struct NativeFunctionRegistry {
+    functions: HashMap<String, NativeFunctionDefinition>,
+}
+
+impl NativeFunctionRegistry {
+    /// Registers a new function.
+    ///
+    /// The signature is used for checking programs that call the function without evaluating
+    /// them.
+    pub fn register<F>(
+        &mut self,
+        name: &str,
+        signature: FunctionSignature,
+        function: F,
+    ) -> Result<()>
+    where
+        F: Fn(FunctionContext<'_, '_, '_, '_, '_>) -> Result<Option<Value>> + Send + Sync + 'static,
+    {
+        if function_signature(name).is_some() {
+            return Err(general_error(format!(
+                "{name} is already defined as a built-in function"
+            )));
+        }
+        if self.functions.contains_key(name) {
+            return Err(general_error(format!("{name} is already registered")));
+        }
+        self.functions.insert(
+            name.to_string(),
+            NativeFunctionDefinition {
+                function: Arc::new(function),
+                signature,
+            },
+        );
+        Ok(())
+    }
+
+    /// Looks up a registered function.
+    pub fn function(&self, name: &str) -> Option<NativeFunction> {
+        self.functions
+            .get(name)
+            .map(|definition| definition.function.clone())
+    }
+
+    /// Looks up the signature of a registered function.
+    pub fn signature(&self, name: &str) -> Option<FunctionSignature> {
+        self.functions
+            .get(name)
+            .map(|definition| definition.signature)
+    }
+
+    /// The names of all registered functions.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.functions.keys().map(|name| name.as_str())
+    }
+}
+
+#[cfg(test)]
+mod native_functions_test {
+    use alloc::format;
+
+    use anyhow::Result;
+
+    use crate::effect::fxlang::{
+        FunctionContext,
+        FunctionSignature,
+        NativeFunctionRegistry,
+        Value,
+    };
+
+    fn echo(mut context: FunctionContext) -> Result<Option<Value>> {
+        Ok(context.pop_front())
+    }
+
+    const SIGNATURE: FunctionSignature = FunctionSignature {
+        required_args: 1,
+        returns_value: true,
+    };
+
+    #[test]
+    fn registers_functions() {
+        let mut registry = NativeFunctionRegistry::default();
+        assert_matches::assert_matches!(registry.register("echo", SIGNATURE, echo), Ok(()));
+        assert!(registry.function("echo").is_some());
+        assert_eq!(registry.signature("echo"), Some(SIGNATURE));
+        assert_eq!(registry.names().collect::<alloc::vec::Vec<_>>(), ["echo"]);
+        assert!(registry.function("missing").is_none());
+    }
+
+    #[test]
+    fn fails_to_register_duplicate_functions() {
+        let mut registry = NativeFunctionRegistry::default();
+        assert_matches::assert_matches!(registry.register("echo", SIGNATURE, echo), Ok(()));
+        assert_matches::assert_matches!(registry.register("echo", SIGNATURE, echo), Err(err) => {
+            assert_eq!(format!("{err:#}"), "echo is already registered");
+        });
+        assert_matches::assert_matches!(registry.register("log", SIGNATURE, echo), Err(err) => {
+            assert_eq!(format!("{err:#}"), "log is already defined as a built-in function");
+        });
+    }
+}
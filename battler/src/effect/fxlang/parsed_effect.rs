@@ -1,6 +1,10 @@
-use alloc::string::{
-    String,
-    ToString,
+use alloc::{
+    borrow::ToOwned,
+    string::{
+        String,
+        ToString,
+    },
+    sync::Arc,
 };
 use core::str::FromStr;
 
@@ -18,19 +22,30 @@ use crate::{
         BattleEventModifier,
         Callback,
         Callbacks,
+        CompiledProgram,
         ConditionAttributes,
         LocalData,
-        ParsedProgram,
         ProgramMetadata,
     },
     error::WrapResultError,
     general_error,
 };
 
+/// The location of a program in battle data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProgramSource {
+    /// The ID of the effect in battle data (e.g., `move:tackle/condition`).
+    pub effect: String,
+    /// The name of the callback (e.g., `on_residual`).
+    pub callback: String,
+}
+
 /// Parsed version of [`Callback`][`crate::effect::fxlang::Callback`].
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ParsedCallback {
-    pub program: ParsedProgram,
+    pub program: Arc<CompiledProgram>,
+    /// Where the program came from, if it came from battle data.
+    pub source: Option<Arc<ProgramSource>>,
     pub order: u32,
     pub priority: i32,
     pub sub_order: u32,
@@ -40,8 +55,9 @@ pub struct ParsedCallback {
 impl ParsedCallback {
     /// Extends the callback with another callback, overriding data if applicable.
     fn extend(&mut self, other: Self) {
-        if !other.program.block.is_empty() {
+        if !other.program.is_empty() {
             self.program = other.program;
+            self.source = other.source;
         }
 
         // Always override order numbers.
@@ -88,19 +104,29 @@ pub struct ParsedEffect {
 }
 
 impl ParsedEffect {
-    fn parse_and_save(&mut self, name: &str, callback: &Callback) -> Result<()> {
+    fn parse_and_save(
+        &mut self,
+        name: &str,
+        callback: &Callback,
+        effect_id: Option<&str>,
+    ) -> Result<()> {
         let (event, modifier) = Self::callback_name_to_event_key(name)
             .wrap_error_with_format(format_args!("invalid callback {name}"))?;
 
-        let program = match callback.program() {
-            Some(program) => ParsedProgram::from(program)
-                .wrap_error_with_format(format_args!("error parsing {event} callback"))?,
-            None => ParsedProgram::default(),
-        };
+        let program = callback
+            .compile()
+            .wrap_error_with_format(format_args!("error parsing {event} callback"))?
+            .unwrap_or_default();
         self.callbacks.insert(
             (event, modifier),
             ParsedCallback {
-                program,
+                program: Arc::new(program),
+                source: effect_id.map(|effect_id| {
+                    Arc::new(ProgramSource {
+                        effect: effect_id.to_owned(),
+                        callback: name.to_owned(),
+                    })
+                }),
                 order: callback.order(),
                 priority: callback.priority(),
                 sub_order: callback.sub_order(),
@@ -162,10 +188,14 @@ impl ParsedEffect {
     }
 
     /// Creates a new [`ParsedEffect`].
+    ///
+    /// The effect ID is the ID of the effect in battle data, which is recorded as the source of
+    /// every callback. Effects that do not come from battle data do not have one.
     pub fn new(
         callbacks: &Callbacks,
         condition: ConditionAttributes,
         local_data: LocalData,
+        effect_id: Option<&str>,
     ) -> Result<Self> {
         let mut parsed = Self {
             callbacks: HashMap::default(),
@@ -174,7 +204,7 @@ impl ParsedEffect {
         };
 
         for (name, callback) in callbacks {
-            parsed.parse_and_save(name, callback)?;
+            parsed.parse_and_save(name, callback, effect_id)?;
         }
 
         Ok(parsed)
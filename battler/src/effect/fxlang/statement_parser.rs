@@ -1331,6 +1331,120 @@ impl<'s> StatementParser<'s> {
     }
 }
 
+/// The kind of a [`StatementToken`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatementTokenKind {
+    /// A keyword, such as `if` or `func_call`.
+    Keyword,
+    /// The name of a called function.
+    Function,
+    /// A variable, including the leading `$`.
+    Variable,
+    /// A member accessed on a variable.
+    Member,
+    /// Any other identifier, which is evaluated as a string.
+    Identifier,
+    /// A string literal.
+    String,
+    /// A number literal.
+    Number,
+    /// An operator or punctuation.
+    Symbol,
+    /// A line comment, which lasts until the end of the statement.
+    Comment,
+}
+
+/// A token of an fxlang statement, used by tooling that works on statements as they are written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StatementToken {
+    pub kind: StatementTokenKind,
+    /// The byte offset of the start of the token in the statement.
+    pub start: usize,
+    /// The byte offset of the end of the token in the statement.
+    pub end: usize,
+}
+
+/// Splits an fxlang statement into tokens, classifying identifiers by their surrounding tokens.
+///
+/// Tokenization stops at the first invalid token, since statements are often incomplete while
+/// they are being edited. Use [`StatementParser`] to validate the statement.
+pub fn tokenize_statement(statement: &str) -> Vec<StatementToken> {
+    let mut token_parser = token::TokenParser::new(statement);
+    let mut tokens = Vec::<StatementToken>::new();
+    let mut previous = Vec::<Token>::new();
+    while let Ok(Some(token)) = token_parser.next_token(NextTokenContext::new()) {
+        let start = token_parser.token_index();
+        if token == Token::LineCommentStart {
+            tokens.push(StatementToken {
+                kind: StatementTokenKind::Comment,
+                start,
+                end: statement.len(),
+            });
+            break;
+        }
+        match token {
+            Token::String => {
+                token_parser.consume_string();
+            }
+            _ => {
+                token_parser.consume_lexeme();
+            }
+        }
+        let end = token_parser.token_index();
+
+        let kind = match token {
+            Token::Identifier => match previous.as_slice() {
+                [.., Token::VariableStart] => {
+                    // The variable includes the preceding "$" token.
+                    let start = tokens.pop().map(|token| token.start).unwrap_or(start);
+                    tokens.push(StatementToken {
+                        kind: StatementTokenKind::Variable,
+                        start,
+                        end,
+                    });
+                    previous.push(token);
+                    continue;
+                }
+                [.., Token::Dot] if tokens.len() >= 2 => match tokens[tokens.len() - 2].kind {
+                    StatementTokenKind::Variable | StatementTokenKind::Member => {
+                        StatementTokenKind::Member
+                    }
+                    _ => StatementTokenKind::Identifier,
+                },
+                [] | [.., Token::FuncCallKeyword, Token::LeftParenthesis] => {
+                    StatementTokenKind::Function
+                }
+                _ => StatementTokenKind::Identifier,
+            },
+            Token::String => StatementTokenKind::String,
+            Token::Integer => StatementTokenKind::Number,
+            Token::TrueKeyword
+            | Token::FalseKeyword
+            | Token::UndefinedKeyword
+            | Token::ExprKeyword
+            | Token::FuncCallKeyword
+            | Token::IfKeyword
+            | Token::ElseKeyword
+            | Token::ForEachKeyword
+            | Token::InKeyword
+            | Token::ReturnKeyword
+            | Token::OrKeyword
+            | Token::AndKeyword
+            | Token::HasKeyword
+            | Token::HasAnyKeyword
+            | Token::StrKeyword
+            | Token::ContinueKeyword
+            | Token::BreakKeyword
+            | Token::RequireKeyword
+            | Token::AssignKeyword => StatementTokenKind::Keyword,
+            _ => StatementTokenKind::Symbol,
+        };
+        tokens.push(StatementToken { kind, start, end });
+        previous.push(token);
+    }
+    tokens
+}
+
 #[cfg(test)]
 mod statement_parser_test {
     use alloc::{
@@ -1338,12 +1452,17 @@ mod statement_parser_test {
         boxed::Box,
         format,
         vec,
+        vec::Vec,
     };
 
     use battler_data::Fraction;
     use pretty_assertions::assert_eq;
 
-    use super::StatementParser;
+    use super::{
+        StatementParser,
+        StatementTokenKind,
+        tokenize_statement,
+    };
     use crate::effect::fxlang::tree::{
         self,
         BinaryExprRhs,
@@ -2297,4 +2416,51 @@ mod statement_parser_test {
             })
         );
     }
+
+    #[test]
+    fn tokenizes_statement() {
+        let statement =
+            "if func_call(has_volatile: $target.side substitute) and 1 > 'a': # Comment";
+        assert_eq!(
+            tokenize_statement(statement)
+                .into_iter()
+                .map(|token| (token.kind, &statement[token.start..token.end]))
+                .collect::<Vec<_>>(),
+            vec![
+                (StatementTokenKind::Keyword, "if"),
+                (StatementTokenKind::Keyword, "func_call"),
+                (StatementTokenKind::Symbol, "("),
+                (StatementTokenKind::Function, "has_volatile"),
+                (StatementTokenKind::Symbol, ":"),
+                (StatementTokenKind::Variable, "$target"),
+                (StatementTokenKind::Symbol, "."),
+                (StatementTokenKind::Member, "side"),
+                (StatementTokenKind::Identifier, "substitute"),
+                (StatementTokenKind::Symbol, ")"),
+                (StatementTokenKind::Keyword, "and"),
+                (StatementTokenKind::Number, "1"),
+                (StatementTokenKind::Symbol, ">"),
+                (StatementTokenKind::String, "'a'"),
+                (StatementTokenKind::Symbol, ":"),
+                (StatementTokenKind::Comment, "# Comment"),
+            ]
+        );
+    }
+
+    #[test]
+    fn tokenizes_incomplete_statement() {
+        let statement = "log_activate: $effect. 'unterminated";
+        assert_eq!(
+            tokenize_statement(statement)
+                .into_iter()
+                .map(|token| (token.kind, &statement[token.start..token.end]))
+                .collect::<Vec<_>>(),
+            vec![
+                (StatementTokenKind::Function, "log_activate"),
+                (StatementTokenKind::Symbol, ":"),
+                (StatementTokenKind::Variable, "$effect"),
+                (StatementTokenKind::Symbol, "."),
+            ]
+        );
+    }
 }
@@ -0,0 +1,59 @@
+use alloc::{
+    string::String,
+    vec::Vec,
+};
+
+use anyhow::Result;
+use battler_data::SubroutineData;
+use serde::Deserialize;
+
+use crate::{
+    effect::fxlang::{
+        CompiledProgram,
+        Program,
+    },
+    error::WrapResultError,
+};
+
+/// Parsed version of [`SubroutineData`], which can be evaluated by any program that calls it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedSubroutine {
+    pub parameters: Vec<String>,
+    pub program: CompiledProgram,
+}
+
+impl ParsedSubroutine {
+    /// The key of a subroutine program compiled ahead of time.
+    const COMPILED_KEY: &str = "compiled";
+
+    /// Parses a new subroutine.
+    pub fn new(subroutine: &SubroutineData) -> Result<Self> {
+        let program = match subroutine.program.get(Self::COMPILED_KEY) {
+            Some(compiled) => CompiledProgram::deserialize(compiled)
+                .wrap_error_with_message("invalid compiled subroutine program")?,
+            None => CompiledProgram::from(&Self::program(subroutine)?)?,
+        };
+        Ok(Self {
+            parameters: subroutine.parameters.clone(),
+            program,
+        })
+    }
+
+    /// Compiles the program of a subroutine ahead of time, replacing the source program.
+    ///
+    /// The subroutine can be stored and loaded without parsing its program.
+    pub fn precompile(subroutine: &mut SubroutineData) -> Result<()> {
+        let program = Self::new(subroutine)?.program;
+        subroutine.program = serde_json::json!({
+            Self::COMPILED_KEY: serde_json::to_value(program)
+                .wrap_error_with_message("failed to serialize compiled subroutine program")?,
+        });
+        Ok(())
+    }
+
+    /// Reads the program of a subroutine.
+    pub(crate) fn program(subroutine: &SubroutineData) -> Result<Program> {
+        serde_json::from_value(subroutine.program.clone())
+            .wrap_error_with_message("invalid subroutine program")
+    }
+}
@@ -0,0 +1,123 @@
+use alloc::{
+    string::String,
+    vec::Vec,
+};
+use core::fmt::Debug;
+
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+use crate::effect::fxlang::{
+    BattleEvent,
+    BattleEventModifier,
+    Value,
+};
+
+/// A structured record of fxlang evaluation, emitted to a [`Tracer`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TraceRecord {
+    /// An event is dispatched to the callbacks of all applicable effects.
+    Event {
+        event: BattleEvent,
+        callbacks: usize,
+    },
+    /// An event finished, producing the value relayed through its callbacks.
+    EventResult {
+        event: BattleEvent,
+        value: Option<Value>,
+    },
+    /// A callback of an effect is evaluated.
+    Callback {
+        event: BattleEvent,
+        modifier: BattleEventModifier,
+        effect: String,
+        input: Vec<Value>,
+    },
+    /// A callback finished.
+    CallbackResult {
+        event: BattleEvent,
+        modifier: BattleEventModifier,
+        effect: String,
+        value: Option<Value>,
+        error: Option<String>,
+    },
+    /// The program of a callback from battle data is evaluated.
+    ///
+    /// Identifies the program that the following statements belong to.
+    Program { effect: String, callback: String },
+    /// A subroutine is evaluated.
+    Subroutine { name: String, args: Vec<Value> },
+    /// A subroutine finished.
+    SubroutineResult {
+        name: String,
+        value: Option<Value>,
+        error: Option<String>,
+    },
+    /// A statement of the current program is executed.
+    ///
+    /// Statements are numbered in the order they appear in the program, starting at 1.
+    Statement { statement: usize },
+    /// A variable is read.
+    VariableRead { name: String, value: Value },
+    /// A variable is written.
+    VariableWrite { name: String, value: Value },
+}
+
+/// A hook for tracing fxlang evaluation.
+///
+/// Records are only created when a tracer is installed, so tracing costs nothing when disabled.
+pub trait Tracer: Debug + Send + Sync {
+    /// Records a single trace record.
+    fn record(&self, record: TraceRecord);
+}
+
+#[cfg(feature = "std")]
+pub mod writer_tracer {
+    use std::{
+        io::Write,
+        sync::Mutex,
+    };
+
+    use crate::effect::fxlang::{
+        TraceRecord,
+        Tracer,
+    };
+
+    /// A [`Tracer`] that streams records to a writer, one JSON object per line.
+    ///
+    /// Records that fail to be written are dropped.
+    #[derive(Debug)]
+    pub struct WriterTracer<W> {
+        writer: Mutex<W>,
+    }
+
+    impl<W> WriterTracer<W> {
+        /// Creates a new tracer writing to the given writer.
+        pub fn new(writer: W) -> Self {
+            Self {
+                writer: Mutex::new(writer),
+            }
+        }
+
+        /// Consumes the tracer, returning the underlying writer.
+        pub fn into_inner(self) -> Option<W> {
+            self.writer.into_inner().ok()
+        }
+    }
+
+    impl<W> Tracer for WriterTracer<W>
+    where
+        W: Write + Send + core::fmt::Debug,
+    {
+        fn record(&self, record: TraceRecord) {
+            if let Ok(mut writer) = self.writer.lock()
+                && serde_json::to_writer(&mut *writer, &record).is_ok()
+            {
+                writer.write_all(b"\n").ok();
+            }
+        }
+    }
+}
@@ -9,6 +9,10 @@ use core::{
 };
 
 use battler_data::Fraction;
+use serde::{
+    Deserialize,
+    Serialize,
+};
 
 /// Bool -> "true" | "false"
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -123,7 +127,7 @@ pub struct Assignment {
 /// Operator.
 ///
 /// Precedence is determined by the parser.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Operator {
     Not,
     UnaryPlus,
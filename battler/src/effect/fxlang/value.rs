@@ -50,6 +50,10 @@ use num::traits::{
     WrappingMul,
     WrappingSub,
 };
+use serde::{
+    Deserialize,
+    Serialize,
+};
 use zone_alloc::ElementRef;
 
 use crate::{
@@ -152,7 +156,7 @@ impl Display for ValueType {
 /// An fxlang value.
 ///
 /// Owned and storable.
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub enum Value {
     #[default]
     Undefined,
@@ -5,7 +5,6 @@ use alloc::{
         String,
         ToString,
     },
-    vec::Vec,
 };
 
 use anyhow::{
@@ -22,7 +21,7 @@ use zone_alloc::{
     BorrowError,
     ElementRef,
     ElementRefMut,
-    KeyedRegistry,
+    Registry,
 };
 
 use crate::{
@@ -48,6 +47,7 @@ use crate::{
             EffectStateConnector,
             EvaluationContext,
             MaybeReferenceValueForOperation,
+            Member,
             Value,
             ValueRef,
             ValueRefMut,
@@ -61,48 +61,79 @@ use crate::{
 };
 
 /// A registry of variables for an fxlang program evaluation.
-pub struct VariableRegistry {
-    vars: KeyedRegistry<String, Value>,
+///
+/// Variables are stored in slots, which are resolved when the program is compiled.
+pub struct VariableRegistry<'program> {
+    names: &'program [String],
+    vars: Registry<Value>,
 }
 
-impl VariableRegistry {
-    pub fn new() -> Self {
-        Self {
-            vars: KeyedRegistry::new(),
-        }
+impl<'program> VariableRegistry<'program> {
+    /// Creates a new registry with one undefined variable per slot.
+    pub fn new(names: &'program [String]) -> Self {
+        let vars = Registry::with_capacity(names.len());
+        vars.register_extend(names.iter().map(|_| Value::Undefined));
+        Self { names, vars }
     }
 
-    pub fn get(&self, var: &str) -> Result<Option<ElementRef<'_, Value>>> {
-        match self.vars.get(var) {
-            Ok(val) => Ok(Some(val)),
-            Err(BorrowError::OutOfBounds) => Ok(None),
-            Err(_) => Err(general_error(format!("failed to borrow variable ${var}"))),
+    fn name(&self, slot: usize) -> &str {
+        self.names
+            .get(slot)
+            .map(|name| name.as_str())
+            .unwrap_or_default()
+    }
+
+    pub fn get(&self, slot: usize) -> Result<ElementRef<'_, Value>> {
+        match self.vars.get(slot) {
+            Ok(val) => Ok(val),
+            Err(BorrowError::OutOfBounds) => {
+                Err(general_error(format!("invalid variable slot {slot}")))
+            }
+            Err(_) => Err(general_error(format!(
+                "failed to borrow variable ${}",
+                self.name(slot)
+            ))),
         }
     }
 
-    pub fn get_mut(&self, var: &str) -> Result<Option<ElementRefMut<'_, Value>>> {
-        match self.vars.get_mut(var) {
-            Ok(val) => Ok(Some(val)),
-            Err(BorrowError::OutOfBounds) => Ok(None),
-            Err(_) => Err(general_error(format!("failed to borrow variable ${var}"))),
+    pub fn get_mut(&self, slot: usize) -> Result<ElementRefMut<'_, Value>> {
+        match self.vars.get_mut(slot) {
+            Ok(val) => Ok(val),
+            Err(BorrowError::OutOfBounds) => {
+                Err(general_error(format!("invalid variable slot {slot}")))
+            }
+            Err(_) => Err(general_error(format!(
+                "failed to borrow variable ${}",
+                self.name(slot)
+            ))),
         }
     }
 
-    pub fn set(&self, var: &str, value: Value) -> Result<()> {
-        match self.vars.get_mut(var) {
+    pub fn set(&self, slot: usize, value: Value) -> Result<()> {
+        match self.vars.get_mut(slot) {
             Ok(mut var) => {
                 *var = value;
                 Ok(())
             }
             Err(BorrowError::OutOfBounds) => {
-                self.vars.register(var.to_owned(), value);
-                Ok(())
+                Err(general_error(format!("invalid variable slot {slot}")))
             }
             Err(_) => Err(general_error(format!(
-                "failed to mutably borrow variable ${var}"
+                "failed to mutably borrow variable ${}",
+                self.name(slot)
             ))),
         }
     }
+
+    /// Sets a variable by name.
+    ///
+    /// Variables that are not used by the program do not have a slot, so they are ignored.
+    pub fn set_by_name(&self, var: &str, value: Value) -> Result<()> {
+        match self.names.iter().position(|name| name == var) {
+            Some(slot) => self.set(slot, value),
+            None => Ok(()),
+        }
+    }
 }
 
 /// An fxlang variable.
@@ -110,7 +141,7 @@ impl VariableRegistry {
 /// Acts as a wrapper for an immutable access of a variable that can be consumed at some later time.
 pub struct Variable<'eval, 'program> {
     stored: Option<ElementRef<'eval, Value>>,
-    member_access: Vec<&'program str>,
+    member_access: &'program [Member],
 }
 
 impl<'eval, 'program> Variable<'eval, 'program>
@@ -119,7 +150,7 @@ where
 {
     pub fn new(
         stored: Option<ElementRef<'eval, Value>>,
-        member_access: Vec<&'program str>,
+        member_access: &'program [Member],
     ) -> Self {
         Self {
             stored,
@@ -136,7 +167,7 @@ where
             Some(stored) => ValueRef::from(stored),
             None => ValueRef::Undefined,
         };
-        for member in &self.member_access {
+        for member in self.member_access {
             // SAFETY: For changing the lifetime of context: the mutable reference inside of
             // `value_ref` is only mutated at the very end of this method. Thus, this entire for
             // loop is actually immutable. Furthermore, since we only hold one
@@ -148,23 +179,23 @@ where
             // `value_ref` value (i.e., the mutable borrow inside of it) is dropped.
             let value_type = value.value_type();
 
-            match *member {
-                "is_boolean" => {
+            match member {
+                Member::IsBoolean => {
                     value = ValueRef::Boolean(value.is_boolean());
                 }
-                "is_defined" => {
+                Member::IsDefined => {
                     value = ValueRef::Boolean(!value.is_undefined());
                 }
-                "is_empty" => {
+                Member::IsEmpty => {
                     value = ValueRef::Boolean(value.is_empty());
                 }
-                "is_true" => {
+                Member::IsTrue => {
                     value = ValueRef::Boolean(value.boolean_coercion());
                 }
-                "is_undefined" => {
+                Member::IsUndefined => {
                     value = ValueRef::Boolean(value.is_undefined());
                 }
-                "length" => {
+                Member::Length => {
                     value = match value.len() {
                         Some(len) => ValueRef::UFraction(
                             TryInto::<u64>::try_into(len)
@@ -174,7 +205,7 @@ where
                         None => ValueRef::Undefined,
                     }
                 }
-                "to_string" => {
+                Member::ToString => {
                     value = ValueRef::TempString(
                         MaybeReferenceValueForOperation::from(value).for_formatted_string()?,
                     )
@@ -184,41 +215,41 @@ where
                     if let Some(effect_handle) = value.effect_handle() {
                         effect_matched = true;
                         let context = unsafe { context.unsafely_detach_borrow_mut() };
-                        value = match *member {
-                            "accuracy" => CoreBattle::get_effect_by_handle(
+                        value = match member {
+                            Member::Accuracy => CoreBattle::get_effect_by_handle(
                                 context.battle_context(),
                                 &effect_handle,
                             )?
                             .move_effect()
                             .map(|mov| ValueRef::Accuracy(mov.data.accuracy))
                             .unwrap_or(ValueRef::Undefined),
-                            "base_power" => CoreBattle::get_effect_by_handle(
+                            Member::BasePower => CoreBattle::get_effect_by_handle(
                                 context.battle_context(),
                                 &effect_handle,
                             )?
                             .move_effect()
                             .map(|mov| ValueRef::UFraction(mov.data.base_power.into()))
                             .unwrap_or(ValueRef::Undefined),
-                            "callable" => CoreBattle::get_effect_by_handle(
+                            Member::Callable => CoreBattle::get_effect_by_handle(
                                 context.battle_context(),
                                 &effect_handle,
                             )?
                             .move_effect()
                             .map(|mov| ValueRef::Boolean(mov.callable()))
                             .unwrap_or(ValueRef::Undefined),
-                            "category" => CoreBattle::get_effect_by_handle(
+                            Member::Category => CoreBattle::get_effect_by_handle(
                                 context.battle_context(),
                                 &effect_handle,
                             )?
                             .move_effect()
                             .map(|mov| ValueRef::MoveCategory(mov.data.category))
                             .unwrap_or(ValueRef::Undefined),
-                            "condition" => ValueRef::TempEffect(
+                            Member::Condition => ValueRef::TempEffect(
                                 effect_handle
                                     .condition_handle(context.battle_context())?
                                     .wrap_expectation("effect has no associated condition")?,
                             ),
-                            "damage" => CoreBattle::get_effect_by_handle(
+                            Member::Damage => CoreBattle::get_effect_by_handle(
                                 context.battle_context(),
                                 &effect_handle,
                             )?
@@ -228,7 +259,7 @@ where
                                 None => ValueRef::Undefined,
                             })
                             .unwrap_or(ValueRef::Undefined),
-                            "damaged_targets" => CoreBattle::get_effect_by_handle(
+                            Member::DamagedTargets => CoreBattle::get_effect_by_handle(
                                 context.battle_context(),
                                 &effect_handle,
                             )?
@@ -244,7 +275,7 @@ where
                                 )
                             })
                             .unwrap_or(ValueRef::Undefined),
-                            "drain_percent" => CoreBattle::get_effect_by_handle(
+                            Member::DrainPercent => CoreBattle::get_effect_by_handle(
                                 context.battle_context(),
                                 &effect_handle,
                             )?
@@ -258,7 +289,7 @@ where
                                 )
                             })
                             .unwrap_or(ValueRef::Undefined),
-                            "id" => ValueRef::TempString(
+                            Member::Id => ValueRef::TempString(
                                 CoreBattle::get_effect_by_handle(
                                     context.battle_context(),
                                     &effect_handle,
@@ -267,22 +298,22 @@ where
                                 .as_ref()
                                 .to_owned(),
                             ),
-                            "is_ability" => ValueRef::Boolean(effect_handle.is_ability()),
-                            "is_item" => ValueRef::Boolean(effect_handle.is_item()),
-                            "is_move" => ValueRef::Boolean(effect_handle.is_active_move()),
-                            "is_move_secondary" => {
+                            Member::IsAbility => ValueRef::Boolean(effect_handle.is_ability()),
+                            Member::IsItem => ValueRef::Boolean(effect_handle.is_item()),
+                            Member::IsMove => ValueRef::Boolean(effect_handle.is_active_move()),
+                            Member::IsMoveSecondary => {
                                 ValueRef::Boolean(effect_handle.is_active_move_secondary())
                             }
-                            "is_raining" => ValueRef::Boolean(weather_states::is_raining(
+                            Member::IsRaining => ValueRef::Boolean(weather_states::is_raining(
                                 context.effect_context_for_handle(&effect_handle)?.as_mut(),
                             )),
-                            "is_snowing" => ValueRef::Boolean(weather_states::is_snowing(
+                            Member::IsSnowing => ValueRef::Boolean(weather_states::is_snowing(
                                 context.effect_context_for_handle(&effect_handle)?.as_mut(),
                             )),
-                            "is_sunny" => ValueRef::Boolean(weather_states::is_sunny(
+                            Member::IsSunny => ValueRef::Boolean(weather_states::is_sunny(
                                 context.effect_context_for_handle(&effect_handle)?.as_mut(),
                             )),
-                            "max_move_base_power" => CoreBattle::get_effect_by_handle(
+                            Member::MaxMoveBasePower => CoreBattle::get_effect_by_handle(
                                 context.battle_context(),
                                 &effect_handle,
                             )?
@@ -296,14 +327,14 @@ where
                                     .unwrap_or(ValueRef::Undefined)
                             })
                             .unwrap_or(ValueRef::Undefined),
-                            "multiaccuracy" => CoreBattle::get_effect_by_handle(
+                            Member::Multiaccuracy => CoreBattle::get_effect_by_handle(
                                 context.battle_context(),
                                 &effect_handle,
                             )?
                             .move_effect()
                             .map(|mov| ValueRef::Boolean(mov.data.multiaccuracy))
                             .unwrap_or(ValueRef::Undefined),
-                            "multihit" => CoreBattle::get_effect_by_handle(
+                            Member::Multihit => CoreBattle::get_effect_by_handle(
                                 context.battle_context(),
                                 &effect_handle,
                             )?
@@ -315,7 +346,7 @@ where
                                     .unwrap_or(ValueRef::Undefined)
                             })
                             .unwrap_or(ValueRef::Undefined),
-                            "name" => ValueRef::TempString(
+                            Member::Name => ValueRef::TempString(
                                 CoreBattle::get_effect_by_handle(
                                     context.battle_context(),
                                     &effect_handle,
@@ -323,14 +354,14 @@ where
                                 .name()
                                 .to_owned(),
                             ),
-                            "ohko" => CoreBattle::get_effect_by_handle(
+                            Member::Ohko => CoreBattle::get_effect_by_handle(
                                 context.battle_context(),
                                 &effect_handle,
                             )?
                             .move_effect()
                             .map(|mov| ValueRef::Boolean(mov.data.ohko_type.is_some()))
                             .unwrap_or(ValueRef::Undefined),
-                            "original_targets" => CoreBattle::get_effect_by_handle(
+                            Member::OriginalTargets => CoreBattle::get_effect_by_handle(
                                 context.battle_context(),
                                 &effect_handle,
                             )?
@@ -346,14 +377,14 @@ where
                                 )
                             })
                             .unwrap_or(ValueRef::Undefined),
-                            "priority" => CoreBattle::get_effect_by_handle(
+                            Member::Priority => CoreBattle::get_effect_by_handle(
                                 context.battle_context(),
                                 &effect_handle,
                             )?
                             .move_effect()
                             .map(|mov| ValueRef::Fraction(mov.priority.into()))
                             .unwrap_or(ValueRef::Undefined),
-                            "recoil_percent" => CoreBattle::get_effect_by_handle(
+                            Member::RecoilPercent => CoreBattle::get_effect_by_handle(
                                 context.battle_context(),
                                 &effect_handle,
                             )?
@@ -369,21 +400,21 @@ where
                                 )
                             })
                             .unwrap_or(ValueRef::Undefined),
-                            "target" => CoreBattle::get_effect_by_handle(
+                            Member::Target => CoreBattle::get_effect_by_handle(
                                 context.battle_context(),
                                 &effect_handle,
                             )?
                             .move_effect()
                             .map(|mov| ValueRef::MoveTarget(mov.data.target))
                             .unwrap_or(ValueRef::Undefined),
-                            "type" => CoreBattle::get_effect_by_handle(
+                            Member::Type => CoreBattle::get_effect_by_handle(
                                 context.battle_context(),
                                 &effect_handle,
                             )?
                             .move_effect()
                             .map(|mov| ValueRef::Type(mov.data.primary_type))
                             .unwrap_or(ValueRef::Undefined),
-                            "z_move_base_power" => CoreBattle::get_effect_by_handle(
+                            Member::ZMoveBasePower => CoreBattle::get_effect_by_handle(
                                 context.battle_context(),
                                 &effect_handle,
                             )?
@@ -397,7 +428,7 @@ where
                                     .unwrap_or(ValueRef::Undefined)
                             })
                             .unwrap_or(ValueRef::Undefined),
-                            "z_power_boosts" => CoreBattle::get_effect_by_handle(
+                            Member::ZPowerBoosts => CoreBattle::get_effect_by_handle(
                                 context.battle_context(),
                                 &effect_handle,
                             )?
@@ -417,7 +448,7 @@ where
                                     .unwrap_or(ValueRef::Undefined)
                             })
                             .unwrap_or(ValueRef::Undefined),
-                            "z_power_effect" => CoreBattle::get_effect_by_handle(
+                            Member::ZPowerEffect => CoreBattle::get_effect_by_handle(
                                 context.battle_context(),
                                 &effect_handle,
                             )?
@@ -443,7 +474,7 @@ where
                                     effect_matched = false;
                                     value
                                 } else {
-                                    return Err(Self::bad_member_access(member, value_type));
+                                    return Err(Self::bad_member_access(member.name(), value_type));
                                 }
                             }
                         }
@@ -455,30 +486,30 @@ where
 
                     if let Some(active_move_handle) = value.active_move_handle() {
                         let context = unsafe { context.unsafely_detach_borrow_mut() };
-                        value = match *member {
-                            "effect_state" => ValueRef::EffectState(
+                        value = match member {
+                            Member::EffectState => ValueRef::EffectState(
                                 ActiveMoveEffectStateConnector::new(active_move_handle)
                                     .make_dynamic(),
                             ),
-                            "external" => {
+                            Member::External => {
                                 ValueRef::Boolean(context.active_move(active_move_handle)?.external)
                             }
-                            "hit" => ValueRef::UFraction(
+                            Member::Hit => ValueRef::UFraction(
                                 context.active_move(active_move_handle)?.hit.into(),
                             ),
-                            "hit_effect" => context
+                            Member::HitEffect => context
                                 .active_move(active_move_handle)?
                                 .data
                                 .hit_effect
                                 .as_ref()
                                 .map(ValueRef::HitEffect)
                                 .unwrap_or(ValueRef::Undefined),
-                            "ignore_all_secondary_effects" => ValueRef::Boolean(
+                            Member::IgnoreAllSecondaryEffects => ValueRef::Boolean(
                                 context
                                     .active_move(active_move_handle)?
                                     .ignore_all_secondary_effects,
                             ),
-                            "override_defensive_stat" => {
+                            Member::OverrideDefensiveStat => {
                                 match context
                                     .active_move(active_move_handle)?
                                     .data
@@ -488,7 +519,7 @@ where
                                     None => ValueRef::Undefined,
                                 }
                             }
-                            "override_offensive_stat" => {
+                            Member::OverrideOffensiveStat => {
                                 match context
                                     .active_move(active_move_handle)?
                                     .data
@@ -498,7 +529,7 @@ where
                                     None => ValueRef::Undefined,
                                 }
                             }
-                            "secondary_effects" => ValueRef::TempList(
+                            Member::SecondaryEffects => ValueRef::TempList(
                                 context
                                     .active_move(active_move_handle)?
                                     .data
@@ -512,22 +543,22 @@ where
                                     })
                                     .collect(),
                             ),
-                            "source" | "user" => {
+                            Member::Source | Member::User => {
                                 match context.active_move(active_move_handle)?.used_by {
                                     Some(mon) => ValueRef::Mon(mon),
                                     None => ValueRef::Undefined,
                                 }
                             }
-                            "spread_hit" => ValueRef::Boolean(
+                            Member::SpreadHit => ValueRef::Boolean(
                                 context.active_move(active_move_handle)?.spread_hit,
                             ),
-                            "total_damage" => ValueRef::UFraction(
+                            Member::TotalDamage => ValueRef::UFraction(
                                 context.active_move(active_move_handle)?.total_damage.into(),
                             ),
-                            "upgraded" => ValueRef::Boolean(
+                            Member::Upgraded => ValueRef::Boolean(
                                 context.active_move(active_move_handle)?.upgraded.is_some(),
                             ),
-                            "upgraded_base_move" => context
+                            Member::UpgradedBaseMove => context
                                 .active_move(active_move_handle)?
                                 .upgraded
                                 .as_ref()
@@ -535,31 +566,31 @@ where
                                 .flatten()
                                 .map(|val| ValueRef::TempString(val.to_string()))
                                 .unwrap_or(ValueRef::Undefined),
-                            "upgraded_z_move" => ValueRef::Boolean(
+                            Member::UpgradedZMove => ValueRef::Boolean(
                                 match context.active_move(active_move_handle)?.upgraded {
                                     Some(UpgradedMoveSource::ZMove { .. }) => true,
                                     _ => false,
                                 },
                             ),
-                            "user_effect" => context
+                            Member::UserEffect => context
                                 .active_move(active_move_handle)?
                                 .data
                                 .user_effect
                                 .as_ref()
                                 .map(ValueRef::HitEffect)
                                 .unwrap_or(ValueRef::Undefined),
-                            "user_effect_chance" => context
+                            Member::UserEffectChance => context
                                 .active_move(active_move_handle)?
                                 .data
                                 .user_effect_chance
                                 .map(|val| ValueRef::UFraction(val.convert()))
                                 .unwrap_or(ValueRef::Undefined),
-                            _ => return Err(Self::bad_member_access(member, value_type)),
+                            _ => return Err(Self::bad_member_access(member.name(), value_type)),
                         }
                     } else if let Some(mon_handle) = value.mon_handle() {
                         let context = unsafe { context.unsafely_detach_borrow_mut() };
-                        value = match *member {
-                            "ability" => ValueRef::TempString(
+                        value = match member {
+                            Member::Ability => ValueRef::TempString(
                                 context
                                     .mon(mon_handle)?
                                     .volatile_state
@@ -568,40 +599,42 @@ where
                                     .id
                                     .to_string(),
                             ),
-                            "active" => ValueRef::Boolean(context.mon(mon_handle)?.active),
-                            "active_move" => context
+                            Member::Active => ValueRef::Boolean(context.mon(mon_handle)?.active),
+                            Member::ActiveMove => context
                                 .mon(mon_handle)?
                                 .active_move
                                 .map(|active_move| ValueRef::TempEffect(active_move.into()))
                                 .unwrap_or(ValueRef::Undefined),
-                            "active_move_actions" => ValueRef::UFraction(
+                            Member::ActiveMoveActions => ValueRef::UFraction(
                                 context.mon(mon_handle)?.active_move_actions.into(),
                             ),
-                            "active_position" => match context.mon(mon_handle)?.active_position {
-                                Some(active_position) => ValueRef::UFraction(
-                                    TryInto::<u64>::try_into(active_position)
-                                        .map_err(integer_overflow_error)?
-                                        .into(),
-                                ),
-                                None => ValueRef::Undefined,
-                            },
-                            "active_turns" => {
+                            Member::ActivePosition => {
+                                match context.mon(mon_handle)?.active_position {
+                                    Some(active_position) => ValueRef::UFraction(
+                                        TryInto::<u64>::try_into(active_position)
+                                            .map_err(integer_overflow_error)?
+                                            .into(),
+                                    ),
+                                    None => ValueRef::Undefined,
+                                }
+                            }
+                            Member::ActiveTurns => {
                                 ValueRef::UFraction(context.mon(mon_handle)?.active_turns.into())
                             }
-                            "added_type" => context
+                            Member::AddedType => context
                                 .mon(mon_handle)?
                                 .volatile_state
                                 .added_type
                                 .map(|typ| ValueRef::Type(typ))
                                 .unwrap_or(ValueRef::Undefined),
-                            "affection_level" => ValueRef::UFraction(
+                            Member::AffectionLevel => ValueRef::UFraction(
                                 context.mon(mon_handle)?.affection_level().into(),
                             ),
-                            "ate_item" => ValueRef::Boolean(context.mon(mon_handle)?.ate_item),
-                            "base_max_hp" => {
+                            Member::AteItem => ValueRef::Boolean(context.mon(mon_handle)?.ate_item),
+                            Member::BaseMaxHp => {
                                 ValueRef::UFraction(context.mon(mon_handle)?.base_max_hp.into())
                             }
-                            "base_move_slots" => ValueRef::TempList(
+                            Member::BaseMoveSlots => ValueRef::TempList(
                                 context
                                     .mon(mon_handle)?
                                     .base_move_slots
@@ -614,45 +647,47 @@ where
                                     })
                                     .collect(),
                             ),
-                            "base_species" => {
+                            Member::BaseSpecies => {
                                 ValueRef::Str(&context.mon(mon_handle)?.base_species.as_ref())
                             }
-                            "base_stats" => ValueRef::StatTable(
+                            Member::BaseStats => ValueRef::StatTable(
                                 &context.mon(mon_handle)?.volatile_state.base_stored_stats,
                             ),
-                            "being_called_back" => ValueRef::Boolean(
+                            Member::BeingCalledBack => ValueRef::Boolean(
                                 context.mon(mon_handle)?.switch_state.being_called_back,
                             ),
-                            "berry_eating_health" => ValueRef::UFraction(
+                            Member::BerryEatingHealth => ValueRef::UFraction(
                                 mon_states::berry_eating_health(
                                     &mut context.mon_context(mon_handle)?,
                                 )
                                 .into(),
                             ),
-                            "boosts" => ValueRef::BoostTable(
+                            Member::Boosts => ValueRef::BoostTable(
                                 &context.mon(mon_handle)?.volatile_state.boosts,
                             ),
-                            "can_heal" => ValueRef::Boolean(mon_states::can_heal(
+                            Member::CanHeal => ValueRef::Boolean(mon_states::can_heal(
                                 &mut context.mon_context(mon_handle)?,
                             )),
-                            "can_suppress_ability" => {
+                            Member::CanSuppressAbility => {
                                 ValueRef::Boolean(mon_states::can_suppress_ability(
                                     &mut context.mon_context(mon_handle)?,
                                 ))
                             }
-                            "can_suppress_item" => {
+                            Member::CanSuppressItem => {
                                 ValueRef::Boolean(mon_states::can_suppress_item(
                                     &mut context.mon_context(mon_handle)?,
                                 ))
                             }
-                            "damaged_this_turn" => ValueRef::Boolean(
+                            Member::DamagedThisTurn => ValueRef::Boolean(
                                 context.mon(mon_handle)?.volatile_state.damaged_this_turn,
                             ),
-                            "dynamaxed" => ValueRef::Boolean(context.mon(mon_handle)?.dynamaxed),
-                            "effect_state" => ValueRef::EffectState(
+                            Member::Dynamaxed => {
+                                ValueRef::Boolean(context.mon(mon_handle)?.dynamaxed)
+                            }
+                            Member::EffectState => ValueRef::EffectState(
                                 MonEffectStateConnector::new(mon_handle).make_dynamic(),
                             ),
-                            "effective_ability" => {
+                            Member::EffectiveAbility => {
                                 match mon_states::effective_ability(
                                     &mut context.mon_context(mon_handle)?,
                                 ) {
@@ -662,7 +697,7 @@ where
                                     None => ValueRef::Undefined,
                                 }
                             }
-                            "effective_item" => {
+                            Member::EffectiveItem => {
                                 match mon_states::effective_item(
                                     &mut context.mon_context(mon_handle)?,
                                 ) {
@@ -670,7 +705,7 @@ where
                                     None => ValueRef::Undefined,
                                 }
                             }
-                            "effective_types" => ValueRef::TempList(
+                            Member::EffectiveTypes => ValueRef::TempList(
                                 mon_states::effective_types(&mut context.mon_context(mon_handle)?)
                                     .iter()
                                     .map(|val| {
@@ -678,7 +713,7 @@ where
                                     })
                                     .collect(),
                             ),
-                            "effective_types_no_added_type" => ValueRef::TempList(
+                            Member::EffectiveTypesNoAddedType => ValueRef::TempList(
                                 mon_states::effective_types_no_added_type(
                                     &mut context.mon_context(mon_handle)?,
                                 )
@@ -686,7 +721,7 @@ where
                                 .map(|val| ValueRefToStoredValue::new(None, ValueRef::Type(*val)))
                                 .collect(),
                             ),
-                            "effective_terrain" => {
+                            Member::EffectiveTerrain => {
                                 match mon_states::effective_terrain(
                                     &mut context.mon_context(mon_handle)?,
                                 ) {
@@ -699,27 +734,27 @@ where
                                     None => ValueRef::Undefined,
                                 }
                             }
-                            "exited" => {
+                            Member::Exited => {
                                 ValueRef::Boolean(context.mon(mon_handle)?.exited.is_some())
                             }
-                            "fainted" => ValueRef::Boolean(
+                            Member::Fainted => ValueRef::Boolean(
                                 context.mon(mon_handle)?.exited == Some(MonExitType::Fainted),
                             ),
-                            "foe_side" => {
+                            Member::FoeSide => {
                                 ValueRef::Side(context.mon_context(mon_handle)?.foe_side().index)
                             }
-                            "force_switch" => ValueRef::Boolean(
+                            Member::ForceSwitch => ValueRef::Boolean(
                                 context.mon(mon_handle)?.switch_state.force_switch.is_some(),
                             ),
-                            "friendship" => {
+                            Member::Friendship => {
                                 ValueRef::UFraction(context.mon(mon_handle)?.friendship.into())
                             }
-                            "gender" => ValueRef::Gender(context.mon(mon_handle)?.gender),
-                            "hidden_power_type" => {
+                            Member::Gender => ValueRef::Gender(context.mon(mon_handle)?.gender),
+                            Member::HiddenPowerType => {
                                 ValueRef::Type(context.mon(mon_handle)?.hidden_power_type)
                             }
-                            "hp" => ValueRef::UFraction(context.mon(mon_handle)?.hp.into()),
-                            "illusion" => context
+                            Member::Hp => ValueRef::UFraction(context.mon(mon_handle)?.hp.into()),
+                            Member::Illusion => context
                                 .mon(mon_handle)?
                                 .volatile_state
                                 .illusion
@@ -727,58 +762,58 @@ where
                                 .map(|illusion| Id::from(illusion.species.as_str()))
                                 .map(|val| ValueRef::TempString(val.to_string()))
                                 .unwrap_or(ValueRef::Undefined),
-                            "is_asleep" => ValueRef::Boolean(mon_states::is_asleep(
+                            Member::IsAsleep => ValueRef::Boolean(mon_states::is_asleep(
                                 &mut context.mon_context(mon_handle)?,
                             )),
-                            "is_away_from_field" => {
+                            Member::IsAwayFromField => {
                                 ValueRef::Boolean(mon_states::is_away_from_field(
                                     &mut context.mon_context(mon_handle)?,
                                 ))
                             }
-                            "is_behind_substitute" => {
+                            Member::IsBehindSubstitute => {
                                 ValueRef::Boolean(mon_states::is_behind_substitute(
                                     &mut context.mon_context(mon_handle)?,
                                 ))
                             }
-                            "is_choice_locked" => ValueRef::Boolean(mon_states::is_choice_locked(
-                                &mut context.mon_context(mon_handle)?,
-                            )),
-                            "is_grounded" => ValueRef::Boolean(mon_states::is_grounded(
+                            Member::IsChoiceLocked => ValueRef::Boolean(
+                                mon_states::is_choice_locked(&mut context.mon_context(mon_handle)?),
+                            ),
+                            Member::IsGrounded => ValueRef::Boolean(mon_states::is_grounded(
                                 &mut context.mon_context(mon_handle)?,
                             )),
-                            "is_immune_to_entry_hazards" => {
+                            Member::IsImmuneToEntryHazards => {
                                 ValueRef::Boolean(mon_states::is_immune_to_entry_hazards(
                                     &mut context.mon_context(mon_handle)?,
                                 ))
                             }
-                            "is_semi_invulnerable" => {
+                            Member::IsSemiInvulnerable => {
                                 ValueRef::Boolean(mon_states::is_semi_invulnerable(
                                     &mut context.mon_context(mon_handle)?,
                                 ))
                             }
-                            "is_soundproof" => ValueRef::Boolean(mon_states::is_soundproof(
+                            Member::IsSoundproof => ValueRef::Boolean(mon_states::is_soundproof(
                                 &mut context.mon_context(mon_handle)?,
                             )),
-                            "item" => match context.mon(mon_handle)?.item.as_ref() {
+                            Member::Item => match context.mon(mon_handle)?.item.as_ref() {
                                 Some(item) => ValueRef::TempString(item.to_string()),
                                 None => ValueRef::Undefined,
                             },
-                            "item_used_this_turn" => ValueRef::Boolean(
+                            Member::ItemUsedThisTurn => ValueRef::Boolean(
                                 context.mon(mon_handle)?.volatile_state.item_used_this_turn,
                             ),
-                            "last_item" => {
+                            Member::LastItem => {
                                 match context.mon(mon_handle)?.volatile_state.last_item.as_ref() {
                                     Some(item) => ValueRef::TempString(item.to_string()),
                                     None => ValueRef::Undefined,
                                 }
                             }
-                            "last_move" => {
+                            Member::LastMove => {
                                 match context.mon(mon_handle)?.volatile_state.last_move {
                                     Some(last_move) => ValueRef::TempEffect(last_move.into()),
                                     _ => ValueRef::Undefined,
                                 }
                             }
-                            "last_move_used" => {
+                            Member::LastMoveUsed => {
                                 match context.mon(mon_handle)?.volatile_state.last_move_used {
                                     Some(last_move_used) => {
                                         ValueRef::TempEffect(last_move_used.into())
@@ -786,7 +821,7 @@ where
                                     _ => ValueRef::Undefined,
                                 }
                             }
-                            "last_target_location" => {
+                            Member::LastTargetLocation => {
                                 match context
                                     .mon(mon_handle)?
                                     .volatile_state
@@ -800,9 +835,13 @@ where
                                     None => ValueRef::Undefined,
                                 }
                             }
-                            "level" => ValueRef::UFraction(context.mon(mon_handle)?.level.into()),
-                            "max_hp" => ValueRef::UFraction(context.mon(mon_handle)?.max_hp.into()),
-                            "move_last_turn_failed" => ValueRef::Boolean(
+                            Member::Level => {
+                                ValueRef::UFraction(context.mon(mon_handle)?.level.into())
+                            }
+                            Member::MaxHp => {
+                                ValueRef::UFraction(context.mon(mon_handle)?.max_hp.into())
+                            }
+                            Member::MoveLastTurnFailed => ValueRef::Boolean(
                                 context
                                     .mon(mon_handle)?
                                     .volatile_state
@@ -810,7 +849,7 @@ where
                                     .map(|outcome| outcome.failed())
                                     .unwrap_or(false),
                             ),
-                            "move_last_turn_succeeded" => ValueRef::Boolean(
+                            Member::MoveLastTurnSucceeded => ValueRef::Boolean(
                                 context
                                     .mon(mon_handle)?
                                     .volatile_state
@@ -818,7 +857,7 @@ where
                                     .map(|outcome| outcome.succeeded())
                                     .unwrap_or(false),
                             ),
-                            "move_slots" => ValueRef::TempList(
+                            Member::MoveSlots => ValueRef::TempList(
                                 context
                                     .mon(mon_handle)?
                                     .volatile_state
@@ -832,7 +871,7 @@ where
                                     })
                                     .collect(),
                             ),
-                            "move_this_turn_failed" => ValueRef::Boolean(
+                            Member::MoveThisTurnFailed => ValueRef::Boolean(
                                 context
                                     .mon(mon_handle)?
                                     .volatile_state
@@ -840,27 +879,27 @@ where
                                     .map(|outcome| !outcome.succeeded())
                                     .unwrap_or(false),
                             ),
-                            "moved_this_turn" => ValueRef::Boolean(
+                            Member::MovedThisTurn => ValueRef::Boolean(
                                 context
                                     .mon(mon_handle)?
                                     .volatile_state
                                     .move_this_turn_outcome
                                     .is_some(),
                             ),
-                            "name" => ValueRef::String(&context.mon(mon_handle)?.name),
-                            "nature" => ValueRef::Nature(context.mon(mon_handle)?.nature),
-                            "needs_switch" => ValueRef::Boolean(
+                            Member::Name => ValueRef::String(&context.mon(mon_handle)?.name),
+                            Member::Nature => ValueRef::Nature(context.mon(mon_handle)?.nature),
+                            Member::NeedsSwitch => ValueRef::Boolean(
                                 context.mon(mon_handle)?.switch_state.needs_switch.is_some(),
                             ),
-                            "newly_switched" => {
+                            Member::NewlySwitched => {
                                 ValueRef::Boolean(context.mon(mon_handle)?.newly_switched)
                             }
-                            "non_external_active_move" => context
+                            Member::NonExternalActiveMove => context
                                 .mon(mon_handle)?
                                 .non_external_active_move
                                 .map(|active_move| ValueRef::TempEffect(active_move.into()))
                                 .unwrap_or(ValueRef::Undefined),
-                            "not_fully_evolved" => ValueRef::Boolean(
+                            Member::NotFullyEvolved => ValueRef::Boolean(
                                 context
                                     .battle_context()
                                     .battle()
@@ -870,20 +909,20 @@ where
                                     .data
                                     .not_fully_evolved(),
                             ),
-                            "original_base_ability" => ValueRef::TempString(
+                            Member::OriginalBaseAbility => ValueRef::TempString(
                                 context.mon(mon_handle)?.original_base_ability.to_string(),
                             ),
-                            "original_base_species" => ValueRef::TempString(
+                            Member::OriginalBaseSpecies => ValueRef::TempString(
                                 context.mon(mon_handle)?.original_base_species.to_string(),
                             ),
-                            "original_item" => context
+                            Member::OriginalItem => context
                                 .mon(mon_handle)?
                                 .original_item
                                 .as_ref()
                                 .map(|item| ValueRef::TempString(item.to_string()))
                                 .unwrap_or(ValueRef::Undefined),
-                            "player" => ValueRef::Player(context.mon(mon_handle)?.player),
-                            "position" => {
+                            Member::Player => ValueRef::Player(context.mon(mon_handle)?.player),
+                            Member::Position => {
                                 match Mon::position_on_side(&context.mon_context(mon_handle)?) {
                                     Some(position) => ValueRef::UFraction(
                                         TryInto::<u32>::try_into(position)
@@ -893,56 +932,58 @@ where
                                     None => ValueRef::Undefined,
                                 }
                             }
-                            "position_details" => ValueRef::TempString(format!(
+                            Member::PositionDetails => ValueRef::TempString(format!(
                                 "{}",
                                 Mon::position_details(&context.mon_context(mon_handle)?)?
                             )),
-                            "positive_boosts" => ValueRef::UFraction(
+                            Member::PositiveBoosts => ValueRef::UFraction(
                                 Mon::positive_boosts(&context.mon_context(mon_handle)?).into(),
                             ),
-                            "side" => ValueRef::Side(context.mon(mon_handle)?.side),
-                            "species" => ValueRef::Str(
+                            Member::Side => ValueRef::Side(context.mon(mon_handle)?.side),
+                            Member::Species => ValueRef::Str(
                                 &context.mon(mon_handle)?.volatile_state.species.as_ref(),
                             ),
-                            "stats" => {
+                            Member::Stats => {
                                 ValueRef::StatTable(&context.mon(mon_handle)?.volatile_state.stats)
                             }
-                            "stats_lowered_this_turn" => ValueRef::Boolean(
+                            Member::StatsLoweredThisTurn => ValueRef::Boolean(
                                 context
                                     .mon(mon_handle)?
                                     .volatile_state
                                     .stats_lowered_this_turn,
                             ),
-                            "stats_raised_this_turn" => ValueRef::Boolean(
+                            Member::StatsRaisedThisTurn => ValueRef::Boolean(
                                 context
                                     .mon(mon_handle)?
                                     .volatile_state
                                     .stats_raised_this_turn,
                             ),
-                            "status" => match context.mon(mon_handle)?.status.as_ref() {
+                            Member::Status => match context.mon(mon_handle)?.status.as_ref() {
                                 Some(status) => ValueRef::TempString(status.as_ref().to_owned()),
                                 None => ValueRef::Undefined,
                             },
-                            "terastallized" => context
+                            Member::Terastallized => context
                                 .mon(mon_handle)?
                                 .terastallized
                                 .map(|val| ValueRef::Type(val))
                                 .unwrap_or(ValueRef::Undefined),
-                            "times_attacked" => ValueRef::UFraction(
+                            Member::TimesAttacked => ValueRef::UFraction(
                                 context
                                     .mon(mon_handle)?
                                     .volatile_state
                                     .times_attacked
                                     .into(),
                             ),
-                            "transformed" => ValueRef::Boolean(
+                            Member::Transformed => ValueRef::Boolean(
                                 context.mon(mon_handle)?.volatile_state.transformed,
                             ),
-                            "trapped" => ValueRef::Boolean(Mon::trapped(
+                            Member::Trapped => ValueRef::Boolean(Mon::trapped(
                                 &mut context.mon_context(mon_handle)?,
                             )?),
-                            "true_nature" => ValueRef::Nature(context.mon(mon_handle)?.true_nature),
-                            "types" => ValueRef::TempList(
+                            Member::TrueNature => {
+                                ValueRef::Nature(context.mon(mon_handle)?.true_nature)
+                            }
+                            Member::Types => ValueRef::TempList(
                                 context
                                     .mon(mon_handle)?
                                     .volatile_state
@@ -953,31 +994,31 @@ where
                                     })
                                     .collect(),
                             ),
-                            "volatile_effect_state" => ValueRef::EffectState(
+                            Member::VolatileEffectState => ValueRef::EffectState(
                                 MonVolatileEffectStateConnector::new(mon_handle).make_dynamic(),
                             ),
-                            "undynamaxed_hp" => ValueRef::UFraction(
+                            Member::UndynamaxedHp => ValueRef::UFraction(
                                 context.mon(mon_handle)?.undynamaxed_hp().into(),
                             ),
-                            "weight" => ValueRef::UFraction(
+                            Member::Weight => ValueRef::UFraction(
                                 Mon::get_weight(&mut context.mon_context(mon_handle)?).into(),
                             ),
-                            _ => return Err(Self::bad_member_access(member, value_type)),
+                            _ => return Err(Self::bad_member_access(member.name(), value_type)),
                         }
                     } else if let ValueRef::Player(player) = value {
                         let context = unsafe { context.unsafely_detach_borrow_mut() };
-                        value = match *member {
-                            "can_escape" => ValueRef::Boolean(Player::can_escape(
+                        value = match member {
+                            Member::CanEscape => ValueRef::Boolean(Player::can_escape(
                                 &context.battle_context_mut().player_context(player)?,
                             )),
-                            "mon_fainted_last_turn" => ValueRef::Boolean(
+                            Member::MonFaintedLastTurn => ValueRef::Boolean(
                                 context
                                     .battle_context_mut()
                                     .player_context(player)?
                                     .player()
                                     .fainted_last_turn,
                             ),
-                            "team" => ValueRef::TempList(
+                            Member::Team => ValueRef::TempList(
                                 context
                                     .battle_context_mut()
                                     .player_context(player)?
@@ -988,14 +1029,14 @@ where
                                     })
                                     .collect(),
                             ),
-                            "team_by_effective_position" => ValueRef::TempList(
+                            Member::TeamByEffectivePosition => ValueRef::TempList(
                                 Player::mon_handles_by_effective_position(
                                     &context.battle_context_mut().player_context(player)?,
                                 )?
                                 .map(|mon| ValueRefToStoredValue::new(None, ValueRef::Mon(mon)))
                                 .collect(),
                             ),
-                            "total_active_positions" => ValueRef::UFraction(
+                            Member::TotalActivePositions => ValueRef::UFraction(
                                 TryInto::<u64>::try_into(
                                     context
                                         .battle_context_mut()
@@ -1006,52 +1047,52 @@ where
                                 .map_err(integer_overflow_error)?
                                 .into(),
                             ),
-                            "wild_encounter_type" => Player::wild_encounter_type(
+                            Member::WildEncounterType => Player::wild_encounter_type(
                                 &mut context.battle_context_mut().player_context(player)?,
                             )
                             .map(|val| ValueRef::WildEncounterType(val))
                             .unwrap_or(ValueRef::Undefined),
-                            _ => return Err(Self::bad_member_access(member, value_type)),
+                            _ => return Err(Self::bad_member_access(member.name(), value_type)),
                         }
                     } else if let ValueRef::MoveSlot(move_slot) = value {
-                        value = match *member {
-                            "id" => ValueRef::Str(move_slot.id.as_ref()),
-                            "max_pp" => ValueRef::UFraction(move_slot.max_pp.into()),
-                            "name" => ValueRef::String(&move_slot.name),
-                            "pp" => ValueRef::UFraction(move_slot.pp.into()),
-                            "used" => ValueRef::Boolean(move_slot.used),
-                            _ => return Err(Self::bad_member_access(member, value_type)),
+                        value = match member {
+                            Member::Id => ValueRef::Str(move_slot.id.as_ref()),
+                            Member::MaxPp => ValueRef::UFraction(move_slot.max_pp.into()),
+                            Member::Name => ValueRef::String(&move_slot.name),
+                            Member::Pp => ValueRef::UFraction(move_slot.pp.into()),
+                            Member::Used => ValueRef::Boolean(move_slot.used),
+                            _ => return Err(Self::bad_member_access(member.name(), value_type)),
                         }
                     } else if let ValueRef::Battle = value {
                         let context = unsafe { context.unsafely_detach_borrow_mut() };
-                        value = match *member {
-                            "ending" => {
+                        value = match member {
+                            Member::Ending => {
                                 ValueRef::Boolean(context.battle_context().battle().ending())
                             }
-                            "in_residual" => {
+                            Member::InResidual => {
                                 ValueRef::Boolean(context.battle_context().battle().in_residual())
                             }
-                            "last_move" => context
+                            Member::LastMove => context
                                 .battle_context()
                                 .battle()
                                 .last_move()
                                 .map(|move_handle| ValueRef::TempEffect(move_handle.into()))
                                 .unwrap_or(ValueRef::Undefined),
-                            "last_successful_move" => context
+                            Member::LastSuccessfulMove => context
                                 .battle_context()
                                 .battle()
                                 .last_successful_move()
                                 .map(|move_handle| ValueRef::TempEffect(move_handle.into()))
                                 .unwrap_or(ValueRef::Undefined),
-                            "turn" => {
+                            Member::Turn => {
                                 ValueRef::UFraction(context.battle_context().battle().turn().into())
                             }
-                            _ => return Err(Self::bad_member_access(member, value_type)),
+                            _ => return Err(Self::bad_member_access(member.name(), value_type)),
                         }
                     } else if let ValueRef::Field = value {
                         let context = unsafe { context.unsafely_detach_borrow_mut() };
-                        value = match *member {
-                            "effective_terrain" => {
+                        value = match member {
+                            Member::EffectiveTerrain => {
                                 match Field::effective_terrain(context.battle_context_mut()) {
                                     Some(weather) => ValueRef::Effect(
                                         context
@@ -1062,7 +1103,7 @@ where
                                     None => ValueRef::Undefined,
                                 }
                             }
-                            "effective_weather" => {
+                            Member::EffectiveWeather => {
                                 match Field::effective_weather(context.battle_context_mut()) {
                                     Some(weather) => ValueRef::Effect(
                                         context
@@ -1073,10 +1114,10 @@ where
                                     None => ValueRef::Undefined,
                                 }
                             }
-                            "environment" => ValueRef::FieldEnvironment(
+                            Member::Environment => ValueRef::FieldEnvironment(
                                 context.battle_context().battle().field.environment,
                             ),
-                            "sides" => ValueRef::TempList(
+                            Member::Sides => ValueRef::TempList(
                                 context
                                     .battle_context()
                                     .battle()
@@ -1086,7 +1127,7 @@ where
                                     })
                                     .collect(),
                             ),
-                            "terrain" => {
+                            Member::Terrain => {
                                 match context.battle_context().battle().field.terrain.clone() {
                                     Some(terrain) => ValueRef::Effect(
                                         context
@@ -1097,10 +1138,10 @@ where
                                     None => ValueRef::Undefined,
                                 }
                             }
-                            "time" => {
+                            Member::Time => {
                                 ValueRef::TimeOfDay(context.battle_context().battle().field.time)
                             }
-                            "weather" => {
+                            Member::Weather => {
                                 match context.battle_context().battle().field.weather.clone() {
                                     Some(weather) => ValueRef::Effect(
                                         context
@@ -1111,19 +1152,27 @@ where
                                     None => ValueRef::Undefined,
                                 }
                             }
-                            _ => return Err(Self::bad_member_access(member, value_type)),
+                            _ => return Err(Self::bad_member_access(member.name(), value_type)),
                         }
                     } else if let ValueRef::Format = value {
                         let context = unsafe { context.unsafely_detach_borrow_mut() };
-                        value = match *member {
-                            "mons_per_side" => ValueRef::UFraction(
+                        value = match member {
+                            Member::Mechanics => ValueRef::UFraction(
+                                context
+                                    .battle_context()
+                                    .battle()
+                                    .mechanics()
+                                    .number()
+                                    .into(),
+                            ),
+                            Member::MonsPerSide => ValueRef::UFraction(
                                 TryInto::<u64>::try_into(
                                     context.battle_context().battle().max_side_length(),
                                 )
                                 .map_err(integer_overflow_error)?
                                 .into(),
                             ),
-                            "obedience_cap" => ValueRef::UFraction(
+                            Member::ObedienceCap => ValueRef::UFraction(
                                 context
                                     .battle_context()
                                     .battle()
@@ -1133,30 +1182,30 @@ where
                                     .obedience_cap
                                     .into(),
                             ),
-                            _ => return Err(Self::bad_member_access(member, value_type)),
+                            _ => return Err(Self::bad_member_access(member.name(), value_type)),
                         };
                     } else if let ValueRef::Side(side) = value {
-                        value = match *member {
-                            "foe_side" => ValueRef::Side(
+                        value = match member {
+                            Member::FoeSide => ValueRef::Side(
                                 context
                                     .battle_context_mut()
                                     .side_context(side)?
                                     .foe_side()
                                     .index,
                             ),
-                            "index" => ValueRef::UFraction(
+                            Member::Index => ValueRef::UFraction(
                                 TryInto::<u64>::try_into(side)
                                     .map_err(integer_overflow_error)?
                                     .into(),
                             ),
-                            "mons_left" => ValueRef::UFraction(
+                            Member::MonsLeft => ValueRef::UFraction(
                                 TryInto::<u64>::try_into(Side::mons_left(
                                     &mut context.battle_context_mut().side_context(side)?,
                                 )?)
                                 .map_err(integer_overflow_error)?
                                 .into(),
                             ),
-                            "players" => ValueRef::TempList(
+                            Member::Players => ValueRef::TempList(
                                 context
                                     .battle_context()
                                     .battle()
@@ -1166,7 +1215,7 @@ where
                                     })
                                     .collect(),
                             ),
-                            "total_fainted" => ValueRef::UFraction(
+                            Member::TotalFainted => ValueRef::UFraction(
                                 context
                                     .battle_context_mut()
                                     .side_context(side)?
@@ -1174,160 +1223,160 @@ where
                                     .total_fainted
                                     .into(),
                             ),
-                            _ => return Err(Self::bad_member_access(member, value_type)),
+                            _ => return Err(Self::bad_member_access(member.name(), value_type)),
                         }
                     } else if let ValueRef::HitEffect(hit_effect) = value {
-                        value = match *member {
-                            "boosts" => hit_effect
+                        value = match member {
+                            Member::Boosts => hit_effect
                                 .boosts
                                 .as_ref()
                                 .map(ValueRef::BoostTable)
                                 .unwrap_or(ValueRef::Undefined),
-                            "volatile_status" => hit_effect
+                            Member::VolatileStatus => hit_effect
                                 .volatile_status
                                 .as_ref()
                                 .map(ValueRef::String)
                                 .unwrap_or(ValueRef::Undefined),
-                            _ => return Err(Self::bad_member_access(member, value_type)),
+                            _ => return Err(Self::bad_member_access(member.name(), value_type)),
                         }
                     } else if let ValueRef::SecondaryHitEffect(secondary_effect) = value {
-                        value = match *member {
-                            "chance" => secondary_effect
+                        value = match member {
+                            Member::Chance => secondary_effect
                                 .chance
                                 .as_ref()
                                 .map(|val| ValueRef::UFraction(val.convert()))
                                 .unwrap_or(ValueRef::Undefined),
-                            "target" => secondary_effect
+                            Member::Target => secondary_effect
                                 .target
                                 .as_ref()
                                 .map(ValueRef::HitEffect)
                                 .unwrap_or(ValueRef::Undefined),
-                            "user" => secondary_effect
+                            Member::User => secondary_effect
                                 .user
                                 .as_ref()
                                 .map(ValueRef::HitEffect)
                                 .unwrap_or(ValueRef::Undefined),
-                            _ => return Err(Self::bad_member_access(member, value_type)),
+                            _ => return Err(Self::bad_member_access(member.name(), value_type)),
                         }
                     } else if let ValueRef::BoostTable(boosts) = value {
-                        value = match *member {
-                            "acc" => ValueRef::Fraction(boosts.acc.into()),
-                            "atk" => ValueRef::Fraction(boosts.atk.into()),
-                            "def" => ValueRef::Fraction(boosts.def.into()),
-                            "eva" => ValueRef::Fraction(boosts.eva.into()),
-                            "spa" => ValueRef::Fraction(boosts.spa.into()),
-                            "spd" => ValueRef::Fraction(boosts.spd.into()),
-                            "spe" => ValueRef::Fraction(boosts.spe.into()),
-                            _ => return Err(Self::bad_member_access(member, value_type)),
+                        value = match member {
+                            Member::Acc => ValueRef::Fraction(boosts.acc.into()),
+                            Member::Atk => ValueRef::Fraction(boosts.atk.into()),
+                            Member::Def => ValueRef::Fraction(boosts.def.into()),
+                            Member::Eva => ValueRef::Fraction(boosts.eva.into()),
+                            Member::Spa => ValueRef::Fraction(boosts.spa.into()),
+                            Member::Spd => ValueRef::Fraction(boosts.spd.into()),
+                            Member::Spe => ValueRef::Fraction(boosts.spe.into()),
+                            _ => return Err(Self::bad_member_access(member.name(), value_type)),
                         }
                     } else if let ValueRef::StatTable(stats) = value {
-                        value = match *member {
-                            "atk" => ValueRef::Fraction(stats.atk.into()),
-                            "def" => ValueRef::Fraction(stats.def.into()),
-                            "hp" => ValueRef::Fraction(stats.hp.into()),
-                            "spa" => ValueRef::Fraction(stats.spa.into()),
-                            "spd" => ValueRef::Fraction(stats.spd.into()),
-                            "spe" => ValueRef::Fraction(stats.spe.into()),
-                            _ => return Err(Self::bad_member_access(member, value_type)),
+                        value = match member {
+                            Member::Atk => ValueRef::Fraction(stats.atk.into()),
+                            Member::Def => ValueRef::Fraction(stats.def.into()),
+                            Member::Hp => ValueRef::Fraction(stats.hp.into()),
+                            Member::Spa => ValueRef::Fraction(stats.spa.into()),
+                            Member::Spd => ValueRef::Fraction(stats.spd.into()),
+                            Member::Spe => ValueRef::Fraction(stats.spe.into()),
+                            _ => return Err(Self::bad_member_access(member.name(), value_type)),
                         }
                     } else if let ValueRef::Nature(nature) = value {
-                        value = match *member {
-                            "boosts" => {
+                        value = match member {
+                            Member::Boosts => {
                                 ValueRef::Boost(nature.boosts().try_into().map_err(general_error)?)
                             }
-                            "drops" => {
+                            Member::Drops => {
                                 ValueRef::Boost(nature.drops().try_into().map_err(general_error)?)
                             }
-                            _ => return Err(Self::bad_member_access(member, value_type)),
+                            _ => return Err(Self::bad_member_access(member.name(), value_type)),
                         }
                     } else if let ValueRef::MoveTarget(target) = value {
-                        value = match *member {
-                            "affects_mons_directly" => {
+                        value = match member {
+                            Member::AffectsMonsDirectly => {
                                 ValueRef::Boolean(target.affects_mons_directly())
                             }
-                            _ => return Err(Self::bad_member_access(member, value_type)),
+                            _ => return Err(Self::bad_member_access(member.name(), value_type)),
                         }
                     } else if let ValueRef::SpecialItemData(special_item_data) = value {
-                        value = match *member {
-                            "fling" => special_item_data
+                        value = match member {
+                            Member::Fling => special_item_data
                                 .fling
                                 .as_ref()
                                 .map(ValueRef::FlingData)
                                 .unwrap_or(ValueRef::Undefined),
-                            "judgment" => special_item_data
+                            Member::Judgment => special_item_data
                                 .judgment
                                 .as_ref()
                                 .map(ValueRef::JudgmentData)
                                 .unwrap_or(ValueRef::Undefined),
-                            "multi_attack" => special_item_data
+                            Member::MultiAttack => special_item_data
                                 .multi_attack
                                 .as_ref()
                                 .map(ValueRef::MultiAttackData)
                                 .unwrap_or(ValueRef::Undefined),
-                            "natural_gift" => special_item_data
+                            Member::NaturalGift => special_item_data
                                 .natural_gift
                                 .as_ref()
                                 .map(ValueRef::NaturalGiftData)
                                 .unwrap_or(ValueRef::Undefined),
-                            "techno_blast" => special_item_data
+                            Member::TechnoBlast => special_item_data
                                 .techno_blast
                                 .as_ref()
                                 .map(ValueRef::TechnoBlastData)
                                 .unwrap_or(ValueRef::Undefined),
-                            _ => return Err(Self::bad_member_access(member, value_type)),
+                            _ => return Err(Self::bad_member_access(member.name(), value_type)),
                         }
                     } else if let ValueRef::FlingData(fling_data) = value {
-                        value = match *member {
-                            "hit_effect" => fling_data
+                        value = match member {
+                            Member::HitEffect => fling_data
                                 .hit_effect
                                 .as_ref()
                                 .map(|hit_effect| ValueRef::HitEffect(hit_effect))
                                 .unwrap_or(ValueRef::Undefined),
-                            "power" => ValueRef::UFraction(fling_data.power.into()),
-                            "use_item" => ValueRef::Boolean(fling_data.use_item),
-                            _ => return Err(Self::bad_member_access(member, value_type)),
+                            Member::Power => ValueRef::UFraction(fling_data.power.into()),
+                            Member::UseItem => ValueRef::Boolean(fling_data.use_item),
+                            _ => return Err(Self::bad_member_access(member.name(), value_type)),
                         }
                     } else if let ValueRef::NaturalGiftData(natural_gift_data) = value {
-                        value = match *member {
-                            "power" => ValueRef::UFraction(natural_gift_data.power.into()),
-                            "type" => ValueRef::Type(natural_gift_data.typ),
-                            _ => return Err(Self::bad_member_access(member, value_type)),
+                        value = match member {
+                            Member::Power => ValueRef::UFraction(natural_gift_data.power.into()),
+                            Member::Type => ValueRef::Type(natural_gift_data.typ),
+                            _ => return Err(Self::bad_member_access(member.name(), value_type)),
                         }
                     } else if let ValueRef::MultihitType(multihit) = value {
-                        value = match *member {
-                            "max" => ValueRef::UFraction(multihit.max().into()),
-                            "min" => ValueRef::UFraction(multihit.min().into()),
-                            _ => return Err(Self::bad_member_access(member, value_type)),
+                        value = match member {
+                            Member::Max => ValueRef::UFraction(multihit.max().into()),
+                            Member::Min => ValueRef::UFraction(multihit.min().into()),
+                            _ => return Err(Self::bad_member_access(member.name(), value_type)),
                         }
                     } else if let ValueRef::JudgmentData(judgment_data) = value {
-                        value = match *member {
-                            "type" => ValueRef::Type(judgment_data.typ),
-                            _ => return Err(Self::bad_member_access(member, value_type)),
+                        value = match member {
+                            Member::Type => ValueRef::Type(judgment_data.typ),
+                            _ => return Err(Self::bad_member_access(member.name(), value_type)),
                         }
                     } else if let ValueRef::MultiAttackData(multi_attack_data) = value {
-                        value = match *member {
-                            "type" => ValueRef::Type(multi_attack_data.typ),
-                            _ => return Err(Self::bad_member_access(member, value_type)),
+                        value = match member {
+                            Member::Type => ValueRef::Type(multi_attack_data.typ),
+                            _ => return Err(Self::bad_member_access(member.name(), value_type)),
                         }
                     } else if let ValueRef::TechnoBlastData(techno_blast_data) = value {
-                        value = match *member {
-                            "type" => ValueRef::Type(techno_blast_data.typ),
-                            _ => return Err(Self::bad_member_access(member, value_type)),
+                        value = match member {
+                            Member::Type => ValueRef::Type(techno_blast_data.typ),
+                            _ => return Err(Self::bad_member_access(member.name(), value_type)),
                         }
                     } else if let ValueRef::EffectState(connector) = value {
                         let context = unsafe { context.unsafely_detach_borrow_mut() };
                         value = connector
                             .get_mut(context.battle_context_mut())?
-                            .get(*member)
+                            .get(member.name())
                             .map(ValueRef::from)
                             .unwrap_or(ValueRef::Undefined);
                     } else if let ValueRef::Object(object) = value {
-                        value = match object.get(*member) {
+                        value = match object.get(member.name()) {
                             Some(value) => ValueRef::from(value),
                             _ => ValueRef::Undefined,
                         };
                     } else {
-                        return Err(Self::bad_member_access(member, value_type));
+                        return Err(Self::bad_member_access(member.name(), value_type));
                     }
                 }
             }
@@ -1356,14 +1405,14 @@ where
 /// Acts as a wrapper for a mutable access of a variable that can be consumed at some later time.
 pub struct VariableMut<'eval, 'program> {
     stored: ElementRefMut<'eval, Value>,
-    member_access: Vec<&'program str>,
+    member_access: &'program [Member],
 }
 
 impl<'eval, 'program> VariableMut<'eval, 'program>
 where
     'program: 'eval,
 {
-    pub fn new(stored: ElementRefMut<'eval, Value>, member_access: Vec<&'program str>) -> Self {
+    pub fn new(stored: ElementRefMut<'eval, Value>, member_access: &'program [Member]) -> Self {
         Self {
             stored,
             member_access,
@@ -1382,7 +1431,7 @@ where
     ) -> Result<ValueRefMut<'var>> {
         let mut value = ValueRefMut::from(self.stored.as_mut());
 
-        for member in &self.member_access {
+        for member in self.member_access {
             let value_type = value.value_type();
 
             // SAFETY: For changing the lifetime of context: the mutable reference inside of
@@ -1397,202 +1446,236 @@ where
             match value {
                 ValueRefMut::Mon(ref mon_handle) => {
                     let context = unsafe { context.unsafely_detach_borrow_mut() };
-                    value = match *member {
-                        "boosts" => ValueRefMut::BoostTable(
+                    value = match member {
+                        Member::Boosts => ValueRefMut::BoostTable(
                             &mut context.mon_mut(**mon_handle)?.volatile_state.boosts,
                         ),
-                        "effect_state" => ValueRefMut::TempEffectState(
+                        Member::EffectState => ValueRefMut::TempEffectState(
                             MonEffectStateConnector::new(**mon_handle).make_dynamic(),
                         ),
-                        "last_item" => ValueRefMut::OptionalId(
+                        Member::LastItem => ValueRefMut::OptionalId(
                             &mut context.mon_mut(**mon_handle)?.volatile_state.last_item,
                         ),
-                        "last_target_location" => ValueRefMut::OptionalISize(
+                        Member::LastTargetLocation => ValueRefMut::OptionalISize(
                             &mut context
                                 .mon_mut(**mon_handle)?
                                 .volatile_state
                                 .last_move_target_location,
                         ),
-                        "skip_before_switch_out" => ValueRefMut::Boolean(
+                        Member::SkipBeforeSwitchOut => ValueRefMut::Boolean(
                             &mut context
                                 .mon_mut(**mon_handle)?
                                 .switch_state
                                 .skip_before_switch_out,
                         ),
-                        "stats" => ValueRefMut::StatTable(
+                        Member::Stats => ValueRefMut::StatTable(
                             &mut context.mon_mut(**mon_handle)?.volatile_state.stats,
                         ),
-                        "status_state" => ValueRefMut::TempEffectState(
+                        Member::StatusState => ValueRefMut::TempEffectState(
                             MonStatusEffectStateConnector::new(**mon_handle).make_dynamic(),
                         ),
-                        "volatile_effect_state" => ValueRefMut::TempEffectState(
+                        Member::VolatileEffectState => ValueRefMut::TempEffectState(
                             MonVolatileEffectStateConnector::new(**mon_handle).make_dynamic(),
                         ),
-                        _ => return Err(Self::bad_member_or_mutable_access(member, value_type)),
+                        _ => {
+                            return Err(Self::bad_member_or_mutable_access(
+                                member.name(),
+                                value_type,
+                            ));
+                        }
                     }
                 }
                 ValueRefMut::Effect(EffectHandle::ActiveMove(active_move_handle, _)) => {
                     let context = unsafe { context.unsafely_detach_borrow_mut() };
-                    value = match *member {
-                        "accuracy" => ValueRefMut::Accuracy(
+                    value = match member {
+                        Member::Accuracy => ValueRefMut::Accuracy(
                             &mut context.active_move_mut(*active_move_handle)?.data.accuracy,
                         ),
-                        "base_power" => ValueRefMut::U32(
+                        Member::BasePower => ValueRefMut::U32(
                             &mut context
                                 .active_move_mut(*active_move_handle)?
                                 .data
                                 .base_power,
                         ),
-                        "category" => ValueRefMut::MoveCategory(
+                        Member::Category => ValueRefMut::MoveCategory(
                             &mut context.active_move_mut(*active_move_handle)?.data.category,
                         ),
-                        "damage" => ValueRefMut::OptionalU16(
+                        Member::Damage => ValueRefMut::OptionalU16(
                             &mut context.active_move_mut(*active_move_handle)?.data.damage,
                         ),
-                        "effect_state" => ValueRefMut::TempEffectState(
+                        Member::EffectState => ValueRefMut::TempEffectState(
                             ActiveMoveEffectStateConnector::new(*active_move_handle).make_dynamic(),
                         ),
-                        "force_stab" => ValueRefMut::Boolean(
+                        Member::ForceStab => ValueRefMut::Boolean(
                             &mut context
                                 .active_move_mut(*active_move_handle)?
                                 .data
                                 .force_stab,
                         ),
-                        "force_try_hit_result" => ValueRefMut::OptionalEventResult(
+                        Member::ForceTryHitResult => ValueRefMut::OptionalEventResult(
                             &mut context
                                 .active_move_mut(*active_move_handle)?
                                 .force_try_hit_result,
                         ),
-                        "hit_effect" => ValueRefMut::OptionalHitEffect(
+                        Member::HitEffect => ValueRefMut::OptionalHitEffect(
                             &mut context
                                 .active_move_mut(*active_move_handle)?
                                 .data
                                 .hit_effect,
                         ),
-                        "ignore_all_secondary_effects" => ValueRefMut::Boolean(
+                        Member::IgnoreAllSecondaryEffects => ValueRefMut::Boolean(
                             &mut context
                                 .active_move_mut(*active_move_handle)?
                                 .ignore_all_secondary_effects,
                         ),
-                        "ignore_evasion" => ValueRefMut::Boolean(
+                        Member::IgnoreEvasion => ValueRefMut::Boolean(
                             &mut context
                                 .active_move_mut(*active_move_handle)?
                                 .data
                                 .ignore_evasion,
                         ),
-                        "multiaccuracy" => ValueRefMut::Boolean(
+                        Member::Multiaccuracy => ValueRefMut::Boolean(
                             &mut context
                                 .active_move_mut(*active_move_handle)?
                                 .data
                                 .multiaccuracy,
                         ),
-                        "multihit" => ValueRefMut::OptionalMultihitType(
+                        Member::Multihit => ValueRefMut::OptionalMultihitType(
                             &mut context.active_move_mut(*active_move_handle)?.data.multihit,
                         ),
-                        "priority" => ValueRefMut::I8(
+                        Member::Priority => ValueRefMut::I8(
                             &mut context.active_move_mut(*active_move_handle)?.priority,
                         ),
-                        "secondary_effects" => ValueRefMut::SecondaryHitEffectList(
+                        Member::SecondaryEffects => ValueRefMut::SecondaryHitEffectList(
                             &mut context
                                 .active_move_mut(*active_move_handle)?
                                 .data
                                 .secondary_effects,
                         ),
-                        "target" => ValueRefMut::MoveTarget(
+                        Member::Target => ValueRefMut::MoveTarget(
                             &mut context.active_move_mut(*active_move_handle)?.data.target,
                         ),
-                        "total_damage" => ValueRefMut::U64(
+                        Member::TotalDamage => ValueRefMut::U64(
                             &mut context.active_move_mut(*active_move_handle)?.total_damage,
                         ),
-                        "tracks_target" => ValueRefMut::Boolean(
+                        Member::TracksTarget => ValueRefMut::Boolean(
                             &mut context
                                 .active_move_mut(*active_move_handle)?
                                 .data
                                 .advanced_targeting
                                 .tracks_target,
                         ),
-                        "type" => ValueRefMut::Type(
+                        Member::Type => ValueRefMut::Type(
                             &mut context
                                 .active_move_mut(*active_move_handle)?
                                 .data
                                 .primary_type,
                         ),
-                        "user_effect" => ValueRefMut::OptionalHitEffect(
+                        Member::UserEffect => ValueRefMut::OptionalHitEffect(
                             &mut context
                                 .active_move_mut(*active_move_handle)?
                                 .data
                                 .user_effect,
                         ),
-                        "user_effect_chance" => ValueRefMut::OptionalFractionU16(
+                        Member::UserEffectChance => ValueRefMut::OptionalFractionU16(
                             &mut context
                                 .active_move_mut(*active_move_handle)?
                                 .data
                                 .user_effect_chance,
                         ),
-                        "override_offensive_stat" => ValueRefMut::OptionalStat(
+                        Member::OverrideOffensiveStat => ValueRefMut::OptionalStat(
                             &mut context
                                 .active_move_mut(*active_move_handle)?
                                 .data
                                 .override_offensive_stat,
                         ),
-                        "override_defensive_stat" => ValueRefMut::OptionalStat(
+                        Member::OverrideDefensiveStat => ValueRefMut::OptionalStat(
                             &mut context
                                 .active_move_mut(*active_move_handle)?
                                 .data
                                 .override_defensive_stat,
                         ),
-                        _ => return Err(Self::bad_member_or_mutable_access(member, value_type)),
+                        _ => {
+                            return Err(Self::bad_member_or_mutable_access(
+                                member.name(),
+                                value_type,
+                            ));
+                        }
                     }
                 }
                 ValueRefMut::HitEffect(hit_effect)
                 | ValueRefMut::OptionalHitEffect(Some(hit_effect)) => {
-                    value = match *member {
-                        "boosts" => ValueRefMut::OptionalBoostTable(&mut hit_effect.boosts),
-                        "heal_percent" => {
+                    value = match member {
+                        Member::Boosts => ValueRefMut::OptionalBoostTable(&mut hit_effect.boosts),
+                        Member::HealPercent => {
                             ValueRefMut::OptionalFractionU16(&mut hit_effect.heal_percent)
                         }
-                        "side_condition" => {
+                        Member::SideCondition => {
                             ValueRefMut::OptionalString(&mut hit_effect.side_condition)
                         }
-                        "status" => ValueRefMut::OptionalString(&mut hit_effect.status),
-                        "volatile_status" => {
+                        Member::Status => ValueRefMut::OptionalString(&mut hit_effect.status),
+                        Member::VolatileStatus => {
                             ValueRefMut::OptionalString(&mut hit_effect.volatile_status)
                         }
-                        _ => return Err(Self::bad_member_or_mutable_access(member, value_type)),
+                        _ => {
+                            return Err(Self::bad_member_or_mutable_access(
+                                member.name(),
+                                value_type,
+                            ));
+                        }
                     }
                 }
                 ValueRefMut::SecondaryHitEffect(secondary_effect) => {
-                    value = match *member {
-                        "chance" => ValueRefMut::OptionalFractionU16(&mut secondary_effect.chance),
-                        "source_effect" => {
+                    value = match member {
+                        Member::Chance => {
+                            ValueRefMut::OptionalFractionU16(&mut secondary_effect.chance)
+                        }
+                        Member::SourceEffect => {
                             ValueRefMut::OptionalString(&mut secondary_effect.source_effect)
                         }
-                        "target" => ValueRefMut::OptionalHitEffect(&mut secondary_effect.target),
-                        "user" => ValueRefMut::OptionalHitEffect(&mut secondary_effect.user),
-                        _ => return Err(Self::bad_member_or_mutable_access(member, value_type)),
+                        Member::Target => {
+                            ValueRefMut::OptionalHitEffect(&mut secondary_effect.target)
+                        }
+                        Member::User => ValueRefMut::OptionalHitEffect(&mut secondary_effect.user),
+                        _ => {
+                            return Err(Self::bad_member_or_mutable_access(
+                                member.name(),
+                                value_type,
+                            ));
+                        }
                     }
                 }
                 ValueRefMut::BoostTable(boosts) => {
-                    value = match *member {
-                        "acc" => ValueRefMut::I8(&mut boosts.acc),
-                        "atk" => ValueRefMut::I8(&mut boosts.atk),
-                        "def" => ValueRefMut::I8(&mut boosts.def),
-                        "eva" => ValueRefMut::I8(&mut boosts.eva),
-                        "spa" => ValueRefMut::I8(&mut boosts.spa),
-                        "spd" => ValueRefMut::I8(&mut boosts.spd),
-                        "spe" => ValueRefMut::I8(&mut boosts.spe),
-                        _ => return Err(Self::bad_member_or_mutable_access(member, value_type)),
+                    value = match member {
+                        Member::Acc => ValueRefMut::I8(&mut boosts.acc),
+                        Member::Atk => ValueRefMut::I8(&mut boosts.atk),
+                        Member::Def => ValueRefMut::I8(&mut boosts.def),
+                        Member::Eva => ValueRefMut::I8(&mut boosts.eva),
+                        Member::Spa => ValueRefMut::I8(&mut boosts.spa),
+                        Member::Spd => ValueRefMut::I8(&mut boosts.spd),
+                        Member::Spe => ValueRefMut::I8(&mut boosts.spe),
+                        _ => {
+                            return Err(Self::bad_member_or_mutable_access(
+                                member.name(),
+                                value_type,
+                            ));
+                        }
                     }
                 }
                 ValueRefMut::StatTable(stats) => {
-                    value = match *member {
-                        "atk" => ValueRefMut::U16(&mut stats.atk),
-                        "def" => ValueRefMut::U16(&mut stats.def),
-                        "hp" => ValueRefMut::U16(&mut stats.hp),
-                        "spa" => ValueRefMut::U16(&mut stats.spa),
-                        "spd" => ValueRefMut::U16(&mut stats.spd),
-                        "spe" => ValueRefMut::U16(&mut stats.spe),
-                        _ => return Err(Self::bad_member_or_mutable_access(member, value_type)),
+                    value = match member {
+                        Member::Atk => ValueRefMut::U16(&mut stats.atk),
+                        Member::Def => ValueRefMut::U16(&mut stats.def),
+                        Member::Hp => ValueRefMut::U16(&mut stats.hp),
+                        Member::Spa => ValueRefMut::U16(&mut stats.spa),
+                        Member::Spd => ValueRefMut::U16(&mut stats.spd),
+                        Member::Spe => ValueRefMut::U16(&mut stats.spe),
+                        _ => {
+                            return Err(Self::bad_member_or_mutable_access(
+                                member.name(),
+                                value_type,
+                            ));
+                        }
                     }
                 }
                 ValueRefMut::EffectState(connector) => {
@@ -1600,7 +1683,7 @@ where
                     value = ValueRefMut::from(
                         connector
                             .get_mut(context.battle_context_mut())?
-                            .get_mut(*member),
+                            .get_mut(member.name()),
                     );
                 }
                 ValueRefMut::TempEffectState(connector) => {
@@ -1608,7 +1691,7 @@ where
                     value = ValueRefMut::from(
                         connector
                             .get_mut(context.battle_context_mut())?
-                            .get_mut(*member),
+                            .get_mut(member.name()),
                     );
                 }
                 ValueRefMut::Object(ref mut object) => {
@@ -1621,11 +1704,16 @@ where
                     // borrow and consuming it with an assignment.
                     let object = unsafe { object.unsafely_detach_borrow_mut() };
                     let entry = object
-                        .entry((*member).to_owned())
+                        .entry(member.name().to_owned())
                         .or_insert(Value::Undefined);
                     value = ValueRefMut::from(entry);
                 }
-                _ => return Err(Self::bad_member_or_mutable_access(member, value_type)),
+                _ => {
+                    return Err(Self::bad_member_or_mutable_access(
+                        member.name(),
+                        value_type,
+                    ));
+                }
             }
         }
         Ok(value)
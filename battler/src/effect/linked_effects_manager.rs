@@ -6,6 +6,10 @@ use alloc::{
 use anyhow::Result;
 use battler_data::Id;
 use hashbrown::HashMap;
+use serde::{
+    Deserialize,
+    Serialize,
+};
 
 use crate::{
     battle::{
@@ -23,6 +27,7 @@ use crate::{
 /// Object for managing applied effects in battle and how they link to one another.
 ///
 /// A linked effect ends when another effect ends. Linking always goes both ways.
+#[derive(Clone, Serialize, Deserialize)]
 pub struct LinkedEffectsManager {
     effects: HashMap<u32, AppliedEffectHandle>,
 }
@@ -15,9 +15,13 @@ pub use effect::{
     EffectType,
     NonExistentEffect,
 };
-pub use effect_manager::EffectManager;
+pub use effect_manager::{
+    EffectManager,
+    SubroutineCall,
+};
 pub use effect_state_connectors::{
     ActiveMoveEffectStateConnector,
+    EffectStateConnectorData,
     MonAbilityEffectStateConnector,
     MonEffectStateConnector,
     MonItemEffectStateConnector,
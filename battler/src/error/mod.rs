@@ -2,6 +2,7 @@ mod battler_error;
 mod context;
 mod conversions;
 mod error;
+mod replay_divergence_error;
 mod validation_error;
 
 pub use battler_error::{
@@ -20,4 +21,5 @@ pub use error::{
     WrapOptionError,
     WrapResultError,
 };
+pub use replay_divergence_error::ReplayDivergenceError;
 pub use validation_error::ValidationError;
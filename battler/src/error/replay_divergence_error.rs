@@ -0,0 +1,16 @@
+use alloc::string::String;
+
+use thiserror::Error;
+
+/// An error resulting from a replayed battle producing a different battle log than the one that
+/// was recorded.
+#[derive(Debug, Error)]
+#[error("replay diverged at log line {line}: expected {expected:?}, found {actual:?}")]
+pub struct ReplayDivergenceError {
+    /// Index of the first log line that differs.
+    pub line: usize,
+    /// The recorded log line, if any.
+    pub expected: Option<String>,
+    /// The replayed log line, if any.
+    pub actual: Option<String>,
+}
@@ -27,11 +27,20 @@ pub mod teams;
 // In theory, everything is public, but everything re-exported at the root level is intended
 // for users.
 pub use battle::{
+    BattleReplay,
     BattleType,
+    CallbackExclusionReason,
+    CallbackExplanation,
+    CallbackOrdering,
     CoreBattleEngineOptions,
     CoreBattleEngineRandomizeBaseDamage,
     CoreBattleEngineSpeedSortTieResolution,
     CoreBattleOptions,
+    CoreBattleSnapshot,
+    EventExplanation,
+    EventSource,
+    EventSourceMon,
+    EventTarget,
     ExperienceOptions,
     ExperienceShareOptions,
     FieldData,
@@ -52,6 +61,9 @@ pub use battle::{
     PlayerOptions,
     PlayerType,
     PublicCoreBattle,
+    RaidBossOptions,
+    RaidThreshold,
+    ReplayChoice,
     Request,
     RequestType,
     SelectPosition,
@@ -66,28 +78,63 @@ pub use battle::{
     WildPlayerOptions,
 };
 pub use battler_data::*;
-pub use config::FormatData;
+pub use config::{
+    FormatData,
+    Generation,
+};
+#[cfg(feature = "std")]
+pub use dex::SharedDexCache;
 pub use dex::{
     AbilityDex,
     ClauseDex,
     ConditionDex,
     Dex,
+    DexCache,
+    DexQuery,
     ItemDex,
     MoveDex,
     ResourceDex,
+    SharedCache,
     SingleValueDex,
     SpeciesDex,
 };
 pub use effect::fxlang::{
+    BattleEvent,
+    BattleEventModifier,
     Callback,
     CallbackInput,
     Callbacks,
+    CheckError,
     ConditionAttributes,
+    Coverage,
+    CoverageReport,
     Effect,
     EffectAttributes,
+    EvaluationLimits,
+    ExternalFunctions,
+    FunctionContext,
+    FunctionSignature,
+    InputVar,
     LocalData,
+    NativeFunction,
+    NativeFunctionRegistry,
     Program,
     ProgramWithPriority,
+    StatementToken,
+    StatementTokenKind,
+    TraceRecord,
+    Tracer,
+    UncoveredProgram,
+    check_effect,
+    check_effect_data,
+    check_subroutine_data,
+    input_vars,
+    tokenize_statement,
+};
+#[cfg(feature = "std")]
+pub use effect::fxlang::{
+    CoverageCollector,
+    WriterTracer,
 };
 pub use error::{
     BorrowFailedError,
@@ -95,6 +142,7 @@ pub use error::{
     GeneralError,
     IntegerOverflowError,
     NotFoundError,
+    ReplayDivergenceError,
     ValidationError,
     WrapError,
     WrapOptionError,
@@ -128,6 +176,8 @@ mod typescript_tests {
         PlayerType::export().unwrap();
         WildPlayerOptions::export().unwrap();
         WildEncounterType::export().unwrap();
+        RaidBossOptions::export().unwrap();
+        RaidThreshold::export().unwrap();
         PlayerOptions::export().unwrap();
         ExperienceOptions::export().unwrap();
         ExperienceShareOptions::export().unwrap();
@@ -13,6 +13,12 @@ use hashbrown::{
     hash_map::Entry,
 };
 use itertools::Itertools;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+use crate::common::ChunkedVec;
 
 /// Trait for objects that can be added directly to the battle log.
 pub trait BattleLoggable {
@@ -40,7 +46,7 @@ where
 ///
 /// This object should not be constructed directly. Instead, use the
 /// [`battle_log_entry`][`crate::battle_log_entry`] macro.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UncommittedBattleLogEntry {
     title: String,
     values: HashMap<String, String>,
@@ -211,9 +217,12 @@ impl Display for BattleLogEntryMut<'_> {
 ///
 /// The log also keeps track of reads. Once a log is read out using [`Self::read_out`], it will not
 /// be read out again.
+///
+/// Committed logs are shared between clones, so cloning the log does not copy them.
+#[derive(Clone, Serialize, Deserialize)]
 pub struct BattleLog {
     last_read: usize,
-    committed_logs: Vec<String>,
+    committed_logs: ChunkedVec<String>,
     new_log_entries: Vec<UncommittedBattleLogEntry>,
 }
 
@@ -222,7 +231,7 @@ impl BattleLog {
     pub fn new() -> Self {
         Self {
             last_read: 0,
-            committed_logs: Vec::new(),
+            committed_logs: ChunkedVec::new(),
             new_log_entries: Vec::new(),
         }
     }
@@ -262,7 +271,12 @@ impl BattleLog {
     pub fn read_out(&mut self) -> impl Iterator<Item = &str> {
         let i = self.last_read;
         self.last_read = self.committed_logs.len();
-        self.committed_logs[i..].iter().map(|s| s.as_ref())
+        self.committed_logs.iter_from(i).map(|s| s.as_ref())
+    }
+
+    /// Marks all committed logs as read, so that they are not returned by [`Self::read_out`].
+    pub fn mark_read(&mut self) {
+        self.last_read = self.committed_logs.len();
     }
 
     /// Returns the total number of log entries.
@@ -23,6 +23,10 @@ use hashbrown::{
     HashSet,
     hash_map::Entry,
 };
+use serde::{
+    Deserialize,
+    Serialize,
+};
 
 use crate::{
     battle::{
@@ -34,7 +38,7 @@ use crate::{
 };
 
 /// Dynamic data on how a move hit a target.
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct MoveHitData {
     /// Did the move critical hit?
     pub crit: bool,
@@ -58,7 +62,7 @@ impl MoveHitData {
 }
 
 /// The current type of [`HitEffect`] being applied on an active [`Move`].
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum MoveHitEffectType {
     PrimaryEffect,
     SecondaryEffect(MonHandle, u8, usize),
@@ -75,7 +79,7 @@ impl MoveHitEffectType {
 }
 
 /// Secondary effect that occurs after a move is used.
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct SecondaryEffect {
     pub data: SecondaryEffectData,
     pub effect: fxlang::Effect,
@@ -89,7 +93,7 @@ impl SecondaryEffect {
 }
 
 /// The source of an upgraded move.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum UpgradedMoveSource {
     ZMove { base_move: Id },
     MaxMove { base_move: Id },
@@ -198,7 +202,7 @@ fn default_max_move(
 ///
 /// Unlike other move effects, [`Move`]s are mutable across multiple Mons and turns. A move used by
 /// one Mon can have different effects than the ame move used by another Mon.
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct Move {
     id: Id,
     pub data: MoveData,
@@ -250,8 +254,10 @@ pub struct Move {
     /// Secondary effects for each target.
     ///
     /// Secondary effects can be modified by effects on the user and the individual target.
+    #[serde(with = "crate::common::map_entries")]
     pub secondary_effects: HashMap<(MonHandle, u8), Vec<SecondaryEffect>>,
 
+    #[serde(with = "crate::common::map_entries")]
     hit_data: HashMap<(MonHandle, u8), MoveHitData>,
 }
 
@@ -5,6 +5,7 @@ use alloc::{
         String,
         ToString,
     },
+    sync::Arc,
     vec::Vec,
 };
 
@@ -23,7 +24,6 @@ use hashbrown::{
 use itertools::Itertools;
 use once_cell::race::OnceBox;
 use regex::Regex;
-use zone_alloc::ElementRef;
 
 use crate::{
     abilities::Ability,
@@ -46,22 +46,22 @@ use crate::{
 /// The maximum length of a Mon name.
 const MAX_NAME_LENGTH: usize = 30;
 
-struct PossibleEvent<'d> {
-    species: ElementRef<'d, Species>,
+struct PossibleEvent {
+    species: Arc<Species>,
 }
 
 /// The state of Mon validation algorithms.
 ///
 /// Some state must be persisted across validating a single Mon. All such state should be stored
 /// here.
-struct MonValidationState<'s> {
+struct MonValidationState {
     /// Was this Mon obtained from a giveaway event?
     from_event: bool,
     /// Possible events the Mon may have been obtained from.
-    possible_events: HashMap<String, PossibleEvent<'s>>,
+    possible_events: HashMap<String, PossibleEvent>,
 }
 
-impl<'s> MonValidationState<'s> {
+impl MonValidationState {
     fn new() -> Self {
         Self {
             from_event: false,
@@ -69,7 +69,7 @@ impl<'s> MonValidationState<'s> {
         }
     }
 
-    fn add_possible_events(&mut self, events: HashMap<String, PossibleEvent<'s>>) {
+    fn add_possible_events(&mut self, events: HashMap<String, PossibleEvent>) {
         // If this Mon is not yet known to be from an event, then the first set of events is the
         // initial set. Otherwise, take the intersection to receive the new set of possible events
         // this Mon could have been from.
@@ -322,6 +322,8 @@ impl<'b, 'd> TeamValidator<'b, 'd> {
             }
         };
 
+        problems.append(&mut self.validate_allowed_species(&species, &ability));
+
         {
             let mut state = MonValidationState::new();
             if let Some(item) = &item {
@@ -352,7 +354,7 @@ impl<'b, 'd> TeamValidator<'b, 'd> {
         check
     }
 
-    fn validate_species(&self, species: &ElementRef<'d, Species>) -> Vec<String> {
+    fn validate_species(&self, species: &Arc<Species>) -> Vec<String> {
         let mut problems = Vec::new();
 
         let flags = species
@@ -378,12 +380,37 @@ impl<'b, 'd> TeamValidator<'b, 'd> {
         problems
     }
 
+    fn validate_allowed_species(
+        &self,
+        species: &Arc<Species>,
+        ability: &Arc<Ability>,
+    ) -> Vec<String> {
+        let mut problems = Vec::new();
+
+        if self
+            .format
+            .rules
+            .predicate_rules
+            .allowed_species
+            .as_ref()
+            .is_some_and(|allowed_species| !allowed_species.matches(&species.data, ability.id()))
+        {
+            problems.push(format!(
+                "{} with {} is not allowed.",
+                species.data.display_name(),
+                ability.data.name
+            ));
+        }
+
+        problems
+    }
+
     fn validate_forme(
         &self,
         mon: &'b mut MonData,
-        species: &ElementRef<'d, Species>,
-        _: &ElementRef<'d, Ability>,
-        item: Option<&ElementRef<'d, Item>>,
+        species: &Arc<Species>,
+        _: &Arc<Ability>,
+        item: Option<&Arc<Item>>,
     ) -> Vec<String> {
         let mut problems = Vec::new();
 
@@ -420,7 +447,7 @@ impl<'b, 'd> TeamValidator<'b, 'd> {
         problems
     }
 
-    fn validate_item(&self, item: &ElementRef<'d, Item>) -> Vec<String> {
+    fn validate_item(&self, item: &Arc<Item>) -> Vec<String> {
         let mut problems = Vec::new();
 
         // Check if item is allowed.
@@ -450,15 +477,12 @@ impl<'b, 'd> TeamValidator<'b, 'd> {
         problems
     }
 
-    fn validate_moveset<'state>(
+    fn validate_moveset(
         &self,
         mon: &'b MonData,
-        species: &ElementRef<'d, Species>,
-        state: &mut MonValidationState<'state>,
-    ) -> Vec<String>
-    where
-        'b: 'state,
-    {
+        species: &Arc<Species>,
+        state: &mut MonValidationState,
+    ) -> Vec<String> {
         let mut problems = Vec::new();
 
         let max_move_count = self.format.rules.numeric_rules.max_move_count as usize;
@@ -497,17 +521,14 @@ impl<'b, 'd> TeamValidator<'b, 'd> {
         problems
     }
 
-    fn validate_move<'mov, 'state>(
+    fn validate_move(
         &self,
         mon: &'b MonData,
-        species: &ElementRef<'d, Species>,
-        mov: &ElementRef<'mov, Move>,
+        species: &Arc<Species>,
+        mov: &Arc<Move>,
         pp_boosts: u8,
-        state: &mut MonValidationState<'state>,
-    ) -> Vec<String>
-    where
-        'b: 'state,
-    {
+        state: &mut MonValidationState,
+    ) -> Vec<String> {
         let mut problems = Vec::new();
 
         // Check if move is allowed.
@@ -568,18 +589,15 @@ impl<'b, 'd> TeamValidator<'b, 'd> {
         problems
     }
 
-    fn validate_can_learn<'mov, 'state>(
+    fn validate_can_learn(
         &self,
         mon: &'b MonData,
-        species: &ElementRef<'d, Species>,
-        mov: &ElementRef<'mov, Move>,
-        state: &mut MonValidationState<'state>,
-    ) -> MoveLegality
-    where
-        'b: 'state,
-    {
+        species: &Arc<Species>,
+        mov: &Arc<Move>,
+        state: &mut MonValidationState,
+    ) -> MoveLegality {
         let mut seen = HashSet::new();
-        let mut current_species: Result<ElementRef<'_, Species>> = Ok(species.clone());
+        let mut current_species: Result<Arc<Species>> = Ok(species.clone());
         let mut possible_events = HashMap::default();
 
         loop {
@@ -730,12 +748,12 @@ impl<'b, 'd> TeamValidator<'b, 'd> {
         return MoveLegality::Illegal(format!("is unobtainable on {}.", species.data.name));
     }
 
-    fn validate_ability<'state>(
+    fn validate_ability(
         &self,
         mon: &'b MonData,
-        species: &ElementRef<'d, Species>,
-        ability: &ElementRef<'d, Ability>,
-        _: &mut MonValidationState<'state>,
+        species: &Arc<Species>,
+        ability: &Arc<Ability>,
+        _: &mut MonValidationState,
     ) -> Vec<String> {
         let mut problems = Vec::new();
 
@@ -784,11 +802,7 @@ impl<'b, 'd> TeamValidator<'b, 'd> {
         problems
     }
 
-    fn validate_event<'state>(
-        &self,
-        mon: &'b MonData,
-        state: &mut MonValidationState<'state>,
-    ) -> Vec<String> {
+    fn validate_event(&self, mon: &'b MonData, state: &mut MonValidationState) -> Vec<String> {
         let mut problems = Vec::new();
 
         // Nothing to check.
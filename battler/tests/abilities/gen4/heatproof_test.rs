@@ -0,0 +1,100 @@
+use anyhow::Result;
+use battler::{
+    BattleType,
+    CoreBattleEngineSpeedSortTieResolution,
+    PublicCoreBattle,
+    TeamData,
+    WrapResultError,
+};
+use battler_test_utils::{
+    LogMatch,
+    TestBattleBuilder,
+    assert_logs_since_turn_eq,
+    static_local_data_store,
+};
+
+fn bronzong() -> Result<TeamData> {
+    serde_json::from_str(
+        r#"{
+            "members": [
+                {
+                    "name": "Bronzong",
+                    "species": "Bronzong",
+                    "ability": "Heatproof",
+                    "moves": [],
+                    "nature": "Hardy",
+                    "level": 50
+                }
+            ]
+        }"#,
+    )
+    .wrap_error()
+}
+
+fn charizard() -> Result<TeamData> {
+    serde_json::from_str(
+        r#"{
+            "members": [
+                {
+                    "name": "Charizard",
+                    "species": "Charizard",
+                    "ability": "No Ability",
+                    "moves": [
+                        "Flamethrower",
+                        "Fire Punch"
+                    ],
+                    "nature": "Hardy",
+                    "level": 50
+                }
+            ]
+        }"#,
+    )
+    .wrap_error()
+}
+
+fn make_battle(seed: u64, team_1: TeamData, team_2: TeamData) -> Result<PublicCoreBattle<'static>> {
+    TestBattleBuilder::new()
+        .with_battle_type(BattleType::Singles)
+        .with_seed(seed)
+        .with_team_validation(false)
+        .with_pass_allowed(true)
+        .with_speed_sort_tie_resolution(CoreBattleEngineSpeedSortTieResolution::Keep)
+        .add_player_to_side_1("player-1", "Player 1")
+        .add_player_to_side_2("player-2", "Player 2")
+        .with_team("player-1", team_1)
+        .with_team("player-2", team_2)
+        .build(static_local_data_store())
+}
+
+#[test]
+fn heatproof_halves_damage_from_fire_moves() {
+    let mut battle = make_battle(0, bronzong().unwrap(), charizard().unwrap()).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "pass"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "move 0"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "pass"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "move 1"), Ok(()));
+
+    let expected_logs = serde_json::from_str::<Vec<LogMatch>>(
+        r#"[
+            "move|mon:Charizard,player-2,1|name:Flamethrower|target:Bronzong,player-1,1",
+            "supereffective|mon:Bronzong,player-1,1",
+            "split|side:0",
+            "damage|mon:Bronzong,player-1,1|health:71/127",
+            "damage|mon:Bronzong,player-1,1|health:56/100",
+            "residual",
+            "turn|turn:2",
+            "continue",
+            "move|mon:Charizard,player-2,1|name:Fire Punch|target:Bronzong,player-1,1",
+            "supereffective|mon:Bronzong,player-1,1",
+            "split|side:0",
+            "damage|mon:Bronzong,player-1,1|health:35/127",
+            "damage|mon:Bronzong,player-1,1|health:28/100",
+            "residual",
+            "turn|turn:3"
+        ]"#,
+    )
+    .unwrap();
+    assert_logs_since_turn_eq(&battle, 1, &expected_logs);
+}
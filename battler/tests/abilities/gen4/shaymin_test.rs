@@ -5,11 +5,13 @@ use battler::{
     FieldEnvironment,
     PublicCoreBattle,
     TeamData,
+    TimeOfDay,
     WrapResultError,
 };
 use battler_test_utils::{
     LogMatch,
     TestBattleBuilder,
+    assert_logs_since_start_eq,
     assert_logs_since_turn_eq,
     get_controlled_rng_for_battle,
     static_local_data_store,
@@ -35,7 +37,12 @@ fn shaymin() -> Result<TeamData> {
     .wrap_error()
 }
 
-fn make_battle(seed: u64, team_1: TeamData, team_2: TeamData) -> Result<PublicCoreBattle<'static>> {
+fn make_battle(
+    seed: u64,
+    team_1: TeamData,
+    team_2: TeamData,
+    time: TimeOfDay,
+) -> Result<PublicCoreBattle<'static>> {
     TestBattleBuilder::new()
         .with_battle_type(BattleType::Singles)
         .with_seed(seed)
@@ -43,6 +50,7 @@ fn make_battle(seed: u64, team_1: TeamData, team_2: TeamData) -> Result<PublicCo
         .with_pass_allowed(true)
         .with_controlled_rng(true)
         .with_field_environment(FieldEnvironment::Ice)
+        .with_time_of_day(time)
         .with_speed_sort_tie_resolution(CoreBattleEngineSpeedSortTieResolution::Keep)
         .add_player_to_side_1("player-1", "Player 1")
         .add_player_to_side_2("player-2", "Player 2")
@@ -53,7 +61,8 @@ fn make_battle(seed: u64, team_1: TeamData, team_2: TeamData) -> Result<PublicCo
 
 #[test]
 fn shaymin_sky_reverts_when_frozen() {
-    let mut battle = make_battle(0, shaymin().unwrap(), shaymin().unwrap()).unwrap();
+    let mut battle =
+        make_battle(0, shaymin().unwrap(), shaymin().unwrap(), TimeOfDay::Day).unwrap();
     assert_matches::assert_matches!(battle.start(), Ok(()));
 
     let rng = get_controlled_rng_for_battle(&mut battle).unwrap();
@@ -80,3 +89,32 @@ fn shaymin_sky_reverts_when_frozen() {
     .unwrap();
     assert_logs_since_turn_eq(&battle, 1, &expected_logs);
 }
+
+#[test]
+fn shaymin_sky_reverts_at_night() {
+    let mut battle =
+        make_battle(0, shaymin().unwrap(), shaymin().unwrap(), TimeOfDay::Night).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    let expected_logs = serde_json::from_str::<Vec<LogMatch>>(
+        r#"[
+            "split|side:0",
+            "switch|player:player-1|position:1|name:Shaymin|health:160/160|species:Shaymin-Sky|level:50|gender:U",
+            "switch|player:player-1|position:1|name:Shaymin|health:100/100|species:Shaymin-Sky|level:50|gender:U",
+            "split|side:1",
+            "switch|player:player-2|position:1|name:Shaymin|health:160/160|species:Shaymin-Sky|level:50|gender:U",
+            "switch|player:player-2|position:1|name:Shaymin|health:100/100|species:Shaymin-Sky|level:50|gender:U",
+            "split|side:0",
+            "specieschange|player:player-1|position:1|name:Shaymin|health:160/160|species:Shaymin|level:50|gender:U",
+            "specieschange|player:player-1|position:1|name:Shaymin|health:100/100|species:Shaymin|level:50|gender:U",
+            "formechange|mon:Shaymin,player-1,1|species:Shaymin|from:species:Shaymin-Sky",
+            "split|side:1",
+            "specieschange|player:player-2|position:1|name:Shaymin|health:160/160|species:Shaymin|level:50|gender:U",
+            "specieschange|player:player-2|position:1|name:Shaymin|health:100/100|species:Shaymin|level:50|gender:U",
+            "formechange|mon:Shaymin,player-2,1|species:Shaymin|from:species:Shaymin-Sky",
+            "turn|turn:1"
+        ]"#,
+    )
+    .unwrap();
+    assert_logs_since_start_eq(&battle, &expected_logs);
+}
@@ -0,0 +1,195 @@
+use anyhow::Result;
+use battler::{
+    BattleType,
+    CoreBattleEngineRandomizeBaseDamage,
+    CoreBattleEngineSpeedSortTieResolution,
+    PublicCoreBattle,
+    TeamData,
+    WrapResultError,
+};
+use battler_test_utils::{
+    LogMatch,
+    TestBattleBuilder,
+    assert_logs_since_turn_eq,
+    static_local_data_store,
+};
+
+fn team_1() -> Result<TeamData> {
+    serde_json::from_str(
+        r#"{
+            "members": [
+                {
+                    "name": "Charmander",
+                    "species": "Charmander",
+                    "ability": "No Ability",
+                    "moves": [
+                        "Tackle",
+                        "Psychic"
+                    ],
+                    "nature": "Hardy",
+                    "level": 50
+                }
+            ]
+        }"#,
+    )
+    .wrap_error()
+}
+
+fn team_2() -> Result<TeamData> {
+    serde_json::from_str(
+        r#"{
+            "members": [
+                {
+                    "name": "Gastly",
+                    "species": "Gastly",
+                    "ability": "No Ability",
+                    "moves": [
+                        "Lick"
+                    ],
+                    "nature": "Hardy",
+                    "level": 50
+                }
+            ]
+        }"#,
+    )
+    .wrap_error()
+}
+
+fn make_battle(seed: u64, rules: &[&str]) -> Result<PublicCoreBattle<'static>> {
+    let mut builder = TestBattleBuilder::new()
+        .with_battle_type(BattleType::Singles)
+        .with_seed(seed)
+        .with_team_validation(false)
+        .with_pass_allowed(true)
+        .with_speed_sort_tie_resolution(CoreBattleEngineSpeedSortTieResolution::Keep)
+        .with_base_damage_randomization(CoreBattleEngineRandomizeBaseDamage::Max)
+        .add_player_to_side_1("player-1", "Player 1")
+        .add_player_to_side_2("player-2", "Player 2")
+        .with_team("player-1", team_1()?)
+        .with_team("player-2", team_2()?);
+    for rule in rules {
+        builder = builder.with_rule(rule);
+    }
+    builder.build(static_local_data_store())
+}
+
+#[test]
+fn inverse_battle_inverts_type_effectiveness() {
+    let mut battle = make_battle(0, &["Inverse Battle"]).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 0"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "pass"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 1"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "pass"), Ok(()));
+
+    assert!(
+        battle
+            .full_log()
+            .any(|log| log == "info|rule:Inverse Battle: Type effectiveness is inverted")
+    );
+    assert!(battle.full_log().any(|log| log == "inversetypechart"));
+
+    let expected_logs = serde_json::from_str::<Vec<LogMatch>>(
+        r#"[
+            "move|mon:Charmander,player-1,1|name:Tackle|target:Gastly,player-2,1",
+            "supereffective|mon:Gastly,player-2,1",
+            "split|side:1",
+            "damage|mon:Gastly,player-2,1|health:30/90",
+            "damage|mon:Gastly,player-2,1|health:34/100",
+            "residual",
+            "turn|turn:2",
+            "continue",
+            "move|mon:Charmander,player-1,1|name:Psychic|target:Gastly,player-2,1",
+            "resisted|mon:Gastly,player-2,1",
+            "split|side:1",
+            "damage|mon:Gastly,player-2,1|health:0",
+            "damage|mon:Gastly,player-2,1|health:0",
+            "faint|mon:Gastly,player-2,1",
+            "win|side:0"
+        ]"#,
+    )
+    .unwrap();
+    assert_logs_since_turn_eq(&battle, 1, &expected_logs);
+}
+
+#[test]
+fn type_chart_override_overrides_type_effectiveness() {
+    let mut battle = make_battle(
+        0,
+        &["Type Chart Override = Normal > Ghost: 1, Psychic > Poison: 0.5"],
+    )
+    .unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    assert_eq!(
+        battle
+            .full_log()
+            .filter(|log| log.starts_with("typechartoverride"))
+            .collect::<Vec<_>>(),
+        Vec::from_iter([
+            "typechartoverride|offense:Normal|defense:Ghost|multiplier:1",
+            "typechartoverride|offense:Psychic|defense:Poison|multiplier:0.5",
+        ])
+    );
+    assert!(!battle.full_log().any(|log| log == "inversetypechart"));
+
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 0"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "pass"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 1"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "pass"), Ok(()));
+
+    let expected_logs = serde_json::from_str::<Vec<LogMatch>>(
+        r#"[
+            "move|mon:Charmander,player-1,1|name:Tackle|target:Gastly,player-2,1",
+            "split|side:1",
+            "damage|mon:Gastly,player-2,1|health:60/90",
+            "damage|mon:Gastly,player-2,1|health:67/100",
+            "residual",
+            "turn|turn:2",
+            "continue",
+            "move|mon:Charmander,player-1,1|name:Psychic|target:Gastly,player-2,1",
+            "resisted|mon:Gastly,player-2,1",
+            "split|side:1",
+            "damage|mon:Gastly,player-2,1|health:27/90",
+            "damage|mon:Gastly,player-2,1|health:30/100",
+            "unboost|mon:Gastly,player-2,1|stat:spd|by:1",
+            "residual",
+            "turn|turn:3"
+        ]"#,
+    )
+    .unwrap();
+    assert_logs_since_turn_eq(&battle, 1, &expected_logs);
+}
+
+#[test]
+fn inverse_battle_respects_type_chart_override() {
+    let mut battle = make_battle(
+        0,
+        &["Inverse Battle", "Type Chart Override = Normal > Ghost: 0"],
+    )
+    .unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 0"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "pass"), Ok(()));
+
+    let expected_logs = serde_json::from_str::<Vec<LogMatch>>(
+        r#"[
+            "move|mon:Charmander,player-1,1|name:Tackle|noanim",
+            "immune|mon:Gastly,player-2,1",
+            "residual",
+            "turn|turn:2"
+        ]"#,
+    )
+    .unwrap();
+    assert_logs_since_turn_eq(&battle, 1, &expected_logs);
+}
+
+#[test]
+fn fails_with_invalid_type_chart_override() {
+    assert_matches::assert_matches!(
+        make_battle(0, &["Type Chart Override = Normal > Ghost: 3"]).err(),
+        Some(err) => assert_eq!(format!("{err:#}"), "rule Type Chart Override is invalid: invalid type effectiveness \"3\"")
+    );
+}
@@ -0,0 +1,135 @@
+use anyhow::Result;
+use battler::{
+    BattleType,
+    CoreBattleEngineSpeedSortTieResolution,
+    PublicCoreBattle,
+    TeamData,
+    WrapResultError,
+};
+use battler_test_utils::{
+    LogMatch,
+    TestBattleBuilder,
+    assert_logs_since_turn_eq,
+    static_local_data_store,
+};
+
+fn team_1() -> Result<TeamData> {
+    serde_json::from_str(
+        r#"{
+            "members": [
+                {
+                    "name": "Pidgeot",
+                    "species": "Pidgeot",
+                    "ability": "Keen Eye",
+                    "moves": [
+                        "Earthquake",
+                        "Gust"
+                    ],
+                    "nature": "Hardy",
+                    "level": 50
+                }
+            ]
+        }"#,
+    )
+    .wrap_error()
+}
+
+fn team_2() -> Result<TeamData> {
+    serde_json::from_str(
+        r#"{
+            "members": [
+                {
+                    "name": "Bronzong",
+                    "species": "Bronzong",
+                    "ability": "Levitate",
+                    "moves": [
+                        "Metronome"
+                    ],
+                    "nature": "Hardy",
+                    "level": 50
+                }
+            ]
+        }"#,
+    )
+    .wrap_error()
+}
+
+fn make_battle(seed: u64, rules: &[&str]) -> Result<PublicCoreBattle<'static>> {
+    let mut builder = TestBattleBuilder::new()
+        .with_battle_type(BattleType::Singles)
+        .with_seed(seed)
+        .with_team_validation(false)
+        .with_pass_allowed(true)
+        .with_speed_sort_tie_resolution(CoreBattleEngineSpeedSortTieResolution::Keep)
+        .add_player_to_side_1("player-1", "Player 1")
+        .add_player_to_side_2("player-2", "Player 2")
+        .with_team("player-1", team_1()?)
+        .with_team("player-2", team_2()?);
+    for rule in rules {
+        builder = builder.with_rule(rule);
+    }
+    builder.build(static_local_data_store())
+}
+
+#[test]
+fn battle_banned_moves_fail() {
+    let mut battle = make_battle(0, &["Sky Battle"]).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 0"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "pass"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 1"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "pass"), Ok(()));
+
+    let expected_logs = serde_json::from_str::<Vec<LogMatch>>(
+        r#"[
+            "move|mon:Pidgeot,player-1,1|name:Earthquake",
+            "fail|mon:Pidgeot,player-1,1|from:clause:Battle Banned Moves",
+            "residual",
+            "turn|turn:2",
+            "continue",
+            "move|mon:Pidgeot,player-1,1|name:Gust|target:Bronzong,player-2,1",
+            "resisted|mon:Bronzong,player-2,1",
+            "split|side:1",
+            "damage|mon:Bronzong,player-2,1|health:119/127",
+            "damage|mon:Bronzong,player-2,1|health:94/100",
+            "residual",
+            "turn|turn:3"
+        ]"#,
+    )
+    .unwrap();
+    assert_logs_since_turn_eq(&battle, 1, &expected_logs);
+}
+
+#[test]
+fn battle_banned_moves_fail_when_called_by_other_moves() {
+    let mut battle = make_battle(0, &["Battle Banned Moves = Move=Absorb"]).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "pass"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "move 0"), Ok(()));
+
+    let expected_logs = serde_json::from_str::<Vec<LogMatch>>(
+        r#"[
+            "move|mon:Bronzong,player-2,1|name:Metronome|target:Bronzong,player-2,1",
+            "move|mon:Bronzong,player-2,1|name:Absorb|target:Pidgeot,player-1,1|from:move:Metronome",
+            "fail|mon:Bronzong,player-2,1|from:clause:Battle Banned Moves",
+            "residual",
+            "turn|turn:2"
+        ]"#,
+    )
+    .unwrap();
+    assert_logs_since_turn_eq(&battle, 1, &expected_logs);
+}
+
+#[test]
+fn fails_with_invalid_predicate() {
+    assert_matches::assert_matches!(
+        make_battle(0, &["Allow Species = Color=Red"]).err(),
+        Some(err) => assert_eq!(format!("{err:#}"), "rule Allow Species is invalid: invalid predicate key \"Color\"")
+    );
+    assert_matches::assert_matches!(
+        make_battle(0, &["Battle Banned Moves = Earthquake"]).err(),
+        Some(err) => assert_eq!(format!("{err:#}"), "rule Battle Banned Moves is invalid: invalid predicate condition \"Earthquake\"")
+    );
+}
@@ -0,0 +1,167 @@
+use battler::{
+    Id,
+    IntegrityIssue,
+    ResourceType,
+    check_integrity,
+};
+use battler_test_utils::{
+    local_data_store,
+    static_local_data_store,
+};
+
+fn dangling_reference(
+    resource_type: ResourceType,
+    id: &str,
+    field: &str,
+    target_type: ResourceType,
+    target: &str,
+) -> IntegrityIssue {
+    IntegrityIssue::DanglingReference {
+        resource_type,
+        id: Id::from(id),
+        field: field.to_owned(),
+        target_type,
+        target: target.to_owned(),
+    }
+}
+
+#[test]
+fn shipped_aliases_and_learnsets_resolve() {
+    let report = check_integrity(static_local_data_store()).unwrap();
+    let issues = report
+        .issues
+        .iter()
+        .filter(|issue| match issue {
+            IntegrityIssue::DanglingAlias { .. } | IntegrityIssue::AliasCycle { .. } => true,
+            IntegrityIssue::DanglingReference { field, .. } => [
+                "learnset",
+                "events.moves",
+                "abilities",
+                "hidden_ability",
+                "prevo",
+            ]
+            .contains(&field.as_str()),
+            _ => false,
+        })
+        .collect::<Vec<_>>();
+    assert!(issues.is_empty(), "{issues:#?}");
+}
+
+#[test]
+fn reports_dangling_references() {
+    let mut data = local_data_store();
+    data.moves.remove(&Id::from("ember"));
+    data.species.remove(&Id::from("charmeleon"));
+    data.moves
+        .get_mut(&Id::from("tackle"))
+        .unwrap()
+        .hit_effect
+        .get_or_insert_default()
+        .volatile_status = Some("fakecondition".to_owned());
+    // Conditions may refer to any effect.
+    data.moves
+        .get_mut(&Id::from("pound"))
+        .unwrap()
+        .hit_effect
+        .get_or_insert_default()
+        .volatile_status = Some("leftovers".to_owned());
+
+    let report = check_integrity(&data).unwrap();
+    assert!(!report.is_ok());
+    for issue in [
+        dangling_reference(
+            ResourceType::Species,
+            "charmander",
+            "learnset",
+            ResourceType::Move,
+            "ember",
+        ),
+        dangling_reference(
+            ResourceType::Species,
+            "charmander",
+            "evos",
+            ResourceType::Species,
+            "Charmeleon",
+        ),
+        dangling_reference(
+            ResourceType::Species,
+            "charizard",
+            "prevo",
+            ResourceType::Species,
+            "Charmeleon",
+        ),
+        dangling_reference(
+            ResourceType::Move,
+            "tackle",
+            "hit_effect.volatile_status",
+            ResourceType::Condition,
+            "fakecondition",
+        ),
+    ] {
+        assert!(report.issues.contains(&issue), "missing issue: {issue}");
+    }
+    assert!(!report.issues.iter().any(|issue| matches!(
+        issue,
+        IntegrityIssue::DanglingReference { id, .. } if id == "pound"
+    )));
+}
+
+#[test]
+fn reports_broken_aliases() {
+    let mut data = local_data_store();
+    data.aliases
+        .insert(Id::from("brokenalias"), Id::from("missing"));
+    data.aliases.insert(Id::from("cyclea"), Id::from("cycleb"));
+    data.aliases.insert(Id::from("cycleb"), Id::from("cyclea"));
+    data.species.get_mut(&Id::from("bulbasaur")).unwrap().evos =
+        Vec::from_iter(["Ivysaur".to_owned(), "Cycle A".to_owned()]);
+
+    let report = check_integrity(&data).unwrap();
+    for issue in [
+        IntegrityIssue::DanglingAlias {
+            alias: Id::from("brokenalias"),
+            target: Id::from("missing"),
+        },
+        IntegrityIssue::AliasCycle {
+            alias: Id::from("cyclea"),
+        },
+        IntegrityIssue::AliasCycle {
+            alias: Id::from("cycleb"),
+        },
+    ] {
+        assert!(report.issues.contains(&issue), "missing issue: {issue}");
+    }
+    // Cycles are reported once, on the aliases themselves.
+    assert!(!report.issues.iter().any(|issue| matches!(
+        issue,
+        IntegrityIssue::DanglingReference { id, .. } if id == "bulbasaur"
+    )));
+}
+
+#[test]
+fn reports_duplicate_names() {
+    let mut data = local_data_store();
+    let leftovers = data.items.get(&Id::from("leftovers")).unwrap().clone();
+    data.items.insert(Id::from("leftovers2"), leftovers);
+
+    let report = check_integrity(&data).unwrap();
+    let issue = IntegrityIssue::DuplicateName {
+        resource_type: ResourceType::Item,
+        name: "Leftovers".to_owned(),
+        ids: Vec::from_iter([Id::from("leftovers"), Id::from("leftovers2")]),
+    };
+    assert!(report.issues.contains(&issue), "missing issue: {issue}");
+    assert!(
+        report
+            .to_string()
+            .contains("item name Leftovers is shared by leftovers, leftovers2")
+    );
+}
+
+#[test]
+fn reports_are_deterministic() {
+    pretty_assertions::assert_eq!(
+        check_integrity(static_local_data_store()).unwrap(),
+        check_integrity(&local_data_store()).unwrap()
+    );
+}
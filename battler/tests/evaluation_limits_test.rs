@@ -0,0 +1,310 @@
+use std::collections::BTreeSet;
+
+use anyhow::Result;
+use battler::{
+    BattleType,
+    CoreBattleSnapshot,
+    EvaluationLimits,
+    PublicCoreBattle,
+    TeamData,
+    WrapResultError,
+};
+use battler_test_utils::{
+    TestBattleBuilder,
+    static_local_data_store,
+};
+
+fn team() -> Result<TeamData> {
+    serde_json::from_str(
+        r#"{
+            "members": [
+                {
+                    "name": "Pikachu",
+                    "species": "Pikachu",
+                    "ability": "No Ability",
+                    "moves": [
+                        "Tackle"
+                    ],
+                    "item": "Light Ball",
+                    "nature": "Hardy",
+                    "level": 50
+                }
+            ]
+        }"#,
+    )
+    .wrap_error()
+}
+
+fn make_battle(limits: EvaluationLimits) -> Result<PublicCoreBattle<'static>> {
+    TestBattleBuilder::new()
+        .with_battle_type(BattleType::Singles)
+        .with_seed(0)
+        .with_team_validation(false)
+        .with_pass_allowed(true)
+        .with_fxlang_limits(limits)
+        .add_player_to_side_1("player-1", "Player 1")
+        .add_player_to_side_2("player-2", "Player 2")
+        .with_team("player-1", team()?)
+        .with_team("player-2", team()?)
+        .build(static_local_data_store())
+}
+
+fn run_outside_effect(battle: &mut PublicCoreBattle, program: serde_json::Value) {
+    run_outside_effect_on_target(battle, serde_json::json!("field"), program);
+}
+
+fn run_outside_effect_on_target(
+    battle: &mut PublicCoreBattle,
+    target: serde_json::Value,
+    program: serde_json::Value,
+) {
+    assert_matches::assert_matches!(
+        battle.push_outside_effect(
+            serde_json::from_value(serde_json::json!({
+                "name": "Custom Effect",
+                "target": target,
+                "program": program,
+            }))
+            .unwrap(),
+        ),
+        Ok(())
+    );
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 0"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "move 0"), Ok(()));
+}
+
+fn sum_program() -> serde_json::Value {
+    serde_json::json!([
+        "$total = 0",
+        "foreach $i in [1, 2, 3, 4, 5, 6, 7, 8, 9, 10]:",
+        ["$total = $total + $i"],
+        "log_activate: str('total:{}', $total)",
+    ])
+}
+
+fn custom_effect_error(battle: &PublicCoreBattle) -> Option<String> {
+    battle
+        .full_log()
+        .find(|entry| {
+            entry.starts_with("debug|event:Activate") && entry.contains("|effect:Custom Effect|")
+        })
+        .map(|entry| entry.to_owned())
+}
+
+fn custom_effect_activated(battle: &PublicCoreBattle, value: &str) -> bool {
+    battle
+        .full_log()
+        .any(|entry| entry.starts_with("activate|") && entry.ends_with(value))
+}
+
+#[test]
+fn programs_run_without_limits() {
+    let mut battle = make_battle(EvaluationLimits::default()).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+    run_outside_effect(&mut battle, sum_program());
+    pretty_assertions::assert_eq!(custom_effect_error(&battle), None);
+    assert!(custom_effect_activated(&battle, "total:55"));
+}
+
+#[test]
+fn callback_fails_when_callback_budget_is_exceeded() {
+    let mut battle = make_battle(EvaluationLimits {
+        callback_instructions: Some(20),
+        ..Default::default()
+    })
+    .unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+    run_outside_effect(&mut battle, sum_program());
+    assert!(
+        custom_effect_error(&battle)
+            .unwrap()
+            .ends_with("exceeded the budget of 20 instructions per callback")
+    );
+    assert!(!custom_effect_activated(&battle, "total:55"));
+
+    // The battle continues normally.
+    assert!(battle.full_log().any(|entry| entry == "turn|turn:2"));
+}
+
+#[test]
+fn callbacks_fail_when_turn_budget_is_exceeded() {
+    let mut battle = make_battle(EvaluationLimits {
+        turn_instructions: Some(40),
+        ..Default::default()
+    })
+    .unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+    run_outside_effect(&mut battle, sum_program());
+    assert!(
+        custom_effect_error(&battle)
+            .unwrap()
+            .ends_with("exceeded the budget of 40 instructions per turn")
+    );
+
+    // The budget resets at the start of the next turn.
+    run_outside_effect(
+        &mut battle,
+        serde_json::json!(["log_activate: str('total:{}', 1)"]),
+    );
+    assert!(custom_effect_activated(&battle, "total:1"));
+}
+
+#[test]
+fn callback_fails_when_list_is_too_long() {
+    let mut battle = make_battle(EvaluationLimits {
+        list_length: Some(3),
+        ..Default::default()
+    })
+    .unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+    run_outside_effect(&mut battle, serde_json::json!(["$list = [1, 2, 3, 4]"]));
+    assert!(
+        custom_effect_error(&battle)
+            .unwrap()
+            .ends_with("list of length 4 exceeds the maximum list length of 3")
+    );
+}
+
+#[test]
+fn untrusted_programs_only_call_allowed_functions() {
+    let mut battle = make_battle(EvaluationLimits {
+        untrusted_functions: Some(BTreeSet::from_iter(["log_activate".to_owned()])),
+        ..Default::default()
+    })
+    .unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+    run_outside_effect(
+        &mut battle,
+        serde_json::json!([
+            "log_activate: str('allowed')",
+            "foreach $mon in func_call(all_active_mons):",
+            ["log_activate: $mon"],
+        ]),
+    );
+    assert!(custom_effect_activated(&battle, "allowed"));
+    assert!(
+        custom_effect_error(&battle)
+            .unwrap()
+            .ends_with("function all_active_mons is not allowed in untrusted programs")
+    );
+
+    // Programs from battle data are not restricted.
+    assert!(
+        battle
+            .full_log()
+            .any(|entry| entry.starts_with("damage|mon:Pikachu,player-2,1"))
+    );
+}
+
+#[test]
+fn subroutines_called_by_untrusted_programs_only_call_allowed_functions() {
+    let mut battle = make_battle(EvaluationLimits {
+        untrusted_functions: Some(BTreeSet::from_iter([
+            "log_activate".to_owned(),
+            "effective_item_special_data".to_owned(),
+        ])),
+        ..Default::default()
+    })
+    .unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+    run_outside_effect_on_target(
+        &mut battle,
+        serde_json::json!({ "mon": { "player": "player-1", "position": 0 } }),
+        serde_json::json!([
+            "log_activate: str('allowed')",
+            "$data = func_call(effective_item_special_data: $target)",
+        ]),
+    );
+    assert!(custom_effect_activated(&battle, "allowed"));
+    assert!(
+        custom_effect_error(&battle)
+            .unwrap()
+            .ends_with("function special_item_data is not allowed in untrusted programs")
+    );
+}
+
+#[test]
+fn forked_and_restored_battles_keep_turn_budget() {
+    const BUDGET: u64 = 200;
+    let team: TeamData = serde_json::from_str(
+        r#"{
+            "members": [
+                {
+                    "name": "Pikachu",
+                    "species": "Pikachu",
+                    "ability": "No Ability",
+                    "moves": [
+                        "U-turn"
+                    ],
+                    "nature": "Hardy",
+                    "level": 50
+                },
+                {
+                    "name": "Eevee",
+                    "species": "Eevee",
+                    "ability": "No Ability",
+                    "moves": [
+                        "Tackle"
+                    ],
+                    "nature": "Hardy",
+                    "level": 50
+                }
+            ]
+        }"#,
+    )
+    .unwrap();
+    let mut battle = TestBattleBuilder::new()
+        .with_battle_type(BattleType::Singles)
+        .with_seed(0)
+        .with_team_validation(false)
+        .with_pass_allowed(true)
+        .with_fxlang_limits(EvaluationLimits {
+            turn_instructions: Some(BUDGET),
+            ..Default::default()
+        })
+        .add_player_to_side_1("player-1", "Player 1")
+        .add_player_to_side_2("player-2", "Player 2")
+        .with_team("player-1", team.clone())
+        .with_team("player-2", team)
+        .build(static_local_data_store())
+        .unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    // The program uses most of the turn budget, and U-turn interrupts the turn with a switch.
+    run_outside_effect(&mut battle, sum_program());
+    assert!(custom_effect_activated(&battle, "total:55"));
+    assert!(
+        battle
+            .active_requests()
+            .any(|(player, _)| player == "player-1")
+    );
+
+    let mut fork = battle.fork().unwrap();
+    let snapshot = serde_json::to_string(&battle.snapshot().unwrap()).unwrap();
+    let mut restored = PublicCoreBattle::restore(
+        serde_json::from_str::<CoreBattleSnapshot>(&snapshot).unwrap(),
+        static_local_data_store(),
+    )
+    .unwrap();
+    for battle in [&mut battle, &mut fork, &mut restored] {
+        assert_matches::assert_matches!(
+            battle.push_outside_effect(
+                serde_json::from_value(serde_json::json!({
+                    "name": "Custom Effect",
+                    "target": "field",
+                    "program": sum_program(),
+                }))
+                .unwrap(),
+            ),
+            Ok(())
+        );
+        assert_matches::assert_matches!(battle.set_player_choice("player-1", "switch 1"), Ok(()));
+    }
+
+    // The turn budget is part of the battle state, so every battle exhausts it.
+    for battle in [&battle, &fork, &restored] {
+        assert!(custom_effect_error(battle).unwrap().ends_with(&format!(
+            "exceeded the budget of {BUDGET} instructions per turn"
+        )));
+    }
+}
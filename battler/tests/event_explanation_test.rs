@@ -0,0 +1,259 @@
+use anyhow::Result;
+use battler::{
+    BattleEvent,
+    BattleEventModifier,
+    BattleType,
+    CallbackExclusionReason,
+    DataStore,
+    EventExplanation,
+    EventSource,
+    EventSourceMon,
+    EventTarget,
+    PublicCoreBattle,
+    TeamData,
+    WrapResultError,
+};
+use battler_test_utils::{
+    TestBattleBuilder,
+    static_local_data_store,
+};
+
+fn venusaur() -> Result<TeamData> {
+    serde_json::from_str(
+        r#"{
+            "members": [
+                {
+                    "name": "Venusaur",
+                    "species": "Venusaur",
+                    "ability": "Chlorophyll",
+                    "item": "Leftovers",
+                    "moves": [
+                        "Tackle"
+                    ],
+                    "nature": "Hardy",
+                    "level": 50
+                }
+            ]
+        }"#,
+    )
+    .wrap_error()
+}
+
+fn jolteon() -> Result<TeamData> {
+    serde_json::from_str(
+        r#"{
+            "members": [
+                {
+                    "name": "Jolteon",
+                    "species": "Jolteon",
+                    "ability": "Swift Swim",
+                    "item": "Leftovers",
+                    "moves": [
+                        "Gastro Acid"
+                    ],
+                    "nature": "Hardy",
+                    "level": 50
+                }
+            ]
+        }"#,
+    )
+    .wrap_error()
+}
+
+fn make_battle(data: &dyn DataStore) -> Result<PublicCoreBattle<'_>> {
+    TestBattleBuilder::new()
+        .with_battle_type(BattleType::Singles)
+        .with_seed(0)
+        .with_pass_allowed(true)
+        .with_team_validation(false)
+        .add_player_to_side_1("player-1", "Player 1")
+        .add_player_to_side_2("player-2", "Player 2")
+        .with_team("player-1", venusaur()?)
+        .with_team("player-2", jolteon()?)
+        .build(data)
+}
+
+fn explained_callbacks(
+    explanation: &EventExplanation,
+) -> Vec<(&str, BattleEventModifier, Option<CallbackExclusionReason>)> {
+    explanation
+        .callbacks
+        .iter()
+        .map(|callback| (callback.name.as_str(), callback.modifier, callback.excluded))
+        .collect()
+}
+
+#[test]
+fn explains_callbacks_for_mon_event() {
+    let mut battle = make_battle(static_local_data_store()).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    let explanation = battle
+        .explain_event(
+            BattleEvent::ModifySpe,
+            EventTarget::Mon {
+                player: "player-1".to_owned(),
+                position: 0,
+            },
+            EventSource::default(),
+        )
+        .unwrap();
+    assert_eq!(explanation.event, BattleEvent::ModifySpe);
+
+    let callbacks = explained_callbacks(&explanation);
+    assert_eq!(
+        callbacks[0],
+        ("Chlorophyll", BattleEventModifier::None, None)
+    );
+    assert!(
+        callbacks[1..]
+            .iter()
+            .all(|(_, _, excluded)| excluded.is_some())
+    );
+    assert!(callbacks.contains(&(
+        "Swift Swim",
+        BattleEventModifier::Foe,
+        Some(CallbackExclusionReason::NoCallback)
+    )));
+    assert!(callbacks.contains(&(
+        "Leftovers",
+        BattleEventModifier::None,
+        Some(CallbackExclusionReason::NoCallback)
+    )));
+    assert!(explanation.callbacks[0].ordering.is_some());
+    assert!(!explanation.callbacks[0].suppressed);
+}
+
+#[test]
+fn explains_suppressed_ability() {
+    let mut battle = make_battle(static_local_data_store()).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "pass"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "move 0"), Ok(()));
+
+    let explanation = battle
+        .explain_event(
+            BattleEvent::ModifySpe,
+            EventTarget::Mon {
+                player: "player-1".to_owned(),
+                position: 0,
+            },
+            EventSource::default(),
+        )
+        .unwrap();
+    let chlorophyll = explanation
+        .callbacks
+        .iter()
+        .find(|callback| callback.name == "Chlorophyll")
+        .unwrap();
+    assert!(chlorophyll.suppressed);
+    assert_eq!(
+        chlorophyll.excluded,
+        Some(CallbackExclusionReason::Suppressed)
+    );
+    assert_eq!(chlorophyll.ordering, None);
+    assert!(
+        explanation
+            .callbacks
+            .iter()
+            .all(|callback| callback.excluded.is_some())
+    );
+}
+
+#[test]
+fn explains_residual_order_by_speed() {
+    let mut battle = make_battle(static_local_data_store()).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    let log_entries = battle.full_log().count();
+    let explanation = battle
+        .explain_event(
+            BattleEvent::Residual,
+            EventTarget::Residual,
+            EventSource::default(),
+        )
+        .unwrap();
+    assert_eq!(battle.full_log().count(), log_entries);
+
+    let included = explanation
+        .callbacks
+        .iter()
+        .filter(|callback| callback.excluded.is_none())
+        .collect::<Vec<_>>();
+    let leftovers = included
+        .iter()
+        .filter(|callback| callback.name == "Leftovers")
+        .collect::<Vec<_>>();
+    assert_eq!(leftovers.len(), 2);
+    assert!(
+        leftovers[0].ordering.as_ref().unwrap().speed
+            > leftovers[1].ordering.as_ref().unwrap().speed
+    );
+    assert!(leftovers.iter().all(|callback| !callback.speed_tie));
+}
+
+#[test]
+fn explains_callbacks_of_source_mon_and_effect() {
+    let mut battle = make_battle(static_local_data_store()).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    let explanation = battle
+        .explain_event(
+            BattleEvent::BasePower,
+            EventTarget::Mon {
+                player: "player-2".to_owned(),
+                position: 0,
+            },
+            EventSource {
+                mon: Some(EventSourceMon {
+                    player: "player-1".to_owned(),
+                    position: 0,
+                }),
+                effect: Some("move:fusionflare".to_owned()),
+            },
+        )
+        .unwrap();
+
+    let callbacks = explained_callbacks(&explanation);
+    assert!(callbacks.contains(&("Fusion Flare", BattleEventModifier::None, None)));
+    assert!(callbacks.contains(&(
+        "Chlorophyll",
+        BattleEventModifier::Source,
+        Some(CallbackExclusionReason::NoCallback)
+    )));
+}
+
+#[test]
+fn fails_for_missing_source_effect() {
+    let mut battle = make_battle(static_local_data_store()).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+    assert!(
+        battle
+            .explain_event(
+                BattleEvent::BasePower,
+                EventTarget::Field,
+                EventSource {
+                    mon: None,
+                    effect: Some("move:nonexistent".to_owned()),
+                },
+            )
+            .is_err()
+    );
+}
+
+#[test]
+fn fails_for_missing_target() {
+    let mut battle = make_battle(static_local_data_store()).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+    assert_matches::assert_matches!(
+        battle.explain_event(
+            BattleEvent::ModifySpe,
+            EventTarget::Mon {
+                player: "player-1".to_owned(),
+                position: 1,
+            },
+            EventSource::default(),
+        ),
+        Err(err) => assert_eq!(format!("{err:#}"), "no active mon in position 1")
+    );
+}
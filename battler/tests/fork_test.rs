@@ -0,0 +1,266 @@
+use std::sync::atomic::{
+    AtomicUsize,
+    Ordering,
+};
+
+use anyhow::Result;
+use battler::{
+    AbilityData,
+    Aliases,
+    BattleType,
+    ClauseData,
+    ConditionData,
+    DataStore,
+    Id,
+    ItemData,
+    MoveData,
+    PublicCoreBattle,
+    SpeciesData,
+    SubroutineData,
+    TeamData,
+    TypeChart,
+    WrapResultError,
+};
+use battler_prng::RealPseudoRandomNumberGenerator;
+use battler_test_utils::{
+    TestBattleBuilder,
+    static_local_data_store,
+};
+
+/// A [`DataStore`] that counts how many times resources are read from it.
+struct CountingDataStore {
+    data: &'static dyn DataStore,
+    reads: AtomicUsize,
+}
+
+impl CountingDataStore {
+    fn new() -> Self {
+        Self {
+            data: static_local_data_store(),
+            reads: AtomicUsize::new(0),
+        }
+    }
+
+    fn reads(&self) -> usize {
+        self.reads.load(Ordering::Relaxed)
+    }
+
+    fn read(&self) -> &dyn DataStore {
+        self.reads.fetch_add(1, Ordering::Relaxed);
+        self.data
+    }
+}
+
+impl DataStore for CountingDataStore {
+    fn all_ability_ids(&self, filter: &dyn Fn(&AbilityData) -> bool) -> Result<Vec<Id>> {
+        self.read().all_ability_ids(filter)
+    }
+
+    fn all_clause_ids(&self, filter: &dyn Fn(&ClauseData) -> bool) -> Result<Vec<Id>> {
+        self.read().all_clause_ids(filter)
+    }
+
+    fn all_condition_ids(&self, filter: &dyn Fn(&ConditionData) -> bool) -> Result<Vec<Id>> {
+        self.read().all_condition_ids(filter)
+    }
+
+    fn all_item_ids(&self, filter: &dyn Fn(&ItemData) -> bool) -> Result<Vec<Id>> {
+        self.read().all_item_ids(filter)
+    }
+
+    fn all_move_ids(&self, filter: &dyn Fn(&MoveData) -> bool) -> Result<Vec<Id>> {
+        self.read().all_move_ids(filter)
+    }
+
+    fn all_species_ids(&self, filter: &dyn Fn(&SpeciesData) -> bool) -> Result<Vec<Id>> {
+        self.read().all_species_ids(filter)
+    }
+
+    fn get_type_chart(&self) -> Result<TypeChart> {
+        self.read().get_type_chart()
+    }
+
+    fn all_aliases(&self) -> Result<Aliases> {
+        self.read().all_aliases()
+    }
+
+    fn translate_alias(&self, id: &Id) -> Result<Option<Id>> {
+        self.read().translate_alias(id)
+    }
+
+    fn get_ability(&self, id: &Id) -> Result<Option<AbilityData>> {
+        self.read().get_ability(id)
+    }
+
+    fn get_clause(&self, id: &Id) -> Result<Option<ClauseData>> {
+        self.read().get_clause(id)
+    }
+
+    fn get_condition(&self, id: &Id) -> Result<Option<ConditionData>> {
+        self.read().get_condition(id)
+    }
+
+    fn get_item(&self, id: &Id) -> Result<Option<ItemData>> {
+        self.read().get_item(id)
+    }
+
+    fn get_move(&self, id: &Id) -> Result<Option<MoveData>> {
+        self.read().get_move(id)
+    }
+
+    fn get_species(&self, id: &Id) -> Result<Option<SpeciesData>> {
+        self.read().get_species(id)
+    }
+
+    fn get_subroutine(&self, id: &Id) -> Result<Option<SubroutineData>> {
+        self.read().get_subroutine(id)
+    }
+}
+
+fn team() -> Result<TeamData> {
+    serde_json::from_str(
+        r#"{
+            "members": [
+                {
+                    "name": "Charizard",
+                    "species": "Charizard",
+                    "ability": "Blaze",
+                    "moves": [
+                        "Flamethrower",
+                        "Air Slash",
+                        "Will-O-Wisp",
+                        "Protect"
+                    ],
+                    "nature": "Hardy",
+                    "level": 50
+                },
+                {
+                    "name": "Blastoise",
+                    "species": "Blastoise",
+                    "ability": "Torrent",
+                    "moves": [
+                        "Surf",
+                        "Rain Dance",
+                        "Substitute",
+                        "Protect"
+                    ],
+                    "nature": "Hardy",
+                    "level": 50
+                }
+            ]
+        }"#,
+    )
+    .wrap_error()
+}
+
+fn make_battle(seed: u64) -> Result<PublicCoreBattle<'static>> {
+    make_battle_with_data(seed, static_local_data_store())
+}
+
+fn make_battle_with_data(seed: u64, data: &dyn DataStore) -> Result<PublicCoreBattle<'_>> {
+    TestBattleBuilder::new()
+        .with_battle_type(BattleType::Singles)
+        .with_seed(seed)
+        .with_team_validation(false)
+        .add_player_to_side_1("player-1", "Player 1")
+        .add_player_to_side_2("player-2", "Player 2")
+        .with_team("player-1", team()?)
+        .with_team("player-2", team()?)
+        .build(data)
+}
+
+fn make_choices(battle: &mut PublicCoreBattle, choices: &[(&str, &str)]) {
+    for (player, choice) in choices {
+        assert_matches::assert_matches!(battle.set_player_choice(player, choice), Ok(()));
+    }
+}
+
+#[test]
+fn fork_does_not_affect_original_battle() {
+    let mut battle = make_battle(0).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+    make_choices(
+        &mut battle,
+        &[("player-1", "move 0"), ("player-2", "move 1")],
+    );
+
+    let log = battle.full_log().map(|s| s.to_owned()).collect::<Vec<_>>();
+    let request = battle.request_for_player("player-1").unwrap();
+
+    let mut fork = battle.fork().unwrap();
+    make_choices(
+        &mut fork,
+        &[("player-1", "switch 1"), ("player-2", "move 0")],
+    );
+    assert_eq!(fork.turn(), 3);
+
+    assert_eq!(battle.turn(), 2);
+    assert_eq!(
+        battle.full_log().map(|s| s.to_owned()).collect::<Vec<_>>(),
+        log
+    );
+    assert_eq!(battle.request_for_player("player-1").unwrap(), request);
+    make_choices(
+        &mut battle,
+        &[("player-1", "move 1"), ("player-2", "move 0")],
+    );
+    assert_eq!(battle.turn(), 3);
+}
+
+#[test]
+fn fork_continues_identically() {
+    let mut battle = make_battle(12345).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+    make_choices(
+        &mut battle,
+        &[("player-1", "move 0"), ("player-2", "move 1")],
+    );
+
+    let mut fork = battle.fork().unwrap();
+    for battle in [&mut battle, &mut fork] {
+        make_choices(battle, &[("player-1", "move 1"), ("player-2", "move 0")]);
+        make_choices(battle, &[("player-1", "move 0"), ("player-2", "move 0")]);
+    }
+
+    assert_eq!(
+        fork.full_log().collect::<Vec<_>>(),
+        battle.full_log().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn fork_with_prng_samples_different_outcomes() {
+    let mut battle = make_battle(0).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    let logs = (0..10)
+        .map(|seed| {
+            let mut fork = battle
+                .fork_with_prng(Box::new(RealPseudoRandomNumberGenerator::new(Some(seed))))
+                .unwrap();
+            make_choices(&mut fork, &[("player-1", "move 0"), ("player-2", "move 1")]);
+            fork.full_log().map(|s| s.to_owned()).collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    assert!(logs.iter().any(|log| log != &logs[0]));
+}
+
+#[test]
+fn fork_shares_loaded_resources() {
+    let data = CountingDataStore::new();
+    let mut battle = make_battle_with_data(0, &data).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+    make_choices(
+        &mut battle,
+        &[("player-1", "move 0"), ("player-2", "move 0")],
+    );
+    make_choices(
+        &mut battle,
+        &[("player-1", "move 0"), ("player-2", "move 0")],
+    );
+
+    let reads = data.reads();
+    let mut fork = battle.fork().unwrap();
+    make_choices(&mut fork, &[("player-1", "move 0"), ("player-2", "move 0")]);
+    assert_eq!(data.reads(), reads);
+}
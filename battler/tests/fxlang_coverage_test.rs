@@ -0,0 +1,180 @@
+use std::{
+    collections::BTreeSet,
+    sync::Arc,
+};
+
+use anyhow::Result;
+use battler::{
+    BattleType,
+    Coverage,
+    CoverageCollector,
+    CoverageReport,
+    DataStore,
+    Id,
+    MoveData,
+    PublicCoreBattle,
+    TeamData,
+    Tracer,
+    WrapResultError,
+};
+use battler_test_utils::{
+    TestBattleBuilder,
+    TestDataStore,
+};
+
+fn team() -> Result<TeamData> {
+    serde_json::from_str(
+        r#"{
+            "members": [
+                {
+                    "name": "Venusaur",
+                    "species": "Venusaur",
+                    "ability": "No Ability",
+                    "moves": [
+                        "Covered Move"
+                    ],
+                    "nature": "Hardy",
+                    "level": 50
+                }
+            ]
+        }"#,
+    )
+    .wrap_error()
+}
+
+fn make_battle(data: &dyn DataStore, tracer: Arc<dyn Tracer>) -> Result<PublicCoreBattle<'_>> {
+    TestBattleBuilder::new()
+        .with_battle_type(BattleType::Singles)
+        .with_seed(0)
+        .with_pass_allowed(true)
+        .with_team_validation(false)
+        .with_tracer(tracer)
+        .add_player_to_side_1("player-1", "Player 1")
+        .add_player_to_side_2("player-2", "Player 2")
+        .with_team("player-1", team()?)
+        .with_team("player-2", team()?)
+        .build(data)
+}
+
+fn covered_move_effect() -> serde_json::Value {
+    serde_json::json!({
+        "callbacks": {
+            "on_hit": [
+                "if $target.hp > 0:",
+                ["log_activate: str('hit')"],
+                "else:",
+                ["log_activate: str('fainted')"]
+            ],
+            "on_damaging_hit": [
+                "log_activate: str('damaging hit')"
+            ],
+        },
+    })
+}
+
+fn make_data_store() -> Result<TestDataStore> {
+    let mut data = TestDataStore::new_from_env("DATA_DIR")?;
+    let mut move_data: MoveData = serde_json::from_str(
+        r#"{
+            "name": "Covered Move",
+            "category": "Status",
+            "primary_type": "Normal",
+            "base_power": 0,
+            "accuracy": "exempt",
+            "pp": 5,
+            "target": "Normal",
+            "flags": []
+        }"#,
+    )
+    .wrap_error()?;
+    move_data.effect = covered_move_effect();
+    data.add_fake_move(Id::from("Covered Move"), move_data);
+    Ok(data)
+}
+
+fn collect_coverage(data: &dyn DataStore) -> Coverage {
+    let collector = Arc::new(CoverageCollector::new());
+    let mut battle = make_battle(data, collector.clone()).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 0"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "pass"), Ok(()));
+    collector.coverage()
+}
+
+#[test]
+fn collects_statements_of_programs_that_ran() {
+    let data = make_data_store().unwrap();
+    let coverage = collect_coverage(&data);
+    pretty_assertions::assert_eq!(
+        coverage.statements("move:coveredmove", "on_hit"),
+        Some(&BTreeSet::from_iter([1, 2]))
+    );
+    pretty_assertions::assert_eq!(
+        coverage.statements("move:coveredmove", "on_damaging_hit"),
+        None
+    );
+
+    // Conditions from battle data are covered too.
+    assert!(
+        coverage
+            .programs
+            .keys()
+            .any(|effect| effect.starts_with("condition:"))
+    );
+}
+
+#[test]
+fn reports_uncovered_statements_and_branches() {
+    let data = make_data_store().unwrap();
+    let effect = covered_move_effect();
+    let report = CoverageReport::new(
+        &collect_coverage(&data),
+        [("move:coveredmove".to_owned(), &effect)],
+        [],
+    );
+    pretty_assertions::assert_eq!(
+        report.to_string(),
+        r#"programs: 1/2 (50.0%)
+statements: 2/5 (40.0%)
+branches: 1/2 (50.0%)
+move:coveredmove on_damaging_hit: never ran
+move:coveredmove on_hit: statements 3-4 never ran; branches at statements 3 never taken
+"#
+    );
+
+    let report = serde_json::to_value(&report).unwrap();
+    pretty_assertions::assert_eq!(
+        report["uncovered"][1],
+        serde_json::json!({
+            "effect": "move:coveredmove",
+            "callback": "on_hit",
+            "ran": true,
+            "statements": [3, 4],
+            "branches": [
+                {
+                    "header": 3,
+                    "first": 4,
+                    "last": 4,
+                },
+            ],
+        })
+    );
+}
+
+#[test]
+fn merges_coverage_from_many_battles() {
+    let mut a = Coverage::default();
+    a.record_statement("move:coveredmove", "on_hit", 1);
+    let mut b = Coverage::default();
+    b.record_statement("move:coveredmove", "on_hit", 3);
+    b.record_program("subroutine:test", "program");
+    a.merge(b);
+    pretty_assertions::assert_eq!(
+        a.statements("move:coveredmove", "on_hit"),
+        Some(&BTreeSet::from_iter([1, 3]))
+    );
+    pretty_assertions::assert_eq!(
+        a.statements("subroutine:test", "program"),
+        Some(&BTreeSet::new())
+    );
+}
@@ -0,0 +1,255 @@
+use std::{
+    env,
+    fs,
+    path::{
+        Path,
+        PathBuf,
+    },
+    sync::Arc,
+    time::Duration,
+};
+
+use anyhow::Result;
+use battler::{
+    BattleType,
+    DataStore,
+    PublicCoreBattle,
+    TeamData,
+    WrapResultError,
+};
+use battler_local_data::WatchedLocalDataStore;
+use battler_test_utils::TestBattleBuilder;
+
+fn copy_dir(from: &Path, to: &Path) -> Result<()> {
+    fs::create_dir_all(to)?;
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let path = entry.path();
+        if path.is_dir() {
+            copy_dir(&path, &to.join(entry.file_name()))?;
+        } else {
+            fs::copy(&path, to.join(entry.file_name()))?;
+        }
+    }
+    Ok(())
+}
+
+struct TempDataDir(PathBuf);
+
+impl TempDataDir {
+    fn new(name: &str) -> Result<Self> {
+        let root = env::temp_dir().join(format!("battler-{name}-{}", std::process::id()));
+        fs::remove_dir_all(&root).ok();
+        copy_dir(Path::new(&env::var("DATA_DIR")?), &root)?;
+        Ok(Self(root))
+    }
+
+    fn root(&self) -> String {
+        self.0.to_string_lossy().to_string()
+    }
+
+    fn write_move(&self, message: &str) -> Result<()> {
+        let moves = serde_json::json!({
+            "hotreloadmove": {
+                "name": "Hot Reload Move",
+                "category": "Status",
+                "primary_type": "Normal",
+                "base_power": 0,
+                "accuracy": "exempt",
+                "pp": 5,
+                "target": "Normal",
+                "flags": [],
+                "effect": {
+                    "callbacks": {
+                        "on_hit": [format!("log_activate: str('{message}')")],
+                    },
+                },
+            },
+        });
+        fs::write(
+            self.0.join("moves").join("hot-reload.json"),
+            serde_json::to_string(&moves)?,
+        )?;
+        Ok(())
+    }
+
+    fn write_type_effectiveness(
+        &self,
+        offense: &str,
+        defense: &str,
+        multiplier: f64,
+    ) -> Result<()> {
+        let path = self.0.join("type-chart.json");
+        let mut type_chart =
+            serde_json::from_str::<serde_json::Value>(&fs::read_to_string(&path)?)?;
+        type_chart["types"][offense][defense] = serde_json::json!(multiplier);
+        fs::write(path, serde_json::to_string(&type_chart)?)?;
+        Ok(())
+    }
+}
+
+impl Drop for TempDataDir {
+    fn drop(&mut self) {
+        fs::remove_dir_all(&self.0).ok();
+    }
+}
+
+fn team() -> Result<TeamData> {
+    serde_json::from_str(
+        r#"{
+            "members": [
+                {
+                    "name": "Venusaur",
+                    "species": "Venusaur",
+                    "ability": "No Ability",
+                    "moves": [
+                        "Hot Reload Move",
+                        "Tackle"
+                    ],
+                    "nature": "Hardy",
+                    "level": 50
+                }
+            ]
+        }"#,
+    )
+    .wrap_error()
+}
+
+fn make_battle(data: &dyn DataStore) -> Result<PublicCoreBattle<'_>> {
+    make_battle_with_rules(data, &[])
+}
+
+fn make_battle_with_rules<'d>(
+    data: &'d dyn DataStore,
+    rules: &[&str],
+) -> Result<PublicCoreBattle<'d>> {
+    let mut builder = TestBattleBuilder::new()
+        .with_battle_type(BattleType::Singles)
+        .with_seed(0)
+        .with_pass_allowed(true)
+        .with_team_validation(false)
+        .add_player_to_side_1("player-1", "Player 1")
+        .add_player_to_side_2("player-2", "Player 2")
+        .with_team("player-1", team()?)
+        .with_team("player-2", team()?);
+    for rule in rules {
+        builder = builder.with_rule(rule);
+    }
+    builder.build(data)
+}
+
+fn use_move(battle: &mut PublicCoreBattle) -> Vec<String> {
+    battle.new_log_entries().for_each(drop);
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 0"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "pass"), Ok(()));
+    battle
+        .new_log_entries()
+        .filter(|log| log.starts_with("activate"))
+        .map(|log| log.to_owned())
+        .collect()
+}
+
+#[test]
+fn uses_edited_effect_after_reload_and_invalidation() {
+    let dir = TempDataDir::new("hot-reload").unwrap();
+    dir.write_move("version 1").unwrap();
+    let data = WatchedLocalDataStore::new(dir.root()).unwrap();
+    let mut battle = make_battle(&data).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    assert_eq!(
+        use_move(&mut battle),
+        ["activate|move:Hot Reload Move|version 1"]
+    );
+
+    dir.write_move("version two").unwrap();
+    assert_matches::assert_matches!(data.reload_if_changed(), Ok(true));
+    assert_eq!(data.generation(), 1);
+
+    // Cached effects are used until the battle is told to invalidate them.
+    assert_eq!(
+        use_move(&mut battle),
+        ["activate|move:Hot Reload Move|version 1"]
+    );
+
+    assert_matches::assert_matches!(battle.invalidate_data_caches(), Ok(()));
+    assert_eq!(
+        use_move(&mut battle),
+        ["activate|move:Hot Reload Move|version two"]
+    );
+    assert_eq!(battle.turn(), 4);
+}
+
+fn use_tackle(battle: &mut PublicCoreBattle) -> Vec<String> {
+    battle.new_log_entries().for_each(drop);
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 1"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "pass"), Ok(()));
+    battle
+        .new_log_entries()
+        .filter(|log| log.starts_with("supereffective") || log.starts_with("resisted"))
+        .map(|log| log.to_owned())
+        .collect()
+}
+
+#[test]
+fn reloads_type_chart_with_overlay_after_invalidation() {
+    let dir = TempDataDir::new("hot-reload-type-chart").unwrap();
+    dir.write_move("version 1").unwrap();
+    let data = WatchedLocalDataStore::new(dir.root()).unwrap();
+    let mut battle = make_battle_with_rules(&data, &["Inverse Battle"]).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    assert_eq!(use_tackle(&mut battle), Vec::<String>::new());
+
+    dir.write_type_effectiveness("Normal", "Grass", 2.0)
+        .unwrap();
+    assert_matches::assert_matches!(data.reload_if_changed(), Ok(true));
+
+    // The cached type chart is used until the battle is told to invalidate it.
+    assert_eq!(use_tackle(&mut battle), Vec::<String>::new());
+
+    // The edited type chart is inverted by the ruleset.
+    assert_matches::assert_matches!(battle.invalidate_data_caches(), Ok(()));
+    assert_eq!(
+        use_tackle(&mut battle),
+        ["resisted|mon:Venusaur,player-2,1"]
+    );
+}
+
+#[test]
+fn keeps_previous_data_when_reload_fails() {
+    let dir = TempDataDir::new("hot-reload-failure").unwrap();
+    dir.write_move("version 1").unwrap();
+    let data = WatchedLocalDataStore::new(dir.root()).unwrap();
+
+    fs::write(dir.0.join("moves").join("hot-reload.json"), "{").unwrap();
+    assert_matches::assert_matches!(data.reload_if_changed(), Err(_));
+    assert_eq!(data.generation(), 0);
+    assert_matches::assert_matches!(
+        data.get_move(&"hotreloadmove".into()),
+        Ok(Some(move_data)) => assert_eq!(move_data.name, "Hot Reload Move")
+    );
+
+    dir.write_move("version two").unwrap();
+    assert_matches::assert_matches!(data.reload_if_changed(), Ok(true));
+    assert_matches::assert_matches!(data.reload_if_changed(), Ok(false));
+    assert_eq!(data.generation(), 1);
+}
+
+#[test]
+fn watcher_reloads_changed_data() {
+    let dir = TempDataDir::new("hot-reload-watcher").unwrap();
+    dir.write_move("version 1").unwrap();
+    let data = Arc::new(WatchedLocalDataStore::new(dir.root()).unwrap());
+    let watcher = data.watch(Duration::from_millis(10));
+
+    dir.write_move("version two").unwrap();
+    for _ in 0..500 {
+        if data.generation() > 0 {
+            break;
+        }
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    drop(watcher);
+    assert_eq!(data.generation(), 1);
+}
@@ -0,0 +1,71 @@
+use anyhow::Result;
+use battler::{
+    BattleType,
+    CoreBattleEngineSpeedSortTieResolution,
+    PublicCoreBattle,
+    TeamData,
+    WrapResultError,
+};
+use battler_test_utils::{
+    LogMatch,
+    TestBattleBuilder,
+    assert_logs_since_turn_eq,
+    static_local_data_store,
+};
+
+fn team() -> Result<TeamData> {
+    serde_json::from_str(
+        r#"{
+            "members": [
+                {
+                    "name": "Bulbasaur",
+                    "species": "Bulbasaur",
+                    "ability": "Overgrow",
+                    "moves": [
+                        "Confuse Ray"
+                    ],
+                    "nature": "Hardy",
+                    "level": 50,
+                    "item": "Persim Berry"
+                }
+            ]
+        }"#,
+    )
+    .wrap_error()
+}
+
+fn make_battle(seed: u64, team_1: TeamData, team_2: TeamData) -> Result<PublicCoreBattle<'static>> {
+    TestBattleBuilder::new()
+        .with_battle_type(BattleType::Singles)
+        .with_seed(seed)
+        .with_team_validation(false)
+        .with_pass_allowed(true)
+        .with_speed_sort_tie_resolution(CoreBattleEngineSpeedSortTieResolution::Keep)
+        .add_player_to_side_1("player-1", "Player 1")
+        .add_player_to_side_2("player-2", "Player 2")
+        .with_team("player-1", team_1)
+        .with_team("player-2", team_2)
+        .build(static_local_data_store())
+}
+
+#[test]
+fn persim_berry_cures_confusion_of_holder() {
+    let mut battle = make_battle(0, team().unwrap(), team().unwrap()).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "pass"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "move 0"), Ok(()));
+
+    let expected_logs = serde_json::from_str::<Vec<LogMatch>>(
+        r#"[
+            "move|mon:Bulbasaur,player-2,1|name:Confuse Ray|target:Bulbasaur,player-1,1",
+            "start|mon:Bulbasaur,player-1,1|condition:Confusion",
+            "itemend|mon:Bulbasaur,player-1,1|item:Persim Berry|eat",
+            "end|mon:Bulbasaur,player-1,1|condition:Confusion",
+            "residual",
+            "turn|turn:2"
+        ]"#,
+    )
+    .unwrap();
+    assert_logs_since_turn_eq(&battle, 1, &expected_logs);
+}
@@ -14,15 +14,14 @@ use battler_test_utils::{
     static_local_data_store,
 };
 
-fn empoleon() -> Result<TeamData> {
-    serde_json::from_str(
+fn empoleon(item: &str) -> Result<TeamData> {
+    let mut team: TeamData = serde_json::from_str(
         r#"{
             "members": [
                 {
                     "name": "Empoleon",
                     "species": "Empoleon",
                     "ability": "Torrent",
-                    "item": "Power Anklet",
                     "moves": [
                         "Waterfall"
                     ],
@@ -32,7 +31,9 @@ fn empoleon() -> Result<TeamData> {
             ]
         }"#,
     )
-    .wrap_error()
+    .wrap_error()?;
+    team.members[0].item = Some(item.to_owned());
+    Ok(team)
 }
 
 fn bidoof() -> Result<TeamData> {
@@ -53,6 +54,24 @@ fn bidoof() -> Result<TeamData> {
     .wrap_error()
 }
 
+fn blastoise() -> Result<TeamData> {
+    serde_json::from_str(
+        r#"{
+            "members": [
+                {
+                    "name": "Blastoise",
+                    "species": "Blastoise",
+                    "ability": "No Ability",
+                    "moves": [],
+                    "nature": "Hardy",
+                    "level": 5
+                }
+            ]
+        }"#,
+    )
+    .wrap_error()
+}
+
 fn make_battle(seed: u64, team_1: TeamData, team_2: TeamData) -> Result<PublicCoreBattle<'static>> {
     TestBattleBuilder::new()
         .with_battle_type(BattleType::Singles)
@@ -69,7 +88,7 @@ fn make_battle(seed: u64, team_1: TeamData, team_2: TeamData) -> Result<PublicCo
 
 #[test]
 fn power_anklet_gives_8_additional_speed_evs() {
-    let mut battle = make_battle(0, empoleon().unwrap(), bidoof().unwrap()).unwrap();
+    let mut battle = make_battle(0, empoleon("Power Anklet").unwrap(), bidoof().unwrap()).unwrap();
     assert_matches::assert_matches!(battle.start(), Ok(()));
 
     assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 0"), Ok(()));
@@ -100,3 +119,37 @@ fn power_anklet_gives_8_additional_speed_evs() {
         )
     });
 }
+
+#[test]
+fn power_band_gives_8_additional_special_defense_evs() {
+    let mut battle = make_battle(0, empoleon("Power Band").unwrap(), blastoise().unwrap()).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 0"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "pass"), Ok(()));
+
+    let expected_logs = serde_json::from_str::<Vec<LogMatch>>(
+        r#"[
+            "move|mon:Empoleon,player-1,1|name:Waterfall|target:Blastoise,player-2,1",
+            "resisted|mon:Blastoise,player-2,1",
+            "split|side:1",
+            "damage|mon:Blastoise,player-2,1|health:0",
+            "damage|mon:Blastoise,player-2,1|health:0",
+            "faint|mon:Blastoise,player-2,1",
+            "exp|mon:Empoleon,player-1,1|exp:12",
+            "win|side:0"
+        ]"#,
+    )
+    .unwrap();
+    assert_logs_since_turn_eq(&battle, 1, &expected_logs);
+
+    assert_matches::assert_matches!(battle.player_data("player-1"), Ok(data) => {
+        pretty_assertions::assert_eq!(
+            data.mons[0].summary.evs,
+            StatTable {
+                spd: 11,
+                ..Default::default()
+            }
+        )
+    });
+}
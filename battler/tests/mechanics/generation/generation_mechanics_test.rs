@@ -0,0 +1,795 @@
+use anyhow::Result;
+use battler::{
+    BattleType,
+    CoreBattleEngineRandomizeBaseDamage,
+    CoreBattleEngineSpeedSortTieResolution,
+    Generation,
+    PublicCoreBattle,
+    TeamData,
+    WrapResultError,
+};
+use battler_test_utils::{
+    LogMatch,
+    TestBattleBuilder,
+    assert_logs_since_turn_eq,
+    get_controlled_rng_for_battle,
+    static_local_data_store,
+};
+
+fn alakazam() -> Result<TeamData> {
+    serde_json::from_str(
+        r#"{
+            "members": [
+                {
+                    "name": "Alakazam",
+                    "species": "Alakazam",
+                    "ability": "No Ability",
+                    "moves": [
+                        "Crunch",
+                        "Frost Breath",
+                        "Toxic",
+                        "Spore"
+                    ],
+                    "nature": "Hardy",
+                    "level": 50,
+                    "item": "Leftovers"
+                }
+            ]
+        }"#,
+    )
+    .wrap_error()
+}
+
+fn snorlax() -> Result<TeamData> {
+    serde_json::from_str(
+        r#"{
+            "members": [
+                {
+                    "name": "Snorlax",
+                    "species": "Snorlax",
+                    "ability": "No Ability",
+                    "moves": [
+                        "Toxic",
+                        "Splash"
+                    ],
+                    "nature": "Hardy",
+                    "level": 50,
+                    "item": "Leftovers"
+                }
+            ]
+        }"#,
+    )
+    .wrap_error()
+}
+
+fn jolteon() -> Result<TeamData> {
+    serde_json::from_str(
+        r#"{
+            "members": [
+                {
+                    "name": "Jolteon",
+                    "species": "Jolteon",
+                    "ability": "No Ability",
+                    "moves": [
+                        "Growl",
+                        "Double Team",
+                        "Sand Attack"
+                    ],
+                    "nature": "Hardy",
+                    "level": 50
+                }
+            ]
+        }"#,
+    )
+    .wrap_error()
+}
+
+fn tauros() -> Result<TeamData> {
+    serde_json::from_str(
+        r#"{
+            "members": [
+                {
+                    "name": "Tauros",
+                    "species": "Tauros",
+                    "ability": "No Ability",
+                    "moves": [
+                        "Swords Dance",
+                        "Tackle",
+                        "Growl"
+                    ],
+                    "nature": "Hardy",
+                    "level": 100
+                }
+            ]
+        }"#,
+    )
+    .wrap_error()
+}
+
+fn blastoise() -> Result<TeamData> {
+    serde_json::from_str(
+        r#"{
+            "members": [
+                {
+                    "name": "Blastoise",
+                    "species": "Blastoise",
+                    "ability": "No Ability",
+                    "moves": [
+                        "Surf"
+                    ],
+                    "nature": "Hardy",
+                    "level": 50
+                }
+            ]
+        }"#,
+    )
+    .wrap_error()
+}
+
+fn snorlax_pair() -> Result<TeamData> {
+    let mut team = snorlax()?;
+    team.members.push(team.members[0].clone());
+    Ok(team)
+}
+
+fn battle_builder(
+    mechanics: Generation,
+    seed: u64,
+    team_1: TeamData,
+    team_2: TeamData,
+) -> TestBattleBuilder {
+    TestBattleBuilder::new()
+        .with_battle_type(BattleType::Singles)
+        .with_seed(seed)
+        .with_team_validation(false)
+        .with_pass_allowed(true)
+        .with_controlled_rng(true)
+        .with_mechanics(mechanics)
+        .with_speed_sort_tie_resolution(CoreBattleEngineSpeedSortTieResolution::Keep)
+        .with_base_damage_randomization(CoreBattleEngineRandomizeBaseDamage::Max)
+        .add_player_to_side_1("player-1", "Player 1")
+        .add_player_to_side_2("player-2", "Player 2")
+        .with_team("player-1", team_1)
+        .with_team("player-2", team_2)
+}
+
+fn make_battle(
+    mechanics: Generation,
+    seed: u64,
+    team_1: TeamData,
+    team_2: TeamData,
+) -> Result<PublicCoreBattle<'static>> {
+    battle_builder(mechanics, seed, team_1, team_2).build(static_local_data_store())
+}
+
+#[test]
+fn damaging_moves_use_category_of_type_before_gen_4() {
+    let mut battle =
+        make_battle(Generation::Gen3, 0, alakazam().unwrap(), snorlax().unwrap()).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 0"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "move 1"), Ok(()));
+
+    // Crunch uses Alakazam's high Special Attack.
+    let expected_logs = serde_json::from_str::<Vec<LogMatch>>(
+        r#"[
+            "move|mon:Alakazam,player-1,1|name:Crunch|target:Snorlax,player-2,1",
+            "split|side:1",
+            "damage|mon:Snorlax,player-2,1|health:176/220",
+            "damage|mon:Snorlax,player-2,1|health:80/100",
+            "move|mon:Snorlax,player-2,1|name:Splash|target:Snorlax,player-2,1",
+            "activate|move:Splash",
+            "split|side:1",
+            "heal|mon:Snorlax,player-2,1|from:item:Leftovers|health:189/220",
+            "heal|mon:Snorlax,player-2,1|from:item:Leftovers|health:86/100",
+            "residual",
+            "turn|turn:2"
+        ]"#,
+    )
+    .unwrap();
+    assert_logs_since_turn_eq(&battle, 1, &expected_logs);
+}
+
+#[test]
+fn damaging_moves_use_own_category_after_gen_4() {
+    let mut battle =
+        make_battle(Generation::Gen9, 0, alakazam().unwrap(), snorlax().unwrap()).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 0"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "move 1"), Ok(()));
+
+    let expected_logs = serde_json::from_str::<Vec<LogMatch>>(
+        r#"[
+            "move|mon:Alakazam,player-1,1|name:Crunch|target:Snorlax,player-2,1",
+            "split|side:1",
+            "damage|mon:Snorlax,player-2,1|health:191/220",
+            "damage|mon:Snorlax,player-2,1|health:87/100",
+            "move|mon:Snorlax,player-2,1|name:Splash|target:Snorlax,player-2,1",
+            "activate|move:Splash",
+            "split|side:1",
+            "heal|mon:Snorlax,player-2,1|from:item:Leftovers|health:204/220",
+            "heal|mon:Snorlax,player-2,1|from:item:Leftovers|health:93/100",
+            "residual",
+            "turn|turn:2"
+        ]"#,
+    )
+    .unwrap();
+    assert_logs_since_turn_eq(&battle, 1, &expected_logs);
+}
+
+#[test]
+fn critical_hits_deal_double_damage_before_gen_6() {
+    let mut battle =
+        make_battle(Generation::Gen5, 0, alakazam().unwrap(), snorlax().unwrap()).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 1"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "move 1"), Ok(()));
+
+    let expected_logs = serde_json::from_str::<Vec<LogMatch>>(
+        r#"[
+            "move|mon:Alakazam,player-1,1|name:Frost Breath|target:Snorlax,player-2,1",
+            "crit|mon:Snorlax,player-2,1",
+            "split|side:1",
+            "damage|mon:Snorlax,player-2,1|health:152/220",
+            "damage|mon:Snorlax,player-2,1|health:70/100",
+            "move|mon:Snorlax,player-2,1|name:Splash|target:Snorlax,player-2,1",
+            "activate|move:Splash",
+            "split|side:1",
+            "heal|mon:Snorlax,player-2,1|from:item:Leftovers|health:165/220",
+            "heal|mon:Snorlax,player-2,1|from:item:Leftovers|health:75/100",
+            "residual",
+            "turn|turn:2"
+        ]"#,
+    )
+    .unwrap();
+    assert_logs_since_turn_eq(&battle, 1, &expected_logs);
+}
+
+#[test]
+fn critical_hit_multiplier_depends_on_level_in_gen_1() {
+    let mut battle =
+        make_battle(Generation::Gen1, 0, alakazam().unwrap(), snorlax().unwrap()).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 1"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "move 1"), Ok(()));
+
+    let expected_logs = serde_json::from_str::<Vec<LogMatch>>(
+        r#"[
+            "move|mon:Alakazam,player-1,1|name:Frost Breath|target:Snorlax,player-2,1",
+            "crit|mon:Snorlax,player-2,1",
+            "split|side:1",
+            "damage|mon:Snorlax,player-2,1|health:155/220",
+            "damage|mon:Snorlax,player-2,1|health:71/100",
+            "move|mon:Snorlax,player-2,1|name:Splash|target:Snorlax,player-2,1",
+            "activate|move:Splash",
+            "split|side:1",
+            "heal|mon:Snorlax,player-2,1|from:item:Leftovers|health:168/220",
+            "heal|mon:Snorlax,player-2,1|from:item:Leftovers|health:77/100",
+            "residual",
+            "turn|turn:2"
+        ]"#,
+    )
+    .unwrap();
+    assert_logs_since_turn_eq(&battle, 1, &expected_logs);
+}
+
+#[test]
+fn residual_effects_resolve_one_mon_at_a_time_before_gen_5() {
+    let mut battle =
+        make_battle(Generation::Gen4, 0, alakazam().unwrap(), snorlax().unwrap()).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 2"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "move 0"), Ok(()));
+
+    let expected_logs = serde_json::from_str::<Vec<LogMatch>>(
+        r#"[
+            "move|mon:Alakazam,player-1,1|name:Toxic|target:Snorlax,player-2,1",
+            "status|mon:Snorlax,player-2,1|status:Bad Poison",
+            "move|mon:Snorlax,player-2,1|name:Toxic|target:Alakazam,player-1,1",
+            "status|mon:Alakazam,player-1,1|status:Bad Poison",
+            "split|side:0",
+            "damage|mon:Alakazam,player-1,1|from:status:Bad Poison|health:108/115",
+            "damage|mon:Alakazam,player-1,1|from:status:Bad Poison|health:94/100",
+            "split|side:0",
+            "heal|mon:Alakazam,player-1,1|from:item:Leftovers|health:115/115",
+            "heal|mon:Alakazam,player-1,1|from:item:Leftovers|health:100/100",
+            "split|side:1",
+            "damage|mon:Snorlax,player-2,1|from:status:Bad Poison|health:207/220",
+            "damage|mon:Snorlax,player-2,1|from:status:Bad Poison|health:95/100",
+            "split|side:1",
+            "heal|mon:Snorlax,player-2,1|from:item:Leftovers|health:220/220",
+            "heal|mon:Snorlax,player-2,1|from:item:Leftovers|health:100/100",
+            "residual",
+            "turn|turn:2"
+        ]"#,
+    )
+    .unwrap();
+    assert_logs_since_turn_eq(&battle, 1, &expected_logs);
+}
+
+#[test]
+fn residual_effects_resolve_one_effect_at_a_time_after_gen_5() {
+    let mut battle =
+        make_battle(Generation::Gen9, 0, alakazam().unwrap(), snorlax().unwrap()).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 2"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "move 0"), Ok(()));
+
+    let expected_logs = serde_json::from_str::<Vec<LogMatch>>(
+        r#"[
+            "move|mon:Alakazam,player-1,1|name:Toxic|target:Snorlax,player-2,1",
+            "status|mon:Snorlax,player-2,1|status:Bad Poison",
+            "move|mon:Snorlax,player-2,1|name:Toxic|target:Alakazam,player-1,1",
+            "status|mon:Alakazam,player-1,1|status:Bad Poison",
+            "split|side:0",
+            "damage|mon:Alakazam,player-1,1|from:status:Bad Poison|health:108/115",
+            "damage|mon:Alakazam,player-1,1|from:status:Bad Poison|health:94/100",
+            "split|side:1",
+            "damage|mon:Snorlax,player-2,1|from:status:Bad Poison|health:207/220",
+            "damage|mon:Snorlax,player-2,1|from:status:Bad Poison|health:95/100",
+            "split|side:0",
+            "heal|mon:Alakazam,player-1,1|from:item:Leftovers|health:115/115",
+            "heal|mon:Alakazam,player-1,1|from:item:Leftovers|health:100/100",
+            "split|side:1",
+            "heal|mon:Snorlax,player-2,1|from:item:Leftovers|health:220/220",
+            "heal|mon:Snorlax,player-2,1|from:item:Leftovers|health:100/100",
+            "residual",
+            "turn|turn:2"
+        ]"#,
+    )
+    .unwrap();
+    assert_logs_since_turn_eq(&battle, 1, &expected_logs);
+}
+
+#[test]
+fn sleep_lasts_up_to_seven_turns_in_gen_1() {
+    let mut battle =
+        make_battle(Generation::Gen1, 2, alakazam().unwrap(), snorlax().unwrap()).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 3"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "move 1"), Ok(()));
+    for _ in 0..7 {
+        assert_matches::assert_matches!(battle.set_player_choice("player-1", "pass"), Ok(()));
+        assert_matches::assert_matches!(battle.set_player_choice("player-2", "move 1"), Ok(()));
+    }
+
+    let expected_logs = serde_json::from_str::<Vec<LogMatch>>(
+        r#"[
+            "move|mon:Alakazam,player-1,1|name:Spore|target:Snorlax,player-2,1",
+            "status|mon:Snorlax,player-2,1|status:Sleep",
+            "cant|mon:Snorlax,player-2,1|from:status:Sleep",
+            "residual",
+            "turn|turn:2",
+            "continue",
+            "cant|mon:Snorlax,player-2,1|from:status:Sleep",
+            "residual",
+            "turn|turn:3",
+            "continue",
+            "cant|mon:Snorlax,player-2,1|from:status:Sleep",
+            "residual",
+            "turn|turn:4",
+            "continue",
+            "cant|mon:Snorlax,player-2,1|from:status:Sleep",
+            "residual",
+            "turn|turn:5",
+            "continue",
+            "cant|mon:Snorlax,player-2,1|from:status:Sleep",
+            "residual",
+            "turn|turn:6",
+            "continue",
+            "cant|mon:Snorlax,player-2,1|from:status:Sleep",
+            "residual",
+            "turn|turn:7",
+            "continue",
+            "cant|mon:Snorlax,player-2,1|from:status:Sleep",
+            "residual",
+            "turn|turn:8",
+            "continue",
+            "curestatus|mon:Snorlax,player-2,1|status:Sleep",
+            "move|mon:Snorlax,player-2,1|name:Splash|target:Snorlax,player-2,1",
+            "activate|move:Splash",
+            "residual",
+            "turn|turn:9"
+        ]"#,
+    )
+    .unwrap();
+    assert_logs_since_turn_eq(&battle, 1, &expected_logs);
+}
+
+#[test]
+fn accuracy_and_evasion_use_stat_stage_ratios_in_gen_1() {
+    let mut battle =
+        make_battle(Generation::Gen1, 0, jolteon().unwrap(), jolteon().unwrap()).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "pass"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "move 1"), Ok(()));
+
+    let rng = get_controlled_rng_for_battle(&mut battle).unwrap();
+    rng.insert_fake_values_relative_to_sequence_count([(1, 0)]);
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "pass"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "move 2"), Ok(()));
+
+    // Accuracy is 100% * 66% * 66% = 43%, which is 109/256.
+    let rng = get_controlled_rng_for_battle(&mut battle).unwrap();
+    rng.insert_fake_values_relative_to_sequence_count([(1, 108)]);
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 0"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "pass"), Ok(()));
+
+    let rng = get_controlled_rng_for_battle(&mut battle).unwrap();
+    rng.insert_fake_values_relative_to_sequence_count([(1, 109)]);
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 0"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "pass"), Ok(()));
+
+    let expected_logs = serde_json::from_str::<Vec<LogMatch>>(
+        r#"[
+            "move|mon:Jolteon,player-2,1|name:Double Team|target:Jolteon,player-2,1",
+            "boost|mon:Jolteon,player-2,1|stat:eva|by:1",
+            "residual",
+            "turn|turn:2",
+            "continue",
+            "move|mon:Jolteon,player-2,1|name:Sand Attack|target:Jolteon,player-1,1",
+            "unboost|mon:Jolteon,player-1,1|stat:acc|by:1",
+            "residual",
+            "turn|turn:3",
+            "continue",
+            "move|mon:Jolteon,player-1,1|name:Growl",
+            "unboost|mon:Jolteon,player-2,1|stat:atk|by:1",
+            "residual",
+            "turn|turn:4",
+            "continue",
+            "move|mon:Jolteon,player-1,1|name:Growl|noanim",
+            "miss|mon:Jolteon,player-2,1",
+            "residual",
+            "turn|turn:5"
+        ]"#,
+    )
+    .unwrap();
+    assert_logs_since_turn_eq(&battle, 1, &expected_logs);
+}
+
+#[test]
+fn accuracy_and_evasion_use_truncated_percentages_in_gen_2() {
+    let mut battle =
+        make_battle(Generation::Gen2, 0, jolteon().unwrap(), jolteon().unwrap()).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "pass"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "move 1"), Ok(()));
+
+    let rng = get_controlled_rng_for_battle(&mut battle).unwrap();
+    rng.insert_fake_values_relative_to_sequence_count([(1, 0)]);
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "pass"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "move 2"), Ok(()));
+
+    // Accuracy is 100% * 75% * 75% = 56%.
+    let rng = get_controlled_rng_for_battle(&mut battle).unwrap();
+    rng.insert_fake_values_relative_to_sequence_count([(1, 55)]);
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 0"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "pass"), Ok(()));
+
+    let rng = get_controlled_rng_for_battle(&mut battle).unwrap();
+    rng.insert_fake_values_relative_to_sequence_count([(1, 56)]);
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 0"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "pass"), Ok(()));
+
+    let expected_logs = serde_json::from_str::<Vec<LogMatch>>(
+        r#"[
+            "move|mon:Jolteon,player-2,1|name:Double Team|target:Jolteon,player-2,1",
+            "boost|mon:Jolteon,player-2,1|stat:eva|by:1",
+            "residual",
+            "turn|turn:2",
+            "continue",
+            "move|mon:Jolteon,player-2,1|name:Sand Attack|target:Jolteon,player-1,1",
+            "unboost|mon:Jolteon,player-1,1|stat:acc|by:1",
+            "residual",
+            "turn|turn:3",
+            "continue",
+            "move|mon:Jolteon,player-1,1|name:Growl",
+            "unboost|mon:Jolteon,player-2,1|stat:atk|by:1",
+            "residual",
+            "turn|turn:4",
+            "continue",
+            "move|mon:Jolteon,player-1,1|name:Growl|noanim",
+            "miss|mon:Jolteon,player-2,1",
+            "residual",
+            "turn|turn:5"
+        ]"#,
+    )
+    .unwrap();
+    assert_logs_since_turn_eq(&battle, 1, &expected_logs);
+}
+
+#[test]
+fn boosted_stats_are_capped_at_999_before_gen_3() {
+    let mut team = tauros().unwrap();
+    team.members[0].evs.atk = 252;
+    team.members[0].ivs.atk = 31;
+    let mut target = snorlax().unwrap();
+    target.members[0].level = 100;
+    let mut battle = make_battle(Generation::Gen2, 0, team, target).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    for _ in 0..3 {
+        assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 0"), Ok(()));
+        assert_matches::assert_matches!(battle.set_player_choice("player-2", "move 1"), Ok(()));
+    }
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 1"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "move 1"), Ok(()));
+
+    // Attack at +6 is capped at 999. Uncapped, Tackle would knock out Snorlax.
+    let expected_logs = serde_json::from_str::<Vec<LogMatch>>(
+        r#"[
+            "move|mon:Tauros,player-1,1|name:Swords Dance|target:Tauros,player-1,1",
+            "boost|mon:Tauros,player-1,1|stat:atk|by:2",
+            "move|mon:Snorlax,player-2,1|name:Splash|target:Snorlax,player-2,1",
+            "activate|move:Splash",
+            "residual",
+            "turn|turn:2",
+            "continue",
+            "move|mon:Tauros,player-1,1|name:Swords Dance|target:Tauros,player-1,1",
+            "boost|mon:Tauros,player-1,1|stat:atk|by:2",
+            "move|mon:Snorlax,player-2,1|name:Splash|target:Snorlax,player-2,1",
+            "activate|move:Splash",
+            "residual",
+            "turn|turn:3",
+            "continue",
+            "move|mon:Tauros,player-1,1|name:Swords Dance|target:Tauros,player-1,1",
+            "boost|mon:Tauros,player-1,1|stat:atk|by:2",
+            "move|mon:Snorlax,player-2,1|name:Splash|target:Snorlax,player-2,1",
+            "activate|move:Splash",
+            "residual",
+            "turn|turn:4",
+            "continue",
+            "move|mon:Tauros,player-1,1|name:Tackle|target:Snorlax,player-2,1",
+            "split|side:1",
+            "damage|mon:Snorlax,player-2,1|health:55/430",
+            "damage|mon:Snorlax,player-2,1|health:13/100",
+            "move|mon:Snorlax,player-2,1|name:Splash|target:Snorlax,player-2,1",
+            "activate|move:Splash",
+            "split|side:1",
+            "heal|mon:Snorlax,player-2,1|from:item:Leftovers|health:81/430",
+            "heal|mon:Snorlax,player-2,1|from:item:Leftovers|health:19/100",
+            "residual",
+            "turn|turn:5"
+        ]"#,
+    )
+    .unwrap();
+    assert_logs_since_turn_eq(&battle, 1, &expected_logs);
+}
+
+#[test]
+fn negative_stat_stages_use_truncated_percentages_before_gen_3() {
+    let mut team = tauros().unwrap();
+    team.members[0].evs.atk = 252;
+    team.members[0].ivs.atk = 31;
+    let mut battle = make_battle(Generation::Gen1, 0, team, tauros().unwrap()).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    let rng = get_controlled_rng_for_battle(&mut battle).unwrap();
+    rng.insert_fake_values_relative_to_sequence_count([(1, 0)]);
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "pass"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "move 2"), Ok(()));
+
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 1"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "pass"), Ok(()));
+
+    // Attack at -1 is multiplied by 66% rather than 2/3.
+    let expected_logs = serde_json::from_str::<Vec<LogMatch>>(
+        r#"[
+            "move|mon:Tauros,player-2,1|name:Growl",
+            "unboost|mon:Tauros,player-1,1|stat:atk|by:1",
+            "residual",
+            "turn|turn:2",
+            "continue",
+            "move|mon:Tauros,player-1,1|name:Tackle|target:Tauros,player-2,1",
+            "split|side:1",
+            "damage|mon:Tauros,player-2,1|health:208/260",
+            "damage|mon:Tauros,player-2,1|health:80/100",
+            "residual",
+            "turn|turn:3"
+        ]"#,
+    )
+    .unwrap();
+    assert_logs_since_turn_eq(&battle, 1, &expected_logs);
+}
+
+#[test]
+fn frozen_mons_never_thaw_in_gen_1() {
+    let mut target = snorlax().unwrap();
+    target.members[0].persistent_battle_data.status = Some("frz".to_owned());
+    let mut battle = make_battle(Generation::Gen1, 0, alakazam().unwrap(), target).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    // This roll would thaw the Mon in any later generation.
+    let rng = get_controlled_rng_for_battle(&mut battle).unwrap();
+    rng.insert_fake_values_relative_to_sequence_count([(1, 0)]);
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "pass"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "move 1"), Ok(()));
+
+    let expected_logs = serde_json::from_str::<Vec<LogMatch>>(
+        r#"[
+            "cant|mon:Snorlax,player-2,1|from:status:Freeze",
+            "residual",
+            "turn|turn:2"
+        ]"#,
+    )
+    .unwrap();
+    assert_logs_since_turn_eq(&battle, 1, &expected_logs);
+}
+
+#[test]
+fn frozen_mons_thaw_with_25_in_256_chance_in_gen_2() {
+    let mut target = snorlax().unwrap();
+    target.members[0].persistent_battle_data.status = Some("frz".to_owned());
+    let mut battle = make_battle(Generation::Gen2, 0, alakazam().unwrap(), target).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    // This roll would thaw the Mon after Gen 2, where the chance is 1/5.
+    let rng = get_controlled_rng_for_battle(&mut battle).unwrap();
+    rng.insert_fake_values_relative_to_sequence_count([(1, 25)]);
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "pass"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "move 1"), Ok(()));
+
+    let rng = get_controlled_rng_for_battle(&mut battle).unwrap();
+    rng.insert_fake_values_relative_to_sequence_count([(1, 24)]);
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "pass"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "move 1"), Ok(()));
+
+    let expected_logs = serde_json::from_str::<Vec<LogMatch>>(
+        r#"[
+            "cant|mon:Snorlax,player-2,1|from:status:Freeze",
+            "residual",
+            "turn|turn:2",
+            "continue",
+            "curestatus|mon:Snorlax,player-2,1|status:Freeze",
+            "move|mon:Snorlax,player-2,1|name:Splash|target:Snorlax,player-2,1",
+            "activate|move:Splash",
+            "residual",
+            "turn|turn:3"
+        ]"#,
+    )
+    .unwrap();
+    assert_logs_since_turn_eq(&battle, 1, &expected_logs);
+}
+
+#[test]
+fn spread_moves_deal_half_damage_before_gen_4() {
+    let mut battle = battle_builder(
+        Generation::Gen3,
+        0,
+        blastoise().unwrap(),
+        snorlax_pair().unwrap(),
+    )
+    .with_battle_type(BattleType::Doubles)
+    .build(static_local_data_store())
+    .unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 0"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "pass;pass"), Ok(()));
+
+    let expected_logs = serde_json::from_str::<Vec<LogMatch>>(
+        r#"[
+            "move|mon:Blastoise,player-1,1|name:Surf|spread:Snorlax,player-2,1;Snorlax,player-2,2",
+            "split|side:1",
+            "damage|mon:Snorlax,player-2,1|health:196/220",
+            "damage|mon:Snorlax,player-2,1|health:90/100",
+            "split|side:1",
+            "damage|mon:Snorlax,player-2,2|health:196/220",
+            "damage|mon:Snorlax,player-2,2|health:90/100",
+            "split|side:1",
+            "heal|mon:Snorlax,player-2,1|from:item:Leftovers|health:209/220",
+            "heal|mon:Snorlax,player-2,1|from:item:Leftovers|health:95/100",
+            "split|side:1",
+            "heal|mon:Snorlax,player-2,2|from:item:Leftovers|health:209/220",
+            "heal|mon:Snorlax,player-2,2|from:item:Leftovers|health:95/100",
+            "residual",
+            "turn|turn:2"
+        ]"#,
+    )
+    .unwrap();
+    assert_logs_since_turn_eq(&battle, 1, &expected_logs);
+}
+
+#[test]
+fn spread_moves_deal_three_quarters_damage_after_gen_4() {
+    let mut battle = battle_builder(
+        Generation::Gen4,
+        0,
+        blastoise().unwrap(),
+        snorlax_pair().unwrap(),
+    )
+    .with_battle_type(BattleType::Doubles)
+    .build(static_local_data_store())
+    .unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 0"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "pass;pass"), Ok(()));
+
+    let expected_logs = serde_json::from_str::<Vec<LogMatch>>(
+        r#"[
+            "move|mon:Blastoise,player-1,1|name:Surf|spread:Snorlax,player-2,1;Snorlax,player-2,2",
+            "split|side:1",
+            "damage|mon:Snorlax,player-2,1|health:184/220",
+            "damage|mon:Snorlax,player-2,1|health:84/100",
+            "split|side:1",
+            "damage|mon:Snorlax,player-2,2|health:184/220",
+            "damage|mon:Snorlax,player-2,2|health:84/100",
+            "split|side:1",
+            "heal|mon:Snorlax,player-2,1|from:item:Leftovers|health:197/220",
+            "heal|mon:Snorlax,player-2,1|from:item:Leftovers|health:90/100",
+            "split|side:1",
+            "heal|mon:Snorlax,player-2,2|from:item:Leftovers|health:197/220",
+            "heal|mon:Snorlax,player-2,2|from:item:Leftovers|health:90/100",
+            "residual",
+            "turn|turn:2"
+        ]"#,
+    )
+    .unwrap();
+    assert_logs_since_turn_eq(&battle, 1, &expected_logs);
+}
+
+#[test]
+fn damage_is_randomized_between_217_and_255_before_gen_3() {
+    let mut battle = battle_builder(Generation::Gen2, 0, alakazam().unwrap(), snorlax().unwrap())
+        .with_base_damage_randomization(CoreBattleEngineRandomizeBaseDamage::Min)
+        .build(static_local_data_store())
+        .unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 0"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "move 1"), Ok(()));
+
+    // Minimum damage is 217/255 of maximum damage.
+    let expected_logs = serde_json::from_str::<Vec<LogMatch>>(
+        r#"[
+            "move|mon:Alakazam,player-1,1|name:Crunch|target:Snorlax,player-2,1",
+            "split|side:1",
+            "damage|mon:Snorlax,player-2,1|health:183/220",
+            "damage|mon:Snorlax,player-2,1|health:84/100",
+            "move|mon:Snorlax,player-2,1|name:Splash|target:Snorlax,player-2,1",
+            "activate|move:Splash",
+            "split|side:1",
+            "heal|mon:Snorlax,player-2,1|from:item:Leftovers|health:196/220",
+            "heal|mon:Snorlax,player-2,1|from:item:Leftovers|health:90/100",
+            "residual",
+            "turn|turn:2"
+        ]"#,
+    )
+    .unwrap();
+    assert_logs_since_turn_eq(&battle, 1, &expected_logs);
+
+    let mut battle =
+        make_battle(Generation::Gen2, 0, alakazam().unwrap(), snorlax().unwrap()).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 0"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "move 1"), Ok(()));
+
+    let expected_logs = serde_json::from_str::<Vec<LogMatch>>(
+        r#"[
+            "move|mon:Alakazam,player-1,1|name:Crunch|target:Snorlax,player-2,1",
+            "split|side:1",
+            "damage|mon:Snorlax,player-2,1|health:176/220",
+            "damage|mon:Snorlax,player-2,1|health:80/100",
+            "move|mon:Snorlax,player-2,1|name:Splash|target:Snorlax,player-2,1",
+            "activate|move:Splash",
+            "split|side:1",
+            "heal|mon:Snorlax,player-2,1|from:item:Leftovers|health:189/220",
+            "heal|mon:Snorlax,player-2,1|from:item:Leftovers|health:86/100",
+            "residual",
+            "turn|turn:2"
+        ]"#,
+    )
+    .unwrap();
+    assert_logs_since_turn_eq(&battle, 1, &expected_logs);
+}
@@ -192,3 +192,53 @@ fn aromatherapy_activates_ally_sap_sipper() {
     .unwrap();
     assert_logs_since_turn_eq(&battle, 1, &expected_logs);
 }
+
+#[test]
+fn aromatherapy_cures_ally_behind_substitute_with_infiltrator() {
+    let mut user = team().unwrap();
+    user.members[0].ability = "Infiltrator".to_owned();
+    user.members[1].moves = Vec::from_iter(["Substitute".to_owned()]);
+    let mut battle = make_battle(BattleType::Doubles, 0, user, team().unwrap()).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "pass;pass"), Ok(()));
+    assert_matches::assert_matches!(
+        battle.set_player_choice("player-2", "move 3,2;pass"),
+        Ok(())
+    );
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "pass;move 0"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "pass;pass"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 0;pass"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "pass;pass"), Ok(()));
+
+    let expected_logs = serde_json::from_str::<Vec<LogMatch>>(
+        r#"[
+            "move|mon:Miltank,player-2,1|name:Toxic|target:Sceptile,player-1,2",
+            "status|mon:Sceptile,player-1,2|status:Bad Poison",
+            "split|side:0",
+            "damage|mon:Sceptile,player-1,2|from:status:Bad Poison|health:122/130",
+            "damage|mon:Sceptile,player-1,2|from:status:Bad Poison|health:94/100",
+            "residual",
+            "turn|turn:2",
+            "continue",
+            "move|mon:Sceptile,player-1,2|name:Substitute|target:Sceptile,player-1,2",
+            "start|mon:Sceptile,player-1,2|move:Substitute",
+            "split|side:0",
+            "damage|mon:Sceptile,player-1,2|health:90/130",
+            "damage|mon:Sceptile,player-1,2|health:70/100",
+            "split|side:0",
+            "damage|mon:Sceptile,player-1,2|from:status:Bad Poison|health:74/130",
+            "damage|mon:Sceptile,player-1,2|from:status:Bad Poison|health:57/100",
+            "residual",
+            "turn|turn:3",
+            "continue",
+            "move|mon:Miltank,player-1,1|name:Aromatherapy",
+            "activate|move:Aromatherapy|of:Miltank,player-1,1",
+            "curestatus|mon:Sceptile,player-1,2|status:Bad Poison|from:move:Aromatherapy|of:Miltank,player-1,1",
+            "residual",
+            "turn|turn:4"
+        ]"#,
+    )
+    .unwrap();
+    assert_logs_since_turn_eq(&battle, 1, &expected_logs);
+}
@@ -0,0 +1,120 @@
+use anyhow::Result;
+use battler::{
+    BattleType,
+    CoreBattleEngineSpeedSortTieResolution,
+    PublicCoreBattle,
+    TeamData,
+    WrapResultError,
+};
+use battler_test_utils::{
+    LogMatch,
+    TestBattleBuilder,
+    assert_logs_since_turn_eq,
+    static_local_data_store,
+};
+
+fn volcarona() -> Result<TeamData> {
+    serde_json::from_str(
+        r#"{
+            "members": [
+                {
+                    "name": "Volcarona",
+                    "species": "Volcarona",
+                    "ability": "No Ability",
+                    "moves": [
+                        "Rage Powder",
+                        "Tackle"
+                    ],
+                    "nature": "Hardy",
+                    "level": 50
+                },
+                {
+                    "name": "Minun",
+                    "species": "Minun",
+                    "ability": "No Ability",
+                    "moves": [
+                        "Tackle"
+                    ],
+                    "nature": "Hardy",
+                    "level": 50
+                }
+            ]
+        }"#,
+    )
+    .wrap_error()
+}
+
+fn plusle() -> Result<TeamData> {
+    serde_json::from_str(
+        r#"{
+            "members": [
+                {
+                    "name": "Plusle",
+                    "species": "Plusle",
+                    "ability": "No Ability",
+                    "moves": [
+                        "Tackle"
+                    ],
+                    "nature": "Hardy",
+                    "level": 50
+                },
+                {
+                    "name": "Minun",
+                    "species": "Minun",
+                    "ability": "No Ability",
+                    "moves": [
+                        "Tackle"
+                    ],
+                    "nature": "Hardy",
+                    "level": 50
+                }
+            ]
+        }"#,
+    )
+    .wrap_error()
+}
+
+fn make_battle(seed: u64, team_1: TeamData, team_2: TeamData) -> Result<PublicCoreBattle<'static>> {
+    TestBattleBuilder::new()
+        .with_battle_type(BattleType::Doubles)
+        .with_seed(seed)
+        .with_team_validation(false)
+        .with_pass_allowed(true)
+        .with_speed_sort_tie_resolution(CoreBattleEngineSpeedSortTieResolution::Keep)
+        .add_player_to_side_1("player-1", "Player 1")
+        .add_player_to_side_2("player-2", "Player 2")
+        .with_team("player-1", team_1)
+        .with_team("player-2", team_2)
+        .build(static_local_data_store())
+}
+
+#[test]
+fn rage_powder_redirects_foe_moves_to_user() {
+    let mut battle = make_battle(0, volcarona().unwrap(), plusle().unwrap()).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 0;pass"), Ok(()));
+    assert_matches::assert_matches!(
+        battle.set_player_choice("player-2", "move 0,2;move 0,2"),
+        Ok(())
+    );
+
+    let expected_logs = serde_json::from_str::<Vec<LogMatch>>(
+        r#"[
+            "move|mon:Volcarona,player-1,1|name:Rage Powder|target:Volcarona,player-1,1",
+            "singleturn|mon:Volcarona,player-1,1|move:Rage Powder",
+            "move|mon:Plusle,player-2,1|name:Tackle|target:Volcarona,player-1,1",
+            "split|side:0",
+            "damage|mon:Volcarona,player-1,1|health:131/145",
+            "damage|mon:Volcarona,player-1,1|health:91/100",
+            "move|mon:Minun,player-2,2|name:Tackle|target:Volcarona,player-1,1",
+            "split|side:0",
+            "damage|mon:Volcarona,player-1,1|health:120/145",
+            "damage|mon:Volcarona,player-1,1|health:83/100",
+            "residual",
+            "turn|turn:2"
+        ]"#,
+    )
+    .unwrap();
+    assert_logs_since_turn_eq(&battle, 1, &expected_logs);
+}
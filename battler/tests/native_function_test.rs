@@ -0,0 +1,217 @@
+use std::sync::{
+    Arc,
+    atomic::{
+        AtomicU64,
+        Ordering,
+    },
+};
+
+use anyhow::Result;
+use battler::{
+    BattleType,
+    DataStore,
+    FunctionContext,
+    FunctionSignature,
+    Id,
+    MoveData,
+    NativeFunctionRegistry,
+    PublicCoreBattle,
+    TeamData,
+    WrapResultError,
+    effect::fxlang::Value,
+};
+use battler_test_utils::{
+    LogMatch,
+    TestBattleBuilder,
+    TestDataStore,
+    assert_logs_since_turn_eq,
+};
+
+fn team() -> Result<TeamData> {
+    serde_json::from_str(
+        r#"{
+            "members": [
+                {
+                    "name": "Venusaur",
+                    "species": "Venusaur",
+                    "ability": "Overgrow",
+                    "moves": [
+                        "Native Move",
+                        "Missing Native Move",
+                        "Counted Move"
+                    ],
+                    "nature": "Hardy",
+                    "level": 50
+                }
+            ]
+        }"#,
+    )
+    .wrap_error()
+}
+
+fn test_move(name: &str, on_hit: &[&str]) -> Result<MoveData> {
+    let mut move_data: MoveData = serde_json::from_str(
+        r#"{
+            "name": "",
+            "category": "Status",
+            "primary_type": "Normal",
+            "base_power": 0,
+            "accuracy": "exempt",
+            "pp": 5,
+            "target": "Normal",
+            "flags": []
+        }"#,
+    )
+    .wrap_error()?;
+    move_data.name = name.to_owned();
+    move_data.effect = serde_json::from_value(serde_json::json!({
+        "callbacks": {
+            "on_hit": on_hit,
+        },
+    }))
+    .wrap_error()?;
+    Ok(move_data)
+}
+
+fn sum(mut context: FunctionContext) -> Result<Option<Value>> {
+    let mut total = 0;
+    while let Some(value) = context.pop_front() {
+        total += value.integer_u64()?;
+    }
+    Ok(Some(Value::UFraction(total.into())))
+}
+
+fn native_functions(counter: Arc<AtomicU64>) -> Result<NativeFunctionRegistry> {
+    let mut native_functions = NativeFunctionRegistry::default();
+    native_functions.register(
+        "sum",
+        FunctionSignature {
+            required_args: 1,
+            returns_value: true,
+        },
+        sum,
+    )?;
+    native_functions.register(
+        "count",
+        FunctionSignature {
+            required_args: 0,
+            returns_value: true,
+        },
+        move |_| {
+            let count = counter.fetch_add(1, Ordering::Relaxed) + 1;
+            Ok(Some(Value::UFraction(count.into())))
+        },
+    )?;
+    Ok(native_functions)
+}
+
+fn make_battle(data: &dyn DataStore) -> Result<PublicCoreBattle<'_>> {
+    make_battle_with_counter(data, Arc::new(AtomicU64::new(0)))
+}
+
+fn make_battle_with_counter(
+    data: &dyn DataStore,
+    counter: Arc<AtomicU64>,
+) -> Result<PublicCoreBattle<'_>> {
+    TestBattleBuilder::new()
+        .with_battle_type(BattleType::Singles)
+        .with_seed(0)
+        .with_pass_allowed(true)
+        .with_team_validation(false)
+        .with_native_functions(native_functions(counter)?)
+        .add_player_to_side_1("player-1", "Player 1")
+        .add_player_to_side_2("player-2", "Player 2")
+        .with_team("player-1", team()?)
+        .with_team("player-2", team()?)
+        .build(data)
+}
+
+fn make_data_store() -> Result<TestDataStore> {
+    let mut data = TestDataStore::new_from_env("DATA_DIR")?;
+    data.add_fake_move(
+        Id::from("Native Move"),
+        test_move(
+            "Native Move",
+            &["log_activate: str('total:{}', func_call(sum: 1 2 3))"],
+        )?,
+    );
+    data.add_fake_move(
+        Id::from("Missing Native Move"),
+        test_move("Missing Native Move", &["missing: 1"])?,
+    );
+    data.add_fake_move(
+        Id::from("Counted Move"),
+        test_move(
+            "Counted Move",
+            &["log_activate: str('count:{}', func_call(count))"],
+        )?,
+    );
+    Ok(data)
+}
+
+#[test]
+fn native_functions_can_be_called_from_effects() {
+    let data = make_data_store().unwrap();
+    let mut battle = make_battle(&data).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 0"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "pass"), Ok(()));
+
+    let expected_logs = serde_json::from_str::<Vec<LogMatch>>(
+        r#"[
+            "move|mon:Venusaur,player-1,1|name:Native Move|target:Venusaur,player-2,1",
+            "activate|move:Native Move|total:6",
+            "residual",
+            "turn|turn:2"
+        ]"#,
+    )
+    .unwrap();
+    assert_logs_since_turn_eq(&battle, 1, &expected_logs);
+}
+
+#[test]
+fn unregistered_functions_fail_evaluation() {
+    let data = make_data_store().unwrap();
+    let mut battle = make_battle(&data).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 1"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "pass"), Ok(()));
+
+    let expected_logs = serde_json::from_str::<Vec<LogMatch>>(
+        r#"[
+            "move|mon:Venusaur,player-1,1|name:Missing Native Move|target:Venusaur,player-2,1",
+            "debug|event:Hit|effect:Missing Native Move|error:error on statement 1: undefined function: missing",
+            "residual",
+            "turn|turn:2"
+        ]"#,
+    )
+    .unwrap();
+    assert_logs_since_turn_eq(&battle, 1, &expected_logs);
+}
+
+#[test]
+fn native_functions_can_capture_state() {
+    let data = make_data_store().unwrap();
+    let counter = Arc::new(AtomicU64::new(10));
+    let mut battle = make_battle_with_counter(&data, counter.clone()).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 2"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "move 2"), Ok(()));
+
+    let expected_logs = serde_json::from_str::<Vec<LogMatch>>(
+        r#"[
+            "move|mon:Venusaur,player-1,1|name:Counted Move|target:Venusaur,player-2,1",
+            "activate|move:Counted Move|count:11",
+            "move|mon:Venusaur,player-2,1|name:Counted Move|target:Venusaur,player-1,1",
+            "activate|move:Counted Move|count:12",
+            "residual",
+            "turn|turn:2"
+        ]"#,
+    )
+    .unwrap();
+    assert_logs_since_turn_eq(&battle, 1, &expected_logs);
+    assert_eq!(counter.load(Ordering::Relaxed), 12);
+}
@@ -0,0 +1,300 @@
+use std::{
+    env,
+    fs,
+    path::PathBuf,
+};
+
+use anyhow::Result;
+use battler::{
+    BattleType,
+    DataStore,
+    DataStoreByName,
+    Id,
+    PublicCoreBattle,
+    TeamData,
+    WrapResultError,
+};
+use battler_local_data::{
+    DataLayer,
+    OverlayDataStore,
+    ResourceSource,
+    ResourceType,
+};
+use battler_test_utils::TestBattleBuilder;
+use serde_json::json;
+
+struct TempLayer(PathBuf);
+
+impl TempLayer {
+    fn new(name: &str) -> Self {
+        let root = env::temp_dir().join(format!("battler-{name}-{}", std::process::id()));
+        fs::remove_dir_all(&root).ok();
+        fs::create_dir_all(&root).unwrap();
+        Self(root)
+    }
+
+    fn write(&self, path: &str, value: serde_json::Value) {
+        let path = self.0.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, serde_json::to_string(&value).unwrap()).unwrap();
+    }
+
+    fn layer(&self, name: &str) -> DataLayer {
+        DataLayer::new(name.to_owned(), self.0.to_string_lossy().to_string())
+    }
+}
+
+impl Drop for TempLayer {
+    fn drop(&mut self) {
+        fs::remove_dir_all(&self.0).ok();
+    }
+}
+
+fn base_layer() -> DataLayer {
+    DataLayer::new("base".to_owned(), env::var("DATA_DIR").unwrap())
+}
+
+fn mod_layer(name: &str) -> TempLayer {
+    let layer = TempLayer::new(name);
+    layer.write(
+        "moves/mod.json",
+        json!({
+            "tackle": {
+                "base_power": 100,
+            },
+            "thunder": {
+                "secondary_effects": null,
+            },
+            "pound": null,
+            "modmove": {
+                "name": "Mod Move",
+                "category": "Status",
+                "primary_type": "Normal",
+                "base_power": 0,
+                "accuracy": "exempt",
+                "pp": 5,
+                "target": "Normal",
+                "flags": [],
+                "effect": {
+                    "callbacks": {
+                        "on_hit": ["log_activate: str('modded')"],
+                    },
+                },
+            },
+        }),
+    );
+    layer.write("items/mod.json", json!({ "leftovers": null }));
+    layer.write("aliases.json", json!({ "modmovealias": "modmove" }));
+
+    // Fakemon are defined in full, so copy an existing species.
+    let base_species: serde_json::Value = serde_json::from_str(
+        &fs::read_to_string(PathBuf::from(env::var("DATA_DIR").unwrap()).join("mons/gen1.json"))
+            .unwrap(),
+    )
+    .unwrap();
+    let mut fakemon = base_species["bulbasaur"].clone();
+    fakemon["name"] = json!("Fakemon");
+    fakemon["base_species"] = json!("Fakemon");
+    fakemon["learnset"]["modmove"] = json!(["M"]);
+    layer.write("mons/fakemon.json", json!({ "fakemon": fakemon }));
+    layer
+}
+
+fn team() -> Result<TeamData> {
+    serde_json::from_str(
+        r#"{
+            "members": [
+                {
+                    "name": "Fakemon",
+                    "species": "Fakemon",
+                    "ability": "Overgrow",
+                    "moves": [
+                        "Mod Move"
+                    ],
+                    "nature": "Hardy",
+                    "level": 50
+                }
+            ]
+        }"#,
+    )
+    .wrap_error()
+}
+
+fn make_battle(data: &dyn DataStore) -> Result<PublicCoreBattle<'_>> {
+    TestBattleBuilder::new()
+        .with_battle_type(BattleType::Singles)
+        .with_seed(0)
+        .with_pass_allowed(true)
+        .add_player_to_side_1("player-1", "Player 1")
+        .add_player_to_side_2("player-2", "Player 2")
+        .with_team("player-1", team()?)
+        .with_team("player-2", team()?)
+        .build(data)
+}
+
+#[test]
+fn patches_deletes_and_adds_resources() {
+    let layer = mod_layer("overlay-patches");
+    let data = OverlayDataStore::new(vec![base_layer(), layer.layer("mod")]).unwrap();
+
+    assert_matches::assert_matches!(data.get_move(&Id::from("tackle")), Ok(Some(move_data)) => {
+        assert_eq!(move_data.name, "Tackle");
+        assert_eq!(move_data.base_power, 100);
+    });
+    assert_matches::assert_matches!(data.get_move(&Id::from("thunder")), Ok(Some(move_data)) => {
+        assert_eq!(move_data.base_power, 110);
+        assert!(move_data.secondary_effects.is_empty());
+    });
+    assert_matches::assert_matches!(data.get_move(&Id::from("pound")), Ok(None));
+    assert_matches::assert_matches!(data.get_item(&Id::from("leftovers")), Ok(None));
+    assert_matches::assert_matches!(
+        data.get_move_by_name("Mod Move"),
+        Ok(Some(move_data)) => assert_eq!(move_data.pp, 5)
+    );
+    assert_matches::assert_matches!(
+        data.get_species(&Id::from("fakemon")),
+        Ok(Some(species)) => assert_eq!(species.name, "Fakemon")
+    );
+    assert_matches::assert_matches!(
+        data.translate_alias(&Id::from("modmovealias")),
+        Ok(Some(id)) => assert_eq!(id, Id::from("modmove"))
+    );
+    assert_matches::assert_matches!(
+        data.translate_alias(&Id::from("pichuspikyeared")),
+        Ok(Some(id)) => assert_eq!(id, Id::from("pichu"))
+    );
+    assert_matches::assert_matches!(data.get_type_chart(), Ok(type_chart) => {
+        assert!(!type_chart.types.is_empty());
+    });
+}
+
+#[test]
+fn reports_layer_that_supplied_resource() {
+    let layer = mod_layer("overlay-sources");
+    let data = OverlayDataStore::new(vec![base_layer(), layer.layer("mod")]).unwrap();
+
+    assert_eq!(data.layers(), ["base", "mod"]);
+    assert_eq!(
+        data.source(ResourceType::Move, &Id::from("tackle")),
+        Some(&ResourceSource {
+            defined_by: "base".to_owned(),
+            patched_by: vec!["mod".to_owned()],
+        })
+    );
+    assert_eq!(
+        data.source(ResourceType::Move, &Id::from("tackle"))
+            .map(|source| source.layer()),
+        Some("mod")
+    );
+    assert_eq!(
+        data.source(ResourceType::Move, &Id::from("ember"))
+            .map(|source| source.layer()),
+        Some("base")
+    );
+    assert_eq!(
+        data.source(ResourceType::Species, &Id::from("fakemon"))
+            .map(|source| source.layer()),
+        Some("mod")
+    );
+    assert_eq!(data.source(ResourceType::Move, &Id::from("pound")), None);
+    assert_eq!(
+        data.source(ResourceType::Item, &Id::from("leftovers")),
+        None
+    );
+}
+
+#[test]
+fn applies_layers_in_priority_order() {
+    let first = mod_layer("overlay-first");
+    let second = TempLayer::new("overlay-second");
+    second.write(
+        "moves/mod.json",
+        json!({
+            "tackle": {
+                "base_power": 120,
+            },
+            "pound": {
+                "name": "Pound",
+                "category": "Physical",
+                "primary_type": "Normal",
+                "base_power": 50,
+                "accuracy": 100,
+                "pp": 35,
+                "target": "Normal",
+                "flags": ["Contact", "Protect"],
+            },
+        }),
+    );
+    second.write("aliases.json", json!({ "modmovealias": null }));
+    let data = OverlayDataStore::new(vec![
+        base_layer(),
+        first.layer("first"),
+        second.layer("second"),
+    ])
+    .unwrap();
+
+    assert_matches::assert_matches!(
+        data.get_move(&Id::from("tackle")),
+        Ok(Some(move_data)) => assert_eq!(move_data.base_power, 120)
+    );
+    assert_eq!(
+        data.source(ResourceType::Move, &Id::from("tackle")),
+        Some(&ResourceSource {
+            defined_by: "base".to_owned(),
+            patched_by: vec!["first".to_owned(), "second".to_owned()],
+        })
+    );
+
+    // A deleted resource can be defined again by a higher priority layer.
+    assert_matches::assert_matches!(
+        data.get_move(&Id::from("pound")),
+        Ok(Some(move_data)) => assert_eq!(move_data.base_power, 50)
+    );
+    assert_eq!(
+        data.source(ResourceType::Move, &Id::from("pound")),
+        Some(&ResourceSource {
+            defined_by: "second".to_owned(),
+            patched_by: Vec::new(),
+        })
+    );
+    assert_matches::assert_matches!(data.translate_alias(&Id::from("modmovealias")), Ok(None));
+}
+
+#[test]
+fn fails_invalid_merged_data() {
+    let layer = TempLayer::new("overlay-invalid");
+    layer.write(
+        "moves/mod.json",
+        json!({ "tackle": { "base_power": "strong" } }),
+    );
+    let err = OverlayDataStore::new(vec![base_layer(), layer.layer("mod")])
+        .err()
+        .unwrap();
+    assert!(
+        format!("{err:#}").contains("Move tackle supplied by mod"),
+        "{err:#}"
+    );
+
+    let layer = TempLayer::new("overlay-no-type-chart");
+    let err = OverlayDataStore::new(vec![layer.layer("mod")])
+        .err()
+        .unwrap();
+    assert_eq!(err.to_string(), "no layer defines a type chart");
+}
+
+#[test]
+fn battles_use_overlaid_data() {
+    let layer = mod_layer("overlay-battle");
+    let data = OverlayDataStore::new(vec![base_layer(), layer.layer("mod")]).unwrap();
+    let mut battle = make_battle(&data).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 0"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "pass"), Ok(()));
+    assert!(
+        battle
+            .full_log()
+            .any(|log| log == "activate|move:Mod Move|modded"),
+        "{:?}",
+        battle.full_log().collect::<Vec<_>>()
+    );
+}
@@ -0,0 +1,571 @@
+use anyhow::Result;
+use battler::{
+    BattleType,
+    CoreBattleEngineRandomizeBaseDamage,
+    CoreBattleEngineSpeedSortTieResolution,
+    PublicCoreBattle,
+    RaidBossOptions,
+    RaidThreshold,
+    TeamData,
+    ValidationError,
+    WrapResultError,
+};
+use battler_test_utils::{
+    LogMatch,
+    TestBattleBuilder,
+    assert_logs_since_start_eq,
+    assert_logs_since_turn_eq,
+    static_local_data_store,
+};
+use itertools::Itertools;
+
+fn raider_team(name: &str) -> Result<TeamData> {
+    serde_json::from_str(&format!(
+        r#"{{
+            "members": [
+                {{
+                    "name": "{name}",
+                    "species": "{name}",
+                    "ability": "No Ability",
+                    "moves": [
+                        "Tackle",
+                        "Swords Dance"
+                    ],
+                    "nature": "Hardy",
+                    "level": 50
+                }}
+            ]
+        }}"#
+    ))
+    .wrap_error()
+}
+
+fn boss_team() -> Result<TeamData> {
+    serde_json::from_str(
+        r#"{
+            "members": [
+                {
+                    "name": "Snorlax",
+                    "species": "Snorlax",
+                    "ability": "No Ability",
+                    "moves": [
+                        "Tackle",
+                        "Swords Dance"
+                    ],
+                    "nature": "Hardy",
+                    "level": 50
+                }
+            ]
+        }"#,
+    )
+    .wrap_error()
+}
+
+fn make_battle(seed: u64, options: RaidBossOptions) -> Result<PublicCoreBattle<'static>> {
+    TestBattleBuilder::new()
+        .with_battle_type(BattleType::Multi)
+        .with_seed(seed)
+        .with_team_validation(false)
+        .with_pass_allowed(true)
+        .with_speed_sort_tie_resolution(CoreBattleEngineSpeedSortTieResolution::Keep)
+        .with_base_damage_randomization(CoreBattleEngineRandomizeBaseDamage::Max)
+        .add_player_to_side_1("player-1", "Player 1")
+        .add_player_to_side_1("player-2", "Player 2")
+        .add_raid_boss_to_side_2("boss", "Boss", options)
+        .with_team("player-1", raider_team("Pikachu")?)
+        .with_team("player-2", raider_team("Eevee")?)
+        .with_team("boss", boss_team()?)
+        .build(static_local_data_store())
+}
+
+#[test]
+fn raid_boss_has_scaled_hp() {
+    let mut battle = make_battle(
+        0,
+        RaidBossOptions {
+            health_multiplier: 5,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    assert_matches::assert_matches!(battle.player_data("boss"), Ok(data) => {
+        assert_eq!(data.mons[0].max_hp, 1100);
+        assert_eq!(data.mons[0].hp, 1100);
+    });
+}
+
+#[test]
+fn shields_reduce_damage_and_break_on_each_hit() {
+    let mut battle = make_battle(
+        0,
+        RaidBossOptions {
+            shields: 1,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 0,1"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "move 0,1"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("boss", "move 1"), Ok(()));
+
+    let expected_logs = serde_json::from_str::<Vec<LogMatch>>(
+        r#"[
+            "split|side:0",
+            "switch|player:player-1|position:1|name:Pikachu|health:95/95|species:Pikachu|level:50|gender:U",
+            "switch|player:player-1|position:1|name:Pikachu|health:100/100|species:Pikachu|level:50|gender:U",
+            "split|side:0",
+            "switch|player:player-2|position:2|name:Eevee|health:115/115|species:Eevee|level:50|gender:U",
+            "switch|player:player-2|position:2|name:Eevee|health:100/100|species:Eevee|level:50|gender:U",
+            "split|side:1",
+            "switch|player:boss|position:1|name:Snorlax|health:220/220|species:Snorlax|level:50|gender:U",
+            "switch|player:boss|position:1|name:Snorlax|health:100/100|species:Snorlax|level:50|gender:U",
+            "raidshields|mon:Snorlax,boss,1|shields:1",
+            "turn|turn:1",
+            "continue",
+            "move|mon:Pikachu,player-1,1|name:Tackle|target:Snorlax,boss,1",
+            "split|side:1",
+            "damage|mon:Snorlax,boss,1|health:216/220",
+            "damage|mon:Snorlax,boss,1|health:99/100",
+            "raidshields|mon:Snorlax,boss,1|shields:0",
+            "move|mon:Eevee,player-2,2|name:Tackle|target:Snorlax,boss,1",
+            "split|side:1",
+            "damage|mon:Snorlax,boss,1|health:191/220",
+            "damage|mon:Snorlax,boss,1|health:87/100",
+            "move|mon:Snorlax,boss,1|name:Swords Dance|target:Snorlax,boss,1",
+            "boost|mon:Snorlax,boss,1|stat:atk|by:2",
+            "residual",
+            "turn|turn:2"
+        ]"#,
+    )
+    .unwrap();
+    assert_logs_since_start_eq(&battle, &expected_logs);
+}
+
+#[test]
+fn raid_boss_acts_multiple_times_per_turn() {
+    let mut battle = make_battle(
+        0,
+        RaidBossOptions {
+            actions_per_turn: 2,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 1"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "move 1"), Ok(()));
+    assert_matches::assert_matches!(
+        battle.set_player_choice("boss", "move 1;move 1;move 1"),
+        Err(err) => assert_eq!(format!("{err:#}"), "invalid choice 2: cannot move: you sent more choices than active mons")
+    );
+    assert_matches::assert_matches!(battle.set_player_choice("boss", "move 1;move 0,1"), Ok(()));
+
+    let expected_logs = serde_json::from_str::<Vec<LogMatch>>(
+        r#"[
+            "move|mon:Pikachu,player-1,1|name:Swords Dance|target:Pikachu,player-1,1",
+            "boost|mon:Pikachu,player-1,1|stat:atk|by:2",
+            "move|mon:Eevee,player-2,2|name:Swords Dance|target:Eevee,player-2,2",
+            "boost|mon:Eevee,player-2,2|stat:atk|by:2",
+            "move|mon:Snorlax,boss,1|name:Swords Dance|target:Snorlax,boss,1",
+            "boost|mon:Snorlax,boss,1|stat:atk|by:2",
+            "move|mon:Snorlax,boss,1|name:Tackle|target:Pikachu,player-1,1",
+            "split|side:0",
+            "damage|mon:Pikachu,player-1,1|health:0",
+            "damage|mon:Pikachu,player-1,1|health:0",
+            "faint|mon:Pikachu,player-1,1",
+            "residual",
+            "turn|turn:2"
+        ]"#,
+    )
+    .unwrap();
+    assert_logs_since_turn_eq(&battle, 1, &expected_logs);
+}
+
+#[test]
+fn threshold_runs_scripted_event_once() {
+    let mut battle = make_battle(
+        0,
+        RaidBossOptions {
+            thresholds: Vec::from_iter([RaidThreshold {
+                name: "Raid Boss Barrier".to_owned(),
+                hp_percent: 90,
+                program: serde_json::from_str(
+                    r#"[
+                        "clear_boosts: $target",
+                        "add_raid_shields: $target 2"
+                    ]"#,
+                )
+                .unwrap(),
+            }]),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 1"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "move 1"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("boss", "move 1"), Ok(()));
+
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 0,1"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "move 0,1"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("boss", "move 1"), Ok(()));
+
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 0,1"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "move 0,1"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("boss", "move 1"), Ok(()));
+
+    let expected_logs = serde_json::from_str::<Vec<LogMatch>>(
+        r#"[
+            "move|mon:Pikachu,player-1,1|name:Tackle|target:Snorlax,boss,1",
+            "split|side:1",
+            "damage|mon:Snorlax,boss,1|health:188/220",
+            "damage|mon:Snorlax,boss,1|health:86/100",
+            "clearboosts|mon:Snorlax,boss,1|from:Raid Boss Barrier",
+            "raidshields|mon:Snorlax,boss,1|shields:2|from:Raid Boss Barrier",
+            "move|mon:Eevee,player-2,2|name:Tackle|target:Snorlax,boss,1",
+            "split|side:1",
+            "damage|mon:Snorlax,boss,1|health:176/220",
+            "damage|mon:Snorlax,boss,1|health:80/100",
+            "raidshields|mon:Snorlax,boss,1|shields:1",
+            "move|mon:Snorlax,boss,1|name:Swords Dance|target:Snorlax,boss,1",
+            "boost|mon:Snorlax,boss,1|stat:atk|by:2",
+            "residual",
+            "turn|turn:3",
+            "continue",
+            "move|mon:Pikachu,player-1,1|name:Tackle|target:Snorlax,boss,1",
+            "split|side:1",
+            "damage|mon:Snorlax,boss,1|health:168/220",
+            "damage|mon:Snorlax,boss,1|health:77/100",
+            "raidshields|mon:Snorlax,boss,1|shields:0",
+            "move|mon:Eevee,player-2,2|name:Tackle|target:Snorlax,boss,1",
+            "split|side:1",
+            "damage|mon:Snorlax,boss,1|health:120/220",
+            "damage|mon:Snorlax,boss,1|health:55/100",
+            "move|mon:Snorlax,boss,1|name:Swords Dance|target:Snorlax,boss,1",
+            "boost|mon:Snorlax,boss,1|stat:atk|by:2",
+            "residual",
+            "turn|turn:4"
+        ]"#,
+    )
+    .unwrap();
+    assert_logs_since_turn_eq(&battle, 2, &expected_logs);
+}
+
+#[test]
+fn threshold_runs_scripted_event_after_residual_damage() {
+    let mut boss = boss_team().unwrap();
+    boss.members[0].item = Some("Sticky Barb".to_owned());
+    let mut battle = TestBattleBuilder::new()
+        .with_battle_type(BattleType::Multi)
+        .with_seed(0)
+        .with_team_validation(false)
+        .with_pass_allowed(true)
+        .with_speed_sort_tie_resolution(CoreBattleEngineSpeedSortTieResolution::Keep)
+        .add_player_to_side_1("player-1", "Player 1")
+        .add_player_to_side_1("player-2", "Player 2")
+        .add_raid_boss_to_side_2(
+            "boss",
+            "Boss",
+            RaidBossOptions {
+                thresholds: Vec::from_iter([RaidThreshold {
+                    name: "Raid Boss Barrier".to_owned(),
+                    hp_percent: 90,
+                    program: serde_json::from_str(r#"["add_raid_shields: $target 2"]"#).unwrap(),
+                }]),
+                ..Default::default()
+            },
+        )
+        .with_team("player-1", raider_team("Pikachu").unwrap())
+        .with_team("player-2", raider_team("Eevee").unwrap())
+        .with_team("boss", boss)
+        .build(static_local_data_store())
+        .unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 1"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "move 1"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("boss", "move 1"), Ok(()));
+
+    let expected_logs = serde_json::from_str::<Vec<LogMatch>>(
+        r#"[
+            "move|mon:Pikachu,player-1,1|name:Swords Dance|target:Pikachu,player-1,1",
+            "boost|mon:Pikachu,player-1,1|stat:atk|by:2",
+            "move|mon:Eevee,player-2,2|name:Swords Dance|target:Eevee,player-2,2",
+            "boost|mon:Eevee,player-2,2|stat:atk|by:2",
+            "move|mon:Snorlax,boss,1|name:Swords Dance|target:Snorlax,boss,1",
+            "boost|mon:Snorlax,boss,1|stat:atk|by:2",
+            "split|side:1",
+            "damage|mon:Snorlax,boss,1|from:item:Sticky Barb|health:193/220",
+            "damage|mon:Snorlax,boss,1|from:item:Sticky Barb|health:88/100",
+            "raidshields|mon:Snorlax,boss,1|shields:2|from:Raid Boss Barrier",
+            "residual",
+            "turn|turn:2"
+        ]"#,
+    )
+    .unwrap();
+    assert_logs_since_turn_eq(&battle, 1, &expected_logs);
+}
+
+#[test]
+fn threshold_runs_scripted_event_after_direct_damage() {
+    let mut boss = boss_team().unwrap();
+    boss.members[0].moves.push("Substitute".to_owned());
+    let mut battle = TestBattleBuilder::new()
+        .with_battle_type(BattleType::Multi)
+        .with_seed(0)
+        .with_team_validation(false)
+        .with_pass_allowed(true)
+        .with_speed_sort_tie_resolution(CoreBattleEngineSpeedSortTieResolution::Keep)
+        .with_base_damage_randomization(CoreBattleEngineRandomizeBaseDamage::Max)
+        .add_player_to_side_1("player-1", "Player 1")
+        .add_player_to_side_1("player-2", "Player 2")
+        .add_raid_boss_to_side_2(
+            "boss",
+            "Boss",
+            RaidBossOptions {
+                thresholds: Vec::from_iter([RaidThreshold {
+                    name: "Raid Boss Barrier".to_owned(),
+                    hp_percent: 90,
+                    program: serde_json::from_str(r#"["add_raid_shields: $target 2"]"#).unwrap(),
+                }]),
+                ..Default::default()
+            },
+        )
+        .with_team("player-1", raider_team("Pikachu").unwrap())
+        .with_team("player-2", raider_team("Eevee").unwrap())
+        .with_team("boss", boss)
+        .build(static_local_data_store())
+        .unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 1"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "move 1"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("boss", "move 2"), Ok(()));
+
+    let expected_logs = serde_json::from_str::<Vec<LogMatch>>(
+        r#"[
+            "move|mon:Pikachu,player-1,1|name:Swords Dance|target:Pikachu,player-1,1",
+            "boost|mon:Pikachu,player-1,1|stat:atk|by:2",
+            "move|mon:Eevee,player-2,2|name:Swords Dance|target:Eevee,player-2,2",
+            "boost|mon:Eevee,player-2,2|stat:atk|by:2",
+            "move|mon:Snorlax,boss,1|name:Substitute|target:Snorlax,boss,1",
+            "start|mon:Snorlax,boss,1|move:Substitute",
+            "split|side:1",
+            "damage|mon:Snorlax,boss,1|health:165/220",
+            "damage|mon:Snorlax,boss,1|health:75/100",
+            "raidshields|mon:Snorlax,boss,1|shields:2|from:Raid Boss Barrier",
+            "residual",
+            "turn|turn:2"
+        ]"#,
+    )
+    .unwrap();
+    assert_logs_since_turn_eq(&battle, 1, &expected_logs);
+}
+
+#[test]
+fn raid_fails_when_turn_limit_is_exceeded() {
+    let mut battle = make_battle(
+        0,
+        RaidBossOptions {
+            turn_limit: Some(1),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 1"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "move 1"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("boss", "move 1"), Ok(()));
+
+    let expected_logs = serde_json::from_str::<Vec<LogMatch>>(
+        r#"[
+            "move|mon:Pikachu,player-1,1|name:Swords Dance|target:Pikachu,player-1,1",
+            "boost|mon:Pikachu,player-1,1|stat:atk|by:2",
+            "move|mon:Eevee,player-2,2|name:Swords Dance|target:Eevee,player-2,2",
+            "boost|mon:Eevee,player-2,2|stat:atk|by:2",
+            "move|mon:Snorlax,boss,1|name:Swords Dance|target:Snorlax,boss,1",
+            "boost|mon:Snorlax,boss,1|stat:atk|by:2",
+            "residual",
+            "raidfailed|player:boss|reason:turnlimit",
+            "win|side:1"
+        ]"#,
+    )
+    .unwrap();
+    assert_logs_since_turn_eq(&battle, 1, &expected_logs);
+    assert!(battle.ended());
+}
+
+#[test]
+fn raid_fails_when_faint_limit_is_reached() {
+    let mut team = raider_team("Pikachu").unwrap();
+    team.members[0].level = 1;
+    let mut battle = TestBattleBuilder::new()
+        .with_battle_type(BattleType::Multi)
+        .with_seed(0)
+        .with_team_validation(false)
+        .with_pass_allowed(true)
+        .with_speed_sort_tie_resolution(CoreBattleEngineSpeedSortTieResolution::Keep)
+        .with_base_damage_randomization(CoreBattleEngineRandomizeBaseDamage::Max)
+        .add_player_to_side_1("player-1", "Player 1")
+        .add_player_to_side_1("player-2", "Player 2")
+        .add_raid_boss_to_side_2(
+            "boss",
+            "Boss",
+            RaidBossOptions {
+                faint_limit: Some(1),
+                ..Default::default()
+            },
+        )
+        .with_team("player-1", team)
+        .with_team("player-2", raider_team("Eevee").unwrap())
+        .with_team("boss", boss_team().unwrap())
+        .build(static_local_data_store())
+        .unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 1"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "move 1"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("boss", "move 0,1"), Ok(()));
+
+    let expected_logs = serde_json::from_str::<Vec<LogMatch>>(
+        r#"[
+            "move|mon:Eevee,player-2,2|name:Swords Dance|target:Eevee,player-2,2",
+            "boost|mon:Eevee,player-2,2|stat:atk|by:2",
+            "move|mon:Snorlax,boss,1|name:Tackle|target:Pikachu,player-1,1",
+            "split|side:0",
+            "damage|mon:Pikachu,player-1,1|health:0",
+            "damage|mon:Pikachu,player-1,1|health:0",
+            "faint|mon:Pikachu,player-1,1",
+            "raidfailed|player:boss|reason:faintlimit",
+            "win|side:1"
+        ]"#,
+    )
+    .unwrap();
+    assert_logs_since_turn_eq(&battle, 1, &expected_logs);
+    assert!(battle.ended());
+}
+
+#[test]
+fn raid_fails_once_when_faint_limit_is_exceeded() {
+    let mut team_1 = raider_team("Pikachu").unwrap();
+    team_1.members[0].level = 1;
+    let mut team_2 = raider_team("Eevee").unwrap();
+    team_2.members[0].level = 1;
+    let mut battle = TestBattleBuilder::new()
+        .with_battle_type(BattleType::Multi)
+        .with_seed(0)
+        .with_team_validation(false)
+        .with_pass_allowed(true)
+        .with_speed_sort_tie_resolution(CoreBattleEngineSpeedSortTieResolution::Keep)
+        .with_base_damage_randomization(CoreBattleEngineRandomizeBaseDamage::Max)
+        .add_player_to_side_1("player-1", "Player 1")
+        .add_player_to_side_1("player-2", "Player 2")
+        .add_raid_boss_to_side_2(
+            "boss",
+            "Boss",
+            RaidBossOptions {
+                actions_per_turn: 2,
+                faint_limit: Some(1),
+                ..Default::default()
+            },
+        )
+        .with_team("player-1", team_1)
+        .with_team("player-2", team_2)
+        .with_team("boss", boss_team().unwrap())
+        .build(static_local_data_store())
+        .unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 1"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "move 1"), Ok(()));
+    assert_matches::assert_matches!(
+        battle.set_player_choice("boss", "move 0,1;move 0,2"),
+        Ok(())
+    );
+
+    let expected_logs = serde_json::from_str::<Vec<LogMatch>>(
+        r#"[
+            "move|mon:Snorlax,boss,1|name:Tackle|target:Pikachu,player-1,1",
+            "split|side:0",
+            "damage|mon:Pikachu,player-1,1|health:0",
+            "damage|mon:Pikachu,player-1,1|health:0",
+            "faint|mon:Pikachu,player-1,1",
+            "raidfailed|player:boss|reason:faintlimit",
+            "win|side:1"
+        ]"#,
+    )
+    .unwrap();
+    assert_logs_since_turn_eq(&battle, 1, &expected_logs);
+    assert!(battle.ended());
+}
+
+#[test]
+fn validates_raid_boss_players() {
+    assert_matches::assert_matches!(
+        TestBattleBuilder::new()
+            .with_battle_type(BattleType::Multi)
+            .with_team_validation(false)
+            .add_player_to_side_1("player-1", "Player 1")
+            .add_raid_boss_to_side_2("boss", "Boss", RaidBossOptions::default())
+            .add_player_to_side_2("player-2", "Player 2")
+            .with_team("player-1", raider_team("Pikachu").unwrap())
+            .with_team("player-2", raider_team("Eevee").unwrap())
+            .with_team("boss", boss_team().unwrap())
+            .build(static_local_data_store())
+            .err(),
+        Some(err) => assert_eq!(format!("{err:#}"), "battle options are invalid: Boss must be the only player on Side 2 to be a raid boss")
+    );
+
+    let mut team = boss_team().unwrap();
+    team.members.push(team.members[0].clone());
+    let mut battle = TestBattleBuilder::new()
+        .with_battle_type(BattleType::Multi)
+        .with_team_validation(false)
+        .add_player_to_side_1("player-1", "Player 1")
+        .add_raid_boss_to_side_2("boss", "Boss", RaidBossOptions::default())
+        .with_team("player-1", raider_team("Pikachu").unwrap())
+        .with_team("boss", team)
+        .build(static_local_data_store())
+        .unwrap();
+    assert_matches::assert_matches!(battle.start(), Err(err) => {
+        assert_matches::assert_matches!(err.downcast_ref::<ValidationError>(), Some(err) => {
+            assert!(err.problems().contains(&"Validation failed for Boss: Raid bosses must have exactly one Mon."), "{err:?}");
+        }, "{err:?}");
+    });
+
+    assert_matches::assert_matches!(
+        TestBattleBuilder::new()
+            .with_battle_type(BattleType::Doubles)
+            .with_team_validation(false)
+            .add_player_to_side_1("player-1", "Player 1")
+            .add_raid_boss_to_side_2("boss", "Boss", RaidBossOptions::default())
+            .with_team("player-1", raider_team("Pikachu").unwrap())
+            .with_team("boss", boss_team().unwrap())
+            .build(static_local_data_store())
+            .err(),
+        Some(err) => assert_eq!(format!("{err:#}"), "battle options are invalid: raid bosses can only battle in battle types with one active mon per player")
+    );
+
+    let mut builder = TestBattleBuilder::new()
+        .with_battle_type(BattleType::Multi)
+        .with_team_validation(false)
+        .add_raid_boss_to_side_2("boss", "Boss", RaidBossOptions::default())
+        .with_team("boss", boss_team().unwrap());
+    for i in 1..=5 {
+        let id = format!("player-{i}");
+        builder = builder
+            .add_player_to_side_1(&id, &format!("Player {i}"))
+            .with_team(&id, raider_team("Pikachu").unwrap());
+    }
+    assert_matches::assert_matches!(
+        builder.build(static_local_data_store()).err(),
+        Some(err) => assert_eq!(format!("{err:#}"), "battle options are invalid: Side 1 has too many players for a raid (at most 4 are allowed)")
+    );
+}
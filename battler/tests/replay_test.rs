@@ -0,0 +1,175 @@
+use anyhow::Result;
+use battler::{
+    BattleReplay,
+    BattleType,
+    PublicCoreBattle,
+    ReplayDivergenceError,
+    TeamData,
+    WrapResultError,
+};
+use battler_test_utils::{
+    TestBattleBuilder,
+    static_local_data_store,
+};
+
+fn team() -> Result<TeamData> {
+    serde_json::from_str(
+        r#"{
+            "members": [
+                {
+                    "name": "Charizard",
+                    "species": "Charizard",
+                    "ability": "Blaze",
+                    "moves": [
+                        "Flamethrower",
+                        "Air Slash",
+                        "Will-O-Wisp",
+                        "Protect"
+                    ],
+                    "nature": "Hardy",
+                    "level": 50
+                },
+                {
+                    "name": "Blastoise",
+                    "species": "Blastoise",
+                    "ability": "Torrent",
+                    "moves": [
+                        "Surf",
+                        "Rain Dance",
+                        "Substitute",
+                        "Protect"
+                    ],
+                    "nature": "Hardy",
+                    "level": 50
+                }
+            ]
+        }"#,
+    )
+    .wrap_error()
+}
+
+fn make_battle(seed: u64, auto_continue: bool) -> Result<PublicCoreBattle<'static>> {
+    TestBattleBuilder::new()
+        .with_battle_type(BattleType::Singles)
+        .with_seed(seed)
+        .with_auto_continue(auto_continue)
+        .add_player_to_side_1("player-1", "Player 1")
+        .add_player_to_side_2("player-2", "Player 2")
+        .with_team("player-1", team()?)
+        .with_team("player-2", team()?)
+        .build(static_local_data_store())
+}
+
+fn make_choices(battle: &mut PublicCoreBattle, choices: &[(&str, &str)]) {
+    for (player, choice) in choices {
+        assert_matches::assert_matches!(battle.set_player_choice(player, choice), Ok(()));
+    }
+    if !battle.internal.engine_options.auto_continue && battle.ready_to_continue().unwrap() {
+        assert_matches::assert_matches!(battle.continue_battle(), Ok(()));
+    }
+}
+
+fn play_battle(auto_continue: bool) -> PublicCoreBattle<'static> {
+    let mut battle = make_battle(55555, auto_continue).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+    if !auto_continue {
+        assert_matches::assert_matches!(battle.continue_battle(), Ok(()));
+    }
+    make_choices(
+        &mut battle,
+        &[("player-1", "move 0"), ("player-2", "move 1")],
+    );
+    make_choices(
+        &mut battle,
+        &[("player-1", "switch 1"), ("player-2", "move 0")],
+    );
+    make_choices(
+        &mut battle,
+        &[("player-1", "move 0"), ("player-2", "move 2")],
+    );
+    battle
+}
+
+fn serialize_replay(battle: &PublicCoreBattle) -> Result<BattleReplay> {
+    let replay = serde_json::to_string(&battle.replay_record()?)?;
+    serde_json::from_str(&replay).wrap_error()
+}
+
+#[test]
+fn replays_battle_identically() {
+    let battle = play_battle(true);
+    let replay = serialize_replay(&battle).unwrap();
+    assert_eq!(replay.options.seed, Some(55555));
+    assert_eq!(replay.choices.len(), 6);
+    assert_eq!(replay.choices[2].turn, 2);
+    assert_eq!(replay.choices[2].player, "player-1");
+    assert_eq!(replay.choices[2].input, "switch 1");
+
+    let replayed = PublicCoreBattle::replay(&replay, static_local_data_store()).unwrap();
+    assert_eq!(replayed.turn(), battle.turn());
+    assert_eq!(
+        replayed.full_log().collect::<Vec<_>>(),
+        battle.full_log().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn replays_battle_without_auto_continue() {
+    let battle = play_battle(false);
+    let replay = serialize_replay(&battle).unwrap();
+    let replayed = PublicCoreBattle::replay(&replay, static_local_data_store()).unwrap();
+    assert_eq!(replayed.turn(), battle.turn());
+    assert_eq!(
+        replayed.full_log().collect::<Vec<_>>(),
+        battle.full_log().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn records_validated_teams() {
+    let battle = play_battle(true);
+    let replay = battle.replay_record().unwrap();
+    assert_eq!(replay.options.side_1.players[0].team.members.len(), 2);
+    assert_eq!(replay.options.side_2.players[0].team.members.len(), 2);
+}
+
+#[test]
+fn fails_to_record_replay_before_start() {
+    let battle = make_battle(0, true).unwrap();
+    assert_matches::assert_matches!(battle.replay_record(), Err(err) => {
+        assert!(err.to_string().contains("has not started"));
+    });
+}
+
+#[test]
+fn reports_first_divergent_log_line() {
+    let battle = play_battle(true);
+    let mut replay = battle.replay_record().unwrap();
+    let line = replay
+        .log
+        .iter()
+        .position(|line| line.starts_with("turn|turn:2"))
+        .unwrap();
+    replay.log[line] = "turn|turn:3".to_owned();
+
+    assert_matches::assert_matches!(
+        PublicCoreBattle::replay(&replay, static_local_data_store()).map(|_| ()),
+        Err(err) => {
+            let err = err.downcast::<ReplayDivergenceError>().unwrap();
+            assert_eq!(err.line, line);
+            assert_eq!(err.expected, Some("turn|turn:3".to_owned()));
+            assert_eq!(err.actual, Some("turn|turn:2".to_owned()));
+        }
+    );
+}
+
+#[test]
+fn reports_divergence_for_different_seed() {
+    let battle = play_battle(true);
+    let mut replay = battle.replay_record().unwrap();
+    replay.options.seed = Some(0);
+    assert_matches::assert_matches!(
+        PublicCoreBattle::replay(&replay, static_local_data_store()).map(|_| ()),
+        Err(err) => assert!(err.downcast_ref::<ReplayDivergenceError>().is_some())
+    );
+}
@@ -0,0 +1,326 @@
+use anyhow::Result;
+use battler::{
+    BattleType,
+    CoreBattleEngineRandomizeBaseDamage,
+    CoreBattleEngineSpeedSortTieResolution,
+    PublicCoreBattle,
+    Request,
+    TeamData,
+    WrapResultError,
+};
+use battler_test_utils::{
+    LogMatch,
+    TestBattleBuilder,
+    assert_logs_since_start_eq,
+    assert_logs_since_turn_eq,
+    static_local_data_store,
+};
+
+fn team_1() -> Result<TeamData> {
+    serde_json::from_str(
+        r#"{
+            "members": [
+                {
+                    "name": "Gyarados",
+                    "species": "Gyarados",
+                    "ability": "Intimidate",
+                    "moves": [
+                        "Tackle"
+                    ],
+                    "nature": "Hardy",
+                    "level": 50
+                },
+                {
+                    "name": "Mightyena",
+                    "species": "Mightyena",
+                    "ability": "Intimidate",
+                    "moves": [
+                        "Earthquake",
+                        "Outrage"
+                    ],
+                    "nature": "Hardy",
+                    "level": 50
+                },
+                {
+                    "name": "Pikachu",
+                    "species": "Pikachu",
+                    "ability": "No Ability",
+                    "moves": [
+                        "Thunderbolt"
+                    ],
+                    "nature": "Hardy",
+                    "level": 50
+                }
+            ]
+        }"#,
+    )
+    .wrap_error()
+}
+
+fn team_2() -> Result<TeamData> {
+    serde_json::from_str(
+        r#"{
+            "members": [
+                {
+                    "name": "Charizard",
+                    "species": "Charizard",
+                    "ability": "No Ability",
+                    "moves": [
+                        "Tackle"
+                    ],
+                    "nature": "Hardy",
+                    "level": 50
+                },
+                {
+                    "name": "Venusaur",
+                    "species": "Venusaur",
+                    "ability": "No Ability",
+                    "moves": [
+                        "Tackle"
+                    ],
+                    "nature": "Hardy",
+                    "level": 50
+                },
+                {
+                    "name": "Blastoise",
+                    "species": "Blastoise",
+                    "ability": "No Ability",
+                    "moves": [
+                        "Tackle"
+                    ],
+                    "nature": "Hardy",
+                    "level": 50
+                }
+            ]
+        }"#,
+    )
+    .wrap_error()
+}
+
+fn make_battle(seed: u64, team_1: TeamData, team_2: TeamData) -> Result<PublicCoreBattle<'static>> {
+    TestBattleBuilder::new()
+        .with_battle_type(BattleType::Rotation)
+        .with_seed(seed)
+        .with_team_validation(false)
+        .with_pass_allowed(true)
+        .with_speed_sort_tie_resolution(CoreBattleEngineSpeedSortTieResolution::Keep)
+        .with_base_damage_randomization(CoreBattleEngineRandomizeBaseDamage::Max)
+        .add_player_to_side_1("player-1", "Player 1")
+        .add_player_to_side_2("player-2", "Player 2")
+        .with_team("player-1", team_1)
+        .with_team("player-2", team_2)
+        .build(static_local_data_store())
+}
+
+#[test]
+fn intimidate_only_affects_front_foe() {
+    let mut battle = make_battle(0, team_1().unwrap(), team_2().unwrap()).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    let expected_logs = serde_json::from_str::<Vec<LogMatch>>(
+        r#"[
+            "split|side:0",
+            ["switch", "player-1", "Gyarados"],
+            ["switch", "player-1", "Gyarados"],
+            "split|side:0",
+            ["switch", "player-1", "Mightyena"],
+            ["switch", "player-1", "Mightyena"],
+            "split|side:0",
+            ["switch", "player-1", "Pikachu"],
+            ["switch", "player-1", "Pikachu"],
+            "split|side:1",
+            ["switch", "player-2", "Charizard"],
+            ["switch", "player-2", "Charizard"],
+            "split|side:1",
+            ["switch", "player-2", "Venusaur"],
+            ["switch", "player-2", "Venusaur"],
+            "split|side:1",
+            ["switch", "player-2", "Blastoise"],
+            ["switch", "player-2", "Blastoise"],
+            "activate|mon:Gyarados,player-1,1|ability:Intimidate",
+            "unboost|mon:Charizard,player-2,1|stat:atk|by:1|from:ability:Intimidate|of:Gyarados,player-1,1",
+            "turn|turn:1"
+        ]"#,
+    )
+    .unwrap();
+    assert_logs_since_start_eq(&battle, &expected_logs);
+}
+
+#[test]
+fn rotates_to_front_before_move() {
+    let mut battle = make_battle(0, team_1().unwrap(), team_2().unwrap()).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    assert_matches::assert_matches!(
+        battle.set_player_choice("player-1", "rotate left;move 0"),
+        Ok(())
+    );
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "move 0"), Ok(()));
+    assert_matches::assert_matches!(
+        battle.set_player_choice("player-1", "rotate left;move 0"),
+        Ok(())
+    );
+    assert_matches::assert_matches!(
+        battle.set_player_choice("player-2", "rotate right;move 0"),
+        Ok(())
+    );
+
+    let expected_logs = serde_json::from_str::<Vec<LogMatch>>(
+        r#"[
+            "move|mon:Charizard,player-2,1|name:Tackle|target:Gyarados,player-1,1",
+            "split|side:0",
+            "damage|mon:Gyarados,player-1,1|health:141/155",
+            "damage|mon:Gyarados,player-1,1|health:91/100",
+            "rotate|mon:Mightyena,player-1,2|direction:left",
+            "move|mon:Mightyena,player-1,1|name:Earthquake|noanim",
+            "immune|mon:Charizard,player-2,1",
+            "residual",
+            "turn|turn:2",
+            "continue",
+            "rotate|mon:Pikachu,player-1,2|direction:left",
+            "move|mon:Pikachu,player-1,1|name:Thunderbolt|target:Charizard,player-2,1",
+            "supereffective|mon:Charizard,player-2,1",
+            "split|side:1",
+            "damage|mon:Charizard,player-2,1|health:60/138",
+            "damage|mon:Charizard,player-2,1|health:44/100",
+            "status|mon:Charizard,player-2,1|status:Paralysis",
+            "rotate|mon:Blastoise,player-2,3|direction:right",
+            "move|mon:Blastoise,player-2,1|name:Tackle|target:Pikachu,player-1,1",
+            "split|side:0",
+            "damage|mon:Pikachu,player-1,1|health:59/95",
+            "damage|mon:Pikachu,player-1,1|health:63/100",
+            "residual",
+            "turn|turn:3"
+        ]"#,
+    )
+    .unwrap();
+    assert_logs_since_turn_eq(&battle, 1, &expected_logs);
+}
+
+#[test]
+fn only_front_mon_makes_a_choice() {
+    let mut battle = make_battle(0, team_1().unwrap(), team_2().unwrap()).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    assert_matches::assert_matches!(
+        battle.request_for_player("player-1"),
+        Ok(Some(Request::Turn(request))) => {
+            assert_eq!(request.active.len(), 3);
+            assert!(request.can_rotate);
+        }
+    );
+
+    assert_matches::assert_matches!(
+        battle.set_player_choice("player-1", "move 0;move 0"),
+        Err(err) => assert_eq!(format!("{err:#}"), "invalid choice 1: cannot move: you sent more choices than active mons")
+    );
+    assert_matches::assert_matches!(
+        battle.set_player_choice("player-1", "move 0,2"),
+        Err(err) => assert_eq!(format!("{err:#}"), "invalid choice 0: cannot move: invalid target for Tackle")
+    );
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 0,1"), Ok(()));
+}
+
+#[test]
+fn rotate_must_be_followed_by_move() {
+    let mut battle = make_battle(0, team_1().unwrap(), team_2().unwrap()).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    assert_matches::assert_matches!(
+        battle.set_player_choice("player-1", "rotate left"),
+        Err(err) => assert_eq!(format!("{err:#}"), "incomplete choice: rotate must be followed by a move")
+    );
+    assert_matches::assert_matches!(
+        battle.set_player_choice("player-1", "rotate left;switch 3"),
+        Err(err) => assert_eq!(format!("{err:#}"), "invalid choice 1: rotate must be followed by a move")
+    );
+    assert_matches::assert_matches!(
+        battle.set_player_choice("player-1", "rotate left;rotate right;move 0"),
+        Err(err) => assert_eq!(format!("{err:#}"), "invalid choice 1: rotate must be followed by a move")
+    );
+}
+
+#[test]
+fn cannot_rotate_outside_of_rotation_battle() {
+    let mut battle = TestBattleBuilder::new()
+        .with_battle_type(BattleType::Triples)
+        .with_seed(0)
+        .with_team_validation(false)
+        .add_player_to_side_1("player-1", "Player 1")
+        .add_player_to_side_2("player-2", "Player 2")
+        .with_team("player-1", team_1().unwrap())
+        .with_team("player-2", team_2().unwrap())
+        .build(static_local_data_store())
+        .unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    assert_matches::assert_matches!(
+        battle.set_player_choice("player-1", "rotate left;move 0,1"),
+        Err(err) => assert_eq!(format!("{err:#}"), "invalid choice 0: cannot rotate: you can only rotate in rotation battles")
+    );
+}
+
+#[test]
+fn cannot_rotate_out_locked_mon() {
+    let mut battle = make_battle(0, team_1().unwrap(), team_2().unwrap()).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    assert_matches::assert_matches!(
+        battle.set_player_choice("player-1", "rotate left;move 1"),
+        Ok(())
+    );
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "move 0"), Ok(()));
+
+    assert_matches::assert_matches!(
+        battle.request_for_player("player-1"),
+        Ok(Some(Request::Turn(request))) => {
+            assert!(!request.can_rotate);
+            assert!(!serde_json::to_string(&request).unwrap().contains("can_rotate"));
+        }
+    );
+    assert_matches::assert_matches!(
+        battle.set_player_choice("player-1", "rotate left;move 0"),
+        Err(err) => assert_eq!(format!("{err:#}"), "invalid choice 0: cannot rotate: Mightyena must use its locked move")
+    );
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 0"), Ok(()));
+}
+
+#[test]
+fn rotates_to_fill_empty_front_position() {
+    let mut team_2 = team_2().unwrap();
+    team_2.members[0].level = 1;
+    let mut battle = make_battle(0, team_1().unwrap(), team_2).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 0"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "move 0"), Ok(()));
+
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 0"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "move 0"), Ok(()));
+
+    let expected_logs = serde_json::from_str::<Vec<LogMatch>>(
+        r#"[
+            "move|mon:Gyarados,player-1,1|name:Tackle|target:Charizard,player-2,1",
+            "split|side:1",
+            "damage|mon:Charizard,player-2,1|health:0",
+            "damage|mon:Charizard,player-2,1|health:0",
+            "faint|mon:Charizard,player-2,1",
+            "residual",
+            "rotate|mon:Venusaur,player-2,2|direction:left",
+            "turn|turn:2",
+            "continue",
+            "move|mon:Gyarados,player-1,1|name:Tackle|target:Venusaur,player-2,1",
+            "split|side:1",
+            ["damage", "mon:Venusaur,player-2,1"],
+            ["damage", "mon:Venusaur,player-2,1"],
+            "move|mon:Venusaur,player-2,1|name:Tackle|target:Gyarados,player-1,1",
+            "split|side:0",
+            ["damage", "mon:Gyarados,player-1,1"],
+            ["damage", "mon:Gyarados,player-1,1"],
+            "residual",
+            "turn|turn:3"
+        ]"#,
+    )
+    .unwrap();
+    assert_logs_since_turn_eq(&battle, 1, &expected_logs);
+}
@@ -0,0 +1,160 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use battler::{
+    BattleType,
+    DataStore,
+    Id,
+    MoveData,
+    PublicCoreBattle,
+    SharedDexCache,
+    TeamData,
+    WrapResultError,
+};
+use battler_test_utils::{
+    TestBattleBuilder,
+    TestDataStore,
+};
+
+fn team() -> Result<TeamData> {
+    serde_json::from_str(
+        r#"{
+            "members": [
+                {
+                    "name": "Venusaur",
+                    "species": "Venusaur",
+                    "ability": "No Ability",
+                    "moves": [
+                        "Shared Cache Move"
+                    ],
+                    "nature": "Hardy",
+                    "level": 50
+                }
+            ]
+        }"#,
+    )
+    .wrap_error()
+}
+
+fn data_store(message: &str) -> Result<TestDataStore> {
+    let mut data = TestDataStore::new_from_env("DATA_DIR")?;
+    let move_data: MoveData = serde_json::from_value(serde_json::json!({
+        "name": "Shared Cache Move",
+        "category": "Status",
+        "primary_type": "Normal",
+        "base_power": 0,
+        "accuracy": "exempt",
+        "pp": 5,
+        "target": "Normal",
+        "flags": [],
+        "effect": {
+            "callbacks": {
+                "on_hit": [format!("log_activate: str('{message}')")],
+            },
+        },
+    }))
+    .wrap_error()?;
+    data.add_fake_move(Id::from("sharedcachemove"), move_data);
+    Ok(data)
+}
+
+fn make_battle(
+    data: &dyn DataStore,
+    dex_cache: Option<Arc<SharedDexCache>>,
+) -> Result<PublicCoreBattle<'_>> {
+    let mut builder = TestBattleBuilder::new()
+        .with_battle_type(BattleType::Singles)
+        .with_seed(0)
+        .with_pass_allowed(true)
+        .with_team_validation(false)
+        .add_player_to_side_1("player-1", "Player 1")
+        .add_player_to_side_2("player-2", "Player 2")
+        .with_team("player-1", team()?)
+        .with_team("player-2", team()?);
+    if let Some(dex_cache) = dex_cache {
+        builder = builder.with_dex_cache(dex_cache);
+    }
+    builder.build(data)
+}
+
+fn use_move(battle: &mut PublicCoreBattle) -> Vec<String> {
+    battle.new_log_entries().for_each(drop);
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 0"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "pass"), Ok(()));
+    battle
+        .new_log_entries()
+        .filter(|log| log.starts_with("activate"))
+        .map(|log| log.to_owned())
+        .collect()
+}
+
+#[test]
+fn battles_share_cached_resources_and_effects() {
+    let data_1 = data_store("version 1").unwrap();
+    let data_2 = data_store("version two").unwrap();
+    let cache = Arc::new(SharedDexCache::new());
+
+    let mut battle_1 = make_battle(&data_1, Some(cache.clone())).unwrap();
+    assert_matches::assert_matches!(battle_1.start(), Ok(()));
+    assert_eq!(
+        use_move(&mut battle_1),
+        ["activate|move:Shared Cache Move|version 1"]
+    );
+
+    // The second battle reads the move created by the first battle, rather than its own data.
+    let mut battle_2 = make_battle(&data_2, Some(cache.clone())).unwrap();
+    assert_matches::assert_matches!(battle_2.start(), Ok(()));
+    assert_eq!(
+        use_move(&mut battle_2),
+        ["activate|move:Shared Cache Move|version 1"]
+    );
+
+    cache.invalidate();
+    assert_matches::assert_matches!(battle_2.invalidate_data_caches(), Ok(()));
+    assert_eq!(
+        use_move(&mut battle_2),
+        ["activate|move:Shared Cache Move|version two"]
+    );
+}
+
+#[test]
+fn battles_without_shared_cache_read_own_data() {
+    let data_1 = data_store("version 1").unwrap();
+    let data_2 = data_store("version two").unwrap();
+
+    let mut battle_1 = make_battle(&data_1, None).unwrap();
+    assert_matches::assert_matches!(battle_1.start(), Ok(()));
+    assert_eq!(
+        use_move(&mut battle_1),
+        ["activate|move:Shared Cache Move|version 1"]
+    );
+
+    let mut battle_2 = make_battle(&data_2, None).unwrap();
+    assert_matches::assert_matches!(battle_2.start(), Ok(()));
+    assert_eq!(
+        use_move(&mut battle_2),
+        ["activate|move:Shared Cache Move|version two"]
+    );
+}
+
+#[test]
+fn shared_cache_is_used_across_threads() {
+    let cache = Arc::new(SharedDexCache::new());
+    let handles = (0..4)
+        .map(|_| {
+            let cache = cache.clone();
+            std::thread::spawn(move || {
+                let data = data_store("threaded").unwrap();
+                let mut battle = make_battle(&data, Some(cache)).unwrap();
+                assert_matches::assert_matches!(battle.start(), Ok(()));
+                use_move(&mut battle)
+            })
+        })
+        .collect::<Vec<_>>();
+    for handle in handles {
+        assert_eq!(
+            handle.join().unwrap(),
+            ["activate|move:Shared Cache Move|threaded"]
+        );
+    }
+}
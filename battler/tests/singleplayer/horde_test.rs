@@ -10,6 +10,7 @@ use battler::{
 use battler_test_utils::{
     LogMatch,
     TestBattleBuilder,
+    assert_logs_since_turn_eq,
     assert_new_logs_eq,
     static_local_data_store,
 };
@@ -91,6 +92,31 @@ fn make_horde_battle(
     builder.build(static_local_data_store())
 }
 
+fn rattata_horde(size: usize) -> Result<TeamData> {
+    let mut horde = rattata()?;
+    horde.members = vec![horde.members[0].clone(); size];
+    Ok(horde)
+}
+
+fn make_horde_battle_type(
+    seed: u64,
+    team: TeamData,
+    horde: TeamData,
+) -> Result<PublicCoreBattle<'static>> {
+    TestBattleBuilder::new()
+        .with_battle_type(BattleType::Horde)
+        .with_seed(seed)
+        .with_team_validation(false)
+        .with_pass_allowed(true)
+        .with_bag_items(true)
+        .with_infinite_bags(true)
+        .with_speed_sort_tie_resolution(CoreBattleEngineSpeedSortTieResolution::Keep)
+        .add_protagonist_to_side_1("protagonist", "Protagonist")
+        .add_horde_to_side_2("wild", "Horde", WildPlayerOptions::default(), horde)
+        .with_team("protagonist", team)
+        .build(static_local_data_store())
+}
+
 #[test]
 fn player_can_hit_all_adjacent_foes() {
     let mut battle = make_horde_battle(0, team().unwrap(), rattata().unwrap()).unwrap();
@@ -193,3 +219,193 @@ fn player_can_hit_all_adjacent_foes() {
     .unwrap();
     assert_new_logs_eq(&mut battle, &expected_logs);
 }
+
+#[test]
+fn horde_splits_wild_team_into_five_wild_players() {
+    let mut battle = make_horde_battle_type(0, team().unwrap(), rattata_horde(5).unwrap()).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    assert_matches::assert_matches!(battle.set_player_choice("protagonist", "move 1"), Ok(()));
+    for i in 0..5 {
+        assert_matches::assert_matches!(
+            battle.set_player_choice(&format!("wild-{i}"), "move 0"),
+            Ok(())
+        );
+    }
+
+    let expected_logs = serde_json::from_str::<Vec<LogMatch>>(
+        r#"[
+            "info|battletype:Horde",
+            "info|environment:Normal|time:Day",
+            "side|id:0|name:Side 1",
+            "side|id:1|name:Side 2",
+            "maxsidelength|length:5",
+            "player|id:protagonist|name:Protagonist|side:0|position:2",
+            "player|id:wild-0|name:Horde|side:1|position:0",
+            "player|id:wild-1|name:Horde|side:1|position:1",
+            "player|id:wild-2|name:Horde|side:1|position:2",
+            "player|id:wild-3|name:Horde|side:1|position:3",
+            "player|id:wild-4|name:Horde|side:1|position:4",
+            "continue",
+            "teamsize|player:protagonist|size:2",
+            "battlestart",
+            "split|side:1",
+            ["appear", "player:wild-0", "position:1"],
+            ["appear", "player:wild-0", "position:1"],
+            "split|side:1",
+            ["appear", "player:wild-1", "position:2"],
+            ["appear", "player:wild-1", "position:2"],
+            "split|side:1",
+            ["appear", "player:wild-2", "position:3"],
+            ["appear", "player:wild-2", "position:3"],
+            "split|side:1",
+            ["appear", "player:wild-3", "position:4"],
+            ["appear", "player:wild-3", "position:4"],
+            "split|side:1",
+            ["appear", "player:wild-4", "position:5"],
+            ["appear", "player:wild-4", "position:5"],
+            "split|side:0",
+            ["switch", "player:protagonist", "position:3"],
+            ["switch", "player:protagonist", "position:3"],
+            "turn|turn:1",
+            "continue",
+            "move|mon:Pikachu,protagonist,3|name:Surf|spread:Rattata,wild-0,1;Rattata,wild-1,2;Rattata,wild-2,3;Rattata,wild-3,4;Rattata,wild-4,5",
+            "split|side:1",
+            "damage|mon:Rattata,wild-0,1|health:0",
+            "damage|mon:Rattata,wild-0,1|health:0",
+            "split|side:1",
+            "damage|mon:Rattata,wild-1,2|health:0",
+            "damage|mon:Rattata,wild-1,2|health:0",
+            "split|side:1",
+            "damage|mon:Rattata,wild-2,3|health:0",
+            "damage|mon:Rattata,wild-2,3|health:0",
+            "split|side:1",
+            "damage|mon:Rattata,wild-3,4|health:0",
+            "damage|mon:Rattata,wild-3,4|health:0",
+            "split|side:1",
+            "damage|mon:Rattata,wild-4,5|health:0",
+            "damage|mon:Rattata,wild-4,5|health:0",
+            "faint|mon:Rattata,wild-0,1",
+            "faint|mon:Rattata,wild-1,2",
+            "faint|mon:Rattata,wild-2,3",
+            "faint|mon:Rattata,wild-3,4",
+            "faint|mon:Rattata,wild-4,5",
+            "exp|mon:Pikachu,protagonist,3|exp:70",
+            "win|side:0"
+        ]"#,
+    )
+    .unwrap();
+    assert_new_logs_eq(&mut battle, &expected_logs);
+}
+
+#[test]
+fn cannot_catch_while_multiple_horde_mons_remain() {
+    let mut battle = make_horde_battle_type(0, team().unwrap(), rattata_horde(2).unwrap()).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    assert_matches::assert_matches!(
+        battle.set_player_choice("protagonist", "item pokeball"),
+        Err(err) => assert_eq!(format!("{err:#}"), "invalid choice 0: cannot use item: Poké Ball requires one target")
+    );
+    assert_matches::assert_matches!(battle.set_player_choice("protagonist", "move 0,1"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("wild-0", "move 0"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("wild-1", "move 0"), Ok(()));
+    assert_matches::assert_matches!(
+        battle.set_player_choice("protagonist", "item pokeball"),
+        Ok(())
+    );
+    assert_matches::assert_matches!(battle.set_player_choice("wild-1", "move 0"), Ok(()));
+
+    let expected_logs = serde_json::from_str::<Vec<LogMatch>>(
+        r#"[
+            "move|mon:Pikachu,protagonist,1|name:Thunderbolt|target:Rattata,wild-0,1",
+            "split|side:1",
+            "damage|mon:Rattata,wild-0,1|health:0",
+            "damage|mon:Rattata,wild-0,1|health:0",
+            "faint|mon:Rattata,wild-0,1",
+            "exp|mon:Pikachu,protagonist,1|exp:14",
+            "move|mon:Rattata,wild-1,2|name:Tackle|target:Pikachu,protagonist,1",
+            "split|side:0",
+            "damage|mon:Pikachu,protagonist,1|health:41/44",
+            "damage|mon:Pikachu,protagonist,1|health:94/100",
+            "residual",
+            "turn|turn:2",
+            "continue",
+            "useitem|player:protagonist|name:Poké Ball|target:Rattata,wild-1,2",
+            "catchfailed|player:protagonist|mon:Rattata,wild-1,2|item:Poké Ball|shakes:2",
+            "move|mon:Rattata,wild-1,2|name:Tackle|target:Pikachu,protagonist,1",
+            "split|side:0",
+            "damage|mon:Pikachu,protagonist,1|health:38/44",
+            "damage|mon:Pikachu,protagonist,1|health:87/100",
+            "residual",
+            "turn|turn:3"
+        ]"#,
+    )
+    .unwrap();
+    assert_logs_since_turn_eq(&battle, 1, &expected_logs);
+}
+
+#[test]
+fn protagonist_can_escape_horde() {
+    let mut battle = make_horde_battle_type(0, team().unwrap(), rattata_horde(5).unwrap()).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    assert_matches::assert_matches!(battle.set_player_choice("protagonist", "escape"), Ok(()));
+    for i in 0..5 {
+        assert_matches::assert_matches!(
+            battle.set_player_choice(&format!("wild-{i}"), "move 0"),
+            Ok(())
+        );
+    }
+
+    let expected_logs = serde_json::from_str::<Vec<LogMatch>>(
+        r#"[
+            "switchout|mon:Pikachu,protagonist,3",
+            "escaped|player:protagonist",
+            "win|side:1"
+        ]"#,
+    )
+    .unwrap();
+    assert_logs_since_turn_eq(&battle, 1, &expected_logs);
+}
+
+#[test]
+fn horde_cannot_be_formed_after_construction() {
+    assert_matches::assert_matches!(
+        TestBattleBuilder::new()
+            .with_battle_type(BattleType::Horde)
+            .with_seed(0)
+            .with_team_validation(false)
+            .add_protagonist_to_side_1("protagonist", "Protagonist")
+            .add_wild_mon_to_side_2("wild", "Horde", WildPlayerOptions::default())
+            .with_team("protagonist", team().unwrap())
+            .with_team("wild", rattata_horde(3).unwrap())
+            .build(static_local_data_store())
+            .err(),
+        Some(err) => assert_eq!(format!("{err:#}"), "a wild player cannot have multiple Mons in a horde battle (declare the horde in the battle options instead)")
+    );
+}
+
+#[test]
+fn horde_cannot_exceed_max_size() {
+    assert_matches::assert_matches!(
+        make_horde_battle_type(0, team().unwrap(), rattata_horde(6).unwrap()).err(),
+        Some(err) => assert_eq!(format!("{err:#}"), "battle options are invalid: Side 2 has too many wild Mons for a horde battle (at most 5 are allowed)")
+    );
+}
+
+#[test]
+fn horde_must_consist_of_wild_players() {
+    assert_matches::assert_matches!(
+        TestBattleBuilder::new()
+            .with_battle_type(BattleType::Horde)
+            .with_seed(0)
+            .with_team_validation(false)
+            .add_protagonist_to_side_1("protagonist", "Protagonist")
+            .add_player_to_side_2("trainer-1", "Trainer 1")
+            .add_player_to_side_2("trainer-2", "Trainer 2")
+            .build(static_local_data_store())
+            .err(),
+        Some(err) => assert_eq!(format!("{err:#}"), "battle options are invalid: Side 2 has too many players for a horde battle (only wild players can form a horde)")
+    );
+}
@@ -0,0 +1,188 @@
+use anyhow::Result;
+use battler::{
+    BattleType,
+    CoreBattleSnapshot,
+    PublicCoreBattle,
+    Request,
+    TeamData,
+    WrapResultError,
+};
+use battler_test_utils::{
+    TestBattleBuilder,
+    static_local_data_store,
+};
+
+fn team() -> Result<TeamData> {
+    serde_json::from_str(
+        r#"{
+            "members": [
+                {
+                    "name": "Venusaur",
+                    "species": "Venusaur",
+                    "ability": "Overgrow",
+                    "moves": [
+                        "Leech Seed",
+                        "Toxic",
+                        "Reflect",
+                        "Giga Drain"
+                    ],
+                    "nature": "Hardy",
+                    "level": 50
+                },
+                {
+                    "name": "Charizard",
+                    "species": "Charizard",
+                    "ability": "Blaze",
+                    "moves": [
+                        "Flamethrower",
+                        "Sunny Day",
+                        "Will-O-Wisp",
+                        "Protect"
+                    ],
+                    "nature": "Hardy",
+                    "level": 50
+                },
+                {
+                    "name": "Blastoise",
+                    "species": "Blastoise",
+                    "ability": "Torrent",
+                    "moves": [
+                        "Surf",
+                        "Rain Dance",
+                        "Substitute",
+                        "Protect"
+                    ],
+                    "nature": "Hardy",
+                    "level": 50
+                }
+            ]
+        }"#,
+    )
+    .wrap_error()
+}
+
+fn make_battle(seed: u64) -> Result<PublicCoreBattle<'static>> {
+    TestBattleBuilder::new()
+        .with_battle_type(BattleType::Singles)
+        .with_seed(seed)
+        .with_team_validation(false)
+        .add_player_to_side_1("player-1", "Player 1")
+        .add_player_to_side_2("player-2", "Player 2")
+        .with_team("player-1", team()?)
+        .with_team("player-2", team()?)
+        .build(static_local_data_store())
+}
+
+fn serialize_and_restore(battle: &PublicCoreBattle) -> Result<PublicCoreBattle<'static>> {
+    let snapshot = serde_json::to_string(&battle.snapshot()?)?;
+    let snapshot = serde_json::from_str::<CoreBattleSnapshot>(&snapshot)?;
+    PublicCoreBattle::restore(snapshot, static_local_data_store())
+}
+
+fn make_choices(battle: &mut PublicCoreBattle, choices: &[(&str, &str)]) {
+    for (player, choice) in choices {
+        assert_matches::assert_matches!(battle.set_player_choice(player, choice), Ok(()));
+    }
+}
+
+fn make_same_choice(
+    battle: &mut PublicCoreBattle,
+    restored: &mut PublicCoreBattle,
+    player: &str,
+    choices: &[String],
+) {
+    for choice in choices {
+        let result = battle.set_player_choice(player, choice);
+        assert_eq!(
+            restored.set_player_choice(player, choice).is_ok(),
+            result.is_ok()
+        );
+        if result.is_ok() {
+            return;
+        }
+    }
+    panic!("no valid choice for {player}");
+}
+
+#[test]
+fn restored_battle_continues_identically() {
+    let mut battle = make_battle(12345).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    make_choices(
+        &mut battle,
+        &[("player-1", "move 0"), ("player-2", "move 1")],
+    );
+    make_choices(
+        &mut battle,
+        &[("player-1", "move 2"), ("player-2", "switch 2")],
+    );
+    make_choices(
+        &mut battle,
+        &[("player-1", "move 3"), ("player-2", "move 2")],
+    );
+
+    let mut restored = serialize_and_restore(&battle).unwrap();
+    assert_eq!(restored.turn(), battle.turn());
+    assert_eq!(
+        restored.full_log().collect::<Vec<_>>(),
+        battle.full_log().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        restored.request_for_player("player-1").unwrap(),
+        battle.request_for_player("player-1").unwrap()
+    );
+    assert_eq!(
+        restored.request_for_player("player-2").unwrap(),
+        battle.request_for_player("player-2").unwrap()
+    );
+
+    // Play out the rest of the battle the same way in both battles.
+    while !battle.ended() && battle.turn() < 20 {
+        let turn = battle.turn() as usize;
+        let requests = battle.active_requests().collect::<Vec<_>>();
+        for (player, request) in requests {
+            let choices = match request {
+                Request::Switch(_) => vec!["switch 1".to_owned(), "switch 2".to_owned()],
+                _ => (0..4)
+                    .map(|i| format!("move {}", (turn + i) % 4))
+                    .chain(["switch 1".to_owned(), "switch 2".to_owned()])
+                    .collect(),
+            };
+            make_same_choice(&mut battle, &mut restored, &player, &choices);
+        }
+    }
+
+    assert_eq!(
+        restored.full_log().collect::<Vec<_>>(),
+        battle.full_log().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn restores_pending_choices() {
+    let mut battle = make_battle(0).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+    make_choices(&mut battle, &[("player-1", "move 1")]);
+
+    let mut restored = serialize_and_restore(&battle).unwrap();
+    make_choices(&mut battle, &[("player-2", "move 0")]);
+    make_choices(&mut restored, &[("player-2", "move 0")]);
+
+    assert_eq!(restored.turn(), 2);
+    assert_eq!(
+        restored.full_log().collect::<Vec<_>>(),
+        battle.full_log().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn fails_to_restore_unsupported_version() {
+    let battle = make_battle(0).unwrap();
+    let mut snapshot = battle.snapshot().unwrap();
+    snapshot.version = CoreBattleSnapshot::VERSION + 1;
+    assert_matches::assert_matches!(
+        PublicCoreBattle::restore(snapshot, static_local_data_store()).map(|_| ()),
+        Err(err) => assert!(err.to_string().contains("unsupported snapshot version"))
+    );
+}
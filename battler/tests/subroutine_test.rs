@@ -0,0 +1,191 @@
+use anyhow::Result;
+use battler::{
+    BattleType,
+    DataStore,
+    Id,
+    MoveData,
+    PublicCoreBattle,
+    SubroutineData,
+    TeamData,
+    WrapResultError,
+};
+use battler_test_utils::{
+    LogMatch,
+    TestBattleBuilder,
+    TestDataStore,
+    assert_logs_since_turn_eq,
+};
+
+fn team() -> Result<TeamData> {
+    serde_json::from_str(
+        r#"{
+            "members": [
+                {
+                    "name": "Venusaur",
+                    "species": "Venusaur",
+                    "ability": "Overgrow",
+                    "moves": [
+                        "Sum Move",
+                        "Invalid Sum Move",
+                        "Recursive Move"
+                    ],
+                    "nature": "Hardy",
+                    "level": 50
+                }
+            ]
+        }"#,
+    )
+    .wrap_error()
+}
+
+fn test_move(name: &str, on_hit: &[&str]) -> Result<MoveData> {
+    let mut move_data: MoveData = serde_json::from_str(
+        r#"{
+            "name": "",
+            "category": "Status",
+            "primary_type": "Normal",
+            "base_power": 0,
+            "accuracy": "exempt",
+            "pp": 5,
+            "target": "Normal",
+            "flags": []
+        }"#,
+    )
+    .wrap_error()?;
+    move_data.name = name.to_owned();
+    move_data.effect = serde_json::from_value(serde_json::json!({
+        "callbacks": {
+            "on_hit": on_hit,
+        },
+    }))
+    .wrap_error()?;
+    Ok(move_data)
+}
+
+fn make_data_store() -> Result<TestDataStore> {
+    let mut data = TestDataStore::new_from_env("DATA_DIR")?;
+    data.add_fake_subroutine(
+        Id::from("sum"),
+        serde_json::from_str::<SubroutineData>(
+            r#"{
+                "parameters": ["a", "b"],
+                "program": [
+                    "$sum = $a + $b",
+                    "return $sum"
+                ]
+            }"#,
+        )
+        .wrap_error()?,
+    );
+    data.add_fake_subroutine(
+        Id::from("recurse"),
+        serde_json::from_str::<SubroutineData>(r#"{ "program": ["recurse"] }"#).wrap_error()?,
+    );
+    data.add_fake_move(
+        Id::from("Sum Move"),
+        test_move(
+            "Sum Move",
+            &[
+                "$sum = 10",
+                "log_activate: str('total:{}', func_call(sum: 1 2))",
+                "log_activate: str('sum:{}', $sum)",
+            ],
+        )?,
+    );
+    data.add_fake_move(
+        Id::from("Invalid Sum Move"),
+        test_move("Invalid Sum Move", &["sum: 1"])?,
+    );
+    data.add_fake_move(
+        Id::from("Recursive Move"),
+        test_move("Recursive Move", &["recurse"])?,
+    );
+    Ok(data)
+}
+
+fn make_battle(data: &dyn DataStore) -> Result<PublicCoreBattle<'_>> {
+    TestBattleBuilder::new()
+        .with_battle_type(BattleType::Singles)
+        .with_seed(0)
+        .with_pass_allowed(true)
+        .with_team_validation(false)
+        .add_player_to_side_1("player-1", "Player 1")
+        .add_player_to_side_2("player-2", "Player 2")
+        .with_team("player-1", team()?)
+        .with_team("player-2", team()?)
+        .build(data)
+}
+
+#[test]
+fn subroutines_can_be_called_from_effects() {
+    let data = make_data_store().unwrap();
+    let mut battle = make_battle(&data).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 0"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "pass"), Ok(()));
+
+    let expected_logs = serde_json::from_str::<Vec<LogMatch>>(
+        r#"[
+            "move|mon:Venusaur,player-1,1|name:Sum Move|target:Venusaur,player-2,1",
+            "activate|move:Sum Move|total:3",
+            "activate|move:Sum Move|sum:10",
+            "residual",
+            "turn|turn:2"
+        ]"#,
+    )
+    .unwrap();
+    assert_logs_since_turn_eq(&battle, 1, &expected_logs);
+}
+
+#[test]
+fn subroutines_fail_with_wrong_number_of_arguments() {
+    let data = make_data_store().unwrap();
+    let mut battle = make_battle(&data).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 1"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "pass"), Ok(()));
+
+    let expected_logs = serde_json::from_str::<Vec<LogMatch>>(
+        r#"[
+            "move|mon:Venusaur,player-1,1|name:Invalid Sum Move|target:Venusaur,player-2,1",
+            "debug|event:Hit|effect:Invalid Sum Move|error:error on statement 1: error in subroutine sum: expected 2 arguments, found 1",
+            "residual",
+            "turn|turn:2"
+        ]"#,
+    )
+    .unwrap();
+    assert_logs_since_turn_eq(&battle, 1, &expected_logs);
+}
+
+#[test]
+fn recursive_subroutines_exceed_stack_size() {
+    let data = make_data_store().unwrap();
+    let mut battle = make_battle(&data).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 2"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "pass"), Ok(()));
+
+    assert!(battle.full_log().any(|entry| {
+        entry.starts_with("debug|event:Hit|effect:Recursive Move|")
+            && entry.ends_with("fxlang effect callback stack size exceeded for subroutine recurse")
+    }));
+
+    // The stack is fully unwound, so the subroutine can run again.
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 0"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "pass"), Ok(()));
+
+    let expected_logs = serde_json::from_str::<Vec<LogMatch>>(
+        r#"[
+            "move|mon:Venusaur,player-1,1|name:Sum Move|target:Venusaur,player-2,1",
+            "activate|move:Sum Move|total:3",
+            "activate|move:Sum Move|sum:10",
+            "residual",
+            "turn|turn:3"
+        ]"#,
+    )
+    .unwrap();
+    assert_logs_since_turn_eq(&battle, 2, &expected_logs);
+}
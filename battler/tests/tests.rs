@@ -64,6 +64,7 @@ mod abilities {
         mod flower_gift_test;
         mod forewarn_test;
         mod frisk_test;
+        mod heatproof_test;
         mod hydration_test;
         mod ice_body_test;
         mod iron_fist_test;
@@ -227,9 +228,11 @@ mod clauses {
     mod ability_clause_test;
     mod endless_battle_clause_test;
     mod force_mono_type_test;
+    mod inverse_battle_test;
     mod item_clause_test;
     mod nickname_clause_test;
     mod same_type_clause_test;
+    mod sky_battle_test;
     mod sleep_clause_test;
     mod species_clause_test;
 }
@@ -250,6 +253,7 @@ mod items {
         mod leppa_berry_test;
         mod lum_berry_test;
         mod micle_berry_test;
+        mod persim_berry_test;
         mod stat_boosting_berry_test;
     }
 
@@ -350,6 +354,10 @@ mod mechanics {
         mod max_move_test;
     }
 
+    mod generation {
+        mod generation_mechanics_test;
+    }
+
     mod mega {
         mod mega_charizard_test;
         mod mega_evolution_test;
@@ -559,6 +567,7 @@ mod moves {
         mod psyshock_test;
         mod quash_test;
         mod quick_guard_test;
+        mod rage_powder_test;
         mod reflect_type_test;
         mod relic_song_test;
         mod retaliate_test;
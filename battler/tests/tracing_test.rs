@@ -0,0 +1,231 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use battler::{
+    BattleType,
+    DataStore,
+    Id,
+    MoveData,
+    PublicCoreBattle,
+    TeamData,
+    TraceRecord,
+    Tracer,
+    WrapResultError,
+    WriterTracer,
+};
+use battler_test_utils::{
+    TestBattleBuilder,
+    TestDataStore,
+    TraceCollector,
+    assert_traced_callbacks_eq,
+};
+
+fn team() -> Result<TeamData> {
+    serde_json::from_str(
+        r#"{
+            "members": [
+                {
+                    "name": "Venusaur",
+                    "species": "Venusaur",
+                    "ability": "No Ability",
+                    "moves": [
+                        "Traced Move"
+                    ],
+                    "nature": "Hardy",
+                    "level": 50
+                }
+            ]
+        }"#,
+    )
+    .wrap_error()
+}
+
+fn make_battle(data: &dyn DataStore, tracer: Arc<dyn Tracer>) -> Result<PublicCoreBattle<'_>> {
+    TestBattleBuilder::new()
+        .with_battle_type(BattleType::Singles)
+        .with_seed(0)
+        .with_pass_allowed(true)
+        .with_team_validation(false)
+        .with_tracer(tracer)
+        .add_player_to_side_1("player-1", "Player 1")
+        .add_player_to_side_2("player-2", "Player 2")
+        .with_team("player-1", team()?)
+        .with_team("player-2", team()?)
+        .build(data)
+}
+
+fn make_data_store() -> Result<TestDataStore> {
+    let mut data = TestDataStore::new_from_env("DATA_DIR")?;
+    let mut move_data: MoveData = serde_json::from_str(
+        r#"{
+            "name": "Traced Move",
+            "category": "Status",
+            "primary_type": "Normal",
+            "base_power": 0,
+            "accuracy": "exempt",
+            "pp": 5,
+            "target": "Normal",
+            "flags": []
+        }"#,
+    )
+    .wrap_error()?;
+    move_data.effect = serde_json::from_value(serde_json::json!({
+        "callbacks": {
+            "on_hit": [
+                "$total = 0",
+                "foreach $i in [1, 2]:",
+                ["$total = $total + $i"],
+                "log_activate: str('total:{}', $total)"
+            ],
+        },
+    }))
+    .wrap_error()?;
+    data.add_fake_move(Id::from("Traced Move"), move_data);
+    Ok(data)
+}
+
+fn format_record(record: &TraceRecord) -> String {
+    match record {
+        TraceRecord::Event { event, callbacks } => format!("event:{event}|callbacks:{callbacks}"),
+        TraceRecord::EventResult { event, value } => {
+            format!("eventresult:{event}|value:{value:?}")
+        }
+        TraceRecord::Callback { event, effect, .. } => format!("callback:{effect}:{event}"),
+        TraceRecord::CallbackResult {
+            event,
+            effect,
+            value,
+            error,
+            ..
+        } => format!("callbackresult:{effect}:{event}|value:{value:?}|error:{error:?}"),
+        TraceRecord::Program { effect, callback } => format!("program:{effect}:{callback}"),
+        TraceRecord::Subroutine { name, .. } => format!("subroutine:{name}"),
+        TraceRecord::SubroutineResult { name, .. } => format!("subroutineresult:{name}"),
+        TraceRecord::Statement { statement } => format!("statement:{statement}"),
+        TraceRecord::VariableRead { name, value } => format!("read:{name}|value:{value:?}"),
+        TraceRecord::VariableWrite { name, value } => format!("write:{name}|value:{value:?}"),
+    }
+}
+
+#[test]
+fn traces_callbacks_statements_and_variables() {
+    let data = make_data_store().unwrap();
+    let tracer = TraceCollector::new();
+    let mut battle = make_battle(&data, tracer.clone()).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    tracer.clear();
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 0"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "pass"), Ok(()));
+
+    let records = tracer
+        .records()
+        .iter()
+        .map(format_record)
+        .skip_while(|record| record != "callback:Traced Move:Hit")
+        .collect::<Vec<_>>();
+    let end = records
+        .iter()
+        .position(|record| record.starts_with("callbackresult:Traced Move:Hit"))
+        .unwrap();
+    pretty_assertions::assert_eq!(
+        records[..=end],
+        [
+            "callback:Traced Move:Hit",
+            "program:move:tracedmove:on_hit",
+            "statement:1",
+            "write:total|value:UFraction(Fraction { num: 0, den: 1 })",
+            "write:i|value:UFraction(Fraction { num: 1, den: 1 })",
+            "statement:2",
+            "read:total|value:UFraction(Fraction { num: 0, den: 1 })",
+            "read:i|value:UFraction(Fraction { num: 1, den: 1 })",
+            "write:total|value:UFraction(Fraction { num: 1, den: 1 })",
+            "write:i|value:UFraction(Fraction { num: 2, den: 1 })",
+            "statement:2",
+            "read:total|value:UFraction(Fraction { num: 1, den: 1 })",
+            "read:i|value:UFraction(Fraction { num: 2, den: 1 })",
+            "write:total|value:UFraction(Fraction { num: 3, den: 1 })",
+            "statement:3",
+            "read:total|value:UFraction(Fraction { num: 3, den: 1 })",
+            "callbackresult:Traced Move:Hit|value:None|error:None",
+        ],
+    );
+}
+
+#[test]
+fn traces_callbacks_in_order() {
+    let data = make_data_store().unwrap();
+    let tracer = TraceCollector::new();
+    let mut battle = make_battle(&data, tracer.clone()).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+
+    tracer.clear();
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 0"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "pass"), Ok(()));
+
+    assert_traced_callbacks_eq(
+        &tracer,
+        &[
+            "Traced Move:MoveTargetOverride",
+            "Traced Move:MoveTargetOverride",
+            "Traced Move:BeforeTurn",
+            "Traced Move:PriorityChargeMove",
+            "Traced Move:BeforeMove",
+            "Traced Move:ModifyMoveType",
+            "Traced Move:UseMove",
+            "Traced Move:TryMove",
+            "Traced Move:UseMoveMessage",
+            "Traced Move:TryUseMove",
+            "Traced Move:PrepareHit",
+            "Traced Move:IgnoreImmunity",
+            "Traced Move:TryImmunity",
+            "Poison:Immunity",
+            "Grass:Immunity",
+            "Traced Move:TryHit",
+            "Traced Move:IgnoreImmunity",
+            "Traced Move:MoveDamage",
+            "Traced Move:MoveBasePower",
+            "Traced Move:Hit",
+            "Traced Move:AfterMoveSecondaryEffects",
+            "Traced Move:AfterMoveSecondaryEffectsDamage",
+            "Traced Move:AfterMoveSecondaryEffectsUser",
+            "Traced Move:AfterMove",
+            "No Ability:Residual",
+            "Poison:Residual",
+            "Mon:Residual",
+            "Grass:Residual",
+            "Poison:Residual",
+            "No Ability:Residual",
+            "Venusaur:Residual",
+            "Grass:Residual",
+            "Mon:Residual",
+            "Venusaur:Residual",
+            "Traced Move:DisableMove",
+            "Traced Move:DisableMove",
+            "Traced Move:MoveTargetOverride",
+            "Traced Move:MoveTargetOverride",
+        ],
+    );
+}
+
+#[test]
+fn streams_records_to_writer() {
+    let data = make_data_store().unwrap();
+    let tracer = Arc::new(WriterTracer::new(Vec::<u8>::new()));
+    let mut battle = make_battle(&data, tracer.clone()).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-1", "move 0"), Ok(()));
+    assert_matches::assert_matches!(battle.set_player_choice("player-2", "pass"), Ok(()));
+    drop(battle);
+
+    let output = Arc::into_inner(tracer).unwrap().into_inner().unwrap();
+    let records = String::from_utf8(output)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str::<TraceRecord>(line).unwrap())
+        .collect::<Vec<_>>();
+    assert!(records.iter().any(|record| matches!(
+        record,
+        TraceRecord::Callback { effect, .. } if effect == "Traced Move"
+    )));
+}
@@ -0,0 +1,208 @@
+use anyhow::Result;
+use battler::{
+    BattleType,
+    PublicCoreBattle,
+    TeamData,
+    WrapResultError,
+};
+use battler_test_utils::{
+    TestBattleBuilder,
+    static_local_data_store,
+};
+
+fn team() -> Result<TeamData> {
+    serde_json::from_str(
+        r#"{
+            "members": [
+                {
+                    "name": "Charizard",
+                    "species": "Charizard",
+                    "ability": "Blaze",
+                    "moves": [
+                        "Flamethrower",
+                        "Air Slash",
+                        "Will-O-Wisp",
+                        "Protect"
+                    ],
+                    "nature": "Hardy",
+                    "level": 50
+                },
+                {
+                    "name": "Blastoise",
+                    "species": "Blastoise",
+                    "ability": "Torrent",
+                    "moves": [
+                        "Surf",
+                        "Rain Dance",
+                        "Substitute",
+                        "Protect"
+                    ],
+                    "nature": "Hardy",
+                    "level": 50
+                }
+            ]
+        }"#,
+    )
+    .wrap_error()
+}
+
+fn make_battle(turn_history: bool, auto_continue: bool) -> Result<PublicCoreBattle<'static>> {
+    make_battle_with_max_turn_history(turn_history, auto_continue, 100)
+}
+
+fn make_battle_with_max_turn_history(
+    turn_history: bool,
+    auto_continue: bool,
+    max_turn_history: u32,
+) -> Result<PublicCoreBattle<'static>> {
+    TestBattleBuilder::new()
+        .with_battle_type(BattleType::Singles)
+        .with_seed(0)
+        .with_turn_history(turn_history)
+        .with_max_turn_history(max_turn_history)
+        .with_auto_continue(auto_continue)
+        .add_player_to_side_1("player-1", "Player 1")
+        .add_player_to_side_2("player-2", "Player 2")
+        .with_team("player-1", team()?)
+        .with_team("player-2", team()?)
+        .build(static_local_data_store())
+}
+
+fn make_choices(battle: &mut PublicCoreBattle, choices: &[(&str, &str)]) {
+    for (player, choice) in choices {
+        assert_matches::assert_matches!(battle.set_player_choice(player, choice), Ok(()));
+    }
+    if !battle.internal.engine_options.auto_continue && battle.ready_to_continue().unwrap() {
+        assert_matches::assert_matches!(battle.continue_battle(), Ok(()));
+    }
+}
+
+fn full_log(battle: &PublicCoreBattle) -> Vec<String> {
+    battle.full_log().map(|line| line.to_owned()).collect()
+}
+
+#[test]
+fn rewinds_to_earlier_turn() {
+    let mut battle = make_battle(true, true).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+    make_choices(
+        &mut battle,
+        &[("player-1", "move 0"), ("player-2", "move 1")],
+    );
+
+    let log = full_log(&battle);
+    let request = battle.request_for_player("player-1").unwrap();
+
+    make_choices(
+        &mut battle,
+        &[("player-1", "move 1"), ("player-2", "move 0")],
+    );
+    make_choices(
+        &mut battle,
+        &[("player-1", "switch 1"), ("player-2", "move 0")],
+    );
+    assert_eq!(battle.turn(), 4);
+    assert_eq!(battle.turn_history().collect::<Vec<_>>(), [1, 2, 3, 4]);
+
+    assert_matches::assert_matches!(battle.rewind_to_turn(2), Ok(()));
+    assert_eq!(battle.turn(), 2);
+    assert_eq!(full_log(&battle), log);
+    assert!(log.last().unwrap().starts_with("turn|turn:2"));
+    assert_eq!(battle.request_for_player("player-1").unwrap(), request);
+    assert_eq!(battle.turn_history().collect::<Vec<_>>(), [1, 2]);
+    assert!(!battle.has_new_log_entries());
+    assert_eq!(battle.new_log_entries().count(), 0);
+
+    // Take the turn back and try something else.
+    make_choices(
+        &mut battle,
+        &[("player-1", "move 2"), ("player-2", "move 0")],
+    );
+    assert_eq!(battle.turn(), 3);
+    assert!(
+        battle
+            .full_log()
+            .skip(log.len())
+            .any(|line| line.contains("name:Will-O-Wisp"))
+    );
+    assert_eq!(battle.turn_history().collect::<Vec<_>>(), [1, 2, 3]);
+}
+
+#[test]
+fn rewinds_multiple_times() {
+    let mut battle = make_battle(true, true).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+    let log = full_log(&battle);
+
+    for _ in 0..3 {
+        make_choices(
+            &mut battle,
+            &[("player-1", "move 0"), ("player-2", "move 1")],
+        );
+        assert_eq!(battle.turn(), 2);
+        assert_matches::assert_matches!(battle.rewind_to_turn(1), Ok(()));
+        assert_eq!(full_log(&battle), log);
+    }
+}
+
+#[test]
+fn rewinds_without_auto_continue() {
+    let mut battle = make_battle(true, false).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+    assert_matches::assert_matches!(battle.continue_battle(), Ok(()));
+    make_choices(
+        &mut battle,
+        &[("player-1", "move 0"), ("player-2", "move 1")],
+    );
+    assert_eq!(battle.turn_history().collect::<Vec<_>>(), [1, 2]);
+
+    assert_matches::assert_matches!(battle.rewind_to_turn(1), Ok(()));
+    assert_eq!(battle.turn(), 1);
+    make_choices(
+        &mut battle,
+        &[("player-1", "move 0"), ("player-2", "move 1")],
+    );
+    assert_eq!(battle.turn(), 2);
+}
+
+#[test]
+fn fails_to_rewind_to_missing_turn() {
+    let mut battle = make_battle(true, true).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+    assert_matches::assert_matches!(battle.rewind_to_turn(5), Err(err) => {
+        assert!(err.to_string().contains("checkpoint for turn 5 not found"));
+    });
+}
+
+#[test]
+fn turn_history_can_be_disabled() {
+    let mut battle = make_battle(false, true).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+    make_choices(
+        &mut battle,
+        &[("player-1", "move 0"), ("player-2", "move 1")],
+    );
+    assert_eq!(battle.turn_history().count(), 0);
+    assert_matches::assert_matches!(battle.rewind_to_turn(1), Err(err) => {
+        assert!(err.to_string().contains("turn history is disabled"));
+    });
+}
+
+#[test]
+fn removes_oldest_checkpoints_over_limit() {
+    let mut battle = make_battle_with_max_turn_history(true, true, 2).unwrap();
+    assert_matches::assert_matches!(battle.start(), Ok(()));
+    for _ in 0..3 {
+        make_choices(
+            &mut battle,
+            &[("player-1", "move 3"), ("player-2", "move 3")],
+        );
+    }
+    assert_eq!(battle.turn(), 4);
+    assert_eq!(battle.turn_history().collect::<Vec<_>>(), [3, 4]);
+    assert_matches::assert_matches!(battle.rewind_to_turn(2), Err(err) => {
+        assert!(err.to_string().contains("checkpoint for turn 2 not found"));
+    });
+    assert_matches::assert_matches!(battle.rewind_to_turn(3), Ok(()));
+    assert_eq!(battle.turn(), 3);
+}